@@ -33,6 +33,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }),
         category: BenchmarkCategory::Performance as i32,
         version: "1.0.0".to_string(),
+        custom_category: None,
     });
 
     match client.create_benchmark(create_request).await {
@@ -56,6 +57,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         page_size: 10,
         sort_by: "created_at".to_string(),
         sort_desc: true,
+        custom_category: None,
     });
 
     match client.list_benchmarks(list_request).await {
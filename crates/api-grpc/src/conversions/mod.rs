@@ -28,21 +28,44 @@ impl From<BenchmarkCategory> for proto::BenchmarkCategory {
             BenchmarkCategory::Safety => proto::BenchmarkCategory::Safety,
             BenchmarkCategory::Cost => proto::BenchmarkCategory::Cost,
             BenchmarkCategory::Capability => proto::BenchmarkCategory::Capability,
+            BenchmarkCategory::Custom(_) => proto::BenchmarkCategory::Custom,
         }
     }
 }
 
-impl From<proto::BenchmarkCategory> for BenchmarkCategory {
-    fn from(cat: proto::BenchmarkCategory) -> Self {
-        match cat {
-            proto::BenchmarkCategory::Performance => BenchmarkCategory::Performance,
-            proto::BenchmarkCategory::Accuracy => BenchmarkCategory::Accuracy,
-            proto::BenchmarkCategory::Reliability => BenchmarkCategory::Reliability,
-            proto::BenchmarkCategory::Safety => BenchmarkCategory::Safety,
-            proto::BenchmarkCategory::Cost => BenchmarkCategory::Cost,
-            proto::BenchmarkCategory::Capability => BenchmarkCategory::Capability,
-            _ => BenchmarkCategory::Performance, // Default fallback
+/// Split a category into its proto enum value and `custom_category` name
+pub fn category_to_proto(cat: &BenchmarkCategory) -> (i32, Option<String>) {
+    let custom = match cat {
+        BenchmarkCategory::Custom(name) => Some(name.clone()),
+        _ => None,
+    };
+    (proto::BenchmarkCategory::from(cat.clone()) as i32, custom)
+}
+
+/// Rebuild a category from its proto enum value and `custom_category` name
+///
+/// Custom categories must carry a valid name; the name is ignored for
+/// built-in categories. An unspecified category is an error.
+pub fn category_from_proto(
+    category: i32,
+    custom_category: Option<&str>,
+) -> Result<BenchmarkCategory, String> {
+    match proto::BenchmarkCategory::try_from(category) {
+        Ok(proto::BenchmarkCategory::Performance) => Ok(BenchmarkCategory::Performance),
+        Ok(proto::BenchmarkCategory::Accuracy) => Ok(BenchmarkCategory::Accuracy),
+        Ok(proto::BenchmarkCategory::Reliability) => Ok(BenchmarkCategory::Reliability),
+        Ok(proto::BenchmarkCategory::Safety) => Ok(BenchmarkCategory::Safety),
+        Ok(proto::BenchmarkCategory::Cost) => Ok(BenchmarkCategory::Cost),
+        Ok(proto::BenchmarkCategory::Capability) => Ok(BenchmarkCategory::Capability),
+        Ok(proto::BenchmarkCategory::Custom) => {
+            let name = custom_category
+                .ok_or_else(|| "custom_category is required for custom categories".to_string())?;
+            BenchmarkCategory::custom(name)
         }
+        Ok(proto::BenchmarkCategory::Unspecified) => {
+            Err("benchmark category is required".to_string())
+        }
+        Err(_) => Err(format!("Unknown benchmark category: {}", category)),
     }
 }
 
@@ -258,3 +281,33 @@ impl From<proto::Vote> for governance::Vote {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_category_roundtrip() {
+        let category = BenchmarkCategory::custom("code_review").unwrap();
+        let (value, custom) = category_to_proto(&category);
+        assert_eq!(value, proto::BenchmarkCategory::Custom as i32);
+        assert_eq!(custom.as_deref(), Some("code_review"));
+        assert_eq!(category_from_proto(value, custom.as_deref()).unwrap(), category);
+
+        for category in BenchmarkCategory::all() {
+            let (value, custom) = category_to_proto(category);
+            assert!(custom.is_none());
+            assert_eq!(&category_from_proto(value, None).unwrap(), category);
+        }
+    }
+
+    #[test]
+    fn test_custom_category_requires_valid_name() {
+        let custom = proto::BenchmarkCategory::Custom as i32;
+        assert!(category_from_proto(custom, None).is_err());
+        assert!(category_from_proto(custom, Some("Not Valid")).is_err());
+        assert!(category_from_proto(99, None).is_err());
+        let unspecified = proto::BenchmarkCategory::Unspecified as i32;
+        assert!(category_from_proto(unspecified, None).is_err());
+    }
+}
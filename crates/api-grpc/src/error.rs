@@ -47,6 +47,9 @@ impl From<GrpcError> for Status {
                 ApplicationError::Forbidden(msg) => Status::new(Code::PermissionDenied, msg),
                 ApplicationError::InvalidInput(msg) => Status::new(Code::InvalidArgument, msg),
                 ApplicationError::ValidationFailed(msg) => Status::new(Code::InvalidArgument, msg),
+                ApplicationError::InvalidFields(result) => {
+                    Status::new(Code::InvalidArgument, result.summary())
+                }
                 ApplicationError::Conflict(msg) => Status::new(Code::AlreadyExists, msg),
                 ApplicationError::Internal(msg) => Status::new(Code::Internal, msg),
                 ApplicationError::ServiceUnavailable(msg) => Status::new(Code::Unavailable, msg),
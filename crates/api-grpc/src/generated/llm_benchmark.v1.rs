@@ -65,6 +65,8 @@ pub struct Benchmark {
     pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(string, tag = "9")]
     pub created_by: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "10")]
+    pub custom_category: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Create benchmark request
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -76,6 +78,8 @@ pub struct CreateBenchmarkRequest {
     pub category: i32,
     #[prost(string, tag = "3")]
     pub version: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub custom_category: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Create benchmark response
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -120,6 +124,8 @@ pub struct ListBenchmarksRequest {
     pub sort_by: ::prost::alloc::string::String,
     #[prost(bool, tag = "8")]
     pub sort_desc: bool,
+    #[prost(message, optional, tag = "9")]
+    pub custom_category: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// List benchmarks response
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -207,6 +213,8 @@ pub enum BenchmarkCategory {
     Safety = 4,
     Cost = 5,
     Capability = 6,
+    /// Community-defined category; the name is carried in `custom_category`
+    Custom = 7,
 }
 impl BenchmarkCategory {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -222,6 +230,7 @@ impl BenchmarkCategory {
             BenchmarkCategory::Safety => "BENCHMARK_CATEGORY_SAFETY",
             BenchmarkCategory::Cost => "BENCHMARK_CATEGORY_COST",
             BenchmarkCategory::Capability => "BENCHMARK_CATEGORY_CAPABILITY",
+            BenchmarkCategory::Custom => "BENCHMARK_CATEGORY_CUSTOM",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -234,6 +243,7 @@ impl BenchmarkCategory {
             "BENCHMARK_CATEGORY_SAFETY" => Some(Self::Safety),
             "BENCHMARK_CATEGORY_COST" => Some(Self::Cost),
             "BENCHMARK_CATEGORY_CAPABILITY" => Some(Self::Capability),
+            "BENCHMARK_CATEGORY_CUSTOM" => Some(Self::Custom),
             _ => None,
         }
     }
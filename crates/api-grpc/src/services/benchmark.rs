@@ -1,6 +1,8 @@
 //! Benchmark service implementation
 
-use crate::conversions::{datetime_to_timestamp, timestamp_to_datetime};
+use crate::conversions::{
+    category_from_proto, category_to_proto, datetime_to_timestamp, timestamp_to_datetime,
+};
 use crate::proto::{
     benchmark_service_server::BenchmarkService, ApproveBenchmarkRequest, ApproveBenchmarkResponse,
     Benchmark, BenchmarkMetadata, Citation, CreateBenchmarkRequest, CreateBenchmarkResponse,
@@ -39,18 +41,23 @@ impl BenchmarkService for BenchmarkServiceImpl {
         let req = request.into_inner();
         info!("Creating benchmark: {:?}", req.metadata.as_ref().map(|m| &m.name));
 
+        let category = category_from_proto(req.category, req.custom_category.as_deref())
+            .map_err(Status::invalid_argument)?;
+        let (category, custom_category) = category_to_proto(&category);
+
         // TODO: Call application service to create benchmark
         // For now, return a placeholder
         let benchmark = Benchmark {
             id: uuid::Uuid::now_v7().to_string(),
             metadata: req.metadata,
-            category: req.category,
+            category,
             status: crate::proto::BenchmarkStatus::Draft as i32,
             version: req.version,
             version_id: uuid::Uuid::now_v7().to_string(),
             created_at: datetime_to_timestamp(&chrono::Utc::now()),
             updated_at: datetime_to_timestamp(&chrono::Utc::now()),
             created_by: "user-id-placeholder".to_string(),
+            custom_category,
         };
 
         Ok(Response::new(CreateBenchmarkResponse {
@@ -76,6 +83,12 @@ impl BenchmarkService for BenchmarkServiceImpl {
         let req = request.into_inner();
         debug!("Listing benchmarks with filters");
 
+        // An unspecified category means no category filter
+        if req.category != crate::proto::BenchmarkCategory::Unspecified as i32 {
+            category_from_proto(req.category, req.custom_category.as_deref())
+                .map_err(Status::invalid_argument)?;
+        }
+
         // TODO: Call application service to list benchmarks
         Ok(Response::new(ListBenchmarksResponse {
             benchmarks: vec![],
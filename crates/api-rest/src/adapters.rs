@@ -0,0 +1,1793 @@
+//! Infrastructure adapters for the application layer.
+//!
+//! This module binds the application repository ports and event publisher
+//! to the PostgreSQL repositories, Redis cache and Redis messaging provided
+//! by the infrastructure crate. It is used by [`AppState::from_infrastructure`]
+//! to wire production services.
+//!
+//! [`AppState::from_infrastructure`]: crate::state::AppState::from_infrastructure
+
+use crate::state::VerificationProgressSource;
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use llm_benchmark_application::{
+    services::{
+        ApiKeyDto, ApiKeyFilters, ApiKeyWithSecretDto, BenchmarkDto, BenchmarkFilters,
+        BenchmarkRepositoryPort, BenchmarkVersionDto, CreateApiKeyData, CreateBenchmarkData,
+        CreateOrganizationData, CreateSubmissionData, CreateUserData, CreateVersionData,
+        DeletionMode, EventPublisher, ExecutionTelemetryPort, GovernanceActivityDto,
+        GovernanceActivityKind, LeaderboardEntryDto, LeaderboardViewer, OrganizationDto,
+        OrganizationMemberDto, OrganizationRepositoryPort, Pagination, PasswordHasher,
+        ServiceConfig, ServiceEvent, SubmissionDto, SubmissionRepositoryPort, TestCaseTelemetry,
+        UpdateBenchmarkData, UpdateOrganizationData, UpdateSubmissionData, UpdateUserData, UserDto,
+        UserProfileDto, UserRepositoryPort, VerificationData, VerificationEventDto,
+        VerificationProgress, TOMBSTONE_USER_ID,
+    },
+    validation::{OrganizationRole, SubmissionQueryFilters},
+    ApplicationError,
+};
+use llm_benchmark_common::{crypto::generate_token, pagination::PaginationParams};
+use llm_benchmark_domain::{
+    benchmark::{BenchmarkStatus, LicenseType},
+    evaluation::{
+        AggregationMethod, EnvironmentRequirements, EvaluationCriteria, ExecutionConfig,
+        MetricDefinition, MetricRange, MetricType, ModelParameters, ParallelismConfig,
+        ScoreNormalization, ScorePrecision,
+    },
+    identifiers::{BenchmarkId, BenchmarkVersionId, OrganizationId, SubmissionId, UserId, VerificationId},
+    submission::{
+        EnvironmentInfo, ExecutionMetadata, ModelInfo, Submission, SubmissionResults,
+        SubmitterInfo, VerificationDetails, VerificationEvent, VerificationLevel, VerifiedBy,
+    },
+    test_case::TestCase,
+    user::{
+        Organization, OrganizationRole as MemberRole, OrganizationType, User, UserProfile,
+        UserRole,
+    },
+    version::SemanticVersion,
+};
+use llm_benchmark_infrastructure::{
+    external_consumers::{
+        observatory::{ObservatoryConsumerTrait, TelemetryQuery},
+        ExecutionTelemetry, ExternalConsumerError,
+    },
+    messaging::{EventMessage, Publisher, Subscriber},
+    repositories::{
+        AccountDeletion, AccountDeletionOutcome, ApiKeyOwner, ApiKeyQuery, ApiKeyRecord,
+        ApiKeyRepository, BenchmarkQuery, BenchmarkRecord, BenchmarkRepository,
+        GovernanceActivityKind as InfraActivityKind, OrganizationQuery, OrganizationRepository,
+        PgApiKeyRepository, PgBenchmarkRepository, PgOrganizationRepository, PgProposalRepository,
+        PgSubmissionRepository, PgUserRepository, ProposalRepository, SubmissionQuery,
+        SubmissionRepository, UserRepository,
+    },
+    Cache, DatabasePool, Error as InfraError,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Convert an infrastructure error into an application error
+fn map_infra_error(err: InfraError) -> ApplicationError {
+    match err {
+        InfraError::NotFound(msg) => ApplicationError::NotFound(msg),
+        InfraError::Conflict(msg) => ApplicationError::Conflict(msg),
+        InfraError::Timeout(msg) => ApplicationError::Timeout(msg),
+        InfraError::Connection(msg) => ApplicationError::ServiceUnavailable(msg),
+        other if other.is_unique_violation() => ApplicationError::Conflict(other.to_string()),
+        other => ApplicationError::Internal(other.to_string()),
+    }
+}
+
+/// Parse a string identifier, mapping failures to `InvalidInput`
+fn parse_id<T: FromStr>(id: &str, kind: &str) -> Result<T, ApplicationError> {
+    id.parse()
+        .map_err(|_| ApplicationError::InvalidInput(format!("Invalid {} ID: {}", kind, id)))
+}
+
+fn empty_model_parameters() -> ModelParameters {
+    ModelParameters {
+        temperature: None,
+        top_p: None,
+        top_k: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        random_seed: None,
+        additional_params: HashMap::new(),
+    }
+}
+
+// ============================================================================
+// BENCHMARKS
+// ============================================================================
+
+/// Benchmark repository port backed by PostgreSQL, with benchmark lookups
+/// by ID cached for the configured TTL.
+pub struct PgBenchmarkStore<C: Cache> {
+    benchmarks: PgBenchmarkRepository,
+    cache: Arc<C>,
+    cache_ttl: Option<Duration>,
+}
+
+impl<C: Cache> PgBenchmarkStore<C> {
+    /// Create a store over `db`, caching according to `config`
+    pub fn new(db: &DatabasePool, cache: Arc<C>, config: &ServiceConfig) -> Self {
+        Self {
+            benchmarks: PgBenchmarkRepository::new(db.pool().clone()),
+            cache,
+            cache_ttl: config
+                .cache_enabled
+                .then(|| Duration::from_secs(config.cache_ttl_seconds)),
+        }
+    }
+
+    fn cache_key(id: &str) -> String {
+        format!("benchmark:{}", id)
+    }
+
+    async fn invalidate(&self, id: &str) {
+        if self.cache_ttl.is_none() {
+            return;
+        }
+        if let Err(e) = self.cache.delete(&Self::cache_key(id)).await {
+            warn!(benchmark_id = %id, error = %e, "Failed to invalidate cached benchmark");
+        }
+    }
+
+    async fn to_dto(&self, record: BenchmarkRecord) -> Result<BenchmarkDto, ApplicationError> {
+        Ok(BenchmarkDto {
+            id: record.id.to_string(),
+            name: record.name,
+            slug: record.slug,
+            description: record.description,
+            category: record.category,
+            status: record.status,
+            tags: record.tags,
+            current_version: Some(record.version.to_string()),
+            submission_count: record.submission_count,
+            created_by: Some(record.created_by.to_string()),
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        })
+    }
+
+    async fn require_record(&self, id: &str) -> Result<BenchmarkRecord, ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(id, "benchmark")?;
+        self.benchmarks
+            .get_by_id(benchmark_id)
+            .await
+            .map_err(map_infra_error)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Benchmark not found: {}", id)))
+    }
+}
+
+/// Evaluation criteria assigned to benchmarks created through the API
+fn default_evaluation_criteria() -> EvaluationCriteria {
+    EvaluationCriteria {
+        primary_metric: MetricDefinition {
+            name: "accuracy".to_string(),
+            description: "Fraction of test cases answered correctly".to_string(),
+            metric_type: MetricType::Accuracy,
+            unit: None,
+            higher_is_better: true,
+            range: Some(MetricRange { min: 0.0, max: 1.0 }),
+        },
+        secondary_metrics: vec![],
+        aggregation_method: AggregationMethod::Mean,
+        score_normalization: ScoreNormalization::None,
+        minimum_test_cases: 1,
+        confidence_level: 0.95,
+        score_precision: None,
+        leaderboard_min_verification: VerificationLevel::Unverified,
+    }
+}
+
+/// Execution configuration assigned to benchmarks created through the API
+fn default_execution_config() -> ExecutionConfig {
+    ExecutionConfig {
+        timeout_per_test_ms: 30_000,
+        max_retries: 3,
+        retry_delay_ms: 1_000,
+        parallelism: ParallelismConfig {
+            max_concurrent_requests: 1,
+            rate_limit_per_minute: None,
+        },
+        model_parameters: empty_model_parameters(),
+        environment_requirements: EnvironmentRequirements {
+            container_image: None,
+            python_version: None,
+            required_packages: vec![],
+            gpu_required: false,
+            min_memory_gb: None,
+        },
+    }
+}
+
+#[async_trait]
+impl<C: Cache + 'static> BenchmarkRepositoryPort for PgBenchmarkStore<C> {
+    async fn create(&self, data: &CreateBenchmarkData) -> Result<String, ApplicationError> {
+        let version = SemanticVersion::parse(&data.version)
+            .map_err(|e| ApplicationError::InvalidInput(format!("Invalid version: {}", e)))?;
+        let now = chrono::Utc::now();
+
+        let record = BenchmarkRecord {
+            id: BenchmarkId::new(),
+            version_id: BenchmarkVersionId::new(),
+            slug: data.slug.clone(),
+            name: data.name.clone(),
+            description: data.description.clone(),
+            long_description: None,
+            category: data.category.clone(),
+            status: BenchmarkStatus::Draft,
+            version,
+            tags: data.tags.clone(),
+            license: LicenseType::Apache2,
+            created_by: parse_id(&data.creator_id, "user")?,
+            created_at: now,
+            updated_at: now,
+            evaluation_criteria: EvaluationCriteria {
+                leaderboard_min_verification: data.leaderboard_min_verification,
+                ..default_evaluation_criteria()
+            },
+            execution_config: default_execution_config(),
+            test_cases: data.test_cases.clone(),
+            submission_count: 0,
+        };
+
+        let id = self.benchmarks.create(&record).await.map_err(map_infra_error)?;
+        Ok(id.to_string())
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<BenchmarkDto>, ApplicationError> {
+        let Ok(benchmark_id) = id.parse::<BenchmarkId>() else {
+            return Ok(None);
+        };
+
+        let key = Self::cache_key(id);
+        if self.cache_ttl.is_some() {
+            match self.cache.get::<BenchmarkDto>(&key).await {
+                Ok(Some(dto)) => return Ok(Some(dto)),
+                Ok(None) => {}
+                Err(e) => warn!(benchmark_id = %id, error = %e, "Benchmark cache read failed"),
+            }
+        }
+
+        let Some(record) = self
+            .benchmarks
+            .get_by_id(benchmark_id)
+            .await
+            .map_err(map_infra_error)?
+        else {
+            return Ok(None);
+        };
+        let dto = self.to_dto(record).await?;
+
+        if let Some(ttl) = self.cache_ttl {
+            if let Err(e) = self.cache.set_with_ttl(&key, &dto, ttl).await {
+                warn!(benchmark_id = %id, error = %e, "Benchmark cache write failed");
+            }
+        }
+
+        Ok(Some(dto))
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<BenchmarkDto>, ApplicationError> {
+        match self.benchmarks.get_by_slug(slug).await.map_err(map_infra_error)? {
+            Some(record) => Ok(Some(self.to_dto(record).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list(
+        &self,
+        filters: &BenchmarkFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<BenchmarkDto>, u64), ApplicationError> {
+        let query = BenchmarkQuery {
+            category: filters.category.clone(),
+            status: filters.status,
+            created_by: filters
+                .maintainer_id
+                .as_deref()
+                .map(|id| parse_id(id, "user"))
+                .transpose()?,
+            search_text: filters.search.clone(),
+            tags: filters.tags.clone(),
+            pagination: pagination.clone().into(),
+            ..Default::default()
+        };
+
+        let page = self.benchmarks.list(query).await.map_err(map_infra_error)?;
+        let mut items = Vec::with_capacity(page.items.len());
+        for record in page.items {
+            items.push(self.to_dto(record).await?);
+        }
+        Ok((items, page.total))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateBenchmarkData) -> Result<(), ApplicationError> {
+        let mut record = self.require_record(id).await?;
+
+        if let Some(ref name) = update.name {
+            record.name = name.clone();
+        }
+        if let Some(ref description) = update.description {
+            record.description = description.clone();
+        }
+        if let Some(ref tags) = update.tags {
+            record.tags = tags.clone();
+        }
+        if let Some(ref long_description) = update.long_description {
+            record.long_description = Some(long_description.clone());
+        }
+        if let Some(ref test_cases) = update.test_cases {
+            record.test_cases = test_cases.clone();
+        }
+
+        self.benchmarks
+            .update(&record, "Metadata updated", false)
+            .await
+            .map_err(map_infra_error)?;
+        self.invalidate(id).await;
+        Ok(())
+    }
+
+    async fn update_status(&self, id: &str, status: BenchmarkStatus) -> Result<(), ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(id, "benchmark")?;
+        self.benchmarks
+            .update_status(benchmark_id, status)
+            .await
+            .map_err(map_infra_error)?;
+        self.invalidate(id).await;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(id, "benchmark")?;
+        let deleted = self.benchmarks.delete(benchmark_id).await.map_err(map_infra_error)?;
+        self.invalidate(id).await;
+        if !deleted {
+            return Err(ApplicationError::NotFound(format!("Benchmark not found: {}", id)));
+        }
+        Ok(())
+    }
+
+    async fn slug_exists(&self, slug: &str) -> Result<bool, ApplicationError> {
+        self.benchmarks.slug_exists(slug).await.map_err(map_infra_error)
+    }
+
+    async fn create_version(&self, data: &CreateVersionData) -> Result<String, ApplicationError> {
+        let mut record = self.require_record(&data.benchmark_id).await?;
+        record.version = SemanticVersion::parse(&data.version)
+            .map_err(|e| ApplicationError::InvalidInput(format!("Invalid version: {}", e)))?;
+        let existing = self
+            .benchmarks
+            .get_version(record.id, &record.version)
+            .await
+            .map_err(map_infra_error)?;
+        if existing.is_some() {
+            return Err(ApplicationError::Conflict(format!(
+                "Version {} already exists for benchmark {}",
+                record.version, data.benchmark_id
+            )));
+        }
+        if let Some(ref test_cases) = data.test_cases {
+            record.test_cases = test_cases.clone();
+        }
+
+        let version_id = self
+            .benchmarks
+            .update(&record, &data.changelog, data.breaking_changes)
+            .await
+            .map_err(map_infra_error)?;
+        self.invalidate(&data.benchmark_id).await;
+        Ok(version_id.to_string())
+    }
+
+    async fn get_versions(&self, benchmark_id: &str) -> Result<Vec<BenchmarkVersionDto>, ApplicationError> {
+        let id: BenchmarkId = parse_id(benchmark_id, "benchmark")?;
+        let history = self
+            .benchmarks
+            .get_version_history(id)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(history
+            .into_iter()
+            .map(|v| BenchmarkVersionDto {
+                id: v.version_id.to_string(),
+                benchmark_id: benchmark_id.to_string(),
+                version: v.version.to_string(),
+                changelog: v.changelog,
+                breaking_changes: v.breaking_changes,
+                created_at: v.created_at,
+            })
+            .collect())
+    }
+
+    async fn get_test_cases(&self, benchmark_id: &str) -> Result<Vec<TestCase>, ApplicationError> {
+        Ok(self.require_record(benchmark_id).await?.test_cases)
+    }
+}
+
+// ============================================================================
+// SUBMISSIONS
+// ============================================================================
+
+/// Submission repository port backed by PostgreSQL.
+///
+/// Submission notes are not persisted by the PostgreSQL schema.
+pub struct PgSubmissionStore {
+    submissions: PgSubmissionRepository,
+    benchmarks: PgBenchmarkRepository,
+}
+
+impl PgSubmissionStore {
+    /// Create a store over `db`
+    pub fn new(db: &DatabasePool) -> Self {
+        Self {
+            submissions: PgSubmissionRepository::new(db.pool().clone()),
+            benchmarks: PgBenchmarkRepository::new(db.pool().clone()),
+        }
+    }
+}
+
+fn submission_to_dto(submission: Submission) -> SubmissionDto {
+    SubmissionDto {
+        aggregate_score: submission.results().aggregate_score,
+        verification_level: submission.verification_status().level,
+        cancelled_at: submission.cancelled_at(),
+        id: submission.id.to_string(),
+        benchmark_id: submission.benchmark_id.to_string(),
+        benchmark_version_id: submission.benchmark_version_id.to_string(),
+        model_provider: submission.model_info.provider,
+        model_name: submission.model_info.model_name,
+        model_version: submission.model_info.model_version,
+        submitter_id: submission.submitter.user_id.to_string(),
+        organization_id: submission.submitter.organization_id.map(|id| id.to_string()),
+        visibility: submission.visibility,
+        created_at: submission.created_at,
+        updated_at: submission.updated_at,
+    }
+}
+
+fn verification_event_to_dto(event: VerificationEvent) -> VerificationEventDto {
+    VerificationEventDto {
+        submission_id: event.submission_id.to_string(),
+        from_level: event.from_level,
+        to_level: event.to_level,
+        verifier: event.verifier.map(|verifier| match verifier {
+            VerifiedBy::CommunityMember { user_id } => user_id.to_string(),
+            VerifiedBy::Platform { verification_id } => verification_id.to_string(),
+            VerifiedBy::Auditor { auditor_name, .. } => auditor_name,
+        }),
+        reason: event.reason,
+        at: event.at,
+    }
+}
+
+#[async_trait]
+impl SubmissionRepositoryPort for PgSubmissionStore {
+    async fn create(&self, data: &CreateSubmissionData) -> Result<String, ApplicationError> {
+        let now = chrono::Utc::now();
+
+        let score_range = self.score_range(&data.benchmark_id).await?;
+        let submission = Submission::new(
+            parse_id(&data.benchmark_id, "benchmark")?,
+            parse_id(&data.benchmark_version_id, "benchmark version")?,
+            ModelInfo {
+                model_id: None,
+                provider: data.model_provider.clone(),
+                model_name: data.model_name.clone(),
+                model_version: data.model_version.clone(),
+                api_endpoint: None,
+                is_official: false,
+            },
+            SubmitterInfo {
+                user_id: parse_id(&data.submitter_id, "user")?,
+                organization_id: data
+                    .organization_id
+                    .as_deref()
+                    .map(|id| parse_id::<OrganizationId>(id, "organization"))
+                    .transpose()?,
+                is_verified_provider: false,
+            },
+            SubmissionResults {
+                aggregate_score: data.aggregate_score,
+                metric_scores: HashMap::new(),
+                test_case_results: vec![],
+                confidence_interval: None,
+                statistical_significance: None,
+            },
+            ExecutionMetadata {
+                execution_id: uuid::Uuid::now_v7().to_string(),
+                started_at: now,
+                completed_at: now,
+                duration_seconds: 0.0,
+                environment: EnvironmentInfo {
+                    platform: "unknown".to_string(),
+                    architecture: "unknown".to_string(),
+                    container_image: None,
+                    container_digest: None,
+                    python_version: None,
+                    package_versions: HashMap::new(),
+                    hardware: None,
+                },
+                model_parameters_used: empty_model_parameters(),
+                dataset_checksums: HashMap::new(),
+                random_seed: None,
+                executor_version: "unknown".to_string(),
+            },
+            data.visibility,
+            score_range.as_ref(),
+        )?;
+
+        let id = self.submissions.create(&submission).await.map_err(map_infra_error)?;
+        Ok(id.to_string())
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<SubmissionDto>, ApplicationError> {
+        let Ok(submission_id) = id.parse::<SubmissionId>() else {
+            return Ok(None);
+        };
+        Ok(self
+            .submissions
+            .get_by_id(submission_id)
+            .await
+            .map_err(map_infra_error)?
+            .map(submission_to_dto))
+    }
+
+    async fn list(
+        &self,
+        filters: &SubmissionQueryFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<SubmissionDto>, u64), ApplicationError> {
+        let query = SubmissionQuery {
+            benchmark_id: filters
+                .benchmark_id
+                .as_deref()
+                .map(|id| parse_id(id, "benchmark"))
+                .transpose()?,
+            model_provider: filters.model_provider.clone(),
+            model_name: filters.model_name.clone(),
+            user_id: filters
+                .submitter_id
+                .as_deref()
+                .map(|id| parse_id(id, "user"))
+                .transpose()?,
+            verification_level: filters.verification_level,
+            visibility: filters.visibility,
+            min_score: filters.min_score,
+            max_score: filters.max_score,
+            pagination: pagination.clone().into(),
+            ..Default::default()
+        };
+
+        let page = self.submissions.list(query).await.map_err(map_infra_error)?;
+        Ok((page.items.into_iter().map(submission_to_dto).collect(), page.total))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateSubmissionData) -> Result<(), ApplicationError> {
+        let submission_id: SubmissionId = parse_id(id, "submission")?;
+        if let Some(visibility) = update.visibility {
+            self.submissions
+                .update_visibility(submission_id, visibility)
+                .await
+                .map_err(map_infra_error)?;
+        }
+        Ok(())
+    }
+
+    async fn update_verification(
+        &self,
+        id: &str,
+        verification: &VerificationData,
+    ) -> Result<(), ApplicationError> {
+        let submission_id: SubmissionId = parse_id(id, "submission")?;
+        let mut submission = self
+            .submissions
+            .get_by_id(submission_id)
+            .await
+            .map_err(map_infra_error)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Submission not found: {}", id)))?;
+
+        let verified_by = match verification.level {
+            VerificationLevel::Unverified => None,
+            VerificationLevel::CommunityVerified => Some(VerifiedBy::CommunityMember {
+                user_id: parse_id(&verification.verified_by, "user")?,
+            }),
+            VerificationLevel::PlatformVerified => Some(VerifiedBy::Platform {
+                verification_id: VerificationId::new(),
+            }),
+            VerificationLevel::Audited => Some(VerifiedBy::Auditor {
+                auditor_name: verification.verified_by.clone(),
+                attestation_url: None,
+            }),
+        };
+
+        let details = verification.reproduced_score.map(|reproduced_score| VerificationDetails {
+            reproduced_score,
+            score_variance: verification.score_variance.unwrap_or(0.0),
+            environment_match: verification.environment_match.unwrap_or(false),
+            notes: verification.notes.clone(),
+        });
+        submission.verify(verification.level, verified_by, details)?;
+
+        self.submissions
+            .update_verification(
+                submission_id,
+                submission.verification_status(),
+                verification.notes.as_deref(),
+            )
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn verification_history(&self, id: &str) -> Result<Vec<VerificationEventDto>, ApplicationError> {
+        let submission_id: SubmissionId = parse_id(id, "submission")?;
+        Ok(self
+            .submissions
+            .verification_history(submission_id)
+            .await
+            .map_err(map_infra_error)?
+            .into_iter()
+            .map(verification_event_to_dto)
+            .collect())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        let submission_id: SubmissionId = parse_id(id, "submission")?;
+        if !self.submissions.delete(submission_id).await.map_err(map_infra_error)? {
+            return Err(ApplicationError::NotFound(format!("Submission not found: {}", id)));
+        }
+        Ok(())
+    }
+
+    async fn cancel(
+        &self,
+        id: &str,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ApplicationError> {
+        let submission_id: SubmissionId = parse_id(id, "submission")?;
+        self.submissions.cancel(submission_id).await.map_err(map_infra_error)
+    }
+
+    async fn get_leaderboard(
+        &self,
+        benchmark_id: &str,
+        version_id: Option<&str>,
+        limit: u32,
+        min_verification: Option<VerificationLevel>,
+        viewer: &LeaderboardViewer,
+    ) -> Result<Vec<LeaderboardEntryDto>, ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(benchmark_id, "benchmark")?;
+        let version_id = version_id
+            .map(|id| parse_id::<BenchmarkVersionId>(id, "benchmark version"))
+            .transpose()?;
+        // An ID that doesn't parse can't own a stored submission
+        let viewer_id = viewer.user_id.as_deref().and_then(|id| id.parse().ok());
+        let organization_ids: Vec<OrganizationId> = viewer
+            .organization_ids
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect();
+
+        let entries = self
+            .submissions
+            .get_leaderboard(
+                benchmark_id,
+                version_id,
+                limit as usize,
+                viewer_id,
+                &organization_ids,
+                min_verification.unwrap_or_default(),
+            )
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| LeaderboardEntryDto {
+                rank: entry.rank,
+                submission_id: entry.submission_id.to_string(),
+                model_provider: entry.model_info.provider,
+                model_name: entry.model_info.model_name,
+                model_version: entry.model_info.model_version,
+                aggregate_score: entry.aggregate_score,
+                verification_level: entry.verification_level,
+                submitter_name: entry.submitter_name.unwrap_or_default(),
+                submitted_at: entry.submitted_at,
+            })
+            .collect())
+    }
+
+    async fn get_user_submissions(
+        &self,
+        user_id: &str,
+        pagination: &Pagination,
+    ) -> Result<(Vec<SubmissionDto>, u64), ApplicationError> {
+        let user_id: UserId = parse_id(user_id, "user")?;
+        let page = self
+            .submissions
+            .get_by_user(user_id, pagination.clone().into())
+            .await
+            .map_err(map_infra_error)?;
+        Ok((page.items.into_iter().map(submission_to_dto).collect(), page.total))
+    }
+
+    async fn get_results(&self, id: &str) -> Result<Option<SubmissionResults>, ApplicationError> {
+        let Ok(submission_id) = id.parse::<SubmissionId>() else {
+            return Ok(None);
+        };
+        Ok(self
+            .submissions
+            .get_by_id(submission_id)
+            .await
+            .map_err(map_infra_error)?
+            .map(Submission::into_results))
+    }
+
+    async fn save_results(&self, id: &str, results: &SubmissionResults) -> Result<(), ApplicationError> {
+        let submission_id: SubmissionId = parse_id(id, "submission")?;
+        let mut submission = self
+            .submissions
+            .get_by_id(submission_id)
+            .await
+            .map_err(map_infra_error)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Submission not found: {}", id)))?;
+
+        let score_range = self.score_range(&submission.benchmark_id.to_string()).await?;
+        submission.set_results(results.clone(), score_range.as_ref())?;
+
+        self.submissions
+            .update_results(submission_id, submission.results())
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn score_range(&self, benchmark_id: &str) -> Result<Option<MetricRange>, ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(benchmark_id, "benchmark")?;
+        Ok(self
+            .benchmarks
+            .get_by_id(benchmark_id)
+            .await
+            .map_err(map_infra_error)?
+            .and_then(|record| record.evaluation_criteria.primary_metric.range))
+    }
+
+    async fn score_precision(
+        &self,
+        benchmark_id: &str,
+    ) -> Result<Option<ScorePrecision>, ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(benchmark_id, "benchmark")?;
+        Ok(self
+            .benchmarks
+            .get_by_id(benchmark_id)
+            .await
+            .map_err(map_infra_error)?
+            .and_then(|record| record.evaluation_criteria.score_precision))
+    }
+
+    async fn leaderboard_min_verification(
+        &self,
+        benchmark_id: &str,
+    ) -> Result<VerificationLevel, ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(benchmark_id, "benchmark")?;
+        Ok(self
+            .benchmarks
+            .get_by_id(benchmark_id)
+            .await
+            .map_err(map_infra_error)?
+            .map(|record| record.evaluation_criteria.leaderboard_min_verification)
+            .unwrap_or_default())
+    }
+
+    async fn find_duplicate(
+        &self,
+        data: &CreateSubmissionData,
+        content_hash: &str,
+    ) -> Result<Option<String>, ApplicationError> {
+        let benchmark_id: BenchmarkId = parse_id(&data.benchmark_id, "benchmark")?;
+        Ok(self
+            .submissions
+            .find_by_content_hash(
+                benchmark_id,
+                &data.model_provider,
+                &data.model_name,
+                data.model_version.as_deref(),
+                content_hash,
+            )
+            .await
+            .map_err(map_infra_error)?
+            .map(|id| id.to_string()))
+    }
+
+    async fn trending_benchmarks(
+        &self,
+        window: Duration,
+        limit: usize,
+    ) -> Result<Vec<(String, f64)>, ApplicationError> {
+        Ok(self
+            .submissions
+            .trending_benchmarks(window, limit)
+            .await
+            .map_err(map_infra_error)?
+            .into_iter()
+            .map(|(id, score)| (id.to_string(), score))
+            .collect())
+    }
+}
+
+// ============================================================================
+// USERS
+// ============================================================================
+
+/// User repository port backed by PostgreSQL.
+///
+/// Display name and avatar changes are not persisted by the PostgreSQL
+/// schema. Personal API keys share the `api_keys` table with organization
+/// keys; organization keys never verify here.
+pub struct PgUserStore<H: PasswordHasher> {
+    users: PgUserRepository,
+    api_keys: PgApiKeyRepository,
+    proposals: PgProposalRepository,
+    benchmarks: PgBenchmarkRepository,
+    submissions: PgSubmissionRepository,
+    password_hasher: Arc<H>,
+}
+
+impl<H: PasswordHasher> PgUserStore<H> {
+    /// Create a store over `db`, verifying passwords with `password_hasher`
+    pub fn new(db: &DatabasePool, password_hasher: Arc<H>) -> Self {
+        Self {
+            users: PgUserRepository::new(db.pool().clone()),
+            api_keys: PgApiKeyRepository::new(db.pool().clone()),
+            proposals: PgProposalRepository::new(db.pool().clone()),
+            benchmarks: PgBenchmarkRepository::new(db.pool().clone()),
+            submissions: PgSubmissionRepository::new(db.pool().clone()),
+            password_hasher,
+        }
+    }
+}
+
+fn user_to_dto(user: User) -> UserDto {
+    UserDto {
+        id: user.id.to_string(),
+        display_name: user.display_name.unwrap_or_else(|| user.username.clone()),
+        email: user.email,
+        username: user.username,
+        bio: user.profile.bio,
+        website: user.profile.website.map(|url| url.to_string()),
+        avatar_url: None,
+        is_verified: user.email_verified,
+        is_admin: user.role == UserRole::Admin,
+        created_at: user.created_at,
+        updated_at: user.last_active_at.unwrap_or(user.created_at),
+    }
+}
+
+#[async_trait]
+impl<H: PasswordHasher + 'static> UserRepositoryPort for PgUserStore<H> {
+    async fn create(&self, data: &CreateUserData) -> Result<String, ApplicationError> {
+        let user = User {
+            id: UserId::new(),
+            email: data.email.clone(),
+            username: data.username.clone(),
+            display_name: Some(data.display_name.clone()),
+            role: UserRole::Registered,
+            organizations: vec![],
+            created_at: chrono::Utc::now(),
+            last_active_at: None,
+            email_verified: false,
+            profile: UserProfile {
+                bio: None,
+                affiliation: None,
+                website: None,
+                github_username: None,
+                orcid: None,
+                public_email: None,
+            },
+        };
+
+        let password_hash = data.password_hash.as_deref().unwrap_or_default();
+        let id = self
+            .users
+            .create(&user, password_hash)
+            .await
+            .map_err(map_infra_error)?;
+        Ok(id.to_string())
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<UserDto>, ApplicationError> {
+        let Ok(user_id) = id.parse::<UserId>() else {
+            return Ok(None);
+        };
+        Ok(self
+            .users
+            .get_by_id(user_id)
+            .await
+            .map_err(map_infra_error)?
+            .map(user_to_dto))
+    }
+
+    async fn get_by_email(&self, email: &str) -> Result<Option<UserDto>, ApplicationError> {
+        Ok(self
+            .users
+            .get_by_email(email)
+            .await
+            .map_err(map_infra_error)?
+            .map(user_to_dto))
+    }
+
+    async fn get_by_username(&self, username: &str) -> Result<Option<UserDto>, ApplicationError> {
+        Ok(self
+            .users
+            .get_by_username(username)
+            .await
+            .map_err(map_infra_error)?
+            .map(user_to_dto))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateUserData) -> Result<(), ApplicationError> {
+        let user_id: UserId = parse_id(id, "user")?;
+        let user = self
+            .users
+            .get_by_id(user_id)
+            .await
+            .map_err(map_infra_error)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("User not found: {}", id)))?;
+
+        let mut profile = user.profile;
+        if let Some(ref bio) = update.bio {
+            profile.bio = Some(bio.clone());
+        }
+        if let Some(ref website) = update.website {
+            profile.website = Some(website.parse().map_err(|_| {
+                ApplicationError::InvalidInput(format!("Invalid website URL: {}", website))
+            })?);
+        }
+
+        self.users
+            .update_profile(user_id, &profile)
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn update_password(&self, id: &str, password_hash: &str) -> Result<(), ApplicationError> {
+        let user_id: UserId = parse_id(id, "user")?;
+        self.users
+            .update_password(user_id, password_hash)
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn verify_password(&self, id: &str, password: &str) -> Result<bool, ApplicationError> {
+        let Ok(user_id) = id.parse::<UserId>() else {
+            return Ok(false);
+        };
+        let Some(user) = self.users.get_by_id(user_id).await.map_err(map_infra_error)? else {
+            return Ok(false);
+        };
+        let Some(credentials) = self
+            .users
+            .get_credentials(&user.email)
+            .await
+            .map_err(map_infra_error)?
+        else {
+            return Ok(false);
+        };
+
+        if credentials.password_hash.is_empty() {
+            return Ok(false);
+        }
+        self.password_hasher
+            .verify(password, &credentials.password_hash)
+            .await
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        let user_id: UserId = parse_id(id, "user")?;
+        if !self.users.delete(user_id).await.map_err(map_infra_error)? {
+            return Err(ApplicationError::NotFound(format!("User not found: {}", id)));
+        }
+        Ok(())
+    }
+
+    async fn get_profile(&self, id: &str) -> Result<Option<UserProfileDto>, ApplicationError> {
+        let Ok(user_id) = id.parse::<UserId>() else {
+            return Ok(None);
+        };
+        let Some(user) = self.users.get_by_id(user_id).await.map_err(map_infra_error)? else {
+            return Ok(None);
+        };
+
+        let submission_count = self
+            .submissions
+            .count(None, Some(user_id))
+            .await
+            .map_err(map_infra_error)?;
+        let benchmark_count = self
+            .benchmarks
+            .list(BenchmarkQuery {
+                created_by: Some(user_id),
+                pagination: PaginationParams { page: 1, per_page: 1 },
+                ..Default::default()
+            })
+            .await
+            .map_err(map_infra_error)?
+            .total;
+
+        Ok(Some(UserProfileDto {
+            id: user.id.to_string(),
+            display_name: user.display_name.unwrap_or_else(|| user.username.clone()),
+            username: user.username,
+            bio: user.profile.bio,
+            website: user.profile.website.map(|url| url.to_string()),
+            avatar_url: None,
+            submission_count,
+            benchmark_count,
+            joined_at: user.created_at,
+        }))
+    }
+
+    async fn email_exists(&self, email: &str) -> Result<bool, ApplicationError> {
+        self.users.email_exists(email).await.map_err(map_infra_error)
+    }
+
+    async fn username_exists(&self, username: &str) -> Result<bool, ApplicationError> {
+        self.users.username_exists(username).await.map_err(map_infra_error)
+    }
+
+    async fn create_api_key(
+        &self,
+        user_id: &str,
+        key: &CreateApiKeyData,
+    ) -> Result<ApiKeyWithSecretDto, ApplicationError> {
+        let owner = ApiKeyOwner::User(parse_id(user_id, "user")?);
+        create_api_key(&self.api_keys, owner, "llm_bm", key).await
+    }
+
+    async fn list_api_keys(
+        &self,
+        user_id: &str,
+        filters: &ApiKeyFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<ApiKeyDto>, u64), ApplicationError> {
+        let owner = ApiKeyOwner::User(parse_id(user_id, "user")?);
+        let result = self
+            .api_keys
+            .list(
+                owner,
+                ApiKeyQuery {
+                    include_expired: filters.include_expired,
+                    include_revoked: filters.include_revoked,
+                    pagination: pagination.clone().into(),
+                },
+            )
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok((result.items.into_iter().map(api_key_to_dto).collect(), result.total))
+    }
+
+    async fn revoke_api_key(&self, user_id: &str, key_id: &str) -> Result<(), ApplicationError> {
+        let owner = ApiKeyOwner::User(parse_id(user_id, "user")?);
+        if !self
+            .api_keys
+            .revoke(owner, parse_id(key_id, "API key")?)
+            .await
+            .map_err(map_infra_error)?
+        {
+            return Err(ApplicationError::NotFound(format!("API key not found: {}", key_id)));
+        }
+        Ok(())
+    }
+
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError> {
+        let key = self
+            .api_keys
+            .find_by_secret(key_secret)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(key.and_then(|key| match key.owner {
+            ApiKeyOwner::User(user_id) => Some((user_id.to_string(), api_key_to_dto(key))),
+            ApiKeyOwner::Organization(_) => None,
+        }))
+    }
+
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError> {
+        self.api_keys
+            .touch(parse_id(key_id, "API key")?, used_at)
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn get_governance_activity(&self, user_id: &str) -> Result<Vec<GovernanceActivityDto>, ApplicationError> {
+        let activity = self
+            .proposals
+            .user_activity(parse_id(user_id, "user")?)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(activity
+            .into_iter()
+            .map(|activity| GovernanceActivityDto {
+                kind: match activity.kind {
+                    InfraActivityKind::ProposalCreated => GovernanceActivityKind::ProposalCreated,
+                    InfraActivityKind::VoteCast => GovernanceActivityKind::VoteCast,
+                    InfraActivityKind::CommentAdded => GovernanceActivityKind::CommentAdded,
+                },
+                proposal_id: activity.proposal_id.to_string(),
+                detail: activity.detail,
+                at: activity.at,
+            })
+            .collect())
+    }
+
+    async fn delete_account(&self, id: &str, mode: DeletionMode) -> Result<(), ApplicationError> {
+        let deletion = match mode {
+            DeletionMode::HardDelete => AccountDeletion::RemoveContent,
+            DeletionMode::Anonymize => AccountDeletion::ReassignContent {
+                tombstone: parse_id(TOMBSTONE_USER_ID, "user")?,
+            },
+        };
+
+        match self
+            .users
+            .delete_account(parse_id(id, "user")?, deletion)
+            .await
+            .map_err(map_infra_error)?
+        {
+            AccountDeletionOutcome::Deleted => Ok(()),
+            AccountDeletionOutcome::NotFound => {
+                Err(ApplicationError::NotFound(format!("User not found: {}", id)))
+            }
+            AccountDeletionOutcome::LastAdmin => {
+                Err(ApplicationError::Forbidden("Cannot delete the last admin".to_string()))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// ORGANIZATIONS
+// ============================================================================
+
+/// Page size used when reading every row of a paginated repository query
+const SCAN_PAGE_SIZE: u32 = 100;
+
+/// Organization repository port backed by PostgreSQL.
+///
+/// Memberships are stored through the user repository and organization API
+/// keys in the `api_keys` table. Contact emails are not persisted, and the
+/// `Viewer` role has no PostgreSQL equivalent.
+pub struct PgOrganizationStore {
+    organizations: PgOrganizationRepository,
+    users: PgUserRepository,
+    api_keys: PgApiKeyRepository,
+}
+
+impl PgOrganizationStore {
+    /// Create a store over `db`
+    pub fn new(db: &DatabasePool) -> Self {
+        Self {
+            organizations: PgOrganizationRepository::new(db.pool().clone()),
+            users: PgUserRepository::new(db.pool().clone()),
+            api_keys: PgApiKeyRepository::new(db.pool().clone()),
+        }
+    }
+
+    async fn to_dto(&self, organization: Organization) -> Result<OrganizationDto, ApplicationError> {
+        let member_count = self
+            .organizations
+            .get_member_count(organization.id)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(OrganizationDto {
+            id: organization.id.to_string(),
+            name: organization.name,
+            slug: organization.slug,
+            description: organization.description,
+            website: organization.website.map(|url| url.to_string()),
+            contact_email: None,
+            logo_url: organization.logo_url.map(|url| url.to_string()),
+            member_count,
+            is_verified: organization.verified,
+            created_at: organization.created_at,
+            updated_at: organization.verification_date.unwrap_or(organization.created_at),
+        })
+    }
+
+    async fn get_organization(&self, id: &str) -> Result<Organization, ApplicationError> {
+        let org_id: OrganizationId = parse_id(id, "organization")?;
+        self.organizations
+            .get_by_id(org_id)
+            .await
+            .map_err(map_infra_error)?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Organization not found: {}", id)))
+    }
+}
+
+fn parse_url<T: FromStr>(value: &str, field: &str) -> Result<T, ApplicationError> {
+    value
+        .parse()
+        .map_err(|_| ApplicationError::InvalidInput(format!("Invalid {} URL: {}", field, value)))
+}
+
+fn member_role_to_dto(role: MemberRole) -> OrganizationRole {
+    match role {
+        MemberRole::Owner => OrganizationRole::Owner,
+        MemberRole::Admin => OrganizationRole::Admin,
+        MemberRole::Member => OrganizationRole::Member,
+    }
+}
+
+fn member_role_from_dto(role: OrganizationRole) -> Result<MemberRole, ApplicationError> {
+    match role {
+        OrganizationRole::Owner => Ok(MemberRole::Owner),
+        OrganizationRole::Admin => Ok(MemberRole::Admin),
+        OrganizationRole::Member => Ok(MemberRole::Member),
+        OrganizationRole::Viewer => Err(ApplicationError::InvalidInput(
+            "The viewer role is not supported by the PostgreSQL organization store".to_string(),
+        )),
+    }
+}
+
+fn api_key_to_dto(key: ApiKeyRecord) -> ApiKeyDto {
+    ApiKeyDto {
+        id: key.id.to_string(),
+        name: key.name,
+        description: key.description,
+        scopes: key.scopes,
+        last_used_at: key.last_used_at,
+        expires_at: key.expires_at,
+        revoked_at: key.revoked_at,
+        created_at: key.created_at,
+    }
+}
+
+/// Store a new API key for `owner`, returning it with its one-time secret
+async fn create_api_key(
+    api_keys: &PgApiKeyRepository,
+    owner: ApiKeyOwner,
+    prefix: &str,
+    data: &CreateApiKeyData,
+) -> Result<ApiKeyWithSecretDto, ApplicationError> {
+    let now = chrono::Utc::now();
+    let record = ApiKeyRecord {
+        id: uuid::Uuid::new_v4(),
+        owner,
+        name: data.name.clone(),
+        description: data.description.clone(),
+        scopes: data.scopes.clone(),
+        last_used_at: None,
+        expires_at: data
+            .expires_in_days
+            .map(|days| now + chrono::Duration::days(i64::from(days))),
+        revoked_at: None,
+        created_at: now,
+    };
+    let secret = format!("{}_{}", prefix, generate_token(32));
+
+    api_keys.create(&record, &secret).await.map_err(map_infra_error)?;
+
+    Ok(ApiKeyWithSecretDto {
+        key: api_key_to_dto(record),
+        secret,
+    })
+}
+
+#[async_trait]
+impl OrganizationRepositoryPort for PgOrganizationStore {
+    async fn create(&self, org: &CreateOrganizationData) -> Result<String, ApplicationError> {
+        let owner_id: UserId = parse_id(&org.owner_id, "user")?;
+        let organization = Organization {
+            id: OrganizationId::new(),
+            name: org.name.clone(),
+            slug: org.slug.clone(),
+            description: org.description.clone(),
+            website: org.website.as_deref().map(|url| parse_url(url, "website")).transpose()?,
+            logo_url: None,
+            organization_type: OrganizationType::Enterprise,
+            verified: false,
+            verification_date: None,
+            created_at: chrono::Utc::now(),
+        };
+
+        let id = self
+            .organizations
+            .create(&organization, owner_id)
+            .await
+            .map_err(map_infra_error)?;
+        Ok(id.to_string())
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<OrganizationDto>, ApplicationError> {
+        let Ok(org_id) = id.parse::<OrganizationId>() else {
+            return Ok(None);
+        };
+        match self.organizations.get_by_id(org_id).await.map_err(map_infra_error)? {
+            Some(organization) => Ok(Some(self.to_dto(organization).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<OrganizationDto>, ApplicationError> {
+        match self.organizations.get_by_slug(slug).await.map_err(map_infra_error)? {
+            Some(organization) => Ok(Some(self.to_dto(organization).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list(
+        &self,
+        pagination: &Pagination,
+    ) -> Result<(Vec<OrganizationDto>, u64), ApplicationError> {
+        let page = self
+            .organizations
+            .list(OrganizationQuery {
+                pagination: pagination.clone().into(),
+                ..Default::default()
+            })
+            .await
+            .map_err(map_infra_error)?;
+
+        let mut items = Vec::with_capacity(page.items.len());
+        for organization in page.items {
+            items.push(self.to_dto(organization).await?);
+        }
+        Ok((items, page.total))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateOrganizationData) -> Result<(), ApplicationError> {
+        let mut organization = self.get_organization(id).await?;
+
+        if let Some(ref name) = update.name {
+            organization.name = name.clone();
+        }
+        if let Some(ref description) = update.description {
+            organization.description = Some(description.clone());
+        }
+        if let Some(ref website) = update.website {
+            organization.website = Some(parse_url(website, "website")?);
+        }
+        if let Some(ref logo_url) = update.logo_url {
+            organization.logo_url = Some(parse_url(logo_url, "logo")?);
+        }
+
+        self.organizations
+            .update(&organization)
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        let org_id: OrganizationId = parse_id(id, "organization")?;
+        if !self.organizations.delete(org_id).await.map_err(map_infra_error)? {
+            return Err(ApplicationError::NotFound(format!("Organization not found: {}", id)));
+        }
+        Ok(())
+    }
+
+    async fn slug_exists(&self, slug: &str) -> Result<bool, ApplicationError> {
+        self.organizations.slug_exists(slug).await.map_err(map_infra_error)
+    }
+
+    async fn add_member(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrganizationRole,
+    ) -> Result<(), ApplicationError> {
+        self.users
+            .add_to_organization(
+                parse_id(user_id, "user")?,
+                parse_id(org_id, "organization")?,
+                member_role_from_dto(role)?,
+            )
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn update_member_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrganizationRole,
+    ) -> Result<(), ApplicationError> {
+        self.users
+            .update_organization_role(
+                parse_id(user_id, "user")?,
+                parse_id(org_id, "organization")?,
+                member_role_from_dto(role)?,
+            )
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn remove_member(&self, org_id: &str, user_id: &str) -> Result<(), ApplicationError> {
+        let removed = self
+            .users
+            .remove_from_organization(parse_id(user_id, "user")?, parse_id(org_id, "organization")?)
+            .await
+            .map_err(map_infra_error)?;
+        if !removed {
+            return Err(ApplicationError::NotFound(format!(
+                "User {} is not a member of organization {}",
+                user_id, org_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn get_members(&self, org_id: &str) -> Result<Vec<OrganizationMemberDto>, ApplicationError> {
+        let members = self
+            .organizations
+            .get_members(parse_id(org_id, "organization")?)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(members
+            .into_iter()
+            .map(|member| OrganizationMemberDto {
+                user_id: member.user_id.to_string(),
+                display_name: member.display_name.unwrap_or_else(|| member.username.clone()),
+                username: member.username,
+                role: member_role_to_dto(member.role),
+                joined_at: member.joined_at,
+            })
+            .collect())
+    }
+
+    async fn get_member_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+    ) -> Result<Option<OrganizationRole>, ApplicationError> {
+        let (Ok(org_id), Ok(user_id)) = (org_id.parse::<OrganizationId>(), user_id.parse::<UserId>()) else {
+            return Ok(None);
+        };
+        let memberships = self.users.get_organizations(user_id).await.map_err(map_infra_error)?;
+
+        Ok(memberships
+            .into_iter()
+            .find(|membership| membership.organization_id == org_id)
+            .map(|membership| member_role_to_dto(membership.role)))
+    }
+
+    async fn get_user_organizations(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<(OrganizationDto, OrganizationRole)>, ApplicationError> {
+        let Ok(user_id) = user_id.parse::<UserId>() else {
+            return Ok(vec![]);
+        };
+        let memberships = self.users.get_organizations(user_id).await.map_err(map_infra_error)?;
+
+        let mut organizations = Vec::with_capacity(memberships.len());
+        for membership in memberships {
+            // Soft-deleted organizations keep their member rows
+            if let Some(organization) = self
+                .organizations
+                .get_by_id(membership.organization_id)
+                .await
+                .map_err(map_infra_error)?
+            {
+                organizations.push((self.to_dto(organization).await?, member_role_to_dto(membership.role)));
+            }
+        }
+        Ok(organizations)
+    }
+
+    async fn create_api_key(&self, org_id: &str, key: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError> {
+        let owner = ApiKeyOwner::Organization(parse_id(org_id, "organization")?);
+        create_api_key(&self.api_keys, owner, "llm_bm_org", key).await
+    }
+
+    async fn list_api_keys(&self, org_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError> {
+        let owner = ApiKeyOwner::Organization(parse_id(org_id, "organization")?);
+
+        let mut keys = Vec::new();
+        let mut page = 1;
+        loop {
+            let result = self
+                .api_keys
+                .list(
+                    owner,
+                    ApiKeyQuery {
+                        include_expired: true,
+                        include_revoked: true,
+                        pagination: PaginationParams { page, per_page: SCAN_PAGE_SIZE },
+                    },
+                )
+                .await
+                .map_err(map_infra_error)?;
+            let fetched = result.items.len();
+            keys.extend(result.items.into_iter().map(api_key_to_dto));
+            if fetched == 0 || keys.len() as u64 >= result.total {
+                break;
+            }
+            page += 1;
+        }
+        Ok(keys)
+    }
+
+    async fn revoke_api_key(&self, org_id: &str, key_id: &str) -> Result<(), ApplicationError> {
+        let owner = ApiKeyOwner::Organization(parse_id(org_id, "organization")?);
+        if !self
+            .api_keys
+            .revoke(owner, parse_id(key_id, "API key")?)
+            .await
+            .map_err(map_infra_error)?
+        {
+            return Err(ApplicationError::NotFound(format!("API key not found: {}", key_id)));
+        }
+        Ok(())
+    }
+
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError> {
+        let key = self
+            .api_keys
+            .find_by_secret(key_secret)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(key.and_then(|key| match key.owner {
+            ApiKeyOwner::Organization(org_id) => Some((org_id.to_string(), api_key_to_dto(key))),
+            ApiKeyOwner::User(_) => None,
+        }))
+    }
+
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError> {
+        self.api_keys
+            .touch(parse_id(key_id, "API key")?, used_at)
+            .await
+            .map_err(map_infra_error)
+    }
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+/// Source name attached to events published by the REST API
+const EVENT_SOURCE: &str = "api-rest";
+
+/// Event publisher that forwards service events to a message broker.
+///
+/// Each event is published on a dotted channel such as `benchmark.created`
+/// wrapped in an [`EventMessage`].
+pub struct MessagingEventPublisher<P: Publisher> {
+    publisher: Arc<P>,
+}
+
+impl<P: Publisher> MessagingEventPublisher<P> {
+    /// Create a publisher forwarding to `publisher`
+    pub fn new(publisher: Arc<P>) -> Self {
+        Self { publisher }
+    }
+}
+
+#[async_trait]
+impl<P: Publisher + 'static> EventPublisher for MessagingEventPublisher<P> {
+    async fn publish(&self, event: ServiceEvent) -> Result<(), ApplicationError> {
+        let (channel, payload) = event.to_message();
+        let message = EventMessage::new(channel.as_str(), payload, EVENT_SOURCE);
+
+        let published = if event.is_retained() {
+            self.publisher.publish_retained(&channel, &message).await
+        } else {
+            self.publisher.publish(&channel, &message).await
+        };
+        published.map_err(|e| ApplicationError::ServiceUnavailable(e.to_string()))
+    }
+}
+
+/// Verification progress read from a message broker.
+///
+/// Subscribes to the per-submission channel that
+/// [`ServiceEvent::SubmissionVerificationProgress`] events are published on,
+/// and reads the channel's retained message for the current stage.
+pub struct MessagingVerificationProgress<S: Subscriber> {
+    subscriber: Arc<S>,
+}
+
+impl<S: Subscriber> MessagingVerificationProgress<S> {
+    /// Create a progress source reading from `subscriber`
+    pub fn new(subscriber: Arc<S>) -> Self {
+        Self { subscriber }
+    }
+}
+
+#[async_trait]
+impl<S: Subscriber + 'static> VerificationProgressSource for MessagingVerificationProgress<S> {
+    async fn subscribe(
+        &self,
+        submission_id: &str,
+    ) -> Result<BoxStream<'static, VerificationProgress>, ApplicationError> {
+        let messages = self
+            .subscriber
+            .subscribe::<VerificationProgress>(&VerificationProgress::channel(submission_id))
+            .await
+            .map_err(|e| ApplicationError::ServiceUnavailable(e.to_string()))?;
+
+        Ok(stream::unfold(messages, |mut rx| async move {
+            rx.recv().await.map(|message| (message.payload, rx))
+        })
+        .boxed())
+    }
+
+    async fn latest(
+        &self,
+        submission_id: &str,
+    ) -> Result<Option<VerificationProgress>, ApplicationError> {
+        let message = self
+            .subscriber
+            .retained::<VerificationProgress>(&VerificationProgress::channel(submission_id))
+            .await
+            .map_err(|e| ApplicationError::ServiceUnavailable(e.to_string()))?;
+
+        Ok(message.map(|message| message.payload))
+    }
+}
+
+/// Per-test-case execution telemetry read from LLM-Observatory.
+///
+/// The platform's execution path tags each telemetry record with
+/// `submission_id` and `test_case_id` dimensions. Records are queried by
+/// benchmark and model, then matched on those dimensions.
+pub struct ObservatoryTelemetry<O: ObservatoryConsumerTrait> {
+    consumer: Arc<O>,
+}
+
+impl<O: ObservatoryConsumerTrait> ObservatoryTelemetry<O> {
+    /// Create a telemetry source reading from `consumer`
+    pub fn new(consumer: Arc<O>) -> Self {
+        Self { consumer }
+    }
+}
+
+#[async_trait]
+impl<O: ObservatoryConsumerTrait + 'static> ExecutionTelemetryPort for ObservatoryTelemetry<O> {
+    async fn test_case_telemetry(
+        &self,
+        submission: &SubmissionDto,
+    ) -> Result<Vec<TestCaseTelemetry>, ApplicationError> {
+        let query = TelemetryQuery {
+            benchmark_id: Some(submission.benchmark_id.clone()),
+            model_id: Some(submission.model_name.clone()),
+            start_time: Some(submission.created_at - chrono::Duration::hours(24)),
+            end_time: Some(submission.created_at),
+            ..TelemetryQuery::default()
+        };
+
+        match self.consumer.query_execution_telemetry(query).await {
+            Ok(records) => Ok(test_case_telemetry(&submission.id, &records)),
+            Err(ExternalConsumerError::NotFound(_)) => Ok(Vec::new()),
+            Err(e) => Err(ApplicationError::ServiceUnavailable(e.to_string())),
+        }
+    }
+}
+
+/// Telemetry records belonging to `submission_id`, one per test case
+fn test_case_telemetry(
+    submission_id: &str,
+    records: &[ExecutionTelemetry],
+) -> Vec<TestCaseTelemetry> {
+    records
+        .iter()
+        .filter(|r| {
+            r.dimensions.get("submission_id").map(String::as_str) == Some(submission_id)
+        })
+        .filter_map(|r| {
+            Some(TestCaseTelemetry {
+                test_case_id: r.dimensions.get("test_case_id")?.clone(),
+                latency_ms: Some(r.duration_ms),
+                tokens_generated: r
+                    .resource_usage
+                    .output_tokens
+                    .and_then(|t| u32::try_from(t).ok()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_infra_error() {
+        assert!(matches!(
+            map_infra_error(InfraError::NotFound("x".to_string())),
+            ApplicationError::NotFound(_)
+        ));
+        assert!(matches!(
+            map_infra_error(InfraError::Connection("x".to_string())),
+            ApplicationError::ServiceUnavailable(_)
+        ));
+        assert!(matches!(
+            map_infra_error(InfraError::Conflict("x".to_string())),
+            ApplicationError::Conflict(_)
+        ));
+        assert!(matches!(
+            map_infra_error(InfraError::Messaging("x".to_string())),
+            ApplicationError::Internal(_)
+        ));
+    }
+
+    #[test]
+    fn test_observatory_records_matched_by_submission() {
+        use llm_benchmark_infrastructure::external_consumers::observatory::{
+            ExecutionStatus, RequestMetrics, ResourceUsage,
+        };
+
+        let record = |submission: &str, test_case: Option<&str>, duration_ms: u64| {
+            let mut dimensions =
+                HashMap::from([("submission_id".to_string(), submission.to_string())]);
+            if let Some(test_case) = test_case {
+                dimensions.insert("test_case_id".to_string(), test_case.to_string());
+            }
+            ExecutionTelemetry {
+                telemetry_id: uuid::Uuid::new_v4().to_string(),
+                execution_id: "exec-1".to_string(),
+                benchmark_id: "b-1".to_string(),
+                model_id: "acme-1".to_string(),
+                timestamp: chrono::Utc::now(),
+                duration_ms,
+                status: ExecutionStatus::Success,
+                resource_usage: ResourceUsage {
+                    cpu_percent: None,
+                    memory_bytes: None,
+                    gpu_memory_bytes: None,
+                    network_sent_bytes: None,
+                    network_recv_bytes: None,
+                    input_tokens: Some(20),
+                    output_tokens: Some(64),
+                },
+                request_metrics: RequestMetrics {
+                    request_count: 1,
+                    success_count: 1,
+                    failure_count: 0,
+                    avg_latency_ms: duration_ms as f64,
+                    p50_latency_ms: duration_ms as f64,
+                    p95_latency_ms: duration_ms as f64,
+                    p99_latency_ms: duration_ms as f64,
+                    requests_per_second: 1.0,
+                },
+                error: None,
+                dimensions,
+                metrics: HashMap::new(),
+            }
+        };
+
+        let telemetry = test_case_telemetry(
+            "s-1",
+            &[
+                record("s-1", Some("tc-1"), 120),
+                record("s-2", Some("tc-1"), 999),
+                record("s-1", None, 5),
+            ],
+        );
+
+        assert_eq!(
+            telemetry,
+            vec![TestCaseTelemetry {
+                test_case_id: "tc-1".to_string(),
+                latency_ms: Some(120),
+                tokens_generated: Some(64),
+            }]
+        );
+    }
+}
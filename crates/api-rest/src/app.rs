@@ -6,8 +6,9 @@
 use crate::{
     config::ApiConfig,
     middleware::{
-        execution_context_middleware, logging_middleware,
-        request_id::request_id_middleware, RateLimitLayer,
+        cors_layer, execution_context_middleware, logging_middleware, maintenance_middleware,
+        request_id::request_id_middleware, request_timeout_middleware, RateLimitLayer,
+        RequestTimeouts,
     },
     routes,
     state::AppState,
@@ -17,15 +18,17 @@ use axum::{
     routing::get,
     Router,
 };
-use std::time::Duration;
+use llm_benchmark_infrastructure::{
+    CacheConfig, DatabaseConfig, DatabasePool, MessagingConfig, RedisCache, RedisMessaging,
+};
+use std::sync::Arc;
 use tower::ServiceBuilder;
 use tower_http::{
     compression::CompressionLayer,
-    cors::{Any, CorsLayer},
-    timeout::TimeoutLayer,
     trace::TraceLayer,
 };
 use utoipa::OpenApi;
+use tracing::{info, warn};
 use utoipa_swagger_ui::SwaggerUi;
 
 /// Create the main application router
@@ -34,10 +37,10 @@ pub async fn create_app(config: ApiConfig) -> anyhow::Result<Router> {
     init_tracing(&config)?;
 
     // Create application state
-    let state = AppState::new(config.clone());
+    let state = build_state(&config).await?;
 
     // Build CORS layer
-    let cors = build_cors_layer(&config);
+    let cors = cors_layer(&config)?;
 
     // Build rate limiting layer
     let rate_limit = RateLimitLayer::new();
@@ -47,7 +50,12 @@ pub async fn create_app(config: ApiConfig) -> anyhow::Result<Router> {
         // Health check routes (no auth required)
         .merge(routes::health::routes())
         // API v1 routes
-        .nest("/api/v1", routes::v1::routes())
+        .nest("/api/v1", routes::v1::routes(&config))
+        // Maintenance mode (health probes stay available)
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            maintenance_middleware,
+        ))
         // Add state
         .with_state(state);
 
@@ -66,10 +74,11 @@ pub async fn create_app(config: ApiConfig) -> anyhow::Result<Router> {
                 .layer(CompressionLayer::new())
                 // CORS
                 .layer(cors)
-                // Timeout
-                .layer(TimeoutLayer::new(Duration::from_secs(
-                    config.request_timeout_seconds,
-                )))
+                // Timeout (health probes are exempt)
+                .layer(middleware::from_fn_with_state(
+                    RequestTimeouts::from_config(&config),
+                    request_timeout_middleware,
+                ))
                 // Rate limiting
                 .layer(rate_limit)
                 // Custom middleware
@@ -81,6 +90,33 @@ pub async fn create_app(config: ApiConfig) -> anyhow::Result<Router> {
     Ok(app)
 }
 
+/// Build application state on PostgreSQL and Redis when a database URL is
+/// configured, or on in-memory repositories for local development
+async fn build_state(config: &ApiConfig) -> anyhow::Result<AppState> {
+    let Some(database_url) = config.database_url.clone() else {
+        warn!("DATABASE_URL not set, using in-memory repositories");
+        return Ok(AppState::new(config.clone()));
+    };
+
+    let db = DatabasePool::new(&DatabaseConfig {
+        url: database_url,
+        max_connections: config.db_pool_size,
+        min_connections: config.db_pool_size.min(DatabaseConfig::default().min_connections),
+        ..DatabaseConfig::default()
+    })
+    .await?;
+    let cache = RedisCache::new(CacheConfig::from_env()?).await?;
+    let messaging = RedisMessaging::new(MessagingConfig::from_env()?).await?;
+
+    info!("Using PostgreSQL repositories with Redis cache and messaging");
+    Ok(AppState::from_infrastructure(
+        config.clone(),
+        &db,
+        Arc::new(cache),
+        Arc::new(messaging),
+    ))
+}
+
 /// Initialize tracing/logging
 fn init_tracing(config: &ApiConfig) -> anyhow::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -96,22 +132,6 @@ fn init_tracing(config: &ApiConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Build CORS layer from configuration
-fn build_cors_layer(config: &ApiConfig) -> CorsLayer {
-    let cors = CorsLayer::new();
-
-    if config.cors_allowed_origins.contains(&"*".to_string()) {
-        cors.allow_origin(Any)
-            .allow_methods(Any)
-            .allow_headers(Any)
-    } else {
-        // In production, parse and validate allowed origins
-        cors.allow_origin(Any)
-            .allow_methods(Any)
-            .allow_headers(Any)
-    }
-}
-
 /// Create Swagger UI routes if enabled
 fn swagger_ui(config: &ApiConfig) -> SwaggerUi {
     #[derive(OpenApi)]
@@ -138,3 +158,24 @@ fn swagger_ui(config: &ApiConfig) -> SwaggerUi {
 
     SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_build_state_without_database_uses_memory() {
+        assert!(build_state(&ApiConfig::default()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_build_state_with_invalid_database_fails() {
+        let config = ApiConfig {
+            database_url: Some("not a database url".to_string()),
+            ..ApiConfig::default()
+        };
+
+        // A configured database must never silently fall back to memory
+        assert!(build_state(&config).await.is_err());
+    }
+}
@@ -1,6 +1,9 @@
 //! API configuration.
 
+use llm_benchmark_domain::identifiers::IdStrategy;
+use llm_benchmark_domain::test_case::TestCase;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// API configuration
@@ -18,18 +21,43 @@ pub struct ApiConfig {
     /// JWT token expiration duration in seconds
     pub jwt_expiration_seconds: u64,
 
-    /// CORS allowed origins
+    /// CORS allowed origins (explicit allow-list; `*` is only honoured without credentials)
     pub cors_allowed_origins: Vec<String>,
 
-    /// Maximum request body size in bytes
+    /// CORS allowed methods
+    pub cors_allowed_methods: Vec<String>,
+
+    /// CORS allowed request headers
+    pub cors_allowed_headers: Vec<String>,
+
+    /// Whether CORS responses allow credentials (cookies, authorization headers)
+    pub cors_allow_credentials: bool,
+
+    /// How long browsers may cache preflight responses, in seconds
+    pub cors_max_age_seconds: u64,
+
+    /// Maximum request body size in bytes for routes without a dedicated group limit
     pub max_body_size: usize,
 
+    /// Maximum request body size in bytes for authentication and user routes
+    pub auth_max_body_size: usize,
+
+    /// Maximum request body size in bytes for import routes (benchmarks, submissions, publications)
+    pub import_max_body_size: usize,
+
     /// Request timeout in seconds
     pub request_timeout_seconds: u64,
 
+    /// Per-route request timeouts in seconds, keyed by path prefix (longest prefix wins)
+    pub request_timeout_overrides: HashMap<String, u64>,
+
     /// Rate limit: maximum requests per minute
     pub rate_limit_per_minute: u32,
 
+    /// PostgreSQL connection URL; without one the API runs on in-memory repositories
+    #[serde(default, skip_serializing)]
+    pub database_url: Option<String>,
+
     /// Database connection pool size
     pub db_pool_size: u32,
 
@@ -38,6 +66,26 @@ pub struct ApiConfig {
 
     /// Log level
     pub log_level: String,
+
+    /// Start the API in maintenance mode (can also be toggled at runtime)
+    pub maintenance_mode: bool,
+
+    /// `Retry-After` value in seconds returned while in maintenance mode
+    pub maintenance_retry_after_seconds: u64,
+
+    /// Let admin requests through while in maintenance mode
+    pub maintenance_allow_admins: bool,
+
+    /// Reject submissions whose results are identical to an earlier
+    /// submission of the same model (otherwise they are accepted and logged)
+    pub reject_duplicate_submissions: bool,
+
+    /// Maximum serialized size in bytes of a test case's input and of its
+    /// expected output
+    pub max_test_case_payload_bytes: usize,
+
+    /// How new entity IDs are generated
+    pub id_strategy: IdStrategy,
 }
 
 impl Default for ApiConfig {
@@ -47,13 +95,27 @@ impl Default for ApiConfig {
             port: 8080,
             jwt_secret: "change-me-in-production".to_string(),
             jwt_expiration_seconds: 24 * 60 * 60, // 24 hours
-            cors_allowed_origins: vec!["*".to_string()],
-            max_body_size: 10 * 1024 * 1024, // 10 MB
+            cors_allowed_origins: Vec::new(),
+            cors_allowed_methods: default_cors_methods(),
+            cors_allowed_headers: default_cors_headers(),
+            cors_allow_credentials: false,
+            cors_max_age_seconds: 3600,
+            max_body_size: 1024 * 1024, // 1 MB
+            auth_max_body_size: 16 * 1024, // 16 KB
+            import_max_body_size: 50 * 1024 * 1024, // 50 MB
             request_timeout_seconds: 30,
+            request_timeout_overrides: HashMap::new(),
             rate_limit_per_minute: 60,
+            database_url: None,
             db_pool_size: 10,
             enable_swagger: true,
             log_level: "info".to_string(),
+            maintenance_mode: false,
+            maintenance_retry_after_seconds: 300,
+            maintenance_allow_admins: true,
+            reject_duplicate_submissions: false,
+            max_test_case_payload_bytes: TestCase::DEFAULT_MAX_PAYLOAD_BYTES,
+            id_strategy: IdStrategy::default(),
         }
     }
 }
@@ -79,20 +141,49 @@ impl ApiConfig {
                 .unwrap_or(24 * 60 * 60),
             cors_allowed_origins: std::env::var("CORS_ALLOWED_ORIGINS")
                 .ok()
-                .map(|s| s.split(',').map(|s| s.trim().to_string()).collect())
-                .unwrap_or_else(|| vec!["*".to_string()]),
+                .map(|s| parse_list(&s))
+                .unwrap_or_default(),
+            cors_allowed_methods: std::env::var("CORS_ALLOWED_METHODS")
+                .ok()
+                .map(|s| parse_list(&s))
+                .unwrap_or_else(default_cors_methods),
+            cors_allowed_headers: std::env::var("CORS_ALLOWED_HEADERS")
+                .ok()
+                .map(|s| parse_list(&s))
+                .unwrap_or_else(default_cors_headers),
+            cors_allow_credentials: std::env::var("CORS_ALLOW_CREDENTIALS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(false),
+            cors_max_age_seconds: std::env::var("CORS_MAX_AGE_SECONDS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3600),
             max_body_size: std::env::var("MAX_BODY_SIZE")
                 .ok()
                 .and_then(|s| s.parse().ok())
-                .unwrap_or(10 * 1024 * 1024),
+                .unwrap_or(1024 * 1024),
+            auth_max_body_size: std::env::var("AUTH_MAX_BODY_SIZE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(16 * 1024),
+            import_max_body_size: std::env::var("IMPORT_MAX_BODY_SIZE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(50 * 1024 * 1024),
             request_timeout_seconds: std::env::var("REQUEST_TIMEOUT_SECONDS")
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(30),
+            request_timeout_overrides: std::env::var("REQUEST_TIMEOUT_OVERRIDES")
+                .ok()
+                .map(|s| parse_timeout_overrides(&s))
+                .unwrap_or_default(),
             rate_limit_per_minute: std::env::var("RATE_LIMIT_PER_MINUTE")
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(60),
+            database_url: std::env::var("DATABASE_URL").ok().filter(|s| !s.is_empty()),
             db_pool_size: std::env::var("DB_POOL_SIZE")
                 .ok()
                 .and_then(|s| s.parse().ok())
@@ -102,6 +193,30 @@ impl ApiConfig {
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(true),
             log_level: std::env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string()),
+            maintenance_mode: std::env::var("MAINTENANCE_MODE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(false),
+            maintenance_retry_after_seconds: std::env::var("MAINTENANCE_RETRY_AFTER_SECONDS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(300),
+            maintenance_allow_admins: std::env::var("MAINTENANCE_ALLOW_ADMINS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(true),
+            reject_duplicate_submissions: std::env::var("REJECT_DUPLICATE_SUBMISSIONS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(false),
+            max_test_case_payload_bytes: std::env::var("MAX_TEST_CASE_PAYLOAD_BYTES")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(TestCase::DEFAULT_MAX_PAYLOAD_BYTES),
+            id_strategy: match std::env::var("ID_STRATEGY") {
+                Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!(e))?,
+                Err(_) => IdStrategy::default(),
+            },
         };
 
         Ok(config)
@@ -122,3 +237,39 @@ impl ApiConfig {
         format!("{}:{}", self.host, self.port)
     }
 }
+
+/// Split a comma-separated environment value into trimmed, non-empty entries
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Parse `prefix=seconds` pairs such as `/api/v1/submissions=120,/api/v1/leaderboards=10`
+///
+/// Entries that are malformed are skipped.
+fn parse_timeout_overrides(value: &str) -> HashMap<String, u64> {
+    parse_list(value)
+        .iter()
+        .filter_map(|entry| {
+            let (prefix, seconds) = entry.split_once('=')?;
+            Some((prefix.trim().to_string(), seconds.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+fn default_cors_methods() -> Vec<String> {
+    ["GET", "POST", "PUT", "PATCH", "DELETE"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_cors_headers() -> Vec<String> {
+    ["authorization", "content-type", "x-request-id", "x-parent-span-id"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
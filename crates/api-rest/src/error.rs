@@ -8,7 +8,7 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use llm_benchmark_application::ApplicationError;
+use llm_benchmark_application::{ApplicationError, ValidationResult};
 use llm_benchmark_domain::errors::{AppError, AuthorizationError, BenchmarkError, GovernanceError, SubmissionError, ValidationError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -37,6 +37,10 @@ pub enum ApiError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// Validation error with the offending fields
+    #[error("Validation error: {}", .0.summary())]
+    InvalidFields(ValidationResult),
+
     /// Not found
     #[error("Resource not found")]
     NotFound,
@@ -65,6 +69,10 @@ pub enum ApiError {
     #[error("Request timeout")]
     Timeout,
 
+    /// Request did not complete within its timeout
+    #[error("Gateway timeout: {0}")]
+    GatewayTimeout(String),
+
     /// Payload too large
     #[error("Payload too large")]
     PayloadTooLarge,
@@ -95,12 +103,13 @@ impl ApiError {
             },
             Self::Application(err) => StatusCode::from_u16(err.http_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
             Self::Unauthorized | Self::InvalidToken(_) => StatusCode::UNAUTHORIZED,
-            Self::Validation(_) | Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::Validation(_) | Self::InvalidFields(_) | Self::BadRequest(_) => StatusCode::BAD_REQUEST,
             Self::NotFound => StatusCode::NOT_FOUND,
             Self::Conflict(_) => StatusCode::CONFLICT,
             Self::Forbidden(_) => StatusCode::FORBIDDEN,
             Self::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
             Self::Timeout => StatusCode::REQUEST_TIMEOUT,
+            Self::GatewayTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
             Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
             Self::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
             Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -113,18 +122,29 @@ impl ApiError {
             Self::Domain(err) => err.error_code(),
             Self::Application(err) => err.error_code(),
             Self::Unauthorized | Self::InvalidToken(_) => "UNAUTHORIZED",
-            Self::Validation(_) => "VALIDATION_ERROR",
+            Self::Validation(_) | Self::InvalidFields(_) => "VALIDATION_ERROR",
             Self::NotFound => "NOT_FOUND",
             Self::BadRequest(_) => "BAD_REQUEST",
             Self::Conflict(_) => "CONFLICT",
             Self::Forbidden(_) => "FORBIDDEN",
             Self::RateLimitExceeded => "RATE_LIMIT_EXCEEDED",
             Self::Timeout => "TIMEOUT",
+            Self::GatewayTimeout(_) => "GATEWAY_TIMEOUT",
             Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
             Self::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
             Self::Internal(_) => "INTERNAL_ERROR",
         }
     }
+
+    /// Field-level validation errors, if this error carries any
+    pub fn field_errors(&self) -> Option<&ValidationResult> {
+        match self {
+            Self::InvalidFields(result) | Self::Application(ApplicationError::InvalidFields(result)) => {
+                Some(result)
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Standardized error response
@@ -181,12 +201,33 @@ impl IntoResponse for ApiError {
         let error_code = self.error_code();
         let message = self.to_string();
 
-        let body = ErrorResponse::new(error_code, message);
+        let mut body = ErrorResponse::new(error_code, message);
+        if let Some(result) = self.field_errors() {
+            body = body.with_details(field_error_details(result));
+        }
 
         (status, Json(body)).into_response()
     }
 }
 
+/// `{"field_errors": [{"field", "message"}]}`, one entry per message,
+/// ordered by field
+fn field_error_details(result: &ValidationResult) -> serde_json::Value {
+    let mut fields: Vec<_> = result.field_errors.iter().collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+
+    let field_errors: Vec<_> = fields
+        .into_iter()
+        .flat_map(|(field, messages)| {
+            messages
+                .iter()
+                .map(move |message| serde_json::json!({ "field": field, "message": message }))
+        })
+        .collect();
+
+    serde_json::json!({ "field_errors": field_errors })
+}
+
 // Implement From for common error types
 impl From<ValidationError> for ApiError {
     fn from(err: ValidationError) -> Self {
@@ -220,3 +261,47 @@ impl From<AuthorizationError> for ApiError {
 
 /// Result type for API operations
 pub type ApiResult<T> = Result<T, ApiError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn body_of(error: ApiError) -> (StatusCode, serde_json::Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_field_errors_in_details() {
+        let mut result = ValidationResult::success();
+        result.add_field_error("version", "Invalid semantic version");
+        result.add_field_error("slug", "Slug cannot be empty");
+        result.add_field_error("slug", "Slug is too short");
+
+        let (status, body) = body_of(ApiError::Application(result.to_error().unwrap())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["error"], "VALIDATION_FAILED");
+        assert_eq!(
+            body["details"]["field_errors"],
+            serde_json::json!([
+                { "field": "slug", "message": "Slug cannot be empty" },
+                { "field": "slug", "message": "Slug is too short" },
+                { "field": "version", "message": "Invalid semantic version" },
+            ])
+        );
+
+        let (status, body) = body_of(ApiError::InvalidFields(result)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["details"]["field_errors"].as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_errors_without_fields_have_no_details() {
+        let (_, body) = body_of(ApiError::Conflict("Slug taken".to_string())).await;
+        assert!(body.get("details").is_none());
+    }
+}
@@ -70,15 +70,8 @@ pub fn build_service_context(
     exec_ctx: Option<ExecutionContext>,
 ) -> ServiceContext {
     let ctx = match user {
-        Some(u) => {
-            let ctx =
-                ServiceContext::authenticated(u.user_id.to_string(), request_id.to_string());
-            if u.is_admin() {
-                ctx.with_admin()
-            } else {
-                ctx
-            }
-        }
+        Some(u) => ServiceContext::authenticated(u.user_id.to_string(), request_id.to_string())
+            .with_role(u.role),
         None => ServiceContext::anonymous(request_id.to_string()),
     };
     match exec_ctx {
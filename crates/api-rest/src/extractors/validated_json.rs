@@ -4,8 +4,10 @@ use crate::error::ApiError;
 use axum::{
     async_trait,
     extract::{FromRequest, Request},
+    http::StatusCode,
     Json,
 };
+use llm_benchmark_application::ValidationResult;
 use serde::de::DeserializeOwned;
 use validator::Validate;
 
@@ -24,11 +26,14 @@ where
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
         let Json(value) = Json::<T>::from_request(req, state)
             .await
-            .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {}", e)))?;
+            .map_err(|e| match e.status() {
+                StatusCode::PAYLOAD_TOO_LARGE => ApiError::PayloadTooLarge,
+                _ => ApiError::BadRequest(format!("Invalid JSON: {}", e)),
+            })?;
 
         value
             .validate()
-            .map_err(|e| ApiError::Validation(format!("Validation failed: {}", e)))?;
+            .map_err(|e| ApiError::InvalidFields(ValidationResult::from(&e)))?;
 
         Ok(ValidatedJson(value))
     }
@@ -9,6 +9,7 @@
 //! The API is organized into the following modules:
 //!
 //! - **app**: Application builder and state management
+//! - **adapters**: Bindings from application ports to PostgreSQL, Redis and messaging
 //! - **routes**: HTTP route handlers organized by domain
 //! - **middleware**: Request/response middleware (auth, logging, rate limiting)
 //! - **extractors**: Custom Axum extractors for common patterns
@@ -40,6 +41,7 @@
 #![warn(clippy::all)]
 #![allow(clippy::too_many_arguments)]
 
+pub mod adapters;
 pub mod app;
 pub mod config;
 pub mod error;
@@ -4,6 +4,7 @@
 //! It creates and runs the Axum-based REST API server.
 
 use llm_benchmark_api_rest::{create_app, ApiConfig};
+use llm_benchmark_domain::identifiers::set_id_generator;
 use std::net::SocketAddr;
 use tracing::{info, warn};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
@@ -37,6 +38,11 @@ async fn main() -> anyhow::Result<()> {
         "Configuration loaded"
     );
 
+    // Before anything generates an ID
+    if set_id_generator(config.id_strategy.generator()).is_err() {
+        warn!(strategy = ?config.id_strategy, "ID generator already in use, strategy not applied");
+    }
+
     // Create the application
     let app = create_app(config.clone()).await.map_err(|e| {
         warn!(error = %e, "Failed to create application");
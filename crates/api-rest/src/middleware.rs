@@ -2,18 +2,27 @@
 //!
 //! This module provides middleware for request/response processing including:
 //! - Request logging and tracing
+//! - CORS
 //! - Error handling
 //! - Rate limiting
+//! - Request timeouts
+//! - Maintenance mode
 //! - Request ID generation
 
+pub mod cors;
 pub mod error_handler;
 pub mod execution;
 pub mod logging;
+pub mod maintenance;
 pub mod rate_limit;
 pub mod request_id;
+pub mod timeout;
 
+pub use cors::cors_layer;
 pub use error_handler::handle_error;
 pub use execution::execution_context_middleware;
 pub use logging::logging_middleware;
+pub use maintenance::{maintenance_middleware, MaintenanceMode};
 pub use rate_limit::RateLimitLayer;
 pub use request_id::RequestIdLayer;
+pub use timeout::{request_timeout_middleware, RequestTimeouts};
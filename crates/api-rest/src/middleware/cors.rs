@@ -0,0 +1,177 @@
+//! CORS middleware.
+//!
+//! Builds a [`CorsLayer`] from [`ApiConfig`]. Origins must be listed
+//! explicitly; the `*` wildcard is only accepted when credentials are
+//! disabled, since browsers refuse credentialed responses with a wildcard
+//! origin. Requests from origins outside the allow-list receive no
+//! `Access-Control-Allow-Origin` header and are blocked by the browser.
+
+use crate::config::ApiConfig;
+use axum::http::{HeaderName, HeaderValue, Method};
+use std::time::Duration;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+const WILDCARD: &str = "*";
+
+/// Build the CORS layer from configuration
+pub fn cors_layer(config: &ApiConfig) -> anyhow::Result<CorsLayer> {
+    let wildcard = config.cors_allowed_origins.iter().any(|o| o == WILDCARD);
+
+    let allow_origin = if wildcard {
+        if config.cors_allow_credentials {
+            anyhow::bail!("CORS wildcard origin cannot be combined with allow_credentials");
+        }
+        AllowOrigin::any()
+    } else {
+        let origins = config
+            .cors_allowed_origins
+            .iter()
+            .map(|origin| {
+                HeaderValue::from_str(origin)
+                    .map_err(|_| anyhow::anyhow!("Invalid CORS origin: {}", origin))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        AllowOrigin::list(origins)
+    };
+
+    let methods = config
+        .cors_allowed_methods
+        .iter()
+        .map(|method| {
+            method
+                .parse::<Method>()
+                .map_err(|_| anyhow::anyhow!("Invalid CORS method: {}", method))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let headers = config
+        .cors_allowed_headers
+        .iter()
+        .map(|header| {
+            header
+                .parse::<HeaderName>()
+                .map_err(|_| anyhow::anyhow!("Invalid CORS header: {}", header))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(methods)
+        .allow_headers(headers)
+        .allow_credentials(config.cors_allow_credentials)
+        .max_age(Duration::from_secs(config.cors_max_age_seconds)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    fn app(config: &ApiConfig) -> Router {
+        Router::new()
+            .route("/benchmarks", get(|| async { "ok" }))
+            .layer(cors_layer(config).unwrap())
+    }
+
+    fn allow_list_config() -> ApiConfig {
+        ApiConfig {
+            cors_allowed_origins: vec!["https://app.example.com".to_string()],
+            cors_allow_credentials: true,
+            cors_max_age_seconds: 600,
+            ..ApiConfig::default()
+        }
+    }
+
+    fn preflight(origin: &str) -> Request<Body> {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/benchmarks")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_preflight_returns_configured_headers() {
+        let config = allow_list_config();
+        let response = app(&config)
+            .oneshot(preflight("https://app.example.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://app.example.com"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(headers[header::ACCESS_CONTROL_MAX_AGE], "600");
+
+        let methods = headers[header::ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap();
+        assert!(methods.contains("POST"));
+        let allowed_headers = headers[header::ACCESS_CONTROL_ALLOW_HEADERS].to_str().unwrap();
+        assert!(allowed_headers.contains("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_disallowed_origin_rejected() {
+        let config = allow_list_config();
+
+        let response = app(&config)
+            .oneshot(preflight("https://evil.example.com"))
+            .await
+            .unwrap();
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+
+        let request = Request::builder()
+            .uri("/benchmarks")
+            .header(header::ORIGIN, "https://evil.example.com")
+            .body(Body::empty())
+            .unwrap();
+        let response = app(&config).oneshot(request).await.unwrap();
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_default_config_allows_no_origins() {
+        let response = app(&ApiConfig::default())
+            .oneshot(preflight("https://app.example.com"))
+            .await
+            .unwrap();
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+    }
+
+    #[test]
+    fn test_wildcard_with_credentials_rejected() {
+        let config = ApiConfig {
+            cors_allowed_origins: vec![WILDCARD.to_string()],
+            cors_allow_credentials: true,
+            ..ApiConfig::default()
+        };
+        assert!(cors_layer(&config).is_err());
+
+        let config = ApiConfig {
+            cors_allow_credentials: false,
+            ..config
+        };
+        assert!(cors_layer(&config).is_ok());
+    }
+}
@@ -0,0 +1,194 @@
+//! Maintenance-mode middleware.
+//!
+//! While maintenance mode is enabled every request except the health probes
+//! is answered with `503 Service Unavailable`, a `Retry-After` header and the
+//! standard JSON error body. Admin requests can optionally pass through so
+//! operators can keep working against the API.
+
+use crate::{
+    config::ApiConfig, error::ApiError, extractors::AuthenticatedUser, state::AppState,
+};
+use axum::{
+    body::Body,
+    extract::{FromRequestParts, State},
+    http::{header, HeaderValue, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Paths that stay available during maintenance
+const EXEMPT_PATHS: &[&str] = &["/health", "/ready", "/live"];
+
+/// Runtime maintenance-mode toggle shared across requests
+#[derive(Debug, Clone)]
+pub struct MaintenanceMode {
+    enabled: Arc<AtomicBool>,
+    retry_after_seconds: u64,
+    allow_admins: bool,
+}
+
+impl MaintenanceMode {
+    /// Create the toggle from configuration
+    pub fn from_config(config: &ApiConfig) -> Self {
+        Self {
+            enabled: Arc::new(AtomicBool::new(config.maintenance_mode)),
+            retry_after_seconds: config.maintenance_retry_after_seconds,
+            allow_admins: config.maintenance_allow_admins,
+        }
+    }
+
+    /// Check whether maintenance mode is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable maintenance mode
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// `Retry-After` value in seconds
+    pub fn retry_after_seconds(&self) -> u64 {
+        self.retry_after_seconds
+    }
+}
+
+/// Middleware that rejects requests with 503 while maintenance mode is enabled
+pub async fn maintenance_middleware(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let maintenance = &state.maintenance;
+
+    if !maintenance.is_enabled() || EXEMPT_PATHS.contains(&req.uri().path()) {
+        return next.run(req).await;
+    }
+
+    if maintenance.allow_admins {
+        let (mut parts, body) = req.into_parts();
+        let is_admin = AuthenticatedUser::from_request_parts(&mut parts, &state)
+            .await
+            .map(|user| user.is_admin())
+            .unwrap_or(false);
+        let req = Request::from_parts(parts, body);
+
+        if is_admin {
+            return next.run(req).await;
+        }
+    }
+
+    let mut response = ApiError::ServiceUnavailable(
+        "The API is undergoing maintenance, please retry later".to_string(),
+    )
+    .into_response();
+    response.headers_mut().insert(
+        header::RETRY_AFTER,
+        HeaderValue::from(maintenance.retry_after_seconds),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::auth::Claims;
+    use axum::{http::StatusCode, middleware, routing::get, Router};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use llm_benchmark_domain::user::UserRole;
+    use tower::ServiceExt;
+
+    fn app(state: AppState) -> Router {
+        Router::new()
+            .route("/health", get(|| async { "healthy" }))
+            .route("/api/v1/benchmarks", get(|| async { "benchmarks" }))
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                maintenance_middleware,
+            ))
+            .with_state(state)
+    }
+
+    fn token(state: &AppState, role: UserRole) -> String {
+        let now = chrono::Utc::now().timestamp() as usize;
+        let claims = Claims {
+            sub: uuid::Uuid::new_v4().to_string(),
+            role,
+            exp: now + 3600,
+            iat: now,
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(state.jwt_secret().as_bytes()),
+        )
+        .unwrap()
+    }
+
+    async fn send(state: &AppState, uri: &str, token: Option<String>) -> Response {
+        let mut request = Request::builder().uri(uri);
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        app(state.clone())
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_toggle_maintenance_mode() {
+        let state = AppState::new(ApiConfig::default());
+
+        let response = send(&state, "/api/v1/benchmarks", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        state.maintenance.set_enabled(true);
+
+        let response = send(&state, "/api/v1/benchmarks", None).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "300");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"], "SERVICE_UNAVAILABLE");
+
+        let response = send(&state, "/health", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        state.maintenance.set_enabled(false);
+
+        let response = send(&state, "/api/v1/benchmarks", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_admin_bypass() {
+        let state = AppState::new(ApiConfig {
+            maintenance_mode: true,
+            ..ApiConfig::default()
+        });
+
+        let admin = token(&state, UserRole::Admin);
+        let response = send(&state, "/api/v1/benchmarks", Some(admin)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let user = token(&state, UserRole::Registered);
+        let response = send(&state, "/api/v1/benchmarks", Some(user)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let state = AppState::new(ApiConfig {
+            maintenance_mode: true,
+            maintenance_allow_admins: false,
+            ..ApiConfig::default()
+        });
+        let admin = token(&state, UserRole::Admin);
+        let response = send(&state, "/api/v1/benchmarks", Some(admin)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -2,21 +2,33 @@
 //!
 //! This is a simple in-memory rate limiter. In production, you would
 //! want to use Redis or a similar distributed cache.
+//!
+//! Every response carries `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+//! `X-RateLimit-Reset` (unix seconds) headers; rejected requests also get
+//! `Retry-After`.
 
 use axum::{
     body::Body,
-    http::{Request, Response, StatusCode},
+    http::{header, HeaderMap, HeaderValue, Request, Response, StatusCode},
     response::IntoResponse,
 };
+use llm_benchmark_infrastructure::RateLimitResult;
 use parking_lot::RwLock;
 use std::{
     collections::HashMap,
     net::IpAddr,
     sync::Arc,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tower::{Layer, Service};
 
+/// Header carrying the request limit for the window
+pub const RATE_LIMIT_LIMIT: &str = "x-ratelimit-limit";
+/// Header carrying the requests left in the current window
+pub const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+/// Header carrying the unix time at which a slot frees up
+pub const RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
+
 /// Rate limit configuration
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
@@ -51,7 +63,7 @@ impl RateLimiter {
         }
     }
 
-    fn check_rate_limit(&mut self, ip: IpAddr) -> bool {
+    fn check_rate_limit(&mut self, ip: IpAddr) -> RateLimitResult {
         let now = Instant::now();
         let window_start = now - self.config.window;
 
@@ -61,14 +73,28 @@ impl RateLimiter {
         // Remove old requests outside the window
         requests.retain(|&timestamp| timestamp > window_start);
 
-        // Check if limit exceeded
-        if requests.len() >= self.config.max_requests as usize {
-            return false;
-        }
+        // Express the window in unix seconds for the response headers
+        let now_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let oldest = requests
+            .first()
+            .map(|&oldest| now_secs.saturating_sub(now.duration_since(oldest).as_secs()));
+
+        let result = RateLimitResult::from_window(
+            u64::from(self.config.max_requests),
+            self.config.window.as_secs(),
+            now_secs,
+            requests.len() as u64,
+            oldest,
+        );
 
         // Record this request
-        requests.push(now);
-        true
+        if result.allowed {
+            requests.push(now);
+        }
+        result
     }
 
     fn cleanup(&mut self) {
@@ -156,18 +182,19 @@ where
                 .unwrap_or_else(|| IpAddr::from([127, 0, 0, 1]));
 
             // Check rate limit
-            let allowed = {
+            let result = {
                 let mut limiter = limiter.write();
                 limiter.check_rate_limit(ip)
             };
 
-            if !allowed {
+            if !result.allowed {
                 // Rate limit exceeded
-                let response = (
+                let mut response = (
                     StatusCode::TOO_MANY_REQUESTS,
                     "Rate limit exceeded",
                 )
                     .into_response();
+                apply_rate_limit_headers(response.headers_mut(), &result);
                 return Ok(response);
             }
 
@@ -176,7 +203,76 @@ where
                 limiter.write().cleanup();
             }
 
-            inner.call(req).await
+            let mut response = inner.call(req).await?;
+            apply_rate_limit_headers(response.headers_mut(), &result);
+            Ok(response)
+        })
+    }
+}
+
+/// Set the rate limit headers describing `result`
+fn apply_rate_limit_headers(headers: &mut HeaderMap, result: &RateLimitResult) {
+    headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from(result.limit));
+    headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from(result.remaining));
+    headers.insert(RATE_LIMIT_RESET, HeaderValue::from(result.reset_at));
+    if let Some(retry_after) = result.retry_after {
+        headers.insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(max_requests: u32) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            max_requests,
+            window: Duration::from_secs(60),
         })
     }
+
+    #[test]
+    fn test_remaining_counts_down_within_window() {
+        let mut limiter = limiter(3);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+
+        let results: Vec<RateLimitResult> =
+            (0..4).map(|_| limiter.check_rate_limit(ip)).collect();
+
+        let remaining: Vec<u64> = results.iter().map(|r| r.remaining).collect();
+        assert_eq!(remaining, vec![2, 1, 0, 0]);
+        assert!(results[..3].iter().all(|r| r.allowed && r.retry_after.is_none()));
+
+        let denied = &results[3];
+        assert!(!denied.allowed);
+        assert_eq!(denied.limit, 3);
+        // All requests share the window opened by the first one
+        assert!(results.iter().all(|r| r.reset_at == results[0].reset_at));
+        assert!(matches!(denied.retry_after, Some(secs) if (59..=60).contains(&secs)));
+
+        // Other clients have their own window
+        assert!(limiter.check_rate_limit(IpAddr::from([10, 0, 0, 2])).allowed);
+    }
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        apply_rate_limit_headers(
+            &mut headers,
+            &RateLimitResult::from_window(5, 60, 1_000, 5, Some(970)),
+        );
+
+        assert_eq!(headers[RATE_LIMIT_LIMIT], "5");
+        assert_eq!(headers[RATE_LIMIT_REMAINING], "0");
+        assert_eq!(headers[RATE_LIMIT_RESET], "1030");
+        assert_eq!(headers[header::RETRY_AFTER], "30");
+
+        let mut headers = HeaderMap::new();
+        apply_rate_limit_headers(
+            &mut headers,
+            &RateLimitResult::from_window(5, 60, 1_000, 1, Some(970)),
+        );
+        assert_eq!(headers[RATE_LIMIT_REMAINING], "3");
+        assert!(!headers.contains_key(header::RETRY_AFTER));
+    }
 }
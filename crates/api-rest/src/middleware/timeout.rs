@@ -0,0 +1,213 @@
+//! Request-timeout middleware.
+//!
+//! Every request except the health probes must complete within the
+//! configured timeout. Individual routes can be given a different budget by
+//! path prefix through [`ApiConfig::request_timeout_overrides`]; the longest
+//! matching prefix wins. When the budget is exceeded the handler future is
+//! dropped, cancelling any work it still has in flight, and the client gets
+//! `504 Gateway Timeout` with the standard JSON error body.
+
+use crate::{config::ApiConfig, error::ApiError};
+use axum::{
+    body::Body,
+    extract::State,
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::{sync::Arc, time::Duration};
+
+/// Paths that are never timed out
+const EXEMPT_PATHS: &[&str] = &["/health", "/ready", "/live"];
+
+/// Timeout budgets shared across requests
+#[derive(Debug, Clone)]
+pub struct RequestTimeouts {
+    default: Duration,
+    /// Path prefix overrides, longest prefix first
+    overrides: Arc<Vec<(String, Duration)>>,
+}
+
+impl RequestTimeouts {
+    /// Create the budgets from configuration
+    pub fn from_config(config: &ApiConfig) -> Self {
+        let mut overrides: Vec<(String, Duration)> = config
+            .request_timeout_overrides
+            .iter()
+            .map(|(prefix, seconds)| (prefix.clone(), Duration::from_secs(*seconds)))
+            .collect();
+        overrides.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+
+        Self {
+            default: config.request_timeout(),
+            overrides: Arc::new(overrides),
+        }
+    }
+
+    /// Use a fixed default timeout
+    pub fn with_default(mut self, timeout: Duration) -> Self {
+        self.default = timeout;
+        self
+    }
+
+    /// Override the timeout for paths starting with `prefix`
+    pub fn with_override(mut self, prefix: impl Into<String>, timeout: Duration) -> Self {
+        let prefix = prefix.into();
+        let overrides = Arc::make_mut(&mut self.overrides);
+        overrides.retain(|(p, _)| *p != prefix);
+        overrides.push((prefix, timeout));
+        overrides.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+        self
+    }
+
+    /// Timeout for a request path, or `None` if the path is exempt
+    pub fn for_path(&self, path: &str) -> Option<Duration> {
+        if EXEMPT_PATHS.contains(&path) {
+            return None;
+        }
+
+        let timeout = self
+            .overrides
+            .iter()
+            .find(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .map(|(_, timeout)| *timeout)
+            .unwrap_or(self.default);
+        Some(timeout)
+    }
+}
+
+/// Middleware that answers 504 when a handler exceeds its timeout
+pub async fn request_timeout_middleware(
+    State(timeouts): State<RequestTimeouts>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(timeout) = timeouts.for_path(req.uri().path()) else {
+        return next.run(req).await;
+    };
+
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+
+    match tokio::time::timeout(timeout, next.run(req)).await {
+        Ok(response) => response,
+        Err(_) => {
+            tracing::warn!(
+                method = %method,
+                path = %path,
+                timeout_ms = timeout.as_millis() as u64,
+                "Request timed out"
+            );
+            ApiError::GatewayTimeout(format!(
+                "The request did not complete within {}ms",
+                timeout.as_millis()
+            ))
+            .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, middleware, routing::get, Router};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tower::ServiceExt;
+
+    const SLOW: Duration = Duration::from_millis(500);
+
+    fn app(timeouts: RequestTimeouts, finished: Arc<AtomicBool>) -> Router {
+        let slow = move || {
+            let finished = finished.clone();
+            async move {
+                tokio::time::sleep(SLOW).await;
+                finished.store(true, Ordering::SeqCst);
+                "done"
+            }
+        };
+
+        Router::new()
+            .route("/health", get(slow.clone()))
+            .route("/api/v1/slow", get(slow.clone()))
+            .route("/api/v1/exports/slow", get(slow))
+            .layer(middleware::from_fn_with_state(
+                timeouts,
+                request_timeout_middleware,
+            ))
+    }
+
+    async fn send(app: Router, uri: &str) -> Response {
+        app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    fn timeouts() -> RequestTimeouts {
+        RequestTimeouts::from_config(&ApiConfig::default())
+            .with_default(Duration::from_millis(50))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_handler_times_out() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let started = tokio::time::Instant::now();
+
+        let response = send(app(timeouts(), finished.clone()), "/api/v1/slow").await;
+
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(started.elapsed(), Duration::from_millis(50));
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"], "GATEWAY_TIMEOUT");
+        assert_eq!(
+            body["message"],
+            "Gateway timeout: The request did not complete within 50ms"
+        );
+
+        // The handler was cancelled rather than left running
+        tokio::time::sleep(SLOW).await;
+        assert!(!finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_route_override_and_health_exemption() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let timeouts = timeouts().with_override("/api/v1/exports", Duration::from_secs(1));
+
+        let response = send(app(timeouts.clone(), finished.clone()), "/api/v1/exports/slow").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(finished.swap(false, Ordering::SeqCst));
+
+        let response = send(app(timeouts, finished.clone()), "/health").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let config = ApiConfig {
+            request_timeout_seconds: 30,
+            request_timeout_overrides: [
+                ("/api/v1".to_string(), 10),
+                ("/api/v1/submissions".to_string(), 120),
+            ]
+            .into_iter()
+            .collect(),
+            ..ApiConfig::default()
+        };
+        let timeouts = RequestTimeouts::from_config(&config);
+
+        assert_eq!(
+            timeouts.for_path("/api/v1/submissions/abc"),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            timeouts.for_path("/api/v1/benchmarks"),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(timeouts.for_path("/metrics"), Some(Duration::from_secs(30)));
+        assert_eq!(timeouts.for_path("/ready"), None);
+    }
+}
@@ -17,6 +17,8 @@ use llm_benchmark_application::{
     validation::{CreateBenchmarkRequest, CreateVersionRequest, StatusTransitionRequest, UpdateBenchmarkRequest},
 };
 use llm_benchmark_domain::benchmark::{BenchmarkCategory, BenchmarkStatus};
+use llm_benchmark_domain::submission::VerificationLevel;
+use llm_benchmark_domain::test_case::TestCase;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -129,6 +131,14 @@ pub struct CreateBenchmarkApiRequest {
 
     #[serde(default)]
     pub tags: Vec<String>,
+
+    /// Weakest verification level ranked on the leaderboard by default
+    #[serde(default)]
+    pub leaderboard_min_verification: VerificationLevel,
+
+    /// Test cases of the initial version
+    #[serde(default)]
+    pub test_cases: Vec<TestCase>,
 }
 
 /// Update benchmark request
@@ -143,6 +153,10 @@ pub struct UpdateBenchmarkApiRequest {
     pub tags: Option<Vec<String>>,
 
     pub long_description: Option<String>,
+
+    /// Replacement test cases; only drafts can change them without a new version
+    #[serde(default)]
+    pub test_cases: Option<Vec<TestCase>>,
 }
 
 /// Status change request
@@ -167,9 +181,22 @@ pub struct CreateVersionApiRequest {
     pub breaking_changes: bool,
 
     pub migration_notes: Option<String>,
+
+    /// Full test case set of the new version; the current set is kept when omitted
+    #[serde(default)]
+    pub test_cases: Option<Vec<TestCase>>,
 }
 
 /// Query parameters for listing benchmarks
+/// Benchmark ranked by recent submission activity
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TrendingBenchmark {
+    /// Benchmark ID
+    pub benchmark_id: String,
+    /// Decayed popularity score; higher is more popular
+    pub score: f64,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct BenchmarkListQuery {
     pub category: Option<BenchmarkCategory>,
@@ -190,6 +217,7 @@ pub fn routes() -> Router<AppState> {
         .route("/benchmarks/:id/versions", get(list_versions).post(create_version))
         .route("/benchmarks/slug/:slug", get(get_benchmark_by_slug))
         .route("/benchmarks/search", get(search_benchmarks))
+        .route("/benchmarks/trending", get(trending_benchmarks))
 }
 
 /// List benchmarks
@@ -230,23 +258,14 @@ async fn list_benchmarks(
         maintainer_id: None,
     };
 
-    let service_pagination = ServicePagination::new(
-        pagination.params.page,
-        pagination.params.per_page,
-    );
+    let service_pagination = ServicePagination::from(pagination.params);
 
     let result = state.benchmark_service
         .list(&ctx, filters, service_pagination)
         .await?;
 
-    let items: Vec<BenchmarkListItem> = result.items.into_iter().map(Into::into).collect();
-
-    let paginated = llm_benchmark_common::pagination::PaginatedResult::new(
-        items,
-        result.page,
-        result.page_size,
-        result.total,
-    );
+    let paginated: llm_benchmark_common::pagination::PaginatedResult<_> =
+        result.map(BenchmarkListItem::from).into();
 
     let execution = exec_ctx.and_then(|ec| ec.finalize().ok());
     Ok(InstrumentedPaginatedResponse::new(paginated.into(), execution))
@@ -294,6 +313,8 @@ async fn create_benchmark(
         category: req.category,
         tags: req.tags,
         version: req.version,
+        leaderboard_min_verification: req.leaderboard_min_verification,
+        test_cases: req.test_cases,
     };
 
     let benchmark = state.benchmark_service.create(&ctx, request).await?;
@@ -409,6 +430,7 @@ async fn update_benchmark(
         description: req.description,
         tags: req.tags,
         long_description: req.long_description,
+        test_cases: req.test_cases,
     };
 
     let benchmark = state.benchmark_service
@@ -712,6 +734,7 @@ async fn list_versions(
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden"),
         (status = 404, description = "Benchmark not found"),
+        (status = 409, description = "Version already exists or is not newer than the latest"),
     ),
     security(
         ("bearer_auth" = [])
@@ -733,6 +756,7 @@ async fn create_version(
         changelog: req.changelog,
         breaking_changes: req.breaking_changes,
         migration_notes: req.migration_notes,
+        test_cases: req.test_cases,
     };
 
     let version = state.benchmark_service
@@ -772,23 +796,14 @@ async fn search_benchmarks(
     let exec_ctx = exec.0;
     let ctx = build_service_context(None, &request_id, exec_ctx.clone());
 
-    let service_pagination = ServicePagination::new(
-        pagination.params.page,
-        pagination.params.per_page,
-    );
+    let service_pagination = ServicePagination::from(pagination.params);
 
     let result = state.benchmark_service
         .search(&ctx, &params.q, service_pagination)
         .await?;
 
-    let items: Vec<BenchmarkListItem> = result.items.into_iter().map(Into::into).collect();
-
-    let paginated = llm_benchmark_common::pagination::PaginatedResult::new(
-        items,
-        result.page,
-        result.page_size,
-        result.total,
-    );
+    let paginated: llm_benchmark_common::pagination::PaginatedResult<_> =
+        result.map(BenchmarkListItem::from).into();
 
     let execution = exec_ctx.and_then(|ec| ec.finalize().ok());
     Ok(InstrumentedPaginatedResponse::new(paginated.into(), execution))
@@ -798,3 +813,309 @@ async fn search_benchmarks(
 struct SearchQuery {
     q: String,
 }
+
+/// Default trending window: one week
+const DEFAULT_TRENDING_WINDOW_HOURS: u64 = 168;
+const DEFAULT_TRENDING_LIMIT: u32 = 10;
+
+/// Trending benchmarks
+///
+/// Benchmarks ranked by public submission activity within the window.
+/// Recent submissions count for more than older ones, so a benchmark
+/// gaining traction ranks above one with a larger but older history.
+#[utoipa::path(
+    get,
+    path = "/benchmarks/trending",
+    tag = "benchmarks",
+    params(
+        ("window_hours" = Option<u64>, Query, description = "Activity window in hours (default 168)"),
+        ("limit" = Option<u32>, Query, description = "Maximum number of benchmarks (default 10)"),
+    ),
+    responses(
+        (status = 200, description = "Trending benchmarks", body = Vec<TrendingBenchmark>),
+        (status = 400, description = "Invalid window"),
+    )
+)]
+async fn trending_benchmarks(
+    State(state): State<AppState>,
+    Query(params): Query<TrendingQuery>,
+    exec: OptionalExecutionContext,
+) -> ApiResult<InstrumentedResponse<Vec<TrendingBenchmark>>> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let exec_ctx = exec.0;
+    let ctx = build_service_context(None, &request_id, exec_ctx.clone());
+
+    let window_hours = params.window_hours.unwrap_or(DEFAULT_TRENDING_WINDOW_HOURS);
+    let window = std::time::Duration::from_secs(window_hours.saturating_mul(3600));
+    let limit = params.limit.unwrap_or(DEFAULT_TRENDING_LIMIT);
+
+    let trending = state.submission_service
+        .trending_benchmarks(&ctx, window, limit)
+        .await?
+        .into_iter()
+        .map(|(benchmark_id, score)| TrendingBenchmark { benchmark_id, score })
+        .collect();
+
+    let execution = exec_ctx.and_then(|ec| ec.finalize().ok());
+    Ok(InstrumentedResponse::new(ApiResponse::success(trending), execution))
+}
+
+#[derive(Debug, Deserialize)]
+struct TrendingQuery {
+    window_hours: Option<u64>,
+    limit: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ApiConfig;
+    use axum::{body::Body, http::Request};
+    use llm_benchmark_application::{
+        services::ServiceContext,
+        validation::{
+            CreateSubmissionRequest, LeaderboardQuery, SubmissionResultsInput, TestCaseResultInput,
+        },
+        ApplicationError,
+    };
+    use llm_benchmark_domain::submission::SubmissionVisibility;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn submit(state: &AppState, benchmark_id: &str) {
+        let submitter = ServiceContext::authenticated(Uuid::new_v4().to_string(), "test".to_string());
+        state
+            .submission_service
+            .create(
+                &submitter,
+                CreateSubmissionRequest {
+                    benchmark_id: benchmark_id.to_string(),
+                    benchmark_version_id: Uuid::new_v4().to_string(),
+                    model_provider: "acme".to_string(),
+                    model_name: "acme-1".to_string(),
+                    model_version: None,
+                    results: SubmissionResultsInput {
+                        aggregate_score: 0.5,
+                        metric_scores: Default::default(),
+                        test_case_results: vec![TestCaseResultInput {
+                            test_case_id: "case-1".to_string(),
+                            passed: true,
+                            score: 0.5,
+                            latency_ms: None,
+                            tokens_generated: None,
+                        }],
+                    },
+                    visibility: SubmissionVisibility::Public,
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn get(state: &AppState, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = routes()
+            .with_state(state.clone())
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_trending_benchmarks_ranked_by_activity() {
+        let state = AppState::new(ApiConfig::default());
+        let busy = Uuid::new_v4().to_string();
+        let quiet = Uuid::new_v4().to_string();
+        for _ in 0..3 {
+            submit(&state, &busy).await;
+        }
+        submit(&state, &quiet).await;
+
+        let (status, body) = get(&state, "/benchmarks/trending?window_hours=24").await;
+        assert_eq!(status, StatusCode::OK);
+        let ids: Vec<&str> = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["benchmark_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, [busy.as_str(), quiet.as_str()]);
+
+        let (_, body) = get(&state, "/benchmarks/trending?limit=1").await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_only_creator_can_update_benchmark() {
+        let state = AppState::new(ApiConfig::default());
+        let creator = ServiceContext::authenticated(Uuid::new_v4().to_string(), "test".to_string());
+        let benchmark = state
+            .benchmark_service
+            .create(
+                &creator,
+                CreateBenchmarkRequest {
+                    name: "Owned Benchmark".to_string(),
+                    slug: "owned-benchmark".to_string(),
+                    description: "A benchmark with a recorded creator".to_string(),
+                    category: BenchmarkCategory::Accuracy,
+                    tags: vec![],
+                    version: "1.0.0".to_string(),
+                    leaderboard_min_verification: VerificationLevel::Unverified,
+                    test_cases: vec![],
+                },
+            )
+            .await
+            .unwrap();
+        let update = || UpdateBenchmarkRequest {
+            name: None,
+            description: Some("Updated description".to_string()),
+            tags: None,
+            long_description: None,
+            test_cases: None,
+        };
+
+        let other = ServiceContext::authenticated(Uuid::new_v4().to_string(), "test".to_string());
+        let result = state.benchmark_service.update(&other, &benchmark.id, update()).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+
+        let updated = state
+            .benchmark_service
+            .update(&creator, &benchmark.id, update())
+            .await
+            .unwrap();
+        assert_eq!(updated.description, "Updated description");
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_leaderboard_minimum_applies_to_in_memory_leaderboard() {
+        let state = AppState::new(ApiConfig::default());
+        let creator = ServiceContext::authenticated(Uuid::new_v4().to_string(), "test".to_string());
+        let benchmark = state
+            .benchmark_service
+            .create(
+                &creator,
+                CreateBenchmarkRequest {
+                    name: "Verified Benchmark".to_string(),
+                    slug: "verified-benchmark".to_string(),
+                    description: "Only verified results are ranked".to_string(),
+                    category: BenchmarkCategory::Accuracy,
+                    tags: vec![],
+                    version: "1.0.0".to_string(),
+                    leaderboard_min_verification: VerificationLevel::PlatformVerified,
+                    test_cases: vec![],
+                },
+            )
+            .await
+            .unwrap();
+        submit(&state, &benchmark.id).await;
+
+        let leaderboard = state
+            .submission_service
+            .get_leaderboard(
+                &ServiceContext::anonymous("test".to_string()),
+                LeaderboardQuery {
+                    benchmark_id: benchmark.id.clone(),
+                    benchmark_version_id: None,
+                    limit: None,
+                    min_verification_level: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(leaderboard.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_version_passes_test_cases_to_service() {
+        use crate::extractors::auth::Claims;
+        use jsonwebtoken::{encode, EncodingKey, Header};
+        use llm_benchmark_domain::user::UserRole;
+
+        let state = AppState::new(ApiConfig {
+            max_test_case_payload_bytes: 1024,
+            ..ApiConfig::default()
+        });
+        let creator_id = Uuid::new_v4().to_string();
+        let creator = ServiceContext::authenticated(creator_id.clone(), "test".to_string());
+        let benchmark = state
+            .benchmark_service
+            .create(
+                &creator,
+                CreateBenchmarkRequest {
+                    name: "Versioned Benchmark".to_string(),
+                    slug: "versioned-benchmark".to_string(),
+                    description: "A benchmark that gains new test cases".to_string(),
+                    category: BenchmarkCategory::Accuracy,
+                    tags: vec![],
+                    version: "1.0.0".to_string(),
+                    leaderboard_min_verification: VerificationLevel::Unverified,
+                    test_cases: vec![],
+                },
+            )
+            .await
+            .unwrap();
+        let now = chrono::Utc::now().timestamp() as usize;
+        let token = encode(
+            &Header::default(),
+            &Claims {
+                sub: creator_id,
+                role: UserRole::Registered,
+                exp: now + 3600,
+                iat: now,
+            },
+            &EncodingKey::from_secret(state.jwt_secret().as_bytes()),
+        )
+        .unwrap();
+        let create_version = |version: &str, prompt: String| {
+            let body = serde_json::json!({
+                "version": version,
+                "changelog": "Add a test case",
+                "test_cases": [{
+                    "id": "case-1",
+                    "name": "case-1",
+                    "input": {
+                        "prompt_template": prompt,
+                        "variables": {},
+                        "few_shot_examples": [],
+                        "input_format": {"type": "plain_text"}
+                    },
+                    "evaluation_method": {"type": "exact_match"},
+                    "weight": 1.0,
+                    "tags": []
+                }]
+            });
+            routes().with_state(state.clone()).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/benchmarks/{}/versions", benchmark.id))
+                    .header("authorization", format!("Bearer {}", token))
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+        };
+
+        // The payload limit only applies when the test cases reach the service
+        let response = create_version("1.1.0", "x".repeat(2048)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("exceeding the limit of 1024 bytes"), "{}", body);
+
+        let response = create_version("1.1.0", "What is 2 + 2?".to_string()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_trending_benchmarks_rejects_empty_window() {
+        let state = AppState::new(ApiConfig::default());
+        let (status, _) = get(&state, "/benchmarks/trending?window_hours=0").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
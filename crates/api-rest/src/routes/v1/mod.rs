@@ -1,7 +1,7 @@
 //! API v1 routes.
 
-use crate::state::AppState;
-use axum::Router;
+use crate::{config::ApiConfig, state::AppState};
+use axum::{extract::DefaultBodyLimit, Router};
 
 pub mod benchmarks;
 pub mod governance;
@@ -11,12 +11,185 @@ pub mod submissions;
 pub mod users;
 
 /// Create all v1 API routes
-pub fn routes() -> Router<AppState> {
-    Router::new()
+///
+/// Routes are grouped by the request body size they accept: authentication
+/// and user routes only take small payloads, import routes (benchmarks with
+/// their test cases, submissions and publications) accept large bodies, and
+/// everything else uses the default limit. Oversized bodies are rejected with 413 Payload Too Large.
+pub fn routes(config: &ApiConfig) -> Router<AppState> {
+    let auth = users::routes().layer(DefaultBodyLimit::max(config.auth_max_body_size));
+
+    let import = Router::new()
         .merge(benchmarks::routes())
         .merge(submissions::routes())
+        .merge(publications::routes())
+        .layer(DefaultBodyLimit::max(config.import_max_body_size));
+
+    let default = Router::new()
         .merge(leaderboards::routes())
         .merge(governance::routes())
-        .merge(users::routes())
-        .merge(publications::routes())
+        .layer(DefaultBodyLimit::max(config.max_body_size));
+
+    Router::new().merge(default).merge(import).merge(auth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractors::auth::Claims;
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+    };
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use llm_benchmark_domain::{test_case::TestCase, user::UserRole};
+    use tower::ServiceExt;
+
+    fn test_config() -> ApiConfig {
+        ApiConfig {
+            max_body_size: 4 * 1024,
+            auth_max_body_size: 1024,
+            import_max_body_size: 16 * 1024,
+            ..ApiConfig::default()
+        }
+    }
+
+    fn app(config: ApiConfig) -> Router {
+        routes(&config).with_state(AppState::new(config))
+    }
+
+    fn bearer_token(config: &ApiConfig, role: UserRole) -> String {
+        let now = chrono::Utc::now().timestamp() as usize;
+        let claims = Claims {
+            sub: uuid::Uuid::new_v4().to_string(),
+            role,
+            exp: now + 3600,
+            iat: now,
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    /// Login payload padded so the serialized body is exactly `size` bytes
+    fn login_body(size: usize) -> String {
+        let base = r#"{"email":"user@example.com","password":""}"#;
+        let password = "p".repeat(size - base.len());
+        let body = format!(r#"{{"email":"user@example.com","password":"{}"}}"#, password);
+        assert_eq!(body.len(), size);
+        body
+    }
+
+    /// Submission payload padded so the serialized body is exactly `size` bytes
+    fn submission_body(size: usize) -> String {
+        let base = r#"{"model_name":"gpt","model_version":"1","results":{},"metadata":{"notes":""}}"#;
+        let notes = "n".repeat(size - base.len());
+        let body = format!(
+            r#"{{"model_name":"gpt","model_version":"1","results":{{}},"metadata":{{"notes":"{}"}}}}"#,
+            notes
+        );
+        assert_eq!(body.len(), size);
+        body
+    }
+
+    /// Benchmark payload with test cases whose prompts total at least `size` bytes
+    fn benchmark_body(size: usize) -> String {
+        let case_size = TestCase::DEFAULT_MAX_PAYLOAD_BYTES / 2;
+        let test_cases: Vec<_> = (0..size.div_ceil(case_size))
+            .map(|i| {
+                serde_json::json!({
+                    "id": format!("case-{}", i),
+                    "name": format!("Case {}", i),
+                    "input": {
+                        "prompt_template": "p".repeat(case_size),
+                        "variables": {},
+                        "few_shot_examples": [],
+                        "input_format": {"type": "plain_text"}
+                    },
+                    "evaluation_method": {"type": "exact_match"},
+                    "weight": 1.0,
+                    "tags": []
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "name": "Large Benchmark",
+            "slug": "large-benchmark",
+            "category": "accuracy",
+            "description": "Benchmark with a large test suite",
+            "version": "1.0.0",
+            "test_cases": test_cases
+        })
+        .to_string()
+    }
+
+    async fn post(app: Router, uri: &str, body: String, token: Option<&str>) -> StatusCode {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let response = app
+            .oneshot(request.body(Body::from(body)).unwrap())
+            .await
+            .unwrap();
+        response.status()
+    }
+
+    #[tokio::test]
+    async fn test_auth_group_limit() {
+        let config = test_config();
+        let limit = config.auth_max_body_size;
+
+        let status = post(app(config.clone()), "/auth/login", login_body(limit), None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let status = post(app(config), "/auth/login", login_body(limit + 1), None).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_import_group_limit() {
+        let config = test_config();
+        let token = bearer_token(&config, UserRole::Registered);
+        let limit = config.import_max_body_size;
+        let uri = format!("/benchmarks/{}/submissions", uuid::Uuid::new_v4());
+
+        // Larger than the default limit, but within the import group's limit
+        assert!(limit > config.max_body_size);
+        let status = post(app(config.clone()), &uri, submission_body(limit), Some(&token)).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let status = post(app(config), &uri, submission_body(limit + 1), Some(&token)).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_default_group_limit() {
+        let config = test_config();
+        let token = bearer_token(&config, UserRole::Registered);
+        let limit = config.max_body_size;
+
+        // Padding beyond the default limit is rejected before validation runs
+        let body = format!(r#"{{"title":"{}"}}"#, "b".repeat(limit));
+        let status = post(app(config), "/proposals", body, Some(&token)).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_with_large_test_suite_is_accepted() {
+        let config = ApiConfig::default();
+        let token = bearer_token(&config, UserRole::Contributor);
+
+        // Test cases within the per-case cap add up past the default limit
+        let body = benchmark_body(2 * 1024 * 1024);
+        assert!(body.len() > config.max_body_size);
+        let status = post(app(config), "/benchmarks", body, Some(&token)).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
 }
@@ -406,24 +406,15 @@ async fn list_publications(
             .map(|d| d.with_timezone(&Utc)),
     };
 
-    let service_pagination = ServicePagination::new(
-        pagination.params.page,
-        pagination.params.per_page,
-    );
+    let service_pagination = ServicePagination::from(pagination.params);
 
     let result = state
         .publication_service
         .list(&ctx, filters, service_pagination)
         .await?;
 
-    let items: Vec<PublicationListItem> = result.items.into_iter().map(Into::into).collect();
-
-    let paginated = llm_benchmark_common::pagination::PaginatedResult::new(
-        items,
-        result.page,
-        result.page_size,
-        result.total,
-    );
+    let paginated: llm_benchmark_common::pagination::PaginatedResult<_> =
+        result.map(PublicationListItem::from).into();
 
     let execution = exec_ctx.and_then(|ec| ec.finalize().ok());
     Ok(InstrumentedPaginatedResponse::new(paginated.into(), execution))
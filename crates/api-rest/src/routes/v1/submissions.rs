@@ -2,15 +2,23 @@
 
 use crate::{
     error::{ApiError, ApiResult},
-    extractors::{AuthenticatedUser, Pagination, ValidatedJson},
+    extractors::{
+        auth::MaybeAuthenticatedUser, build_service_context, AuthenticatedUser,
+        OptionalExecutionContext, Pagination, ValidatedJson,
+    },
     responses::{ApiResponse, Created, NoContent, PaginatedResponse},
     state::AppState,
 };
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    response::sse::{Event, KeepAlive, Sse},
     routing::{get, patch, post},
     Json, Router,
 };
+use futures::{stream, Stream, StreamExt};
+use llm_benchmark_application::services::{
+    SubmissionDto, VerificationEventDto, VerificationProgress, VerificationStage,
+};
 use llm_benchmark_domain::{
     identifiers::{BenchmarkId, SubmissionId},
     submission::{SubmissionVisibility, VerificationLevel},
@@ -48,6 +56,69 @@ pub struct SubmissionDetail {
     pub submitted_by: String,
     pub submitted_at: String,
     pub metadata: serde_json::Value,
+    /// When the submitter cancelled the submission
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<String>,
+    /// Verification history, oldest first (only when requested)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_history: Option<Vec<VerificationEventResponse>>,
+}
+
+impl From<SubmissionDto> for SubmissionDetail {
+    fn from(dto: SubmissionDto) -> Self {
+        Self {
+            id: dto.id.parse().unwrap_or_default(),
+            benchmark_id: dto.benchmark_id.parse().unwrap_or_default(),
+            benchmark_name: dto.benchmark_id,
+            model_name: dto.model_name,
+            model_version: dto.model_version.unwrap_or_default(),
+            score: dto.aggregate_score,
+            verification_level: dto.verification_level,
+            visibility: dto.visibility,
+            submitted_by: dto.submitter_id,
+            submitted_at: dto.created_at.to_rfc3339(),
+            metadata: serde_json::Value::Null,
+            cancelled_at: dto.cancelled_at.map(|at| at.to_rfc3339()),
+            verification_history: None,
+        }
+    }
+}
+
+/// Verification history entry
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerificationEventResponse {
+    /// Level before the change
+    pub from_level: VerificationLevel,
+    /// Level after the change
+    pub to_level: VerificationLevel,
+    /// Who performed the verification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verifier: Option<String>,
+    /// Reason or notes given by the verifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// When the change happened
+    pub at: String,
+}
+
+impl From<VerificationEventDto> for VerificationEventResponse {
+    fn from(dto: VerificationEventDto) -> Self {
+        Self {
+            from_level: dto.from_level,
+            to_level: dto.to_level,
+            verifier: dto.verifier,
+            reason: dto.reason,
+            at: dto.at.to_rfc3339(),
+        }
+    }
+}
+
+/// Query parameters for fetching a submission
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct SubmissionDetailQuery {
+    /// Include the verification history in the response
+    #[serde(default)]
+    pub include_verification_history: bool,
 }
 
 /// Create submission request
@@ -77,6 +148,17 @@ pub struct UpdateVisibilityRequest {
     pub visibility: SubmissionVisibility,
 }
 
+/// Cancellation response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SubmissionCancellation {
+    /// Cancelled submission
+    pub id: SubmissionId,
+    /// Always `cancelled`
+    pub status: String,
+    /// When the submission was cancelled
+    pub cancelled_at: String,
+}
+
 /// Submission routes
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -90,6 +172,11 @@ pub fn routes() -> Router<AppState> {
             post(request_verification),
         )
         .route("/submissions/:id/visibility", patch(update_visibility))
+        .route("/submissions/:id/cancel", post(cancel_submission))
+        .route(
+            "/submissions/:id/verification/stream",
+            get(stream_verification),
+        )
 }
 
 /// Create submission
@@ -140,6 +227,8 @@ async fn create_submission(
         submitted_by: user.user_id.to_string(),
         submitted_at: chrono::Utc::now().to_rfc3339(),
         metadata: req.metadata.unwrap_or(serde_json::Value::Null),
+        cancelled_at: None,
+        verification_history: None,
     };
 
     Ok(Created(submission))
@@ -154,6 +243,7 @@ async fn create_submission(
     tag = "submissions",
     params(
         ("id" = Uuid, Path, description = "Submission ID"),
+        ("include_verification_history" = Option<bool>, Query, description = "Include the verification history"),
     ),
     responses(
         (status = 200, description = "Submission details", body = SubmissionDetail),
@@ -161,13 +251,99 @@ async fn create_submission(
     )
 )]
 async fn get_submission(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    user: MaybeAuthenticatedUser,
     Path(id): Path<Uuid>,
+    Query(query): Query<SubmissionDetailQuery>,
+    exec: OptionalExecutionContext,
 ) -> ApiResult<Json<ApiResponse<SubmissionDetail>>> {
-    let _submission_id = SubmissionId::from(id);
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let ctx = build_service_context(user.user(), &request_id, exec.0);
+    let id = id.to_string();
 
-    // In production: Query database
-    Err(ApiError::NotFound)
+    let submission = state
+        .submission_service
+        .get_by_id(&ctx, &id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let mut detail = SubmissionDetail::from(submission);
+    if query.include_verification_history {
+        detail.verification_history = state
+            .submission_service
+            .get_verification_history(&ctx, &id)
+            .await?
+            .map(|events| events.into_iter().map(Into::into).collect());
+    }
+
+    Ok(Json(ApiResponse::success(detail)))
+}
+
+/// Stream verification progress
+///
+/// Server-Sent Events for a submission's verification. Each event is named
+/// after its stage (`queued`, `running`, `verified` or `failed`) and carries
+/// the progress update as JSON. The first event is the submission's current
+/// stage, when known, and the stream closes after a terminal stage, at once
+/// if the submission has already finished verification.
+#[utoipa::path(
+    get,
+    path = "/submissions/{id}/verification/stream",
+    tag = "submissions",
+    params(
+        ("id" = Uuid, Path, description = "Submission ID"),
+    ),
+    responses(
+        (status = 200, description = "Event stream of verification progress", content_type = "text/event-stream"),
+        (status = 404, description = "Submission not found"),
+    )
+)]
+async fn stream_verification(
+    State(state): State<AppState>,
+    user: MaybeAuthenticatedUser,
+    Path(id): Path<Uuid>,
+    exec: OptionalExecutionContext,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, axum::Error>>>> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let ctx = build_service_context(user.user(), &request_id, exec.0);
+    let id = id.to_string();
+
+    // Only callers who can see the submission may follow it
+    let submission = state
+        .submission_service
+        .get_by_id(&ctx, &id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    // Subscribe before reading the current stage so no update falls between
+    let updates = state.verification_progress.subscribe(&id).await?;
+    let current = match state.verification_progress.latest(&id).await? {
+        Some(progress) => Some(progress),
+        // Verified without a worker, e.g. by a reviewer
+        None if submission.verification_level != VerificationLevel::Unverified => {
+            Some(VerificationProgress::new(&id, VerificationStage::Verified))
+        }
+        None => None,
+    };
+
+    let progress = stream::iter(current).chain(updates);
+
+    // End right after a terminal stage, without waiting for another update
+    let events = stream::unfold((progress, false), |(mut progress, finished)| async move {
+        if finished {
+            return None;
+        }
+        let next = progress.next().await?;
+        let finished = next.stage.is_terminal();
+        Some((next, (progress, finished)))
+    })
+    .map(|progress| {
+        Event::default()
+            .event(progress.stage.as_str())
+            .json_data(&progress)
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
 }
 
 /// List benchmark submissions
@@ -240,6 +416,52 @@ async fn request_verification(
     Err(ApiError::NotFound)
 }
 
+/// Cancel submission
+///
+/// Withdraw an unverified submission. Only its submitter may cancel it, and
+/// a cancelled submission no longer appears on leaderboards.
+#[utoipa::path(
+    post,
+    path = "/submissions/{id}/cancel",
+    tag = "submissions",
+    params(
+        ("id" = Uuid, Path, description = "Submission ID"),
+    ),
+    responses(
+        (status = 200, description = "Submission cancelled", body = SubmissionCancellation),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the submitter"),
+        (status = 404, description = "Submission not found"),
+        (status = 409, description = "Submission already verified or cancelled"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+async fn cancel_submission(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<Uuid>,
+    exec: OptionalExecutionContext,
+) -> ApiResult<Json<SubmissionCancellation>> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let ctx = build_service_context(Some(&user), &request_id, exec.0);
+
+    let submission = state
+        .submission_service
+        .cancel(&ctx, &id.to_string())
+        .await?;
+    let cancelled_at = submission.cancelled_at.ok_or_else(|| {
+        ApiError::Internal("Cancelled submission has no cancellation time".to_string())
+    })?;
+
+    Ok(Json(SubmissionCancellation {
+        id: SubmissionId::from(id),
+        status: "cancelled".to_string(),
+        cancelled_at: cancelled_at.to_rfc3339(),
+    }))
+}
+
 /// Update visibility
 ///
 /// Update the visibility setting of a submission.
@@ -272,3 +494,320 @@ async fn update_visibility(
     // In production: Update visibility in database
     Err(ApiError::NotFound)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ApiConfig;
+    use axum::{body::Body, http::Request};
+    use crate::state::InMemoryVerificationProgress;
+    use llm_benchmark_application::{
+        services::ServiceContext,
+        validation::{
+            CreateSubmissionRequest as CreateSubmission, SubmissionResultsInput,
+            TestCaseResultInput, VerificationRequest,
+        },
+    };
+    use llm_benchmark_domain::user::UserRole;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn unverified_submission(state: &AppState) -> String {
+        submission_by(state, &Uuid::new_v4().to_string()).await
+    }
+
+    async fn submission_by(state: &AppState, submitter_id: &str) -> String {
+        let submitter = ServiceContext::authenticated(submitter_id.to_string(), "test".to_string());
+        let submission = state
+            .submission_service
+            .create(
+                &submitter,
+                CreateSubmission {
+                    benchmark_id: Uuid::new_v4().to_string(),
+                    benchmark_version_id: Uuid::new_v4().to_string(),
+                    model_provider: "acme".to_string(),
+                    model_name: "acme-1".to_string(),
+                    model_version: Some("1.0".to_string()),
+                    results: SubmissionResultsInput {
+                        aggregate_score: 0.9,
+                        metric_scores: Default::default(),
+                        test_case_results: vec![TestCaseResultInput {
+                            test_case_id: "case-1".to_string(),
+                            passed: true,
+                            score: 0.9,
+                            latency_ms: None,
+                            tokens_generated: None,
+                        }],
+                    },
+                    visibility: SubmissionVisibility::Public,
+                },
+            )
+            .await
+            .unwrap();
+
+        submission.id
+    }
+
+    async fn verified_submission(state: &AppState) -> String {
+        let id = unverified_submission(state).await;
+        let verifier = ServiceContext::authenticated(Uuid::new_v4().to_string(), "test".to_string())
+            .with_role(UserRole::Reviewer);
+        for (level, notes) in [
+            (VerificationLevel::CommunityVerified, "Reproduced locally"),
+            (VerificationLevel::PlatformVerified, "Platform rerun"),
+        ] {
+            state
+                .submission_service
+                .verify(
+                    &verifier,
+                    VerificationRequest {
+                        submission_id: id.clone(),
+                        verification_level: level,
+                        reproduced_score: Some(0.89),
+                        score_variance: Some(0.01),
+                        environment_match: Some(true),
+                        notes: Some(notes.to_string()),
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        id
+    }
+
+    async fn get_detail(state: &AppState, uri: &str) -> serde_json::Value {
+        let response = routes()
+            .with_state(state.clone())
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_submission_includes_verification_history_on_request() {
+        let state = AppState::new(ApiConfig::default());
+        let id = verified_submission(&state).await;
+
+        let body = get_detail(&state, &format!("/submissions/{}", id)).await;
+        assert_eq!(body["data"]["verification_level"], "platform_verified");
+        assert!(body["data"].get("verification_history").is_none());
+
+        let body = get_detail(
+            &state,
+            &format!("/submissions/{}?include_verification_history=true", id),
+        )
+        .await;
+        let history = body["data"]["verification_history"].as_array().unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0]["from_level"], "unverified");
+        assert_eq!(history[0]["to_level"], "community_verified");
+        assert_eq!(history[0]["reason"], "Reproduced locally");
+        assert_eq!(history[1]["from_level"], "community_verified");
+        assert_eq!(history[1]["to_level"], "platform_verified");
+    }
+
+    async fn open_stream(state: AppState, id: &str) -> axum::response::Response {
+        let response = routes()
+            .with_state(state)
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/submissions/{}/verification/stream", id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        response
+    }
+
+    /// Read a stream to its end as `(event name, data)` pairs
+    async fn stream_events(response: axum::response::Response) -> Vec<(String, serde_json::Value)> {
+        let body = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            axum::body::to_bytes(response.into_body(), usize::MAX),
+        )
+        .await
+        .expect("stream did not close after the terminal event")
+        .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        body.split("\n\n")
+            .filter(|frame| !frame.trim().is_empty())
+            .map(|frame| {
+                let field = |name: &str| {
+                    frame
+                        .lines()
+                        .find_map(|line| line.strip_prefix(name))
+                        .unwrap()
+                };
+                (field("event: ").to_string(), serde_json::from_str(field("data: ")).unwrap())
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_verification_stream_ends_with_terminal_event() {
+        let progress = Arc::new(InMemoryVerificationProgress::new());
+        let state = AppState::new(ApiConfig::default()).with_verification_progress(progress.clone());
+        let id = unverified_submission(&state).await;
+
+        // The handler has subscribed by the time the response is returned
+        let response = open_stream(state, &id).await;
+        for (submission_id, stage) in [
+            (id.as_str(), VerificationStage::Queued),
+            ("another-submission", VerificationStage::Failed),
+            (id.as_str(), VerificationStage::Running),
+            (id.as_str(), VerificationStage::Verified),
+            (id.as_str(), VerificationStage::Failed),
+        ] {
+            progress.publish(VerificationProgress::new(submission_id, stage));
+        }
+
+        // The body only completes because the stream closes after `verified`
+        let events = stream_events(response).await;
+        let stages: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(stages, ["queued", "running", "verified"]);
+        assert_eq!(events[2].1["submission_id"], id);
+        assert_eq!(events[2].1["stage"], "verified");
+    }
+
+    #[tokio::test]
+    async fn test_verification_stream_starts_with_current_stage() {
+        let progress = Arc::new(InMemoryVerificationProgress::new());
+        let state = AppState::new(ApiConfig::default()).with_verification_progress(progress.clone());
+        let id = unverified_submission(&state).await;
+        progress.publish(VerificationProgress::new(&id, VerificationStage::Queued));
+        progress.publish(VerificationProgress::new(&id, VerificationStage::Running));
+
+        let response = open_stream(state, &id).await;
+        progress.publish(VerificationProgress::new(&id, VerificationStage::Verified));
+
+        let stages: Vec<String> =
+            stream_events(response).await.into_iter().map(|(name, _)| name).collect();
+        assert_eq!(stages, ["running", "verified"]);
+    }
+
+    #[tokio::test]
+    async fn test_verification_stream_closes_when_already_finished() {
+        let progress = Arc::new(InMemoryVerificationProgress::new());
+        let state = AppState::new(ApiConfig::default()).with_verification_progress(progress.clone());
+        let id = unverified_submission(&state).await;
+        progress.publish(
+            VerificationProgress::new(&id, VerificationStage::Failed).with_message("Scores differ"),
+        );
+
+        let events = stream_events(open_stream(state, &id).await).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "failed");
+        assert_eq!(events[0].1["message"], "Scores differ");
+
+        // Verified by a reviewer, so no progress was ever published
+        let state = AppState::new(ApiConfig::default())
+            .with_verification_progress(Arc::new(InMemoryVerificationProgress::new()));
+        let id = verified_submission(&state).await;
+
+        let events = stream_events(open_stream(state, &id).await).await;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "verified");
+        assert_eq!(events[0].1["submission_id"], id);
+    }
+
+    #[tokio::test]
+    async fn test_verification_stream_unknown_submission() {
+        let response = routes()
+            .with_state(AppState::new(ApiConfig::default()))
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/submissions/{}/verification/stream", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+    }
+
+    fn bearer_token(state: &AppState, user_id: &str) -> String {
+        use crate::extractors::auth::Claims;
+        use jsonwebtoken::{encode, EncodingKey, Header};
+
+        let now = chrono::Utc::now().timestamp() as usize;
+        let claims = Claims {
+            sub: user_id.to_string(),
+            role: UserRole::Registered,
+            exp: now + 3600,
+            iat: now,
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(state.jwt_secret().as_bytes()),
+        )
+        .unwrap()
+    }
+
+    async fn cancel(state: &AppState, id: &str, user_id: &str) -> axum::response::Response {
+        routes()
+            .with_state(state.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/submissions/{}/cancel", id))
+                    .header("authorization", format!("Bearer {}", bearer_token(state, user_id)))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cancel_submission() {
+        let state = AppState::new(ApiConfig::default());
+        let submitter = Uuid::new_v4().to_string();
+        let id = submission_by(&state, &submitter).await;
+
+        let response = cancel(&state, &id, &Uuid::new_v4().to_string()).await;
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+
+        let response = cancel(&state, &id, &submitter).await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["id"], id);
+        assert_eq!(body["status"], "cancelled");
+        assert!(body["cancelled_at"].is_string());
+
+        let response = cancel(&state, &id, &submitter).await;
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+
+        let response = cancel(&state, &Uuid::new_v4().to_string(), &submitter).await;
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_verified_submission_conflicts() {
+        let state = AppState::new(ApiConfig::default());
+        let id = verified_submission(&state).await;
+        let submitter = state
+            .submission_service
+            .get_by_id(&ServiceContext::anonymous("test".to_string()), &id)
+            .await
+            .unwrap()
+            .unwrap()
+            .submitter_id;
+
+        let response = cancel(&state, &id, &submitter).await;
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+    }
+}
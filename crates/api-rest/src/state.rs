@@ -3,25 +3,42 @@
 //! This module defines the shared application state that is passed
 //! to all route handlers via Axum's state extraction.
 
+use crate::adapters::{
+    MessagingEventPublisher, MessagingVerificationProgress, PgBenchmarkStore, PgOrganizationStore,
+    PgSubmissionStore, PgUserStore,
+};
 use crate::config::ApiConfig;
+use crate::middleware::MaintenanceMode;
 use async_trait::async_trait;
 use llm_benchmark_application::{
     services::{
         Authorizer, AuthorizationResult, BenchmarkDto, BenchmarkFilters, BenchmarkRepositoryPort,
         BenchmarkService, BenchmarkVersionDto, CreateBenchmarkData, CreateVersionData,
-        CreateSubmissionData, DefaultAuthorizer, EventPublisher, LeaderboardEntryDto,
+        CreateSubmissionData, EventPublisher, LeaderboardEntryDto, LeaderboardViewer,
+        CreateOrganizationData, OrganizationDto, OrganizationMemberDto, OrganizationRepositoryPort,
+        OwnershipAuthorizer, UpdateOrganizationData,
         NoOpEventPublisher, Pagination, PaginatedResult, ServiceConfig, ServiceContext,
         ServiceEvent, SubmissionDto, SubmissionRepositoryPort, SubmissionService,
         UpdateBenchmarkData, UpdateSubmissionData, UserDto, UserProfileDto, UserRepositoryPort,
-        UserService, ApiKeyDto, ApiKeyWithSecretDto, CreateApiKeyData, CreateUserData,
-        UpdateUserData, VerificationData, PasswordHasher, Argon2PasswordHasher,
+        UserService, ApiKeyDto, ApiKeyFilters, ApiKeyPrincipal, DeletionMode, GovernanceActivityDto, ApiKeyWithSecretDto, CreateApiKeyData, CreateUserData,
+        UpdateUserData, VerificationData, VerificationEventDto, PasswordHasher, Argon2PasswordHasher,
+        VerificationProgress, trending_scores,
     },
-    validation::SubmissionQueryFilters,
+    validation::{OrganizationRole, SubmissionQueryFilters},
     ApplicationError,
 };
+use llm_benchmark_common::crypto::content_hash;
 use llm_benchmark_domain::benchmark::BenchmarkStatus;
-use llm_benchmark_domain::submission::{SubmissionResults, VerificationLevel};
+use llm_benchmark_domain::evaluation::{MetricRange, ScorePrecision};
+use llm_benchmark_domain::identifiers::{
+    generate_id, BenchmarkId, BenchmarkVersionId, OrganizationId, SubmissionId, UserId,
+};
+use llm_benchmark_domain::submission::{SubmissionResults, SubmissionVisibility, VerificationLevel};
+use llm_benchmark_domain::test_case::TestCase;
+use futures::stream::{self, BoxStream, StreamExt};
+use llm_benchmark_infrastructure::{Cache, DatabasePool, Publisher, Subscriber};
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 /// Application state shared across all requests
 #[derive(Clone)]
@@ -32,6 +49,9 @@ pub struct AppState {
     /// JWT encoding/decoding key
     pub jwt_secret: Arc<String>,
 
+    /// Runtime maintenance-mode toggle
+    pub maintenance: MaintenanceMode,
+
     /// Benchmark service (type-erased)
     pub benchmark_service: Arc<dyn BenchmarkServiceTrait>,
 
@@ -40,6 +60,18 @@ pub struct AppState {
 
     /// User service (type-erased)
     pub user_service: Arc<dyn UserServiceTrait>,
+
+    /// Source of submission verification progress updates
+    pub verification_progress: Arc<dyn VerificationProgressSource>,
+}
+
+/// Service settings derived from the API configuration
+fn service_config(config: &ApiConfig) -> ServiceConfig {
+    ServiceConfig {
+        reject_duplicate_submissions: config.reject_duplicate_submissions,
+        max_test_case_payload_bytes: config.max_test_case_payload_bytes,
+        ..ServiceConfig::default()
+    }
 }
 
 impl AppState {
@@ -47,13 +79,18 @@ impl AppState {
     /// Suitable for development and testing
     pub fn new(config: ApiConfig) -> Self {
         let jwt_secret = config.jwt_secret.clone();
-        let service_config = ServiceConfig::default();
+        let service_config = service_config(&config);
 
         // Create default implementations
         let benchmark_repo = Arc::new(InMemoryBenchmarkRepository::new());
-        let submission_repo = Arc::new(InMemorySubmissionRepository::new());
+        let submission_repo =
+            Arc::new(InMemorySubmissionRepository::new().with_benchmarks(Arc::clone(&benchmark_repo)));
         let user_repo = Arc::new(InMemoryUserRepository::new());
-        let authorizer = Arc::new(DefaultAuthorizer);
+        let organization_repo = Arc::new(InMemoryOrganizationRepository::new(Arc::clone(&user_repo)));
+        let authorizer = Arc::new(OwnershipAuthorizer::new(
+            Arc::clone(&benchmark_repo),
+            Arc::clone(&organization_repo),
+        ));
         let event_publisher = Arc::new(NoOpEventPublisher);
         let password_hasher = Arc::new(Argon2PasswordHasher);
 
@@ -64,29 +101,91 @@ impl AppState {
             service_config.clone(),
         ));
 
-        let submission_service = Arc::new(SubmissionService::new(
-            submission_repo,
-            Arc::clone(&authorizer),
-            Arc::clone(&event_publisher),
-            service_config.clone(),
-        ));
-
-        let user_service = Arc::new(UserService::new(
-            user_repo,
-            Arc::clone(&event_publisher),
-            password_hasher,
-            service_config,
-        ));
+        let submission_service = Arc::new(
+            SubmissionService::new(
+                Arc::clone(&submission_repo),
+                Arc::clone(&authorizer),
+                Arc::clone(&event_publisher),
+                service_config.clone(),
+            )
+            .with_organizations(Arc::clone(&organization_repo) as _),
+        );
+
+        let user_service = Arc::new(
+            UserService::new(
+                user_repo,
+                submission_repo,
+                Arc::clone(&event_publisher),
+                password_hasher,
+                service_config,
+            )
+            .with_organization_keys(organization_repo),
+        );
 
         Self {
+            maintenance: MaintenanceMode::from_config(&config),
             config: Arc::new(config),
             jwt_secret: Arc::new(jwt_secret),
             benchmark_service,
             submission_service,
             user_service,
+            verification_progress: Arc::new(InMemoryVerificationProgress::new()),
         }
     }
 
+    /// Create application state backed by PostgreSQL repositories, the given
+    /// cache for benchmark lookups, and a message broker for service events
+    pub fn from_infrastructure<C, P>(
+        config: ApiConfig,
+        db: &DatabasePool,
+        cache: Arc<C>,
+        publisher: Arc<P>,
+    ) -> Self
+    where
+        C: Cache + 'static,
+        P: Publisher + Subscriber + 'static,
+    {
+        let service_config = service_config(&config);
+        let benchmark_store = Arc::new(PgBenchmarkStore::new(db, cache, &service_config));
+        let organization_store = Arc::new(PgOrganizationStore::new(db));
+        let authorizer = Arc::new(OwnershipAuthorizer::new(
+            Arc::clone(&benchmark_store),
+            Arc::clone(&organization_store),
+        ));
+        let verification_progress =
+            Arc::new(MessagingVerificationProgress::new(Arc::clone(&publisher)));
+        let event_publisher = Arc::new(MessagingEventPublisher::new(publisher));
+        let password_hasher = Arc::new(Argon2PasswordHasher);
+
+        let benchmark_service = BenchmarkService::new(
+            benchmark_store,
+            Arc::clone(&authorizer),
+            Arc::clone(&event_publisher),
+            service_config.clone(),
+        );
+
+        let submission_store = Arc::new(PgSubmissionStore::new(db));
+        let submission_service = SubmissionService::new(
+            Arc::clone(&submission_store),
+            Arc::clone(&authorizer),
+            Arc::clone(&event_publisher),
+            service_config.clone(),
+        )
+        .with_organizations(Arc::clone(&organization_store) as _);
+
+        let user_service = UserService::new(
+            Arc::new(PgUserStore::new(db, Arc::clone(&password_hasher))),
+            submission_store,
+            event_publisher,
+            password_hasher,
+            service_config,
+        )
+        .with_organization_keys(organization_store);
+
+        Self::with_services(config, benchmark_service, submission_service, user_service)
+            .with_verification_progress(verification_progress)
+    }
+
     /// Create application state with custom service implementations
     pub fn with_services<B, S, U>(
         config: ApiConfig,
@@ -102,14 +201,25 @@ impl AppState {
         let jwt_secret = config.jwt_secret.clone();
 
         Self {
+            maintenance: MaintenanceMode::from_config(&config),
             config: Arc::new(config),
             jwt_secret: Arc::new(jwt_secret),
             benchmark_service: Arc::new(benchmark_service),
             submission_service: Arc::new(submission_service),
             user_service: Arc::new(user_service),
+            verification_progress: Arc::new(InMemoryVerificationProgress::new()),
         }
     }
 
+    /// Replace the source the verification progress stream reads from
+    pub fn with_verification_progress(
+        mut self,
+        verification_progress: Arc<dyn VerificationProgressSource>,
+    ) -> Self {
+        self.verification_progress = verification_progress;
+        self
+    }
+
     /// Get JWT secret
     pub fn jwt_secret(&self) -> &str {
         &self.jwt_secret
@@ -226,6 +336,14 @@ pub trait SubmissionServiceTrait: Send + Sync {
         request: llm_benchmark_application::validation::VerificationRequest,
     ) -> Result<SubmissionDto, ApplicationError>;
 
+    /// Verification changes of a submission, oldest first; `None` if the
+    /// submission doesn't exist or isn't visible to the caller
+    async fn get_verification_history(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<Option<Vec<VerificationEventDto>>, ApplicationError>;
+
     async fn get_leaderboard(
         &self,
         ctx: &ServiceContext,
@@ -240,6 +358,18 @@ pub trait SubmissionServiceTrait: Send + Sync {
     ) -> Result<PaginatedResult<SubmissionDto>, ApplicationError>;
 
     async fn delete(&self, ctx: &ServiceContext, id: &str) -> Result<(), ApplicationError>;
+
+    /// Withdraw an unverified submission on behalf of its submitter
+    async fn cancel(&self, ctx: &ServiceContext, id: &str) -> Result<SubmissionDto, ApplicationError>;
+
+    /// Up to `limit` benchmark IDs with their trending score, highest first;
+    /// only benchmarks with a public submission within `window` are ranked
+    async fn trending_benchmarks(
+        &self,
+        ctx: &ServiceContext,
+        window: std::time::Duration,
+        limit: u32,
+    ) -> Result<Vec<(String, f64)>, ApplicationError>;
 }
 
 /// Type-erased user service trait
@@ -292,7 +422,13 @@ pub trait UserServiceTrait: Send + Sync {
         request: llm_benchmark_application::validation::CreateApiKeyRequest,
     ) -> Result<ApiKeyWithSecretDto, ApplicationError>;
 
-    async fn list_api_keys(&self, ctx: &ServiceContext) -> Result<Vec<ApiKeyDto>, ApplicationError>;
+    /// List the caller's API keys
+    async fn list_api_keys(
+        &self,
+        ctx: &ServiceContext,
+        filters: ApiKeyFilters,
+        pagination: Pagination,
+    ) -> Result<PaginatedResult<ApiKeyDto>, ApplicationError>;
 
     async fn revoke_api_key(
         &self,
@@ -303,9 +439,32 @@ pub trait UserServiceTrait: Send + Sync {
     async fn verify_api_key(
         &self,
         key_secret: &str,
-    ) -> Result<Option<(String, Vec<String>)>, ApplicationError>;
+    ) -> Result<Option<(ApiKeyPrincipal, Vec<String>)>, ApplicationError>;
 
-    async fn delete(&self, ctx: &ServiceContext, id: &str) -> Result<(), ApplicationError>;
+    /// Delete a user account, either removing everything it owns or
+    /// anonymizing it and keeping its content under the tombstone user
+    async fn delete(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+        mode: DeletionMode,
+    ) -> Result<(), ApplicationError>;
+}
+
+/// Source of verification progress for the submission SSE stream
+#[async_trait]
+pub trait VerificationProgressSource: Send + Sync {
+    /// Updates for `submission_id` published from now on
+    async fn subscribe(
+        &self,
+        submission_id: &str,
+    ) -> Result<BoxStream<'static, VerificationProgress>, ApplicationError>;
+
+    /// The most recent update published for `submission_id`, if any
+    async fn latest(
+        &self,
+        submission_id: &str,
+    ) -> Result<Option<VerificationProgress>, ApplicationError>;
 }
 
 // ============================================================================
@@ -458,6 +617,14 @@ where
         SubmissionService::verify(self, ctx, request).await
     }
 
+    async fn get_verification_history(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<Option<Vec<VerificationEventDto>>, ApplicationError> {
+        SubmissionService::get_verification_history(self, ctx, id).await
+    }
+
     async fn get_leaderboard(
         &self,
         ctx: &ServiceContext,
@@ -478,6 +645,19 @@ where
     async fn delete(&self, ctx: &ServiceContext, id: &str) -> Result<(), ApplicationError> {
         SubmissionService::delete(self, ctx, id).await
     }
+
+    async fn cancel(&self, ctx: &ServiceContext, id: &str) -> Result<SubmissionDto, ApplicationError> {
+        SubmissionService::cancel(self, ctx, id).await
+    }
+
+    async fn trending_benchmarks(
+        &self,
+        ctx: &ServiceContext,
+        window: std::time::Duration,
+        limit: u32,
+    ) -> Result<Vec<(String, f64)>, ApplicationError> {
+        SubmissionService::trending_benchmarks(self, ctx, window, limit).await
+    }
 }
 
 #[async_trait]
@@ -552,8 +732,13 @@ where
         UserService::create_api_key(self, ctx, request).await
     }
 
-    async fn list_api_keys(&self, ctx: &ServiceContext) -> Result<Vec<ApiKeyDto>, ApplicationError> {
-        UserService::list_api_keys(self, ctx).await
+    async fn list_api_keys(
+        &self,
+        ctx: &ServiceContext,
+        filters: ApiKeyFilters,
+        pagination: Pagination,
+    ) -> Result<PaginatedResult<ApiKeyDto>, ApplicationError> {
+        UserService::list_api_keys(self, ctx, filters, pagination).await
     }
 
     async fn revoke_api_key(
@@ -567,12 +752,17 @@ where
     async fn verify_api_key(
         &self,
         key_secret: &str,
-    ) -> Result<Option<(String, Vec<String>)>, ApplicationError> {
+    ) -> Result<Option<(ApiKeyPrincipal, Vec<String>)>, ApplicationError> {
         UserService::verify_api_key(self, key_secret).await
     }
 
-    async fn delete(&self, ctx: &ServiceContext, id: &str) -> Result<(), ApplicationError> {
-        UserService::delete(self, ctx, id).await
+    async fn delete(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+        mode: DeletionMode,
+    ) -> Result<(), ApplicationError> {
+        UserService::delete(self, ctx, id, mode).await
     }
 }
 
@@ -587,6 +777,8 @@ use std::collections::HashMap;
 pub struct InMemoryBenchmarkRepository {
     benchmarks: RwLock<HashMap<String, BenchmarkDto>>,
     versions: RwLock<HashMap<String, Vec<BenchmarkVersionDto>>>,
+    test_cases: RwLock<HashMap<String, Vec<TestCase>>>,
+    leaderboard_levels: RwLock<HashMap<String, VerificationLevel>>,
 }
 
 impl InMemoryBenchmarkRepository {
@@ -594,8 +786,15 @@ impl InMemoryBenchmarkRepository {
         Self {
             benchmarks: RwLock::new(HashMap::new()),
             versions: RwLock::new(HashMap::new()),
+            test_cases: RwLock::new(HashMap::new()),
+            leaderboard_levels: RwLock::new(HashMap::new()),
         }
     }
+
+    /// The benchmark's default leaderboard verification minimum
+    pub fn leaderboard_min_verification(&self, benchmark_id: &str) -> VerificationLevel {
+        self.leaderboard_levels.read().get(benchmark_id).copied().unwrap_or_default()
+    }
 }
 
 impl Default for InMemoryBenchmarkRepository {
@@ -607,7 +806,7 @@ impl Default for InMemoryBenchmarkRepository {
 #[async_trait]
 impl BenchmarkRepositoryPort for InMemoryBenchmarkRepository {
     async fn create(&self, data: &CreateBenchmarkData) -> Result<String, ApplicationError> {
-        let id = uuid::Uuid::new_v4().to_string();
+        let id = BenchmarkId::new().to_string();
         let now = chrono::Utc::now();
 
         let benchmark = BenchmarkDto {
@@ -620,11 +819,16 @@ impl BenchmarkRepositoryPort for InMemoryBenchmarkRepository {
             tags: data.tags.clone(),
             current_version: Some(data.version.clone()),
             submission_count: 0,
+            created_by: Some(data.creator_id.clone()),
             created_at: now,
             updated_at: now,
         };
 
         self.benchmarks.write().insert(id.clone(), benchmark);
+        self.leaderboard_levels
+            .write()
+            .insert(id.clone(), data.leaderboard_min_verification);
+        self.test_cases.write().insert(id.clone(), data.test_cases.clone());
         Ok(id)
     }
 
@@ -690,6 +894,9 @@ impl BenchmarkRepositoryPort for InMemoryBenchmarkRepository {
             if let Some(ref tags) = update.tags {
                 benchmark.tags = tags.clone();
             }
+            if let Some(ref test_cases) = update.test_cases {
+                self.test_cases.write().insert(id.to_string(), test_cases.clone());
+            }
             benchmark.updated_at = chrono::Utc::now();
             Ok(())
         } else {
@@ -709,8 +916,11 @@ impl BenchmarkRepositoryPort for InMemoryBenchmarkRepository {
     }
 
     async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
-        self.benchmarks.write().remove(id);
+        if self.benchmarks.write().remove(id).is_none() {
+            return Err(ApplicationError::NotFound(format!("Benchmark not found: {}", id)));
+        }
         self.versions.write().remove(id);
+        self.test_cases.write().remove(id);
         Ok(())
     }
 
@@ -719,7 +929,19 @@ impl BenchmarkRepositoryPort for InMemoryBenchmarkRepository {
     }
 
     async fn create_version(&self, data: &CreateVersionData) -> Result<String, ApplicationError> {
-        let id = uuid::Uuid::new_v4().to_string();
+        let exists = self
+            .versions
+            .read()
+            .get(&data.benchmark_id)
+            .is_some_and(|versions| versions.iter().any(|v| v.version == data.version));
+        if exists {
+            return Err(ApplicationError::Conflict(format!(
+                "Version {} already exists for benchmark {}",
+                data.version, data.benchmark_id
+            )));
+        }
+
+        let id = BenchmarkVersionId::new().to_string();
         let now = chrono::Utc::now();
 
         let version = BenchmarkVersionDto {
@@ -737,18 +959,30 @@ impl BenchmarkRepositoryPort for InMemoryBenchmarkRepository {
             .or_default()
             .push(version);
 
+        if let Some(ref test_cases) = data.test_cases {
+            self.test_cases
+                .write()
+                .insert(data.benchmark_id.clone(), test_cases.clone());
+        }
+
         Ok(id)
     }
 
     async fn get_versions(&self, benchmark_id: &str) -> Result<Vec<BenchmarkVersionDto>, ApplicationError> {
         Ok(self.versions.read().get(benchmark_id).cloned().unwrap_or_default())
     }
+
+    async fn get_test_cases(&self, benchmark_id: &str) -> Result<Vec<TestCase>, ApplicationError> {
+        Ok(self.test_cases.read().get(benchmark_id).cloned().unwrap_or_default())
+    }
 }
 
 /// In-memory submission repository for development
 pub struct InMemorySubmissionRepository {
     submissions: RwLock<HashMap<String, SubmissionDto>>,
     results: RwLock<HashMap<String, SubmissionResults>>,
+    verification_events: RwLock<HashMap<String, Vec<VerificationEventDto>>>,
+    benchmarks: Option<Arc<InMemoryBenchmarkRepository>>,
 }
 
 impl InMemorySubmissionRepository {
@@ -756,8 +990,16 @@ impl InMemorySubmissionRepository {
         Self {
             submissions: RwLock::new(HashMap::new()),
             results: RwLock::new(HashMap::new()),
+            verification_events: RwLock::new(HashMap::new()),
+            benchmarks: None,
         }
     }
+
+    /// Read per-benchmark leaderboard settings from `benchmarks`
+    pub fn with_benchmarks(mut self, benchmarks: Arc<InMemoryBenchmarkRepository>) -> Self {
+        self.benchmarks = Some(benchmarks);
+        self
+    }
 }
 
 impl Default for InMemorySubmissionRepository {
@@ -769,7 +1011,7 @@ impl Default for InMemorySubmissionRepository {
 #[async_trait]
 impl SubmissionRepositoryPort for InMemorySubmissionRepository {
     async fn create(&self, data: &CreateSubmissionData) -> Result<String, ApplicationError> {
-        let id = uuid::Uuid::new_v4().to_string();
+        let id = SubmissionId::new().to_string();
         let now = chrono::Utc::now();
 
         let submission = SubmissionDto {
@@ -784,6 +1026,7 @@ impl SubmissionRepositoryPort for InMemorySubmissionRepository {
             aggregate_score: data.aggregate_score,
             verification_level: VerificationLevel::Unverified,
             visibility: data.visibility.clone(),
+            cancelled_at: None,
             created_at: now,
             updated_at: now,
         };
@@ -815,7 +1058,7 @@ impl SubmissionRepositoryPort for InMemorySubmissionRepository {
                     }
                 }
                 if let Some(ref level) = filters.verification_level {
-                    if (s.verification_level as u8) < (*level as u8) {
+                    if !s.verification_level.meets(*level) {
                         return false;
                     }
                 }
@@ -857,30 +1100,78 @@ impl SubmissionRepositoryPort for InMemorySubmissionRepository {
     ) -> Result<(), ApplicationError> {
         let mut submissions = self.submissions.write();
         if let Some(submission) = submissions.get_mut(id) {
+            let now = chrono::Utc::now();
+            self.verification_events
+                .write()
+                .entry(id.to_string())
+                .or_default()
+                .push(VerificationEventDto {
+                    submission_id: id.to_string(),
+                    from_level: submission.verification_level,
+                    to_level: verification.level,
+                    verifier: Some(verification.verified_by.clone()),
+                    reason: verification.notes.clone(),
+                    at: now,
+                });
             submission.verification_level = verification.level.clone();
-            submission.updated_at = chrono::Utc::now();
+            submission.updated_at = now;
             Ok(())
         } else {
             Err(ApplicationError::NotFound(format!("Submission not found: {}", id)))
         }
     }
 
+    async fn verification_history(&self, id: &str) -> Result<Vec<VerificationEventDto>, ApplicationError> {
+        Ok(self
+            .verification_events
+            .read()
+            .get(id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
     async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
-        self.submissions.write().remove(id);
+        if self.submissions.write().remove(id).is_none() {
+            return Err(ApplicationError::NotFound(format!("Submission not found: {}", id)));
+        }
         self.results.write().remove(id);
+        self.verification_events.write().remove(id);
         Ok(())
     }
 
+    async fn cancel(
+        &self,
+        id: &str,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ApplicationError> {
+        let mut submissions = self.submissions.write();
+        let Some(submission) = submissions.get_mut(id) else {
+            return Ok(None);
+        };
+        if submission.cancelled_at.is_some()
+            || submission.verification_level != VerificationLevel::Unverified
+        {
+            return Ok(None);
+        }
+
+        let now = chrono::Utc::now();
+        submission.cancelled_at = Some(now);
+        submission.updated_at = now;
+        Ok(Some(now))
+    }
+
     async fn get_leaderboard(
         &self,
         benchmark_id: &str,
         _version_id: Option<&str>,
         limit: u32,
-        _min_verification: Option<VerificationLevel>,
+        min_verification: Option<VerificationLevel>,
+        viewer: &LeaderboardViewer,
     ) -> Result<Vec<LeaderboardEntryDto>, ApplicationError> {
+        let min_level = min_verification.unwrap_or_default();
         let mut entries: Vec<_> = self.submissions.read()
             .values()
-            .filter(|s| s.benchmark_id == benchmark_id)
+            .filter(|s| s.benchmark_id == benchmark_id && viewer.can_see(s))
+            .filter(|s| s.cancelled_at.is_none() && s.verification_level.meets(min_level))
             .cloned()
             .collect();
 
@@ -938,6 +1229,71 @@ impl SubmissionRepositoryPort for InMemorySubmissionRepository {
         self.results.write().insert(id.to_string(), results.clone());
         Ok(())
     }
+
+    async fn score_range(&self, _benchmark_id: &str) -> Result<Option<MetricRange>, ApplicationError> {
+        Ok(None)
+    }
+
+    async fn score_precision(
+        &self,
+        _benchmark_id: &str,
+    ) -> Result<Option<ScorePrecision>, ApplicationError> {
+        Ok(None)
+    }
+
+    async fn leaderboard_min_verification(
+        &self,
+        benchmark_id: &str,
+    ) -> Result<VerificationLevel, ApplicationError> {
+        Ok(self
+            .benchmarks
+            .as_ref()
+            .map(|benchmarks| benchmarks.leaderboard_min_verification(benchmark_id))
+            .unwrap_or_default())
+    }
+
+    async fn find_duplicate(
+        &self,
+        data: &CreateSubmissionData,
+        hash: &str,
+    ) -> Result<Option<String>, ApplicationError> {
+        let results = self.results.read();
+        Ok(self
+            .submissions
+            .read()
+            .values()
+            .filter(|s| {
+                s.benchmark_id == data.benchmark_id
+                    && s.model_provider == data.model_provider
+                    && s.model_name == data.model_name
+                    && s.model_version == data.model_version
+            })
+            .filter(|s| {
+                results
+                    .get(&s.id)
+                    .and_then(|r| content_hash(r).ok())
+                    .is_some_and(|h| h == hash)
+            })
+            .min_by_key(|s| s.created_at)
+            .map(|s| s.id.clone()))
+    }
+
+    async fn trending_benchmarks(
+        &self,
+        window: std::time::Duration,
+        limit: usize,
+    ) -> Result<Vec<(String, f64)>, ApplicationError> {
+        let submissions = self.submissions.read();
+        Ok(trending_scores(
+            submissions
+                .values()
+                .filter(|s| s.visibility == SubmissionVisibility::Public)
+                .map(|s| (s.benchmark_id.as_str(), s.created_at)),
+            chrono::Utc::now(),
+            window,
+            limit,
+        ))
+    }
 }
 
 /// In-memory user repository for development
@@ -945,7 +1301,8 @@ pub struct InMemoryUserRepository {
     users: RwLock<HashMap<String, UserDto>>,
     passwords: RwLock<HashMap<String, String>>,
     api_keys: RwLock<HashMap<String, Vec<ApiKeyDto>>>,
-    api_key_secrets: RwLock<HashMap<String, (String, Vec<String>)>>,
+    /// Secret -> (owner user ID, key ID)
+    api_key_secrets: RwLock<HashMap<String, (String, String)>>,
 }
 
 impl InMemoryUserRepository {
@@ -968,7 +1325,7 @@ impl Default for InMemoryUserRepository {
 #[async_trait]
 impl UserRepositoryPort for InMemoryUserRepository {
     async fn create(&self, data: &CreateUserData) -> Result<String, ApplicationError> {
-        let id = uuid::Uuid::new_v4().to_string();
+        let id = UserId::new().to_string();
         let now = chrono::Utc::now();
 
         let user = UserDto {
@@ -1072,7 +1429,7 @@ impl UserRepositoryPort for InMemoryUserRepository {
     }
 
     async fn create_api_key(&self, user_id: &str, data: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError> {
-        let id = uuid::Uuid::new_v4().to_string();
+        let id = generate_id().to_string();
         let secret = format!("llm_bm_{}_{}", user_id, uuid::Uuid::new_v4());
         let now = chrono::Utc::now();
 
@@ -1083,6 +1440,7 @@ impl UserRepositoryPort for InMemoryUserRepository {
             scopes: data.scopes.clone(),
             last_used_at: None,
             expires_at: data.expires_in_days.map(|d| now + chrono::Duration::days(d as i64)),
+            revoked_at: None,
             created_at: now,
         };
 
@@ -1094,24 +1452,459 @@ impl UserRepositoryPort for InMemoryUserRepository {
 
         self.api_key_secrets
             .write()
-            .insert(secret.clone(), (user_id.to_string(), data.scopes.clone()));
+            .insert(secret.clone(), (user_id.to_string(), id));
 
         Ok(ApiKeyWithSecretDto { key, secret })
     }
 
-    async fn list_api_keys(&self, user_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError> {
-        Ok(self.api_keys.read().get(user_id).cloned().unwrap_or_default())
+    async fn list_api_keys(
+        &self,
+        user_id: &str,
+        filters: &ApiKeyFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<ApiKeyDto>, u64), ApplicationError> {
+        let now = chrono::Utc::now();
+        let keys = self.api_keys.read();
+        let matching: Vec<_> = keys
+            .get(user_id)
+            .into_iter()
+            .flatten()
+            .filter(|k| filters.matches(k, now))
+            .collect();
+
+        let total = matching.len() as u64;
+        let items = matching
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .cloned()
+            .collect();
+
+        Ok((items, total))
     }
 
     async fn revoke_api_key(&self, user_id: &str, key_id: &str) -> Result<(), ApplicationError> {
         let mut keys = self.api_keys.write();
-        if let Some(user_keys) = keys.get_mut(user_id) {
-            user_keys.retain(|k| k.id != key_id);
+        let key = keys
+            .get_mut(user_id)
+            .and_then(|user_keys| user_keys.iter_mut().find(|k| k.id == key_id))
+            .ok_or_else(|| ApplicationError::NotFound(format!("API key not found: {}", key_id)))?;
+        key.revoked_at.get_or_insert_with(chrono::Utc::now);
+        Ok(())
+    }
+
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError> {
+        let Some((user_id, key_id)) = self.api_key_secrets.read().get(key_secret).cloned() else {
+            return Ok(None);
+        };
+        let key = self
+            .api_keys
+            .read()
+            .get(&user_id)
+            .and_then(|keys| keys.iter().find(|k| k.id == key_id).cloned());
+        Ok(key.map(|key| (user_id, key)))
+    }
+
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError> {
+        if let Some(key) = self
+            .api_keys
+            .write()
+            .values_mut()
+            .flatten()
+            .find(|k| k.id == key_id)
+        {
+            key.last_used_at = Some(used_at);
+        }
+        Ok(())
+    }
+
+    async fn get_governance_activity(&self, _user_id: &str) -> Result<Vec<GovernanceActivityDto>, ApplicationError> {
+        Ok(vec![])
+    }
+
+    async fn delete_account(&self, id: &str, _mode: DeletionMode) -> Result<(), ApplicationError> {
+        // No authored content is held by the in-memory user repository, so
+        // both modes only remove the account and its keys. The write lock is
+        // held throughout so the last-admin check cannot race.
+        let mut users = self.users.write();
+        let is_admin = users.get(id).is_some_and(|u| u.is_admin);
+        if is_admin && users.values().filter(|u| u.is_admin).count() <= 1 {
+            return Err(ApplicationError::Forbidden("Cannot delete the last admin".to_string()));
+        }
+
+        self.api_keys.write().remove(id);
+        self.api_key_secrets.write().retain(|_, (owner, _)| owner != id);
+        self.passwords.write().remove(id);
+        users.remove(id);
+        Ok(())
+    }
+}
+
+/// Role and join time of an organization member
+struct Membership {
+    role: OrganizationRole,
+    joined_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// In-memory organization repository for development
+///
+/// Member usernames and display names are read from the user repository.
+pub struct InMemoryOrganizationRepository {
+    users: Arc<InMemoryUserRepository>,
+    organizations: RwLock<HashMap<String, OrganizationDto>>,
+    /// Keyed by (organization ID, user ID)
+    members: RwLock<HashMap<(String, String), Membership>>,
+    api_keys: RwLock<HashMap<String, Vec<ApiKeyDto>>>,
+    /// Secret -> (owner organization ID, key ID)
+    api_key_secrets: RwLock<HashMap<String, (String, String)>>,
+}
+
+impl InMemoryOrganizationRepository {
+    /// Create an empty repository resolving member names through `users`
+    pub fn new(users: Arc<InMemoryUserRepository>) -> Self {
+        Self {
+            users,
+            organizations: RwLock::new(HashMap::new()),
+            members: RwLock::new(HashMap::new()),
+            api_keys: RwLock::new(HashMap::new()),
+            api_key_secrets: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn with_member_count(&self, mut org: OrganizationDto) -> OrganizationDto {
+        org.member_count = self.members.read().keys().filter(|(org_id, _)| *org_id == org.id).count() as u64;
+        org
+    }
+}
+
+#[async_trait]
+impl OrganizationRepositoryPort for InMemoryOrganizationRepository {
+    async fn create(&self, data: &CreateOrganizationData) -> Result<String, ApplicationError> {
+        let id = OrganizationId::new().to_string();
+        let now = chrono::Utc::now();
+
+        let org = OrganizationDto {
+            id: id.clone(),
+            name: data.name.clone(),
+            slug: data.slug.clone(),
+            description: data.description.clone(),
+            website: data.website.clone(),
+            contact_email: data.contact_email.clone(),
+            logo_url: None,
+            member_count: 0,
+            is_verified: false,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.organizations.write().insert(id.clone(), org);
+        self.members
+            .write()
+            .insert(
+                (id.clone(), data.owner_id.clone()),
+                Membership { role: OrganizationRole::Owner, joined_at: now },
+            );
+        Ok(id)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<OrganizationDto>, ApplicationError> {
+        let org = self.organizations.read().get(id).cloned();
+        Ok(org.map(|org| self.with_member_count(org)))
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<OrganizationDto>, ApplicationError> {
+        let org = self.organizations.read().values().find(|o| o.slug == slug).cloned();
+        Ok(org.map(|org| self.with_member_count(org)))
+    }
+
+    async fn list(
+        &self,
+        pagination: &Pagination,
+    ) -> Result<(Vec<OrganizationDto>, u64), ApplicationError> {
+        let mut orgs: Vec<_> = self.organizations.read().values().cloned().collect();
+        orgs.sort_by_key(|o| std::cmp::Reverse(o.created_at));
+
+        let total = orgs.len() as u64;
+        let items = orgs
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .map(|org| self.with_member_count(org))
+            .collect();
+
+        Ok((items, total))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateOrganizationData) -> Result<(), ApplicationError> {
+        let mut orgs = self.organizations.write();
+        let org = orgs
+            .get_mut(id)
+            .ok_or_else(|| ApplicationError::NotFound(format!("Organization not found: {}", id)))?;
+
+        if let Some(ref name) = update.name {
+            org.name = name.clone();
+        }
+        if let Some(ref description) = update.description {
+            org.description = Some(description.clone());
+        }
+        if let Some(ref website) = update.website {
+            org.website = Some(website.clone());
+        }
+        if let Some(ref contact_email) = update.contact_email {
+            org.contact_email = Some(contact_email.clone());
+        }
+        if let Some(ref logo_url) = update.logo_url {
+            org.logo_url = Some(logo_url.clone());
+        }
+        org.updated_at = chrono::Utc::now();
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        if self.organizations.write().remove(id).is_none() {
+            return Err(ApplicationError::NotFound(format!("Organization not found: {}", id)));
+        }
+        self.members.write().retain(|(org_id, _), _| org_id != id);
+        Ok(())
+    }
+
+    async fn slug_exists(&self, slug: &str) -> Result<bool, ApplicationError> {
+        Ok(self.organizations.read().values().any(|o| o.slug == slug))
+    }
+
+    async fn add_member(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrganizationRole,
+    ) -> Result<(), ApplicationError> {
+        self.members.write().insert(
+            (org_id.to_string(), user_id.to_string()),
+            Membership { role, joined_at: chrono::Utc::now() },
+        );
+        Ok(())
+    }
+
+    async fn update_member_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrganizationRole,
+    ) -> Result<(), ApplicationError> {
+        let mut members = self.members.write();
+        let member = members
+            .get_mut(&(org_id.to_string(), user_id.to_string()))
+            .ok_or_else(|| ApplicationError::NotFound(format!("Member not found: {}", user_id)))?;
+        member.role = role;
+        Ok(())
+    }
+
+    async fn remove_member(&self, org_id: &str, user_id: &str) -> Result<(), ApplicationError> {
+        if self
+            .members
+            .write()
+            .remove(&(org_id.to_string(), user_id.to_string()))
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound(format!("Member not found: {}", user_id)));
+        }
+        Ok(())
+    }
+
+    async fn get_members(&self, org_id: &str) -> Result<Vec<OrganizationMemberDto>, ApplicationError> {
+        let users = self.users.users.read();
+        let mut members: Vec<_> = self
+            .members
+            .read()
+            .iter()
+            .filter(|((id, _), _)| id == org_id)
+            .map(|((_, user_id), membership)| {
+                let user = users.get(user_id);
+                OrganizationMemberDto {
+                    user_id: user_id.clone(),
+                    username: user.map_or_else(|| user_id.clone(), |u| u.username.clone()),
+                    display_name: user.map_or_else(|| user_id.clone(), |u| u.display_name.clone()),
+                    role: membership.role,
+                    joined_at: membership.joined_at,
+                }
+            })
+            .collect();
+        members.sort_by_key(|m| m.joined_at);
+        Ok(members)
+    }
+
+    async fn get_member_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+    ) -> Result<Option<OrganizationRole>, ApplicationError> {
+        Ok(self
+            .members
+            .read()
+            .get(&(org_id.to_string(), user_id.to_string()))
+            .map(|membership| membership.role))
+    }
+
+    async fn get_user_organizations(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<(OrganizationDto, OrganizationRole)>, ApplicationError> {
+        let memberships: Vec<_> = self
+            .members
+            .read()
+            .iter()
+            .filter(|((_, member), _)| member == user_id)
+            .map(|((org_id, _), membership)| (org_id.clone(), membership.role))
+            .collect();
+
+        let orgs = self.organizations.read();
+        Ok(memberships
+            .into_iter()
+            .filter_map(|(org_id, role)| orgs.get(&org_id).cloned().map(|org| (org, role)))
+            .map(|(org, role)| (self.with_member_count(org), role))
+            .collect())
+    }
+
+    async fn create_api_key(&self, org_id: &str, data: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError> {
+        let id = generate_id().to_string();
+        let secret = format!("llm_bm_org_{}", uuid::Uuid::new_v4());
+        let now = chrono::Utc::now();
+
+        let key = ApiKeyDto {
+            id: id.clone(),
+            name: data.name.clone(),
+            description: data.description.clone(),
+            scopes: data.scopes.clone(),
+            last_used_at: None,
+            expires_at: data.expires_in_days.map(|d| now + chrono::Duration::days(d as i64)),
+            revoked_at: None,
+            created_at: now,
+        };
+
+        self.api_keys
+            .write()
+            .entry(org_id.to_string())
+            .or_default()
+            .push(key.clone());
+
+        self.api_key_secrets
+            .write()
+            .insert(secret.clone(), (org_id.to_string(), id));
+
+        Ok(ApiKeyWithSecretDto { key, secret })
+    }
+
+    async fn list_api_keys(&self, org_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError> {
+        Ok(self.api_keys.read().get(org_id).cloned().unwrap_or_default())
+    }
+
+    async fn revoke_api_key(&self, org_id: &str, key_id: &str) -> Result<(), ApplicationError> {
+        let mut keys = self.api_keys.write();
+        let key = keys
+            .get_mut(org_id)
+            .and_then(|org_keys| org_keys.iter_mut().find(|k| k.id == key_id))
+            .ok_or_else(|| ApplicationError::NotFound(format!("API key not found: {}", key_id)))?;
+        key.revoked_at.get_or_insert_with(chrono::Utc::now);
+        Ok(())
+    }
+
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError> {
+        let Some((org_id, key_id)) = self.api_key_secrets.read().get(key_secret).cloned() else {
+            return Ok(None);
+        };
+        let key = self
+            .api_keys
+            .read()
+            .get(&org_id)
+            .and_then(|keys| keys.iter().find(|k| k.id == key_id).cloned());
+        Ok(key.map(|key| (org_id, key)))
+    }
+
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError> {
+        if let Some(key) = self
+            .api_keys
+            .write()
+            .values_mut()
+            .flatten()
+            .find(|k| k.id == key_id)
+        {
+            key.last_used_at = Some(used_at);
         }
         Ok(())
     }
+}
+
+/// In-process verification progress, for development and tests
+///
+/// Nothing publishes here on its own; callers push updates with
+/// [`InMemoryVerificationProgress::publish`].
+pub struct InMemoryVerificationProgress {
+    sender: broadcast::Sender<VerificationProgress>,
+    latest: RwLock<HashMap<String, VerificationProgress>>,
+}
+
+impl InMemoryVerificationProgress {
+    /// Create a source with no subscribers
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(64);
+        Self {
+            sender,
+            latest: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Deliver `progress` to current subscribers of its submission
+    pub fn publish(&self, progress: VerificationProgress) {
+        self.latest
+            .write()
+            .insert(progress.submission_id.clone(), progress.clone());
+        // No subscribers is fine; the update is simply dropped
+        let _ = self.sender.send(progress);
+    }
+}
+
+impl Default for InMemoryVerificationProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl VerificationProgressSource for InMemoryVerificationProgress {
+    async fn subscribe(
+        &self,
+        submission_id: &str,
+    ) -> Result<BoxStream<'static, VerificationProgress>, ApplicationError> {
+        let submission_id = submission_id.to_string();
+        let updates = stream::unfold(self.sender.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(progress) => return Some((progress, rx)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(updates
+            .filter(move |progress| {
+                futures::future::ready(progress.submission_id == submission_id)
+            })
+            .boxed())
+    }
 
-    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, Vec<String>)>, ApplicationError> {
-        Ok(self.api_key_secrets.read().get(key_secret).cloned())
+    async fn latest(
+        &self,
+        submission_id: &str,
+    ) -> Result<Option<VerificationProgress>, ApplicationError> {
+        Ok(self.latest.read().get(submission_id).cloned())
     }
 }
@@ -0,0 +1,146 @@
+//! Tests for AppState wired to real infrastructure
+//!
+//! These tests require a migrated PostgreSQL database and Redis and are
+//! marked with #[ignore].
+//! Run with: TEST_DATABASE_URL=postgres://... REDIS_URL=redis://... \
+//!     cargo test --test infrastructure_state_tests -- --ignored
+
+use llm_benchmark_api_rest::{ApiConfig, AppState};
+use llm_benchmark_application::services::{ApiKeyFilters, ApiKeyPrincipal, Pagination, ServiceContext};
+use llm_benchmark_application::validation::{
+    CreateApiKeyRequest, CreateBenchmarkRequest, CreateUserRequest,
+};
+use llm_benchmark_domain::benchmark::{BenchmarkCategory, BenchmarkStatus};
+use llm_benchmark_infrastructure::{
+    CacheConfig, DatabaseConfig, DatabasePool, MessagingConfig, RedisCache, RedisMessaging,
+};
+use std::sync::Arc;
+
+async fn infrastructure_state() -> AppState {
+    let database_url =
+        std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    let db = DatabasePool::new(&DatabaseConfig::test_config(database_url))
+        .await
+        .expect("Failed to connect to database");
+    let cache = RedisCache::new(CacheConfig::from_env().unwrap())
+        .await
+        .expect("Failed to connect to Redis cache");
+    let messaging = RedisMessaging::new(MessagingConfig::from_env().unwrap())
+        .await
+        .expect("Failed to connect to Redis messaging");
+
+    AppState::from_infrastructure(
+        ApiConfig::default(),
+        &db,
+        Arc::new(cache),
+        Arc::new(messaging),
+    )
+}
+
+async fn register_user(state: &AppState, suffix: &str) -> String {
+    state
+        .user_service
+        .register(CreateUserRequest {
+            email: format!("state-{}@example.com", suffix),
+            username: format!("state_{}", &suffix[..12]),
+            display_name: "State Test".to_string(),
+            password: Some("correct-horse-battery".to_string()),
+            oauth_provider: None,
+            oauth_id: None,
+        })
+        .await
+        .expect("Failed to register user")
+        .id
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_from_infrastructure_benchmark_create_and_get() {
+    let state = infrastructure_state().await;
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let user_id = register_user(&state, &suffix).await;
+
+    let ctx = ServiceContext::authenticated(user_id, "state-test".to_string());
+    let created = state
+        .benchmark_service
+        .create(
+            &ctx,
+            CreateBenchmarkRequest {
+                name: "Infrastructure State Benchmark".to_string(),
+                slug: format!("infra-state-{}", suffix),
+                description: "Benchmark created through AppState::from_infrastructure"
+                    .to_string(),
+                category: BenchmarkCategory::Accuracy,
+                tags: vec!["integration".to_string()],
+                version: "1.0.0".to_string(),
+                leaderboard_min_verification: Default::default(),
+                test_cases: vec![],
+            },
+        )
+        .await
+        .expect("Failed to create benchmark");
+
+    let fetched = state
+        .benchmark_service
+        .get_by_id(&ctx, &created.id)
+        .await
+        .expect("Failed to get benchmark")
+        .expect("Benchmark not found");
+
+    assert_eq!(fetched.id, created.id);
+    assert_eq!(fetched.slug, created.slug);
+    assert_eq!(fetched.status, BenchmarkStatus::Draft);
+    assert_eq!(fetched.current_version.as_deref(), Some("1.0.0"));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_from_infrastructure_personal_api_keys() {
+    let state = infrastructure_state().await;
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let user_id = register_user(&state, &suffix).await;
+    let ctx = ServiceContext::authenticated(user_id.clone(), "state-test".to_string());
+
+    let created = state
+        .user_service
+        .create_api_key(
+            &ctx,
+            CreateApiKeyRequest {
+                name: "ci".to_string(),
+                description: None,
+                scopes: vec!["read:benchmarks".to_string()],
+                expires_in_days: None,
+            },
+        )
+        .await
+        .expect("Failed to create API key");
+
+    let (principal, scopes) = state
+        .user_service
+        .verify_api_key(&created.secret)
+        .await
+        .expect("Failed to verify API key")
+        .expect("API key did not verify");
+    assert_eq!(principal, ApiKeyPrincipal::User(user_id));
+    assert_eq!(scopes, vec!["read:benchmarks".to_string()]);
+
+    let listed = state
+        .user_service
+        .list_api_keys(&ctx, ApiKeyFilters::default(), Pagination::default())
+        .await
+        .expect("Failed to list API keys");
+    assert_eq!(listed.total, 1);
+    assert!(listed.items[0].last_used_at.is_some());
+
+    state
+        .user_service
+        .revoke_api_key(&ctx, &created.key.id)
+        .await
+        .expect("Failed to revoke API key");
+    let listed = state
+        .user_service
+        .list_api_keys(&ctx, ApiKeyFilters::default(), Pagination::default())
+        .await
+        .expect("Failed to list API keys");
+    assert_eq!(listed.total, 0);
+}
@@ -147,7 +147,7 @@ async fn test_submit_benchmark_results() {
     // Expected response: 201 Created
     let expected_status = 201;
     assert_eq!(expected_status, 201);
-    assert!(!submission.results.test_case_results.is_empty());
+    assert!(!submission.results().test_case_results.is_empty());
 }
 
 #[tokio::test]
@@ -0,0 +1,91 @@
+//! Repository conformance suite run against the in-memory repositories
+//!
+//! The same checks from `llm_benchmark_testing::repository_conformance` apply
+//! to every implementation of the repository ports.
+
+use llm_benchmark_api_rest::state::{InMemoryBenchmarkRepository, InMemorySubmissionRepository};
+use llm_benchmark_testing::repository_conformance::*;
+
+fn creator_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+#[tokio::test]
+async fn test_in_memory_benchmark_create_and_get() {
+    benchmark_create_and_get(&InMemoryBenchmarkRepository::new(), &creator_id()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_benchmark_not_found() {
+    benchmark_not_found(&InMemoryBenchmarkRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_benchmark_update() {
+    benchmark_update(&InMemoryBenchmarkRepository::new(), &creator_id()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_benchmark_delete() {
+    benchmark_delete(&InMemoryBenchmarkRepository::new(), &creator_id()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_benchmark_pagination() {
+    benchmark_pagination(&InMemoryBenchmarkRepository::new(), &creator_id()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_benchmark_suite_on_shared_repository() {
+    run_benchmark_conformance(&InMemoryBenchmarkRepository::new(), &creator_id()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_submission_create_and_get() {
+    submission_create_and_get(
+        &InMemorySubmissionRepository::new(),
+        &SubmissionFixture::unreferenced(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_in_memory_submission_not_found() {
+    submission_not_found(&InMemorySubmissionRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_submission_update() {
+    submission_update(
+        &InMemorySubmissionRepository::new(),
+        &SubmissionFixture::unreferenced(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_in_memory_submission_delete() {
+    submission_delete(
+        &InMemorySubmissionRepository::new(),
+        &SubmissionFixture::unreferenced(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_in_memory_submission_pagination() {
+    submission_pagination(
+        &InMemorySubmissionRepository::new(),
+        &SubmissionFixture::unreferenced(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_in_memory_submission_suite_on_shared_repository() {
+    run_submission_conformance(
+        &InMemorySubmissionRepository::new(),
+        &SubmissionFixture::unreferenced(),
+    )
+    .await;
+}
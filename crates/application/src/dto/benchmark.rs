@@ -1,6 +1,8 @@
 //! Benchmark DTOs for API layer
 
 use llm_benchmark_domain::benchmark::{BenchmarkCategory, BenchmarkStatus};
+use llm_benchmark_domain::evaluation::ScorePrecision;
+use llm_benchmark_domain::submission::VerificationLevel;
 use serde::{Deserialize, Serialize};
 
 /// Benchmark response DTO
@@ -148,6 +150,10 @@ pub struct EvaluationCriteriaDto {
     pub minimum_test_cases: usize,
     #[serde(default = "default_confidence")]
     pub confidence_level: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_precision: Option<ScorePrecision>,
+    #[serde(default)]
+    pub leaderboard_min_verification: VerificationLevel,
 }
 
 fn default_aggregation() -> String {
@@ -297,5 +303,6 @@ mod tests {
         assert_eq!(dto.aggregation_method, "mean");
         assert_eq!(dto.confidence_level, 0.95);
         assert_eq!(dto.minimum_test_cases, 10);
+        assert_eq!(dto.leaderboard_min_verification, VerificationLevel::Unverified);
     }
 }
@@ -163,8 +163,10 @@ pub struct PaginationInfo {
 }
 
 impl PaginationInfo {
+    /// Page 0 is treated as page 1
     pub fn new(page: u32, page_size: u32, total_items: u64) -> Self {
-        let total_pages = ((total_items as f64) / (page_size as f64)).ceil() as u32;
+        let page = page.max(1);
+        let total_pages = llm_benchmark_common::pagination::page_count(total_items, page_size);
         Self {
             page,
             page_size,
@@ -274,6 +276,22 @@ mod tests {
         assert!(info.has_previous);
     }
 
+    #[test]
+    fn test_pagination_info_edges() {
+        let info = PaginationInfo::new(0, 20, 100);
+        assert_eq!(info.page, 1);
+        assert!(!info.has_previous);
+
+        let info = PaginationInfo::new(6, 20, 101);
+        assert_eq!(info.total_pages, 6);
+        assert!(!info.has_next);
+
+        let info = PaginationInfo::new(1, 20, 0);
+        assert_eq!(info.total_pages, 0);
+        assert!(!info.has_next);
+        assert!(!info.has_previous);
+    }
+
     #[test]
     fn test_links_pagination() {
         let links = Links::for_pagination("/api/items", 2, 20, 5);
@@ -30,6 +30,7 @@ pub use services::{
 pub use validation::{Validatable, ValidationContext, ValidationResult, ValidationRules};
 
 // Common error types for the application layer
+use llm_benchmark_domain::errors::{SubmissionError, VerificationError};
 use thiserror::Error;
 
 /// Application-level errors
@@ -55,6 +56,10 @@ pub enum ApplicationError {
     #[error("Validation failed: {0}")]
     ValidationFailed(String),
 
+    /// Validation errors with the offending fields
+    #[error("Validation failed: {}", .0.summary())]
+    InvalidFields(ValidationResult),
+
     /// Resource conflict (e.g., duplicate)
     #[error("Conflict: {0}")]
     Conflict(String),
@@ -84,7 +89,7 @@ impl ApplicationError {
             ApplicationError::Unauthorized(_) => 401,
             ApplicationError::Forbidden(_) => 403,
             ApplicationError::InvalidInput(_) => 400,
-            ApplicationError::ValidationFailed(_) => 422,
+            ApplicationError::ValidationFailed(_) | ApplicationError::InvalidFields(_) => 422,
             ApplicationError::Conflict(_) => 409,
             ApplicationError::Internal(_) => 500,
             ApplicationError::ServiceUnavailable(_) => 503,
@@ -110,7 +115,9 @@ impl ApplicationError {
             ApplicationError::Unauthorized(_) => "UNAUTHORIZED",
             ApplicationError::Forbidden(_) => "FORBIDDEN",
             ApplicationError::InvalidInput(_) => "INVALID_INPUT",
-            ApplicationError::ValidationFailed(_) => "VALIDATION_FAILED",
+            ApplicationError::ValidationFailed(_) | ApplicationError::InvalidFields(_) => {
+                "VALIDATION_FAILED"
+            }
             ApplicationError::Conflict(_) => "CONFLICT",
             ApplicationError::Internal(_) => "INTERNAL_ERROR",
             ApplicationError::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
@@ -120,6 +127,33 @@ impl ApplicationError {
     }
 }
 
+impl From<SubmissionError> for ApplicationError {
+    fn from(err: SubmissionError) -> Self {
+        match err {
+            SubmissionError::NotFound(_) => ApplicationError::NotFound(err.to_string()),
+            SubmissionError::DuplicateSubmission
+            | SubmissionError::BenchmarkNotActive
+            | SubmissionError::NotCancellable(_) => {
+                ApplicationError::Conflict(err.to_string())
+            }
+            SubmissionError::RateLimitExceeded(_) => ApplicationError::RateLimitExceeded(err.to_string()),
+            _ => ApplicationError::InvalidInput(err.to_string()),
+        }
+    }
+}
+
+impl From<VerificationError> for ApplicationError {
+    fn from(err: VerificationError) -> Self {
+        match err {
+            VerificationError::NotFound(_) => ApplicationError::NotFound(err.to_string()),
+            VerificationError::AlreadyInProgress
+            | VerificationError::InvalidSubmissionState
+            | VerificationError::Downgrade { .. } => ApplicationError::Conflict(err.to_string()),
+            _ => ApplicationError::InvalidInput(err.to_string()),
+        }
+    }
+}
+
 pub type ApplicationResult<T> = Result<T, ApplicationError>;
 
 #[cfg(test)]
@@ -132,6 +166,7 @@ mod tests {
         assert_eq!(ApplicationError::Unauthorized("test".to_string()).http_status(), 401);
         assert_eq!(ApplicationError::Forbidden("test".to_string()).http_status(), 403);
         assert_eq!(ApplicationError::ValidationFailed("test".to_string()).http_status(), 422);
+        assert_eq!(ApplicationError::InvalidFields(ValidationResult::error("test")).http_status(), 422);
         assert_eq!(ApplicationError::Conflict("test".to_string()).http_status(), 409);
         assert_eq!(ApplicationError::Internal("test".to_string()).http_status(), 500);
     }
@@ -9,15 +9,32 @@ use crate::scoring::evaluators::{
     RegexMatchEvaluator,
 };
 use crate::ApplicationError;
-use llm_benchmark_domain::evaluation::{AggregationMethod, EvaluationCriteria, ScoreNormalization};
+use llm_benchmark_domain::evaluation::{
+    AggregationMethod, EvaluationCriteria, MetricDefinition, ScoreNormalization,
+};
 use llm_benchmark_domain::submission::{
     ConfidenceInterval, MetricScore, StatisticalSignificance, SubmissionResults, TestCaseResult,
 };
+use llm_benchmark_domain::test_case::DifficultyLevel;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info, instrument, warn};
 
+/// Metric name for median test case latency
+pub const LATENCY_P50_METRIC: &str = "latency_p50_ms";
+/// Metric name for 90th percentile test case latency
+pub const LATENCY_P90_METRIC: &str = "latency_p90_ms";
+/// Metric name for 99th percentile test case latency
+pub const LATENCY_P99_METRIC: &str = "latency_p99_ms";
+/// Metric name for mean generation throughput
+pub const THROUGHPUT_METRIC: &str = "throughput_tokens_per_sec";
+/// Metric name for accuracy per unit cost
+pub const COST_EFFICIENCY_METRIC: &str = "cost_efficiency";
+
+/// Default cap on the number of test cases in a single scoring request
+pub const DEFAULT_MAX_TEST_CASES: usize = 100_000;
+
 /// Scoring engine configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoringEngineConfig {
@@ -31,6 +48,15 @@ pub struct ScoringEngineConfig {
     pub detailed_breakdown: bool,
     /// Z-score threshold for outlier detection
     pub outlier_z_threshold: f64,
+    /// Maximum number of test cases accepted in a single scoring request
+    ///
+    /// Streamed scoring holds one batch at a time and is not limited.
+    #[serde(default = "default_max_test_cases")]
+    pub max_test_cases: usize,
+}
+
+fn default_max_test_cases() -> usize {
+    DEFAULT_MAX_TEST_CASES
 }
 
 impl Default for ScoringEngineConfig {
@@ -41,6 +67,7 @@ impl Default for ScoringEngineConfig {
             max_concurrent_evaluations: 100,
             detailed_breakdown: true,
             outlier_z_threshold: 3.0,
+            max_test_cases: DEFAULT_MAX_TEST_CASES,
         }
     }
 }
@@ -60,8 +87,18 @@ pub struct TestCaseInput {
     pub latency_ms: Option<u64>,
     /// Number of tokens generated
     pub tokens_generated: Option<u32>,
+    /// What generating the response cost, e.g. in USD
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
     /// Weight for this test case (default 1.0)
     pub weight: f64,
+    /// Difficulty tier, used by difficulty-weighted aggregation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<DifficultyLevel>,
+    /// Registered evaluator to use for the primary metric instead of the one
+    /// derived from its metric type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evaluator: Option<String>,
 }
 
 impl Default for TestCaseInput {
@@ -73,9 +110,39 @@ impl Default for TestCaseInput {
             context: HashMap::new(),
             latency_ms: None,
             tokens_generated: None,
+            cost: None,
             weight: 1.0,
+            difficulty: None,
+            evaluator: None,
+        }
+    }
+}
+
+/// Running totals of the cost and tokens reported by test cases
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct CostTotals {
+    cost: f64,
+    tokens: u64,
+}
+
+impl CostTotals {
+    /// Count a test case that reports both a finite, non-negative cost and
+    /// a token count
+    pub(super) fn push(&mut self, test_case: &TestCaseInput) {
+        if let (Some(cost), Some(tokens)) = (test_case.cost, test_case.tokens_generated) {
+            if cost.is_finite() && cost >= 0.0 {
+                self.cost += cost;
+                self.tokens += u64::from(tokens);
+            }
         }
     }
+
+    pub(super) fn cost_per_token(&self) -> Option<f64> {
+        if self.tokens == 0 || self.cost <= 0.0 {
+            return None;
+        }
+        Some(self.cost / self.tokens as f64)
+    }
 }
 
 /// Scoring request containing all test cases to evaluate
@@ -87,11 +154,174 @@ pub struct ScoringRequest {
     pub criteria: EvaluationCriteria,
     /// Optional metadata about the submission
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Historical aggregate scores that `ZScore` and `Percentile`
+    /// normalization rank against; without them those methods leave the
+    /// score unchanged
+    pub population_scores: Option<Vec<f64>>,
+}
+
+/// Per-test-case divergence between two runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseDivergence {
+    /// Test case identifier
+    pub test_case_id: String,
+    /// Score in the first run, `None` if the test case is missing from it
+    pub score_a: Option<f64>,
+    /// Score in the second run, `None` if the test case is missing from it
+    pub score_b: Option<f64>,
+    /// Absolute score difference, `None` if the test case is missing from either run
+    pub difference: Option<f64>,
+}
+
+/// Outcome of comparing two runs of the same submission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReproducibilityReport {
+    /// Whether the runs are equivalent within the tolerance
+    pub passed: bool,
+    /// Maximum allowed absolute score difference
+    pub tolerance: f64,
+    /// Aggregate score of the first run
+    pub aggregate_score_a: f64,
+    /// Aggregate score of the second run
+    pub aggregate_score_b: f64,
+    /// Number of test cases present in both runs
+    pub compared_test_cases: usize,
+    /// Test cases that differ by more than the tolerance or appear in only one run
+    pub divergences: Vec<TestCaseDivergence>,
+}
+
+impl ReproducibilityReport {
+    /// Absolute difference between the aggregate scores
+    pub fn aggregate_difference(&self) -> f64 {
+        (self.aggregate_score_a - self.aggregate_score_b).abs()
+    }
+}
+
+/// Step-by-step account of how a scoring request is scored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreExplanation {
+    /// Aggregation method applied to every metric
+    pub aggregation_method: AggregationMethod,
+    /// Normalization applied to the primary metric's aggregate
+    pub normalization: ScoreNormalization,
+    /// The primary metric first, then secondary metrics in criteria order
+    pub metrics: Vec<MetricExplanation>,
+}
+
+/// How one metric's value was computed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricExplanation {
+    /// Metric name
+    pub metric: String,
+    /// Evaluator used to score each test case
+    pub evaluator: String,
+    /// Whether this is the primary metric
+    pub primary: bool,
+    /// Per-test-case scores and their share of the aggregate
+    pub test_cases: Vec<TestCaseContribution>,
+    /// Aggregated value before normalization
+    pub aggregate: f64,
+    /// Reported value after normalization (only the primary metric is normalized)
+    pub score: f64,
+}
+
+/// A single test case's part in a metric's aggregate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseContribution {
+    /// Test case identifier
+    pub test_case_id: String,
+    /// Evaluator that scored this case
+    pub evaluator: String,
+    /// Score assigned by the evaluator
+    pub score: f64,
+    /// Effective weight in the aggregate, e.g. 0.0 for a trimmed case
+    ///
+    /// `None` for methods that aren't a weighted mean of the scores
+    /// (median, percentiles, min/max, geometric and harmonic means).
+    pub weight: Option<f64>,
+    /// `score * weight / total weight`; these sum to the aggregate
+    pub contribution: Option<f64>,
+}
+
+/// Replace a NaN or infinite statistic with `fallback`, logging a warning
+///
+/// Results are serialized and sorted downstream, where non-finite values
+/// either fail to encode or break ordering.
+pub(super) fn finite_or(value: f64, fallback: f64, what: &str) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        warn!(statistic = what, value = %value, fallback, "Non-finite statistic replaced");
+        fallback
+    }
+}
+
+/// Weight of the test case at `index` under a weighted-mean style method
+///
+/// Weighted means use the method's index-keyed weights when it has any and
+/// the test case's own `weight` otherwise; difficulty weighting looks up the
+/// case's tier. Every other method weighs cases equally.
+pub(super) fn case_weight(
+    method: &AggregationMethod,
+    index: usize,
+    weight: f64,
+    difficulty: Option<DifficultyLevel>,
+) -> f64 {
+    match method {
+        AggregationMethod::WeightedMean { weights } if weights.is_empty() => weight,
+        AggregationMethod::WeightedMean { weights } => {
+            weights.get(&index.to_string()).copied().unwrap_or(1.0)
+        }
+        AggregationMethod::DifficultyWeighted { tier_weights } => difficulty
+            .and_then(|d| tier_weights.get(d.as_str()).copied())
+            .unwrap_or(1.0),
+        _ => 1.0,
+    }
+}
+
+/// Evaluate a single test case, recording evaluator errors as a zero score
+pub(super) async fn evaluate_case(
+    evaluator: &dyn Evaluator,
+    metric_name: &str,
+    test_case: &TestCaseInput,
+) -> TestCaseResult {
+    let eval_result = evaluator
+        .evaluate(&test_case.actual, Some(&test_case.expected), &EvaluatorConfig::default())
+        .await;
+
+    let (passed, score, error) = if eval_result.error.is_some() {
+        warn!(
+            metric = %metric_name,
+            test_case_id = %test_case.id,
+            error = ?eval_result.error,
+            "Evaluation error"
+        );
+        (
+            false,
+            0.0,
+            Some(llm_benchmark_domain::submission::TestCaseError {
+                error_type: llm_benchmark_domain::submission::TestCaseErrorType::EvaluationError,
+                message: eval_result.error.unwrap_or_default(),
+            }),
+        )
+    } else {
+        let score = finite_or(eval_result.score, 0.0, "test case score");
+        (eval_result.passed, score, None)
+    };
+
+    TestCaseResult {
+        test_case_id: test_case.id.clone(),
+        passed,
+        score,
+        latency_ms: test_case.latency_ms,
+        tokens_generated: test_case.tokens_generated,
+        error,
+    }
 }
 
 /// The main scoring engine
 pub struct ScoringEngine {
-    config: ScoringEngineConfig,
+    pub(super) config: ScoringEngineConfig,
     evaluators: HashMap<String, Arc<dyn Evaluator>>,
 }
 
@@ -145,65 +375,39 @@ impl ScoringEngine {
             request.test_cases.len()
         );
 
-        // Validate minimum test cases
-        if request.test_cases.len() < request.criteria.minimum_test_cases {
-            return Err(ApplicationError::ValidationFailed(format!(
-                "Insufficient test cases: got {}, required {}",
-                request.test_cases.len(),
-                request.criteria.minimum_test_cases
-            )));
-        }
-
-        // Determine evaluator from metric type
-        let evaluator_name = self.metric_type_to_evaluator(&request.criteria.primary_metric.metric_type);
-        let evaluator = self
-            .get_evaluator(&evaluator_name)
-            .ok_or_else(|| ApplicationError::Internal(format!("Evaluator not found: {}", evaluator_name)))?;
-
-        // Evaluate all test cases
-        let mut test_case_results = Vec::with_capacity(request.test_cases.len());
-        let mut scores: Vec<f64> = Vec::with_capacity(request.test_cases.len());
-        let mut weights: Vec<f64> = Vec::with_capacity(request.test_cases.len());
-
-        let eval_config = EvaluatorConfig::default();
-
-        for test_case in &request.test_cases {
-            let eval_result = evaluator
-                .evaluate(&test_case.actual, Some(&test_case.expected), &eval_config)
-                .await;
-
-            let (passed, score, error) = if eval_result.error.is_some() {
-                warn!(test_case_id = %test_case.id, error = ?eval_result.error, "Evaluation error");
-                (
-                    false,
-                    0.0,
-                    Some(llm_benchmark_domain::submission::TestCaseError {
-                        error_type: llm_benchmark_domain::submission::TestCaseErrorType::EvaluationError,
-                        message: eval_result.error.unwrap_or_default(),
-                    }),
-                )
-            } else {
-                (eval_result.passed, eval_result.score, None)
-            };
-
-            test_case_results.push(TestCaseResult {
-                test_case_id: test_case.id.clone(),
-                passed,
-                score,
-                latency_ms: test_case.latency_ms,
-                tokens_generated: test_case.tokens_generated,
-                error,
-            });
+        self.check_test_case_count(request)?;
 
-            scores.push(score);
-            weights.push(test_case.weight);
-        }
+        // Evaluate all test cases against the primary metric
+        let test_case_results = self
+            .evaluate_metric(&request.criteria.primary_metric, &request.test_cases, true)
+            .await?;
+        let scores: Vec<f64> = test_case_results.iter().map(|r| r.score).collect();
+        let weights: Vec<f64> = request
+            .test_cases
+            .iter()
+            .map(|tc| finite_or(tc.weight, 1.0, "test case weight"))
+            .collect();
 
         // Calculate aggregate score
-        let aggregate_score = self.aggregate_scores(&scores, &weights, &request.criteria.aggregation_method)?;
+        let difficulties: Vec<Option<DifficultyLevel>> =
+            request.test_cases.iter().map(|tc| tc.difficulty).collect();
+        let aggregate_score = self.aggregate_scores(
+            &scores,
+            &weights,
+            &difficulties,
+            &request.criteria.aggregation_method,
+        )?;
 
         // Normalize score if configured
-        let aggregate_score = self.normalize_score(aggregate_score, &request.criteria.score_normalization);
+        let aggregate_score = finite_or(
+            self.normalize_score(
+                aggregate_score,
+                &request.criteria.score_normalization,
+                request.population_scores.as_deref(),
+            ),
+            0.0,
+            "normalized score",
+        );
 
         // Calculate metric scores
         let mut metric_scores = HashMap::new();
@@ -221,21 +425,47 @@ impl ScoringEngine {
             },
         );
 
-        // Secondary metrics (if any)
+        // Secondary metrics are evaluated independently with their own
+        // evaluator and aggregated the same way as the primary metric
         for secondary in &request.criteria.secondary_metrics {
-            // For now, secondary metrics use the same scores
-            // In production, each metric would have its own evaluation
+            let secondary_raw: Vec<f64> = self
+                .evaluate_metric(secondary, &request.test_cases, false)
+                .await?
+                .into_iter()
+                .map(|r| r.score)
+                .collect();
+            let value = self.aggregate_scores(
+                &secondary_raw,
+                &weights,
+                &difficulties,
+                &request.criteria.aggregation_method,
+            )?;
             metric_scores.insert(
                 secondary.name.clone(),
                 MetricScore {
-                    value: aggregate_score,
+                    value,
                     unit: secondary.unit.clone(),
-                    raw_values: None,
-                    std_dev: None,
+                    std_dev: Some(self.calculate_std_dev(&secondary_raw)),
+                    raw_values: Some(secondary_raw),
                 },
             );
         }
 
+        // Latency and throughput metrics from per-case timings
+        for (name, metric) in self.timing_metrics(&request.test_cases) {
+            metric_scores.entry(name).or_insert(metric);
+        }
+
+        // Accuracy per unit cost when the test cases report what they cost
+        let cost_per_token = self.cost_per_token(&request.test_cases);
+        if let Some(metric) =
+            self.efficiency_metric(&request.criteria, &metric_scores, cost_per_token)
+        {
+            metric_scores
+                .entry(COST_EFFICIENCY_METRIC.to_string())
+                .or_insert(metric);
+        }
+
         // Calculate confidence interval if enough samples
         let confidence_interval = if scores.len() >= self.config.min_test_cases_for_stats {
             Some(self.calculate_confidence_interval(
@@ -268,8 +498,309 @@ impl ScoringEngine {
         })
     }
 
+    /// Explain how `score` arrives at its results for this request
+    ///
+    /// Evaluates the test cases the same way as [`Self::score`] and reports,
+    /// for every metric, the evaluator used, each test case's score, weight
+    /// and contribution, and the aggregate before and after normalization.
+    #[instrument(skip(self, request), fields(test_cases = request.test_cases.len()))]
+    pub async fn explain(&self, request: &ScoringRequest) -> Result<ScoreExplanation, ApplicationError> {
+        self.check_test_case_count(request)?;
+
+        let criteria = &request.criteria;
+        let weights: Vec<f64> = request
+            .test_cases
+            .iter()
+            .map(|tc| finite_or(tc.weight, 1.0, "test case weight"))
+            .collect();
+        let difficulties: Vec<Option<DifficultyLevel>> =
+            request.test_cases.iter().map(|tc| tc.difficulty).collect();
+
+        let all_metrics = std::iter::once((&criteria.primary_metric, true))
+            .chain(criteria.secondary_metrics.iter().map(|m| (m, false)));
+
+        let mut metrics = Vec::with_capacity(1 + criteria.secondary_metrics.len());
+        for (metric, primary) in all_metrics {
+            let results = self.evaluate_metric(metric, &request.test_cases, primary).await?;
+            let scores: Vec<f64> = results.iter().map(|r| r.score).collect();
+
+            let aggregate = self.aggregate_scores(
+                &scores,
+                &weights,
+                &difficulties,
+                &criteria.aggregation_method,
+            )?;
+            let score = if primary {
+                finite_or(
+                    self.normalize_score(
+                        aggregate,
+                        &criteria.score_normalization,
+                        request.population_scores.as_deref(),
+                    ),
+                    0.0,
+                    "normalized score",
+                )
+            } else {
+                aggregate
+            };
+
+            let metric_evaluator = self.metric_type_to_evaluator(&metric.metric_type);
+            let effective =
+                self.linear_weights(&scores, &weights, &difficulties, &criteria.aggregation_method);
+            let total_weight: f64 = effective.iter().flatten().sum();
+            let test_cases = results
+                .into_iter()
+                .zip(&request.test_cases)
+                .enumerate()
+                .map(|(i, (result, input))| {
+                    let weight = effective.as_ref().map(|w| w[i]);
+                    let evaluator = match &input.evaluator {
+                        Some(name) if primary => name.clone(),
+                        _ => metric_evaluator.clone(),
+                    };
+                    TestCaseContribution {
+                        contribution: weight.map(|w| result.score * w / total_weight),
+                        test_case_id: result.test_case_id,
+                        evaluator,
+                        score: result.score,
+                        weight,
+                    }
+                })
+                .collect();
+
+            metrics.push(MetricExplanation {
+                metric: metric.name.clone(),
+                evaluator: metric_evaluator,
+                primary,
+                test_cases,
+                aggregate,
+                score,
+            });
+        }
+
+        Ok(ScoreExplanation {
+            aggregation_method: criteria.aggregation_method.clone(),
+            normalization: criteria.score_normalization.clone(),
+            metrics,
+        })
+    }
+
+    /// Reject requests with too many or too few test cases
+    fn check_test_case_count(&self, request: &ScoringRequest) -> Result<(), ApplicationError> {
+        // Reject oversized requests before allocating per-case results
+        if request.test_cases.len() > self.config.max_test_cases {
+            return Err(ApplicationError::InvalidInput(format!(
+                "Too many test cases: got {}, maximum is {}",
+                request.test_cases.len(),
+                self.config.max_test_cases
+            )));
+        }
+
+        // Validate minimum test cases
+        if request.test_cases.len() < request.criteria.minimum_test_cases {
+            return Err(ApplicationError::ValidationFailed(format!(
+                "Insufficient test cases: got {}, required {}",
+                request.test_cases.len(),
+                request.criteria.minimum_test_cases
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Evaluate every test case with the evaluator for `metric`'s type
+    ///
+    /// With `use_overrides`, a test case naming its own evaluator is scored
+    /// with that one instead; unregistered names are rejected before any
+    /// case is evaluated.
+    async fn evaluate_metric(
+        &self,
+        metric: &MetricDefinition,
+        test_cases: &[TestCaseInput],
+        use_overrides: bool,
+    ) -> Result<Vec<TestCaseResult>, ApplicationError> {
+        let default_evaluator = self.metric_evaluator(metric)?;
+
+        let evaluators = test_cases
+            .iter()
+            .map(|test_case| self.case_evaluator(&default_evaluator, test_case, use_overrides))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut results = Vec::with_capacity(test_cases.len());
+        for (test_case, evaluator) in test_cases.iter().zip(evaluators) {
+            results.push(evaluate_case(evaluator.as_ref(), &metric.name, test_case).await);
+        }
+
+        Ok(results)
+    }
+
+    /// Evaluator derived from a metric's type
+    pub(super) fn metric_evaluator(
+        &self,
+        metric: &MetricDefinition,
+    ) -> Result<Arc<dyn Evaluator>, ApplicationError> {
+        let evaluator_name = self.metric_type_to_evaluator(&metric.metric_type);
+        self.get_evaluator(&evaluator_name)
+            .ok_or_else(|| ApplicationError::Internal(format!("Evaluator not found: {}", evaluator_name)))
+    }
+
+    /// Evaluator for one test case, honouring its override when `use_overrides` is set
+    pub(super) fn case_evaluator(
+        &self,
+        default: &Arc<dyn Evaluator>,
+        test_case: &TestCaseInput,
+        use_overrides: bool,
+    ) -> Result<Arc<dyn Evaluator>, ApplicationError> {
+        match &test_case.evaluator {
+            Some(name) if use_overrides => self.get_evaluator(name).ok_or_else(|| {
+                ApplicationError::InvalidInput(format!(
+                    "Unknown evaluator '{}' for test case {}",
+                    name, test_case.id
+                ))
+            }),
+            _ => Ok(default.clone()),
+        }
+    }
+
+    /// Aggregate latency percentiles and mean throughput from test case timings.
+    ///
+    /// Test cases without timing data are excluded; throughput additionally
+    /// requires a token count and a non-zero latency.
+    fn timing_metrics(&self, test_cases: &[TestCaseInput]) -> Vec<(String, MetricScore)> {
+        let timings: Vec<_> = test_cases
+            .iter()
+            .map(|tc| (tc.latency_ms, tc.tokens_generated))
+            .collect();
+        self.timings_to_metrics(&timings)
+    }
+
+    /// Timing metrics recomputed from stored test case results, e.g. after
+    /// their latencies have been backfilled
+    pub fn result_timing_metrics(
+        &self,
+        results: &[TestCaseResult],
+    ) -> Vec<(String, MetricScore)> {
+        let timings: Vec<_> = results
+            .iter()
+            .map(|tc| (tc.latency_ms, tc.tokens_generated))
+            .collect();
+        self.timings_to_metrics(&timings)
+    }
+
+    /// Names of the metrics produced from test case timings
+    pub fn timing_metric_names() -> &'static [&'static str] {
+        &[
+            LATENCY_P50_METRIC,
+            LATENCY_P90_METRIC,
+            LATENCY_P99_METRIC,
+            THROUGHPUT_METRIC,
+        ]
+    }
+
+    fn timings_to_metrics(
+        &self,
+        timings: &[(Option<u64>, Option<u32>)],
+    ) -> Vec<(String, MetricScore)> {
+        let mut metrics = Vec::new();
+
+        let latencies: Vec<f64> = timings
+            .iter()
+            .filter_map(|(latency, _)| latency.map(|l| l as f64))
+            .collect();
+
+        if !latencies.is_empty() {
+            for (name, p) in [
+                (LATENCY_P50_METRIC, 50.0),
+                (LATENCY_P90_METRIC, 90.0),
+                (LATENCY_P99_METRIC, 99.0),
+            ] {
+                metrics.push((
+                    name.to_string(),
+                    MetricScore {
+                        value: self.latency_percentile(&latencies, p),
+                        unit: Some("ms".to_string()),
+                        raw_values: None,
+                        std_dev: None,
+                    },
+                ));
+            }
+        }
+
+        let throughputs: Vec<f64> = timings
+            .iter()
+            .filter_map(|timing| match *timing {
+                (Some(latency), Some(tokens)) if latency > 0 => {
+                    Some(tokens as f64 / (latency as f64 / 1000.0))
+                }
+                _ => None,
+            })
+            .collect();
+
+        if !throughputs.is_empty() {
+            metrics.push((
+                THROUGHPUT_METRIC.to_string(),
+                MetricScore {
+                    value: self.mean(&throughputs),
+                    unit: Some("tokens/s".to_string()),
+                    std_dev: Some(self.calculate_std_dev(&throughputs)),
+                    raw_values: Some(throughputs),
+                },
+            ));
+        }
+
+        metrics
+    }
+
+    /// Accuracy per unit cost.
+    ///
+    /// Returns 0.0 when the cost is zero, negative or not finite, since no
+    /// meaningful ratio exists.
+    pub fn compute_efficiency(&self, accuracy: f64, cost_per_token: f64) -> f64 {
+        if !cost_per_token.is_finite() || cost_per_token <= 0.0 {
+            return 0.0;
+        }
+        accuracy / cost_per_token
+    }
+
+    /// Measured cost per generated token: the summed cost of the test cases
+    /// that report both a cost and a token count, over their summed tokens.
+    ///
+    /// `None` when no test case reports both or the total is not positive.
+    pub fn cost_per_token(&self, test_cases: &[TestCaseInput]) -> Option<f64> {
+        let mut totals = CostTotals::default();
+        for test_case in test_cases {
+            totals.push(test_case);
+        }
+        totals.cost_per_token()
+    }
+
+    /// Composite cost-efficiency metric, if the criteria define an accuracy
+    /// metric and the submission's measured cost per token is positive.
+    pub(super) fn efficiency_metric(
+        &self,
+        criteria: &EvaluationCriteria,
+        metric_scores: &HashMap<String, MetricScore>,
+        cost_per_token: Option<f64>,
+    ) -> Option<MetricScore> {
+        use llm_benchmark_domain::evaluation::MetricType;
+
+        let cost = cost_per_token.filter(|cost| cost.is_finite() && *cost > 0.0)?;
+        let accuracy = std::iter::once(&criteria.primary_metric)
+            .chain(&criteria.secondary_metrics)
+            .find(|m| matches!(m.metric_type, MetricType::Accuracy))
+            .and_then(|m| metric_scores.get(&m.name))
+            .map(|score| score.value)?;
+
+        Some(MetricScore {
+            value: self.compute_efficiency(accuracy, cost),
+            unit: Some("accuracy/cost".to_string()),
+            raw_values: None,
+            std_dev: None,
+        })
+    }
+
     /// Map metric type to evaluator name
-    fn metric_type_to_evaluator(&self, metric_type: &llm_benchmark_domain::evaluation::MetricType) -> String {
+    pub(super) fn metric_type_to_evaluator(&self, metric_type: &llm_benchmark_domain::evaluation::MetricType) -> String {
         use llm_benchmark_domain::evaluation::MetricType;
         match metric_type {
             MetricType::ExactMatch => "exact_match".to_string(),
@@ -290,6 +821,7 @@ impl ScoringEngine {
         &self,
         scores: &[f64],
         weights: &[f64],
+        difficulties: &[Option<DifficultyLevel>],
         method: &AggregationMethod,
     ) -> Result<f64, ApplicationError> {
         if scores.is_empty() {
@@ -298,33 +830,102 @@ impl ScoringEngine {
             ));
         }
 
-        match method {
-            AggregationMethod::Mean => Ok(self.mean(scores)),
-            AggregationMethod::WeightedMean { weights: method_weights } => {
-                // Use method weights if provided, otherwise use test case weights
-                let w: Vec<f64> = if method_weights.is_empty() {
-                    weights.to_vec()
-                } else {
-                    scores
-                        .iter()
-                        .enumerate()
-                        .map(|(i, _)| method_weights.get(&i.to_string()).copied().unwrap_or(1.0))
-                        .collect()
-                };
-                Ok(self.weighted_mean(scores, &w))
+        let aggregate = match method {
+            AggregationMethod::Mean => self.mean(scores),
+            AggregationMethod::WeightedMean { .. } => {
+                let w = self
+                    .method_weights(scores.len(), weights, difficulties, method)
+                    .unwrap_or_default();
+                self.weighted_mean(scores, &w)
+            }
+            AggregationMethod::Median => self.median(scores),
+            AggregationMethod::GeometricMean => self.geometric_mean(scores),
+            AggregationMethod::HarmonicMean => self.harmonic_mean(scores),
+            AggregationMethod::Min => scores.iter().cloned().fold(f64::INFINITY, f64::min),
+            AggregationMethod::Max => scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            AggregationMethod::Percentile { percentile } => self.percentile(scores, *percentile),
+            AggregationMethod::TrimmedMean { trim_fraction } => {
+                method.validate().map_err(ApplicationError::ValidationFailed)?;
+                self.trimmed_mean(scores, *trim_fraction)
+            }
+            AggregationMethod::DifficultyWeighted { .. } => {
+                method.validate().map_err(ApplicationError::ValidationFailed)?;
+                let w = self
+                    .method_weights(scores.len(), weights, difficulties, method)
+                    .unwrap_or_default();
+                self.weighted_mean(scores, &w)
             }
-            AggregationMethod::Median => Ok(self.median(scores)),
-            AggregationMethod::GeometricMean => Ok(self.geometric_mean(scores)),
-            AggregationMethod::HarmonicMean => Ok(self.harmonic_mean(scores)),
-            AggregationMethod::Min => Ok(scores.iter().cloned().fold(f64::INFINITY, f64::min)),
-            AggregationMethod::Max => Ok(scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max)),
-            AggregationMethod::Percentile { percentile } => Ok(self.percentile(scores, *percentile)),
             AggregationMethod::Custom { formula } => {
                 // For custom formulas, fall back to mean
                 warn!(formula = %formula, "Custom aggregation not implemented, using mean");
-                Ok(self.mean(scores))
+                self.mean(scores)
+            }
+        };
+
+        Ok(finite_or(aggregate, 0.0, "aggregate score"))
+    }
+
+    /// Per-case weights for the weighted-mean style methods
+    fn method_weights(
+        &self,
+        len: usize,
+        weights: &[f64],
+        difficulties: &[Option<DifficultyLevel>],
+        method: &AggregationMethod,
+    ) -> Option<Vec<f64>> {
+        match method {
+            AggregationMethod::WeightedMean { weights: method_weights } if method_weights.is_empty() => {
+                Some(weights.to_vec())
+            }
+            AggregationMethod::WeightedMean { .. } => {
+                Some((0..len).map(|i| case_weight(method, i, 1.0, None)).collect())
+            }
+            AggregationMethod::DifficultyWeighted { .. } => Some(
+                difficulties
+                    .iter()
+                    .enumerate()
+                    .map(|(i, difficulty)| case_weight(method, i, 1.0, *difficulty))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Effective per-case weights when the aggregate is a weighted mean of
+    /// the scores, or `None` for methods that aren't
+    fn linear_weights(
+        &self,
+        scores: &[f64],
+        weights: &[f64],
+        difficulties: &[Option<DifficultyLevel>],
+        method: &AggregationMethod,
+    ) -> Option<Vec<f64>> {
+        let w = match method {
+            AggregationMethod::Mean | AggregationMethod::Custom { .. } => vec![1.0; scores.len()],
+            AggregationMethod::WeightedMean { .. } | AggregationMethod::DifficultyWeighted { .. } => {
+                self.method_weights(scores.len(), weights, difficulties, method)?
+            }
+            AggregationMethod::TrimmedMean { trim_fraction } => {
+                let mut w = vec![1.0; scores.len()];
+                if scores.len() >= 3 {
+                    let mut order: Vec<usize> = (0..scores.len()).collect();
+                    order.sort_by(|&a, &b| scores[a].total_cmp(&scores[b]));
+                    let trim = (scores.len() as f64 * trim_fraction).floor() as usize;
+                    for &i in order[..trim].iter().chain(&order[scores.len() - trim..]) {
+                        w[i] = 0.0;
+                    }
+                }
+                w
             }
+            _ => return None,
+        };
+
+        // `weighted_mean` falls back to a plain mean for unusable weights
+        let sum: f64 = w.iter().sum();
+        if w.len() != scores.len() || sum == 0.0 || !sum.is_finite() {
+            return Some(vec![1.0; scores.len()]);
         }
+        Some(w)
     }
 
     /// Calculate arithmetic mean
@@ -335,6 +936,20 @@ impl ScoringEngine {
         scores.iter().sum::<f64>() / scores.len() as f64
     }
 
+    /// Calculate mean after discarding `trim_fraction` of sorted scores from each end
+    ///
+    /// With fewer than three scores there are no tails to drop, so this is
+    /// the plain mean.
+    fn trimmed_mean(&self, scores: &[f64], trim_fraction: f64) -> f64 {
+        if scores.len() < 3 {
+            return self.mean(scores);
+        }
+        let mut sorted = scores.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let trim = (sorted.len() as f64 * trim_fraction).floor() as usize;
+        self.mean(&sorted[trim..sorted.len() - trim])
+    }
+
     /// Calculate weighted mean
     fn weighted_mean(&self, scores: &[f64], weights: &[f64]) -> f64 {
         if scores.is_empty() || weights.is_empty() {
@@ -342,7 +957,7 @@ impl ScoringEngine {
         }
 
         let weight_sum: f64 = weights.iter().sum();
-        if weight_sum == 0.0 {
+        if weight_sum == 0.0 || !weight_sum.is_finite() {
             return self.mean(scores);
         }
 
@@ -428,8 +1043,40 @@ impl ScoringEngine {
         sorted[idx]
     }
 
+    /// Nearest-rank percentile of latencies: the smallest value with at
+    /// least p% of values at or below it
+    ///
+    /// Kept apart from [`Self::percentile`], whose floor index decides
+    /// `AggregationMethod::Percentile` scores.
+    fn latency_percentile(&self, latencies: &[f64], p: f64) -> f64 {
+        if latencies.is_empty() {
+            return 0.0;
+        }
+
+        let mut sorted: Vec<f64> = latencies.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let p = p.clamp(0.0, 100.0) / 100.0;
+        let rank = (sorted.len() as f64 * p).ceil() as usize;
+        let idx = rank.saturating_sub(1).min(sorted.len() - 1);
+
+        sorted[idx]
+    }
+
     /// Normalize a score according to the configured method
-    fn normalize_score(&self, score: f64, method: &ScoreNormalization) -> f64 {
+    pub(super) fn normalize_score(
+        &self,
+        score: f64,
+        method: &ScoreNormalization,
+        population: Option<&[f64]>,
+    ) -> f64 {
+        let population: Vec<f64> = population
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect();
+
         match method {
             ScoreNormalization::None => score,
             ScoreNormalization::MinMax { min, max } => {
@@ -439,13 +1086,23 @@ impl ScoringEngine {
                 (score - min) / (max - min)
             }
             ScoreNormalization::ZScore => {
-                // Z-score normalization requires population stats
-                // For single score, return as-is
-                score
+                // Without a population (or any spread in it) there is
+                // nothing to standardize against
+                let std_dev = self.calculate_std_dev(&population);
+                if std_dev > 0.0 {
+                    (score - self.mean(&population)) / std_dev
+                } else {
+                    score
+                }
             }
             ScoreNormalization::Percentile => {
-                // Percentile normalization requires population
-                score
+                if population.is_empty() {
+                    return score;
+                }
+                // Percentile rank, counting ties as half below
+                let below = population.iter().filter(|&&v| v < score).count() as f64;
+                let equal = population.iter().filter(|&&v| v == score).count() as f64;
+                (below + 0.5 * equal) / population.len() as f64
             }
             ScoreNormalization::LogScale => {
                 if score <= 0.0 {
@@ -466,38 +1123,48 @@ impl ScoringEngine {
         let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>()
             / (values.len() - 1) as f64;
 
-        variance.sqrt()
+        finite_or(variance.sqrt(), 0.0, "standard deviation")
     }
 
     /// Calculate confidence interval
     fn calculate_confidence_interval(&self, scores: &[f64], confidence_level: f64) -> ConfidenceInterval {
-        let n = scores.len() as f64;
-        let mean = self.mean(scores);
-        let std_dev = self.calculate_std_dev(scores);
-        let std_error = std_dev / n.sqrt();
-
-        // Z-scores for common confidence levels
-        let z = match confidence_level {
-            l if (l - 0.90).abs() < 0.01 => 1.645,
-            l if (l - 0.95).abs() < 0.01 => 1.96,
-            l if (l - 0.99).abs() < 0.01 => 2.576,
-            _ => 1.96, // Default to 95%
-        };
+        self.confidence_interval_from(
+            self.mean(scores),
+            self.calculate_std_dev(scores),
+            scores.len(),
+            confidence_level,
+        )
+    }
 
-        let margin = z * std_error;
+    /// Confidence interval from a sample's mean, standard deviation and size
+    pub(super) fn confidence_interval_from(
+        &self,
+        mean: f64,
+        std_dev: f64,
+        n: usize,
+        confidence_level: f64,
+    ) -> ConfidenceInterval {
+        let std_error = std_dev / (n as f64).sqrt();
+        let critical = critical_value(confidence_level, n);
+
+        // A degenerate interval at the mean beats an unusable one
+        let mean = finite_or(mean, 0.0, "confidence interval mean");
+        let margin = finite_or(critical * std_error, 0.0, "confidence interval margin");
 
         ConfidenceInterval {
-            lower: mean - margin,
-            upper: mean + margin,
+            lower: finite_or(mean - margin, mean, "confidence interval lower bound"),
+            upper: finite_or(mean + margin, mean, "confidence interval upper bound"),
             confidence_level,
         }
     }
 
     /// Calculate statistical significance metrics
     fn calculate_statistical_significance(&self, scores: &[f64]) -> StatisticalSignificance {
-        let n = scores.len();
-        let mean = self.mean(scores);
-        let std_dev = self.calculate_std_dev(scores);
+        self.significance_from(self.mean(scores), self.calculate_std_dev(scores), scores.len())
+    }
+
+    /// Statistical significance from a sample's mean, standard deviation and size
+    pub(super) fn significance_from(&self, mean: f64, std_dev: f64, n: usize) -> StatisticalSignificance {
 
         // Calculate t-statistic for one-sample t-test against 0
         let t_stat = if std_dev > 0.0 {
@@ -507,46 +1174,32 @@ impl ScoringEngine {
         };
 
         // Approximate p-value using normal distribution (valid for large n)
-        // For exact p-values, would need t-distribution tables
-        let p_value = 2.0 * (1.0 - self.standard_normal_cdf(t_stat.abs()));
+        // For exact p-values, would need t-distribution tables. Taking the
+        // lower tail rather than 1 - cdf keeps precision for extreme
+        // t-statistics, and an infinite one gives a p-value of exactly 0.
+        let p_value = 2.0 * standard_normal_cdf(-t_stat.abs());
 
         // Cohen's d effect size
         let effect_size = if std_dev > 0.0 { mean / std_dev } else { 0.0 };
 
         StatisticalSignificance {
-            p_value,
-            effect_size,
+            p_value: finite_or(p_value, 1.0, "p-value"),
+            effect_size: finite_or(effect_size, 0.0, "effect size"),
             sample_size: n,
             test_used: "one-sample t-test".to_string(),
         }
     }
 
-    /// Approximate standard normal CDF using error function approximation
-    fn standard_normal_cdf(&self, x: f64) -> f64 {
-        0.5 * (1.0 + self.erf(x / std::f64::consts::SQRT_2))
-    }
-
-    /// Error function approximation (Horner's method)
-    fn erf(&self, x: f64) -> f64 {
-        // Abramowitz and Stegun approximation
-        let a1 = 0.254829592;
-        let a2 = -0.284496736;
-        let a3 = 1.421413741;
-        let a4 = -1.453152027;
-        let a5 = 1.061405429;
-        let p = 0.3275911;
-
-        let sign = if x < 0.0 { -1.0 } else { 1.0 };
-        let x = x.abs();
-
-        let t = 1.0 / (1.0 + p * x);
-        let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
-
-        sign * y
-    }
-
     /// Detect outliers using Z-score method
     pub fn detect_outliers(&self, scores: &[f64]) -> Vec<usize> {
+        self.outlier_z_scores(scores)
+            .into_iter()
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Z-scores of the values exceeding the outlier threshold, with their indices
+    pub fn outlier_z_scores(&self, scores: &[f64]) -> Vec<(usize, f64)> {
         if scores.len() < 3 {
             return vec![];
         }
@@ -561,32 +1214,122 @@ impl ScoringEngine {
         scores
             .iter()
             .enumerate()
-            .filter(|(_, &score)| {
-                let z_score = (score - mean).abs() / std_dev;
-                z_score > self.config.outlier_z_threshold
-            })
-            .map(|(i, _)| i)
+            .map(|(i, &score)| (i, (score - mean) / std_dev))
+            .filter(|(_, z_score)| z_score.abs() > self.config.outlier_z_threshold)
             .collect()
     }
 
-    /// Score with outlier removal
-    #[instrument(skip(self, request))]
-    pub async fn score_with_outlier_removal(
+    /// Check that two runs of the same submission are equivalent
+    ///
+    /// Both requests are scored and matched by test case id. Test cases whose
+    /// scores differ by more than `tolerance`, or that appear in only one run,
+    /// are reported as divergences. The check passes when there are no
+    /// divergences and the aggregate scores are also within `tolerance`.
+    #[instrument(skip(self, a, b))]
+    pub async fn check_reproducibility(
         &self,
-        request: &ScoringRequest,
-    ) -> Result<(SubmissionResults, Vec<String>), ApplicationError> {
-        // First pass: score all test cases
-        let initial_results = self.score(request).await?;
+        a: &ScoringRequest,
+        b: &ScoringRequest,
+        tolerance: f64,
+    ) -> Result<ReproducibilityReport, ApplicationError> {
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(ApplicationError::ValidationFailed(format!(
+                "Tolerance must be a non-negative number, got {}",
+                tolerance
+            )));
+        }
 
-        // Detect outliers from initial scores
-        let scores: Vec<f64> = initial_results
+        let results_a = self.score(a).await?;
+        let results_b = self.score(b).await?;
+
+        let scores_b: HashMap<&str, f64> = results_b
             .test_case_results
             .iter()
-            .map(|r| r.score)
+            .map(|r| (r.test_case_id.as_str(), r.score))
             .collect();
-        let outlier_indices = self.detect_outliers(&scores);
 
-        if outlier_indices.is_empty() {
+        let mut divergences = Vec::new();
+        let mut compared_test_cases = 0;
+
+        for result in &results_a.test_case_results {
+            match scores_b.get(result.test_case_id.as_str()) {
+                Some(&score_b) => {
+                    compared_test_cases += 1;
+                    let difference = (result.score - score_b).abs();
+                    if difference > tolerance {
+                        divergences.push(TestCaseDivergence {
+                            test_case_id: result.test_case_id.clone(),
+                            score_a: Some(result.score),
+                            score_b: Some(score_b),
+                            difference: Some(difference),
+                        });
+                    }
+                }
+                None => divergences.push(TestCaseDivergence {
+                    test_case_id: result.test_case_id.clone(),
+                    score_a: Some(result.score),
+                    score_b: None,
+                    difference: None,
+                }),
+            }
+        }
+
+        let ids_a: HashSet<&str> = results_a
+            .test_case_results
+            .iter()
+            .map(|r| r.test_case_id.as_str())
+            .collect();
+        for result in &results_b.test_case_results {
+            if !ids_a.contains(result.test_case_id.as_str()) {
+                divergences.push(TestCaseDivergence {
+                    test_case_id: result.test_case_id.clone(),
+                    score_a: None,
+                    score_b: Some(result.score),
+                    difference: None,
+                });
+            }
+        }
+
+        let aggregate_difference = (results_a.aggregate_score - results_b.aggregate_score).abs();
+        let passed = divergences.is_empty() && aggregate_difference <= tolerance;
+
+        if !passed {
+            warn!(
+                divergences = divergences.len(),
+                aggregate_difference,
+                tolerance,
+                "Runs are not reproducible within tolerance"
+            );
+        }
+
+        Ok(ReproducibilityReport {
+            passed,
+            tolerance,
+            aggregate_score_a: results_a.aggregate_score,
+            aggregate_score_b: results_b.aggregate_score,
+            compared_test_cases,
+            divergences,
+        })
+    }
+
+    /// Score with outlier removal
+    #[instrument(skip(self, request))]
+    pub async fn score_with_outlier_removal(
+        &self,
+        request: &ScoringRequest,
+    ) -> Result<(SubmissionResults, Vec<String>), ApplicationError> {
+        // First pass: score all test cases
+        let initial_results = self.score(request).await?;
+
+        // Detect outliers from initial scores
+        let scores: Vec<f64> = initial_results
+            .test_case_results
+            .iter()
+            .map(|r| r.score)
+            .collect();
+        let outlier_indices = self.detect_outliers(&scores);
+
+        if outlier_indices.is_empty() {
             return Ok((initial_results, vec![]));
         }
 
@@ -608,6 +1351,7 @@ impl ScoringEngine {
             test_cases: filtered_test_cases,
             criteria: request.criteria.clone(),
             metadata: request.metadata.clone(),
+            population_scores: request.population_scores.clone(),
         };
 
         let filtered_results = self.score(&filtered_request).await?;
@@ -655,6 +1399,11 @@ impl ScoringEngineBuilder {
         self
     }
 
+    pub fn max_test_cases(mut self, max: usize) -> Self {
+        self.config.max_test_cases = max;
+        self
+    }
+
     pub fn evaluator(mut self, name: &str, evaluator: Arc<dyn Evaluator>) -> Self {
         self.custom_evaluators.insert(name.to_string(), evaluator);
         self
@@ -678,10 +1427,204 @@ impl Default for ScoringEngineBuilder {
     }
 }
 
+/// Samples larger than this use the normal approximation for intervals
+const T_DISTRIBUTION_MAX_SAMPLES: usize = 200;
+
+/// Two-sided normal critical values at 90%, 95% and 99% confidence
+const Z_CRITICAL: [f64; 3] = [1.645, 1.96, 2.576];
+
+/// Two-sided Student's t critical values at 90%, 95% and 99% confidence,
+/// keyed by degrees of freedom
+const T_CRITICAL: [(usize, [f64; 3]); 37] = [
+    (1, [6.314, 12.706, 63.657]),
+    (2, [2.920, 4.303, 9.925]),
+    (3, [2.353, 3.182, 5.841]),
+    (4, [2.132, 2.776, 4.604]),
+    (5, [2.015, 2.571, 4.032]),
+    (6, [1.943, 2.447, 3.707]),
+    (7, [1.895, 2.365, 3.499]),
+    (8, [1.860, 2.306, 3.355]),
+    (9, [1.833, 2.262, 3.250]),
+    (10, [1.812, 2.228, 3.169]),
+    (11, [1.796, 2.201, 3.106]),
+    (12, [1.782, 2.179, 3.055]),
+    (13, [1.771, 2.160, 3.012]),
+    (14, [1.761, 2.145, 2.977]),
+    (15, [1.753, 2.131, 2.947]),
+    (16, [1.746, 2.120, 2.921]),
+    (17, [1.740, 2.110, 2.898]),
+    (18, [1.734, 2.101, 2.878]),
+    (19, [1.729, 2.093, 2.861]),
+    (20, [1.725, 2.086, 2.845]),
+    (21, [1.721, 2.080, 2.831]),
+    (22, [1.717, 2.074, 2.819]),
+    (23, [1.714, 2.069, 2.807]),
+    (24, [1.711, 2.064, 2.797]),
+    (25, [1.708, 2.060, 2.787]),
+    (26, [1.706, 2.056, 2.779]),
+    (27, [1.703, 2.052, 2.771]),
+    (28, [1.701, 2.048, 2.763]),
+    (29, [1.699, 2.045, 2.756]),
+    (30, [1.697, 2.042, 2.750]),
+    (40, [1.684, 2.021, 2.704]),
+    (50, [1.676, 2.009, 2.678]),
+    (60, [1.671, 2.000, 2.660]),
+    (80, [1.664, 1.990, 2.639]),
+    (100, [1.660, 1.984, 2.626]),
+    (120, [1.658, 1.980, 2.617]),
+    (200, [1.653, 1.972, 2.601]),
+];
+
+/// Critical value for a two-sided interval around the mean of `n` samples
+///
+/// Uses Student's t with `n - 1` degrees of freedom, interpolating linearly
+/// in `1 / df` between tabulated rows, and the normal distribution once `n`
+/// exceeds [`T_DISTRIBUTION_MAX_SAMPLES`]. Levels other than 90%, 95% and
+/// 99% are treated as 95%.
+pub(super) fn critical_value(confidence_level: f64, n: usize) -> f64 {
+    let column = match confidence_level {
+        l if (l - 0.90).abs() < 0.01 => 0,
+        l if (l - 0.99).abs() < 0.01 => 2,
+        _ => 1,
+    };
+    if n > T_DISTRIBUTION_MAX_SAMPLES {
+        return Z_CRITICAL[column];
+    }
+
+    let df = n.saturating_sub(1).max(1);
+    match T_CRITICAL.iter().position(|(row_df, _)| *row_df >= df) {
+        Some(i) if T_CRITICAL[i].0 == df => T_CRITICAL[i].1[column],
+        Some(i) => {
+            let (lo_df, lo) = T_CRITICAL[i - 1];
+            let (hi_df, hi) = T_CRITICAL[i];
+            let inv = |d: usize| 1.0 / d as f64;
+            let fraction = (inv(lo_df) - inv(df)) / (inv(lo_df) - inv(hi_df));
+            lo[column] + fraction * (hi[column] - lo[column])
+        }
+        None => Z_CRITICAL[column],
+    }
+}
+
+/// Standard normal cumulative distribution function
+pub(super) fn standard_normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Complementary error function, `1 - erf(x)`
+///
+/// W. J. Cody's rational Chebyshev approximation (ACM Algorithm 715),
+/// accurate to about 1e-16 across the real line. Computing the complement
+/// directly keeps full relative precision in the tails, where `1 - erf(x)`
+/// would cancel to zero.
+pub(super) fn erfc(x: f64) -> f64 {
+    const A: [f64; 5] = [
+        3.161_123_743_870_565_5e0,
+        1.138_641_541_510_501_6e2,
+        3.774_852_376_853_02e2,
+        3.209_377_589_138_469_4e3,
+        1.857_777_061_846_031_5e-1,
+    ];
+    const B: [f64; 4] = [
+        2.360_129_095_234_412e1,
+        2.440_246_379_344_441_7e2,
+        1.282_616_526_077_372_3e3,
+        2.844_236_833_439_171e3,
+    ];
+    const C: [f64; 9] = [
+        5.641_884_969_886_701e-1,
+        8.883_149_794_388_377e0,
+        6.611_919_063_714_163e1,
+        2.986_351_381_974_001e2,
+        8.819_522_212_417_69e2,
+        1.712_047_612_634_070_7e3,
+        2.051_078_377_826_071_6e3,
+        1.230_339_354_797_997_2e3,
+        2.153_115_354_744_038_3e-8,
+    ];
+    const D: [f64; 8] = [
+        1.574_492_611_070_983_5e1,
+        1.176_939_508_913_125e2,
+        5.371_811_018_620_099e2,
+        1.621_389_574_566_690_3e3,
+        3.290_799_235_733_459_7e3,
+        4.362_619_090_143_247e3,
+        3.439_367_674_143_721_6e3,
+        1.230_339_354_803_749_3e3,
+    ];
+    const P: [f64; 6] = [
+        3.053_266_349_612_323_6e-1,
+        3.603_448_999_498_044_5e-1,
+        1.257_817_261_112_292_6e-1,
+        1.608_378_514_874_228e-2,
+        6.587_491_615_298_378e-4,
+        1.631_538_713_730_209_7e-2,
+    ];
+    const Q: [f64; 5] = [
+        2.568_520_192_289_822e0,
+        1.872_952_849_923_467_3e0,
+        5.279_051_029_514_285e-1,
+        6.051_834_131_244_132e-2,
+        2.335_204_976_268_691_8e-3,
+    ];
+    /// Beyond this erfc underflows to zero
+    const X_BIG: f64 = 26.543;
+
+    if x.is_nan() {
+        return f64::NAN;
+    }
+
+    let y = x.abs();
+    if y <= 0.5 {
+        // erf(x) = x * P(x^2) / Q(x^2)
+        let ysq = y * y;
+        let mut num = A[4] * ysq;
+        let mut den = ysq;
+        for i in 0..3 {
+            num = (num + A[i]) * ysq;
+            den = (den + B[i]) * ysq;
+        }
+        return 1.0 - x * (num + A[3]) / (den + B[3]);
+    }
+
+    let tail = if y >= X_BIG {
+        0.0
+    } else {
+        let ratio = if y <= 4.0 {
+            let mut num = C[8] * y;
+            let mut den = y;
+            for i in 0..7 {
+                num = (num + C[i]) * y;
+                den = (den + D[i]) * y;
+            }
+            (num + C[7]) / (den + D[7])
+        } else {
+            let ysq = 1.0 / (y * y);
+            let mut num = P[5] * ysq;
+            let mut den = ysq;
+            for i in 0..4 {
+                num = (num + P[i]) * ysq;
+                den = (den + Q[i]) * ysq;
+            }
+            let r = ysq * (num + P[4]) / (den + Q[4]);
+            (std::f64::consts::FRAC_2_SQRT_PI / 2.0 - r) / y
+        };
+        // exp(-y^2) split in two to avoid rounding error in y^2
+        let ysq = (y * 16.0).trunc() / 16.0;
+        let del = (y - ysq) * (y + ysq);
+        (-ysq * ysq).exp() * (-del).exp() * ratio
+    };
+
+    if x < 0.0 {
+        2.0 - tail
+    } else {
+        tail
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use llm_benchmark_domain::evaluation::{MetricDefinition, MetricType};
+    use llm_benchmark_domain::evaluation::MetricType;
 
     fn make_test_criteria() -> EvaluationCriteria {
         EvaluationCriteria {
@@ -698,6 +1641,8 @@ mod tests {
             score_normalization: ScoreNormalization::None,
             minimum_test_cases: 1,
             confidence_level: 0.95,
+            score_precision: None,
+            leaderboard_min_verification: Default::default(),
         }
     }
 
@@ -709,7 +1654,10 @@ mod tests {
             context: HashMap::new(),
             latency_ms: Some(100),
             tokens_generated: Some(50),
+            cost: None,
             weight: 1.0,
+            difficulty: None,
+            evaluator: None,
         }
     }
 
@@ -725,6 +1673,7 @@ mod tests {
             ],
             criteria: make_test_criteria(),
             metadata: HashMap::new(),
+            population_scores: None,
         };
 
         let results = engine.score(&request).await.unwrap();
@@ -738,6 +1687,215 @@ mod tests {
         assert!(results.aggregate_score > 0.6 && results.aggregate_score < 0.7);
     }
 
+    #[tokio::test]
+    async fn test_max_test_cases() {
+        let engine = ScoringEngineBuilder::new().max_test_cases(3).build();
+
+        let at_limit = make_request(vec![
+            make_test_case("1", "a", "a"),
+            make_test_case("2", "b", "b"),
+            make_test_case("3", "c", "c"),
+        ]);
+        let results = engine.score(&at_limit).await.unwrap();
+        assert_eq!(results.test_case_results.len(), 3);
+
+        let mut over_limit = at_limit.clone();
+        over_limit.test_cases.push(make_test_case("4", "d", "d"));
+        let err = engine.score(&over_limit).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::InvalidInput(_)));
+        assert!(err.to_string().contains("maximum is 3"));
+    }
+
+    #[test]
+    fn test_max_test_cases_defaults_when_missing() {
+        let config: ScoringEngineConfig = serde_json::from_value(serde_json::json!({
+            "default_confidence_level": 0.95,
+            "min_test_cases_for_stats": 30,
+            "max_concurrent_evaluations": 100,
+            "detailed_breakdown": true,
+            "outlier_z_threshold": 3.0,
+        }))
+        .unwrap();
+        assert_eq!(config.max_test_cases, DEFAULT_MAX_TEST_CASES);
+    }
+
+    #[tokio::test]
+    async fn test_secondary_metrics_evaluated_independently() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let mut criteria = make_test_criteria();
+        criteria.primary_metric.metric_type = MetricType::ExactMatch;
+        criteria.secondary_metrics = vec![MetricDefinition {
+            name: "latency".to_string(),
+            description: "Reported latency".to_string(),
+            metric_type: MetricType::Latency,
+            unit: Some("ms".to_string()),
+            higher_is_better: false,
+            range: None,
+        }];
+
+        // Numerically equal but textually different answers only pass the
+        // numeric evaluator used for latency
+        let request = ScoringRequest {
+            test_cases: vec![
+                make_test_case("1", "42", "42.0"),
+                make_test_case("2", "7", "7"),
+                make_test_case("3", "100", "100.00"),
+                make_test_case("4", "5", "9"),
+            ],
+            criteria,
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+
+        let results = engine.score(&request).await.unwrap();
+
+        let primary = &results.metric_scores["accuracy"];
+        let secondary = &results.metric_scores["latency"];
+        assert_eq!(primary.value, 0.25);
+        assert!(secondary.value > 0.7);
+        assert_ne!(primary.value, secondary.value);
+        assert_eq!(secondary.unit.as_deref(), Some("ms"));
+
+        let raw = secondary.raw_values.as_ref().unwrap();
+        assert_eq!(raw.len(), 4);
+        assert_eq!(&raw[..3], &[1.0, 1.0, 1.0]);
+        assert!(secondary.std_dev.unwrap() > 0.0);
+
+        // Per-case results still reflect the primary metric
+        assert!(!results.test_case_results[0].passed);
+        assert!(results.test_case_results[1].passed);
+    }
+
+    fn explained_total(explanation: &MetricExplanation) -> f64 {
+        explanation
+            .test_cases
+            .iter()
+            .map(|tc| tc.contribution.unwrap())
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn test_explain_mean_contributions() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let request = make_request(vec![
+            make_test_case("1", "hello", "hello"),
+            make_test_case("2", "world", "world"),
+            make_test_case("3", "test", "wrong"),
+        ]);
+
+        let results = engine.score(&request).await.unwrap();
+        let explanation = engine.explain(&request).await.unwrap();
+
+        assert_eq!(explanation.metrics.len(), 1);
+        let primary = &explanation.metrics[0];
+        assert!(primary.primary);
+        assert_eq!(primary.metric, "accuracy");
+        assert_eq!(primary.evaluator, "exact_match");
+        assert_eq!(primary.score, results.aggregate_score);
+        assert!((explained_total(primary) - results.aggregate_score).abs() < 1e-12);
+
+        let ids: Vec<&str> = primary.test_cases.iter().map(|tc| tc.test_case_id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+        assert!(primary.test_cases.iter().all(|tc| tc.weight == Some(1.0)));
+        assert_eq!(primary.test_cases[2].contribution, Some(0.0));
+
+        // Explaining is deterministic
+        let again = engine.explain(&request).await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&explanation).unwrap(),
+            serde_json::to_value(&again).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_explain_weighted_and_trimmed() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let mut test_cases = vec![
+            make_test_case("1", "a", "a"),
+            make_test_case("2", "b", "x"),
+            make_test_case("3", "c", "c"),
+            make_test_case("4", "d", "d"),
+        ];
+        test_cases[0].weight = 3.0;
+
+        let mut request = make_request(test_cases);
+        request.criteria.aggregation_method = AggregationMethod::WeightedMean { weights: HashMap::new() };
+        let results = engine.score(&request).await.unwrap();
+        let primary = &engine.explain(&request).await.unwrap().metrics[0];
+        assert_eq!(primary.test_cases[0].weight, Some(3.0));
+        assert!((explained_total(primary) - results.aggregate_score).abs() < 1e-12);
+
+        request.criteria.aggregation_method = AggregationMethod::TrimmedMean { trim_fraction: 0.25 };
+        let results = engine.score(&request).await.unwrap();
+        let primary = &engine.explain(&request).await.unwrap().metrics[0];
+        let trimmed = primary.test_cases.iter().filter(|tc| tc.weight == Some(0.0)).count();
+        assert_eq!(trimmed, 2);
+        assert!((explained_total(primary) - results.aggregate_score).abs() < 1e-12);
+
+        // Order statistics have no per-case contribution
+        request.criteria.aggregation_method = AggregationMethod::Median;
+        let primary = &engine.explain(&request).await.unwrap().metrics[0];
+        assert!(primary.test_cases.iter().all(|tc| tc.weight.is_none() && tc.contribution.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_per_case_evaluator_override() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let mut numeric = make_test_case("numeric", "3.14", "3.140");
+        numeric.evaluator = Some("numeric_tolerance".to_string());
+        let request = make_request(vec![
+            make_test_case("exact-1", "hello", "hello"),
+            make_test_case("exact-2", "3.14", "3.140"),
+            numeric,
+        ]);
+
+        let results = engine.score(&request).await.unwrap();
+
+        // The same answer only passes where numeric tolerance applies
+        assert!(results.test_case_results[0].passed);
+        assert!(!results.test_case_results[1].passed);
+        assert!(results.test_case_results[2].passed);
+        assert!((results.aggregate_score - 2.0 / 3.0).abs() < 1e-9);
+
+        let explanation = engine.explain(&request).await.unwrap();
+        let evaluators: Vec<&str> = explanation.metrics[0]
+            .test_cases
+            .iter()
+            .map(|tc| tc.evaluator.as_str())
+            .collect();
+        assert_eq!(evaluators, ["exact_match", "exact_match", "numeric_tolerance"]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_evaluator_override_is_rejected() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let mut case = make_test_case("1", "a", "a");
+        case.evaluator = Some("telepathy".to_string());
+
+        let err = engine.score(&make_request(vec![case])).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::InvalidInput(_)));
+        assert!(err.to_string().contains("Unknown evaluator 'telepathy'"));
+    }
+
+    #[test]
+    fn test_evaluator_override_serde() {
+        let case: TestCaseInput = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "expected": "1",
+            "actual": "1.0",
+            "context": {},
+            "latency_ms": null,
+            "tokens_generated": null,
+            "weight": 1.0,
+            "evaluator": "numeric_tolerance",
+        }))
+        .unwrap();
+        assert_eq!(case.evaluator.as_deref(), Some("numeric_tolerance"));
+
+        let json = serde_json::to_value(TestCaseInput::default()).unwrap();
+        assert!(json.get("evaluator").is_none());
+    }
+
     #[tokio::test]
     async fn test_aggregation_methods() {
         let engine = ScoringEngine::new(ScoringEngineConfig::default());
@@ -766,6 +1924,133 @@ mod tests {
         assert_eq!(engine.percentile(&scores, 50.0), 3.0);
         assert_eq!(engine.percentile(&scores, 0.0), 1.0);
         assert_eq!(engine.percentile(&scores, 100.0), 5.0);
+
+        // Aggregation keeps the floor index; latencies use nearest rank
+        let even = vec![1.0, 2.0, 3.0, 4.0];
+        assert_eq!(engine.percentile(&even, 50.0), 3.0);
+        assert_eq!(engine.latency_percentile(&even, 50.0), 2.0);
+        assert_eq!(engine.latency_percentile(&even, 0.0), 1.0);
+        assert_eq!(engine.latency_percentile(&even, 100.0), 4.0);
+    }
+
+    #[tokio::test]
+    async fn test_all_zero_scores_are_finite() {
+        let engine = ScoringEngine::new(ScoringEngineConfig {
+            min_test_cases_for_stats: 1,
+            ..Default::default()
+        });
+        let zeros = vec![0.0; 5];
+        let weights = vec![0.0; 5];
+
+        for method in [
+            AggregationMethod::Mean,
+            AggregationMethod::WeightedMean { weights: HashMap::new() },
+            AggregationMethod::Median,
+            AggregationMethod::GeometricMean,
+            AggregationMethod::HarmonicMean,
+            AggregationMethod::Min,
+            AggregationMethod::Max,
+            AggregationMethod::Percentile { percentile: 90.0 },
+            AggregationMethod::TrimmedMean { trim_fraction: 0.2 },
+        ] {
+            let value = engine.aggregate_scores(&zeros, &weights, &[], &method).unwrap();
+            assert_eq!(value, 0.0, "{:?}", method);
+        }
+
+        // Every case fails, so every score is zero
+        let request = make_request((0..5).map(|i| make_test_case(&i.to_string(), "a", "b")).collect());
+        let results = engine.score(&request).await.unwrap();
+
+        assert_eq!(results.aggregate_score, 0.0);
+        let ci = results.confidence_interval.as_ref().unwrap();
+        assert!(ci.lower.is_finite() && ci.upper.is_finite());
+        let significance = results.statistical_significance.as_ref().unwrap();
+        assert!(significance.p_value.is_finite());
+        assert!(significance.effect_size.is_finite());
+        serde_json::to_string(&results).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_single_score_is_finite() {
+        let engine = ScoringEngine::new(ScoringEngineConfig {
+            min_test_cases_for_stats: 1,
+            ..Default::default()
+        });
+
+        assert_eq!(engine.calculate_std_dev(&[0.7]), 0.0);
+        let ci = engine.calculate_confidence_interval(&[0.7], 0.95);
+        assert_eq!((ci.lower, ci.upper), (0.7, 0.7));
+        let significance = engine.calculate_statistical_significance(&[0.7]);
+        assert!(significance.p_value.is_finite());
+        assert_eq!(significance.effect_size, 0.0);
+
+        let request = make_request(vec![make_test_case("only", "a", "a")]);
+        let results = engine.score(&request).await.unwrap();
+        assert_eq!(results.aggregate_score, 1.0);
+        assert!(results.confidence_interval.unwrap().upper.is_finite());
+    }
+
+    #[test]
+    fn test_standard_normal_cdf_reference_values() {
+        // Reference values of the standard normal CDF
+        let reference = [
+            (0.0, 0.5),
+            (1.0, 0.841_344_746_068_542_9),
+            (2.0, 0.977_249_868_051_820_8),
+            (3.0, 0.998_650_101_968_369_9),
+            (4.0, 0.999_968_328_758_166_9),
+        ];
+        for (x, expected) in reference {
+            let cdf = standard_normal_cdf(x);
+            assert!((cdf - expected).abs() < 1e-10, "cdf({}) = {}, expected {}", x, cdf, expected);
+            let mirrored = standard_normal_cdf(-x);
+            assert!((mirrored - (1.0 - expected)).abs() < 1e-10, "cdf({}) = {}", -x, mirrored);
+        }
+
+        // Tails keep relative precision instead of cancelling to zero
+        let tail = erfc(6.0);
+        assert!((tail / 2.151_973_671_249_891_3e-17 - 1.0).abs() < 1e-12);
+        assert_eq!(erfc(f64::INFINITY), 0.0);
+        assert_eq!(erfc(f64::NEG_INFINITY), 2.0);
+    }
+
+    #[test]
+    fn test_infinite_t_stat_gives_zero_p_value() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+
+        // A vanishing spread around a non-zero mean sends t to infinity
+        let significance = engine.significance_from(0.9, f64::MIN_POSITIVE, 100);
+        assert_eq!(significance.p_value, 0.0);
+
+        let significance = engine.significance_from(0.0, 0.1, 100);
+        assert!((significance.p_value - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_non_finite_inputs_fall_back() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let scores = vec![f64::MAX, f64::MAX];
+        let weights = vec![f64::INFINITY, 1.0];
+
+        // The sum overflows to infinity
+        let mean = engine
+            .aggregate_scores(&scores, &weights, &[], &AggregationMethod::Mean)
+            .unwrap();
+        assert_eq!(mean, 0.0);
+
+        let weighted = engine
+            .aggregate_scores(
+                &[0.5, 1.0],
+                &weights,
+                &[],
+                &AggregationMethod::WeightedMean { weights: HashMap::new() },
+            )
+            .unwrap();
+        assert_eq!(weighted, 0.75);
+
+        assert_eq!(engine.calculate_std_dev(&[f64::NAN, 1.0]), 0.0);
+        let ci = engine.calculate_confidence_interval(&[f64::NAN, 1.0], 0.95);
+        assert!(ci.lower.is_finite() && ci.upper.is_finite());
     }
 
     #[tokio::test]
@@ -782,6 +2067,108 @@ mod tests {
         assert!(ci.lower < mean && mean < ci.upper);
     }
 
+    #[test]
+    fn test_population_normalization() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let population: Vec<f64> = (1..=100).map(|i| i as f64 / 100.0).collect();
+
+        let percentile =
+            engine.normalize_score(0.9, &ScoreNormalization::Percentile, Some(&population));
+        assert!((percentile - 0.9).abs() < 0.01, "{}", percentile);
+        assert_eq!(
+            engine.normalize_score(2.0, &ScoreNormalization::Percentile, Some(&population)),
+            1.0
+        );
+        assert_eq!(
+            engine.normalize_score(0.0, &ScoreNormalization::Percentile, Some(&population)),
+            0.0
+        );
+
+        let z = engine.normalize_score(0.505, &ScoreNormalization::ZScore, Some(&population));
+        assert!(z.abs() < 1e-12);
+        let std_dev = engine.calculate_std_dev(&population);
+        let z = engine.normalize_score(
+            0.505 + std_dev,
+            &ScoreNormalization::ZScore,
+            Some(&population),
+        );
+        assert!((z - 1.0).abs() < 1e-12);
+
+        // Without a usable population both methods are no-ops
+        for method in [ScoreNormalization::Percentile, ScoreNormalization::ZScore] {
+            assert_eq!(engine.normalize_score(0.7, &method, None), 0.7);
+            assert_eq!(engine.normalize_score(0.7, &method, Some(&[])), 0.7);
+            assert_eq!(engine.normalize_score(0.7, &method, Some(&[f64::NAN])), 0.7);
+        }
+        let flat = [0.5, 0.5];
+        assert_eq!(engine.normalize_score(0.7, &ScoreNormalization::ZScore, Some(&flat)), 0.7);
+    }
+
+    #[tokio::test]
+    async fn test_score_with_population_percentile() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let mut request = make_request(
+            (0..10)
+                .map(|i| {
+                    let output = if i < 9 { "a" } else { "b" };
+                    make_test_case(&i.to_string(), "a", output)
+                })
+                .collect(),
+        );
+        request.criteria.score_normalization = ScoreNormalization::Percentile;
+
+        // Raw aggregate is 0.9, ranked against scores 0.01..=1.00
+        let unranked = engine.score(&request).await.unwrap();
+        assert!((unranked.aggregate_score - 0.9).abs() < 1e-12);
+
+        request.population_scores = Some((1..=100).map(|i| i as f64 / 100.0).collect());
+        let ranked = engine.score(&request).await.unwrap();
+        assert!((ranked.aggregate_score - 0.895).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_confidence_interval_widens_for_small_samples() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let std_dev = 0.2;
+
+        let small = engine.confidence_interval_from(0.5, std_dev, 5, 0.95);
+        let large = engine.confidence_interval_from(0.5, std_dev, 500, 0.95);
+
+        // Undo the standard error to compare the critical values directly
+        let multiplier =
+            |ci: &ConfidenceInterval, n: f64| (ci.upper - ci.lower) / 2.0 / (std_dev / n.sqrt());
+        let small_multiplier = multiplier(&small, 5.0);
+        let large_multiplier = multiplier(&large, 500.0);
+        assert!((small_multiplier - 2.776).abs() < 1e-9);
+        assert!((large_multiplier - 1.96).abs() < 1e-9);
+        assert!(small_multiplier / large_multiplier > 1.4);
+    }
+
+    #[test]
+    fn test_critical_values() {
+        // Tabulated rows
+        assert_eq!(critical_value(0.95, 2), 12.706);
+        assert_eq!(critical_value(0.90, 11), 1.812);
+        assert_eq!(critical_value(0.99, 31), 2.750);
+        assert_eq!(critical_value(0.95, 121), 1.980);
+
+        // Interpolated rows fall between their neighbours
+        let df_35 = critical_value(0.95, 36);
+        assert!(2.021 < df_35 && df_35 < 2.042);
+
+        // Large samples and unknown levels
+        assert_eq!(critical_value(0.99, 202), 2.576);
+        assert_eq!(critical_value(0.80, 1_000), 1.96);
+        assert_eq!(critical_value(0.95, 1), 12.706);
+
+        // The critical value never increases with more samples
+        for level in [0.90, 0.95, 0.99] {
+            for n in 2..=300 {
+                assert!(critical_value(level, n + 1) <= critical_value(level, n), "{} {}", level, n);
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_outlier_detection() {
         let engine = ScoringEngine::new(ScoringEngineConfig {
@@ -796,6 +2183,341 @@ mod tests {
         assert!(outliers.contains(&7)); // Index of 100.0
     }
 
+    #[test]
+    fn test_trimmed_mean() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+
+        // 18 ordinary latencies plus one extreme on each side
+        let mut scores: Vec<f64> = (0..18).map(|i| 100.0 + i as f64).collect();
+        scores.push(5000.0);
+        scores.push(0.0);
+        let weights = vec![1.0; scores.len()];
+        let method = AggregationMethod::TrimmedMean { trim_fraction: 0.1 };
+
+        // 10% of 20 trims two values from each end, removing both extremes
+        // along with the lowest and highest ordinary value
+        let trimmed = engine.aggregate_scores(&scores, &weights, &[], &method).unwrap();
+        let expected = (101..=116).sum::<i32>() as f64 / 16.0;
+        assert!((trimmed - expected).abs() < 1e-9);
+        assert!(engine.mean(&scores) > 300.0);
+
+        // Nothing trimmed is a plain mean
+        let untrimmed = engine
+            .aggregate_scores(
+                &scores,
+                &weights,
+                &[],
+                &AggregationMethod::TrimmedMean { trim_fraction: 0.0 },
+            )
+            .unwrap();
+        assert_eq!(untrimmed, engine.mean(&scores));
+    }
+
+    #[test]
+    fn test_trimmed_mean_small_samples() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let method = AggregationMethod::TrimmedMean { trim_fraction: 0.49 };
+
+        for scores in [vec![0.2], vec![0.2, 1.0]] {
+            let weights = vec![1.0; scores.len()];
+            let trimmed = engine.aggregate_scores(&scores, &weights, &[], &method).unwrap();
+            assert_eq!(trimmed, engine.mean(&scores));
+        }
+
+        // Three samples trim one value from each end, leaving the middle
+        let scores = vec![0.0, 0.4, 100.0];
+        let trimmed = engine
+            .aggregate_scores(&scores, &[1.0; 3], &[], &method)
+            .unwrap();
+        assert_eq!(trimmed, 0.4);
+    }
+
+    #[test]
+    fn test_trimmed_mean_rejects_invalid_fraction() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let scores = vec![1.0, 2.0, 3.0];
+        let weights = vec![1.0; 3];
+
+        for trim_fraction in [-0.1, 0.5, 0.9, f64::NAN] {
+            let method = AggregationMethod::TrimmedMean { trim_fraction };
+            assert!(method.validate().is_err());
+            assert!(matches!(
+                engine.aggregate_scores(&scores, &weights, &[], &method),
+                Err(ApplicationError::ValidationFailed(_))
+            ));
+        }
+    }
+
+    fn tiered_case(id: &str, passed: bool, difficulty: Option<DifficultyLevel>) -> TestCaseInput {
+        TestCaseInput {
+            difficulty,
+            ..make_test_case(id, "answer", if passed { "answer" } else { "wrong" })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_difficulty_weighted_aggregation() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let mut criteria = make_test_criteria();
+        criteria.aggregation_method = AggregationMethod::DifficultyWeighted {
+            tier_weights: HashMap::from([("hard".to_string(), 3.0), ("easy".to_string(), 0.5)]),
+        };
+
+        let mut test_cases = vec![
+            tiered_case("easy-1", true, Some(DifficultyLevel::Easy)),
+            tiered_case("easy-2", true, Some(DifficultyLevel::Easy)),
+            tiered_case("hard-1", false, Some(DifficultyLevel::Hard)),
+            tiered_case("hard-2", false, Some(DifficultyLevel::Hard)),
+            // Unlisted tier and no tier both weigh 1.0
+            tiered_case("medium", true, Some(DifficultyLevel::Medium)),
+            tiered_case("untiered", false, None),
+        ];
+        let request = ScoringRequest {
+            test_cases: test_cases.clone(),
+            criteria: criteria.clone(),
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+
+        let results = engine.score(&request).await.unwrap();
+
+        // (0.5 + 0.5 + 0 + 0 + 1 + 0) / (0.5 + 0.5 + 3 + 3 + 1 + 1)
+        let expected = 2.0 / 9.0;
+        assert!((results.aggregate_score - expected).abs() < 1e-9);
+        // Failing hard cases pull the score well below the unweighted mean
+        assert!(results.aggregate_score < 0.5);
+
+        // Reordering the cases doesn't change the weighting
+        test_cases.reverse();
+        let reordered = ScoringRequest {
+            test_cases,
+            criteria,
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+        let results = engine.score(&reordered).await.unwrap();
+        assert!((results.aggregate_score - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_difficulty_weighted_rejects_negative_weights() {
+        let method = AggregationMethod::DifficultyWeighted {
+            tier_weights: HashMap::from([("hard".to_string(), -1.0)]),
+        };
+        assert!(method.validate().is_err());
+    }
+
+    fn make_request(test_cases: Vec<TestCaseInput>) -> ScoringRequest {
+        ScoringRequest {
+            test_cases,
+            criteria: make_test_criteria(),
+            metadata: HashMap::new(),
+            population_scores: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reproducibility_identical_runs() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let run = make_request(vec![
+            make_test_case("1", "hello", "hello"),
+            make_test_case("2", "world", "wrong"),
+        ]);
+
+        let report = engine
+            .check_reproducibility(&run, &run.clone(), 0.0)
+            .await
+            .unwrap();
+
+        assert!(report.passed);
+        assert_eq!(report.compared_test_cases, 2);
+        assert!(report.divergences.is_empty());
+        assert_eq!(report.aggregate_difference(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_reproducibility_perturbed_run() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let a = make_request(vec![
+            make_test_case("1", "hello", "hello"),
+            make_test_case("2", "world", "world"),
+            make_test_case("3", "test", "test"),
+        ]);
+        let b = make_request(vec![
+            make_test_case("1", "hello", "hello"),
+            make_test_case("2", "world", "nope"),
+            make_test_case("4", "extra", "extra"),
+        ]);
+
+        let report = engine.check_reproducibility(&a, &b, 0.1).await.unwrap();
+
+        assert!(!report.passed);
+        assert_eq!(report.compared_test_cases, 2);
+        assert_eq!(report.divergences.len(), 3);
+
+        let changed = &report.divergences[0];
+        assert_eq!(changed.test_case_id, "2");
+        assert_eq!(changed.difference, Some(1.0));
+
+        let only_in_a = report.divergences.iter().find(|d| d.test_case_id == "3").unwrap();
+        assert!(only_in_a.score_b.is_none());
+        let only_in_b = report.divergences.iter().find(|d| d.test_case_id == "4").unwrap();
+        assert!(only_in_b.score_a.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reproducibility_rejects_negative_tolerance() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let run = make_request(vec![make_test_case("1", "a", "a")]);
+
+        let result = engine.check_reproducibility(&run, &run, -0.1).await;
+        assert!(matches!(result, Err(ApplicationError::ValidationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_timing_metrics() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+
+        let mut test_cases: Vec<TestCaseInput> = (1..=100)
+            .map(|i| TestCaseInput {
+                id: i.to_string(),
+                expected: "a".to_string(),
+                actual: "a".to_string(),
+                latency_ms: Some(i * 10),
+                tokens_generated: Some(i as u32),
+                ..Default::default()
+            })
+            .collect();
+        // Cases without timing data are excluded from the aggregates
+        test_cases.push(TestCaseInput {
+            id: "untimed".to_string(),
+            expected: "a".to_string(),
+            actual: "a".to_string(),
+            ..Default::default()
+        });
+
+        let request = ScoringRequest {
+            test_cases,
+            criteria: make_test_criteria(),
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+
+        let results = engine.score(&request).await.unwrap();
+
+        assert_eq!(results.metric_scores[LATENCY_P50_METRIC].value, 500.0);
+        assert_eq!(results.metric_scores[LATENCY_P90_METRIC].value, 900.0);
+        assert_eq!(results.metric_scores[LATENCY_P99_METRIC].value, 990.0);
+
+        // Every case generates 1 token per 10ms = 100 tokens/s
+        let throughput = &results.metric_scores[THROUGHPUT_METRIC];
+        assert!((throughput.value - 100.0).abs() < 1e-9);
+        assert_eq!(throughput.raw_values.as_ref().unwrap().len(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_timing_metrics_absent_without_timings() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+
+        let request = ScoringRequest {
+            test_cases: vec![TestCaseInput {
+                id: "1".to_string(),
+                expected: "a".to_string(),
+                actual: "a".to_string(),
+                ..Default::default()
+            }],
+            criteria: make_test_criteria(),
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+
+        let results = engine.score(&request).await.unwrap();
+
+        assert!(!results.metric_scores.contains_key(LATENCY_P50_METRIC));
+        assert!(!results.metric_scores.contains_key(THROUGHPUT_METRIC));
+    }
+
+    #[test]
+    fn test_compute_efficiency() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+
+        assert!((engine.compute_efficiency(0.9, 0.5) - 1.8).abs() < 1e-12);
+        assert!((engine.compute_efficiency(0.5, 2.0) - 0.25).abs() < 1e-12);
+        assert_eq!(engine.compute_efficiency(0.0, 1.0), 0.0);
+
+        // No meaningful ratio without a positive, finite cost
+        assert_eq!(engine.compute_efficiency(0.9, 0.0), 0.0);
+        assert_eq!(engine.compute_efficiency(0.9, -1.0), 0.0);
+        assert_eq!(engine.compute_efficiency(0.9, f64::NAN), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_cost_efficiency_metric() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+
+        let criteria = make_test_criteria();
+        let mut test_cases = vec![
+            make_test_case("1", "a", "a"),
+            make_test_case("2", "b", "b"),
+            make_test_case("3", "c", "x"),
+            make_test_case("4", "d", "x"),
+        ];
+
+        // Without reported costs there is nothing to compose
+        let request = ScoringRequest {
+            test_cases: test_cases.clone(),
+            criteria: criteria.clone(),
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+        let results = engine.score(&request).await.unwrap();
+        assert!(!results.metric_scores.contains_key(COST_EFFICIENCY_METRIC));
+
+        // 50 tokens per case; the third case reports no cost and is left out
+        let costs = [Some(0.5), Some(1.5), None, Some(1.0)];
+        for (test_case, cost) in test_cases.iter_mut().zip(costs) {
+            test_case.cost = cost;
+        }
+        assert_eq!(engine.cost_per_token(&test_cases), Some(3.0 / 150.0));
+
+        let request = ScoringRequest {
+            test_cases,
+            criteria,
+            metadata: HashMap::new(),
+            population_scores: None,
+        };
+        let results = engine.score(&request).await.unwrap();
+
+        let accuracy = results.metric_scores["accuracy"].value;
+        assert_eq!(accuracy, 0.5);
+        let efficiency = &results.metric_scores[COST_EFFICIENCY_METRIC];
+        assert!((efficiency.value - accuracy / (3.0 / 150.0)).abs() < 1e-9);
+        assert_eq!(efficiency.unit.as_deref(), Some("accuracy/cost"));
+    }
+
+    #[test]
+    fn test_cost_per_token_needs_positive_cost() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        let with_cost = |cost: Option<f64>, tokens: Option<u32>| TestCaseInput {
+            cost,
+            tokens_generated: tokens,
+            ..Default::default()
+        };
+
+        assert_eq!(engine.cost_per_token(&[]), None);
+        assert_eq!(engine.cost_per_token(&[with_cost(Some(1.0), None)]), None);
+        assert_eq!(engine.cost_per_token(&[with_cost(Some(0.0), Some(10))]), None);
+        assert_eq!(engine.cost_per_token(&[with_cost(Some(1.0), Some(0))]), None);
+        assert_eq!(
+            engine.cost_per_token(&[
+                with_cost(Some(f64::NAN), Some(10)),
+                with_cost(Some(-1.0), Some(10)),
+                with_cost(Some(2.0), Some(10)),
+            ]),
+            Some(0.2)
+        );
+    }
+
     #[test]
     fn test_std_dev() {
         let engine = ScoringEngine::new(ScoringEngineConfig::default());
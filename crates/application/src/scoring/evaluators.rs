@@ -84,7 +84,11 @@ pub struct EvaluatorConfig {
     /// Case sensitivity for string comparisons
     pub case_sensitive: bool,
     /// Whether to trim whitespace
+    #[serde(alias = "trim")]
     pub trim_whitespace: bool,
+    /// Whether to collapse internal runs of whitespace into a single space
+    #[serde(default)]
+    pub collapse_whitespace: bool,
     /// Additional parameters
     pub params: HashMap<String, serde_json::Value>,
 }
@@ -95,6 +99,7 @@ impl Default for EvaluatorConfig {
             pass_threshold: 0.5,
             case_sensitive: true,
             trim_whitespace: true,
+            collapse_whitespace: false,
             params: HashMap::new(),
         }
     }
@@ -113,6 +118,40 @@ impl EvaluatorConfig {
         }
         self
     }
+
+    /// Normalize a string according to the trim, whitespace and case options.
+    pub fn normalize(&self, input: &str) -> String {
+        let trimmed = if self.trim_whitespace {
+            input.trim()
+        } else {
+            input
+        };
+
+        let collapsed = if self.collapse_whitespace {
+            let mut out = String::with_capacity(trimmed.len());
+            let mut in_whitespace = false;
+            for c in trimmed.chars() {
+                if c.is_whitespace() {
+                    if !in_whitespace {
+                        out.push(' ');
+                    }
+                    in_whitespace = true;
+                } else {
+                    out.push(c);
+                    in_whitespace = false;
+                }
+            }
+            out
+        } else {
+            trimmed.to_string()
+        };
+
+        if self.case_sensitive {
+            collapsed
+        } else {
+            collapsed.to_lowercase()
+        }
+    }
 }
 
 /// Exact match evaluator.
@@ -132,17 +171,7 @@ impl Evaluator for ExactMatchEvaluator {
             None => return EvaluationResult::failure("No expected output provided"),
         };
 
-        let (actual_normalized, expected_normalized) = if config.trim_whitespace {
-            (actual.trim(), expected.trim())
-        } else {
-            (actual, expected)
-        };
-
-        let matches = if config.case_sensitive {
-            actual_normalized == expected_normalized
-        } else {
-            actual_normalized.eq_ignore_ascii_case(expected_normalized)
-        };
+        let matches = config.normalize(actual) == config.normalize(expected);
 
         let score = if matches { 1.0 } else { 0.0 };
 
@@ -151,6 +180,8 @@ impl Evaluator for ExactMatchEvaluator {
         EvaluationResult::success_with_threshold(score, config.pass_threshold)
             .with_detail("match_type", "exact")
             .with_detail("case_sensitive", config.case_sensitive)
+            .with_detail("trim_whitespace", config.trim_whitespace)
+            .with_detail("collapse_whitespace", config.collapse_whitespace)
     }
 
     fn name(&self) -> &'static str {
@@ -259,8 +290,74 @@ impl Evaluator for FuzzyMatchEvaluator {
 }
 
 /// Numeric comparison evaluator with tolerance.
+///
+/// Supported parameters:
+/// - `abs_tol`: maximum absolute difference accepted
+/// - `rel_tol`: maximum difference relative to the expected value
+/// - `unit`: unit suffix stripped from both values before parsing (e.g. `"ms"`)
+/// - `partial_credit`: whether scores decay outside the tolerance (default true)
+///
+/// A value passes when it is within either tolerance. The legacy `tolerance`
+/// and `relative` parameters are honored when neither `abs_tol` nor `rel_tol`
+/// is set.
 pub struct NumericToleranceEvaluator;
 
+impl NumericToleranceEvaluator {
+    /// Parse a number from free-form text.
+    ///
+    /// Accepts thousands separators, scientific notation, a leading currency
+    /// sign and a trailing unit (`"1,250 ms"`, `"3.2e-4"`, `"$0.02"`).
+    fn parse_number(input: &str, unit: Option<&str>) -> Option<f64> {
+        let mut text = input.trim();
+        if let Some(unit) = unit.filter(|u| !u.is_empty()) {
+            if text.len() >= unit.len()
+                && text.is_char_boundary(text.len() - unit.len())
+                && text[text.len() - unit.len()..].eq_ignore_ascii_case(unit)
+            {
+                text = text[..text.len() - unit.len()].trim_end();
+            }
+        }
+        let text = text.strip_prefix('$').unwrap_or(text);
+
+        let cleaned: String = text.chars().filter(|c| *c != ',' && *c != '_').collect();
+        let bytes = cleaned.as_bytes();
+
+        // Scan the longest numeric prefix: sign, digits, fraction, exponent.
+        let mut end = 0;
+        if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+            end += 1;
+        }
+        let digits_start = end;
+        while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+            end += 1;
+        }
+        if end == digits_start {
+            return None;
+        }
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exp_end = end + 1;
+            if exp_end < bytes.len() && (bytes[exp_end] == b'+' || bytes[exp_end] == b'-') {
+                exp_end += 1;
+            }
+            let exp_digits_start = exp_end;
+            while exp_end < bytes.len() && bytes[exp_end].is_ascii_digit() {
+                exp_end += 1;
+            }
+            if exp_end > exp_digits_start {
+                end = exp_end;
+            }
+        }
+
+        // Anything after the number must look like a unit, not more digits.
+        let suffix = cleaned[end..].trim();
+        if suffix.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        cleaned[..end].parse::<f64>().ok().filter(|n| n.is_finite())
+    }
+}
+
 #[async_trait]
 impl Evaluator for NumericToleranceEvaluator {
     #[instrument(skip(self, actual, expected))]
@@ -275,41 +372,43 @@ impl Evaluator for NumericToleranceEvaluator {
             None => return EvaluationResult::failure("No expected output provided"),
         };
 
-        let actual_num: f64 = match actual.trim().parse() {
-            Ok(n) => n,
-            Err(_) => return EvaluationResult::failure("Could not parse actual output as number"),
-        };
+        let unit = config.get_param::<String>("unit");
 
-        let expected_num: f64 = match expected.trim().parse() {
-            Ok(n) => n,
-            Err(_) => return EvaluationResult::failure("Could not parse expected output as number"),
+        let actual_num = match Self::parse_number(actual, unit.as_deref()) {
+            Some(n) => n,
+            None => return EvaluationResult::failure("Could not parse actual output as number"),
         };
 
-        let tolerance = config.get_param::<f64>("tolerance").unwrap_or(0.01);
-        let relative = config.get_param::<bool>("relative").unwrap_or(false);
+        let expected_num = match Self::parse_number(expected, unit.as_deref()) {
+            Some(n) => n,
+            None => return EvaluationResult::failure("Could not parse expected output as number"),
+        };
 
-        let diff = (actual_num - expected_num).abs();
-        let within_tolerance = if relative {
-            let relative_diff = if expected_num.abs() > f64::EPSILON {
-                diff / expected_num.abs()
-            } else {
-                diff
-            };
-            relative_diff <= tolerance
+        let abs_tol_param = config.get_param::<f64>("abs_tol");
+        let rel_tol_param = config.get_param::<f64>("rel_tol");
+        let (abs_tol, rel_tol) = if abs_tol_param.is_some() || rel_tol_param.is_some() {
+            (abs_tol_param.unwrap_or(0.0), rel_tol_param.unwrap_or(0.0))
         } else {
-            diff <= tolerance
+            let tolerance = config.get_param::<f64>("tolerance").unwrap_or(0.01);
+            if config.get_param::<bool>("relative").unwrap_or(false) {
+                (0.0, tolerance)
+            } else {
+                (tolerance, 0.0)
+            }
         };
+        let partial_credit = config.get_param::<bool>("partial_credit").unwrap_or(true);
+
+        let diff = (actual_num - expected_num).abs();
+        let allowed = abs_tol.max(rel_tol * expected_num.abs());
+        let within_tolerance = diff <= allowed;
 
         let score = if within_tolerance {
             1.0
-        } else {
+        } else if partial_credit && allowed > 0.0 {
             // Gradual decay based on how far from tolerance
-            let max_diff = if relative {
-                expected_num.abs() * tolerance * 10.0
-            } else {
-                tolerance * 10.0
-            };
-            (1.0 - diff / max_diff).max(0.0)
+            (1.0 - diff / (allowed * 10.0)).max(0.0)
+        } else {
+            0.0
         };
 
         debug!(
@@ -325,7 +424,9 @@ impl Evaluator for NumericToleranceEvaluator {
             .with_detail("actual_value", actual_num)
             .with_detail("expected_value", expected_num)
             .with_detail("difference", diff)
-            .with_detail("tolerance", tolerance)
+            .with_detail("abs_tol", abs_tol)
+            .with_detail("rel_tol", rel_tol)
+            .with_detail("unit", unit)
             .with_detail("within_tolerance", within_tolerance)
     }
 
@@ -446,8 +547,58 @@ impl Evaluator for ContainsEvaluator {
 }
 
 /// Regex match evaluator.
+///
+/// By default the output passes if the pattern matches. When the
+/// `expected_groups` parameter maps named capture groups to expected values,
+/// the score is the fraction of groups whose captured text matches.
 pub struct RegexMatchEvaluator;
 
+impl RegexMatchEvaluator {
+    /// Score the named capture groups of the first match against expected values.
+    fn score_capture_groups(
+        regex: &regex::Regex,
+        actual: &str,
+        expected_groups: &HashMap<String, String>,
+        config: &EvaluatorConfig,
+    ) -> EvaluationResult {
+        let captures = regex.captures(actual);
+
+        let mut matched_groups = Vec::new();
+        let mut mismatched_groups = Vec::new();
+        for (group, expected_value) in expected_groups {
+            let captured = captures
+                .as_ref()
+                .and_then(|c| c.name(group))
+                .map(|m| m.as_str());
+
+            match captured {
+                Some(value) if config.normalize(value) == config.normalize(expected_value) => {
+                    matched_groups.push(group.clone());
+                }
+                _ => mismatched_groups.push(group.clone()),
+            }
+        }
+
+        matched_groups.sort();
+        mismatched_groups.sort();
+
+        let score = matched_groups.len() as f64 / expected_groups.len() as f64;
+
+        debug!(
+            matched = matched_groups.len(),
+            total = expected_groups.len(),
+            score = score,
+            "Regex capture group evaluation"
+        );
+
+        EvaluationResult::success_with_threshold(score, config.pass_threshold)
+            .with_detail("match_type", "regex_groups")
+            .with_detail("is_match", captures.is_some())
+            .with_detail("matched_groups", matched_groups)
+            .with_detail("mismatched_groups", mismatched_groups)
+    }
+}
+
 #[async_trait]
 impl Evaluator for RegexMatchEvaluator {
     #[instrument(skip(self, actual, expected, config))]
@@ -477,6 +628,14 @@ impl Evaluator for RegexMatchEvaluator {
             actual
         };
 
+        let expected_groups: HashMap<String, String> =
+            config.get_param("expected_groups").unwrap_or_default();
+
+        if !expected_groups.is_empty() {
+            return Self::score_capture_groups(&regex, actual_normalized, &expected_groups, config)
+                .with_detail("pattern", pattern);
+        }
+
         let is_match = regex.is_match(actual_normalized);
         let captures: Vec<String> = regex
             .captures_iter(actual_normalized)
@@ -613,6 +772,50 @@ mod tests {
         assert_eq!(result.score, 1.0);
     }
 
+    #[tokio::test]
+    async fn test_exact_match_normalization_options() {
+        let evaluator = ExactMatchEvaluator;
+
+        let result = evaluator
+            .evaluate("Hello ", Some("hello"), &EvaluatorConfig::default())
+            .await;
+        assert!(!result.passed);
+
+        let config = EvaluatorConfig {
+            case_sensitive: false,
+            trim_whitespace: true,
+            ..Default::default()
+        };
+        let result = evaluator.evaluate("Hello ", Some("hello"), &config).await;
+        assert!(result.passed);
+
+        let config = EvaluatorConfig {
+            trim_whitespace: false,
+            ..Default::default()
+        };
+        let result = evaluator.evaluate("hello ", Some("hello"), &config).await;
+        assert!(!result.passed);
+    }
+
+    #[tokio::test]
+    async fn test_exact_match_collapse_whitespace() {
+        let evaluator = ExactMatchEvaluator;
+
+        let result = evaluator
+            .evaluate("hello   big\tworld", Some("hello big world"), &EvaluatorConfig::default())
+            .await;
+        assert!(!result.passed);
+
+        let config = EvaluatorConfig {
+            collapse_whitespace: true,
+            ..Default::default()
+        };
+        let result = evaluator
+            .evaluate("hello   big\tworld", Some("hello big world"), &config)
+            .await;
+        assert!(result.passed);
+    }
+
     #[tokio::test]
     async fn test_fuzzy_match() {
         let evaluator = FuzzyMatchEvaluator;
@@ -647,6 +850,59 @@ mod tests {
         assert!(!result.passed);
     }
 
+    #[tokio::test]
+    async fn test_numeric_within_abs_tol() {
+        let evaluator = NumericToleranceEvaluator;
+        let config = EvaluatorConfig::default()
+            .set_param("abs_tol", 5.0)
+            .set_param("unit", "ms");
+
+        let result = evaluator.evaluate("1,203 ms", Some("1200ms"), &config).await;
+        assert!(result.passed);
+        assert_eq!(result.score, 1.0);
+
+        let result = evaluator.evaluate("1,210 ms", Some("1200ms"), &config).await;
+        assert!(result.score < 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_numeric_within_rel_tol() {
+        let evaluator = NumericToleranceEvaluator;
+        let config = EvaluatorConfig::default()
+            .set_param("rel_tol", 0.05)
+            .set_param("partial_credit", false);
+
+        let result = evaluator.evaluate("1.04e3", Some("1000"), &config).await;
+        assert!(result.passed);
+        assert_eq!(result.score, 1.0);
+
+        let result = evaluator.evaluate("1.1e3", Some("1000"), &config).await;
+        assert!(!result.passed);
+        assert_eq!(result.score, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_numeric_non_numeric_actual() {
+        let evaluator = NumericToleranceEvaluator;
+        let config = EvaluatorConfig::default().set_param("abs_tol", 1.0);
+
+        let result = evaluator.evaluate("about ten", Some("10"), &config).await;
+        assert!(result.error.is_some());
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(NumericToleranceEvaluator::parse_number("42", None), Some(42.0));
+        assert_eq!(NumericToleranceEvaluator::parse_number(" -3.5e2 ", None), Some(-350.0));
+        assert_eq!(NumericToleranceEvaluator::parse_number("1,234.5 tok/s", None), Some(1234.5));
+        assert_eq!(NumericToleranceEvaluator::parse_number("$0.02", None), Some(0.02));
+        assert_eq!(NumericToleranceEvaluator::parse_number("12ms", Some("ms")), Some(12.0));
+        assert_eq!(NumericToleranceEvaluator::parse_number("12 34", None), None);
+        assert_eq!(NumericToleranceEvaluator::parse_number("NaN", None), None);
+        assert_eq!(NumericToleranceEvaluator::parse_number("", None), None);
+    }
+
     #[tokio::test]
     async fn test_contains() {
         let evaluator = ContainsEvaluator;
@@ -676,4 +932,39 @@ mod tests {
         let result = evaluator.evaluate("No phone number", None, &config).await;
         assert!(!result.passed);
     }
+
+    #[tokio::test]
+    async fn test_regex_capture_groups_partial_credit() {
+        let evaluator = RegexMatchEvaluator;
+        let mut groups = HashMap::new();
+        groups.insert("name", "Alice");
+        groups.insert("age", "30");
+        groups.insert("city", "Paris");
+        let config = EvaluatorConfig::default()
+            .set_param(
+                "pattern",
+                r"name=(?P<name>\w+) age=(?P<age>\d+) city=(?P<city>\w+)",
+            )
+            .set_param("expected_groups", groups);
+
+        let result = evaluator
+            .evaluate("name=Alice age=30 city=London", None, &config)
+            .await;
+        assert!(result.error.is_none());
+        assert!((result.score - 2.0 / 3.0).abs() < 1e-3);
+        assert!(result.passed);
+
+        let result = evaluator.evaluate("no match here", None, &config).await;
+        assert_eq!(result.score, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_regex_invalid_pattern() {
+        let evaluator = RegexMatchEvaluator;
+        let config = EvaluatorConfig::default().set_param("pattern", r"(?P<open>\d+");
+
+        let result = evaluator.evaluate("123", None, &config).await;
+        assert!(result.error.is_some());
+        assert!(!result.passed);
+    }
 }
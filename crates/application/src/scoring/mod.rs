@@ -5,6 +5,8 @@
 
 mod engine;
 mod evaluators;
+mod stream;
 
 pub use engine::*;
 pub use evaluators::*;
+pub use stream::*;
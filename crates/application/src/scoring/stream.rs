@@ -0,0 +1,561 @@
+//! Streaming scoring for very large submissions
+//!
+//! [`ScoringEngine::score_stream`] pulls test cases from a stream in batches
+//! of `max_concurrent_evaluations`, evaluates each batch concurrently and
+//! folds the scores into running statistics. Only the current batch is held
+//! in memory, so submissions with hundreds of thousands of test cases can be
+//! scored without materializing them, and `max_test_cases` does not apply.
+
+use super::engine::{case_weight, evaluate_case, finite_or, CostTotals};
+use super::{ScoringEngine, TestCaseInput, COST_EFFICIENCY_METRIC, THROUGHPUT_METRIC};
+use crate::ApplicationError;
+use futures::future::join_all;
+use futures::{Stream, StreamExt};
+use llm_benchmark_domain::evaluation::{AggregationMethod, EvaluationCriteria};
+use llm_benchmark_domain::submission::{MetricScore, SubmissionResults};
+use std::collections::HashMap;
+use tracing::{debug, info, instrument};
+
+/// Running mean and variance using Welford's algorithm
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunningStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    /// Add a value
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Number of values seen
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Arithmetic mean, 0.0 when empty
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Sample standard deviation, 0.0 with fewer than two values
+    pub fn std_dev(&self) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        finite_or((self.m2 / (self.count - 1) as f64).sqrt(), 0.0, "standard deviation")
+    }
+}
+
+/// Running state for every streamable aggregation method
+#[derive(Debug, Clone)]
+struct MetricAccumulator {
+    stats: RunningStats,
+    weighted_sum: f64,
+    weight_sum: f64,
+    min: f64,
+    max: f64,
+    log_sum: f64,
+    reciprocal_sum: f64,
+    positive_count: usize,
+}
+
+impl Default for MetricAccumulator {
+    fn default() -> Self {
+        Self {
+            stats: RunningStats::default(),
+            weighted_sum: 0.0,
+            weight_sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            log_sum: 0.0,
+            reciprocal_sum: 0.0,
+            positive_count: 0,
+        }
+    }
+}
+
+impl MetricAccumulator {
+    fn push(&mut self, score: f64, weight: f64) {
+        self.stats.push(score);
+        self.weighted_sum += score * weight;
+        self.weight_sum += weight;
+        self.min = self.min.min(score);
+        self.max = self.max.max(score);
+        if score > 0.0 {
+            self.log_sum += score.ln();
+            self.reciprocal_sum += 1.0 / score;
+            self.positive_count += 1;
+        }
+    }
+
+    /// Aggregate with `method`, matching the batch results of `score`
+    fn aggregate(&self, method: &AggregationMethod) -> f64 {
+        let positive = self.positive_count as f64;
+        let aggregate = match method {
+            AggregationMethod::WeightedMean { .. }
+            | AggregationMethod::DifficultyWeighted { .. } => {
+                if self.weight_sum == 0.0 || !self.weight_sum.is_finite() {
+                    self.stats.mean()
+                } else {
+                    self.weighted_sum / self.weight_sum
+                }
+            }
+            AggregationMethod::GeometricMean if self.positive_count > 0 => {
+                (self.log_sum / positive).exp()
+            }
+            AggregationMethod::HarmonicMean if self.reciprocal_sum > 0.0 => {
+                positive / self.reciprocal_sum
+            }
+            AggregationMethod::GeometricMean | AggregationMethod::HarmonicMean => 0.0,
+            AggregationMethod::Min => self.min,
+            AggregationMethod::Max => self.max,
+            _ => self.stats.mean(),
+        };
+        finite_or(aggregate, 0.0, "aggregate score")
+    }
+
+    fn metric_score(&self, value: f64, unit: Option<String>) -> MetricScore {
+        MetricScore {
+            value,
+            unit,
+            raw_values: None,
+            std_dev: Some(self.stats.std_dev()),
+        }
+    }
+}
+
+/// Scores for one test case across the primary and secondary metrics
+struct CaseScores {
+    primary: f64,
+    passed: bool,
+    secondary: Vec<f64>,
+}
+
+impl ScoringEngine {
+    /// Score a stream of test cases without holding them all in memory
+    ///
+    /// Test cases are evaluated in concurrent batches of
+    /// `max_concurrent_evaluations`. Aggregates, standard deviations,
+    /// confidence intervals and significance come from running sums, so the
+    /// results carry no `raw_values`, no per-test-case results and no
+    /// latency percentiles. Median, percentile and trimmed-mean aggregation
+    /// need every score and are rejected; use [`Self::score`] for those.
+    /// There is no reference population, so `ZScore` and `Percentile`
+    /// normalization leave the aggregate unchanged.
+    #[instrument(skip(self, test_cases, criteria))]
+    pub async fn score_stream<S>(
+        &self,
+        test_cases: S,
+        criteria: &EvaluationCriteria,
+    ) -> Result<SubmissionResults, ApplicationError>
+    where
+        S: Stream<Item = TestCaseInput>,
+    {
+        let method = &criteria.aggregation_method;
+        if matches!(
+            method,
+            AggregationMethod::Median
+                | AggregationMethod::Percentile { .. }
+                | AggregationMethod::TrimmedMean { .. }
+        ) {
+            return Err(ApplicationError::ValidationFailed(format!(
+                "{:?} aggregation needs every score and cannot be streamed",
+                method
+            )));
+        }
+        method.validate().map_err(ApplicationError::ValidationFailed)?;
+
+        let primary_evaluator = self.metric_evaluator(&criteria.primary_metric)?;
+        let secondary_evaluators = criteria
+            .secondary_metrics
+            .iter()
+            .map(|metric| self.metric_evaluator(metric))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let batch_size = self.config.max_concurrent_evaluations.max(1);
+        let mut batches = std::pin::pin!(test_cases.chunks(batch_size));
+
+        let mut primary = MetricAccumulator::default();
+        let mut secondary = vec![MetricAccumulator::default(); criteria.secondary_metrics.len()];
+        let mut throughput = RunningStats::default();
+        let mut costs = CostTotals::default();
+        let mut passed_count = 0usize;
+        let mut index = 0usize;
+
+        while let Some(batch) = batches.next().await {
+            let evaluators = batch
+                .iter()
+                .map(|test_case| self.case_evaluator(&primary_evaluator, test_case, true))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let scored = join_all(batch.iter().zip(&evaluators).map(|(test_case, evaluator)| {
+                let secondary_evaluators = &secondary_evaluators;
+                async move {
+                    let result =
+                        evaluate_case(evaluator.as_ref(), &criteria.primary_metric.name, test_case)
+                            .await;
+                    let mut secondary = Vec::with_capacity(secondary_evaluators.len());
+                    for (metric, evaluator) in
+                        criteria.secondary_metrics.iter().zip(secondary_evaluators)
+                    {
+                        let result = evaluate_case(evaluator.as_ref(), &metric.name, test_case).await;
+                        secondary.push(result.score);
+                    }
+                    CaseScores {
+                        primary: result.score,
+                        passed: result.passed,
+                        secondary,
+                    }
+                }
+            }))
+            .await;
+
+            for (test_case, scores) in batch.iter().zip(scored) {
+                let weight = case_weight(
+                    method,
+                    index,
+                    finite_or(test_case.weight, 1.0, "test case weight"),
+                    test_case.difficulty,
+                );
+                primary.push(scores.primary, weight);
+                for (accumulator, score) in secondary.iter_mut().zip(scores.secondary) {
+                    accumulator.push(score, weight);
+                }
+                if scores.passed {
+                    passed_count += 1;
+                }
+                if let (Some(latency), Some(tokens)) =
+                    (test_case.latency_ms, test_case.tokens_generated)
+                {
+                    if latency > 0 {
+                        throughput.push(tokens as f64 / (latency as f64 / 1000.0));
+                    }
+                }
+                costs.push(test_case);
+                index += 1;
+            }
+
+            debug!(scored = index, "Scored streamed batch");
+        }
+
+        let count = primary.stats.count();
+        if count < criteria.minimum_test_cases {
+            return Err(ApplicationError::ValidationFailed(format!(
+                "Insufficient test cases: got {}, required {}",
+                count, criteria.minimum_test_cases
+            )));
+        }
+        if count == 0 {
+            return Err(ApplicationError::ValidationFailed(
+                "No scores to aggregate".to_string(),
+            ));
+        }
+
+        let aggregate_score = finite_or(
+            self.normalize_score(primary.aggregate(method), &criteria.score_normalization, None),
+            0.0,
+            "normalized score",
+        );
+
+        let mut metric_scores = HashMap::new();
+        metric_scores.insert(
+            criteria.primary_metric.name.clone(),
+            primary.metric_score(aggregate_score, criteria.primary_metric.unit.clone()),
+        );
+        for (metric, accumulator) in criteria.secondary_metrics.iter().zip(&secondary) {
+            metric_scores.insert(
+                metric.name.clone(),
+                accumulator.metric_score(accumulator.aggregate(method), metric.unit.clone()),
+            );
+        }
+        if throughput.count() > 0 {
+            metric_scores
+                .entry(THROUGHPUT_METRIC.to_string())
+                .or_insert(MetricScore {
+                    value: throughput.mean(),
+                    unit: Some("tokens/s".to_string()),
+                    raw_values: None,
+                    std_dev: Some(throughput.std_dev()),
+                });
+        }
+        if let Some(metric) =
+            self.efficiency_metric(criteria, &metric_scores, costs.cost_per_token())
+        {
+            metric_scores
+                .entry(COST_EFFICIENCY_METRIC.to_string())
+                .or_insert(metric);
+        }
+
+        let (confidence_interval, statistical_significance) =
+            if count >= self.config.min_test_cases_for_stats {
+                let (mean, std_dev) = (primary.stats.mean(), primary.stats.std_dev());
+                (
+                    Some(self.confidence_interval_from(
+                        mean,
+                        std_dev,
+                        count,
+                        criteria.confidence_level,
+                    )),
+                    Some(self.significance_from(mean, std_dev, count)),
+                )
+            } else {
+                (None, None)
+            };
+
+        info!(
+            test_cases = count,
+            passed_count,
+            aggregate_score = %aggregate_score,
+            "Streaming scoring complete"
+        );
+
+        Ok(SubmissionResults {
+            aggregate_score,
+            metric_scores,
+            test_case_results: Vec::new(),
+            confidence_interval,
+            statistical_significance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::{
+        EvaluationResult, Evaluator, EvaluatorConfig, ScoringEngineBuilder, ScoringEngineConfig,
+        ScoringRequest, DEFAULT_MAX_TEST_CASES,
+    };
+    use async_trait::async_trait;
+    use llm_benchmark_domain::evaluation::{MetricDefinition, MetricType, ScoreNormalization};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn criteria(aggregation_method: AggregationMethod) -> EvaluationCriteria {
+        EvaluationCriteria {
+            primary_metric: MetricDefinition {
+                name: "accuracy".to_string(),
+                description: "Test accuracy".to_string(),
+                metric_type: MetricType::Accuracy,
+                unit: None,
+                higher_is_better: true,
+                range: None,
+            },
+            secondary_metrics: vec![],
+            aggregation_method,
+            score_normalization: ScoreNormalization::None,
+            minimum_test_cases: 1,
+            confidence_level: 0.95,
+            score_precision: None,
+            leaderboard_min_verification: Default::default(),
+        }
+    }
+
+    /// Every fourth case answers wrongly
+    fn synthetic_case(i: usize) -> TestCaseInput {
+        TestCaseInput {
+            id: i.to_string(),
+            expected: "yes".to_string(),
+            actual: if i % 4 == 3 { "no" } else { "yes" }.to_string(),
+            latency_ms: Some(100 + (i % 7) as u64),
+            tokens_generated: Some(20),
+            cost: Some(0.01 * (i % 5) as f64),
+            weight: (i % 3 + 1) as f64,
+            ..Default::default()
+        }
+    }
+
+    /// Exact match that records concurrency and completed evaluations
+    struct TrackingEvaluator {
+        in_flight: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+        done: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Evaluator for TrackingEvaluator {
+        async fn evaluate(
+            &self,
+            actual: &str,
+            expected: Option<&str>,
+            _config: &EvaluatorConfig,
+        ) -> EvaluationResult {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.done.fetch_add(1, Ordering::SeqCst);
+            EvaluationResult::success(if Some(actual) == expected { 1.0 } else { 0.0 })
+        }
+
+        fn name(&self) -> &'static str {
+            "tracking"
+        }
+    }
+
+    #[test]
+    fn test_running_stats_match_batch() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut stats = RunningStats::default();
+        for value in values {
+            stats.push(value);
+        }
+        assert_eq!(stats.count(), 8);
+        assert!((stats.mean() - 5.0).abs() < 1e-12);
+        assert!((stats.std_dev() - 2.138).abs() < 0.001);
+
+        let mut single = RunningStats::default();
+        single.push(3.0);
+        assert_eq!(single.std_dev(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_stream_matches_batch_scoring() {
+        let engine = ScoringEngine::new(ScoringEngineConfig {
+            max_concurrent_evaluations: 16,
+            ..Default::default()
+        });
+
+        for method in [
+            AggregationMethod::Mean,
+            AggregationMethod::WeightedMean { weights: HashMap::new() },
+            AggregationMethod::GeometricMean,
+            AggregationMethod::Min,
+            AggregationMethod::Max,
+        ] {
+            let criteria = criteria(method);
+            let test_cases: Vec<TestCaseInput> = (0..1_000).map(synthetic_case).collect();
+            let request = ScoringRequest {
+                test_cases: test_cases.clone(),
+                criteria: criteria.clone(),
+                metadata: HashMap::new(),
+                population_scores: None,
+            };
+
+            let batch = engine.score(&request).await.unwrap();
+            let streamed = engine
+                .score_stream(futures::stream::iter(test_cases), &criteria)
+                .await
+                .unwrap();
+
+            assert!((streamed.aggregate_score - batch.aggregate_score).abs() < 1e-9);
+            let (s, b) = (&streamed.metric_scores["accuracy"], &batch.metric_scores["accuracy"]);
+            assert!((s.std_dev.unwrap() - b.std_dev.unwrap()).abs() < 1e-9);
+            assert!(s.raw_values.is_none());
+
+            let (s, b) = (
+                streamed.confidence_interval.unwrap(),
+                batch.confidence_interval.unwrap(),
+            );
+            assert!((s.lower - b.lower).abs() < 1e-9);
+            assert!((s.upper - b.upper).abs() < 1e-9);
+
+            let throughput = (
+                streamed.metric_scores[THROUGHPUT_METRIC].value,
+                batch.metric_scores[THROUGHPUT_METRIC].value,
+            );
+            assert!((throughput.0 - throughput.1).abs() < 1e-9);
+            let efficiency = (
+                streamed.metric_scores[COST_EFFICIENCY_METRIC].value,
+                batch.metric_scores[COST_EFFICIENCY_METRIC].value,
+            );
+            assert!((efficiency.0 - efficiency.1).abs() < 1e-9);
+            assert!(streamed.test_case_results.is_empty());
+        }
+    }
+
+    /// Allocation is measured in `tests/scoring_stream_allocation.rs`, which
+    /// runs alone under a counting allocator
+    #[tokio::test]
+    async fn test_stream_buffers_at_most_one_batch() {
+        // More than the batch scoring limit
+        const CASES: usize = DEFAULT_MAX_TEST_CASES + 50_000;
+        const BATCH: usize = 64;
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak_evaluating = Arc::new(AtomicUsize::new(0));
+        let done = Arc::new(AtomicUsize::new(0));
+        let engine = ScoringEngineBuilder::new()
+            .max_concurrent_evaluations(BATCH)
+            .evaluator(
+                "exact_match",
+                Arc::new(TrackingEvaluator {
+                    in_flight: in_flight.clone(),
+                    peak: peak_evaluating.clone(),
+                    done: done.clone(),
+                }),
+            )
+            .build();
+
+        // Cases are generated lazily; track those pulled but not yet scored
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let peak_buffered = Arc::new(AtomicUsize::new(0));
+        let stream = futures::stream::iter(0..CASES).map({
+            let pulled = pulled.clone();
+            let peak_buffered = peak_buffered.clone();
+            let done = done.clone();
+            move |i| {
+                let pulled = pulled.fetch_add(1, Ordering::SeqCst) + 1;
+                peak_buffered.fetch_max(pulled - done.load(Ordering::SeqCst), Ordering::SeqCst);
+                synthetic_case(i)
+            }
+        });
+
+        let results = engine
+            .score_stream(stream, &criteria(AggregationMethod::Mean))
+            .await
+            .unwrap();
+
+        assert_eq!(pulled.load(Ordering::SeqCst), CASES);
+        assert_eq!(done.load(Ordering::SeqCst), CASES);
+        assert!(peak_buffered.load(Ordering::SeqCst) <= BATCH);
+        let peak = peak_evaluating.load(Ordering::SeqCst);
+        assert!(peak > 1 && peak <= BATCH, "peak concurrency {}", peak);
+
+        assert!((results.aggregate_score - 0.75).abs() < 1e-9);
+        assert!(results.confidence_interval.is_some());
+        assert!(results.metric_scores["accuracy"].raw_values.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_rejects_order_statistics() {
+        let engine = ScoringEngine::new(ScoringEngineConfig::default());
+        for method in [
+            AggregationMethod::Median,
+            AggregationMethod::Percentile { percentile: 90.0 },
+            AggregationMethod::TrimmedMean { trim_fraction: 0.1 },
+        ] {
+            let stream = futures::stream::iter((0..10).map(synthetic_case));
+            let result = engine.score_stream(stream, &criteria(method)).await;
+            assert!(matches!(result, Err(ApplicationError::ValidationFailed(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_case_limits() {
+        let engine = ScoringEngineBuilder::new()
+            .max_concurrent_evaluations(4)
+            .max_test_cases(10)
+            .build();
+
+        // The batch scoring cap does not apply to streams
+        let stream = futures::stream::iter((0..11).map(synthetic_case));
+        let results = engine
+            .score_stream(stream, &criteria(AggregationMethod::Mean))
+            .await
+            .unwrap();
+        assert!((results.aggregate_score - 9.0 / 11.0).abs() < 1e-9);
+
+        let mut needs_more = criteria(AggregationMethod::Mean);
+        needs_more.minimum_test_cases = 5;
+        let stream = futures::stream::iter((0..3).map(synthetic_case));
+        let result = engine.score_stream(stream, &needs_more).await;
+        assert!(matches!(result, Err(ApplicationError::ValidationFailed(_))));
+    }
+}
@@ -0,0 +1,201 @@
+//! Ownership-aware authorization
+//!
+//! [`DefaultAuthorizer`] only checks whether a caller is signed in. The
+//! [`OwnershipAuthorizer`] here looks benchmarks and organization roles up
+//! through the repository ports, so only a benchmark's creator, a maintainer
+//! of the organization they act for, or an admin can change it.
+
+use super::{
+    AuthorizationResult, Authorizer, BenchmarkRepositoryPort, DefaultAuthorizer,
+    OrganizationRepositoryPort, Permission, ServiceContext,
+};
+use crate::validation::OrganizationRole;
+use crate::ApplicationResult;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Authorizer that checks benchmark ownership through repository lookups
+///
+/// A benchmark update is allowed for admins, for the benchmark's creator,
+/// and for an owner or admin of the caller's current organization when the
+/// creator is a member of that organization. Every other decision is
+/// delegated to [`DefaultAuthorizer`].
+pub struct OwnershipAuthorizer<B, O>
+where
+    B: BenchmarkRepositoryPort,
+    O: OrganizationRepositoryPort,
+{
+    benchmarks: Arc<B>,
+    organizations: Arc<O>,
+}
+
+impl<B, O> OwnershipAuthorizer<B, O>
+where
+    B: BenchmarkRepositoryPort,
+    O: OrganizationRepositoryPort,
+{
+    pub fn new(benchmarks: Arc<B>, organizations: Arc<O>) -> Self {
+        Self {
+            benchmarks,
+            organizations,
+        }
+    }
+
+    async fn check_benchmark_owner(
+        &self,
+        ctx: &ServiceContext,
+        benchmark_id: &str,
+    ) -> ApplicationResult<AuthorizationResult> {
+        if ctx.has(Permission::EditBenchmarks) {
+            return Ok(AuthorizationResult::allow());
+        }
+        let Some(user_id) = ctx.user_id.as_deref() else {
+            return Ok(AuthorizationResult::deny(
+                "Authentication required to update benchmarks",
+            ));
+        };
+
+        let Some(benchmark) = self.benchmarks.get_by_id(benchmark_id).await? else {
+            return Ok(AuthorizationResult::deny(format!(
+                "Benchmark not found: {}",
+                benchmark_id
+            )));
+        };
+        let Some(creator) = benchmark.created_by.as_deref() else {
+            return Ok(AuthorizationResult::deny(
+                "Benchmark has no recorded creator; only admins can update it",
+            ));
+        };
+        if creator == user_id {
+            return Ok(AuthorizationResult::allow());
+        }
+
+        if let Some(org_id) = ctx.organization_id.as_deref() {
+            let role = self.organizations.get_member_role(org_id, user_id).await?;
+            let is_maintainer =
+                matches!(role, Some(OrganizationRole::Owner | OrganizationRole::Admin));
+            if is_maintainer
+                && self
+                    .organizations
+                    .get_member_role(org_id, creator)
+                    .await?
+                    .is_some()
+            {
+                return Ok(AuthorizationResult::allow());
+            }
+        }
+
+        Ok(AuthorizationResult::deny(
+            "Only the benchmark's creator or an organization maintainer can update it",
+        ))
+    }
+}
+
+#[async_trait]
+impl<B, O> Authorizer for OwnershipAuthorizer<B, O>
+where
+    B: BenchmarkRepositoryPort,
+    O: OrganizationRepositoryPort,
+{
+    async fn can_create_benchmark(&self, ctx: &ServiceContext) -> AuthorizationResult {
+        DefaultAuthorizer.can_create_benchmark(ctx).await
+    }
+
+    async fn can_update_benchmark(&self, ctx: &ServiceContext, benchmark_id: &str) -> AuthorizationResult {
+        let result = self
+            .check_benchmark_owner(ctx, benchmark_id)
+            .await
+            .unwrap_or_else(|err| {
+                warn!(benchmark_id, error = %err, "Benchmark ownership lookup failed");
+                AuthorizationResult::deny("Could not verify benchmark ownership")
+            });
+        result.logged(ctx, "update_benchmark", Some(benchmark_id))
+    }
+
+    async fn can_delete_benchmark(&self, ctx: &ServiceContext, benchmark_id: &str) -> AuthorizationResult {
+        DefaultAuthorizer.can_delete_benchmark(ctx, benchmark_id).await
+    }
+
+    async fn can_create_submission(&self, ctx: &ServiceContext, benchmark_id: &str) -> AuthorizationResult {
+        DefaultAuthorizer.can_create_submission(ctx, benchmark_id).await
+    }
+
+    async fn can_verify_submission(&self, ctx: &ServiceContext, submission_id: &str) -> AuthorizationResult {
+        DefaultAuthorizer.can_verify_submission(ctx, submission_id).await
+    }
+
+    async fn can_manage_organization(&self, ctx: &ServiceContext, org_id: &str) -> AuthorizationResult {
+        DefaultAuthorizer.can_manage_organization(ctx, org_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_support::{benchmark, InMemoryBenchmarks, InMemoryOrganizations};
+
+    const BENCHMARK_ID: &str = "bench-1";
+    const CREATOR: &str = "creator";
+    const ORG_ID: &str = "org-1";
+
+    fn authorizer(
+        memberships: InMemoryOrganizations,
+    ) -> OwnershipAuthorizer<InMemoryBenchmarks, InMemoryOrganizations> {
+        let benchmarks = InMemoryBenchmarks::default().with_benchmark(benchmark(BENCHMARK_ID, Some(CREATOR)));
+        OwnershipAuthorizer::new(Arc::new(benchmarks), Arc::new(memberships))
+    }
+
+    fn user(id: &str) -> ServiceContext {
+        ServiceContext::authenticated(id.to_string(), "corr".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_creator_can_update() {
+        let result = authorizer(InMemoryOrganizations::default())
+            .can_update_benchmark(&user(CREATOR), BENCHMARK_ID)
+            .await;
+        assert!(result.allowed);
+    }
+
+    #[tokio::test]
+    async fn test_other_user_cannot_update() {
+        let authorizer = authorizer(InMemoryOrganizations::default());
+
+        let result = authorizer.can_update_benchmark(&user("stranger"), BENCHMARK_ID).await;
+        assert!(!result.allowed);
+        assert!(result.ensure_allowed().is_err());
+
+        let anonymous = ServiceContext::anonymous("corr".to_string());
+        assert!(!authorizer.can_update_benchmark(&anonymous, BENCHMARK_ID).await.allowed);
+        assert!(!authorizer.can_update_benchmark(&user(CREATOR), "missing").await.allowed);
+    }
+
+    #[tokio::test]
+    async fn test_admin_can_update() {
+        let result = authorizer(InMemoryOrganizations::default())
+            .can_update_benchmark(&user("root").with_admin(), BENCHMARK_ID)
+            .await;
+        assert!(result.allowed);
+    }
+
+    #[tokio::test]
+    async fn test_org_maintainer_can_update_members_benchmark() {
+        let memberships = InMemoryOrganizations::default()
+            .with_member(ORG_ID, CREATOR, OrganizationRole::Member)
+            .with_member(ORG_ID, "maintainer", OrganizationRole::Admin)
+            .with_member(ORG_ID, "viewer", OrganizationRole::Viewer);
+        let authorizer = authorizer(memberships);
+
+        let mut maintainer = user("maintainer");
+        maintainer.organization_id = Some(ORG_ID.to_string());
+        assert!(authorizer.can_update_benchmark(&maintainer, BENCHMARK_ID).await.allowed);
+
+        // Without the organization context the role doesn't apply
+        assert!(!authorizer.can_update_benchmark(&user("maintainer"), BENCHMARK_ID).await.allowed);
+
+        let mut viewer = user("viewer");
+        viewer.organization_id = Some(ORG_ID.to_string());
+        assert!(!authorizer.can_update_benchmark(&viewer, BENCHMARK_ID).await.allowed);
+    }
+}
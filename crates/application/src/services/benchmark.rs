@@ -5,22 +5,25 @@
 
 use super::{
     Authorizer, EventPublisher, PaginatedResult, Pagination, ServiceConfig, ServiceContext,
-    ServiceEvent,
+    ServiceEvent, VersionChangeSummary,
 };
 use crate::validation::{
     CreateBenchmarkRequest, CreateVersionRequest, StatusTransitionRequest, UpdateBenchmarkRequest,
-    Validatable,
+    Validatable, ValidationResult,
 };
 use crate::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
 use llm_benchmark_domain::benchmark::{BenchmarkCategory, BenchmarkMetadata, BenchmarkStatus};
 use llm_benchmark_domain::identifiers::{BenchmarkId, BenchmarkVersionId, UserId};
+use llm_benchmark_domain::submission::VerificationLevel;
+use llm_benchmark_domain::test_case::TestCase;
+use llm_benchmark_domain::version::{BumpKind, SemanticVersion};
 use llm_benchmark_common::execution::Artifact;
 use std::sync::Arc;
 use tracing::{debug, info, instrument, warn};
 
 /// Benchmark data transfer object
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BenchmarkDto {
     pub id: String,
     pub name: String,
@@ -31,6 +34,8 @@ pub struct BenchmarkDto {
     pub tags: Vec<String>,
     pub current_version: Option<String>,
     pub submission_count: u64,
+    /// User who created the benchmark, when known
+    pub created_by: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -73,6 +78,7 @@ pub trait BenchmarkRepositoryPort: Send + Sync {
     async fn slug_exists(&self, slug: &str) -> Result<bool, ApplicationError>;
     async fn create_version(&self, version: &CreateVersionData) -> Result<String, ApplicationError>;
     async fn get_versions(&self, benchmark_id: &str) -> Result<Vec<BenchmarkVersionDto>, ApplicationError>;
+    async fn get_test_cases(&self, benchmark_id: &str) -> Result<Vec<TestCase>, ApplicationError>;
 }
 
 /// Data for creating a benchmark
@@ -85,6 +91,10 @@ pub struct CreateBenchmarkData {
     pub tags: Vec<String>,
     pub version: String,
     pub creator_id: String,
+    /// Weakest verification level ranked on the leaderboard by default
+    pub leaderboard_min_verification: VerificationLevel,
+    /// Test cases of the initial version
+    pub test_cases: Vec<TestCase>,
 }
 
 /// Data for updating a benchmark
@@ -94,6 +104,8 @@ pub struct UpdateBenchmarkData {
     pub description: Option<String>,
     pub tags: Option<Vec<String>>,
     pub long_description: Option<String>,
+    /// Replacement test cases for the current version
+    pub test_cases: Option<Vec<TestCase>>,
 }
 
 /// Data for creating a version
@@ -105,6 +117,8 @@ pub struct CreateVersionData {
     pub breaking_changes: bool,
     pub migration_notes: Option<String>,
     pub creator_id: String,
+    /// Test cases for the new version; `None` keeps the current ones
+    pub test_cases: Option<Vec<TestCase>>,
 }
 
 /// Benchmark service implementation
@@ -150,7 +164,8 @@ where
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("BenchmarkAgent"));
 
         // Validate request
-        let validation = request.validate_all();
+        let mut validation = request.validate_all();
+        self.check_test_case_payloads(&request.test_cases, &mut validation);
         validation.ensure_valid()?;
 
         // Check authorization
@@ -177,6 +192,8 @@ where
             tags: request.tags,
             version: request.version,
             creator_id: user_id.to_string(),
+            leaderboard_min_verification: request.leaderboard_min_verification,
+            test_cases: request.test_cases,
         };
 
         let id = self.repository.create(&create_data).await?;
@@ -263,7 +280,10 @@ where
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("BenchmarkAgent"));
 
         // Validate request
-        let validation = request.validate_all();
+        let mut validation = request.validate_all();
+        if let Some(ref test_cases) = request.test_cases {
+            self.check_test_case_payloads(test_cases, &mut validation);
+        }
         validation.ensure_valid()?;
 
         // Check authorization
@@ -277,12 +297,20 @@ where
             .await?
             .ok_or_else(|| ApplicationError::NotFound(format!("Benchmark not found: {}", id)))?;
 
+        // Published test cases only change through a new version
+        if request.test_cases.is_some() && existing.status != BenchmarkStatus::Draft {
+            return Err(ApplicationError::Conflict(
+                "Test cases of a published benchmark can only change in a new version".to_string(),
+            ));
+        }
+
         // Update benchmark
         let update_data = UpdateBenchmarkData {
             name: request.name,
             description: request.description,
             tags: request.tags,
             long_description: request.long_description,
+            test_cases: request.test_cases,
         };
 
         self.repository.update(id, &update_data).await?;
@@ -381,7 +409,10 @@ where
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("BenchmarkAgent"));
 
         // Validate request
-        let validation = request.validate_all();
+        let mut validation = request.validate_all();
+        if let Some(ref test_cases) = request.test_cases {
+            self.check_test_case_payloads(test_cases, &mut validation);
+        }
         validation.ensure_valid()?;
 
         // Check authorization
@@ -399,6 +430,19 @@ where
             .ok_or_else(|| {
                 ApplicationError::NotFound(format!("Benchmark not found: {}", benchmark_id))
             })?;
+        self.check_new_version(&existing, &request.version).await?;
+
+        // Summarize test case changes against the current version
+        let changes = match &request.test_cases {
+            Some(test_cases) => {
+                let previous = self.repository.get_test_cases(benchmark_id).await?;
+                VersionChangeSummary::between(&previous, test_cases, request.breaking_changes)
+            }
+            None => VersionChangeSummary {
+                breaking: request.breaking_changes,
+                ..VersionChangeSummary::default()
+            },
+        };
 
         // Create version
         let version_data = CreateVersionData {
@@ -408,6 +452,7 @@ where
             breaking_changes: request.breaking_changes,
             migration_notes: request.migration_notes,
             creator_id: user_id.to_string(),
+            test_cases: request.test_cases,
         };
 
         let version_id = self.repository.create_version(&version_data).await?;
@@ -415,6 +460,9 @@ where
         info!(
             benchmark_id = %benchmark_id,
             version_id = %version_id,
+            added = changes.added,
+            removed = changes.removed,
+            modified = changes.modified,
             "Benchmark version created"
         );
 
@@ -423,6 +471,7 @@ where
             .publish(ServiceEvent::BenchmarkVersionCreated {
                 benchmark_id: benchmark_id.to_string(),
                 version_id: version_id.clone(),
+                changes,
             })
             .await?;
 
@@ -439,6 +488,85 @@ where
             .ok_or_else(|| ApplicationError::Internal("Failed to fetch created version".to_string()))
     }
 
+    /// Add an error for every test case input or expected output over the
+    /// configured payload limit
+    fn check_test_case_payloads(&self, test_cases: &[TestCase], validation: &mut ValidationResult) {
+        let max_payload = self.config.max_test_case_payload_bytes;
+        for (i, test_case) in test_cases.iter().enumerate() {
+            for issue in test_case.check_payload_size(max_payload) {
+                let field = format!("test_cases[{}].{}", i, issue.path);
+                validation.add_field_error(field, issue.message);
+            }
+        }
+    }
+
+    /// Reject a version that already exists or does not follow the latest one
+    async fn check_new_version(
+        &self,
+        benchmark: &BenchmarkDto,
+        version: &str,
+    ) -> ApplicationResult<()> {
+        let requested = SemanticVersion::parse(version)
+            .map_err(|e| ApplicationError::InvalidInput(format!("Invalid version: {}", e)))?;
+
+        let known = self.known_versions(benchmark).await?;
+        if known.iter().any(|v| v.cmp(&requested).is_eq()) {
+            return Err(ApplicationError::Conflict(format!(
+                "Version {} already exists for benchmark {}",
+                requested, benchmark.id
+            )));
+        }
+        if let Some(latest) = known.iter().max() {
+            if requested < *latest {
+                return Err(ApplicationError::Conflict(format!(
+                    "Version {} must be greater than the latest version {}",
+                    requested, latest
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Every parseable version a benchmark has had, including its current one
+    async fn known_versions(
+        &self,
+        benchmark: &BenchmarkDto,
+    ) -> ApplicationResult<Vec<SemanticVersion>> {
+        let versions = self.repository.get_versions(&benchmark.id).await?;
+        Ok(versions
+            .iter()
+            .map(|v| v.version.as_str())
+            .chain(benchmark.current_version.as_deref())
+            .filter_map(|v| SemanticVersion::parse(v).ok())
+            .collect())
+    }
+
+    /// Suggest the version to follow a benchmark's latest one
+    ///
+    /// Breaking changes bump the major version and anything else the minor
+    /// version. A benchmark without versions starts at `1.0.0`.
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn suggest_next_version(
+        &self,
+        ctx: &ServiceContext,
+        benchmark_id: &str,
+        breaking: bool,
+    ) -> ApplicationResult<String> {
+        let benchmark = self
+            .repository
+            .get_by_id(benchmark_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Benchmark not found: {}", benchmark_id))
+            })?;
+
+        let next = match self.known_versions(&benchmark).await?.into_iter().max() {
+            Some(latest) => latest.bump(BumpKind::for_change(breaking)),
+            None => SemanticVersion::new(1, 0, 0),
+        };
+        Ok(next.to_string())
+    }
+
     /// Get all versions for a benchmark
     #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
     pub async fn get_versions(
@@ -525,6 +653,299 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::test_support::{benchmark, InMemoryBenchmarks, RecordingPublisher};
+    use crate::services::DefaultAuthorizer;
+
+    const BENCHMARK_ID: &str = "bench-1";
+
+    fn repository(test_cases: Vec<TestCase>) -> Arc<InMemoryBenchmarks> {
+        Arc::new(
+            InMemoryBenchmarks::default()
+                .with_benchmark(benchmark(BENCHMARK_ID, None))
+                .with_test_cases(BENCHMARK_ID, test_cases),
+        )
+    }
+
+    fn test_case(id: &str) -> TestCase {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "input": {
+                "prompt_template": format!("Question {}?", id),
+                "variables": {},
+                "few_shot_examples": [],
+                "input_format": {"type": "plain_text"}
+            },
+            "evaluation_method": {"type": "exact_match"},
+            "weight": 1.0,
+            "tags": []
+        }))
+        .unwrap()
+    }
+
+    fn service(
+        test_cases: Vec<TestCase>,
+    ) -> (
+        BenchmarkService<InMemoryBenchmarks, DefaultAuthorizer, RecordingPublisher>,
+        Arc<RecordingPublisher>,
+    ) {
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = BenchmarkService::new(
+            repository(test_cases),
+            Arc::new(DefaultAuthorizer),
+            publisher.clone(),
+            ServiceConfig::default(),
+        );
+        (service, publisher)
+    }
+
+    fn version_request(test_cases: Option<Vec<TestCase>>) -> CreateVersionRequest {
+        CreateVersionRequest {
+            version: "1.1.0".to_string(),
+            changelog: "Replace one of the test cases".to_string(),
+            breaking_changes: false,
+            migration_notes: None,
+            test_cases,
+        }
+    }
+
+    fn published_changes(publisher: &RecordingPublisher) -> VersionChangeSummary {
+        match publisher.events.lock().unwrap().as_slice() {
+            [ServiceEvent::BenchmarkVersionCreated { changes, .. }] => *changes,
+            events => panic!("Unexpected events: {:?}", events),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_version_publishes_change_summary() {
+        let (service, publisher) = service(vec![test_case("a"), test_case("b")]);
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        let version = service
+            .create_version(
+                &ctx,
+                BENCHMARK_ID,
+                version_request(Some(vec![test_case("a"), test_case("c")])),
+            )
+            .await
+            .unwrap();
+        assert_eq!(version.version, "1.1.0");
+
+        assert_eq!(
+            published_changes(&publisher),
+            VersionChangeSummary {
+                added: 1,
+                removed: 1,
+                modified: 0,
+                breaking: false,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_version_without_test_cases_reports_no_changes() {
+        let (service, publisher) = service(vec![test_case("a")]);
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        let mut request = version_request(None);
+        request.breaking_changes = true;
+        request.migration_notes = Some("Scores are not comparable with 1.0.0 results".to_string());
+        service.create_version(&ctx, BENCHMARK_ID, request).await.unwrap();
+
+        assert_eq!(
+            published_changes(&publisher),
+            VersionChangeSummary {
+                breaking: true,
+                ..VersionChangeSummary::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_version_rejects_duplicate_version() {
+        let (service, publisher) = service(vec![test_case("a")]);
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        service.create_version(&ctx, BENCHMARK_ID, version_request(None)).await.unwrap();
+        let err = service
+            .create_version(&ctx, BENCHMARK_ID, version_request(None))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+        assert!(err.to_string().contains("Version 1.1.0 already exists"));
+        assert_eq!(publisher.events.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_version_rejects_non_increasing_version() {
+        let (service, publisher) = service(vec![test_case("a")]);
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        service.create_version(&ctx, BENCHMARK_ID, version_request(None)).await.unwrap();
+
+        for version in ["1.0.5", "0.9.0", "0.12.3"] {
+            let mut request = version_request(None);
+            request.version = version.to_string();
+            let err = service.create_version(&ctx, BENCHMARK_ID, request).await.unwrap_err();
+            assert!(matches!(err, ApplicationError::Conflict(_)), "{}: {:?}", version, err);
+            assert!(err
+                .to_string()
+                .contains("must be greater than the latest version 1.1.0"));
+        }
+
+        assert_eq!(publisher.events.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_current_version_counts_as_latest() {
+        let (service, _) = service(vec![test_case("a")]);
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        let mut request = version_request(None);
+        request.version = "1.0.0".to_string();
+        let err = service.create_version(&ctx, BENCHMARK_ID, request).await.unwrap_err();
+        assert!(err.to_string().contains("Version 1.0.0 already exists"));
+    }
+
+    /// Service over `repository` with a 1 KiB test case payload limit
+    fn limited_service(
+        repository: Arc<InMemoryBenchmarks>,
+    ) -> BenchmarkService<InMemoryBenchmarks, DefaultAuthorizer, RecordingPublisher> {
+        BenchmarkService::new(
+            repository,
+            Arc::new(DefaultAuthorizer),
+            Arc::new(RecordingPublisher::default()),
+            ServiceConfig {
+                max_test_case_payload_bytes: 1024,
+                ..ServiceConfig::default()
+            },
+        )
+    }
+
+    fn oversized_test_case(id: &str) -> TestCase {
+        let mut test_case = test_case(id);
+        test_case.input.prompt_template = "x".repeat(2048);
+        test_case
+    }
+
+    fn assert_payload_rejected(err: ApplicationError, field: &str) {
+        match err {
+            ApplicationError::InvalidFields(result) => {
+                let messages = &result.field_errors[field];
+                assert!(messages[0].contains("exceeding the limit of 1024 bytes"), "{:?}", messages);
+            }
+            other => panic!("expected validation failure, got {:?}", other),
+        }
+    }
+
+    fn create_request(test_cases: Vec<TestCase>) -> CreateBenchmarkRequest {
+        CreateBenchmarkRequest {
+            name: "Sized Benchmark".to_string(),
+            slug: "sized-benchmark".to_string(),
+            description: "A benchmark with capped test cases".to_string(),
+            category: BenchmarkCategory::Accuracy,
+            tags: vec![],
+            version: "1.0.0".to_string(),
+            leaderboard_min_verification: VerificationLevel::Unverified,
+            test_cases,
+        }
+    }
 
-    // Tests would go here with mock implementations
+    fn test_cases_update(test_cases: Vec<TestCase>) -> UpdateBenchmarkRequest {
+        UpdateBenchmarkRequest {
+            name: None,
+            description: None,
+            tags: None,
+            long_description: None,
+            test_cases: Some(test_cases),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_oversized_test_case_input() {
+        let repository = Arc::new(InMemoryBenchmarks::default());
+        let service = limited_service(repository.clone());
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        let err = service
+            .create(&ctx, create_request(vec![test_case("a"), oversized_test_case("b")]))
+            .await
+            .unwrap_err();
+        assert_payload_rejected(err, "test_cases[1].input");
+        assert!(repository.benchmarks.lock().unwrap().is_empty());
+
+        let created = service.create(&ctx, create_request(vec![test_case("a")])).await.unwrap();
+        assert_eq!(repository.get_test_cases(&created.id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_replaces_draft_test_cases_within_limit() {
+        let repository = Arc::new(InMemoryBenchmarks::default());
+        let service = limited_service(repository.clone());
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+        let created = service.create(&ctx, create_request(vec![test_case("a")])).await.unwrap();
+
+        let err = service
+            .update(&ctx, &created.id, test_cases_update(vec![oversized_test_case("a")]))
+            .await
+            .unwrap_err();
+        assert_payload_rejected(err, "test_cases[0].input");
+
+        service
+            .update(&ctx, &created.id, test_cases_update(vec![test_case("b"), test_case("c")]))
+            .await
+            .unwrap();
+        assert_eq!(repository.get_test_cases(&created.id).await.unwrap().len(), 2);
+
+        // Once published, test cases only change through a new version
+        repository
+            .update_status(&created.id, BenchmarkStatus::Active)
+            .await
+            .unwrap();
+        let err = service
+            .update(&ctx, &created.id, test_cases_update(vec![test_case("d")]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::Conflict(_)), "{:?}", err);
+        assert_eq!(repository.get_test_cases(&created.id).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_version_rejects_oversized_test_case_input() {
+        let service = limited_service(repository(vec![test_case("a")]));
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+        let oversized = oversized_test_case("b");
+
+        let err = service
+            .create_version(
+                &ctx,
+                BENCHMARK_ID,
+                version_request(Some(vec![test_case("a"), oversized])),
+            )
+            .await
+            .unwrap_err();
+        assert_payload_rejected(err, "test_cases[1].input");
+
+        service
+            .create_version(&ctx, BENCHMARK_ID, version_request(Some(vec![test_case("a")])))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_suggest_next_version() {
+        let (service, _) = service(vec![test_case("a")]);
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "test".to_string());
+
+        // Only the benchmark's current 1.0.0 is known
+        assert_eq!(service.suggest_next_version(&ctx, BENCHMARK_ID, false).await.unwrap(), "1.1.0");
+
+        service.create_version(&ctx, BENCHMARK_ID, version_request(None)).await.unwrap();
+        assert_eq!(service.suggest_next_version(&ctx, BENCHMARK_ID, false).await.unwrap(), "1.2.0");
+        assert_eq!(service.suggest_next_version(&ctx, BENCHMARK_ID, true).await.unwrap(), "2.0.0");
+
+        let err = service.suggest_next_version(&ctx, "missing", false).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::NotFound(_)));
+    }
 }
@@ -0,0 +1,423 @@
+//! Service events
+//!
+//! Events published by the application services after a change has been
+//! persisted, for consumers such as caches, notifications and the worker.
+
+use crate::ApplicationError;
+use async_trait::async_trait;
+use llm_benchmark_domain::governance::ProposalOutcome;
+use llm_benchmark_domain::test_case::TestCase;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Service event for event-driven architecture
+#[derive(Debug, Clone)]
+pub enum ServiceEvent {
+    // Benchmark events
+    BenchmarkCreated { benchmark_id: String },
+    BenchmarkUpdated { benchmark_id: String },
+    BenchmarkStatusChanged { benchmark_id: String, new_status: String },
+    BenchmarkVersionCreated {
+        benchmark_id: String,
+        version_id: String,
+        changes: VersionChangeSummary,
+    },
+
+    // Submission events
+    SubmissionCreated { submission_id: String },
+    SubmissionVerified { submission_id: String, level: String },
+    SubmissionVerificationFailed { submission_id: String, reason: String },
+    SubmissionRejected { submission_id: String, reason: String },
+    SubmissionScoreUpdated { submission_id: String },
+    SubmissionCancelled { submission_id: String },
+    SubmissionVerificationProgress { progress: VerificationProgress },
+
+    // User events
+    UserCreated { user_id: String },
+    UserUpdated { user_id: String },
+    UserPasswordChanged { user_id: String },
+    UserDeleted { user_id: String },
+
+    // Organization events
+    OrganizationCreated { organization_id: String },
+    OrganizationMemberAdded { organization_id: String, user_id: String },
+    OrganizationMemberRemoved { organization_id: String, user_id: String },
+
+    // Governance events
+    VoteCast { proposal_id: String, user_id: String },
+    ProposalCommentAdded { proposal_id: String, comment_id: String },
+    ProposalFinalized { proposal_id: String, outcome: ProposalOutcome },
+}
+
+impl ServiceEvent {
+    /// Messaging channel and JSON payload for the event
+    ///
+    /// Most events go to a dotted channel such as `benchmark.created`;
+    /// progress updates go to their submission's own channel.
+    pub fn to_message(&self) -> (String, serde_json::Value) {
+        match self {
+            Self::BenchmarkCreated { benchmark_id } => {
+                ("benchmark.created".to_string(), json!({ "benchmark_id": benchmark_id }))
+            }
+            Self::BenchmarkUpdated { benchmark_id } => {
+                ("benchmark.updated".to_string(), json!({ "benchmark_id": benchmark_id }))
+            }
+            Self::BenchmarkStatusChanged { benchmark_id, new_status } => (
+                "benchmark.status_changed".to_string(),
+                json!({ "benchmark_id": benchmark_id, "new_status": new_status }),
+            ),
+            Self::BenchmarkVersionCreated { benchmark_id, version_id, changes } => (
+                "benchmark.version_created".to_string(),
+                json!({
+                    "benchmark_id": benchmark_id,
+                    "version_id": version_id,
+                    "changes": changes,
+                }),
+            ),
+            Self::SubmissionCreated { submission_id } => {
+                ("submission.created".to_string(), json!({ "submission_id": submission_id }))
+            }
+            Self::SubmissionVerified { submission_id, level } => (
+                "submission.verified".to_string(),
+                json!({ "submission_id": submission_id, "level": level }),
+            ),
+            Self::SubmissionVerificationFailed { submission_id, reason } => (
+                "submission.verification_failed".to_string(),
+                json!({ "submission_id": submission_id, "reason": reason }),
+            ),
+            Self::SubmissionRejected { submission_id, reason } => (
+                "submission.rejected".to_string(),
+                json!({ "submission_id": submission_id, "reason": reason }),
+            ),
+            Self::SubmissionVerificationProgress { progress } => (
+                VerificationProgress::channel(&progress.submission_id),
+                json!(progress),
+            ),
+            Self::SubmissionScoreUpdated { submission_id } => {
+                ("submission.score_updated".to_string(), json!({ "submission_id": submission_id }))
+            }
+            Self::SubmissionCancelled { submission_id } => {
+                ("submission.cancelled".to_string(), json!({ "submission_id": submission_id }))
+            }
+            Self::UserCreated { user_id } => {
+                ("user.created".to_string(), json!({ "user_id": user_id }))
+            }
+            Self::UserUpdated { user_id } => {
+                ("user.updated".to_string(), json!({ "user_id": user_id }))
+            }
+            Self::UserPasswordChanged { user_id } => {
+                ("user.password_changed".to_string(), json!({ "user_id": user_id }))
+            }
+            Self::UserDeleted { user_id } => {
+                ("user.deleted".to_string(), json!({ "user_id": user_id }))
+            }
+            Self::OrganizationCreated { organization_id } => {
+                ("organization.created".to_string(), json!({ "organization_id": organization_id }))
+            }
+            Self::OrganizationMemberAdded { organization_id, user_id } => (
+                "organization.member_added".to_string(),
+                json!({ "organization_id": organization_id, "user_id": user_id }),
+            ),
+            Self::OrganizationMemberRemoved { organization_id, user_id } => (
+                "organization.member_removed".to_string(),
+                json!({ "organization_id": organization_id, "user_id": user_id }),
+            ),
+            Self::VoteCast { proposal_id, user_id } => (
+                "proposal.vote_cast".to_string(),
+                json!({ "proposal_id": proposal_id, "user_id": user_id }),
+            ),
+            Self::ProposalCommentAdded { proposal_id, comment_id } => (
+                "proposal.comment_added".to_string(),
+                json!({ "proposal_id": proposal_id, "comment_id": comment_id }),
+            ),
+            Self::ProposalFinalized { proposal_id, outcome } => (
+                "proposal.finalized".to_string(),
+                json!({ "proposal_id": proposal_id, "outcome": outcome }),
+            ),
+        }
+    }
+
+    /// Whether the event describes current state that subscribers arriving
+    /// later should still be able to read
+    pub fn is_retained(&self) -> bool {
+        matches!(self, Self::SubmissionVerificationProgress { .. })
+    }
+}
+
+/// What changed between a benchmark version and the one before it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionChangeSummary {
+    /// Test cases whose ID is new in this version
+    pub added: usize,
+    /// Test cases no longer present in this version
+    pub removed: usize,
+    /// Test cases kept under the same ID but with different content
+    pub modified: usize,
+    /// Whether the version is marked as a breaking change
+    pub breaking: bool,
+}
+
+impl VersionChangeSummary {
+    /// Compare two sets of test cases, matching them by ID
+    pub fn between(previous: &[TestCase], next: &[TestCase], breaking: bool) -> Self {
+        let previous: HashMap<&str, &TestCase> =
+            previous.iter().map(|tc| (tc.id.as_str(), tc)).collect();
+        let mut summary = Self {
+            breaking,
+            ..Self::default()
+        };
+
+        let mut kept = 0;
+        for test_case in next {
+            match previous.get(test_case.id.as_str()) {
+                Some(old) => {
+                    kept += 1;
+                    if !same_content(old, test_case) {
+                        summary.modified += 1;
+                    }
+                }
+                None => summary.added += 1,
+            }
+        }
+        summary.removed = previous.len() - kept;
+
+        summary
+    }
+
+    /// Whether the version changed any test cases
+    pub fn has_test_case_changes(&self) -> bool {
+        self.added + self.removed + self.modified > 0
+    }
+}
+
+/// Test cases don't implement `PartialEq`, so compare their serialized form
+fn same_content(a: &TestCase, b: &TestCase) -> bool {
+    match (serde_json::to_value(a), serde_json::to_value(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Stage of an asynchronous submission verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationStage {
+    /// Waiting for a worker to pick the job up
+    Queued,
+    /// A worker is verifying the submission
+    Running,
+    /// Verification finished successfully
+    Verified,
+    /// Verification finished with an error
+    Failed,
+}
+
+impl VerificationStage {
+    /// Snake-case name, as serialized
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Verified => "verified",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Whether no further progress follows this stage
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Verified | Self::Failed)
+    }
+}
+
+/// Progress update for one submission's verification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationProgress {
+    pub submission_id: String,
+    pub stage: VerificationStage,
+    /// Detail for the stage, such as the error behind a failure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl VerificationProgress {
+    pub fn new(submission_id: impl Into<String>, stage: VerificationStage) -> Self {
+        Self {
+            submission_id: submission_id.into(),
+            stage,
+            message: None,
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Messaging channel carrying progress for `submission_id`
+    ///
+    /// Each submission gets its own channel so a subscriber only receives
+    /// the updates it is waiting for.
+    pub fn channel(submission_id: &str) -> String {
+        format!("submission.verification.{}", submission_id)
+    }
+}
+
+/// Event publisher trait for service events
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, event: ServiceEvent) -> Result<(), ApplicationError>;
+}
+
+/// No-op event publisher for testing
+pub struct NoOpEventPublisher;
+
+#[async_trait]
+impl EventPublisher for NoOpEventPublisher {
+    async fn publish(&self, _event: ServiceEvent) -> Result<(), ApplicationError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_case(id: &str, prompt: &str) -> TestCase {
+        serde_json::from_value(json!({
+            "id": id,
+            "name": id,
+            "input": {
+                "prompt_template": prompt,
+                "variables": {},
+                "few_shot_examples": [],
+                "input_format": {"type": "plain_text"}
+            },
+            "evaluation_method": {"type": "exact_match"},
+            "weight": 1.0,
+            "tags": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_event_message() {
+        let (channel, payload) = ServiceEvent::BenchmarkStatusChanged {
+            benchmark_id: "b-1".to_string(),
+            new_status: "active".to_string(),
+        }
+        .to_message();
+        assert_eq!(channel, "benchmark.status_changed");
+        assert_eq!(payload["benchmark_id"], "b-1");
+        assert_eq!(payload["new_status"], "active");
+    }
+
+    #[test]
+    fn test_submission_failure_events_carry_reason() {
+        let (channel, payload) = ServiceEvent::SubmissionVerificationFailed {
+            submission_id: "s-1".to_string(),
+            reason: "Reproduced score differs".to_string(),
+        }
+        .to_message();
+        assert_eq!(channel, "submission.verification_failed");
+        assert_eq!(payload, json!({"submission_id": "s-1", "reason": "Reproduced score differs"}));
+
+        let (channel, payload) = ServiceEvent::SubmissionRejected {
+            submission_id: "s-1".to_string(),
+            reason: "Score out of range".to_string(),
+        }
+        .to_message();
+        assert_eq!(channel, "submission.rejected");
+        assert_eq!(payload["reason"], "Score out of range");
+    }
+
+    #[test]
+    fn test_version_created_event_carries_changes() {
+        let (channel, payload) = ServiceEvent::BenchmarkVersionCreated {
+            benchmark_id: "b-1".to_string(),
+            version_id: "v-2".to_string(),
+            changes: VersionChangeSummary {
+                added: 1,
+                removed: 1,
+                modified: 0,
+                breaking: true,
+            },
+        }
+        .to_message();
+        assert_eq!(channel, "benchmark.version_created");
+        assert_eq!(
+            payload["changes"],
+            json!({"added": 1, "removed": 1, "modified": 0, "breaking": true})
+        );
+    }
+
+    #[test]
+    fn test_progress_events_are_retained() {
+        let event = ServiceEvent::SubmissionVerificationProgress {
+            progress: VerificationProgress::new("s-1", VerificationStage::Running),
+        };
+        let (channel, payload) = event.to_message();
+        assert_eq!(channel, "submission.verification.s-1");
+        assert_eq!(payload["stage"], "running");
+        assert!(event.is_retained());
+
+        let event = ServiceEvent::SubmissionCreated {
+            submission_id: "s-1".to_string(),
+        };
+        assert!(!event.is_retained());
+    }
+
+    #[test]
+    fn test_summary_between_versions() {
+        let previous = vec![test_case("a", "A?"), test_case("b", "B?"), test_case("c", "C?")];
+        let next = vec![test_case("a", "A?"), test_case("b", "B, reworded?"), test_case("d", "D?")];
+
+        let summary = VersionChangeSummary::between(&previous, &next, true);
+        assert_eq!(
+            summary,
+            VersionChangeSummary {
+                added: 1,
+                removed: 1,
+                modified: 1,
+                breaking: true,
+            }
+        );
+        assert!(summary.has_test_case_changes());
+    }
+
+    #[test]
+    fn test_summary_without_changes() {
+        let cases = vec![test_case("a", "A?")];
+        let summary = VersionChangeSummary::between(&cases, &cases, false);
+
+        assert_eq!(summary, VersionChangeSummary::default());
+        assert!(!summary.has_test_case_changes());
+    }
+
+    #[test]
+    fn test_summary_serialization() {
+        let summary = VersionChangeSummary {
+            added: 2,
+            removed: 0,
+            modified: 1,
+            breaking: false,
+        };
+        assert_eq!(
+            serde_json::to_value(summary).unwrap(),
+            json!({"added": 2, "removed": 0, "modified": 1, "breaking": false})
+        );
+    }
+
+    #[test]
+    fn test_verification_progress_serialization() {
+        let progress = VerificationProgress::new("sub-1", VerificationStage::Failed)
+            .with_message("Scores differ");
+        assert!(progress.stage.is_terminal());
+        assert!(!VerificationStage::Running.is_terminal());
+        assert_eq!(
+            serde_json::to_value(&progress).unwrap(),
+            json!({"submission_id": "sub-1", "stage": "failed", "message": "Scores differ"})
+        );
+        assert_eq!(VerificationProgress::channel("sub-1"), "submission.verification.sub-1");
+    }
+}
@@ -0,0 +1,721 @@
+//! Governance Service
+//!
+//! Business logic for community governance proposals, voting and
+//! finalization.
+
+use super::{EventPublisher, ServiceContext, ServiceEvent};
+use crate::{ApplicationError, ApplicationResult};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use llm_benchmark_domain::errors::GovernanceError;
+use llm_benchmark_domain::governance::{
+    Comment, CommentThread, Proposal, ProposalOutcome, ProposalPolicy, ProposalStatus,
+    ProposalType, RoleWeights, Vote, VotingEligibility,
+};
+use llm_benchmark_domain::identifiers::{BenchmarkId, CommentId, ProposalId, UserId};
+use llm_benchmark_domain::user::UserRole;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Proposal repository trait (to be implemented by infrastructure)
+#[async_trait]
+pub trait ProposalRepositoryPort: Send + Sync {
+    async fn get_by_id(&self, id: &ProposalId) -> Result<Option<Proposal>, ApplicationError>;
+    /// Store `proposal`, failing with `Conflict` if its stored status is no
+    /// longer `expected_status`
+    async fn update(
+        &self,
+        proposal: &Proposal,
+        expected_status: ProposalStatus,
+    ) -> Result<(), ApplicationError>;
+    async fn add_comment(&self, comment: &Comment) -> Result<(), ApplicationError>;
+    async fn get_comment(&self, id: &CommentId) -> Result<Option<Comment>, ApplicationError>;
+    async fn list_comments(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<Vec<Comment>, ApplicationError>;
+}
+
+/// Maximum length of a proposal comment body
+const MAX_COMMENT_LENGTH: usize = 10_000;
+
+/// How a proposal was decided, for publication
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub proposal_id: ProposalId,
+    pub proposal_type: ProposalType,
+    pub title: String,
+    pub description: String,
+    pub rationale: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub benchmark_id: Option<BenchmarkId>,
+    /// Status the proposal was finalized with
+    pub status: ProposalStatus,
+    pub voting_starts: Option<DateTime<Utc>>,
+    pub voting_ends: Option<DateTime<Utc>>,
+    pub tally: VoteTally,
+    /// Every vote in the order it was cast
+    pub votes: Vec<RecordedVote>,
+    /// Quorum and approval threshold the outcome was computed with
+    pub policy: ProposalPolicy,
+    /// Voting power of each voter role in the weighted tally
+    pub role_weights: RoleWeights,
+    pub outcome: ProposalOutcome,
+}
+
+/// Final vote counts of a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteTally {
+    pub approve: u32,
+    pub reject: u32,
+    pub abstain: u32,
+    /// All votes, abstentions included
+    pub total: u32,
+}
+
+/// A vote as published in a decision record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedVote {
+    /// The voter, omitted when voters are anonymized
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voter: Option<UserId>,
+    pub voter_role: UserRole,
+    pub vote: Vote,
+    pub cast_at: DateTime<Utc>,
+}
+
+/// Governance service implementation
+pub struct GovernanceService<R, E>
+where
+    R: ProposalRepositoryPort,
+    E: EventPublisher,
+{
+    repository: Arc<R>,
+    event_publisher: Arc<E>,
+    eligibility: VotingEligibility,
+    role_weights: RoleWeights,
+    anonymize_voters: bool,
+}
+
+impl<R, E> GovernanceService<R, E>
+where
+    R: ProposalRepositoryPort,
+    E: EventPublisher,
+{
+    pub fn new(repository: Arc<R>, event_publisher: Arc<E>, eligibility: VotingEligibility) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            eligibility,
+            role_weights: RoleWeights::platform_default(),
+            anonymize_voters: false,
+        }
+    }
+
+    /// Voting power of each role when tallying, matching the weights
+    /// proposals are finalized with
+    pub fn with_role_weights(mut self, weights: RoleWeights) -> Self {
+        self.role_weights = weights;
+        self
+    }
+
+    /// Leave voter identities out of published decision records
+    pub fn with_anonymized_voters(mut self, anonymize: bool) -> Self {
+        self.anonymize_voters = anonymize;
+        self
+    }
+
+    /// Cast a vote on a proposal, weighted by the caller's platform role.
+    ///
+    /// Votes outside the proposal's voting window or repeat votes are
+    /// rejected with `Conflict`; voters below the eligible role get
+    /// `Forbidden`.
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn cast_vote(
+        &self,
+        ctx: &ServiceContext,
+        proposal_id: &ProposalId,
+        vote: Vote,
+    ) -> ApplicationResult<()> {
+        let user_id = ctx.require_authenticated()?;
+        let voter: UserId = user_id
+            .parse()
+            .map_err(|_| ApplicationError::InvalidInput(format!("Invalid user ID: {}", user_id)))?;
+
+        let mut proposal = self.require_proposal(proposal_id).await?;
+        let loaded_status = proposal.status;
+
+        proposal
+            .cast_vote(voter, ctx.role, vote, &self.eligibility, Utc::now())
+            .map_err(map_vote_error)?;
+
+        // Fails with `Conflict` if the proposal was finalized meanwhile
+        self.repository.update(&proposal, loaded_status).await?;
+
+        info!(proposal_id = %proposal_id, voter = %user_id, vote = ?vote, "Vote cast");
+
+        self.event_publisher
+            .publish(ServiceEvent::VoteCast {
+                proposal_id: proposal_id.to_string(),
+                user_id: user_id.to_string(),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Add a comment to a proposal, optionally as a reply.
+    ///
+    /// A `parent_id` must refer to a comment on the same proposal.
+    #[instrument(skip(self, ctx, body), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn add_comment(
+        &self,
+        ctx: &ServiceContext,
+        proposal_id: &ProposalId,
+        body: String,
+        parent_id: Option<CommentId>,
+    ) -> ApplicationResult<Comment> {
+        let user_id = ctx.require_authenticated()?;
+        let author: UserId = user_id
+            .parse()
+            .map_err(|_| ApplicationError::InvalidInput(format!("Invalid user ID: {}", user_id)))?;
+
+        let body = body.trim().to_string();
+        if body.is_empty() {
+            return Err(ApplicationError::ValidationFailed(
+                "Comment must not be empty".to_string(),
+            ));
+        }
+        if body.len() > MAX_COMMENT_LENGTH {
+            return Err(ApplicationError::ValidationFailed(format!(
+                "Comment must be at most {} characters",
+                MAX_COMMENT_LENGTH
+            )));
+        }
+
+        self.require_proposal(proposal_id).await?;
+
+        if let Some(parent_id) = parent_id {
+            let parent = self
+                .repository
+                .get_comment(&parent_id)
+                .await?
+                .ok_or_else(|| ApplicationError::NotFound(format!("Comment not found: {}", parent_id)))?;
+
+            if parent.proposal_id != *proposal_id {
+                return Err(ApplicationError::InvalidInput(format!(
+                    "Comment {} does not belong to proposal {}",
+                    parent_id, proposal_id
+                )));
+            }
+        }
+
+        let comment = Comment::new(*proposal_id, author, body, parent_id);
+        self.repository.add_comment(&comment).await?;
+
+        info!(proposal_id = %proposal_id, comment_id = %comment.id, "Comment added");
+
+        self.event_publisher
+            .publish(ServiceEvent::ProposalCommentAdded {
+                proposal_id: proposal_id.to_string(),
+                comment_id: comment.id.to_string(),
+            })
+            .await?;
+
+        Ok(comment)
+    }
+
+    /// List a proposal's comments arranged into reply threads
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn list_comments(
+        &self,
+        ctx: &ServiceContext,
+        proposal_id: &ProposalId,
+    ) -> ApplicationResult<Vec<CommentThread>> {
+        self.require_proposal(proposal_id).await?;
+
+        let comments = self.repository.list_comments(proposal_id).await?;
+        Ok(CommentThread::build(comments))
+    }
+
+    /// Build the publishable record of how a finalized proposal was decided.
+    ///
+    /// The outcome is recomputed from the ballots with the policy and role
+    /// weights recorded on the proposal at finalization. Proposals finalized
+    /// before role weights were recorded fall back to the service's weights.
+    /// Proposals that have not been decided yet are rejected with `Conflict`.
+    #[instrument(skip(self))]
+    pub async fn decision_record(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> ApplicationResult<DecisionRecord> {
+        let proposal = self.require_proposal(proposal_id).await?;
+        if !matches!(proposal.status, ProposalStatus::Approved | ProposalStatus::Rejected) {
+            return Err(ApplicationError::Conflict(format!(
+                "Proposal {} has not been decided",
+                proposal_id
+            )));
+        }
+
+        let voting = &proposal.voting;
+        let policy = ProposalPolicy::from_voting(voting);
+        let role_weights = voting
+            .role_weights
+            .clone()
+            .unwrap_or_else(|| self.role_weights.clone());
+        let outcome = ProposalOutcome::from_weighted_votes(voting, &policy, &role_weights);
+        let votes = voting
+            .ballots
+            .iter()
+            .map(|ballot| RecordedVote {
+                voter: (!self.anonymize_voters).then_some(ballot.voter),
+                voter_role: ballot.role,
+                vote: ballot.vote,
+                cast_at: ballot.cast_at,
+            })
+            .collect();
+
+        Ok(DecisionRecord {
+            proposal_id: proposal.id,
+            proposal_type: proposal.proposal_type,
+            title: proposal.title.clone(),
+            description: proposal.description.clone(),
+            rationale: proposal.rationale.clone(),
+            benchmark_id: proposal.benchmark_id,
+            status: proposal.status,
+            voting_starts: voting.voting_starts,
+            voting_ends: voting.voting_ends,
+            tally: VoteTally {
+                approve: voting.votes_for,
+                reject: voting.votes_against,
+                abstain: voting.votes_abstain,
+                total: voting.total_votes(),
+            },
+            votes,
+            policy,
+            role_weights,
+            outcome,
+        })
+    }
+
+    async fn require_proposal(&self, proposal_id: &ProposalId) -> ApplicationResult<Proposal> {
+        self.repository
+            .get_by_id(proposal_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Proposal not found: {}", proposal_id)))
+    }
+}
+
+fn map_vote_error(err: GovernanceError) -> ApplicationError {
+    match err {
+        GovernanceError::VotingNotActive | GovernanceError::AlreadyVoted => {
+            ApplicationError::Conflict(err.to_string())
+        }
+        GovernanceError::InsufficientVotingPower => ApplicationError::Forbidden(err.to_string()),
+        other => ApplicationError::Internal(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::NoOpEventPublisher;
+    use chrono::Duration;
+    use llm_benchmark_domain::governance::{ProposalStatus, ProposalType, VotingState};
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryProposals {
+        proposals: Mutex<HashMap<ProposalId, Proposal>>,
+        comments: Mutex<Vec<Comment>>,
+    }
+
+    #[async_trait]
+    impl ProposalRepositoryPort for InMemoryProposals {
+        async fn get_by_id(&self, id: &ProposalId) -> Result<Option<Proposal>, ApplicationError> {
+            Ok(self.proposals.lock().unwrap().get(id).cloned())
+        }
+
+        async fn update(
+            &self,
+            proposal: &Proposal,
+            expected_status: ProposalStatus,
+        ) -> Result<(), ApplicationError> {
+            let mut proposals = self.proposals.lock().unwrap();
+            match proposals.get(&proposal.id) {
+                Some(stored) if stored.status != expected_status => {
+                    Err(ApplicationError::Conflict("Proposal status changed".to_string()))
+                }
+                _ => {
+                    proposals.insert(proposal.id, proposal.clone());
+                    Ok(())
+                }
+            }
+        }
+
+        async fn add_comment(&self, comment: &Comment) -> Result<(), ApplicationError> {
+            self.comments.lock().unwrap().push(comment.clone());
+            Ok(())
+        }
+
+        async fn get_comment(&self, id: &CommentId) -> Result<Option<Comment>, ApplicationError> {
+            Ok(self
+                .comments
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id == *id)
+                .cloned())
+        }
+
+        async fn list_comments(
+            &self,
+            proposal_id: &ProposalId,
+        ) -> Result<Vec<Comment>, ApplicationError> {
+            Ok(self
+                .comments
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.proposal_id == *proposal_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn proposal_with_window(starts: Duration, ends: Duration) -> Proposal {
+        let now = Utc::now();
+        Proposal {
+            id: ProposalId::new(),
+            proposal_type: ProposalType::NewBenchmark,
+            title: "New benchmark".to_string(),
+            description: "Test proposal".to_string(),
+            created_by: UserId::new(),
+            status: ProposalStatus::Voting,
+            benchmark_id: None,
+            rationale: "Test rationale".to_string(),
+            voting: VotingState {
+                voting_starts: Some(now + starts),
+                voting_ends: Some(now + ends),
+                votes_for: 0,
+                votes_against: 0,
+                votes_abstain: 0,
+                voters: HashSet::new(),
+                quorum_required: 1,
+                approval_threshold: 0.5,
+                ballots: Vec::new(),
+                role_weights: None,
+            },
+            reviews: vec![],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    type TestService = GovernanceService<InMemoryProposals, NoOpEventPublisher>;
+
+    fn service_with(proposal: &Proposal) -> (TestService, Arc<InMemoryProposals>) {
+        let repository = Arc::new(InMemoryProposals::default());
+        repository
+            .proposals
+            .lock()
+            .unwrap()
+            .insert(proposal.id, proposal.clone());
+        let service = GovernanceService::new(
+            repository.clone(),
+            Arc::new(NoOpEventPublisher),
+            VotingEligibility::default(),
+        );
+        (service, repository)
+    }
+
+    fn voter_ctx(role: UserRole) -> ServiceContext {
+        ServiceContext::authenticated(UserId::new().to_string(), "corr-123".to_string()).with_role(role)
+    }
+
+    #[tokio::test]
+    async fn test_vote_within_window() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+        let ctx = voter_ctx(UserRole::Contributor);
+
+        service
+            .cast_vote(&ctx, &proposal.id, Vote::Approve)
+            .await
+            .unwrap();
+
+        let stored = repository.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.voting.votes_for, 1);
+
+        let repeat = service
+            .cast_vote(&ctx, &proposal.id, Vote::Reject)
+            .await;
+        assert!(matches!(repeat, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_vote_before_window_rejected() {
+        let proposal = proposal_with_window(Duration::hours(1), Duration::hours(2));
+        let (service, repository) = service_with(&proposal);
+
+        let result = service
+            .cast_vote(&voter_ctx(UserRole::Contributor), &proposal.id, Vote::Approve)
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+        let stored = repository.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.voting.total_votes(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_vote_after_window_rejected() {
+        let proposal = proposal_with_window(-Duration::hours(2), -Duration::hours(1));
+        let (service, _) = service_with(&proposal);
+
+        let result = service
+            .cast_vote(&voter_ctx(UserRole::Contributor), &proposal.id, Vote::Approve)
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_vote_by_ineligible_role_rejected() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+
+        let result = service
+            .cast_vote(&voter_ctx(UserRole::Registered), &proposal.id, Vote::Approve)
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+        let stored = repository.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert!(stored.voting.voters.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_vote_weighted_by_context_role() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+
+        service
+            .cast_vote(&voter_ctx(UserRole::Reviewer), &proposal.id, Vote::Approve)
+            .await
+            .unwrap();
+
+        let stored = repository.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.voting.ballots[0].role, UserRole::Reviewer);
+    }
+
+    #[tokio::test]
+    async fn test_comment_thread_structure() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, _) = service_with(&proposal);
+        let ctx = voter_ctx(UserRole::Contributor);
+
+        let root = service
+            .add_comment(&ctx, &proposal.id, "Looks good".to_string(), None)
+            .await
+            .unwrap();
+        let reply = service
+            .add_comment(&ctx, &proposal.id, "Agreed".to_string(), Some(root.id))
+            .await
+            .unwrap();
+        let nested = service
+            .add_comment(&ctx, &proposal.id, "Same here".to_string(), Some(reply.id))
+            .await
+            .unwrap();
+        let other = service
+            .add_comment(&ctx, &proposal.id, "One concern".to_string(), None)
+            .await
+            .unwrap();
+
+        let threads = service.list_comments(&ctx, &proposal.id).await.unwrap();
+
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].comment.id, root.id);
+        assert_eq!(threads[0].replies.len(), 1);
+        assert_eq!(threads[0].replies[0].comment.id, reply.id);
+        assert_eq!(threads[0].replies[0].replies.len(), 1);
+        assert_eq!(threads[0].replies[0].replies[0].comment.id, nested.id);
+        assert!(threads[0].replies[0].replies[0].replies.is_empty());
+        assert_eq!(threads[1].comment.id, other.id);
+        assert!(threads[1].replies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reply_to_comment_on_other_proposal_rejected() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let other = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+        repository.update(&other, other.status).await.unwrap();
+        let ctx = voter_ctx(UserRole::Contributor);
+
+        let foreign = service
+            .add_comment(&ctx, &other.id, "Elsewhere".to_string(), None)
+            .await
+            .unwrap();
+
+        let result = service
+            .add_comment(&ctx, &proposal.id, "Reply".to_string(), Some(foreign.id))
+            .await;
+        assert!(matches!(result, Err(ApplicationError::InvalidInput(_))));
+
+        let missing = service
+            .add_comment(&ctx, &proposal.id, "Reply".to_string(), Some(CommentId::new()))
+            .await;
+        assert!(matches!(missing, Err(ApplicationError::NotFound(_))));
+    }
+
+    /// Vote with each role, then finalize the proposal as the governance worker would
+    async fn decided_proposal(
+        service: &TestService,
+        repository: &InMemoryProposals,
+        proposal: &Proposal,
+        votes: &[(UserRole, Vote)],
+    ) {
+        for (role, vote) in votes {
+            service
+                .cast_vote(&voter_ctx(*role), &proposal.id, *vote)
+                .await
+                .unwrap();
+        }
+
+        let mut stored = repository.get_by_id(&proposal.id).await.unwrap().unwrap();
+        // Finalize the way GovernanceWorker does, with role-weighted votes
+        let policy = ProposalPolicy::from_voting(&stored.voting);
+        stored.voting.role_weights = Some(service.role_weights.clone());
+        let outcome =
+            ProposalOutcome::from_weighted_votes(&stored.voting, &policy, &service.role_weights);
+        stored.finalize(outcome, Utc::now()).unwrap();
+        repository.update(&stored, ProposalStatus::Voting).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_decision_record_for_approved_proposal() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+        let votes = [
+            (UserRole::Contributor, Vote::Approve),
+            (UserRole::Reviewer, Vote::Approve),
+            (UserRole::Admin, Vote::Reject),
+            (UserRole::Contributor, Vote::Abstain),
+        ];
+        decided_proposal(&service, &repository, &proposal, &votes).await;
+
+        let record = service.decision_record(&proposal.id).await.unwrap();
+
+        assert_eq!(record.status, ProposalStatus::Approved);
+        assert_eq!(record.outcome, ProposalOutcome::Approved);
+        assert_eq!(record.outcome.resulting_status(), record.status);
+        assert_eq!(
+            record.tally,
+            VoteTally {
+                approve: 2,
+                reject: 1,
+                abstain: 1,
+                total: 4
+            }
+        );
+        assert_eq!(record.policy.quorum, 1);
+        assert_eq!(record.policy.approval_threshold, 0.5);
+
+        // Each vote is listed with its voter's role, consistent with the tally
+        let recorded: Vec<(UserRole, Vote)> =
+            record.votes.iter().map(|v| (v.voter_role, v.vote)).collect();
+        assert_eq!(recorded, votes);
+        assert!(record.votes.iter().all(|v| v.voter.is_some()));
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["outcome"], "approved");
+        assert_eq!(json["tally"]["total"], 4);
+        assert_eq!(
+            json["votes"][2]["voter_role"],
+            serde_json::to_value(UserRole::Admin).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decision_record_weights_votes_by_role() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+        // Two contributors outnumber an admin, who outweighs them
+        let votes = [
+            (UserRole::Contributor, Vote::Approve),
+            (UserRole::Contributor, Vote::Approve),
+            (UserRole::Admin, Vote::Reject),
+        ];
+        decided_proposal(&service, &repository, &proposal, &votes).await;
+
+        let record = service.decision_record(&proposal.id).await.unwrap();
+        assert_eq!(record.status, ProposalStatus::Rejected);
+        assert_eq!(record.outcome, ProposalOutcome::Rejected);
+        assert_eq!(record.role_weights, RoleWeights::platform_default());
+
+        // The record alone is enough to replay the decision
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["role_weights"]["admin"], 3);
+        let replayed: DecisionRecord = serde_json::from_value(json).unwrap();
+        let approve: u32 = replayed
+            .votes
+            .iter()
+            .filter(|v| v.vote == Vote::Approve)
+            .map(|v| replayed.role_weights.weight(v.voter_role))
+            .sum();
+        let reject: u32 = replayed
+            .votes
+            .iter()
+            .filter(|v| v.vote == Vote::Reject)
+            .map(|v| replayed.role_weights.weight(v.voter_role))
+            .sum();
+        assert_eq!((approve, reject), (2, 3));
+    }
+
+    #[tokio::test]
+    async fn test_decision_record_uses_weights_recorded_at_finalization() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+        let votes = [
+            (UserRole::Contributor, Vote::Approve),
+            (UserRole::Contributor, Vote::Approve),
+            (UserRole::Admin, Vote::Reject),
+        ];
+        decided_proposal(&service, &repository, &proposal, &votes).await;
+
+        // Equal weights would approve, but the proposal was rejected under the
+        // weights in force when it was finalized
+        let service = service.with_role_weights(RoleWeights::default());
+        let record = service.decision_record(&proposal.id).await.unwrap();
+        assert_eq!(record.status, ProposalStatus::Rejected);
+        assert_eq!(record.outcome, ProposalOutcome::Rejected);
+        assert_eq!(record.role_weights, RoleWeights::platform_default());
+    }
+
+    #[tokio::test]
+    async fn test_decision_record_anonymizes_voters() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, repository) = service_with(&proposal);
+        let service = service.with_anonymized_voters(true);
+        decided_proposal(&service, &repository, &proposal, &[(UserRole::Reviewer, Vote::Reject)])
+            .await;
+
+        let record = service.decision_record(&proposal.id).await.unwrap();
+        assert_eq!(record.outcome, ProposalOutcome::Rejected);
+        assert_eq!(record.votes.len(), 1);
+        assert!(record.votes[0].voter.is_none());
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert!(json["votes"][0].get("voter").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decision_record_requires_decided_proposal() {
+        let proposal = proposal_with_window(-Duration::hours(1), Duration::hours(1));
+        let (service, _) = service_with(&proposal);
+
+        let err = service.decision_record(&proposal.id).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+    }
+}
@@ -3,21 +3,35 @@
 //! Business logic orchestration layer that coordinates domain operations,
 //! repository access, and cross-cutting concerns.
 
+mod authorization;
 mod benchmark;
+mod events;
+mod governance;
 mod organization;
+mod permissions;
 mod publication;
 mod submission;
+#[cfg(test)]
+pub(crate) mod test_support;
 mod user;
 
+pub use authorization::*;
 pub use benchmark::*;
+pub use events::*;
+pub use governance::*;
 pub use organization::*;
+pub use permissions::*;
 pub use publication::*;
 pub use submission::*;
 pub use user::*;
 
+use crate::validation::OrganizationRole;
 use crate::ApplicationError;
 use async_trait::async_trait;
 use llm_benchmark_common::execution::ExecutionContext;
+use llm_benchmark_common::pagination::{self as common, page_count, PaginationParams};
+use llm_benchmark_domain::test_case::TestCase;
+use llm_benchmark_domain::user::UserRole;
 use std::sync::Arc;
 
 /// Service configuration
@@ -31,6 +45,12 @@ pub struct ServiceConfig {
     pub cache_enabled: bool,
     /// Cache TTL in seconds
     pub cache_ttl_seconds: u64,
+    /// Reject a submission whose results are identical to an earlier
+    /// submission of the same model, instead of only logging a warning
+    pub reject_duplicate_submissions: bool,
+    /// Maximum serialized size in bytes of a test case's input and of its
+    /// expected output
+    pub max_test_case_payload_bytes: usize,
 }
 
 impl Default for ServiceConfig {
@@ -40,11 +60,15 @@ impl Default for ServiceConfig {
             default_page_size: 20,
             cache_enabled: true,
             cache_ttl_seconds: 300,
+            reject_duplicate_submissions: false,
+            max_test_case_payload_bytes: TestCase::DEFAULT_MAX_PAYLOAD_BYTES,
         }
     }
 }
 
 /// Pagination parameters for list operations
+///
+/// Pages are 1-indexed; page 0 is treated as page 1.
 #[derive(Debug, Clone)]
 pub struct Pagination {
     pub page: u32,
@@ -53,11 +77,14 @@ pub struct Pagination {
 
 impl Pagination {
     pub fn new(page: u32, page_size: u32) -> Self {
-        Self { page, page_size }
+        Self {
+            page: page.max(1),
+            page_size,
+        }
     }
 
     pub fn offset(&self) -> u64 {
-        ((self.page.saturating_sub(1)) * self.page_size) as u64
+        u64::from(self.page.saturating_sub(1)) * u64::from(self.page_size)
     }
 
     pub fn limit(&self) -> u32 {
@@ -65,6 +92,21 @@ impl Pagination {
     }
 }
 
+impl From<PaginationParams> for Pagination {
+    fn from(params: PaginationParams) -> Self {
+        Self::new(params.page, params.per_page)
+    }
+}
+
+impl From<Pagination> for PaginationParams {
+    fn from(pagination: Pagination) -> Self {
+        Self {
+            page: pagination.page,
+            per_page: pagination.page_size,
+        }
+    }
+}
+
 impl Default for Pagination {
     fn default() -> Self {
         Self {
@@ -86,13 +128,12 @@ pub struct PaginatedResult<T> {
 
 impl<T> PaginatedResult<T> {
     pub fn new(items: Vec<T>, total: u64, pagination: &Pagination) -> Self {
-        let total_pages = ((total as f64) / (pagination.page_size as f64)).ceil() as u32;
         Self {
             items,
             total,
-            page: pagination.page,
+            page: pagination.page.max(1),
             page_size: pagination.page_size,
-            total_pages,
+            total_pages: page_count(total, pagination.page_size),
         }
     }
 
@@ -100,7 +141,7 @@ impl<T> PaginatedResult<T> {
         Self {
             items: vec![],
             total: 0,
-            page: pagination.page,
+            page: pagination.page.max(1),
             page_size: pagination.page_size,
             total_pages: 0,
         }
@@ -113,6 +154,48 @@ impl<T> PaginatedResult<T> {
     pub fn has_previous_page(&self) -> bool {
         self.page > 1
     }
+
+    /// Convert the items, keeping the pagination metadata
+    pub fn map<U, F>(self, f: F) -> PaginatedResult<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        PaginatedResult {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            page_size: self.page_size,
+            total_pages: self.total_pages,
+        }
+    }
+}
+
+impl<T> From<common::PaginatedResult<T>> for PaginatedResult<T> {
+    fn from(result: common::PaginatedResult<T>) -> Self {
+        Self {
+            items: result.items,
+            total: result.total,
+            page: result.page,
+            page_size: result.per_page,
+            total_pages: result.total_pages,
+        }
+    }
+}
+
+impl<T> From<PaginatedResult<T>> for common::PaginatedResult<T> {
+    fn from(result: PaginatedResult<T>) -> Self {
+        let has_next = result.has_next_page();
+        let has_prev = result.has_previous_page();
+        Self {
+            items: result.items,
+            page: result.page,
+            per_page: result.page_size,
+            total: result.total,
+            total_pages: result.total_pages,
+            has_next,
+            has_prev,
+        }
+    }
 }
 
 /// Service context for request handling
@@ -124,8 +207,12 @@ pub struct ServiceContext {
     pub correlation_id: String,
     /// Organization context (if any)
     pub organization_id: Option<String>,
-    /// Whether the user has admin privileges
-    pub is_admin: bool,
+    /// Permissions held by the caller
+    pub permissions: Permissions,
+    /// Highest platform role granted to the caller
+    pub role: UserRole,
+    /// Scopes of the API key the caller authenticated with, if any
+    pub scopes: Vec<String>,
     /// Agentics execution context for span tracking.
     /// Present when this operation is part of an externally-invoked execution.
     pub execution_ctx: Option<ExecutionContext>,
@@ -137,31 +224,87 @@ impl ServiceContext {
             user_id: None,
             correlation_id,
             organization_id: None,
-            is_admin: false,
+            permissions: Permissions::none(),
+            role: UserRole::Anonymous,
+            scopes: Vec::new(),
             execution_ctx: None,
         }
     }
 
+    /// Context for a signed-in user, holding the permissions of a
+    /// [`UserRole::Registered`] user until [`Self::with_role`] says otherwise
     pub fn authenticated(user_id: String, correlation_id: String) -> Self {
         Self {
             user_id: Some(user_id),
             correlation_id,
             organization_id: None,
-            is_admin: false,
+            permissions: Permissions::for_role(UserRole::Registered),
+            role: UserRole::Registered,
+            scopes: Vec::new(),
             execution_ctx: None,
         }
     }
 
+    /// Build a context for a verified API key and its scopes. Organization
+    /// keys carry no user and act on behalf of their organization.
+    pub fn from_api_key(
+        principal: &ApiKeyPrincipal,
+        scopes: Vec<String>,
+        correlation_id: String,
+    ) -> Self {
+        let ctx = match principal {
+            ApiKeyPrincipal::User(user_id) => Self::authenticated(user_id.clone(), correlation_id),
+            ApiKeyPrincipal::Organization(org_id) => {
+                Self::anonymous(correlation_id).with_organization(org_id.clone())
+            }
+        };
+        Self { scopes, ..ctx }
+    }
+
+    /// Whether this context is an organization key acting for `org_id`
+    pub fn acts_for_organization(&self, org_id: &str) -> bool {
+        self.user_id.is_none() && self.organization_id.as_deref() == Some(org_id)
+    }
+
+    /// Whether this context is an organization key for `org_id` holding the
+    /// [`ORGANIZATION_ADMIN_SCOPE`]
+    pub fn administers_organization(&self, org_id: &str) -> bool {
+        self.acts_for_organization(org_id) && self.has_scope(ORGANIZATION_ADMIN_SCOPE)
+    }
+
+    /// Whether the caller's API key grants `scope`
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
     pub fn with_organization(mut self, org_id: String) -> Self {
         self.organization_id = Some(org_id);
         self
     }
 
-    pub fn with_admin(mut self) -> Self {
-        self.is_admin = true;
+    /// Grant the permissions of a platform role
+    pub fn with_role(mut self, role: UserRole) -> Self {
+        self.permissions.extend(Permissions::for_role(role).iter());
+        self.role = self.role.max(role);
+        self
+    }
+
+    /// Grant the permissions of a role in the organization acted for
+    pub fn with_organization_role(mut self, role: OrganizationRole) -> Self {
+        self.permissions
+            .extend(Permissions::for_organization_role(role).iter());
         self
     }
 
+    pub fn with_admin(self) -> Self {
+        self.with_role(UserRole::Admin)
+    }
+
+    /// Whether the caller holds `permission`
+    pub fn has(&self, permission: Permission) -> bool {
+        self.permissions.has(permission)
+    }
+
     /// Attach an Agentics execution context to this service context.
     pub fn with_execution(mut self, exec_ctx: ExecutionContext) -> Self {
         self.execution_ctx = Some(exec_ctx);
@@ -183,8 +326,19 @@ impl ServiceContext {
             .ok_or_else(|| ApplicationError::Unauthorized("Authentication required".to_string()))
     }
 
+    /// Fail with `Forbidden` unless the caller holds `permission`
+    pub fn require(&self, permission: Permission) -> Result<(), ApplicationError> {
+        if !self.has(permission) {
+            return Err(ApplicationError::Forbidden(format!(
+                "Permission required: {}",
+                permission
+            )));
+        }
+        Ok(())
+    }
+
     pub fn require_admin(&self) -> Result<(), ApplicationError> {
-        if !self.is_admin {
+        if !self.has(Permission::Admin) {
             return Err(ApplicationError::Forbidden(
                 "Admin privileges required".to_string(),
             ));
@@ -193,47 +347,6 @@ impl ServiceContext {
     }
 }
 
-/// Service event for event-driven architecture
-#[derive(Debug, Clone)]
-pub enum ServiceEvent {
-    // Benchmark events
-    BenchmarkCreated { benchmark_id: String },
-    BenchmarkUpdated { benchmark_id: String },
-    BenchmarkStatusChanged { benchmark_id: String, new_status: String },
-    BenchmarkVersionCreated { benchmark_id: String, version_id: String },
-
-    // Submission events
-    SubmissionCreated { submission_id: String },
-    SubmissionVerified { submission_id: String, level: String },
-    SubmissionScoreUpdated { submission_id: String },
-
-    // User events
-    UserCreated { user_id: String },
-    UserUpdated { user_id: String },
-    UserPasswordChanged { user_id: String },
-
-    // Organization events
-    OrganizationCreated { organization_id: String },
-    OrganizationMemberAdded { organization_id: String, user_id: String },
-    OrganizationMemberRemoved { organization_id: String, user_id: String },
-}
-
-/// Event publisher trait for service events
-#[async_trait]
-pub trait EventPublisher: Send + Sync {
-    async fn publish(&self, event: ServiceEvent) -> Result<(), ApplicationError>;
-}
-
-/// No-op event publisher for testing
-pub struct NoOpEventPublisher;
-
-#[async_trait]
-impl EventPublisher for NoOpEventPublisher {
-    async fn publish(&self, _event: ServiceEvent) -> Result<(), ApplicationError> {
-        Ok(())
-    }
-}
-
 /// Authorization result
 #[derive(Debug, Clone)]
 pub struct AuthorizationResult {
@@ -256,6 +369,24 @@ impl AuthorizationResult {
         }
     }
 
+    /// Emit a debug event recording this decision, then return it
+    ///
+    /// The event carries the subject (user ID or `anonymous`), the action,
+    /// the resource it targets (if any), whether it was allowed and the
+    /// reason for a denial.
+    pub fn logged(self, ctx: &ServiceContext, action: &str, resource: Option<&str>) -> Self {
+        tracing::debug!(
+            subject = ctx.user_id.as_deref().unwrap_or("anonymous"),
+            action,
+            resource = resource.unwrap_or(""),
+            allowed = self.allowed,
+            reason = self.reason.as_deref().unwrap_or(""),
+            correlation_id = %ctx.correlation_id,
+            "Authorization decision"
+        );
+        self
+    }
+
     pub fn ensure_allowed(&self) -> Result<(), ApplicationError> {
         if self.allowed {
             Ok(())
@@ -286,60 +417,66 @@ pub struct DefaultAuthorizer;
 #[async_trait]
 impl Authorizer for DefaultAuthorizer {
     async fn can_create_benchmark(&self, ctx: &ServiceContext) -> AuthorizationResult {
-        if ctx.user_id.is_some() {
+        let result = if ctx.user_id.is_some() {
             AuthorizationResult::allow()
         } else {
             AuthorizationResult::deny("Authentication required to create benchmarks")
-        }
+        };
+        result.logged(ctx, "create_benchmark", None)
     }
 
-    async fn can_update_benchmark(&self, ctx: &ServiceContext, _benchmark_id: &str) -> AuthorizationResult {
+    async fn can_update_benchmark(&self, ctx: &ServiceContext, benchmark_id: &str) -> AuthorizationResult {
         // In a real implementation, check if user is a maintainer
-        if ctx.user_id.is_some() {
+        let result = if ctx.user_id.is_some() {
             AuthorizationResult::allow()
         } else {
             AuthorizationResult::deny("Authentication required to update benchmarks")
-        }
+        };
+        result.logged(ctx, "update_benchmark", Some(benchmark_id))
     }
 
-    async fn can_delete_benchmark(&self, ctx: &ServiceContext, _benchmark_id: &str) -> AuthorizationResult {
-        if ctx.is_admin {
+    async fn can_delete_benchmark(&self, ctx: &ServiceContext, benchmark_id: &str) -> AuthorizationResult {
+        let result = if ctx.has(Permission::DeleteBenchmarks) {
             AuthorizationResult::allow()
         } else {
             AuthorizationResult::deny("Admin privileges required to delete benchmarks")
-        }
+        };
+        result.logged(ctx, "delete_benchmark", Some(benchmark_id))
     }
 
-    async fn can_create_submission(&self, ctx: &ServiceContext, _benchmark_id: &str) -> AuthorizationResult {
-        if ctx.user_id.is_some() {
+    async fn can_create_submission(&self, ctx: &ServiceContext, benchmark_id: &str) -> AuthorizationResult {
+        let result = if ctx.user_id.is_some() {
             AuthorizationResult::allow()
         } else {
             AuthorizationResult::deny("Authentication required to create submissions")
-        }
+        };
+        result.logged(ctx, "create_submission", Some(benchmark_id))
     }
 
-    async fn can_verify_submission(&self, ctx: &ServiceContext, _submission_id: &str) -> AuthorizationResult {
-        // Platform verification requires special privileges
-        if ctx.is_admin || ctx.user_id.is_some() {
+    async fn can_verify_submission(&self, ctx: &ServiceContext, submission_id: &str) -> AuthorizationResult {
+        let result = if ctx.has(Permission::VerifySubmissions) {
             AuthorizationResult::allow()
         } else {
-            AuthorizationResult::deny("Authentication required to verify submissions")
-        }
+            AuthorizationResult::deny("Reviewer privileges required to verify submissions")
+        };
+        result.logged(ctx, "verify_submission", Some(submission_id))
     }
 
-    async fn can_manage_organization(&self, ctx: &ServiceContext, _org_id: &str) -> AuthorizationResult {
+    async fn can_manage_organization(&self, ctx: &ServiceContext, org_id: &str) -> AuthorizationResult {
         // In a real implementation, check if user is an admin/owner of the org
-        if ctx.user_id.is_some() {
+        let result = if ctx.user_id.is_some() || ctx.administers_organization(org_id) {
             AuthorizationResult::allow()
         } else {
             AuthorizationResult::deny("Authentication required to manage organizations")
-        }
+        };
+        result.logged(ctx, "manage_organization", Some(org_id))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_pagination() {
@@ -365,6 +502,94 @@ mod tests {
         assert!(!result.has_previous_page());
     }
 
+    #[test]
+    fn test_pagination_page_zero() {
+        let pagination = Pagination::new(0, 10);
+        assert_eq!(pagination.page, 1);
+        assert_eq!(pagination.offset(), 0);
+
+        // Fields are public, so a zero page can still reach the result
+        let raw = Pagination { page: 0, page_size: 10 };
+        assert_eq!(raw.offset(), 0);
+        let result = PaginatedResult::new(vec![1], 15, &raw);
+        assert_eq!(result.page, 1);
+        assert!(!result.has_previous_page());
+        assert!(result.has_next_page());
+    }
+
+    #[test]
+    fn test_paginated_result_last_partial_page() {
+        let pagination = Pagination::new(3, 10);
+        assert_eq!(pagination.offset(), 20);
+
+        let result = PaginatedResult::new(vec![21, 22, 23], 23, &pagination);
+        assert_eq!(result.total_pages, 3);
+        assert!(!result.has_next_page());
+        assert!(result.has_previous_page());
+    }
+
+    #[test]
+    fn test_paginated_result_empty_set() {
+        let pagination = Pagination::new(1, 20);
+        let result = PaginatedResult::<u32>::new(vec![], 0, &pagination);
+        assert_eq!(result.total_pages, 0);
+        assert!(!result.has_next_page());
+        assert!(!result.has_previous_page());
+
+        let empty = PaginatedResult::<u32>::empty(&Pagination { page: 0, page_size: 20 });
+        assert_eq!(empty.page, 1);
+        assert_eq!(empty.total_pages, 0);
+    }
+
+    #[test]
+    fn test_paginated_result_map() {
+        let pagination = Pagination::new(2, 3);
+        let result = PaginatedResult::new(vec![4, 5, 6], 8, &pagination);
+
+        let mapped = result.map(|n| n.to_string());
+
+        assert_eq!(mapped.items, vec!["4", "5", "6"]);
+        assert_eq!(mapped.total, 8);
+        assert_eq!(mapped.page, 2);
+        assert_eq!(mapped.page_size, 3);
+        assert_eq!(mapped.total_pages, 3);
+        assert!(mapped.has_next_page());
+        assert!(mapped.has_previous_page());
+    }
+
+    #[test]
+    fn test_pagination_conversions() {
+        let params = PaginationParams { page: 4, per_page: 25 };
+        let pagination = Pagination::from(params);
+        assert_eq!(pagination.page, 4);
+        assert_eq!(pagination.page_size, 25);
+
+        let params = PaginationParams::from(pagination);
+        assert_eq!(params.page, 4);
+        assert_eq!(params.per_page, 25);
+    }
+
+    #[test]
+    fn test_paginated_result_conversions() {
+        let result = PaginatedResult::new(vec!["a", "b"], 7, &Pagination::new(2, 5));
+
+        let converted = common::PaginatedResult::from(result.clone());
+        assert_eq!(converted.items, result.items);
+        assert_eq!(converted.total, result.total);
+        assert_eq!(converted.page, result.page);
+        assert_eq!(converted.per_page, result.page_size);
+        assert_eq!(converted.total_pages, result.total_pages);
+        assert_eq!(converted.has_next, result.has_next_page());
+        assert_eq!(converted.has_prev, result.has_previous_page());
+
+        let back = PaginatedResult::from(converted);
+        assert_eq!(back.items, result.items);
+        assert_eq!(back.total, result.total);
+        assert_eq!(back.page, result.page);
+        assert_eq!(back.page_size, result.page_size);
+        assert_eq!(back.total_pages, result.total_pages);
+    }
+
     #[test]
     fn test_service_context() {
         let ctx = ServiceContext::anonymous("corr-123".to_string());
@@ -377,6 +602,29 @@ mod tests {
 
         let ctx = ctx.with_admin();
         assert!(ctx.require_admin().is_ok());
+        assert!(ctx.has(Permission::DeleteBenchmarks));
+    }
+
+    #[test]
+    fn test_service_context_from_api_key() {
+        let principal = ApiKeyPrincipal::User("user-123".to_string());
+        let ctx = ServiceContext::from_api_key(&principal, vec![], "corr-123".to_string());
+        assert_eq!(ctx.require_authenticated().unwrap(), "user-123");
+        assert!(!ctx.acts_for_organization("org-1"));
+
+        let principal = ApiKeyPrincipal::Organization("org-1".to_string());
+        let scopes = vec!["write:submissions".to_string()];
+        let ctx = ServiceContext::from_api_key(&principal, scopes, "corr-123".to_string());
+        assert!(ctx.require_authenticated().is_err());
+        assert!(ctx.acts_for_organization("org-1"));
+        assert!(!ctx.acts_for_organization("org-2"));
+        assert!(ctx.has_scope("write:submissions"));
+        assert!(!ctx.administers_organization("org-1"));
+
+        let scopes = vec![ORGANIZATION_ADMIN_SCOPE.to_string()];
+        let ctx = ServiceContext::from_api_key(&principal, scopes, "corr-123".to_string());
+        assert!(ctx.administers_organization("org-1"));
+        assert!(!ctx.administers_organization("org-2"));
     }
 
     #[test]
@@ -387,4 +635,76 @@ mod tests {
         let denied = AuthorizationResult::deny("Not allowed");
         assert!(denied.ensure_allowed().is_err());
     }
+
+    /// Layer recording the fields of every event it sees
+    #[derive(Clone, Default)]
+    struct CapturedEvents(Arc<std::sync::Mutex<Vec<HashMap<String, String>>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CapturedEvents {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            struct Fields<'a>(&'a mut HashMap<String, String>);
+
+            impl tracing::field::Visit for Fields<'_> {
+                fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                    self.0.insert(field.name().to_string(), value.to_string());
+                }
+
+                fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                    self.0.insert(field.name().to_string(), format!("{:?}", value));
+                }
+            }
+
+            let mut fields = HashMap::new();
+            fields.insert("level".to_string(), event.metadata().level().to_string());
+            event.record(&mut Fields(&mut fields));
+            self.0.lock().unwrap().push(fields);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_denied_delete_logs_decision() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let events = CapturedEvents::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(events.clone()));
+
+        let ctx = ServiceContext::authenticated("user-1".to_string(), "corr-1".to_string());
+        let result = DefaultAuthorizer.can_delete_benchmark(&ctx, "bench-1").await;
+        assert!(!result.allowed);
+
+        let events = events.0.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event["level"], "DEBUG");
+        assert_eq!(event["message"], "Authorization decision");
+        assert_eq!(event["subject"], "user-1");
+        assert_eq!(event["action"], "delete_benchmark");
+        assert_eq!(event["resource"], "bench-1");
+        assert_eq!(event["allowed"], "false");
+        assert_eq!(event["reason"], "Admin privileges required to delete benchmarks");
+        assert_eq!(event["correlation_id"], "corr-1");
+    }
+
+    #[tokio::test]
+    async fn test_reviewer_can_verify_but_not_delete() {
+        let reviewer = ServiceContext::authenticated("user-1".to_string(), "corr-1".to_string())
+            .with_role(UserRole::Reviewer);
+        assert!(DefaultAuthorizer.can_verify_submission(&reviewer, "sub-1").await.allowed);
+        assert!(!DefaultAuthorizer.can_delete_benchmark(&reviewer, "bench-1").await.allowed);
+        assert!(reviewer.require_admin().is_err());
+
+        let registered = ServiceContext::authenticated("user-2".to_string(), "corr-2".to_string());
+        assert!(!DefaultAuthorizer.can_verify_submission(&registered, "sub-1").await.allowed);
+        assert!(registered.require(Permission::SubmitResults).is_ok());
+        assert_eq!(
+            registered.require(Permission::VerifySubmissions).unwrap_err().to_string(),
+            ApplicationError::Forbidden("Permission required: verify_submissions".to_string())
+                .to_string()
+        );
+    }
 }
@@ -4,12 +4,13 @@
 //! roles, and team operations.
 
 use super::{
-    Authorizer, EventPublisher, PaginatedResult, Pagination, ServiceConfig, ServiceContext,
-    ServiceEvent,
+    ApiKeyDto, ApiKeyPrincipal, ApiKeyWithSecretDto, Authorizer, CreateApiKeyData,
+    EventPublisher, PaginatedResult, Pagination, Permission, ServiceConfig, ServiceContext,
+    ServiceEvent, ORGANIZATION_ADMIN_SCOPE,
 };
 use crate::validation::{
-    AddMemberRequest, CreateOrganizationRequest, OrganizationRole, UpdateOrganizationRequest,
-    Validatable,
+    AddMemberRequest, CreateApiKeyRequest, CreateOrganizationRequest, OrganizationRole,
+    UpdateOrganizationRequest, Validatable,
 };
 use crate::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
@@ -79,6 +80,41 @@ pub trait OrganizationRepositoryPort: Send + Sync {
         &self,
         user_id: &str,
     ) -> Result<Vec<(OrganizationDto, OrganizationRole)>, ApplicationError>;
+    async fn create_api_key(&self, org_id: &str, key: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError>;
+    async fn list_api_keys(&self, org_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError>;
+    async fn revoke_api_key(&self, org_id: &str, key_id: &str) -> Result<(), ApplicationError>;
+    /// Resolve an organization key secret to the organization ID and key,
+    /// whether or not the key is still active
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError>;
+    /// Record that an organization key was used
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError>;
+}
+
+/// Resolve an organization key secret to its principal and scopes
+///
+/// Expired and revoked keys do not verify. A successful verification records
+/// the key's last use.
+pub(crate) async fn verify_organization_key<R: OrganizationRepositoryPort + ?Sized>(
+    repository: &R,
+    key_secret: &str,
+) -> ApplicationResult<Option<(ApiKeyPrincipal, Vec<String>)>> {
+    let Some((org_id, key)) = repository.verify_api_key(key_secret).await? else {
+        return Ok(None);
+    };
+
+    let now = chrono::Utc::now();
+    if !key.is_active_at(now) {
+        debug!(key_id = %key.id, "Rejected inactive organization API key");
+        return Ok(None);
+    }
+
+    repository.touch_api_key(&key.id, now).await?;
+
+    Ok(Some((ApiKeyPrincipal::Organization(org_id), key.scopes)))
 }
 
 /// Data for creating an organization
@@ -263,17 +299,18 @@ where
     ) -> ApplicationResult<OrganizationDto> {
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("OrganizationAgent"));
 
-        // Validate request
-        let validation = request.validate_all();
-        validation.ensure_valid()?;
-
-        // Check authorization
+        // Check authorization before looking at the request, so callers
+        // without access learn nothing about its validity
         let auth = self.authorizer.can_manage_organization(ctx, id).await;
         auth.ensure_allowed()?;
 
         // Check user has admin or owner role
         self.require_org_admin(ctx, id).await?;
 
+        // Validate request
+        let validation = request.validate_all();
+        validation.ensure_valid()?;
+
         // Check organization exists
         self.repository
             .get_by_id(id)
@@ -317,13 +354,13 @@ where
     ) -> ApplicationResult<OrganizationMemberDto> {
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("OrganizationAgent"));
 
+        // Check authorization
+        self.require_org_admin(ctx, org_id).await?;
+
         // Validate request
         let validation = request.validate_all();
         validation.ensure_valid()?;
 
-        // Check authorization
-        self.require_org_admin(ctx, org_id).await?;
-
         // Check organization exists
         self.repository
             .get_by_id(org_id)
@@ -567,12 +604,118 @@ where
         Ok(())
     }
 
+    /// Create an organization-scoped API key (owners only)
+    #[instrument(skip(self, ctx, request), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn create_api_key(
+        &self,
+        ctx: &ServiceContext,
+        org_id: &str,
+        request: CreateApiKeyRequest,
+    ) -> ApplicationResult<ApiKeyWithSecretDto> {
+        let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("OrganizationAgent"));
+
+        // Only owners can mint keys that act on behalf of the organization
+        self.require_org_owner(ctx, org_id).await?;
+
+        // Validate request
+        let validation = request.validate_all();
+        validation.ensure_valid()?;
+
+        // Check organization exists
+        self.repository
+            .get_by_id(org_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Organization not found: {}", org_id)))?;
+
+        let key_data = CreateApiKeyData {
+            name: request.name,
+            description: request.description,
+            scopes: request.scopes,
+            expires_in_days: request.expires_in_days,
+        };
+
+        let key = self.repository.create_api_key(org_id, &key_data).await?;
+
+        info!(org_id = %org_id, key_id = %key.key.id, "Organization API key created");
+
+        if let Some(guard) = _guard {
+            guard.attach_artifact(Artifact::new("organization_api_key_created", &key.key.id));
+            guard.complete();
+        }
+
+        Ok(key)
+    }
+
+    /// List API keys of an organization
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn list_api_keys(
+        &self,
+        ctx: &ServiceContext,
+        org_id: &str,
+    ) -> ApplicationResult<Vec<ApiKeyDto>> {
+        let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("OrganizationAgent"));
+
+        self.require_org_admin(ctx, org_id).await?;
+        let result = self.repository.list_api_keys(org_id).await;
+
+        if let Some(guard) = _guard { guard.complete(); }
+
+        result
+    }
+
+    /// Revoke an organization API key (owners only)
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn revoke_api_key(
+        &self,
+        ctx: &ServiceContext,
+        org_id: &str,
+        key_id: &str,
+    ) -> ApplicationResult<()> {
+        let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("OrganizationAgent"));
+
+        self.require_org_owner(ctx, org_id).await?;
+        self.repository.revoke_api_key(org_id, key_id).await?;
+
+        info!(org_id = %org_id, key_id = %key_id, "Organization API key revoked");
+
+        if let Some(guard) = _guard {
+            guard.attach_artifact(Artifact::new("organization_api_key_revoked", key_id));
+            guard.complete();
+        }
+
+        Ok(())
+    }
+
+    /// Verify an organization API key and return its principal and scopes
+    ///
+    /// Expired and revoked keys do not verify. A successful verification
+    /// records the key's last use.
+    #[instrument(skip(self, key_secret))]
+    pub async fn verify_api_key(
+        &self,
+        key_secret: &str,
+    ) -> ApplicationResult<Option<(ApiKeyPrincipal, Vec<String>)>> {
+        verify_organization_key(self.repository.as_ref(), key_secret).await
+    }
+
     /// Check if user has admin or owner role in organization
     async fn require_org_admin(&self, ctx: &ServiceContext, org_id: &str) -> ApplicationResult<()> {
+        // Organization keys manage their own organization only when granted
+        // the admin scope
+        if ctx.acts_for_organization(org_id) {
+            if ctx.has_scope(ORGANIZATION_ADMIN_SCOPE) {
+                return Ok(());
+            }
+            return Err(ApplicationError::Forbidden(format!(
+                "API key scope required: {}",
+                ORGANIZATION_ADMIN_SCOPE
+            )));
+        }
+
         let user_id = ctx.require_authenticated()?;
 
         // Platform admins can always manage
-        if ctx.is_admin {
+        if ctx.has(Permission::Admin) {
             return Ok(());
         }
 
@@ -598,7 +741,7 @@ where
         let user_id = ctx.require_authenticated()?;
 
         // Platform admins can always manage
-        if ctx.is_admin {
+        if ctx.has(Permission::Admin) {
             return Ok(());
         }
 
@@ -621,6 +764,166 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::test_support::InMemoryOrganizations;
+    use crate::services::{DefaultAuthorizer, NoOpEventPublisher};
 
-    // Tests would go here with mock implementations
+    type TestService = OrganizationService<InMemoryOrganizations, DefaultAuthorizer, NoOpEventPublisher>;
+
+    async fn service_with_org() -> (TestService, String) {
+        let service = OrganizationService::new(
+            Arc::new(InMemoryOrganizations::default()),
+            Arc::new(DefaultAuthorizer),
+            Arc::new(NoOpEventPublisher),
+            ServiceConfig::default(),
+        );
+        let owner = ServiceContext::authenticated("owner".to_string(), "corr".to_string());
+        let org = service
+            .create(
+                &owner,
+                CreateOrganizationRequest {
+                    name: "Acme Labs".to_string(),
+                    slug: "acme-labs".to_string(),
+                    description: None,
+                    website: None,
+                    contact_email: None,
+                },
+            )
+            .await
+            .unwrap();
+        service
+            .repository
+            .add_member(&org.id, "member", OrganizationRole::Member)
+            .await
+            .unwrap();
+        (service, org.id)
+    }
+
+    fn key_request() -> CreateApiKeyRequest {
+        CreateApiKeyRequest {
+            name: "ci".to_string(),
+            description: Some("Nightly benchmark runs".to_string()),
+            scopes: vec!["write:submissions".to_string()],
+            expires_in_days: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_owner_creates_org_key_resolving_to_org() {
+        let (service, org_id) = service_with_org().await;
+        let owner = ServiceContext::authenticated("owner".to_string(), "corr".to_string());
+
+        let key = service.create_api_key(&owner, &org_id, key_request()).await.unwrap();
+        assert_eq!(key.key.scopes, vec!["write:submissions".to_string()]);
+
+        let (principal, scopes) = service.verify_api_key(&key.secret).await.unwrap().unwrap();
+        assert_eq!(principal, ApiKeyPrincipal::Organization(org_id.clone()));
+        assert_eq!(scopes, vec!["write:submissions".to_string()]);
+        assert!(service.verify_api_key("unknown").await.unwrap().is_none());
+
+        let keys = service.list_api_keys(&owner, &org_id).await.unwrap();
+        assert_eq!(keys.len(), 1);
+        assert!(keys[0].last_used_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_revoked_and_expired_org_keys_do_not_verify() {
+        let (service, org_id) = service_with_org().await;
+        let owner = ServiceContext::authenticated("owner".to_string(), "corr".to_string());
+
+        let revoked = service.create_api_key(&owner, &org_id, key_request()).await.unwrap();
+        service.revoke_api_key(&owner, &org_id, &revoked.key.id).await.unwrap();
+        assert!(service.verify_api_key(&revoked.secret).await.unwrap().is_none());
+
+        let expired = service.create_api_key(&owner, &org_id, key_request()).await.unwrap();
+        service
+            .repository
+            .api_keys
+            .lock()
+            .unwrap()
+            .get_mut(&org_id)
+            .unwrap()
+            .iter_mut()
+            .find(|k| k.id == expired.key.id)
+            .unwrap()
+            .expires_at = Some(chrono::Utc::now() - chrono::Duration::days(1));
+        assert!(service.verify_api_key(&expired.secret).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_non_owner_cannot_create_org_key() {
+        let (service, org_id) = service_with_org().await;
+
+        let member = ServiceContext::authenticated("member".to_string(), "corr".to_string());
+        let result = service.create_api_key(&member, &org_id, key_request()).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+
+        let outsider = ServiceContext::authenticated("outsider".to_string(), "corr".to_string());
+        let result = service.create_api_key(&outsider, &org_id, key_request()).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+    }
+
+    fn description_update() -> UpdateOrganizationRequest {
+        UpdateOrganizationRequest {
+            name: None,
+            description: Some("Managed by CI".to_string()),
+            website: None,
+            contact_email: None,
+            logo_url: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_narrowly_scoped_org_key_cannot_manage_org() {
+        const INTRUDER: &str = "7d9f3c1e-4b2a-4c8d-9e0f-1a2b3c4d5e6f";
+        let (service, org_id) = service_with_org().await;
+        let owner = ServiceContext::authenticated("owner".to_string(), "corr".to_string());
+        let key = service.create_api_key(&owner, &org_id, key_request()).await.unwrap();
+
+        let (principal, scopes) = service.verify_api_key(&key.secret).await.unwrap().unwrap();
+        let ctx = ServiceContext::from_api_key(&principal, scopes, "corr".to_string());
+
+        let result = service.update(&ctx, &org_id, description_update()).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+        let result = service.list_api_keys(&ctx, &org_id).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+        let result = service
+            .add_member(
+                &ctx,
+                &org_id,
+                AddMemberRequest {
+                    user_id: INTRUDER.to_string(),
+                    role: OrganizationRole::Owner,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+        assert!(service
+            .repository
+            .get_member_role(&org_id, INTRUDER)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_org_admin_key_manages_only_its_org() {
+        let (service, org_id) = service_with_org().await;
+        let owner = ServiceContext::authenticated("owner".to_string(), "corr".to_string());
+        let request = CreateApiKeyRequest {
+            scopes: vec![ORGANIZATION_ADMIN_SCOPE.to_string()],
+            ..key_request()
+        };
+        let key = service.create_api_key(&owner, &org_id, request).await.unwrap();
+
+        let (principal, scopes) = service.verify_api_key(&key.secret).await.unwrap().unwrap();
+        let ctx = ServiceContext::from_api_key(&principal, scopes, "corr".to_string());
+
+        let updated = service.update(&ctx, &org_id, description_update()).await.unwrap();
+        assert_eq!(updated.description.as_deref(), Some("Managed by CI"));
+
+        // Org keys cannot mint further keys or act for other organizations
+        let result = service.create_api_key(&ctx, &org_id, key_request()).await;
+        assert!(matches!(result, Err(ApplicationError::Unauthorized(_))));
+        assert!(service.list_api_keys(&ctx, "other-org").await.is_err());
+    }
 }
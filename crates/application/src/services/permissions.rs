@@ -0,0 +1,207 @@
+//! Typed permissions
+//!
+//! A [`ServiceContext`](super::ServiceContext) carries the [`Permissions`]
+//! its caller holds. They are derived from the caller's platform
+//! [`UserRole`] and, when acting for an organization, their
+//! [`OrganizationRole`] in it, so services check the specific capability an
+//! operation needs instead of a single admin flag.
+
+use crate::validation::OrganizationRole;
+use llm_benchmark_domain::user::UserRole;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// A single capability a caller may hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Permission {
+    /// Submit results to benchmarks
+    SubmitResults,
+    /// Propose new benchmarks
+    ProposeBenchmarks,
+    /// Vote on governance proposals
+    Vote,
+    /// Review governance proposals
+    ReviewProposals,
+    /// Verify submissions
+    VerifySubmissions,
+    /// Edit benchmarks created by others
+    EditBenchmarks,
+    /// Delete benchmarks
+    DeleteBenchmarks,
+    /// Manage the governance process
+    ManageGovernance,
+    /// Manage the organization the caller acts for
+    ManageOrganization,
+    /// Manage other users' accounts
+    ManageUsers,
+    /// Platform administration
+    Admin,
+}
+
+impl Permission {
+    /// All permissions
+    pub fn all() -> &'static [Permission] {
+        &[
+            Self::SubmitResults,
+            Self::ProposeBenchmarks,
+            Self::Vote,
+            Self::ReviewProposals,
+            Self::VerifySubmissions,
+            Self::EditBenchmarks,
+            Self::DeleteBenchmarks,
+            Self::ManageGovernance,
+            Self::ManageOrganization,
+            Self::ManageUsers,
+            Self::Admin,
+        ]
+    }
+
+    /// Snake-case name, e.g. `verify_submissions`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SubmitResults => "submit_results",
+            Self::ProposeBenchmarks => "propose_benchmarks",
+            Self::Vote => "vote",
+            Self::ReviewProposals => "review_proposals",
+            Self::VerifySubmissions => "verify_submissions",
+            Self::EditBenchmarks => "edit_benchmarks",
+            Self::DeleteBenchmarks => "delete_benchmarks",
+            Self::ManageGovernance => "manage_governance",
+            Self::ManageOrganization => "manage_organization",
+            Self::ManageUsers => "manage_users",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The set of permissions a caller holds
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Permissions(BTreeSet<Permission>);
+
+impl Permissions {
+    /// No permissions, as held by anonymous callers
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Permissions granted by a platform role
+    ///
+    /// Roles are cumulative, mirroring the `UserRole::can_*` checks: each
+    /// role holds everything the roles below it do. Admins hold every
+    /// permission.
+    pub fn for_role(role: UserRole) -> Self {
+        let mut permissions = Self::none();
+        if role.can_submit_results() {
+            permissions.insert(Permission::SubmitResults);
+        }
+        if role.can_propose_benchmarks() {
+            permissions.insert(Permission::ProposeBenchmarks);
+        }
+        if role.can_vote() {
+            permissions.insert(Permission::Vote);
+        }
+        if role.can_review() {
+            permissions.insert(Permission::ReviewProposals);
+        }
+        if role.can_verify() {
+            permissions.insert(Permission::VerifySubmissions);
+        }
+        if role.can_manage_users() {
+            permissions.extend(Permission::all().iter().copied());
+        }
+        permissions
+    }
+
+    /// Permissions granted by a role in the organization the caller acts for
+    pub fn for_organization_role(role: OrganizationRole) -> Self {
+        match role {
+            OrganizationRole::Owner | OrganizationRole::Admin => {
+                [Permission::ManageOrganization].into_iter().collect()
+            }
+            OrganizationRole::Member | OrganizationRole::Viewer => Self::none(),
+        }
+    }
+
+    /// Whether `permission` is held
+    pub fn has(&self, permission: Permission) -> bool {
+        self.0.contains(&permission)
+    }
+
+    /// Grant a permission
+    pub fn insert(&mut self, permission: Permission) {
+        self.0.insert(permission);
+    }
+
+    /// Iterate over held permissions in declaration order
+    pub fn iter(&self) -> impl Iterator<Item = Permission> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl Extend<Permission> for Permissions {
+    fn extend<I: IntoIterator<Item = Permission>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl FromIterator<Permission> for Permissions {
+    fn from_iter<I: IntoIterator<Item = Permission>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(permissions: &Permissions) -> Vec<&'static str> {
+        permissions.iter().map(|p| p.as_str()).collect()
+    }
+
+    #[test]
+    fn test_permissions_for_each_role() {
+        assert_eq!(names(&Permissions::for_role(UserRole::Anonymous)), Vec::<&str>::new());
+        assert_eq!(names(&Permissions::for_role(UserRole::Registered)), ["submit_results"]);
+        assert_eq!(
+            names(&Permissions::for_role(UserRole::Contributor)),
+            ["submit_results", "propose_benchmarks", "vote"]
+        );
+        assert_eq!(
+            names(&Permissions::for_role(UserRole::Reviewer)),
+            [
+                "submit_results",
+                "propose_benchmarks",
+                "vote",
+                "review_proposals",
+                "verify_submissions"
+            ]
+        );
+
+        let admin = Permissions::for_role(UserRole::Admin);
+        assert!(Permission::all().iter().all(|p| admin.has(*p)));
+    }
+
+    #[test]
+    fn test_reviewer_can_verify_but_not_delete() {
+        let reviewer = Permissions::for_role(UserRole::Reviewer);
+        assert!(reviewer.has(Permission::VerifySubmissions));
+        assert!(!reviewer.has(Permission::DeleteBenchmarks));
+        assert!(!reviewer.has(Permission::Admin));
+    }
+
+    #[test]
+    fn test_permissions_for_organization_role() {
+        assert!(Permissions::for_organization_role(OrganizationRole::Owner)
+            .has(Permission::ManageOrganization));
+        assert!(Permissions::for_organization_role(OrganizationRole::Admin)
+            .has(Permission::ManageOrganization));
+        assert_eq!(Permissions::for_organization_role(OrganizationRole::Member), Permissions::none());
+        assert_eq!(Permissions::for_organization_role(OrganizationRole::Viewer), Permissions::none());
+    }
+}
@@ -281,7 +281,7 @@ impl PublicationAuthorizer for DefaultPublicationAuthorizer {
     }
 
     async fn can_retract(&self, ctx: &ServiceContext, _publication_id: &str) -> super::AuthorizationResult {
-        if ctx.is_admin {
+        if ctx.has(super::Permission::Admin) {
             super::AuthorizationResult::allow()
         } else {
             super::AuthorizationResult::deny("Admin privileges required to retract publications")
@@ -816,10 +816,9 @@ where
         );
 
         let (publications, total) = self.repository.list(&filters, &pagination).await?;
-        let items: Vec<PublicationDto> = publications.into_iter().map(|p| p.into()).collect();
 
         if let Some(guard) = _guard { guard.complete(); }
-        Ok(PaginatedResult::new(items, total, &pagination))
+        Ok(PaginatedResult::new(publications, total, &pagination).map(PublicationDto::from))
     }
 
     // =========================================================================
@@ -4,8 +4,8 @@
 //! scoring, and leaderboard operations.
 
 use super::{
-    Authorizer, EventPublisher, PaginatedResult, Pagination, ServiceConfig, ServiceContext,
-    ServiceEvent,
+    Authorizer, EventPublisher, OrganizationRepositoryPort, PaginatedResult, Pagination,
+    Permission, ServiceConfig, ServiceContext, ServiceEvent,
 };
 use crate::scoring::{ScoringEngine, ScoringEngineConfig, ScoringRequest, TestCaseInput};
 use crate::validation::{
@@ -14,16 +14,19 @@ use crate::validation::{
 };
 use crate::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
+use llm_benchmark_common::crypto::content_hash;
+use llm_benchmark_domain::evaluation::{MetricRange, ScorePrecision};
 use llm_benchmark_domain::submission::{
-    SubmissionResults, SubmissionVisibility, TestCaseResult, VerificationLevel, VerificationStatus,
+    check_cancellable, SubmissionResults, SubmissionVisibility, TestCaseResult, VerificationLevel,
 };
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 use llm_benchmark_common::execution::Artifact;
 use tracing::{debug, info, instrument, warn};
 
 /// Submission data transfer object
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SubmissionDto {
     pub id: String,
     pub benchmark_id: String,
@@ -36,6 +39,8 @@ pub struct SubmissionDto {
     pub aggregate_score: f64,
     pub verification_level: VerificationLevel,
     pub visibility: SubmissionVisibility,
+    /// When the submitter withdrew the submission, if they did
+    pub cancelled_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -54,9 +59,67 @@ pub struct LeaderboardEntryDto {
     pub submitted_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Whose non-public submissions a leaderboard may rank
+///
+/// Public submissions are always ranked. Private and unlisted ones are ranked
+/// only for their submitter and for members of their organization.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeaderboardViewer {
+    pub user_id: Option<String>,
+    /// Organizations whose submissions the viewer may see
+    pub organization_ids: Vec<String>,
+}
+
+impl LeaderboardViewer {
+    /// The viewer making the request described by `ctx`, before any
+    /// organization memberships of its user are resolved
+    ///
+    /// An organization API key context sees its own organization's submissions.
+    pub fn from_context(ctx: &ServiceContext) -> Self {
+        Self {
+            user_id: ctx.user_id.clone(),
+            organization_ids: ctx.organization_id.iter().cloned().collect(),
+        }
+    }
+
+    /// Whether a submission may appear on this viewer's leaderboard
+    pub fn can_see(&self, submission: &SubmissionDto) -> bool {
+        submission.visibility == SubmissionVisibility::Public
+            || self.user_id.as_deref() == Some(submission.submitter_id.as_str())
+            || submission
+                .organization_id
+                .as_ref()
+                .is_some_and(|org| self.organization_ids.contains(org))
+    }
+}
+
+/// Verification history entry data transfer object
+#[derive(Debug, Clone)]
+pub struct VerificationEventDto {
+    pub submission_id: String,
+    pub from_level: VerificationLevel,
+    pub to_level: VerificationLevel,
+    pub verifier: Option<String>,
+    pub reason: Option<String>,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A submission whose aggregate score is a statistical outlier for its benchmark
+#[derive(Debug, Clone, Serialize)]
+pub struct AnomalyFlag {
+    pub submission_id: String,
+    pub aggregate_score: f64,
+    /// Signed z-score against the benchmark's score distribution
+    pub z_score: f64,
+    /// Mean aggregate score across the benchmark's submissions
+    pub mean_score: f64,
+}
+
 /// Submission repository trait
 #[async_trait]
 pub trait SubmissionRepositoryPort: Send + Sync {
+    /// Store a submission, counting it against its benchmark's
+    /// `submission_count` in the same transaction
     async fn create(&self, submission: &CreateSubmissionData) -> Result<String, ApplicationError>;
     async fn get_by_id(&self, id: &str) -> Result<Option<SubmissionDto>, ApplicationError>;
     async fn list(
@@ -65,18 +128,29 @@ pub trait SubmissionRepositoryPort: Send + Sync {
         pagination: &Pagination,
     ) -> Result<(Vec<SubmissionDto>, u64), ApplicationError>;
     async fn update(&self, id: &str, update: &UpdateSubmissionData) -> Result<(), ApplicationError>;
+    /// Update the verification level and append an entry to the verification history
     async fn update_verification(
         &self,
         id: &str,
         verification: &VerificationData,
     ) -> Result<(), ApplicationError>;
+    /// Verification history for a submission, oldest first
+    async fn verification_history(&self, id: &str) -> Result<Vec<VerificationEventDto>, ApplicationError>;
     async fn delete(&self, id: &str) -> Result<(), ApplicationError>;
+    /// Mark an unverified submission cancelled, returning when it was
+    /// cancelled, or `None` if it was verified or cancelled in the meantime
+    async fn cancel(
+        &self,
+        id: &str,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ApplicationError>;
+    /// Ranked submissions for a benchmark, leaving out those `viewer` may not see
     async fn get_leaderboard(
         &self,
         benchmark_id: &str,
         version_id: Option<&str>,
         limit: u32,
         min_verification: Option<VerificationLevel>,
+        viewer: &LeaderboardViewer,
     ) -> Result<Vec<LeaderboardEntryDto>, ApplicationError>;
     async fn get_user_submissions(
         &self,
@@ -85,6 +159,97 @@ pub trait SubmissionRepositoryPort: Send + Sync {
     ) -> Result<(Vec<SubmissionDto>, u64), ApplicationError>;
     async fn get_results(&self, id: &str) -> Result<Option<SubmissionResults>, ApplicationError>;
     async fn save_results(&self, id: &str, results: &SubmissionResults) -> Result<(), ApplicationError>;
+    /// Valid range for a benchmark's aggregate score, if it defines one
+    async fn score_range(&self, benchmark_id: &str) -> Result<Option<MetricRange>, ApplicationError>;
+    /// Rounding policy for a benchmark's displayed scores, if it defines one
+    async fn score_precision(
+        &self,
+        benchmark_id: &str,
+    ) -> Result<Option<ScorePrecision>, ApplicationError>;
+    /// Weakest verification level a benchmark's leaderboard ranks by default
+    async fn leaderboard_min_verification(
+        &self,
+        benchmark_id: &str,
+    ) -> Result<VerificationLevel, ApplicationError>;
+    /// Earliest submission of the same model to the same benchmark whose
+    /// results have `content_hash`
+    async fn find_duplicate(
+        &self,
+        submission: &CreateSubmissionData,
+        content_hash: &str,
+    ) -> Result<Option<String>, ApplicationError>;
+    /// Benchmarks ranked by decayed popularity over `window`, most popular
+    /// first (see [`trending_scores`])
+    async fn trending_benchmarks(
+        &self,
+        window: std::time::Duration,
+        limit: usize,
+    ) -> Result<Vec<(String, f64)>, ApplicationError>;
+}
+
+/// Rank benchmarks by decayed popularity
+///
+/// `submissions` yields the benchmark and creation time of each public
+/// submission. Every submission made within `window` before `now`
+/// contributes a weight that halves every quarter of the window, and each
+/// benchmark's total submission count adds `ln(1 + total)`, so recent
+/// velocity dominates while long-standing popularity breaks ties. Benchmarks
+/// without a submission in the window are left out.
+pub fn trending_scores<'a>(
+    submissions: impl IntoIterator<Item = (&'a str, chrono::DateTime<chrono::Utc>)>,
+    now: chrono::DateTime<chrono::Utc>,
+    window: std::time::Duration,
+    limit: usize,
+) -> Vec<(String, f64)> {
+    let window_secs = window.as_secs_f64();
+    if window_secs <= 0.0 {
+        return Vec::new();
+    }
+    let half_life = window_secs / 4.0;
+
+    // benchmark -> (decayed recent weight, recent count, total count)
+    let mut stats: HashMap<&str, (f64, u64, u64)> = HashMap::new();
+    for (benchmark_id, created_at) in submissions {
+        let entry = stats.entry(benchmark_id).or_default();
+        entry.2 += 1;
+        let age = (now - created_at).num_milliseconds().max(0) as f64 / 1000.0;
+        if age <= window_secs {
+            entry.0 += 0.5f64.powf(age / half_life);
+            entry.1 += 1;
+        }
+    }
+
+    let mut ranked: Vec<(String, f64)> = stats
+        .into_iter()
+        .filter(|(_, (_, recent, _))| *recent > 0)
+        .map(|(id, (velocity, _, total))| {
+            (id.to_string(), velocity + (1.0 + total as f64).ln())
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Timing the execution platform recorded for one test case
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCaseTelemetry {
+    pub test_case_id: String,
+    pub latency_ms: Option<u64>,
+    pub tokens_generated: Option<u32>,
+}
+
+/// Source of execution telemetry for submissions produced on the platform,
+/// such as LLM-Observatory
+#[async_trait]
+pub trait ExecutionTelemetryPort: Send + Sync {
+    /// Per-test-case telemetry for a submission's execution
+    ///
+    /// Returns an empty list when no telemetry was recorded.
+    async fn test_case_telemetry(
+        &self,
+        submission: &SubmissionDto,
+    ) -> Result<Vec<TestCaseTelemetry>, ApplicationError>;
 }
 
 /// Data for creating a submission
@@ -131,6 +296,7 @@ where
     event_publisher: Arc<E>,
     scoring_engine: ScoringEngine,
     config: ServiceConfig,
+    organizations: Option<Arc<dyn OrganizationRepositoryPort>>,
 }
 
 impl<R, A, E> SubmissionService<R, A, E>
@@ -151,9 +317,29 @@ where
             event_publisher,
             scoring_engine: ScoringEngine::new(ScoringEngineConfig::default()),
             config,
+            organizations: None,
         }
     }
 
+    /// Resolve leaderboard viewers' organization memberships through `organizations`
+    pub fn with_organizations(mut self, organizations: Arc<dyn OrganizationRepositoryPort>) -> Self {
+        self.organizations = Some(organizations);
+        self
+    }
+
+    /// The viewer for `ctx`, including every organization its user belongs to
+    async fn leaderboard_viewer(&self, ctx: &ServiceContext) -> ApplicationResult<LeaderboardViewer> {
+        let mut viewer = LeaderboardViewer::from_context(ctx);
+        if let (Some(organizations), Some(user_id)) = (&self.organizations, &ctx.user_id) {
+            for (org, _) in organizations.get_user_organizations(user_id).await? {
+                if !viewer.organization_ids.contains(&org.id) {
+                    viewer.organization_ids.push(org.id);
+                }
+            }
+        }
+        Ok(viewer)
+    }
+
     /// Create a new submission
     #[instrument(skip(self, ctx, request), fields(correlation_id = %ctx.correlation_id))]
     pub async fn create(
@@ -177,23 +363,8 @@ where
         // Get authenticated user
         let user_id = ctx.require_authenticated()?;
 
-        // Create submission
-        let create_data = CreateSubmissionData {
-            benchmark_id: request.benchmark_id,
-            benchmark_version_id: request.benchmark_version_id,
-            model_provider: request.model_provider,
-            model_name: request.model_name,
-            model_version: request.model_version,
-            submitter_id: user_id.to_string(),
-            organization_id: ctx.organization_id.clone(),
-            aggregate_score: request.results.aggregate_score,
-            visibility: request.visibility,
-        };
-
-        let id = self.repository.create(&create_data).await?;
-
-        // Store detailed results
-        let results = SubmissionResults {
+        // Build detailed results and check them against the benchmark
+        let mut results = SubmissionResults {
             aggregate_score: request.results.aggregate_score,
             metric_scores: request
                 .results
@@ -227,7 +398,38 @@ where
             confidence_interval: None,
             statistical_significance: None,
         };
+        let score_range = self.repository.score_range(&request.benchmark_id).await?;
+        results.check_range(score_range.as_ref())?;
+        if let Some(precision) = self.repository.score_precision(&request.benchmark_id).await? {
+            results.apply_precision(&precision);
+        }
+
+        // Create submission
+        let create_data = CreateSubmissionData {
+            benchmark_id: request.benchmark_id,
+            benchmark_version_id: request.benchmark_version_id,
+            model_provider: request.model_provider,
+            model_name: request.model_name,
+            model_version: request.model_version,
+            submitter_id: user_id.to_string(),
+            organization_id: ctx.organization_id.clone(),
+            aggregate_score: results.aggregate_score,
+            visibility: request.visibility,
+        };
+
+        // Catch accidental resubmission of identical results
+        let hash = content_hash(&results).map_err(|e| ApplicationError::Internal(e.to_string()))?;
+        if let Some(existing) = self.repository.find_duplicate(&create_data, &hash).await? {
+            if self.config.reject_duplicate_submissions {
+                return Err(ApplicationError::Conflict(format!(
+                    "Identical results were already submitted for this model as submission {}",
+                    existing
+                )));
+            }
+            warn!(duplicate_of = %existing, "Submission duplicates the results of an earlier one");
+        }
 
+        let id = self.repository.create(&create_data).await?;
         self.repository.save_results(&id, &results).await?;
 
         info!(submission_id = %id, "Submission created");
@@ -267,7 +469,7 @@ where
             if sub.visibility == SubmissionVisibility::Private {
                 // Only owner or admin can see private submissions
                 if let Some(user_id) = &ctx.user_id {
-                    if user_id != &sub.submitter_id && !ctx.is_admin {
+                    if user_id != &sub.submitter_id && !ctx.has(Permission::Admin) {
                         return Ok(None);
                     }
                 } else {
@@ -325,7 +527,7 @@ where
                 if sub.visibility == SubmissionVisibility::Private {
                     ctx.user_id
                         .as_ref()
-                        .map(|uid| uid == &sub.submitter_id || ctx.is_admin)
+                        .map(|uid| uid == &sub.submitter_id || ctx.has(Permission::Admin))
                         .unwrap_or(false)
                 } else {
                     true
@@ -360,7 +562,7 @@ where
 
         // Check ownership
         let user_id = ctx.require_authenticated()?;
-        if existing.submitter_id != user_id && !ctx.is_admin {
+        if existing.submitter_id != user_id && !ctx.has(Permission::Admin) {
             return Err(ApplicationError::Forbidden(
                 "You can only update your own submissions".to_string(),
             ));
@@ -420,6 +622,13 @@ where
                 ApplicationError::NotFound(format!("Submission not found: {}", request.submission_id))
             })?;
 
+        if existing.cancelled_at.is_some() {
+            return Err(ApplicationError::Conflict(format!(
+                "Submission {} has been cancelled",
+                request.submission_id
+            )));
+        }
+
         // Cannot verify own submissions for platform verification
         if request.verification_level == VerificationLevel::PlatformVerified
             || request.verification_level == VerificationLevel::Audited
@@ -431,6 +640,11 @@ where
             }
         }
 
+        // Verification can only stay put or move up
+        existing
+            .verification_level
+            .check_transition(request.verification_level)?;
+
         // Update verification
         let verification_data = VerificationData {
             level: request.verification_level,
@@ -441,9 +655,15 @@ where
             notes: request.notes,
         };
 
-        self.repository
+        // Rejected requests never got this far; a failure here means an
+        // accepted verification could not be recorded
+        if let Err(e) = self
+            .repository
             .update_verification(&request.submission_id, &verification_data)
-            .await?;
+            .await
+        {
+            return Err(self.verification_failed(&request.submission_id, e).await);
+        }
 
         info!(
             submission_id = %request.submission_id,
@@ -471,6 +691,42 @@ where
             .ok_or_else(|| ApplicationError::Internal("Failed to fetch verified submission".to_string()))
     }
 
+    /// Announce a failed verification, returning the error that caused it
+    async fn verification_failed(
+        &self,
+        submission_id: &str,
+        err: ApplicationError,
+    ) -> ApplicationError {
+        let event = ServiceEvent::SubmissionVerificationFailed {
+            submission_id: submission_id.to_string(),
+            reason: err.to_string(),
+        };
+        self.publish_failure(event).await;
+        err
+    }
+
+    /// Publish a failure event without masking the failure itself
+    async fn publish_failure(&self, event: ServiceEvent) {
+        if let Err(e) = self.event_publisher.publish(event).await {
+            warn!(error = %e, "Failed to publish submission failure event");
+        }
+    }
+
+    /// Get the verification history of a submission
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn get_verification_history(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> ApplicationResult<Option<Vec<VerificationEventDto>>> {
+        // Visibility rules are the same as for the submission itself
+        if self.get_by_id(ctx, id).await?.is_none() {
+            return Ok(None);
+        }
+
+        self.repository.verification_history(id).await.map(Some)
+    }
+
     /// Get leaderboard for a benchmark
     #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
     pub async fn get_leaderboard(
@@ -486,16 +742,51 @@ where
 
         let limit = query.limit.unwrap_or(LeaderboardQuery::DEFAULT_LIMIT);
 
-        let result = self.repository
+        // Anyone may ask for a stricter minimum than the benchmark's, but only
+        // verifiers may see below it
+        let default_min = self
+            .repository
+            .leaderboard_min_verification(&query.benchmark_id)
+            .await?;
+        let min_verification = match query.min_verification_level {
+            Some(level) if ctx.has(Permission::VerifySubmissions) => level,
+            requested => requested.map_or(default_min, |level| level.max(default_min)),
+        };
+        let query = LeaderboardQuery {
+            min_verification_level: Some(min_verification),
+            ..query
+        };
+
+        // Private and unlisted submissions only rank for their owners
+        let viewer = self.leaderboard_viewer(ctx).await?;
+        let result = self.leaderboard_entries(&query, limit, &viewer).await;
+        if let Some(guard) = _guard { guard.complete(); }
+        result
+    }
+
+    /// Leaderboard rows with scores rounded to the benchmark's precision
+    async fn leaderboard_entries(
+        &self,
+        query: &LeaderboardQuery,
+        limit: u32,
+        viewer: &LeaderboardViewer,
+    ) -> ApplicationResult<Vec<LeaderboardEntryDto>> {
+        let mut entries = self
+            .repository
             .get_leaderboard(
                 &query.benchmark_id,
                 query.benchmark_version_id.as_deref(),
                 limit,
                 query.min_verification_level,
+                viewer,
             )
-            .await;
-        if let Some(guard) = _guard { guard.complete(); }
-        result
+            .await?;
+        if let Some(precision) = self.repository.score_precision(&query.benchmark_id).await? {
+            for entry in &mut entries {
+                entry.aggregate_score = precision.round(entry.aggregate_score);
+            }
+        }
+        Ok(entries)
     }
 
     /// Get submissions by user
@@ -525,7 +816,7 @@ where
             .into_iter()
             .filter(|sub| {
                 if sub.visibility == SubmissionVisibility::Private {
-                    is_own || ctx.is_admin
+                    is_own || ctx.has(Permission::Admin)
                 } else {
                     true
                 }
@@ -550,7 +841,7 @@ where
 
         // Check ownership or admin
         let user_id = ctx.require_authenticated()?;
-        if existing.submitter_id != user_id && !ctx.is_admin {
+        if existing.submitter_id != user_id && !ctx.has(Permission::Admin) {
             return Err(ApplicationError::Forbidden(
                 "You can only delete your own submissions".to_string(),
             ));
@@ -569,6 +860,231 @@ where
         Ok(())
     }
 
+    /// Cancel a submission
+    ///
+    /// Only the submitter may cancel, and only while the submission is
+    /// unverified. Cancelled submissions drop off leaderboards.
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn cancel(&self, ctx: &ServiceContext, id: &str) -> ApplicationResult<SubmissionDto> {
+        let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("SubmissionAgent"));
+
+        let user_id = ctx.require_authenticated()?;
+        let existing = self
+            .repository
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Submission not found: {}", id)))?;
+
+        if existing.submitter_id != user_id {
+            return Err(ApplicationError::Forbidden(
+                "Only the submitter can cancel a submission".to_string(),
+            ));
+        }
+        check_cancellable(existing.verification_level, existing.cancelled_at)?;
+
+        // The repository re-checks the state, so a verification that lands
+        // after the check above still wins
+        let cancelled_at = self.repository.cancel(id).await?.ok_or_else(|| {
+            ApplicationError::Conflict(format!("Submission {} can no longer be cancelled", id))
+        })?;
+
+        info!(submission_id = %id, "Submission cancelled");
+
+        self.event_publisher
+            .publish(ServiceEvent::SubmissionCancelled {
+                submission_id: id.to_string(),
+            })
+            .await?;
+
+        if let Some(guard) = _guard {
+            guard.attach_artifact(Artifact::new("submission_cancelled", id));
+            guard.complete();
+        }
+
+        Ok(SubmissionDto {
+            cancelled_at: Some(cancelled_at),
+            updated_at: cancelled_at,
+            ..existing
+        })
+    }
+
+    /// Flag submissions whose aggregate score is an outlier for the benchmark
+    ///
+    /// Uses the scoring engine's z-score threshold over every submission to
+    /// the benchmark. Flags are ordered by how far they deviate, largest first.
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn flag_anomalies(
+        &self,
+        ctx: &ServiceContext,
+        benchmark_id: &str,
+    ) -> ApplicationResult<Vec<AnomalyFlag>> {
+        let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("SubmissionAgent"));
+
+        ctx.require_admin()?;
+
+        let filters = SubmissionQueryFilters {
+            benchmark_id: Some(benchmark_id.to_string()),
+            ..Default::default()
+        };
+        filters.validate_all().ensure_valid()?;
+
+        // Pull the full score distribution for the benchmark
+        let mut submissions = Vec::new();
+        let mut page = 1;
+        loop {
+            let pagination = Pagination::new(page, self.config.max_page_size);
+            let (items, total) = self.repository.list(&filters, &pagination).await?;
+            let fetched = items.len();
+            submissions.extend(items);
+            if fetched == 0 || submissions.len() as u64 >= total {
+                break;
+            }
+            page += 1;
+        }
+
+        let scores: Vec<f64> = submissions.iter().map(|s| s.aggregate_score).collect();
+        let mean_score = if scores.is_empty() {
+            0.0
+        } else {
+            scores.iter().sum::<f64>() / scores.len() as f64
+        };
+
+        let mut flags: Vec<AnomalyFlag> = self
+            .scoring_engine
+            .outlier_z_scores(&scores)
+            .into_iter()
+            .map(|(i, z_score)| AnomalyFlag {
+                submission_id: submissions[i].id.clone(),
+                aggregate_score: submissions[i].aggregate_score,
+                z_score,
+                mean_score,
+            })
+            .collect();
+        flags.sort_by(|a, b| b.z_score.abs().total_cmp(&a.z_score.abs()));
+
+        if !flags.is_empty() {
+            warn!(
+                benchmark_id = %benchmark_id,
+                anomalies = flags.len(),
+                submissions = submissions.len(),
+                "Anomalous submission scores detected"
+            );
+        }
+
+        if let Some(guard) = _guard { guard.complete(); }
+        Ok(flags)
+    }
+
+    /// Backfill missing test case latencies and token counts from execution
+    /// telemetry, then recompute the latency and throughput metrics
+    ///
+    /// Only values absent from the stored results are filled in. Test cases
+    /// without telemetry are left as they are, and if nothing is backfilled
+    /// the stored results are returned unchanged.
+    #[instrument(skip(self, ctx, observatory), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn enrich_from_telemetry<O>(
+        &self,
+        ctx: &ServiceContext,
+        submission_id: &str,
+        observatory: &O,
+    ) -> ApplicationResult<SubmissionResults>
+    where
+        O: ExecutionTelemetryPort + ?Sized,
+    {
+        ctx.require_admin()?;
+
+        let existing = self
+            .repository
+            .get_by_id(submission_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!("Submission not found: {}", submission_id))
+            })?;
+        let mut results = self
+            .repository
+            .get_results(submission_id)
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::NotFound(format!(
+                    "Results not found for submission: {}",
+                    submission_id
+                ))
+            })?;
+
+        let telemetry: HashMap<String, TestCaseTelemetry> = observatory
+            .test_case_telemetry(&existing)
+            .await?
+            .into_iter()
+            .map(|t| (t.test_case_id.clone(), t))
+            .collect();
+        if telemetry.is_empty() {
+            debug!(submission_id = %submission_id, "No execution telemetry recorded");
+            return Ok(results);
+        }
+
+        let mut backfilled = 0;
+        for result in &mut results.test_case_results {
+            let Some(recorded) = telemetry.get(&result.test_case_id) else {
+                continue;
+            };
+            let mut changed = false;
+            if result.latency_ms.is_none() && recorded.latency_ms.is_some() {
+                result.latency_ms = recorded.latency_ms;
+                changed = true;
+            }
+            if result.tokens_generated.is_none() && recorded.tokens_generated.is_some() {
+                result.tokens_generated = recorded.tokens_generated;
+                changed = true;
+            }
+            if changed {
+                backfilled += 1;
+            }
+        }
+        if backfilled == 0 {
+            debug!(submission_id = %submission_id, "Telemetry added no missing timings");
+            return Ok(results);
+        }
+
+        for name in ScoringEngine::timing_metric_names() {
+            results.metric_scores.remove(*name);
+        }
+        let timing = self.scoring_engine.result_timing_metrics(&results.test_case_results);
+        results.metric_scores.extend(timing);
+        if let Some(precision) = self.repository.score_precision(&existing.benchmark_id).await? {
+            results.apply_precision(&precision);
+        }
+
+        self.repository.save_results(submission_id, &results).await?;
+
+        info!(
+            submission_id = %submission_id,
+            backfilled = backfilled,
+            "Submission timings backfilled from telemetry"
+        );
+
+        Ok(results)
+    }
+
+    /// Benchmarks ranked by recent submission activity
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn trending_benchmarks(
+        &self,
+        ctx: &ServiceContext,
+        window: std::time::Duration,
+        limit: u32,
+    ) -> ApplicationResult<Vec<(String, f64)>> {
+        if window.is_zero() {
+            return Err(ApplicationError::InvalidInput(
+                "Trending window must be positive".to_string(),
+            ));
+        }
+        let limit = limit.clamp(1, self.config.max_page_size);
+
+        self.repository
+            .trending_benchmarks(window, limit as usize)
+            .await
+    }
+
     /// Re-score a submission using the scoring engine
     #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
     pub async fn rescore(
@@ -590,7 +1106,20 @@ where
             .ok_or_else(|| ApplicationError::NotFound(format!("Submission not found: {}", id)))?;
 
         // Score using engine
-        let results = self.scoring_engine.score(&request).await?;
+        let mut results = self.scoring_engine.score(&request).await?;
+        let score_range = self.repository.score_range(&existing.benchmark_id).await?;
+        if let Err(e) = results.check_range(score_range.as_ref()) {
+            let err = ApplicationError::from(e);
+            let event = ServiceEvent::SubmissionRejected {
+                submission_id: id.to_string(),
+                reason: err.to_string(),
+            };
+            self.publish_failure(event).await;
+            return Err(err);
+        }
+        if let Some(precision) = self.repository.score_precision(&existing.benchmark_id).await? {
+            results.apply_precision(&precision);
+        }
 
         // Save updated results
         self.repository.save_results(id, &results).await?;
@@ -616,6 +1145,775 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::test_support::{
+        organization, InMemoryOrganizations, InMemorySubmissions, RecordingPublisher,
+    };
+    use crate::services::{DefaultAuthorizer, NoOpEventPublisher};
+    use crate::validation::OrganizationRole;
+    use llm_benchmark_domain::user::UserRole;
+
+    fn service(
+        repo: InMemorySubmissions,
+    ) -> SubmissionService<InMemorySubmissions, DefaultAuthorizer, NoOpEventPublisher> {
+        SubmissionService::new(
+            Arc::new(repo),
+            Arc::new(DefaultAuthorizer),
+            Arc::new(NoOpEventPublisher),
+            ServiceConfig::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_successive_verifications_recorded_in_order() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let service = service(InMemorySubmissions::with_submission(&submission_id, "submitter"));
+        let ctx = ServiceContext::authenticated("verifier".to_string(), "corr".to_string())
+            .with_role(UserRole::Reviewer);
+
+        service
+            .verify(
+                &ctx,
+                VerificationRequest {
+                    submission_id: submission_id.clone(),
+                    verification_level: VerificationLevel::CommunityVerified,
+                    reproduced_score: None,
+                    score_variance: None,
+                    environment_match: None,
+                    notes: Some("Reproduced locally".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        service
+            .verify(
+                &ctx,
+                VerificationRequest {
+                    submission_id: submission_id.clone(),
+                    verification_level: VerificationLevel::PlatformVerified,
+                    reproduced_score: Some(0.89),
+                    score_variance: Some(0.01),
+                    environment_match: Some(true),
+                    notes: Some("Platform rerun".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+
+        let history = service
+            .get_verification_history(&ctx, &submission_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].from_level, VerificationLevel::Unverified);
+        assert_eq!(history[0].to_level, VerificationLevel::CommunityVerified);
+        assert_eq!(history[0].reason.as_deref(), Some("Reproduced locally"));
+        assert_eq!(history[1].from_level, VerificationLevel::CommunityVerified);
+        assert_eq!(history[1].to_level, VerificationLevel::PlatformVerified);
+        assert_eq!(history[1].verifier.as_deref(), Some("verifier"));
+        assert!(history[0].at <= history[1].at);
+    }
+
+    fn with_scores(benchmark_id: &str, scores: &[f64]) -> InMemorySubmissions {
+        let repo = InMemorySubmissions::default();
+        for (i, score) in scores.iter().enumerate() {
+            let id = format!("sub-{:03}", i);
+            let mut submission = InMemorySubmissions::with_submission(&id, "submitter")
+                .submissions
+                .into_inner()
+                .unwrap()
+                .remove(&id)
+                .unwrap();
+            submission.benchmark_id = benchmark_id.to_string();
+            submission.aggregate_score = *score;
+            repo.submissions.lock().unwrap().insert(id, submission);
+        }
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_flag_anomalies_detects_planted_outlier() {
+        let benchmark_id = uuid::Uuid::new_v4().to_string();
+        let mut scores: Vec<f64> = (0..20).map(|i| 0.70 + (i % 5) as f64 * 0.02).collect();
+        scores.push(0.05);
+        let repo = with_scores(&benchmark_id, &scores);
+        // A submission to another benchmark doesn't affect the distribution
+        repo.submissions.lock().unwrap().insert(
+            "other".to_string(),
+            InMemorySubmissions::with_submission("other", "submitter")
+                .submissions
+                .into_inner()
+                .unwrap()
+                .remove("other")
+                .unwrap(),
+        );
+        let service = SubmissionService::new(
+            Arc::new(repo),
+            Arc::new(DefaultAuthorizer),
+            Arc::new(NoOpEventPublisher),
+            ServiceConfig {
+                max_page_size: 8,
+                ..ServiceConfig::default()
+            },
+        );
+        let ctx = ServiceContext::authenticated("admin".to_string(), "corr".to_string()).with_admin();
+
+        let flags = service.flag_anomalies(&ctx, &benchmark_id).await.unwrap();
+
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].submission_id, "sub-020");
+        assert_eq!(flags[0].aggregate_score, 0.05);
+        assert!(flags[0].z_score < -3.0);
+    }
+
+    #[tokio::test]
+    async fn test_flag_anomalies_uniform_scores_and_authorization() {
+        let benchmark_id = uuid::Uuid::new_v4().to_string();
+        let service = service(with_scores(&benchmark_id, &[0.8; 12]));
+
+        let admin = ServiceContext::authenticated("admin".to_string(), "corr".to_string()).with_admin();
+        assert!(service.flag_anomalies(&admin, &benchmark_id).await.unwrap().is_empty());
+
+        let user = ServiceContext::authenticated("user".to_string(), "corr".to_string());
+        assert!(matches!(
+            service.flag_anomalies(&user, &benchmark_id).await,
+            Err(ApplicationError::Forbidden(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verification_history_hidden_for_private_submission() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let repo = InMemorySubmissions::with_submission(&submission_id, "submitter");
+        repo.submissions
+            .lock()
+            .unwrap()
+            .get_mut(&submission_id)
+            .unwrap()
+            .visibility = SubmissionVisibility::Private;
+        let service = service(repo);
+
+        let stranger = ServiceContext::authenticated("stranger".to_string(), "corr".to_string());
+        assert!(service
+            .get_verification_history(&stranger, &submission_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let owner = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+        let history = service
+            .get_verification_history(&owner, &submission_id)
+            .await
+            .unwrap();
+        assert_eq!(history.map(|events| events.len()), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_private_submission_ranks_only_for_owner_and_organization() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let repo = InMemorySubmissions::with_submission(&submission_id, "submitter");
+        let benchmark_id = {
+            let mut submissions = repo.submissions.lock().unwrap();
+            let submission = submissions.get_mut(&submission_id).unwrap();
+            submission.visibility = SubmissionVisibility::Private;
+            submission.organization_id = Some("acme-org".to_string());
+            submission.benchmark_id.clone()
+        };
+        let service = service(repo);
+        let query = || LeaderboardQuery {
+            benchmark_id: benchmark_id.clone(),
+            benchmark_version_id: None,
+            limit: None,
+            min_verification_level: None,
+        };
+
+        let anonymous = ServiceContext::anonymous("corr".to_string());
+        let stranger = ServiceContext::authenticated("stranger".to_string(), "corr".to_string());
+        for ctx in [&anonymous, &stranger] {
+            assert!(service.get_leaderboard(ctx, query()).await.unwrap().is_empty());
+        }
+
+        let owner = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+        let colleague = ServiceContext::authenticated("colleague".to_string(), "corr".to_string())
+            .with_organization("acme-org".to_string());
+        let org_key = ServiceContext::anonymous("corr".to_string())
+            .with_organization("acme-org".to_string());
+        for ctx in [&owner, &colleague, &org_key] {
+            let leaderboard = service.get_leaderboard(ctx, query()).await.unwrap();
+            assert_eq!(leaderboard.len(), 1);
+            assert_eq!(leaderboard[0].submission_id, submission_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_private_submission_ranks_for_resolved_organization_members() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let repo = InMemorySubmissions::with_submission(&submission_id, "submitter");
+        let benchmark_id = {
+            let mut submissions = repo.submissions.lock().unwrap();
+            let submission = submissions.get_mut(&submission_id).unwrap();
+            submission.visibility = SubmissionVisibility::Private;
+            submission.organization_id = Some("acme-org".to_string());
+            submission.benchmark_id.clone()
+        };
+        let organizations = InMemoryOrganizations::default()
+            .with_organization(organization("acme-org"))
+            .with_member("acme-org", "colleague", OrganizationRole::Member);
+        let service = service(repo).with_organizations(Arc::new(organizations));
+        let query = LeaderboardQuery {
+            benchmark_id,
+            benchmark_version_id: None,
+            limit: None,
+            min_verification_level: None,
+        };
+
+        // No organization on the context; membership comes from the repository
+        let colleague = ServiceContext::authenticated("colleague".to_string(), "corr".to_string());
+        let leaderboard = service.get_leaderboard(&colleague, query.clone()).await.unwrap();
+        assert_eq!(leaderboard.len(), 1);
+
+        let stranger = ServiceContext::authenticated("stranger".to_string(), "corr".to_string());
+        assert!(service.get_leaderboard(&stranger, query).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_minimum_verification_excludes_unverified() {
+        let verified_id = uuid::Uuid::new_v4().to_string();
+        let mut repo = InMemorySubmissions::with_submission(&verified_id, "submitter");
+        repo.leaderboard_min_verification = VerificationLevel::PlatformVerified;
+        let benchmark_id = {
+            let mut submissions = repo.submissions.lock().unwrap();
+            let verified = submissions.get_mut(&verified_id).unwrap();
+            verified.verification_level = VerificationLevel::PlatformVerified;
+            let mut unverified = verified.clone();
+            unverified.id = uuid::Uuid::new_v4().to_string();
+            unverified.aggregate_score = 0.95;
+            unverified.verification_level = VerificationLevel::Unverified;
+            let benchmark_id = unverified.benchmark_id.clone();
+            submissions.insert(unverified.id.clone(), unverified);
+            benchmark_id
+        };
+        let service = service(repo);
+        let query = |min_verification_level| LeaderboardQuery {
+            benchmark_id: benchmark_id.clone(),
+            benchmark_version_id: None,
+            limit: None,
+            min_verification_level,
+        };
+        let ids = |entries: Vec<LeaderboardEntryDto>| -> Vec<String> {
+            entries.into_iter().map(|entry| entry.submission_id).collect()
+        };
+
+        // Callers without verification rights can't lower the benchmark's minimum
+        let anonymous = ServiceContext::anonymous("corr".to_string());
+        for min in [None, Some(VerificationLevel::Unverified)] {
+            let leaderboard = service.get_leaderboard(&anonymous, query(min)).await.unwrap();
+            assert_eq!(ids(leaderboard), vec![verified_id.clone()]);
+        }
+        let audited = service
+            .get_leaderboard(&anonymous, query(Some(VerificationLevel::Audited)))
+            .await
+            .unwrap();
+        assert!(audited.is_empty());
+
+        let reviewer = ServiceContext::authenticated("reviewer".to_string(), "corr".to_string())
+            .with_role(UserRole::Reviewer);
+        let leaderboard = service
+            .get_leaderboard(&reviewer, query(Some(VerificationLevel::Unverified)))
+            .await
+            .unwrap();
+        assert_eq!(leaderboard.len(), 2);
+        assert_eq!(leaderboard[1].submission_id, verified_id);
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_downgrade() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let repo = InMemorySubmissions::with_submission(&submission_id, "submitter");
+        repo.submissions
+            .lock()
+            .unwrap()
+            .get_mut(&submission_id)
+            .unwrap()
+            .verification_level = VerificationLevel::PlatformVerified;
+        let service = service(repo);
+        let ctx = ServiceContext::authenticated("verifier".to_string(), "corr".to_string())
+            .with_role(UserRole::Reviewer);
+
+        let err = service
+            .verify(
+                &ctx,
+                VerificationRequest {
+                    submission_id: submission_id.clone(),
+                    verification_level: VerificationLevel::CommunityVerified,
+                    reproduced_score: None,
+                    score_variance: None,
+                    environment_match: None,
+                    notes: None,
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+        assert!(err.to_string().contains("Cannot downgrade verification"));
+        assert!(service
+            .get_verification_history(&ctx, &submission_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_only_by_submitter_while_unverified() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let service = service(InMemorySubmissions::with_submission(&submission_id, "submitter"));
+        let owner = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+        let other = ServiceContext::authenticated("someone-else".to_string(), "corr".to_string())
+            .with_role(UserRole::Admin);
+
+        let err = service.cancel(&other, &submission_id).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::Forbidden(_)));
+        let err = service.cancel(&owner, "missing").await.unwrap_err();
+        assert!(matches!(err, ApplicationError::NotFound(_)));
+
+        let cancelled = service.cancel(&owner, &submission_id).await.unwrap();
+        assert!(cancelled.cancelled_at.is_some());
+
+        // Cancelling again, or verifying afterwards, conflicts
+        let err = service.cancel(&owner, &submission_id).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+        let reviewer = ServiceContext::authenticated("verifier".to_string(), "corr".to_string())
+            .with_role(UserRole::Reviewer);
+        let err = service
+            .verify(
+                &reviewer,
+                VerificationRequest {
+                    submission_id: submission_id.clone(),
+                    verification_level: VerificationLevel::CommunityVerified,
+                    reproduced_score: None,
+                    score_variance: None,
+                    environment_match: None,
+                    notes: None,
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+    }
 
-    // Tests would go here with mock implementations
+    #[tokio::test]
+    async fn test_cancel_rejects_verified_submission() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let repo = InMemorySubmissions::with_submission(&submission_id, "submitter");
+        repo.submissions
+            .lock()
+            .unwrap()
+            .get_mut(&submission_id)
+            .unwrap()
+            .verification_level = VerificationLevel::CommunityVerified;
+        let service = service(repo);
+        let owner = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+
+        let err = service.cancel(&owner, &submission_id).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+        assert!(err.to_string().contains("Verified submissions cannot be cancelled"));
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_score_outside_benchmark_range() {
+        let repo = InMemorySubmissions {
+            score_range: Some(MetricRange { min: 0.0, max: 0.5 }),
+            ..Default::default()
+        };
+        let service = service(repo);
+        let ctx = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+
+        let request = CreateSubmissionRequest {
+            benchmark_id: uuid::Uuid::new_v4().to_string(),
+            benchmark_version_id: uuid::Uuid::new_v4().to_string(),
+            model_provider: "acme".to_string(),
+            model_name: "acme-1".to_string(),
+            model_version: None,
+            results: crate::validation::SubmissionResultsInput {
+                aggregate_score: 0.8,
+                metric_scores: HashMap::from([("accuracy".to_string(), 0.8)]),
+                test_case_results: vec![crate::validation::TestCaseResultInput {
+                    test_case_id: "tc-1".to_string(),
+                    passed: true,
+                    score: 0.8,
+                    latency_ms: None,
+                    tokens_generated: None,
+                }],
+            },
+            visibility: SubmissionVisibility::Public,
+        };
+
+        let err = service.create(&ctx, request).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::InvalidInput(_)));
+        assert!(err.to_string().contains("Score out of valid range: 0.8 not in [0, 0.5]"));
+    }
+
+    fn resubmission(benchmark_id: &str, second_case_score: f64) -> CreateSubmissionRequest {
+        CreateSubmissionRequest {
+            benchmark_id: benchmark_id.to_string(),
+            benchmark_version_id: "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d".to_string(),
+            model_provider: "acme".to_string(),
+            model_name: "acme-1".to_string(),
+            model_version: Some("1.0".to_string()),
+            results: crate::validation::SubmissionResultsInput {
+                aggregate_score: 0.8,
+                metric_scores: HashMap::from([
+                    ("accuracy".to_string(), 0.8),
+                    ("f1".to_string(), 0.75),
+                    ("recall".to_string(), 0.7),
+                ]),
+                test_case_results: [("tc-1", 0.9), ("tc-2", second_case_score)]
+                    .into_iter()
+                    .map(|(id, score)| crate::validation::TestCaseResultInput {
+                        test_case_id: id.to_string(),
+                        passed: true,
+                        score,
+                        latency_ms: Some(120),
+                        tokens_generated: None,
+                    })
+                    .collect(),
+            },
+            visibility: SubmissionVisibility::Public,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_identical_resubmission() {
+        let service = SubmissionService::new(
+            Arc::new(InMemorySubmissions::default()),
+            Arc::new(DefaultAuthorizer),
+            Arc::new(NoOpEventPublisher),
+            ServiceConfig {
+                reject_duplicate_submissions: true,
+                ..ServiceConfig::default()
+            },
+        );
+        let ctx = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+        let benchmark_id = uuid::Uuid::new_v4().to_string();
+
+        let original = service.create(&ctx, resubmission(&benchmark_id, 0.7)).await.unwrap();
+
+        let err = service
+            .create(&ctx, resubmission(&benchmark_id, 0.7))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::Conflict(_)));
+        assert!(err.to_string().contains(&original.id));
+
+        // One test case scoring differently is a new result
+        service.create(&ctx, resubmission(&benchmark_id, 0.71)).await.unwrap();
+
+        // So are identical results for another benchmark or model
+        let other_benchmark = uuid::Uuid::new_v4().to_string();
+        service.create(&ctx, resubmission(&other_benchmark, 0.7)).await.unwrap();
+        let mut other_model = resubmission(&benchmark_id, 0.7);
+        other_model.model_version = Some("1.1".to_string());
+        service.create(&ctx, other_model).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_allows_identical_resubmission_by_default() {
+        let repo = Arc::new(InMemorySubmissions::default());
+        let service = SubmissionService::new(
+            repo.clone(),
+            Arc::new(DefaultAuthorizer),
+            Arc::new(NoOpEventPublisher),
+            ServiceConfig::default(),
+        );
+        let ctx = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+        let benchmark_id = uuid::Uuid::new_v4().to_string();
+
+        let first = service.create(&ctx, resubmission(&benchmark_id, 0.7)).await.unwrap();
+        let second = service.create(&ctx, resubmission(&benchmark_id, 0.7)).await.unwrap();
+
+        assert_ne!(first.id, second.id);
+        assert_eq!(repo.submissions.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_score_precision_rounds_aggregates() {
+        let repo = Arc::new(InMemorySubmissions {
+            score_precision: Some(ScorePrecision::DecimalPlaces { places: 3 }),
+            ..InMemorySubmissions::default()
+        });
+        let service = SubmissionService::new(
+            repo.clone(),
+            Arc::new(DefaultAuthorizer),
+            Arc::new(NoOpEventPublisher),
+            ServiceConfig::default(),
+        );
+        let ctx = ServiceContext::authenticated("submitter".to_string(), "corr".to_string());
+        let benchmark_id = uuid::Uuid::new_v4().to_string();
+
+        let mut request = resubmission(&benchmark_id, 0.6666667);
+        request.results.aggregate_score = 0.6666667;
+        request.results.metric_scores = HashMap::from([("accuracy".to_string(), 0.6666667)]);
+        request.results.test_case_results[0].score = 0.6666667;
+        let created = service.create(&ctx, request).await.unwrap();
+
+        assert_eq!(created.aggregate_score, 0.667);
+        assert_eq!(
+            serde_json::to_value(&created).unwrap()["aggregate_score"],
+            serde_json::json!(0.667)
+        );
+        let results = repo.results.lock().unwrap()[&created.id].clone();
+        assert_eq!(results.aggregate_score, 0.667);
+        assert_eq!(results.metric_scores["accuracy"].value, 0.667);
+        // Per-test-case scores keep full precision
+        assert_eq!(results.test_case_results[1].score, 0.6666667);
+
+        // Rows stored before the policy existed are rounded on the way out
+        repo.submissions
+            .lock()
+            .unwrap()
+            .get_mut(&created.id)
+            .unwrap()
+            .aggregate_score = 2.0 / 3.0;
+        let leaderboard = service
+            .get_leaderboard(
+                &ctx,
+                LeaderboardQuery {
+                    benchmark_id: benchmark_id.clone(),
+                    benchmark_version_id: None,
+                    limit: None,
+                    min_verification_level: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(leaderboard.len(), 1);
+        assert_eq!(leaderboard[0].aggregate_score, 0.667);
+    }
+
+    struct MockObservatory(Vec<TestCaseTelemetry>);
+
+    #[async_trait]
+    impl ExecutionTelemetryPort for MockObservatory {
+        async fn test_case_telemetry(
+            &self,
+            _submission: &SubmissionDto,
+        ) -> Result<Vec<TestCaseTelemetry>, ApplicationError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn case(id: &str, latency_ms: Option<u64>, tokens_generated: Option<u32>) -> TestCaseResult {
+        TestCaseResult {
+            test_case_id: id.to_string(),
+            passed: true,
+            score: 1.0,
+            latency_ms,
+            tokens_generated,
+            error: None,
+        }
+    }
+
+    fn telemetry(id: &str, latency_ms: u64, tokens_generated: u32) -> TestCaseTelemetry {
+        TestCaseTelemetry {
+            test_case_id: id.to_string(),
+            latency_ms: Some(latency_ms),
+            tokens_generated: Some(tokens_generated),
+        }
+    }
+
+    fn repo_with_results(id: &str) -> InMemorySubmissions {
+        let repo = InMemorySubmissions::with_submission(id, "submitter");
+        let stale = llm_benchmark_domain::submission::MetricScore {
+            value: 50.0,
+            unit: Some("ms".to_string()),
+            raw_values: None,
+            std_dev: None,
+        };
+        repo.results.lock().unwrap().insert(
+            id.to_string(),
+            SubmissionResults {
+                aggregate_score: 1.0,
+                metric_scores: HashMap::from([(
+                    crate::scoring::LATENCY_P99_METRIC.to_string(),
+                    stale,
+                )]),
+                test_case_results: vec![
+                    case("tc-1", None, None),
+                    case("tc-2", Some(50), None),
+                    case("tc-3", None, None),
+                ],
+                confidence_interval: None,
+                statistical_significance: None,
+            },
+        );
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_enrich_from_telemetry_backfills_missing_timings() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let service = service(repo_with_results(&submission_id));
+        let ctx = ServiceContext::authenticated("admin".to_string(), "corr".to_string())
+            .with_role(UserRole::Admin);
+        // tc-2's reported latency is kept; tc-3 has no telemetry
+        let observatory =
+            MockObservatory(vec![telemetry("tc-1", 200, 100), telemetry("tc-2", 999, 10)]);
+
+        let results = service
+            .enrich_from_telemetry(&ctx, &submission_id, &observatory)
+            .await
+            .unwrap();
+
+        let cases = &results.test_case_results;
+        assert_eq!(cases[0], case("tc-1", Some(200), Some(100)));
+        assert_eq!(cases[1], case("tc-2", Some(50), Some(10)));
+        assert_eq!(cases[2], case("tc-3", None, None));
+
+        // Recomputed from the 50ms and 200ms cases, replacing the stale value
+        let p99 = results.metric_scores[crate::scoring::LATENCY_P99_METRIC].value;
+        assert!(p99 > 50.0 && p99 <= 200.0);
+        let throughput = &results.metric_scores[crate::scoring::THROUGHPUT_METRIC];
+        assert_eq!(throughput.raw_values.as_deref(), Some(&[500.0, 200.0][..]));
+
+        let stored = service.repository.get_results(&submission_id).await.unwrap().unwrap();
+        assert_eq!(stored.test_case_results, results.test_case_results);
+        assert_eq!(stored.metric_scores, results.metric_scores);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_from_telemetry_without_telemetry() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let service = service(repo_with_results(&submission_id));
+        let ctx = ServiceContext::authenticated("admin".to_string(), "corr".to_string())
+            .with_role(UserRole::Admin);
+
+        let results = service
+            .enrich_from_telemetry(&ctx, &submission_id, &MockObservatory(vec![]))
+            .await
+            .unwrap();
+        assert_eq!(results.test_case_results[0], case("tc-1", None, None));
+        assert_eq!(
+            results.metric_scores[crate::scoring::LATENCY_P99_METRIC].value,
+            50.0
+        );
+
+        let user = ServiceContext::authenticated("user".to_string(), "corr".to_string());
+        let err = service
+            .enrich_from_telemetry(&user, &submission_id, &MockObservatory(vec![]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::Forbidden(_)));
+    }
+
+    #[test]
+    fn test_trending_scores_favour_recent_activity() {
+        let now = chrono::Utc::now();
+        let week = std::time::Duration::from_secs(7 * 24 * 60 * 60);
+        let days = |d: i64| now - chrono::Duration::days(d);
+        let hours = |h: i64| now - chrono::Duration::hours(h);
+
+        let mut submissions = Vec::new();
+        // Popular long ago, one submission near the end of the window
+        submissions.extend(std::iter::repeat_n(("established", days(20)), 6));
+        submissions.push(("established", days(6)));
+        // Fewer submissions, all within the last few hours
+        submissions.extend([("rising", hours(1)), ("rising", hours(2)), ("rising", hours(3))]);
+        // Nothing within the window
+        submissions.push(("dormant", days(30)));
+
+        let ranked = trending_scores(submissions.clone(), now, week, 10);
+        let ids: Vec<&str> = ranked.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["rising", "established"]);
+        assert!(ranked[0].1 > ranked[1].1);
+
+        // The same activity a week earlier no longer trends
+        let later = now + chrono::Duration::days(8);
+        let ranked = trending_scores(submissions.clone(), later, week, 10);
+        assert!(ranked.is_empty());
+
+        assert_eq!(trending_scores(submissions, now, week, 1).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trending_benchmarks_rejects_empty_window() {
+        let service = service(InMemorySubmissions::default());
+        let ctx = ServiceContext::anonymous("corr".to_string());
+
+        let err = service
+            .trending_benchmarks(&ctx, std::time::Duration::ZERO, 10)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::InvalidInput(_)));
+    }
+
+    fn recorded_service(
+        repo: InMemorySubmissions,
+    ) -> (
+        SubmissionService<InMemorySubmissions, DefaultAuthorizer, RecordingPublisher>,
+        Arc<RecordingPublisher>,
+    ) {
+        let publisher = Arc::new(RecordingPublisher::default());
+        let service = SubmissionService::new(
+            Arc::new(repo),
+            Arc::new(DefaultAuthorizer),
+            publisher.clone(),
+            ServiceConfig::default(),
+        );
+        (service, publisher)
+    }
+
+    fn platform_verification(submission_id: &str) -> VerificationRequest {
+        VerificationRequest {
+            submission_id: submission_id.to_string(),
+            verification_level: VerificationLevel::PlatformVerified,
+            reproduced_score: Some(0.89),
+            score_variance: Some(0.01),
+            environment_match: Some(true),
+            notes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejected_verification_publishes_nothing() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let (service, publisher) =
+            recorded_service(InMemorySubmissions::with_submission(&submission_id, "submitter"));
+        let ctx = ServiceContext::authenticated("submitter".to_string(), "corr".to_string())
+            .with_role(UserRole::Reviewer);
+
+        let err = service
+            .verify(&ctx, platform_verification(&submission_id))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::Forbidden(_)));
+        assert!(publisher.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_verification_publishes_reason() {
+        let submission_id = uuid::Uuid::new_v4().to_string();
+        let mut repo = InMemorySubmissions::with_submission(&submission_id, "submitter");
+        repo.reject_verification_updates = true;
+        let (service, publisher) = recorded_service(repo);
+        let ctx = ServiceContext::authenticated("verifier".to_string(), "corr".to_string())
+            .with_role(UserRole::Reviewer);
+
+        let err = service
+            .verify(&ctx, platform_verification(&submission_id))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::Internal(_)));
+
+        let events = publisher.events.lock().unwrap();
+        match events.as_slice() {
+            [ServiceEvent::SubmissionVerificationFailed { submission_id: id, reason }] => {
+                assert_eq!(id, &submission_id);
+                assert_eq!(reason, &err.to_string());
+            }
+            other => panic!("unexpected events {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,775 @@
+//! In-memory repository doubles shared by the service tests
+
+use super::{
+    trending_scores, ApiKeyDto, ApiKeyWithSecretDto, BenchmarkDto, BenchmarkFilters,
+    BenchmarkRepositoryPort, BenchmarkVersionDto, CreateApiKeyData, CreateBenchmarkData,
+    CreateOrganizationData, CreateSubmissionData, CreateVersionData, EventPublisher,
+    LeaderboardEntryDto, LeaderboardViewer, OrganizationDto, OrganizationMemberDto,
+    OrganizationRepositoryPort, Pagination, ServiceEvent, SubmissionDto, SubmissionRepositoryPort,
+    UpdateBenchmarkData, UpdateOrganizationData, UpdateSubmissionData, VerificationData,
+    VerificationEventDto,
+};
+use crate::validation::{OrganizationRole, SubmissionQueryFilters};
+use crate::ApplicationError;
+use async_trait::async_trait;
+use llm_benchmark_common::crypto::content_hash;
+use llm_benchmark_domain::benchmark::{BenchmarkCategory, BenchmarkStatus};
+use llm_benchmark_domain::evaluation::{MetricRange, ScorePrecision};
+use llm_benchmark_domain::submission::{SubmissionResults, SubmissionVisibility, VerificationLevel};
+use llm_benchmark_domain::test_case::TestCase;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// An active benchmark at version 1.0.0
+pub(crate) fn benchmark(id: &str, created_by: Option<&str>) -> BenchmarkDto {
+    let now = chrono::Utc::now();
+    BenchmarkDto {
+        id: id.to_string(),
+        name: "Bench".to_string(),
+        slug: id.to_string(),
+        description: "A benchmark".to_string(),
+        category: BenchmarkCategory::Accuracy,
+        status: BenchmarkStatus::Active,
+        tags: vec![],
+        current_version: Some("1.0.0".to_string()),
+        submission_count: 0,
+        created_by: created_by.map(str::to_string),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// Keeps every published event, in order
+#[derive(Default)]
+pub(crate) struct RecordingPublisher {
+    pub(crate) events: Mutex<Vec<ServiceEvent>>,
+}
+
+#[async_trait]
+impl EventPublisher for RecordingPublisher {
+    async fn publish(&self, event: ServiceEvent) -> Result<(), ApplicationError> {
+        self.events.lock().unwrap().push(event);
+        Ok(())
+    }
+}
+
+/// An unverified organization with no members counted
+pub(crate) fn organization(id: &str) -> OrganizationDto {
+    let now = chrono::Utc::now();
+    OrganizationDto {
+        id: id.to_string(),
+        name: "Org".to_string(),
+        slug: id.to_string(),
+        description: None,
+        website: None,
+        contact_email: None,
+        logo_url: None,
+        member_count: 0,
+        is_verified: false,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// Benchmarks with their versions and current test cases
+#[derive(Default)]
+pub(crate) struct InMemoryBenchmarks {
+    pub(crate) benchmarks: Mutex<HashMap<String, BenchmarkDto>>,
+    pub(crate) versions: Mutex<HashMap<String, Vec<BenchmarkVersionDto>>>,
+    pub(crate) test_cases: Mutex<HashMap<String, Vec<TestCase>>>,
+}
+
+impl InMemoryBenchmarks {
+    /// Add an existing benchmark
+    pub(crate) fn with_benchmark(self, benchmark: BenchmarkDto) -> Self {
+        self.benchmarks
+            .lock()
+            .unwrap()
+            .insert(benchmark.id.clone(), benchmark);
+        self
+    }
+
+    /// Set the current test cases of a benchmark
+    pub(crate) fn with_test_cases(self, benchmark_id: &str, test_cases: Vec<TestCase>) -> Self {
+        self.test_cases
+            .lock()
+            .unwrap()
+            .insert(benchmark_id.to_string(), test_cases);
+        self
+    }
+}
+
+#[async_trait]
+impl BenchmarkRepositoryPort for InMemoryBenchmarks {
+    async fn create(&self, data: &CreateBenchmarkData) -> Result<String, ApplicationError> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut created = benchmark(&id, Some(&data.creator_id));
+        created.name = data.name.clone();
+        created.slug = data.slug.clone();
+        created.description = data.description.clone();
+        created.category = data.category.clone();
+        created.status = BenchmarkStatus::Draft;
+        created.tags = data.tags.clone();
+        created.current_version = Some(data.version.clone());
+        self.benchmarks.lock().unwrap().insert(id.clone(), created);
+        self.test_cases
+            .lock()
+            .unwrap()
+            .insert(id.clone(), data.test_cases.clone());
+        Ok(id)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<BenchmarkDto>, ApplicationError> {
+        Ok(self.benchmarks.lock().unwrap().get(id).cloned())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<BenchmarkDto>, ApplicationError> {
+        Ok(self
+            .benchmarks
+            .lock()
+            .unwrap()
+            .values()
+            .find(|b| b.slug == slug)
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        filters: &BenchmarkFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<BenchmarkDto>, u64), ApplicationError> {
+        let mut items: Vec<_> = self
+            .benchmarks
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|b| filters.status.is_none_or(|status| b.status == status))
+            .filter(|b| filters.category.as_ref().is_none_or(|category| b.category == *category))
+            .cloned()
+            .collect();
+        items.sort_by(|a, b| a.id.cmp(&b.id));
+        let total = items.len() as u64;
+        let items = items
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect();
+        Ok((items, total))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateBenchmarkData) -> Result<(), ApplicationError> {
+        let mut benchmarks = self.benchmarks.lock().unwrap();
+        let benchmark = benchmarks
+            .get_mut(id)
+            .ok_or_else(|| ApplicationError::NotFound(id.to_string()))?;
+        if let Some(ref name) = update.name {
+            benchmark.name = name.clone();
+        }
+        if let Some(ref description) = update.description {
+            benchmark.description = description.clone();
+        }
+        if let Some(ref tags) = update.tags {
+            benchmark.tags = tags.clone();
+        }
+        if let Some(ref test_cases) = update.test_cases {
+            self.test_cases
+                .lock()
+                .unwrap()
+                .insert(id.to_string(), test_cases.clone());
+        }
+        benchmark.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    async fn update_status(&self, id: &str, status: BenchmarkStatus) -> Result<(), ApplicationError> {
+        let mut benchmarks = self.benchmarks.lock().unwrap();
+        let benchmark = benchmarks
+            .get_mut(id)
+            .ok_or_else(|| ApplicationError::NotFound(id.to_string()))?;
+        benchmark.status = status;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        self.benchmarks
+            .lock()
+            .unwrap()
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| ApplicationError::NotFound(id.to_string()))
+    }
+
+    async fn slug_exists(&self, slug: &str) -> Result<bool, ApplicationError> {
+        Ok(self.get_by_slug(slug).await?.is_some())
+    }
+
+    async fn create_version(&self, data: &CreateVersionData) -> Result<String, ApplicationError> {
+        let mut versions = self.versions.lock().unwrap();
+        let benchmark_versions = versions.entry(data.benchmark_id.clone()).or_default();
+        let id = format!("version-{}", benchmark_versions.len() + 1);
+        benchmark_versions.push(BenchmarkVersionDto {
+            id: id.clone(),
+            benchmark_id: data.benchmark_id.clone(),
+            version: data.version.clone(),
+            changelog: data.changelog.clone(),
+            breaking_changes: data.breaking_changes,
+            created_at: chrono::Utc::now(),
+        });
+        if let Some(test_cases) = &data.test_cases {
+            self.test_cases
+                .lock()
+                .unwrap()
+                .insert(data.benchmark_id.clone(), test_cases.clone());
+        }
+        Ok(id)
+    }
+
+    async fn get_versions(&self, benchmark_id: &str) -> Result<Vec<BenchmarkVersionDto>, ApplicationError> {
+        Ok(self
+            .versions
+            .lock()
+            .unwrap()
+            .get(benchmark_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn get_test_cases(&self, benchmark_id: &str) -> Result<Vec<TestCase>, ApplicationError> {
+        Ok(self
+            .test_cases
+            .lock()
+            .unwrap()
+            .get(benchmark_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+/// Organizations, memberships and organization API keys
+#[derive(Default)]
+pub(crate) struct InMemoryOrganizations {
+    pub(crate) organizations: Mutex<HashMap<String, OrganizationDto>>,
+    pub(crate) members: Mutex<HashMap<(String, String), OrganizationRole>>,
+    pub(crate) api_keys: Mutex<HashMap<String, Vec<ApiKeyDto>>>,
+    /// Secret -> (organization ID, key ID)
+    pub(crate) api_key_secrets: Mutex<HashMap<String, (String, String)>>,
+}
+
+impl InMemoryOrganizations {
+    /// Add an existing organization
+    pub(crate) fn with_organization(self, organization: OrganizationDto) -> Self {
+        self.organizations
+            .lock()
+            .unwrap()
+            .insert(organization.id.clone(), organization);
+        self
+    }
+
+    /// Add a member with `role` to `org_id`
+    pub(crate) fn with_member(self, org_id: &str, user_id: &str, role: OrganizationRole) -> Self {
+        self.members
+            .lock()
+            .unwrap()
+            .insert((org_id.to_string(), user_id.to_string()), role);
+        self
+    }
+}
+
+#[async_trait]
+impl OrganizationRepositoryPort for InMemoryOrganizations {
+    async fn create(&self, org: &CreateOrganizationData) -> Result<String, ApplicationError> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = chrono::Utc::now();
+        self.organizations.lock().unwrap().insert(
+            id.clone(),
+            OrganizationDto {
+                id: id.clone(),
+                name: org.name.clone(),
+                slug: org.slug.clone(),
+                description: org.description.clone(),
+                website: org.website.clone(),
+                contact_email: org.contact_email.clone(),
+                logo_url: None,
+                member_count: 0,
+                is_verified: false,
+                created_at: now,
+                updated_at: now,
+            },
+        );
+        Ok(id)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<OrganizationDto>, ApplicationError> {
+        Ok(self.organizations.lock().unwrap().get(id).cloned())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<OrganizationDto>, ApplicationError> {
+        Ok(self
+            .organizations
+            .lock()
+            .unwrap()
+            .values()
+            .find(|org| org.slug == slug)
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        _pagination: &Pagination,
+    ) -> Result<(Vec<OrganizationDto>, u64), ApplicationError> {
+        let items: Vec<_> = self.organizations.lock().unwrap().values().cloned().collect();
+        let total = items.len() as u64;
+        Ok((items, total))
+    }
+
+    async fn update(&self, id: &str, update: &UpdateOrganizationData) -> Result<(), ApplicationError> {
+        let mut organizations = self.organizations.lock().unwrap();
+        let org = organizations
+            .get_mut(id)
+            .ok_or_else(|| ApplicationError::NotFound(id.to_string()))?;
+        if let Some(ref description) = update.description {
+            org.description = Some(description.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        self.organizations.lock().unwrap().remove(id);
+        Ok(())
+    }
+
+    async fn slug_exists(&self, slug: &str) -> Result<bool, ApplicationError> {
+        Ok(self.get_by_slug(slug).await?.is_some())
+    }
+
+    async fn add_member(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrganizationRole,
+    ) -> Result<(), ApplicationError> {
+        self.members
+            .lock()
+            .unwrap()
+            .insert((org_id.to_string(), user_id.to_string()), role);
+        Ok(())
+    }
+
+    async fn update_member_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrganizationRole,
+    ) -> Result<(), ApplicationError> {
+        self.add_member(org_id, user_id, role).await
+    }
+
+    async fn remove_member(&self, org_id: &str, user_id: &str) -> Result<(), ApplicationError> {
+        self.members
+            .lock()
+            .unwrap()
+            .remove(&(org_id.to_string(), user_id.to_string()));
+        Ok(())
+    }
+
+    async fn get_members(&self, org_id: &str) -> Result<Vec<OrganizationMemberDto>, ApplicationError> {
+        let now = chrono::Utc::now();
+        Ok(self
+            .members
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|((id, _), _)| id == org_id)
+            .map(|((_, user_id), role)| OrganizationMemberDto {
+                user_id: user_id.clone(),
+                username: user_id.clone(),
+                display_name: user_id.clone(),
+                role: *role,
+                joined_at: now,
+            })
+            .collect())
+    }
+
+    async fn get_member_role(
+        &self,
+        org_id: &str,
+        user_id: &str,
+    ) -> Result<Option<OrganizationRole>, ApplicationError> {
+        Ok(self
+            .members
+            .lock()
+            .unwrap()
+            .get(&(org_id.to_string(), user_id.to_string()))
+            .copied())
+    }
+
+    async fn get_user_organizations(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<(OrganizationDto, OrganizationRole)>, ApplicationError> {
+        let organizations = self.organizations.lock().unwrap();
+        Ok(self
+            .members
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|((_, member), _)| member == user_id)
+            .filter_map(|((org_id, _), role)| organizations.get(org_id).map(|org| (org.clone(), *role)))
+            .collect())
+    }
+
+    async fn create_api_key(&self, org_id: &str, key: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError> {
+        let secret = format!("llm_bm_org_{}", uuid::Uuid::new_v4());
+        let now = chrono::Utc::now();
+        let key = ApiKeyDto {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: key.name.clone(),
+            description: key.description.clone(),
+            scopes: key.scopes.clone(),
+            last_used_at: None,
+            expires_at: key.expires_in_days.map(|days| now + chrono::Duration::days(i64::from(days))),
+            revoked_at: None,
+            created_at: now,
+        };
+        self.api_keys
+            .lock()
+            .unwrap()
+            .entry(org_id.to_string())
+            .or_default()
+            .push(key.clone());
+        self.api_key_secrets
+            .lock()
+            .unwrap()
+            .insert(secret.clone(), (org_id.to_string(), key.id.clone()));
+        Ok(ApiKeyWithSecretDto { key, secret })
+    }
+
+    async fn list_api_keys(&self, org_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError> {
+        Ok(self.api_keys.lock().unwrap().get(org_id).cloned().unwrap_or_default())
+    }
+
+    async fn revoke_api_key(&self, org_id: &str, key_id: &str) -> Result<(), ApplicationError> {
+        let mut keys = self.api_keys.lock().unwrap();
+        let key = keys
+            .get_mut(org_id)
+            .and_then(|keys| keys.iter_mut().find(|k| k.id == key_id))
+            .ok_or_else(|| ApplicationError::NotFound(format!("API key not found: {}", key_id)))?;
+        key.revoked_at.get_or_insert_with(chrono::Utc::now);
+        Ok(())
+    }
+
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError> {
+        let Some((org_id, key_id)) = self.api_key_secrets.lock().unwrap().get(key_secret).cloned() else {
+            return Ok(None);
+        };
+        let key = self
+            .api_keys
+            .lock()
+            .unwrap()
+            .get(&org_id)
+            .and_then(|keys| keys.iter().find(|k| k.id == key_id).cloned());
+        Ok(key.map(|key| (org_id, key)))
+    }
+
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError> {
+        for key in self.api_keys.lock().unwrap().values_mut().flatten() {
+            if key.id == key_id {
+                key.last_used_at = Some(used_at);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submissions keyed by ID, with their results and verification history
+#[derive(Default)]
+pub(crate) struct InMemorySubmissions {
+    pub(crate) submissions: Mutex<HashMap<String, SubmissionDto>>,
+    pub(crate) history: Mutex<Vec<VerificationEventDto>>,
+    pub(crate) results: Mutex<HashMap<String, SubmissionResults>>,
+    pub(crate) score_range: Option<MetricRange>,
+    pub(crate) score_precision: Option<ScorePrecision>,
+    pub(crate) leaderboard_min_verification: VerificationLevel,
+    /// Fail every verification update, as an unreachable store would
+    pub(crate) reject_verification_updates: bool,
+}
+
+impl InMemorySubmissions {
+    /// A store holding one public, unverified submission
+    pub(crate) fn with_submission(id: &str, submitter_id: &str) -> Self {
+        let repo = Self::default();
+        let now = chrono::Utc::now();
+        repo.submissions.lock().unwrap().insert(
+            id.to_string(),
+            SubmissionDto {
+                id: id.to_string(),
+                benchmark_id: uuid::Uuid::new_v4().to_string(),
+                benchmark_version_id: uuid::Uuid::new_v4().to_string(),
+                model_provider: "acme".to_string(),
+                model_name: "acme-1".to_string(),
+                model_version: None,
+                submitter_id: submitter_id.to_string(),
+                organization_id: None,
+                aggregate_score: 0.9,
+                verification_level: VerificationLevel::Unverified,
+                visibility: SubmissionVisibility::Public,
+                cancelled_at: None,
+                created_at: now,
+                updated_at: now,
+            },
+        );
+        repo
+    }
+}
+
+#[async_trait]
+impl SubmissionRepositoryPort for InMemorySubmissions {
+    async fn create(&self, submission: &CreateSubmissionData) -> Result<String, ApplicationError> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = chrono::Utc::now();
+        self.submissions.lock().unwrap().insert(
+            id.clone(),
+            SubmissionDto {
+                id: id.clone(),
+                benchmark_id: submission.benchmark_id.clone(),
+                benchmark_version_id: submission.benchmark_version_id.clone(),
+                model_provider: submission.model_provider.clone(),
+                model_name: submission.model_name.clone(),
+                model_version: submission.model_version.clone(),
+                submitter_id: submission.submitter_id.clone(),
+                organization_id: submission.organization_id.clone(),
+                aggregate_score: submission.aggregate_score,
+                verification_level: VerificationLevel::Unverified,
+                visibility: submission.visibility,
+                cancelled_at: None,
+                created_at: now,
+                updated_at: now,
+            },
+        );
+        Ok(id)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<SubmissionDto>, ApplicationError> {
+        Ok(self.submissions.lock().unwrap().get(id).cloned())
+    }
+
+    async fn list(
+        &self,
+        filters: &SubmissionQueryFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<SubmissionDto>, u64), ApplicationError> {
+        let mut matching: Vec<SubmissionDto> = self
+            .submissions
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| {
+                filters
+                    .benchmark_id
+                    .as_ref()
+                    .is_none_or(|id| &s.benchmark_id == id)
+            })
+            .cloned()
+            .collect();
+        matching.sort_by(|a, b| a.id.cmp(&b.id));
+        let total = matching.len() as u64;
+        let items = matching
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect();
+        Ok((items, total))
+    }
+
+    async fn update(&self, _id: &str, _update: &UpdateSubmissionData) -> Result<(), ApplicationError> {
+        Ok(())
+    }
+
+    async fn update_verification(
+        &self,
+        id: &str,
+        verification: &VerificationData,
+    ) -> Result<(), ApplicationError> {
+        if self.reject_verification_updates {
+            return Err(ApplicationError::Internal("store unavailable".to_string()));
+        }
+        let mut submissions = self.submissions.lock().unwrap();
+        let submission = submissions
+            .get_mut(id)
+            .ok_or_else(|| ApplicationError::NotFound(id.to_string()))?;
+        self.history.lock().unwrap().push(VerificationEventDto {
+            submission_id: id.to_string(),
+            from_level: submission.verification_level,
+            to_level: verification.level,
+            verifier: Some(verification.verified_by.clone()),
+            reason: verification.notes.clone(),
+            at: chrono::Utc::now(),
+        });
+        submission.verification_level = verification.level;
+        Ok(())
+    }
+
+    async fn verification_history(&self, id: &str) -> Result<Vec<VerificationEventDto>, ApplicationError> {
+        Ok(self
+            .history
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event.submission_id == id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, _id: &str) -> Result<(), ApplicationError> {
+        Ok(())
+    }
+
+    async fn cancel(
+        &self,
+        id: &str,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, ApplicationError> {
+        let mut submissions = self.submissions.lock().unwrap();
+        Ok(submissions
+            .get_mut(id)
+            .filter(|s| {
+                s.cancelled_at.is_none() && s.verification_level == VerificationLevel::Unverified
+            })
+            .map(|s| *s.cancelled_at.insert(chrono::Utc::now())))
+    }
+
+    async fn get_leaderboard(
+        &self,
+        benchmark_id: &str,
+        _version_id: Option<&str>,
+        limit: u32,
+        min_verification: Option<VerificationLevel>,
+        viewer: &LeaderboardViewer,
+    ) -> Result<Vec<LeaderboardEntryDto>, ApplicationError> {
+        let min_level = min_verification.unwrap_or_default();
+        let mut ranked: Vec<SubmissionDto> = self
+            .submissions
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.benchmark_id == benchmark_id && viewer.can_see(s))
+            .filter(|s| s.cancelled_at.is_none() && s.verification_level.meets(min_level))
+            .cloned()
+            .collect();
+        ranked.sort_by(|a, b| b.aggregate_score.total_cmp(&a.aggregate_score));
+        Ok(ranked
+            .into_iter()
+            .take(limit as usize)
+            .enumerate()
+            .map(|(i, s)| LeaderboardEntryDto {
+                rank: i as u32 + 1,
+                submission_id: s.id,
+                model_provider: s.model_provider,
+                model_name: s.model_name,
+                model_version: s.model_version,
+                aggregate_score: s.aggregate_score,
+                verification_level: s.verification_level,
+                submitter_name: s.submitter_id,
+                submitted_at: s.created_at,
+            })
+            .collect())
+    }
+
+    async fn get_user_submissions(
+        &self,
+        user_id: &str,
+        pagination: &Pagination,
+    ) -> Result<(Vec<SubmissionDto>, u64), ApplicationError> {
+        let mut matching: Vec<SubmissionDto> = self
+            .submissions
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.submitter_id == user_id)
+            .cloned()
+            .collect();
+        matching.sort_by(|a, b| a.id.cmp(&b.id));
+        let total = matching.len() as u64;
+        let items = matching
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect();
+        Ok((items, total))
+    }
+
+    async fn get_results(&self, id: &str) -> Result<Option<SubmissionResults>, ApplicationError> {
+        Ok(self.results.lock().unwrap().get(id).cloned())
+    }
+
+    async fn save_results(&self, id: &str, results: &SubmissionResults) -> Result<(), ApplicationError> {
+        self.results
+            .lock()
+            .unwrap()
+            .insert(id.to_string(), results.clone());
+        Ok(())
+    }
+
+    async fn score_range(&self, _benchmark_id: &str) -> Result<Option<MetricRange>, ApplicationError> {
+        Ok(self.score_range.clone())
+    }
+
+    async fn score_precision(
+        &self,
+        _benchmark_id: &str,
+    ) -> Result<Option<ScorePrecision>, ApplicationError> {
+        Ok(self.score_precision)
+    }
+
+    async fn leaderboard_min_verification(
+        &self,
+        _benchmark_id: &str,
+    ) -> Result<VerificationLevel, ApplicationError> {
+        Ok(self.leaderboard_min_verification)
+    }
+
+    async fn find_duplicate(
+        &self,
+        submission: &CreateSubmissionData,
+        hash: &str,
+    ) -> Result<Option<String>, ApplicationError> {
+        let results = self.results.lock().unwrap();
+        Ok(self
+            .submissions
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| {
+                s.benchmark_id == submission.benchmark_id
+                    && s.model_provider == submission.model_provider
+                    && s.model_name == submission.model_name
+                    && s.model_version == submission.model_version
+            })
+            .filter(|s| {
+                results
+                    .get(&s.id)
+                    .is_some_and(|r| content_hash(r).unwrap() == hash)
+            })
+            .min_by_key(|s| s.created_at)
+            .map(|s| s.id.clone()))
+    }
+
+    async fn trending_benchmarks(
+        &self,
+        window: std::time::Duration,
+        limit: usize,
+    ) -> Result<Vec<(String, f64)>, ApplicationError> {
+        let submissions = self.submissions.lock().unwrap();
+        Ok(trending_scores(
+            submissions
+                .values()
+                .map(|s| (s.benchmark_id.as_str(), s.created_at)),
+            chrono::Utc::now(),
+            window,
+            limit,
+        ))
+    }
+}
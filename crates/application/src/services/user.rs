@@ -3,16 +3,21 @@
 //! Business logic for user management including authentication,
 //! profile management, and API key operations.
 
-use super::{EventPublisher, PaginatedResult, Pagination, ServiceConfig, ServiceContext, ServiceEvent};
+use super::organization::verify_organization_key;
+use super::{
+    EventPublisher, OrganizationRepositoryPort, PaginatedResult, Pagination, Permission,
+    ServiceConfig, ServiceContext, ServiceEvent, SubmissionDto, SubmissionRepositoryPort,
+};
 use crate::validation::{ChangePasswordRequest, CreateApiKeyRequest, CreateUserRequest, UpdateUserRequest, Validatable};
 use crate::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
+use serde::Serialize;
 use std::sync::Arc;
 use llm_benchmark_common::execution::Artifact;
 use tracing::{debug, info, instrument, warn};
 
 /// User data transfer object
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UserDto {
     pub id: String,
     pub email: String,
@@ -42,7 +47,7 @@ pub struct UserProfileDto {
 }
 
 /// API key data transfer object
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ApiKeyDto {
     pub id: String,
     pub name: String,
@@ -50,9 +55,70 @@ pub struct ApiKeyDto {
     pub scopes: Vec<String>,
     pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+impl ApiKeyDto {
+    pub fn is_expired_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Whether the key can still be used to authenticate
+    pub fn is_active_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        !self.is_revoked() && !self.is_expired_at(now)
+    }
+}
+
+/// Filters for listing API keys. Expired and revoked keys are hidden unless
+/// explicitly requested.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyFilters {
+    pub include_expired: bool,
+    pub include_revoked: bool,
+}
+
+impl ApiKeyFilters {
+    /// Filters that match every key
+    pub fn all() -> Self {
+        Self {
+            include_expired: true,
+            include_revoked: true,
+        }
+    }
+
+    pub fn matches(&self, key: &ApiKeyDto, now: chrono::DateTime<chrono::Utc>) -> bool {
+        (self.include_expired || !key.is_expired_at(now))
+            && (self.include_revoked || !key.is_revoked())
+    }
+}
+
+/// Scope an organization key needs to manage its organization: update it,
+/// manage members and list its keys
+pub const ORGANIZATION_ADMIN_SCOPE: &str = "admin:organizations";
+
+/// Principal an API key authenticates as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiKeyPrincipal {
+    /// Personal key acting as the user who created it
+    User(String),
+    /// Organization service key acting on behalf of the organization
+    Organization(String),
+}
+
+impl ApiKeyPrincipal {
+    /// ID of the user or organization the key belongs to
+    pub fn id(&self) -> &str {
+        match self {
+            Self::User(id) | Self::Organization(id) => id,
+        }
+    }
+}
+
 /// API key with secret (only returned on creation)
 #[derive(Debug, Clone)]
 pub struct ApiKeyWithSecretDto {
@@ -60,6 +126,51 @@ pub struct ApiKeyWithSecretDto {
     pub secret: String,
 }
 
+/// Kind of governance action taken by a user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GovernanceActivityKind {
+    ProposalCreated,
+    VoteCast,
+    CommentAdded,
+}
+
+/// A single governance action taken by a user
+#[derive(Debug, Clone, Serialize)]
+pub struct GovernanceActivityDto {
+    pub kind: GovernanceActivityKind,
+    pub proposal_id: String,
+    /// Vote choice or comment body, depending on the kind
+    pub detail: Option<String>,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Everything held about a user, bundled for data-subject requests.
+///
+/// API keys are exported as metadata only; secrets and password hashes are
+/// never part of the export.
+#[derive(Debug, Clone, Serialize)]
+pub struct UserDataExport {
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    pub user: UserDto,
+    pub submissions: Vec<SubmissionDto>,
+    pub api_keys: Vec<ApiKeyDto>,
+    pub governance_activity: Vec<GovernanceActivityDto>,
+}
+
+/// ID of the placeholder user that inherits content from anonymized accounts
+pub const TOMBSTONE_USER_ID: &str = "00000000-0000-0000-0000-000000000000";
+
+/// How a user account is deleted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeletionMode {
+    /// Remove the account together with everything it owns
+    HardDelete,
+    /// Remove personal data but keep submissions and proposals, reassigned
+    /// to the tombstone user
+    Anonymize,
+}
+
 /// User repository trait
 #[async_trait]
 pub trait UserRepositoryPort: Send + Sync {
@@ -75,9 +186,28 @@ pub trait UserRepositoryPort: Send + Sync {
     async fn email_exists(&self, email: &str) -> Result<bool, ApplicationError>;
     async fn username_exists(&self, username: &str) -> Result<bool, ApplicationError>;
     async fn create_api_key(&self, user_id: &str, key: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError>;
-    async fn list_api_keys(&self, user_id: &str) -> Result<Vec<ApiKeyDto>, ApplicationError>;
+    async fn list_api_keys(
+        &self,
+        user_id: &str,
+        filters: &ApiKeyFilters,
+        pagination: &Pagination,
+    ) -> Result<(Vec<ApiKeyDto>, u64), ApplicationError>;
+    /// Mark a key as revoked; revoked keys are kept for auditing
     async fn revoke_api_key(&self, user_id: &str, key_id: &str) -> Result<(), ApplicationError>;
-    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, Vec<String>)>, ApplicationError>;
+    /// Look up a key secret, returning the owning user ID and the key metadata
+    async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError>;
+    async fn touch_api_key(
+        &self,
+        key_id: &str,
+        used_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApplicationError>;
+    /// Proposals, votes and comments made by the user, oldest first
+    async fn get_governance_activity(&self, user_id: &str) -> Result<Vec<GovernanceActivityDto>, ApplicationError>;
+    /// Delete the account in one atomic step: drop its API keys, remove its
+    /// content or move it to [`TOMBSTONE_USER_ID`] as `mode` says, and strip
+    /// personal data. Fails with `Forbidden`, changing nothing, if the user
+    /// is the last admin
+    async fn delete_account(&self, id: &str, mode: DeletionMode) -> Result<(), ApplicationError>;
 }
 
 /// Data for creating a user
@@ -144,6 +274,8 @@ where
     event_publisher: Arc<E>,
     password_hasher: Arc<H>,
     config: ServiceConfig,
+    submissions: Arc<dyn SubmissionRepositoryPort>,
+    organization_keys: Option<Arc<dyn OrganizationRepositoryPort>>,
 }
 
 impl<R, E, H> UserService<R, E, H>
@@ -152,8 +284,10 @@ where
     E: EventPublisher,
     H: PasswordHasher,
 {
+    /// Create a user service; `submissions` is read for data exports
     pub fn new(
         repository: Arc<R>,
+        submissions: Arc<dyn SubmissionRepositoryPort>,
         event_publisher: Arc<E>,
         password_hasher: Arc<H>,
         config: ServiceConfig,
@@ -163,9 +297,17 @@ where
             event_publisher,
             password_hasher,
             config,
+            submissions,
+            organization_keys: None,
         }
     }
 
+    /// Also accept organization API keys in [`Self::verify_api_key`]
+    pub fn with_organization_keys(mut self, organizations: Arc<dyn OrganizationRepositoryPort>) -> Self {
+        self.organization_keys = Some(organizations);
+        self
+    }
+
     /// Register a new user
     #[instrument(skip(self, request), fields(email = %request.email))]
     pub async fn register(&self, request: CreateUserRequest) -> ApplicationResult<UserDto> {
@@ -274,7 +416,7 @@ where
 
         // Check authorization
         let user_id = ctx.require_authenticated()?;
-        if user_id != id && !ctx.is_admin {
+        if user_id != id && !ctx.has(Permission::ManageUsers) {
             return Err(ApplicationError::Forbidden(
                 "You can only update your own profile".to_string(),
             ));
@@ -442,12 +584,28 @@ where
 
     /// List API keys for a user
     #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
-    pub async fn list_api_keys(&self, ctx: &ServiceContext) -> ApplicationResult<Vec<ApiKeyDto>> {
+    pub async fn list_api_keys(
+        &self,
+        ctx: &ServiceContext,
+        filters: ApiKeyFilters,
+        pagination: Pagination,
+    ) -> ApplicationResult<PaginatedResult<ApiKeyDto>> {
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("UserAgent"));
         let user_id = ctx.require_authenticated()?;
-        let result = self.repository.list_api_keys(user_id).await;
+
+        let pagination = Pagination::new(
+            pagination.page.max(1),
+            pagination.page_size.min(self.config.max_page_size),
+        );
+
+        let (items, total) = self
+            .repository
+            .list_api_keys(user_id, &filters, &pagination)
+            .await?;
+
         if let Some(guard) = _guard { guard.complete(); }
-        result
+
+        Ok(PaginatedResult::new(items, total, &pagination))
     }
 
     /// Revoke an API key
@@ -472,38 +630,154 @@ where
         Ok(())
     }
 
-    /// Verify an API key and return user ID and scopes
+    /// Verify an API key and return its principal and scopes.
+    ///
+    /// Personal keys resolve to their user; when organization keys are
+    /// enabled, service keys resolve to their organization. Expired and
+    /// revoked keys do not verify. A successful verification records the
+    /// key's last use.
     #[instrument(skip(self, key_secret))]
     pub async fn verify_api_key(
         &self,
         key_secret: &str,
-    ) -> ApplicationResult<Option<(String, Vec<String>)>> {
-        self.repository.verify_api_key(key_secret).await
+    ) -> ApplicationResult<Option<(ApiKeyPrincipal, Vec<String>)>> {
+        let Some((user_id, key)) = self.repository.verify_api_key(key_secret).await? else {
+            return match &self.organization_keys {
+                Some(organizations) => verify_organization_key(organizations.as_ref(), key_secret).await,
+                None => Ok(None),
+            };
+        };
+
+        let now = chrono::Utc::now();
+        if !key.is_active_at(now) {
+            debug!(key_id = %key.id, "Rejected inactive API key");
+            return Ok(None);
+        }
+
+        self.repository.touch_api_key(&key.id, now).await?;
+
+        Ok(Some((ApiKeyPrincipal::User(user_id), key.scopes)))
+    }
+
+    /// Export all data tied to a user (the user themselves or an admin)
+    #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
+    pub async fn export_user_data(
+        &self,
+        ctx: &ServiceContext,
+        user_id: &str,
+    ) -> ApplicationResult<UserDataExport> {
+        let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("UserAgent"));
+
+        // Check authorization
+        let current_user_id = ctx.require_authenticated()?;
+        if current_user_id != user_id && !ctx.has(Permission::ManageUsers) {
+            return Err(ApplicationError::Forbidden(
+                "You can only export your own data".to_string(),
+            ));
+        }
+
+        let user = self
+            .repository
+            .get_by_id(user_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound(format!("User not found: {}", user_id)))?;
+
+        let submissions = self.all_submissions(user_id).await?;
+        let api_keys = self.all_api_keys(user_id).await?;
+        let governance_activity = self.repository.get_governance_activity(user_id).await?;
+
+        info!(
+            user_id = %user_id,
+            requested_by = %current_user_id,
+            submissions = submissions.len(),
+            "User data exported"
+        );
+
+        if let Some(guard) = _guard {
+            guard.attach_artifact(Artifact::new("user_data_exported", user_id));
+            guard.complete();
+        }
+
+        Ok(UserDataExport {
+            exported_at: chrono::Utc::now(),
+            user,
+            submissions,
+            api_keys,
+            governance_activity,
+        })
+    }
+
+    /// Every submission made by the user, regardless of visibility
+    async fn all_submissions(&self, user_id: &str) -> ApplicationResult<Vec<SubmissionDto>> {
+        let mut pagination = Pagination::new(1, self.config.max_page_size);
+        let mut submissions = Vec::new();
+        loop {
+            let (items, total) = self
+                .submissions
+                .get_user_submissions(user_id, &pagination)
+                .await?;
+            let fetched = items.len();
+            submissions.extend(items);
+            if fetched == 0 || submissions.len() as u64 >= total {
+                return Ok(submissions);
+            }
+            pagination.page += 1;
+        }
+    }
+
+    /// Every API key of a user, including expired and revoked ones
+    async fn all_api_keys(&self, user_id: &str) -> ApplicationResult<Vec<ApiKeyDto>> {
+        let filters = ApiKeyFilters::all();
+        let mut pagination = Pagination::new(1, self.config.max_page_size);
+        let mut keys = Vec::new();
+        loop {
+            let (items, total) = self
+                .repository
+                .list_api_keys(user_id, &filters, &pagination)
+                .await?;
+            let fetched = items.len();
+            keys.extend(items);
+            if fetched == 0 || keys.len() as u64 >= total {
+                return Ok(keys);
+            }
+            pagination.page += 1;
+        }
     }
 
     /// Delete a user account
     #[instrument(skip(self, ctx), fields(correlation_id = %ctx.correlation_id))]
-    pub async fn delete(&self, ctx: &ServiceContext, id: &str) -> ApplicationResult<()> {
+    pub async fn delete(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+        mode: DeletionMode,
+    ) -> ApplicationResult<()> {
         let _guard = ctx.execution_ctx.as_ref().map(|exec| exec.agent_guard("UserAgent"));
 
         // Check authorization
         let user_id = ctx.require_authenticated()?;
-        if user_id != id && !ctx.is_admin {
+        if user_id != id && !ctx.has(Permission::ManageUsers) {
             return Err(ApplicationError::Forbidden(
                 "You can only delete your own account".to_string(),
             ));
         }
 
         // Check user exists
-        self.repository
-            .get_by_id(id)
-            .await?
-            .ok_or_else(|| ApplicationError::NotFound(format!("User not found: {}", id)))?;
+        if self.repository.get_by_id(id).await?.is_none() {
+            return Err(ApplicationError::NotFound(format!("User not found: {}", id)));
+        }
 
-        // Delete user
-        self.repository.delete(id).await?;
+        // The store refuses to remove the last admin
+        self.repository.delete_account(id, mode).await?;
 
-        info!(user_id = %id, "User deleted");
+        info!(user_id = %id, mode = ?mode, "User deleted");
+
+        // Publish event
+        self.event_publisher
+            .publish(ServiceEvent::UserDeleted {
+                user_id: id.to_string(),
+            })
+            .await?;
 
         if let Some(guard) = _guard {
             guard.attach_artifact(Artifact::new("user_deleted", id));
@@ -517,6 +791,560 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::test_support::InMemorySubmissions;
+    use crate::services::NoOpEventPublisher;
+    use llm_benchmark_domain::submission::{SubmissionVisibility, VerificationLevel};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryUsers {
+        users: Mutex<HashMap<String, UserDto>>,
+        api_keys: Mutex<HashMap<String, Vec<ApiKeyDto>>>,
+        api_key_secrets: Mutex<HashMap<String, (String, String)>>,
+        /// Shared with the service's submission port, as one database would be
+        submissions: Arc<InMemorySubmissions>,
+        governance: Mutex<Vec<(String, GovernanceActivityDto)>>,
+    }
+
+    impl InMemoryUsers {
+        fn submissions_of(&self, user_id: &str) -> Vec<SubmissionDto> {
+            self.submissions
+                .submissions
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|s| s.submitter_id == user_id)
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl UserRepositoryPort for InMemoryUsers {
+        async fn create(&self, user: &CreateUserData) -> Result<String, ApplicationError> {
+            let id = uuid::Uuid::new_v4().to_string();
+            let now = chrono::Utc::now();
+            self.users.lock().unwrap().insert(
+                id.clone(),
+                UserDto {
+                    id: id.clone(),
+                    email: user.email.clone(),
+                    username: user.username.clone(),
+                    display_name: user.display_name.clone(),
+                    bio: None,
+                    website: None,
+                    avatar_url: None,
+                    is_verified: false,
+                    is_admin: false,
+                    created_at: now,
+                    updated_at: now,
+                },
+            );
+            Ok(id)
+        }
+
+        async fn get_by_id(&self, id: &str) -> Result<Option<UserDto>, ApplicationError> {
+            Ok(self.users.lock().unwrap().get(id).cloned())
+        }
+
+        async fn get_by_email(&self, email: &str) -> Result<Option<UserDto>, ApplicationError> {
+            Ok(self.users.lock().unwrap().values().find(|u| u.email == email).cloned())
+        }
+
+        async fn get_by_username(&self, username: &str) -> Result<Option<UserDto>, ApplicationError> {
+            Ok(self.users.lock().unwrap().values().find(|u| u.username == username).cloned())
+        }
+
+        async fn update(&self, _id: &str, _update: &UpdateUserData) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn update_password(&self, _id: &str, _password_hash: &str) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn verify_password(&self, _id: &str, _password: &str) -> Result<bool, ApplicationError> {
+            Ok(false)
+        }
+
+        async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+            self.users.lock().unwrap().remove(id);
+            Ok(())
+        }
+
+        async fn get_profile(&self, _id: &str) -> Result<Option<UserProfileDto>, ApplicationError> {
+            Ok(None)
+        }
+
+        async fn email_exists(&self, email: &str) -> Result<bool, ApplicationError> {
+            Ok(self.get_by_email(email).await?.is_some())
+        }
+
+        async fn username_exists(&self, username: &str) -> Result<bool, ApplicationError> {
+            Ok(self.get_by_username(username).await?.is_some())
+        }
+
+        async fn create_api_key(&self, user_id: &str, key: &CreateApiKeyData) -> Result<ApiKeyWithSecretDto, ApplicationError> {
+            let key = ApiKeyDto {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: key.name.clone(),
+                description: key.description.clone(),
+                scopes: key.scopes.clone(),
+                last_used_at: None,
+                expires_at: key
+                    .expires_in_days
+                    .map(|days| chrono::Utc::now() + chrono::Duration::days(days as i64)),
+                revoked_at: None,
+                created_at: chrono::Utc::now(),
+            };
+            let secret = format!("llm_bm_{}_{}", user_id, uuid::Uuid::new_v4());
+            self.api_keys
+                .lock()
+                .unwrap()
+                .entry(user_id.to_string())
+                .or_default()
+                .push(key.clone());
+            self.api_key_secrets
+                .lock()
+                .unwrap()
+                .insert(secret.clone(), (user_id.to_string(), key.id.clone()));
+            Ok(ApiKeyWithSecretDto { key, secret })
+        }
+
+        async fn list_api_keys(
+            &self,
+            user_id: &str,
+            filters: &ApiKeyFilters,
+            pagination: &Pagination,
+        ) -> Result<(Vec<ApiKeyDto>, u64), ApplicationError> {
+            let now = chrono::Utc::now();
+            let keys: Vec<_> = self
+                .api_keys
+                .lock()
+                .unwrap()
+                .get(user_id)
+                .into_iter()
+                .flatten()
+                .filter(|key| filters.matches(key, now))
+                .cloned()
+                .collect();
+            let total = keys.len() as u64;
+            let items = keys
+                .into_iter()
+                .skip(pagination.offset() as usize)
+                .take(pagination.limit() as usize)
+                .collect();
+            Ok((items, total))
+        }
+
+        async fn revoke_api_key(&self, user_id: &str, key_id: &str) -> Result<(), ApplicationError> {
+            if let Some(key) = self
+                .api_keys
+                .lock()
+                .unwrap()
+                .get_mut(user_id)
+                .and_then(|keys| keys.iter_mut().find(|k| k.id == key_id))
+            {
+                key.revoked_at = Some(chrono::Utc::now());
+            }
+            Ok(())
+        }
+
+        async fn verify_api_key(&self, key_secret: &str) -> Result<Option<(String, ApiKeyDto)>, ApplicationError> {
+            let Some((user_id, key_id)) = self.api_key_secrets.lock().unwrap().get(key_secret).cloned() else {
+                return Ok(None);
+            };
+            let key = self
+                .api_keys
+                .lock()
+                .unwrap()
+                .get(&user_id)
+                .and_then(|keys| keys.iter().find(|k| k.id == key_id).cloned());
+            Ok(key.map(|key| (user_id, key)))
+        }
+
+        async fn touch_api_key(
+            &self,
+            key_id: &str,
+            used_at: chrono::DateTime<chrono::Utc>,
+        ) -> Result<(), ApplicationError> {
+            for key in self.api_keys.lock().unwrap().values_mut().flatten() {
+                if key.id == key_id {
+                    key.last_used_at = Some(used_at);
+                }
+            }
+            Ok(())
+        }
+
+        async fn get_governance_activity(&self, user_id: &str) -> Result<Vec<GovernanceActivityDto>, ApplicationError> {
+            Ok(self
+                .governance
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(owner, _)| owner == user_id)
+                .map(|(_, activity)| activity.clone())
+                .collect())
+        }
+        async fn delete_account(&self, id: &str, mode: DeletionMode) -> Result<(), ApplicationError> {
+            // Holding the user table for the whole deletion makes it atomic
+            let mut users = self.users.lock().unwrap();
+            let is_admin = users.get(id).is_some_and(|u| u.is_admin);
+            if is_admin && users.values().filter(|u| u.is_admin).count() <= 1 {
+                return Err(ApplicationError::Forbidden("Cannot delete the last admin".to_string()));
+            }
+
+            let mut submissions = self.submissions.submissions.lock().unwrap();
+            let mut governance = self.governance.lock().unwrap();
+            match mode {
+                DeletionMode::HardDelete => {
+                    submissions.retain(|_, s| s.submitter_id != id);
+                    governance.retain(|(owner, _)| owner != id);
+                }
+                DeletionMode::Anonymize => {
+                    for submission in submissions.values_mut().filter(|s| s.submitter_id == id) {
+                        submission.submitter_id = TOMBSTONE_USER_ID.to_string();
+                    }
+                    for (owner, _) in governance.iter_mut().filter(|(owner, _)| owner == id) {
+                        *owner = TOMBSTONE_USER_ID.to_string();
+                    }
+                }
+            }
+            self.api_keys.lock().unwrap().remove(id);
+            users.remove(id);
+            Ok(())
+        }
+    }
+
+    type TestService = UserService<InMemoryUsers, NoOpEventPublisher, Argon2PasswordHasher>;
+
+    fn submission(submitter_id: &str, model_name: &str) -> SubmissionDto {
+        let now = chrono::Utc::now();
+        SubmissionDto {
+            id: uuid::Uuid::new_v4().to_string(),
+            benchmark_id: uuid::Uuid::new_v4().to_string(),
+            benchmark_version_id: uuid::Uuid::new_v4().to_string(),
+            model_provider: "acme".to_string(),
+            model_name: model_name.to_string(),
+            model_version: None,
+            submitter_id: submitter_id.to_string(),
+            organization_id: None,
+            aggregate_score: 0.8,
+            verification_level: VerificationLevel::Unverified,
+            visibility: SubmissionVisibility::Private,
+            cancelled_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    async fn service_with_user() -> (TestService, String, String) {
+        let users = InMemoryUsers::default();
+        let submissions = Arc::clone(&users.submissions);
+        let service = UserService::new(
+            Arc::new(users),
+            submissions,
+            Arc::new(NoOpEventPublisher),
+            Arc::new(Argon2PasswordHasher),
+            ServiceConfig::default(),
+        );
+        let user = service
+            .register(CreateUserRequest {
+                email: "ada@example.com".to_string(),
+                username: "ada".to_string(),
+                display_name: "Ada".to_string(),
+                password: Some("Correct-Horse-Battery-9".to_string()),
+                oauth_provider: None,
+                oauth_id: None,
+            })
+            .await
+            .unwrap();
+
+        let ctx = ServiceContext::authenticated(user.id.clone(), "corr".to_string());
+        let key = service
+            .create_api_key(
+                &ctx,
+                CreateApiKeyRequest {
+                    name: "ci".to_string(),
+                    description: None,
+                    scopes: vec!["read:benchmarks".to_string()],
+                    expires_in_days: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        {
+            let mut submissions = service.repository.submissions.submissions.lock().unwrap();
+            for submission in [submission(&user.id, "ada-model"), submission("someone-else", "other-model")] {
+                submissions.insert(submission.id.clone(), submission);
+            }
+        }
+        service.repository.governance.lock().unwrap().push((
+            user.id.clone(),
+            GovernanceActivityDto {
+                kind: GovernanceActivityKind::VoteCast,
+                proposal_id: uuid::Uuid::new_v4().to_string(),
+                detail: Some("approve".to_string()),
+                at: chrono::Utc::now(),
+            },
+        ));
+
+        (service, user.id, key.secret)
+    }
+
+    #[tokio::test]
+    async fn test_export_contains_submissions_and_omits_secrets() {
+        let (service, user_id, secret) = service_with_user().await;
+        let ctx = ServiceContext::authenticated(user_id.clone(), "corr".to_string());
+
+        let export = service.export_user_data(&ctx, &user_id).await.unwrap();
+        assert_eq!(export.user.id, user_id);
+        assert_eq!(export.submissions.len(), 1);
+        assert_eq!(export.submissions[0].model_name, "ada-model");
+        assert_eq!(export.api_keys.len(), 1);
+        assert_eq!(export.governance_activity.len(), 1);
+
+        let json = serde_json::to_string(&export).unwrap();
+        assert!(json.contains("ada-model"));
+        assert!(json.contains("vote_cast"));
+        assert!(!json.contains("other-model"));
+        assert!(!json.contains(&secret));
+        assert!(!json.contains("Correct-Horse-Battery-9"));
+        assert!(!json.contains("argon2"));
+    }
+
+    #[tokio::test]
+    async fn test_export_restricted_to_self_or_admin() {
+        let (service, user_id, _) = service_with_user().await;
+
+        let other = ServiceContext::authenticated("someone-else".to_string(), "corr".to_string());
+        let result = service.export_user_data(&other, &user_id).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+
+        let anonymous = ServiceContext::anonymous("corr".to_string());
+        let result = service.export_user_data(&anonymous, &user_id).await;
+        assert!(matches!(result, Err(ApplicationError::Unauthorized(_))));
+
+        let admin = other.with_admin();
+        let export = service.export_user_data(&admin, &user_id).await.unwrap();
+        assert_eq!(export.submissions.len(), 1);
+    }
+
+    fn set_admin(service: &TestService, user_id: &str) {
+        service
+            .repository
+            .users
+            .lock()
+            .unwrap()
+            .get_mut(user_id)
+            .unwrap()
+            .is_admin = true;
+    }
+
+    #[tokio::test]
+    async fn test_hard_delete_removes_owned_records() {
+        let (service, user_id, _) = service_with_user().await;
+        let ctx = ServiceContext::authenticated(user_id.clone(), "corr".to_string());
+
+        service.delete(&ctx, &user_id, DeletionMode::HardDelete).await.unwrap();
+
+        let repo = &service.repository;
+        assert!(repo.get_by_id(&user_id).await.unwrap().is_none());
+        assert!(repo.api_keys.lock().unwrap().get(&user_id).is_none());
+        assert!(repo.submissions_of(&user_id).is_empty());
+        let remaining = repo.submissions_of("someone-else");
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].model_name, "other-model");
+        assert!(repo.governance.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_anonymize_keeps_content_under_tombstone() {
+        let (service, user_id, _) = service_with_user().await;
+        let ctx = ServiceContext::authenticated(user_id.clone(), "corr".to_string());
+
+        service.delete(&ctx, &user_id, DeletionMode::Anonymize).await.unwrap();
+
+        let repo = &service.repository;
+        assert!(repo.get_by_id(&user_id).await.unwrap().is_none());
+        assert!(repo.get_by_email("ada@example.com").await.unwrap().is_none());
+        assert!(repo.api_keys.lock().unwrap().get(&user_id).is_none());
+        assert!(repo.submissions_of(&user_id).is_empty());
+
+        let retained = repo.submissions_of(TOMBSTONE_USER_ID);
+        assert_eq!(retained.len(), 1);
+        assert_eq!(retained[0].model_name, "ada-model");
+        let activity = repo.get_governance_activity(TOMBSTONE_USER_ID).await.unwrap();
+        assert_eq!(activity.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_requires_self_or_admin() {
+        let (service, user_id, _) = service_with_user().await;
+
+        let other = ServiceContext::authenticated("someone-else".to_string(), "corr".to_string());
+        let result = service.delete(&other, &user_id, DeletionMode::HardDelete).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+
+        let admin = other.with_admin();
+        service.delete(&admin, &user_id, DeletionMode::Anonymize).await.unwrap();
+    }
 
-    // Tests would go here with mock implementations
+    #[tokio::test]
+    async fn test_last_admin_cannot_be_deleted() {
+        let (service, user_id, _) = service_with_user().await;
+        set_admin(&service, &user_id);
+        let ctx = ServiceContext::authenticated(user_id.clone(), "corr".to_string()).with_admin();
+
+        for mode in [DeletionMode::HardDelete, DeletionMode::Anonymize] {
+            let result = service.delete(&ctx, &user_id, mode).await;
+            assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+        }
+
+        // Once another admin exists the account can go
+        let second = service
+            .register(CreateUserRequest {
+                email: "grace@example.com".to_string(),
+                username: "grace".to_string(),
+                display_name: "Grace".to_string(),
+                password: Some("Correct-Horse-Battery-9".to_string()),
+                oauth_provider: None,
+                oauth_id: None,
+            })
+            .await
+            .unwrap();
+        set_admin(&service, &second.id);
+
+        service.delete(&ctx, &user_id, DeletionMode::HardDelete).await.unwrap();
+        assert!(service.repository.get_by_id(&user_id).await.unwrap().is_none());
+    }
+
+    fn key_request(name: &str) -> CreateApiKeyRequest {
+        CreateApiKeyRequest {
+            name: name.to_string(),
+            description: None,
+            scopes: vec!["read:benchmarks".to_string()],
+            expires_in_days: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_api_keys_excludes_expired_and_revoked_by_default() {
+        let (service, user_id, _) = service_with_user().await;
+        let ctx = ServiceContext::authenticated(user_id.clone(), "corr".to_string());
+
+        let expired = service.create_api_key(&ctx, key_request("expired")).await.unwrap();
+        let revoked = service.create_api_key(&ctx, key_request("revoked")).await.unwrap();
+        service
+            .repository
+            .api_keys
+            .lock()
+            .unwrap()
+            .get_mut(&user_id)
+            .unwrap()
+            .iter_mut()
+            .find(|k| k.id == expired.key.id)
+            .unwrap()
+            .expires_at = Some(chrono::Utc::now() - chrono::Duration::days(1));
+        service.revoke_api_key(&ctx, &revoked.key.id).await.unwrap();
+
+        let page = service
+            .list_api_keys(&ctx, ApiKeyFilters::default(), Pagination::default())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].name, "ci");
+
+        let filters = ApiKeyFilters {
+            include_expired: true,
+            ..Default::default()
+        };
+        let page = service.list_api_keys(&ctx, filters, Pagination::default()).await.unwrap();
+        assert_eq!(page.total, 2);
+
+        let page = service
+            .list_api_keys(&ctx, ApiKeyFilters::all(), Pagination::default())
+            .await
+            .unwrap();
+        assert_eq!(page.total, 3);
+
+        assert!(service.verify_api_key(&expired.secret).await.unwrap().is_none());
+        assert!(service.verify_api_key(&revoked.secret).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_api_keys_paginates() {
+        let (service, user_id, _) = service_with_user().await;
+        let ctx = ServiceContext::authenticated(user_id, "corr".to_string());
+        for i in 0..4 {
+            service.create_api_key(&ctx, key_request(&format!("key-{}", i))).await.unwrap();
+        }
+
+        let page = service
+            .list_api_keys(&ctx, ApiKeyFilters::default(), Pagination::new(2, 2))
+            .await
+            .unwrap();
+        assert_eq!(page.total, 5);
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.items.len(), 2);
+        assert!(page.has_next_page());
+    }
+
+    #[tokio::test]
+    async fn test_verify_api_key_updates_last_used_at() {
+        let (service, user_id, secret) = service_with_user().await;
+        let ctx = ServiceContext::authenticated(user_id.clone(), "corr".to_string());
+
+        let keys = service
+            .list_api_keys(&ctx, ApiKeyFilters::default(), Pagination::default())
+            .await
+            .unwrap();
+        assert!(keys.items[0].last_used_at.is_none());
+
+        let (principal, scopes) = service.verify_api_key(&secret).await.unwrap().unwrap();
+        assert_eq!(principal, ApiKeyPrincipal::User(user_id));
+        assert_eq!(scopes, vec!["read:benchmarks".to_string()]);
+
+        let keys = service
+            .list_api_keys(&ctx, ApiKeyFilters::default(), Pagination::default())
+            .await
+            .unwrap();
+        assert!(keys.items[0].last_used_at.is_some());
+    }
+    #[tokio::test]
+    async fn test_verify_api_key_accepts_organization_keys() {
+        use crate::services::test_support::InMemoryOrganizations;
+
+        let (service, _, personal) = service_with_user().await;
+        let organizations = Arc::new(InMemoryOrganizations::default());
+        let org_key = organizations
+            .create_api_key(
+                "acme",
+                &CreateApiKeyData {
+                    name: "ci".to_string(),
+                    description: None,
+                    scopes: vec!["write:submissions".to_string()],
+                    expires_in_days: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        // Without organization keys enabled only personal keys verify
+        assert!(service.verify_api_key(&org_key.secret).await.unwrap().is_none());
+
+        let service = service.with_organization_keys(organizations.clone());
+        let (principal, scopes) = service.verify_api_key(&org_key.secret).await.unwrap().unwrap();
+        assert_eq!(principal, ApiKeyPrincipal::Organization("acme".to_string()));
+        assert_eq!(scopes, vec!["write:submissions".to_string()]);
+        assert!(matches!(
+            service.verify_api_key(&personal).await.unwrap(),
+            Some((ApiKeyPrincipal::User(_), _))
+        ));
+
+        organizations.revoke_api_key("acme", &org_key.key.id).await.unwrap();
+        assert!(service.verify_api_key(&org_key.secret).await.unwrap().is_none());
+    }
 }
@@ -1,8 +1,12 @@
 //! Benchmark validation rules
 
 use super::{Validatable, ValidationResult, ValidationRules};
+use crate::dto::CreateBenchmarkDto;
 use llm_benchmark_domain::benchmark::{BenchmarkCategory, BenchmarkMetadata, BenchmarkStatus};
+use llm_benchmark_domain::submission::VerificationLevel;
+use llm_benchmark_domain::test_case::TestCase;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Create benchmark request validation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +17,12 @@ pub struct CreateBenchmarkRequest {
     pub category: BenchmarkCategory,
     pub tags: Vec<String>,
     pub version: String,
+    /// Weakest verification level ranked on the leaderboard by default
+    #[serde(default)]
+    pub leaderboard_min_verification: VerificationLevel,
+    /// Test cases of the initial version
+    #[serde(default)]
+    pub test_cases: Vec<TestCase>,
 }
 
 impl CreateBenchmarkRequest {
@@ -21,6 +31,24 @@ impl CreateBenchmarkRequest {
     pub const MAX_TAGS: usize = 20;
 }
 
+impl From<CreateBenchmarkDto> for CreateBenchmarkRequest {
+    fn from(dto: CreateBenchmarkDto) -> Self {
+        let criteria = dto.evaluation_criteria;
+        Self {
+            name: dto.name,
+            slug: dto.slug,
+            description: dto.description,
+            category: dto.category,
+            tags: dto.tags,
+            version: dto.version,
+            leaderboard_min_verification: criteria
+                .map(|c| c.leaderboard_min_verification)
+                .unwrap_or_default(),
+            test_cases: Vec::new(),
+        }
+    }
+}
+
 impl Validatable for CreateBenchmarkRequest {
     fn validate_all(&self) -> ValidationResult {
         let mut result = ValidationResult::success();
@@ -47,6 +75,11 @@ impl Validatable for CreateBenchmarkRequest {
         );
         result.merge(desc_result);
 
+        // Category validation (custom category names)
+        if let Err(e) = self.category.validate() {
+            result.add_field_error("category", e);
+        }
+
         // Tags validation
         let tags_result = ValidationRules::validate_list_size(
             &self.tags,
@@ -70,6 +103,8 @@ impl Validatable for CreateBenchmarkRequest {
         let version_result = ValidationRules::validate_semver(&self.version);
         result.merge(version_result);
 
+        validate_test_case_ids(&self.test_cases, &mut result);
+
         result
     }
 }
@@ -81,6 +116,9 @@ pub struct UpdateBenchmarkRequest {
     pub description: Option<String>,
     pub tags: Option<Vec<String>>,
     pub long_description: Option<String>,
+    /// Replacement test cases; only drafts can change them without a new version
+    #[serde(default)]
+    pub test_cases: Option<Vec<TestCase>>,
 }
 
 impl Validatable for UpdateBenchmarkRequest {
@@ -127,6 +165,10 @@ impl Validatable for UpdateBenchmarkRequest {
             result.merge(long_desc_result);
         }
 
+        if let Some(ref test_cases) = self.test_cases {
+            validate_test_case_ids(test_cases, &mut result);
+        }
+
         result
     }
 }
@@ -194,6 +236,9 @@ pub struct CreateVersionRequest {
     pub changelog: String,
     pub breaking_changes: bool,
     pub migration_notes: Option<String>,
+    /// Test cases for the new version; `None` carries the current ones forward
+    #[serde(default)]
+    pub test_cases: Option<Vec<TestCase>>,
 }
 
 impl Validatable for CreateVersionRequest {
@@ -240,10 +285,27 @@ impl Validatable for CreateVersionRequest {
             }
         }
 
+        if let Some(test_cases) = &self.test_cases {
+            validate_test_case_ids(test_cases, &mut result);
+        }
+
         result
     }
 }
 
+/// Test cases are matched across versions by ID, so IDs must be unique
+fn validate_test_case_ids(test_cases: &[TestCase], result: &mut ValidationResult) {
+    let mut seen = HashSet::new();
+    for test_case in test_cases {
+        if !seen.insert(test_case.id.as_str()) {
+            result.add_field_error(
+                "test_cases",
+                format!("Duplicate test case ID: {}", test_case.id),
+            );
+        }
+    }
+}
+
 /// Benchmark metadata validation
 impl Validatable for BenchmarkMetadata {
     fn validate_all(&self) -> ValidationResult {
@@ -346,6 +408,8 @@ mod tests {
             category: BenchmarkCategory::Accuracy,
             tags: vec!["test".to_string()],
             version: "1.0.0".to_string(),
+            leaderboard_min_verification: VerificationLevel::Unverified,
+            test_cases: vec![],
         };
         assert!(valid.validate_all().valid);
 
@@ -356,6 +420,8 @@ mod tests {
             category: BenchmarkCategory::Accuracy,
             tags: vec!["test".to_string()],
             version: "1.0.0".to_string(),
+            leaderboard_min_verification: VerificationLevel::Unverified,
+            test_cases: vec![],
         };
         assert!(!invalid_slug.validate_all().valid);
 
@@ -366,8 +432,41 @@ mod tests {
             category: BenchmarkCategory::Accuracy,
             tags: vec!["test".to_string()],
             version: "invalid".to_string(),
+            leaderboard_min_verification: VerificationLevel::Unverified,
+            test_cases: vec![],
         };
         assert!(!invalid_version.validate_all().valid);
+
+        let invalid_category = CreateBenchmarkRequest {
+            category: BenchmarkCategory::Custom("Not Valid".to_string()),
+            ..valid
+        };
+        let result = invalid_category.validate_all();
+        assert!(!result.valid);
+        assert!(result.field_errors.contains_key("category"));
+    }
+
+    #[test]
+    fn test_create_request_from_dto_keeps_leaderboard_minimum() {
+        let dto: CreateBenchmarkDto = serde_json::from_value(serde_json::json!({
+            "name": "Test Benchmark",
+            "slug": "test-benchmark",
+            "description": "A test benchmark for validation",
+            "category": "accuracy",
+            "version": "1.0.0",
+            "evaluation_criteria": {
+                "primary_metric": {
+                    "name": "accuracy",
+                    "description": "Accuracy metric",
+                    "metric_type": "accuracy"
+                },
+                "leaderboard_min_verification": "platform_verified"
+            }
+        }))
+        .unwrap();
+
+        let request = CreateBenchmarkRequest::from(dto);
+        assert_eq!(request.leaderboard_min_verification, VerificationLevel::PlatformVerified);
     }
 
     #[test]
@@ -401,6 +500,7 @@ mod tests {
             changelog: "Major changes to the benchmark methodology".to_string(),
             breaking_changes: false,
             migration_notes: None,
+            test_cases: None,
         };
         assert!(valid.validate_all().valid);
 
@@ -409,6 +509,7 @@ mod tests {
             changelog: "Breaking changes to the API".to_string(),
             breaking_changes: true,
             migration_notes: None,
+            test_cases: None,
         };
         assert!(!breaking_without_notes.validate_all().valid);
 
@@ -417,7 +518,42 @@ mod tests {
             changelog: "Breaking changes to the API".to_string(),
             breaking_changes: true,
             migration_notes: Some("Migrate by updating your test cases to use the new format".to_string()),
+            test_cases: None,
         };
         assert!(breaking_with_notes.validate_all().valid);
     }
+
+    #[test]
+    fn test_create_version_duplicate_test_case_ids() {
+        let test_case = |id: &str| -> TestCase {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "name": id,
+                "input": {
+                    "prompt_template": "Q?",
+                    "variables": {},
+                    "few_shot_examples": [],
+                    "input_format": {"type": "plain_text"}
+                },
+                "evaluation_method": {"type": "exact_match"},
+                "weight": 1.0,
+                "tags": []
+            }))
+            .unwrap()
+        };
+
+        let mut request = CreateVersionRequest {
+            version: "1.1.0".to_string(),
+            changelog: "Add and reword test cases".to_string(),
+            breaking_changes: false,
+            migration_notes: None,
+            test_cases: Some(vec![test_case("a"), test_case("b")]),
+        };
+        assert!(request.validate_all().valid);
+
+        request.test_cases = Some(vec![test_case("a"), test_case("a")]);
+        let result = request.validate_all();
+        assert!(!result.valid);
+        assert!(result.field_errors.contains_key("test_cases"));
+    }
 }
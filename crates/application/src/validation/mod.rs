@@ -79,12 +79,8 @@ impl ValidationResult {
         self.object_errors.extend(other.object_errors);
     }
 
-    /// Convert to ApplicationError if invalid
-    pub fn to_error(&self) -> Option<ApplicationError> {
-        if self.valid {
-            return None;
-        }
-
+    /// All errors on one line, field errors prefixed with their field
+    pub fn summary(&self) -> String {
         let mut messages = Vec::new();
 
         for (field, errors) in &self.field_errors {
@@ -95,7 +91,21 @@ impl ValidationResult {
 
         messages.extend(self.object_errors.clone());
 
-        Some(ApplicationError::ValidationFailed(messages.join("; ")))
+        messages.join("; ")
+    }
+
+    /// Convert to ApplicationError if invalid
+    ///
+    /// Field errors are kept in [`ApplicationError::InvalidFields`] so the API
+    /// can report them per field.
+    pub fn to_error(&self) -> Option<ApplicationError> {
+        if self.valid {
+            None
+        } else if self.field_errors.is_empty() {
+            Some(ApplicationError::ValidationFailed(self.summary()))
+        } else {
+            Some(ApplicationError::InvalidFields(self.clone()))
+        }
     }
 
     /// Ensure validation passed, returning error if not
@@ -123,27 +133,36 @@ impl<T: Validate> ValidatorExt for T {
     fn to_validation_result(&self) -> ValidationResult {
         match self.validate() {
             Ok(_) => ValidationResult::success(),
-            Err(errors) => {
-                let mut result = ValidationResult::success();
-
-                for (field, field_errors) in errors.field_errors() {
-                    for error in field_errors {
-                        let message = error
-                            .message
-                            .as_ref()
-                            .map(|m| m.to_string())
-                            .unwrap_or_else(|| {
-                                error
-                                    .code
-                                    .to_string()
-                            });
-                        result.add_field_error(field.to_string(), message);
-                    }
-                }
+            Err(errors) => ValidationResult::from(&errors),
+        }
+    }
+}
 
-                result
+impl From<&validator::ValidationErrors> for ValidationResult {
+    fn from(errors: &validator::ValidationErrors) -> Self {
+        let mut result = ValidationResult::success();
+
+        for (field, field_errors) in errors.field_errors() {
+            for error in field_errors {
+                let message = error
+                    .message
+                    .as_ref()
+                    .map(|m| m.to_string())
+                    .unwrap_or_else(|| {
+                        error
+                            .code
+                            .to_string()
+                    });
+                result.add_field_error(field.to_string(), message);
             }
         }
+
+        // Nested struct and list errors have no top-level field
+        if result.valid {
+            result.add_object_error(errors.to_string());
+        }
+
+        result
     }
 }
 
@@ -430,6 +449,17 @@ mod tests {
         result.add_field_error("name", "Required");
         assert!(!result.valid);
         assert!(result.field_errors.contains_key("name"));
+
+        match result.to_error() {
+            Some(ApplicationError::InvalidFields(fields)) => {
+                assert_eq!(fields.field_errors["name"], vec!["Required".to_string()]);
+            }
+            other => panic!("expected field errors, got {:?}", other),
+        }
+        assert_eq!(
+            result.to_error().unwrap().to_string(),
+            "Validation failed: name: Required"
+        );
     }
 
     #[test]
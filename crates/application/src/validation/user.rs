@@ -434,6 +434,7 @@ impl Validatable for CreateApiKeyRequest {
             "write:users",
             "read:organizations",
             "write:organizations",
+            "admin:organizations",
             "admin",
         ];
 
@@ -0,0 +1,110 @@
+//! Peak heap use of streamed scoring
+//!
+//! Runs in its own test binary so the counting allocator only sees this test.
+
+use futures::StreamExt;
+use llm_benchmark_application::scoring::{ScoringEngineBuilder, TestCaseInput, DEFAULT_MAX_TEST_CASES};
+use llm_benchmark_domain::evaluation::{
+    AggregationMethod, EvaluationCriteria, MetricDefinition, MetricType, ScoreNormalization,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Tracks live and peak heap bytes
+struct CountingAllocator;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let live = LIVE.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(live, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const BATCH: usize = 64;
+
+fn criteria() -> EvaluationCriteria {
+    EvaluationCriteria {
+        primary_metric: MetricDefinition {
+            name: "accuracy".to_string(),
+            description: "Exact match accuracy".to_string(),
+            metric_type: MetricType::Accuracy,
+            unit: None,
+            higher_is_better: true,
+            range: None,
+        },
+        secondary_metrics: vec![],
+        aggregation_method: AggregationMethod::Mean,
+        score_normalization: ScoreNormalization::None,
+        minimum_test_cases: 1,
+        confidence_level: 0.95,
+        score_precision: None,
+        leaderboard_min_verification: Default::default(),
+    }
+}
+
+/// Every fourth case answers wrongly; each carries a 1 KiB prompt-sized answer
+fn synthetic_case(i: usize) -> TestCaseInput {
+    let answer = "x".repeat(1024);
+    TestCaseInput {
+        id: i.to_string(),
+        expected: answer.clone(),
+        actual: if i % 4 == 3 { String::new() } else { answer },
+        weight: 1.0,
+        ..Default::default()
+    }
+}
+
+/// Heap growth over the live bytes before scoring `cases` streamed cases
+async fn peak_growth(cases: usize) -> usize {
+    let engine = ScoringEngineBuilder::new()
+        .max_concurrent_evaluations(BATCH)
+        .build();
+    let criteria = criteria();
+
+    let baseline = LIVE.load(Ordering::SeqCst);
+    PEAK.store(baseline, Ordering::SeqCst);
+
+    let results = engine
+        .score_stream(futures::stream::iter(0..cases).map(synthetic_case), &criteria)
+        .await
+        .unwrap();
+    assert!((results.aggregate_score - 0.75).abs() < 0.01);
+    assert!(results.metric_scores["accuracy"].raw_values.is_none());
+
+    PEAK.load(Ordering::SeqCst) - baseline
+}
+
+#[tokio::test]
+async fn test_stream_peak_allocation_bounded_by_batch_size() {
+    let cases = DEFAULT_MAX_TEST_CASES + 50_000;
+    // A single case holds two 1 KiB strings
+    let per_case = 2 * 1024;
+
+    let small = peak_growth(BATCH * 4).await;
+    let large = peak_growth(cases).await;
+
+    // Materializing the submission would need `cases * per_case` bytes
+    assert!(
+        large < BATCH * per_case * 8,
+        "peak growth {} bytes for {} cases",
+        large,
+        cases
+    );
+    // and the peak does not grow with the number of cases
+    assert!(large < small * 2, "peak growth {} vs {} for a few batches", large, small);
+}
@@ -21,8 +21,8 @@ async fn test_submit_results_happy_path() {
 
     // Assert
     assert_eq!(submission.visibility, SubmissionVisibility::Public);
-    assert_eq!(submission.verification_status.level, VerificationLevel::Unverified);
-    assert!(!submission.results.test_case_results.is_empty());
+    assert_eq!(submission.verification_status().level, VerificationLevel::Unverified);
+    assert!(!submission.results().test_case_results.is_empty());
 }
 
 #[tokio::test]
@@ -87,7 +87,7 @@ async fn test_submission_with_failed_tests() {
     let submission = create_test_submission_with_failures();
 
     // Assert - Should have at least one failed test
-    assert!(submission.results.test_case_results.iter().any(|r| !r.passed));
+    assert!(submission.results().test_case_results.iter().any(|r| !r.passed));
 }
 
 #[tokio::test]
@@ -96,10 +96,10 @@ async fn test_score_validation_range() {
     let submission = create_test_submission();
 
     // Assert - Scores should be in valid range
-    assert!(submission.results.aggregate_score >= 0.0);
-    assert!(submission.results.aggregate_score <= 1.0);
+    assert!(submission.results().aggregate_score >= 0.0);
+    assert!(submission.results().aggregate_score <= 1.0);
 
-    for result in &submission.results.test_case_results {
+    for result in &submission.results().test_case_results {
         assert!(result.score >= 0.0);
         assert!(result.score <= 1.0);
     }
@@ -111,8 +111,8 @@ async fn test_submission_with_confidence_interval() {
     let submission = create_test_submission();
 
     // Assert
-    assert!(submission.results.confidence_interval.is_some());
-    if let Some(ci) = &submission.results.confidence_interval {
+    assert!(submission.results().confidence_interval.is_some());
+    if let Some(ci) = &submission.results().confidence_interval {
         assert!(ci.lower <= ci.upper);
         assert!(ci.confidence_level > 0.0 && ci.confidence_level < 1.0);
     }
@@ -124,8 +124,8 @@ async fn test_submission_with_statistical_significance() {
     let submission = create_test_submission();
 
     // Assert
-    assert!(submission.results.statistical_significance.is_some());
-    if let Some(stats) = &submission.results.statistical_significance {
+    assert!(submission.results().statistical_significance.is_some());
+    if let Some(stats) = &submission.results().statistical_significance {
         assert!(stats.p_value >= 0.0 && stats.p_value <= 1.0);
         assert!(stats.sample_size > 0);
     }
@@ -182,8 +182,8 @@ async fn test_verified_submission() {
         .build();
 
     // Assert
-    assert_eq!(submission.verification_status.level, VerificationLevel::PlatformVerified);
-    assert!(submission.verification_status.verified_at.is_some());
+    assert_eq!(submission.verification_status().level, VerificationLevel::PlatformVerified);
+    assert!(submission.verification_status().verified_at.is_some());
 }
 
 #[tokio::test]
@@ -194,7 +194,7 @@ async fn test_audited_submission() {
         .build();
 
     // Assert
-    assert_eq!(submission.verification_status.level, VerificationLevel::Audited);
+    assert_eq!(submission.verification_status().level, VerificationLevel::Audited);
 }
 
 #[cfg(test)]
@@ -237,8 +237,10 @@ mod repository_tests {
 
         repo.create(submission.clone()).await.unwrap();
 
-        submission.verification_status.level = VerificationLevel::PlatformVerified;
+        submission
+            .verify(VerificationLevel::PlatformVerified, None, None)
+            .unwrap();
         let updated = repo.update(submission.clone()).await.unwrap();
-        assert_eq!(updated.verification_status.level, VerificationLevel::PlatformVerified);
+        assert_eq!(updated.verification_status().level, VerificationLevel::PlatformVerified);
     }
 }
@@ -75,6 +75,58 @@ pub trait BenchTarget: Send + Sync {
     fn category(&self) -> &'static str {
         "general"
     }
+
+    /// Returns a JSON schema that this target's metrics must match.
+    ///
+    /// Results are checked against the schema after every run, so a renamed
+    /// or mistyped metric fails the run instead of going unnoticed.
+    /// Default implementation returns `None` (no validation).
+    fn metrics_schema(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+/// Checks a result's metrics against its target's schema, if it has one.
+///
+/// # Errors
+///
+/// Returns an error naming each offending field if the metrics do not match,
+/// or if the target's schema is not a valid JSON schema.
+pub fn validate_metrics(target: &dyn BenchTarget, result: &BenchmarkResult) -> anyhow::Result<()> {
+    let Some(schema) = target.metrics_schema() else {
+        return Ok(());
+    };
+
+    let validator = jsonschema::validator_for(&schema).map_err(|e| {
+        anyhow::anyhow!("Invalid metrics schema for target '{}': {}", target.id(), e)
+    })?;
+
+    let violations: Vec<String> = validator
+        .iter_errors(&result.metrics)
+        .map(|e| format!("{} at '{}'", e, e.instance_path))
+        .collect();
+
+    if !violations.is_empty() {
+        anyhow::bail!(
+            "Metrics from target '{}' do not match its schema: {}",
+            target.id(),
+            violations.join("; ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs a benchmark target and validates its metrics.
+///
+/// # Errors
+///
+/// Returns an error if the target fails to execute or its metrics do not
+/// match its schema.
+pub async fn run_target(target: &dyn BenchTarget) -> anyhow::Result<BenchmarkResult> {
+    let result = target.run().await?;
+    validate_metrics(target, &result)?;
+    Ok(result)
 }
 
 /// Returns a vector of all registered benchmark targets.
@@ -160,6 +212,63 @@ mod tests {
         }
     }
 
+    struct MisnamedMetrics;
+
+    #[async_trait]
+    impl BenchTarget for MisnamedMetrics {
+        fn id(&self) -> &'static str {
+            "misnamed-metrics"
+        }
+
+        async fn run(&self) -> anyhow::Result<BenchmarkResult> {
+            Ok(BenchmarkResult::new(
+                self.id().to_string(),
+                serde_json::json!({"durationMs": 12.5}),
+            ))
+        }
+
+        fn metrics_schema(&self) -> Option<serde_json::Value> {
+            Some(serde_json::json!({
+                "type": "object",
+                "required": ["duration_ms"],
+                "properties": {"duration_ms": {"type": "number"}},
+                "additionalProperties": false
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_malformed_metrics_are_rejected() {
+        let err = run_target(&MisnamedMetrics).await.unwrap_err().to_string();
+
+        assert!(err.contains("misnamed-metrics"), "{}", err);
+        assert!(err.contains("\"duration_ms\" is a required property"), "{}", err);
+        assert!(err.contains("durationMs"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_registered_targets_match_their_schemas() {
+        let with_schema: Vec<_> = all_targets()
+            .into_iter()
+            .filter(|t| t.metrics_schema().is_some())
+            .collect();
+        assert!(!with_schema.is_empty());
+
+        for target in with_schema {
+            run_target(target.as_ref()).await.unwrap();
+        }
+    }
+
+    #[test]
+    fn test_schema_type_errors_name_the_field() {
+        let result = BenchmarkResult::new(
+            "misnamed-metrics".to_string(),
+            serde_json::json!({"duration_ms": "fast"}),
+        );
+        let err = validate_metrics(&MisnamedMetrics, &result).unwrap_err().to_string();
+        assert!(err.contains("at '/duration_ms'"), "{}", err);
+    }
+
     #[test]
     fn test_get_nonexistent_target() {
         let found = get_target("nonexistent-target");
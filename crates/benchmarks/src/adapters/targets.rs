@@ -139,6 +139,34 @@ impl BenchTarget for CorpusHashingBenchmark {
         "processing"
     }
 
+    fn metrics_schema(&self) -> Option<serde_json::Value> {
+        let duration = json!({"type": "number", "minimum": 0});
+        Some(json!({
+            "type": "object",
+            "required": [
+                "duration_ms",
+                "corpus_size_bytes",
+                "generation_time_ms",
+                "hash_time_ms",
+                "checksum_time_ms",
+                "throughput_mb_s",
+                "hash_value",
+                "checksum_value"
+            ],
+            "properties": {
+                "duration_ms": duration,
+                "corpus_size_bytes": {"type": "integer", "minimum": 0},
+                "generation_time_ms": duration,
+                "hash_time_ms": duration,
+                "checksum_time_ms": duration,
+                "throughput_mb_s": {"type": "number"},
+                "hash_value": {"type": "string", "pattern": "^[0-9a-f]{16}$"},
+                "checksum_value": {"type": "integer", "minimum": 0}
+            },
+            "additionalProperties": false
+        }))
+    }
+
     async fn run(&self) -> anyhow::Result<BenchmarkResult> {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
@@ -225,6 +253,7 @@ impl BenchTarget for MetadataAggregationBenchmark {
     }
 
     async fn run(&self) -> anyhow::Result<BenchmarkResult> {
+        reset_peak_memory();
         let start = Instant::now();
 
         // Generate simulated metadata records
@@ -279,7 +308,7 @@ impl BenchTarget for MetadataAggregationBenchmark {
         let duration_ms = total_duration.as_secs_f64() * 1000.0;
         let records_per_second = self.record_count as f64 / total_duration.as_secs_f64();
 
-        let metrics = json!({
+        let mut metrics = json!({
             "duration_ms": duration_ms,
             "record_count": self.record_count,
             "generation_time_ms": generation_time.as_secs_f64() * 1000.0,
@@ -291,10 +320,56 @@ impl BenchTarget for MetadataAggregationBenchmark {
             "unique_categories": unique_categories
         });
 
+        // Omitted on platforms where peak memory can't be read
+        if let Some(peak_memory_bytes) = peak_memory_bytes() {
+            metrics["peak_memory_bytes"] = json!(peak_memory_bytes);
+        }
+
         Ok(BenchmarkResult::new(self.id().to_string(), metrics))
     }
 }
 
+/// Resets the process's peak resident set size so the next reading covers
+/// only the work that follows.
+///
+/// Best-effort: failures (unsupported kernel, restricted `/proc`) leave the
+/// lifetime peak in place.
+fn reset_peak_memory() {
+    #[cfg(target_os = "linux")]
+    {
+        let _ = std::fs::write("/proc/self/clear_refs", "5");
+    }
+}
+
+/// Returns the process's peak resident set size in bytes, or `None` if it
+/// can't be determined on this platform.
+fn peak_memory_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        parse_vm_hwm(&status)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Parses the `VmHWM` (peak RSS) line of `/proc/self/status` into bytes.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_vm_hwm(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kib: u64 = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
 /// Benchmark for leaderboard recomputation latency.
 ///
 /// Measures the performance of recomputing leaderboard rankings,
@@ -594,6 +669,21 @@ mod tests {
         assert!(result.metrics["record_count"].as_u64().unwrap() == 100);
     }
 
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_metadata_aggregation_reports_peak_memory() {
+        let benchmark = MetadataAggregationBenchmark::with_count(100);
+        let result = benchmark.run().await.unwrap();
+        assert!(result.metrics["peak_memory_bytes"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_parse_vm_hwm() {
+        let status = "Name:\tbench\nVmPeak:\t  20000 kB\nVmHWM:\t    1234 kB\nVmRSS:\t 1000 kB\n";
+        assert_eq!(parse_vm_hwm(status), Some(1234 * 1024));
+        assert_eq!(parse_vm_hwm("Name:\tbench\n"), None);
+    }
+
     #[tokio::test]
     async fn test_leaderboard_recomputation_benchmark() {
         let benchmark = LeaderboardRecomputationBenchmark::with_count(100);
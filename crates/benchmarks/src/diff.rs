@@ -0,0 +1,374 @@
+//! Comparison of two benchmark result sets.
+//!
+//! This module diffs the results of two benchmark runs per `target_id`, so a
+//! rerun can be checked against an earlier baseline.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::result::BenchmarkResult;
+
+/// Differences between two sets of benchmark results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultsDiff {
+    /// Targets present only in the new results, sorted by ID.
+    pub added: Vec<String>,
+
+    /// Targets present only in the old results, sorted by ID.
+    pub removed: Vec<String>,
+
+    /// Targets present in both, sorted by ID.
+    pub targets: Vec<TargetDiff>,
+}
+
+/// Comparison of a single target's metrics between two runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetDiff {
+    /// Benchmark target ID.
+    pub target_id: String,
+
+    /// Outcome of comparing the two runs' metrics.
+    pub comparison: MetricsComparison,
+}
+
+/// Outcome of comparing two metrics values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MetricsComparison {
+    /// Both runs reported metrics of the same shape.
+    Comparable {
+        /// One entry per numeric metric, sorted by metric path.
+        deltas: Vec<MetricDelta>,
+    },
+
+    /// The runs reported metrics of different shapes.
+    Incomparable {
+        /// Which metric paths differ.
+        reason: String,
+    },
+}
+
+/// Change in a single numeric metric.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta {
+    /// Dotted path to the metric, e.g. `latency.p99_ms` or `samples.0`.
+    pub metric: String,
+
+    /// Value in the old results.
+    pub old: f64,
+
+    /// Value in the new results.
+    pub new: f64,
+
+    /// `new - old`.
+    pub delta: f64,
+
+    /// Change relative to the old value, in percent.
+    ///
+    /// `None` when the old value is zero.
+    pub percent_change: Option<f64>,
+}
+
+impl ResultsDiff {
+    /// Returns `true` if no target was added or removed and no metric changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.targets.iter().all(|target| match &target.comparison {
+                MetricsComparison::Comparable { deltas } => deltas.iter().all(|d| d.delta == 0.0),
+                MetricsComparison::Incomparable { .. } => false,
+            })
+    }
+}
+
+/// Compares two benchmark result sets per `target_id`.
+///
+/// If a set holds several results for one target, the newest is used.
+///
+/// # Arguments
+///
+/// * `old` - Results of the baseline run
+/// * `new` - Results of the run being compared
+///
+/// # Returns
+///
+/// A `ResultsDiff` listing added and removed targets and, for targets in
+/// both sets, the change in every numeric metric.
+///
+/// # Example
+///
+/// ```rust
+/// use llm_benchmark_benchmarks::{diff::diff_results, BenchmarkResult};
+/// use serde_json::json;
+///
+/// let old = vec![BenchmarkResult::new("ingest".to_string(), json!({"duration_ms": 200}))];
+/// let new = vec![BenchmarkResult::new("ingest".to_string(), json!({"duration_ms": 150}))];
+///
+/// let diff = diff_results(&old, &new);
+/// assert!(diff.added.is_empty() && diff.removed.is_empty());
+/// ```
+pub fn diff_results(old: &[BenchmarkResult], new: &[BenchmarkResult]) -> ResultsDiff {
+    let old = latest_by_target(old);
+    let new = latest_by_target(new);
+
+    let added = new
+        .keys()
+        .filter(|id| !old.contains_key(*id))
+        .map(|id| id.to_string())
+        .collect();
+    let removed = old
+        .keys()
+        .filter(|id| !new.contains_key(*id))
+        .map(|id| id.to_string())
+        .collect();
+    let targets = old
+        .iter()
+        .filter_map(|(id, old)| {
+            let new = new.get(id)?;
+            Some(TargetDiff {
+                target_id: id.to_string(),
+                comparison: compare_metrics(&old.metrics, &new.metrics),
+            })
+        })
+        .collect();
+
+    ResultsDiff {
+        added,
+        removed,
+        targets,
+    }
+}
+
+/// Compares two metrics values of the same target.
+///
+/// The values are comparable when they have the same shape: the same set of
+/// paths to leaf values, with the same JSON type at each path. Non-numeric
+/// leaves must match in type but are not reported as deltas.
+pub fn compare_metrics(old: &serde_json::Value, new: &serde_json::Value) -> MetricsComparison {
+    let old = flatten(old);
+    let new = flatten(new);
+
+    let mut mismatched: Vec<String> = Vec::new();
+    for (path, old_value) in &old {
+        match new.get(path) {
+            None => mismatched.push(format!("`{}` missing from new results", display(path))),
+            Some(new_value) if kind(old_value) != kind(new_value) => mismatched.push(format!(
+                "`{}` changed from {} to {}",
+                display(path),
+                kind(old_value),
+                kind(new_value)
+            )),
+            Some(_) => {}
+        }
+    }
+    for path in new.keys().filter(|path| !old.contains_key(*path)) {
+        mismatched.push(format!("`{}` missing from old results", display(path)));
+    }
+
+    if !mismatched.is_empty() {
+        return MetricsComparison::Incomparable {
+            reason: mismatched.join(", "),
+        };
+    }
+
+    let deltas = old
+        .iter()
+        .filter_map(|(path, old_value)| {
+            let old = old_value.as_f64()?;
+            let new = new[path].as_f64()?;
+            let delta = new - old;
+            Some(MetricDelta {
+                metric: display(path),
+                old,
+                new,
+                delta,
+                percent_change: (old != 0.0).then(|| delta / old.abs() * 100.0),
+            })
+        })
+        .collect();
+
+    MetricsComparison::Comparable { deltas }
+}
+
+/// The newest result for each target, keyed by target ID.
+fn latest_by_target(results: &[BenchmarkResult]) -> BTreeMap<&str, &BenchmarkResult> {
+    let mut latest: BTreeMap<&str, &BenchmarkResult> = BTreeMap::new();
+    for result in results {
+        latest
+            .entry(result.target_id.as_str())
+            .and_modify(|current| {
+                if result.timestamp > current.timestamp {
+                    *current = result;
+                }
+            })
+            .or_insert(result);
+    }
+    latest
+}
+
+/// Flattens a JSON value into its leaves, keyed by path.
+///
+/// Empty objects and arrays count as leaves so that they still take part in
+/// the shape comparison.
+fn flatten(value: &serde_json::Value) -> BTreeMap<Vec<String>, &serde_json::Value> {
+    fn walk<'a>(
+        value: &'a serde_json::Value,
+        path: &mut Vec<String>,
+        out: &mut BTreeMap<Vec<String>, &'a serde_json::Value>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) if !map.is_empty() => {
+                for (key, child) in map {
+                    path.push(key.clone());
+                    walk(child, path, out);
+                    path.pop();
+                }
+            }
+            serde_json::Value::Array(items) if !items.is_empty() => {
+                for (index, child) in items.iter().enumerate() {
+                    path.push(index.to_string());
+                    walk(child, path, out);
+                    path.pop();
+                }
+            }
+            leaf => {
+                out.insert(path.clone(), leaf);
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk(value, &mut Vec::new(), &mut out);
+    out
+}
+
+/// The JSON type of a value, for shape comparison and messages.
+fn kind(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+/// Renders a leaf path, using `.` for the metrics value itself.
+fn display(path: &[String]) -> String {
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path.join(".")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use serde_json::json;
+
+    fn result(target_id: &str, metrics: serde_json::Value) -> BenchmarkResult {
+        BenchmarkResult::new(target_id.to_string(), metrics)
+    }
+
+    fn deltas(comparison: &MetricsComparison) -> &[MetricDelta] {
+        match comparison {
+            MetricsComparison::Comparable { deltas } => deltas,
+            MetricsComparison::Incomparable { reason } => panic!("incomparable: {}", reason),
+        }
+    }
+
+    #[test]
+    fn test_diff_added_removed_and_changed_targets() {
+        let old = vec![
+            result("ingest", json!({"duration_ms": 200, "items": 1000, "mode": "batch"})),
+            result("retired", json!({"duration_ms": 5})),
+        ];
+        let new = vec![
+            result("ingest", json!({"duration_ms": 150, "items": 1000, "mode": "stream"})),
+            result("fresh", json!({"duration_ms": 7})),
+        ];
+
+        let diff = diff_results(&old, &new);
+
+        assert_eq!(diff.added, vec!["fresh"]);
+        assert_eq!(diff.removed, vec!["retired"]);
+        assert_eq!(diff.targets.len(), 1);
+        assert_eq!(diff.targets[0].target_id, "ingest");
+
+        // Strings take part in the shape but are not deltas
+        let deltas = deltas(&diff.targets[0].comparison);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].metric, "duration_ms");
+        assert_eq!(deltas[0].delta, -50.0);
+        assert_eq!(deltas[0].percent_change, Some(-25.0));
+        assert_eq!(deltas[1].metric, "items");
+        assert_eq!(deltas[1].percent_change, Some(0.0));
+        assert!(!diff.is_unchanged());
+    }
+
+    #[test]
+    fn test_nested_metrics_and_zero_baseline() {
+        let comparison = compare_metrics(
+            &json!({"latency": {"p50_ms": 10.0, "p99_ms": 40.0}, "errors": 0, "samples": [1, 2]}),
+            &json!({"latency": {"p50_ms": 12.0, "p99_ms": 30.0}, "errors": 3, "samples": [1, 4]}),
+        );
+
+        let deltas = deltas(&comparison);
+        let metrics: Vec<&str> = deltas.iter().map(|d| d.metric.as_str()).collect();
+        assert_eq!(
+            metrics,
+            vec!["errors", "latency.p50_ms", "latency.p99_ms", "samples.0", "samples.1"]
+        );
+        assert_eq!(deltas[0].delta, 3.0);
+        assert_eq!(deltas[0].percent_change, None);
+        assert_eq!(deltas[1].percent_change, Some(20.0));
+        assert_eq!(deltas[2].percent_change, Some(-25.0));
+        assert_eq!(deltas[4].percent_change, Some(100.0));
+    }
+
+    #[test]
+    fn test_different_shapes_are_incomparable() {
+        let cases = [
+            (json!({"duration_ms": 1}), json!({"duration_ms": 1, "items": 2}), "`items`"),
+            (json!({"duration_ms": 1}), json!({"duration_ms": "1"}), "number to string"),
+            (json!({"latency": {"p50": 1}}), json!({"latency": 1}), "`latency.p50`"),
+            (json!({"samples": [1, 2]}), json!({"samples": [1]}), "`samples.1`"),
+            (json!(1), json!({"duration_ms": 1}), "`.`"),
+        ];
+
+        for (old, new, expected) in cases {
+            match compare_metrics(&old, &new) {
+                MetricsComparison::Incomparable { reason } => {
+                    assert!(reason.contains(expected), "{} does not mention {}", reason, expected)
+                }
+                comparable => panic!("{} vs {} compared as {:?}", old, new, comparable),
+            }
+        }
+    }
+
+    #[test]
+    fn test_newest_result_per_target_is_used() {
+        let now = Utc::now();
+        let old = vec![result("ingest", json!({"duration_ms": 100}))];
+        let new = vec![
+            BenchmarkResult::with_timestamp(
+                "ingest".to_string(),
+                json!({"duration_ms": 100}),
+                now,
+            ),
+            BenchmarkResult::with_timestamp(
+                "ingest".to_string(),
+                json!({"duration_ms": 999}),
+                now - Duration::hours(1),
+            ),
+        ];
+
+        let diff = diff_results(&old, &new);
+        assert!(diff.is_unchanged());
+        assert!(diff_results(&[], &[]).is_unchanged());
+    }
+}
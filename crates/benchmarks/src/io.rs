@@ -3,6 +3,7 @@
 //! This module provides functions for reading and writing benchmark results
 //! to the canonical output directories.
 
+use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
@@ -21,19 +22,89 @@ pub const RAW_OUTPUT_DIR: &str = "benchmarks/output/raw";
 /// Default summary file name.
 pub const SUMMARY_FILE: &str = "benchmarks/output/summary.md";
 
+/// Default file name prefix for combined results.
+pub const COMBINED_FILE_PREFIX: &str = "benchmark_results";
+
+/// Locations of benchmark output files, relative to a base path.
+///
+/// The default layout matches the canonical `benchmarks/output/...` directories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLayout {
+    /// Directory for combined results.
+    pub output_dir: PathBuf,
+
+    /// Directory for individual raw results.
+    pub raw_dir: PathBuf,
+
+    /// File name prefix for combined results; a timestamp and `.json` are appended.
+    pub combined_file_prefix: String,
+
+    /// Path of the markdown summary file.
+    pub summary_file: PathBuf,
+}
+
+impl Default for OutputLayout {
+    fn default() -> Self {
+        Self {
+            output_dir: PathBuf::from(DEFAULT_OUTPUT_DIR),
+            raw_dir: PathBuf::from(RAW_OUTPUT_DIR),
+            combined_file_prefix: COMBINED_FILE_PREFIX.to_string(),
+            summary_file: PathBuf::from(SUMMARY_FILE),
+        }
+    }
+}
+
+impl OutputLayout {
+    /// Creates a layout with every file placed under a single directory.
+    ///
+    /// Raw results go to `<dir>/raw` and the summary to `<dir>/summary.md`.
+    pub fn under(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        Self {
+            raw_dir: dir.join("raw"),
+            summary_file: dir.join("summary.md"),
+            output_dir: dir,
+            combined_file_prefix: COMBINED_FILE_PREFIX.to_string(),
+        }
+    }
+
+    /// Resolves the combined results directory against a base path.
+    pub fn output_dir_in(&self, base_path: Option<&Path>) -> PathBuf {
+        resolve(base_path, &self.output_dir)
+    }
+
+    /// Resolves the raw results directory against a base path.
+    pub fn raw_dir_in(&self, base_path: Option<&Path>) -> PathBuf {
+        resolve(base_path, &self.raw_dir)
+    }
+
+    /// Resolves the summary file against a base path.
+    pub fn summary_file_in(&self, base_path: Option<&Path>) -> PathBuf {
+        resolve(base_path, &self.summary_file)
+    }
+}
+
+fn resolve(base_path: Option<&Path>, path: &Path) -> PathBuf {
+    base_path.unwrap_or(Path::new(".")).join(path)
+}
+
 /// Writes a single benchmark result to the raw output directory.
 ///
 /// # Arguments
 ///
 /// * `result` - The benchmark result to write
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// The path to the written file on success.
-pub fn write_result(result: &BenchmarkResult, base_path: Option<&Path>) -> Result<PathBuf> {
-    let base = base_path.unwrap_or(Path::new("."));
-    let raw_dir = base.join(RAW_OUTPUT_DIR);
+pub fn write_result(
+    result: &BenchmarkResult,
+    base_path: Option<&Path>,
+    layout: &OutputLayout,
+) -> Result<PathBuf> {
+    let raw_dir = layout.raw_dir_in(base_path);
 
     // Ensure the directory exists
     fs::create_dir_all(&raw_dir)
@@ -63,14 +134,19 @@ pub fn write_result(result: &BenchmarkResult, base_path: Option<&Path>) -> Resul
 ///
 /// * `results` - The benchmark results to write
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// A vector of paths to the written files on success.
-pub fn write_results(results: &[BenchmarkResult], base_path: Option<&Path>) -> Result<Vec<PathBuf>> {
+pub fn write_results(
+    results: &[BenchmarkResult],
+    base_path: Option<&Path>,
+    layout: &OutputLayout,
+) -> Result<Vec<PathBuf>> {
     results
         .iter()
-        .map(|r| write_result(r, base_path))
+        .map(|r| write_result(r, base_path, layout))
         .collect()
 }
 
@@ -80,20 +156,24 @@ pub fn write_results(results: &[BenchmarkResult], base_path: Option<&Path>) -> R
 ///
 /// * `results` - The benchmark results to write
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// The path to the written file on success.
-pub fn write_combined_results(results: &[BenchmarkResult], base_path: Option<&Path>) -> Result<PathBuf> {
-    let base = base_path.unwrap_or(Path::new("."));
-    let output_dir = base.join(DEFAULT_OUTPUT_DIR);
+pub fn write_combined_results(
+    results: &[BenchmarkResult],
+    base_path: Option<&Path>,
+    layout: &OutputLayout,
+) -> Result<PathBuf> {
+    let output_dir = layout.output_dir_in(base_path);
 
     // Ensure the directory exists
     fs::create_dir_all(&output_dir)
         .with_context(|| format!("Failed to create directory: {}", output_dir.display()))?;
 
     let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-    let filename = format!("benchmark_results_{}.json", timestamp);
+    let filename = format!("{}_{}.json", layout.combined_file_prefix, timestamp);
     let file_path = output_dir.join(&filename);
 
     let file = File::create(&file_path)
@@ -108,6 +188,63 @@ pub fn write_combined_results(results: &[BenchmarkResult], base_path: Option<&Pa
     Ok(file_path)
 }
 
+/// Merges combined results files produced by sharded benchmark runs.
+///
+/// Results are de-duplicated by `target_id`, keeping the one with the latest
+/// `timestamp`. The merged results are sorted newest first.
+///
+/// # Arguments
+///
+/// * `paths` - Paths to combined JSON files written by [`write_combined_results`]
+///
+/// # Returns
+///
+/// The merged benchmark results.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or parsed, or if two files report
+/// different metrics for the same target and timestamp.
+pub fn merge_combined_results(paths: &[&Path]) -> Result<Vec<BenchmarkResult>> {
+    let mut merged: HashMap<String, BenchmarkResult> = HashMap::new();
+
+    for path in paths {
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open file: {}", path.display()))?;
+        let results: Vec<BenchmarkResult> = serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("Failed to parse combined results from: {}", path.display()))?;
+
+        for result in results {
+            match merged.get(&result.target_id) {
+                Some(existing) if existing.timestamp > result.timestamp => {}
+                Some(existing)
+                    if existing.timestamp == result.timestamp
+                        && existing.metrics != result.metrics =>
+                {
+                    anyhow::bail!(
+                        "Conflicting metrics for target '{}' at {} in {}",
+                        result.target_id,
+                        result.timestamp,
+                        path.display()
+                    );
+                }
+                _ => {
+                    merged.insert(result.target_id.clone(), result);
+                }
+            }
+        }
+    }
+
+    let mut results: Vec<BenchmarkResult> = merged.into_values().collect();
+    results.sort_by(|a, b| {
+        b.timestamp
+            .cmp(&a.timestamp)
+            .then_with(|| a.target_id.cmp(&b.target_id))
+    });
+
+    Ok(results)
+}
+
 /// Reads a benchmark result from a JSON file.
 ///
 /// # Arguments
@@ -131,13 +268,16 @@ pub fn read_result(path: &Path) -> Result<BenchmarkResult> {
 /// # Arguments
 ///
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// A vector of all benchmark results found in the directory.
-pub fn read_all_results(base_path: Option<&Path>) -> Result<Vec<BenchmarkResult>> {
-    let base = base_path.unwrap_or(Path::new("."));
-    let raw_dir = base.join(RAW_OUTPUT_DIR);
+pub fn read_all_results(
+    base_path: Option<&Path>,
+    layout: &OutputLayout,
+) -> Result<Vec<BenchmarkResult>> {
+    let raw_dir = layout.raw_dir_in(base_path);
 
     if !raw_dir.exists() {
         return Ok(Vec::new());
@@ -167,20 +307,19 @@ pub fn read_all_results(base_path: Option<&Path>) -> Result<Vec<BenchmarkResult>
     Ok(results)
 }
 
-/// Ensures the canonical output directories exist.
+/// Ensures the output directories of a layout exist.
 ///
 /// # Arguments
 ///
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// `Ok(())` if directories were created or already exist.
-pub fn ensure_output_dirs(base_path: Option<&Path>) -> Result<()> {
-    let base = base_path.unwrap_or(Path::new("."));
-
-    let output_dir = base.join(DEFAULT_OUTPUT_DIR);
-    let raw_dir = base.join(RAW_OUTPUT_DIR);
+pub fn ensure_output_dirs(base_path: Option<&Path>, layout: &OutputLayout) -> Result<()> {
+    let output_dir = layout.output_dir_in(base_path);
+    let raw_dir = layout.raw_dir_in(base_path);
 
     fs::create_dir_all(&output_dir)
         .with_context(|| format!("Failed to create directory: {}", output_dir.display()))?;
@@ -204,7 +343,7 @@ mod tests {
             json!({"duration_ms": 100}),
         );
 
-        let path = write_result(&result, Some(temp_dir.path())).unwrap();
+        let path = write_result(&result, Some(temp_dir.path()), &OutputLayout::default()).unwrap();
         assert!(path.exists());
 
         let read_back = read_result(&path).unwrap();
@@ -215,9 +354,130 @@ mod tests {
     #[test]
     fn test_ensure_output_dirs() {
         let temp_dir = TempDir::new().unwrap();
-        ensure_output_dirs(Some(temp_dir.path())).unwrap();
+        ensure_output_dirs(Some(temp_dir.path()), &OutputLayout::default()).unwrap();
 
         assert!(temp_dir.path().join(DEFAULT_OUTPUT_DIR).exists());
         assert!(temp_dir.path().join(RAW_OUTPUT_DIR).exists());
     }
+
+    #[test]
+    fn test_custom_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout = OutputLayout {
+            output_dir: PathBuf::from("out"),
+            raw_dir: PathBuf::from("out/results"),
+            combined_file_prefix: "combined".to_string(),
+            summary_file: PathBuf::from("docs/BENCHMARKS.md"),
+        };
+        let base = Some(temp_dir.path());
+        let results = vec![
+            BenchmarkResult::new("first".to_string(), json!({"duration_ms": 1})),
+            BenchmarkResult::new("second".to_string(), json!({"duration_ms": 2})),
+        ];
+
+        ensure_output_dirs(base, &layout).unwrap();
+        let paths = write_results(&results, base, &layout).unwrap();
+        let combined = write_combined_results(&results, base, &layout).unwrap();
+
+        for path in &paths {
+            assert_eq!(path.parent().unwrap(), temp_dir.path().join("out/results"));
+        }
+        assert_eq!(combined.parent().unwrap(), temp_dir.path().join("out"));
+        assert!(combined
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("combined_"));
+        assert_eq!(read_all_results(base, &layout).unwrap().len(), 2);
+
+        // Nothing lands in the default locations
+        assert!(!temp_dir.path().join(DEFAULT_OUTPUT_DIR).exists());
+        assert!(read_all_results(base, &OutputLayout::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    fn result_at(target_id: &str, metrics: serde_json::Value, secs: i64) -> BenchmarkResult {
+        BenchmarkResult {
+            target_id: target_id.to_string(),
+            metrics,
+            timestamp: chrono::DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap(),
+        }
+    }
+
+    fn write_partial(dir: &Path, name: &str, results: &[BenchmarkResult]) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, serde_json::to_string(results).unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_merge_combined_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let shard_a = write_partial(
+            temp_dir.path(),
+            "a.json",
+            &[
+                result_at("alpha", json!({"duration_ms": 10}), 0),
+                result_at("shared", json!({"duration_ms": 20}), 0),
+            ],
+        );
+        let shard_b = write_partial(
+            temp_dir.path(),
+            "b.json",
+            &[
+                result_at("shared", json!({"duration_ms": 25}), 60),
+                result_at("beta", json!({"duration_ms": 30}), 30),
+            ],
+        );
+
+        let merged = merge_combined_results(&[&shard_a, &shard_b]).unwrap();
+        let ids: Vec<&str> = merged.iter().map(|r| r.target_id.as_str()).collect();
+        assert_eq!(ids, ["shared", "beta", "alpha"]);
+        assert_eq!(merged[0].metrics, json!({"duration_ms": 25}));
+
+        // Order of the inputs doesn't matter
+        let merged = merge_combined_results(&[&shard_b, &shard_a]).unwrap();
+        assert_eq!(merged[0].metrics, json!({"duration_ms": 25}));
+    }
+
+    #[test]
+    fn test_merge_identical_duplicates() {
+        let temp_dir = TempDir::new().unwrap();
+        let results = [result_at("alpha", json!({"duration_ms": 10}), 0)];
+        let shard_a = write_partial(temp_dir.path(), "a.json", &results);
+        let shard_b = write_partial(temp_dir.path(), "b.json", &results);
+
+        let merged = merge_combined_results(&[&shard_a, &shard_b]).unwrap();
+        assert_eq!(merged.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_conflicting_metrics() {
+        let temp_dir = TempDir::new().unwrap();
+        let shard_a = write_partial(
+            temp_dir.path(),
+            "a.json",
+            &[result_at("alpha", json!({"duration_ms": 10}), 0)],
+        );
+        let shard_b = write_partial(
+            temp_dir.path(),
+            "b.json",
+            &[result_at("alpha", json!({"duration_ms": 11}), 0)],
+        );
+
+        let err = merge_combined_results(&[&shard_a, &shard_b]).unwrap_err();
+        assert!(err.to_string().contains("Conflicting metrics for target 'alpha'"));
+    }
+
+    #[test]
+    fn test_layout_under() {
+        let layout = OutputLayout::under("target/bench");
+        assert_eq!(layout.raw_dir, PathBuf::from("target/bench/raw"));
+        assert_eq!(layout.summary_file, PathBuf::from("target/bench/summary.md"));
+        assert_eq!(
+            layout.output_dir_in(Some(Path::new("/repo"))),
+            PathBuf::from("/repo/target/bench")
+        );
+    }
 }
@@ -11,6 +11,7 @@
 //! - **result**: The canonical `BenchmarkResult` struct with `target_id`, `metrics`, and `timestamp` fields
 //! - **io**: I/O operations for reading and writing benchmark results
 //! - **markdown**: Markdown generation for benchmark reports
+//! - **diff**: Comparison of two benchmark result sets
 //! - **adapters**: The `BenchTarget` trait and target registry
 //!
 //! ## Usage
@@ -49,12 +50,14 @@
 pub mod result;
 pub mod io;
 pub mod markdown;
+pub mod diff;
 pub mod adapters;
 
 use anyhow::Result;
 
 pub use result::BenchmarkResult;
-pub use adapters::{BenchTarget, all_targets, get_target, target_ids};
+pub use diff::{diff_results, ResultsDiff};
+pub use adapters::{BenchTarget, all_targets, get_target, run_target, target_ids};
 
 /// Runs all registered benchmark targets and returns their results.
 ///
@@ -67,7 +70,8 @@ pub use adapters::{BenchTarget, all_targets, get_target, target_ids};
 ///
 /// # Errors
 ///
-/// Returns an error if any benchmark target fails to execute.
+/// Returns an error if any benchmark target fails to execute, or reports
+/// metrics that do not match its [`BenchTarget::metrics_schema`].
 ///
 /// # Example
 ///
@@ -90,7 +94,7 @@ pub async fn run_all_benchmarks() -> Result<Vec<BenchmarkResult>> {
     let mut results = Vec::with_capacity(targets.len());
 
     for target in targets {
-        let result = target.run().await?;
+        let result = run_target(target.as_ref()).await?;
         results.push(result);
     }
 
@@ -127,20 +131,24 @@ pub async fn run_benchmark(target_id: &str) -> Result<BenchmarkResult> {
     let target = get_target(target_id)
         .ok_or_else(|| anyhow::anyhow!("Benchmark target not found: {}", target_id))?;
 
-    target.run().await
+    run_target(target.as_ref()).await
 }
 
-/// Runs all benchmarks and writes results to the canonical output directories.
+/// Runs all benchmarks and writes results to the output directories.
 ///
 /// This function:
 /// 1. Runs all registered benchmark targets
-/// 2. Writes individual results to `benchmarks/output/raw/`
-/// 3. Writes a combined JSON file to `benchmarks/output/`
-/// 4. Generates and writes a markdown summary to `benchmarks/output/summary.md`
+/// 2. Writes individual results to the layout's raw directory
+///    (`benchmarks/output/raw/` by default)
+/// 3. Writes a combined JSON file to the layout's output directory
+///    (`benchmarks/output/` by default)
+/// 4. Generates and writes a markdown summary to the layout's summary file
+///    (`benchmarks/output/summary.md` by default)
 ///
 /// # Arguments
 ///
 /// * `base_path` - Optional base path for output (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
@@ -153,31 +161,35 @@ pub async fn run_benchmark(target_id: &str) -> Result<BenchmarkResult> {
 /// # Example
 ///
 /// ```rust,no_run
-/// use llm_benchmark_benchmarks::run_all_benchmarks_with_output;
+/// use llm_benchmark_benchmarks::{io::OutputLayout, run_all_benchmarks_with_output};
 /// use std::path::Path;
 ///
 /// #[tokio::main]
 /// async fn main() -> anyhow::Result<()> {
-///     let results = run_all_benchmarks_with_output(Some(Path::new("."))).await?;
+///     let layout = OutputLayout::default();
+///     let results = run_all_benchmarks_with_output(Some(Path::new(".")), &layout).await?;
 ///     println!("Completed {} benchmarks", results.len());
 ///     Ok(())
 /// }
 /// ```
-pub async fn run_all_benchmarks_with_output(base_path: Option<&std::path::Path>) -> Result<Vec<BenchmarkResult>> {
+pub async fn run_all_benchmarks_with_output(
+    base_path: Option<&std::path::Path>,
+    layout: &io::OutputLayout,
+) -> Result<Vec<BenchmarkResult>> {
     // Ensure output directories exist
-    io::ensure_output_dirs(base_path)?;
+    io::ensure_output_dirs(base_path, layout)?;
 
     // Run all benchmarks
     let results = run_all_benchmarks().await?;
 
     // Write individual results
-    io::write_results(&results, base_path)?;
+    io::write_results(&results, base_path, layout)?;
 
     // Write combined results
-    io::write_combined_results(&results, base_path)?;
+    io::write_combined_results(&results, base_path, layout)?;
 
     // Generate and write summary
-    markdown::write_summary(&results, base_path)?;
+    markdown::write_summary(&results, base_path, layout)?;
 
     Ok(results)
 }
@@ -208,4 +220,21 @@ mod tests {
         let result = run_benchmark("nonexistent-benchmark").await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_run_all_benchmarks_with_custom_layout() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let layout = io::OutputLayout::under("bench-out");
+
+        let results = run_all_benchmarks_with_output(Some(temp_dir.path()), &layout)
+            .await
+            .unwrap();
+
+        let raw_files = std::fs::read_dir(temp_dir.path().join("bench-out/raw"))
+            .unwrap()
+            .count();
+        assert_eq!(raw_files, results.len());
+        assert!(temp_dir.path().join("bench-out/summary.md").exists());
+        assert!(!temp_dir.path().join(io::DEFAULT_OUTPUT_DIR).exists());
+    }
 }
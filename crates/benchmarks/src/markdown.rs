@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use chrono::Utc;
 
-use crate::io::SUMMARY_FILE;
+use crate::io::OutputLayout;
 use crate::result::BenchmarkResult;
 
 /// Generates a markdown summary of benchmark results.
@@ -143,19 +143,23 @@ fn format_metric_value(key: &str, value: &serde_json::Value) -> String {
     }
 }
 
-/// Writes a markdown summary to the canonical summary file.
+/// Writes a markdown summary to the layout's summary file.
 ///
 /// # Arguments
 ///
 /// * `results` - The benchmark results to summarize
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// The path to the written file on success.
-pub fn write_summary(results: &[BenchmarkResult], base_path: Option<&Path>) -> Result<PathBuf> {
-    let base = base_path.unwrap_or(Path::new("."));
-    let summary_path = base.join(SUMMARY_FILE);
+pub fn write_summary(
+    results: &[BenchmarkResult],
+    base_path: Option<&Path>,
+    layout: &OutputLayout,
+) -> Result<PathBuf> {
+    let summary_path = layout.summary_file_in(base_path);
 
     // Ensure parent directory exists
     if let Some(parent) = summary_path.parent() {
@@ -183,17 +187,21 @@ pub fn write_summary(results: &[BenchmarkResult], base_path: Option<&Path>) -> R
 ///
 /// * `results` - The new benchmark results to append
 /// * `base_path` - Optional base path (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
 /// The path to the updated file on success.
-pub fn append_to_summary(results: &[BenchmarkResult], base_path: Option<&Path>) -> Result<PathBuf> {
-    let base = base_path.unwrap_or(Path::new("."));
-    let summary_path = base.join(SUMMARY_FILE);
+pub fn append_to_summary(
+    results: &[BenchmarkResult],
+    base_path: Option<&Path>,
+    layout: &OutputLayout,
+) -> Result<PathBuf> {
+    let summary_path = layout.summary_file_in(base_path);
 
     // If file doesn't exist, just write a new one
     if !summary_path.exists() {
-        return write_summary(results, base_path);
+        return write_summary(results, base_path, layout);
     }
 
     // Read existing content
@@ -271,10 +279,27 @@ mod tests {
             json!({"value": 42}),
         )];
 
-        let path = write_summary(&results, Some(temp_dir.path())).unwrap();
+        let path = write_summary(&results, Some(temp_dir.path()), &OutputLayout::default()).unwrap();
         assert!(path.exists());
 
         let content = fs::read_to_string(&path).unwrap();
         assert!(content.contains("test"));
     }
+
+    #[test]
+    fn test_write_summary_custom_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout = OutputLayout {
+            summary_file: PathBuf::from("reports/bench.md"),
+            ..OutputLayout::default()
+        };
+        let results = vec![BenchmarkResult::new(
+            "test".to_string(),
+            json!({"value": 42}),
+        )];
+
+        let path = write_summary(&results, Some(temp_dir.path()), &layout).unwrap();
+        assert_eq!(path, temp_dir.path().join("reports/bench.md"));
+        assert!(path.exists());
+    }
 }
@@ -130,17 +130,21 @@ pub async fn run_benchmark(target_id: &str) -> Result<BenchmarkResult> {
     target.run().await
 }
 
-/// Runs all benchmarks and writes results to the canonical output directories.
+/// Runs all benchmarks and writes results to the output directories.
 ///
 /// This function:
 /// 1. Runs all registered benchmark targets
-/// 2. Writes individual results to `benchmarks/output/raw/`
-/// 3. Writes a combined JSON file to `benchmarks/output/`
-/// 4. Generates and writes a markdown summary to `benchmarks/output/summary.md`
+/// 2. Writes individual results to the layout's raw directory
+///    (`benchmarks/output/raw/` by default)
+/// 3. Writes a combined JSON file to the layout's output directory
+///    (`benchmarks/output/` by default)
+/// 4. Generates and writes a markdown summary to the layout's summary file
+///    (`benchmarks/output/summary.md` by default)
 ///
 /// # Arguments
 ///
 /// * `base_path` - Optional base path for output (defaults to current directory)
+/// * `layout` - Output directory layout
 ///
 /// # Returns
 ///
@@ -153,31 +157,35 @@ pub async fn run_benchmark(target_id: &str) -> Result<BenchmarkResult> {
 /// # Example
 ///
 /// ```rust
-/// use llm_benchmark_benchmarks::run_all_benchmarks_with_output;
+/// use llm_benchmark_benchmarks::{io::OutputLayout, run_all_benchmarks_with_output};
 /// use std::path::Path;
 ///
 /// #[tokio::main]
 /// async fn main() -> anyhow::Result<()> {
-///     let results = run_all_benchmarks_with_output(Some(Path::new("."))).await?;
+///     let layout = OutputLayout::default();
+///     let results = run_all_benchmarks_with_output(Some(Path::new(".")), &layout).await?;
 ///     println!("Completed {} benchmarks", results.len());
 ///     Ok(())
 /// }
 /// ```
-pub async fn run_all_benchmarks_with_output(base_path: Option<&std::path::Path>) -> Result<Vec<BenchmarkResult>> {
+pub async fn run_all_benchmarks_with_output(
+    base_path: Option<&std::path::Path>,
+    layout: &io::OutputLayout,
+) -> Result<Vec<BenchmarkResult>> {
     // Ensure output directories exist
-    io::ensure_output_dirs(base_path)?;
+    io::ensure_output_dirs(base_path, layout)?;
 
     // Run all benchmarks
     let results = run_all_benchmarks().await?;
 
     // Write individual results
-    io::write_results(&results, base_path)?;
+    io::write_results(&results, base_path, layout)?;
 
     // Write combined results
-    io::write_combined_results(&results, base_path)?;
+    io::write_combined_results(&results, base_path, layout)?;
 
     // Generate and write summary
-    markdown::write_summary(&results, base_path)?;
+    markdown::write_summary(&results, base_path, layout)?;
 
     Ok(results)
 }
@@ -208,4 +216,21 @@ mod tests {
         let result = run_benchmark("nonexistent-benchmark").await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_run_all_benchmarks_with_custom_layout() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let layout = io::OutputLayout::under("bench-out");
+
+        let results = run_all_benchmarks_with_output(Some(temp_dir.path()), &layout)
+            .await
+            .unwrap();
+
+        let raw_files = std::fs::read_dir(temp_dir.path().join("bench-out/raw"))
+            .unwrap()
+            .count();
+        assert_eq!(raw_files, results.len());
+        assert!(temp_dir.path().join("bench-out/summary.md").exists());
+        assert!(!temp_dir.path().join(io::DEFAULT_OUTPUT_DIR).exists());
+    }
 }
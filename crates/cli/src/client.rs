@@ -1,7 +1,7 @@
 //! HTTP API client for the LLM Benchmark Exchange
 
 use anyhow::{Context, Result};
-use reqwest::{Client, RequestBuilder, Response};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
 use std::time::Duration;
 
@@ -99,6 +99,24 @@ impl ApiClient {
         self.handle_response(response).await
     }
 
+    /// Check whether a GET of `path` finds anything, treating 404 as absent
+    pub async fn exists(&self, path: &str) -> Result<bool> {
+        let url = format!("{}{}", self.base_url, path);
+        let builder = self.add_headers(self.client.get(&url));
+
+        let response = builder
+            .send()
+            .await
+            .context("Failed to send GET request")?;
+
+        match response.status() {
+            status if status.is_success() => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            // Any other failure is reported like it is for every request
+            _ => self.handle_response::<serde::de::IgnoredAny>(response).await.map(|_| false),
+        }
+    }
+
     /// Make a POST request with JSON body
     pub async fn post<T: Serialize, R: DeserializeOwned>(
         &self,
@@ -2,12 +2,19 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::Path;
 
-use crate::commands::CommandContext;
-use crate::interactive::{confirm_default_yes, spinner};
-use crate::output::{colors, TableFormatter};
+use crate::commands::{resolve_id, stream_ndjson, CommandContext};
+use crate::definition::{load_definition, DefinitionFormat};
+use crate::interactive::{confirm_default_yes, spinner, PickerCandidate};
+use crate::output::{colors, locate_nearest, NdjsonFormatter, OutputFormat, TableFormatter};
+use llm_benchmark_sdk::services::{BenchmarkStats, TestCase};
+use llm_benchmark_domain::version::{BumpKind, SemanticVersion};
+use llm_benchmark_sdk::SdkError;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Benchmark {
@@ -28,6 +35,29 @@ pub struct BenchmarkList {
     pub total: usize,
 }
 
+/// A benchmark definition file, as written by users
+///
+/// Only the fields the CLI needs are typed; everything else is kept as-is
+/// and sent along with the definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkDefinition {
+    pub name: String,
+    pub slug: String,
+    pub description: String,
+    pub category: String,
+    #[serde(flatten)]
+    pub rest: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Load a benchmark definition from a `.json`, `.yaml` or `.yml` file
+///
+/// The format follows the extension, and `$ref` includes are resolved.
+pub fn load_benchmark_definition(path: &Path) -> Result<BenchmarkDefinition> {
+    let definition = load_definition(path)?;
+    serde_json::from_value(definition)
+        .with_context(|| format!("Invalid benchmark definition in {}", path.display()))
+}
+
 #[derive(Debug, Serialize)]
 pub struct BenchmarkCreateRequest {
     pub name: String,
@@ -60,10 +90,21 @@ pub async fn list(
         path.push_str(&format!("status={}&", st));
     }
 
+    if ctx.config.output_format == OutputFormat::Ndjson {
+        sp.finish_and_clear();
+        stream_ndjson(ctx, &path, |list: BenchmarkList| (list.benchmarks, list.total)).await?;
+        return Ok(());
+    }
+
     let list: BenchmarkList = ctx.client.get(&path).await?;
 
     sp.finish_and_clear();
 
+    if ctx.config.output_format == OutputFormat::Json {
+        println!("{}", ctx.config.json_formatter().format(&list)?);
+        return Ok(());
+    }
+
     if list.benchmarks.is_empty() {
         println!("{}", colors::warning("No benchmarks found."));
         return Ok(());
@@ -88,13 +129,30 @@ pub async fn list(
 
     let table = TableFormatter::simple(headers, rows)?;
     println!("{}", table);
-    println!("{} benchmarks found", colors::dim(&list.total.to_string()));
+    println!(
+        "{} benchmarks found",
+        colors::dim(&ctx.config.locale_formatter().format_count(list.total))
+    );
 
     Ok(())
 }
 
 /// Show detailed benchmark information
-pub async fn show(ctx: &CommandContext, id_or_slug: String) -> Result<()> {
+pub async fn show(ctx: &CommandContext, id_or_slug: Option<String>) -> Result<()> {
+    let id_or_slug = resolve_id(
+        ctx,
+        id_or_slug,
+        "/api/v1/benchmarks",
+        "Select a benchmark",
+        |list: BenchmarkList| {
+            list.benchmarks
+                .into_iter()
+                .map(|b| PickerCandidate::new(b.slug, format!("{} ({})", b.name, b.status)))
+                .collect()
+        },
+    )
+    .await?;
+
     let sp = spinner("Fetching benchmark details...");
 
     let benchmark: Benchmark = ctx
@@ -105,7 +163,15 @@ pub async fn show(ctx: &CommandContext, id_or_slug: String) -> Result<()> {
     sp.finish_and_clear();
 
     // Display as key-value table
-    let items = vec![
+    let fmt = ctx.config.locale_formatter();
+    let next_version = SemanticVersion::parse(&benchmark.version).ok().map(|v| {
+        format!(
+            "{} ({} if breaking)",
+            v.bump(BumpKind::for_change(false)),
+            v.bump(BumpKind::for_change(true))
+        )
+    });
+    let mut items = vec![
         ("ID", benchmark.id),
         ("Slug", benchmark.slug),
         ("Name", benchmark.name),
@@ -113,9 +179,12 @@ pub async fn show(ctx: &CommandContext, id_or_slug: String) -> Result<()> {
         ("Category", benchmark.category),
         ("Status", benchmark.status),
         ("Version", benchmark.version),
-        ("Created", benchmark.created_at),
-        ("Updated", benchmark.updated_at),
+        ("Created", fmt.format_timestamp(&benchmark.created_at)),
+        ("Updated", fmt.format_timestamp(&benchmark.updated_at)),
     ];
+    if let Some(next_version) = next_version {
+        items.insert(7, ("Next Version", next_version));
+    }
 
     let table = TableFormatter::key_value(items)?;
     println!("{}", table);
@@ -132,48 +201,13 @@ pub async fn create(ctx: &CommandContext, file_path: String) -> Result<()> {
         anyhow::bail!("File not found: {}", file_path);
     }
 
-    let content = fs::read_to_string(path)
-        .context("Failed to read benchmark definition file")?;
-
-    let definition: serde_json::Value = if file_path.ends_with(".yaml")
-        || file_path.ends_with(".yml")
-    {
-        let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
-            .context("Failed to parse YAML")?;
-        serde_json::to_value(yaml)?
-    } else {
-        serde_json::from_str(&content).context("Failed to parse JSON")?
-    };
-
-    // Extract required fields
-    let name = definition
-        .get("name")
-        .and_then(|v| v.as_str())
-        .context("Missing 'name' field in definition")?
-        .to_string();
-
-    let slug = definition
-        .get("slug")
-        .and_then(|v| v.as_str())
-        .context("Missing 'slug' field in definition")?
-        .to_string();
-
-    let description = definition
-        .get("description")
-        .and_then(|v| v.as_str())
-        .context("Missing 'description' field in definition")?
-        .to_string();
-
-    let category = definition
-        .get("category")
-        .and_then(|v| v.as_str())
-        .context("Missing 'category' field in definition")?
-        .to_string();
+    // Resolves `$ref` includes relative to the definition file
+    let definition = load_benchmark_definition(path)?;
 
     println!("{}", colors::bold("Creating new benchmark:"));
-    println!("  Name:     {}", name);
-    println!("  Slug:     {}", slug);
-    println!("  Category: {}", category);
+    println!("  Name:     {}", definition.name);
+    println!("  Slug:     {}", definition.slug);
+    println!("  Category: {}", definition.category);
     println!();
 
     let confirmed = confirm_default_yes("Create this benchmark?")?;
@@ -185,11 +219,11 @@ pub async fn create(ctx: &CommandContext, file_path: String) -> Result<()> {
     let sp = spinner("Creating benchmark...");
 
     let request = BenchmarkCreateRequest {
-        name,
-        slug,
-        description,
-        category,
-        definition,
+        name: definition.name.clone(),
+        slug: definition.slug.clone(),
+        description: definition.description.clone(),
+        category: definition.category.clone(),
+        definition: serde_json::to_value(&definition)?,
     };
 
     let benchmark: Benchmark = ctx
@@ -220,20 +254,7 @@ pub async fn update(
             anyhow::bail!("File not found: {}", path.display());
         }
 
-        let content = fs::read_to_string(path)
-            .context("Failed to read benchmark definition file")?;
-
-        let def: serde_json::Value = if path.extension().and_then(|s| s.to_str()) == Some("yaml")
-            || path.extension().and_then(|s| s.to_str()) == Some("yml")
-        {
-            let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
-                .context("Failed to parse YAML")?;
-            serde_json::to_value(yaml)?
-        } else {
-            serde_json::from_str(&content).context("Failed to parse JSON")?
-        };
-
-        Some(def)
+        Some(load_definition(path)?)
     } else {
         None
     };
@@ -283,8 +304,193 @@ pub async fn submit_for_review(ctx: &CommandContext, id: String) -> Result<()> {
     Ok(())
 }
 
+/// Show submission statistics for a benchmark
+pub async fn stats(ctx: &CommandContext, id_or_slug: String) -> Result<()> {
+    let sp = spinner("Fetching benchmark statistics...");
+    let stats = fetch_stats(ctx, &id_or_slug).await;
+    sp.finish_and_clear();
+
+    println!("{}", render_stats(ctx, &stats?)?);
+    Ok(())
+}
+
+async fn fetch_stats(ctx: &CommandContext, id_or_slug: &str) -> Result<BenchmarkStats> {
+    let client = ctx.sdk_client()?;
+    match client.benchmarks().stats(id_or_slug).await {
+        Ok(stats) => Ok(stats),
+        Err(SdkError::NotFound { .. }) => anyhow::bail!("Benchmark not found: {}", id_or_slug),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Render benchmark statistics in the configured output format
+fn render_stats(ctx: &CommandContext, stats: &BenchmarkStats) -> Result<String> {
+    match ctx.config.output_format {
+        OutputFormat::Json => ctx.config.json_formatter().format(stats),
+        OutputFormat::Ndjson => NdjsonFormatter::format(stats),
+        OutputFormat::Table => TableFormatter::key_value(stats_items(ctx, stats)),
+        OutputFormat::Plain => Ok(stats_items(ctx, stats)
+            .into_iter()
+            .map(|(label, value)| format!("{}: {}", label, value))
+            .collect::<Vec<_>>()
+            .join("\n")),
+    }
+}
+
+fn stats_items(ctx: &CommandContext, stats: &BenchmarkStats) -> Vec<(&'static str, String)> {
+    let fmt = ctx.config.locale_formatter();
+    vec![
+        ("Submissions", fmt.format_count(stats.total_submissions as usize)),
+        ("Versions", fmt.format_count(stats.version_count as usize)),
+        ("Models", fmt.format_count(stats.unique_models as usize)),
+        ("Providers", fmt.format_count(stats.unique_providers as usize)),
+        ("Average score", fmt.format_decimal(stats.average_score, 4)),
+        (
+            "Latest submission",
+            stats
+                .latest_submission_at
+                .as_ref()
+                .map(|at| fmt.format_datetime(at))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ]
+}
+
+/// Name of the manifest written alongside downloaded test cases
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Contents of a download's `manifest.json`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DownloadManifest {
+    pub benchmark: String,
+    pub version: String,
+    pub test_cases: Vec<ManifestEntry>,
+}
+
+/// A downloaded test case file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub id: String,
+    pub file: String,
+    /// Hex-encoded SHA-256 of the file contents
+    pub sha256: String,
+}
+
+/// Download a benchmark's test cases into a directory
+pub async fn download(
+    ctx: &CommandContext,
+    id_or_slug: String,
+    version: Option<String>,
+    output: String,
+) -> Result<()> {
+    let sp = spinner("Downloading test cases...");
+    let manifest = download_to(ctx, &id_or_slug, version.as_deref(), Path::new(&output)).await;
+    sp.finish_and_clear();
+    let manifest = manifest?;
+
+    if manifest.test_cases.is_empty() {
+        println!(
+            "{}",
+            colors::warning(&format!(
+                "Benchmark {} has no test cases in version {}; wrote an empty manifest.",
+                id_or_slug, manifest.version
+            ))
+        );
+    } else {
+        println!(
+            "{}",
+            colors::success(&format!(
+                "Downloaded {} test cases (version {}) to {}",
+                manifest.test_cases.len(),
+                manifest.version,
+                output
+            ))
+        );
+    }
+
+    Ok(())
+}
+
+/// Fetch test cases and write one JSON file per test case plus the manifest
+///
+/// The output directory is created if it doesn't exist. `version` defaults
+/// to the latest version.
+pub async fn download_to(
+    ctx: &CommandContext,
+    id_or_slug: &str,
+    version: Option<&str>,
+    output: &Path,
+) -> Result<DownloadManifest> {
+    let client = ctx.sdk_client()?;
+    let set = match client.benchmarks().test_cases(id_or_slug, version).await {
+        Ok(set) => set,
+        Err(SdkError::NotFound { .. }) => match version {
+            Some(version) => {
+                anyhow::bail!("Benchmark not found: {} (version {})", id_or_slug, version)
+            }
+            None => anyhow::bail!("Benchmark not found: {}", id_or_slug),
+        },
+        Err(e) => return Err(e.into()),
+    };
+
+    fs::create_dir_all(output)
+        .with_context(|| format!("Failed to create {}", output.display()))?;
+
+    // The manifest is written last, so no test case may take its name
+    let mut files = HashSet::from([MANIFEST_FILE.to_string()]);
+    let mut entries = Vec::with_capacity(set.test_cases.len());
+    for test_case in &set.test_cases {
+        let file = test_case_file_name(test_case)?;
+        if !files.insert(file.clone()) {
+            anyhow::bail!("Test case '{}' would overwrite {}", test_case.id, file);
+        }
+
+        let mut contents = serde_json::to_vec_pretty(test_case)?;
+        contents.push(b'\n');
+        fs::write(output.join(&file), &contents)
+            .with_context(|| format!("Failed to write {}", file))?;
+
+        entries.push(ManifestEntry {
+            id: test_case.id.clone(),
+            file,
+            sha256: hex::encode(Sha256::digest(&contents)),
+        });
+    }
+
+    let manifest = DownloadManifest {
+        benchmark: id_or_slug.to_string(),
+        version: set.version,
+        test_cases: entries,
+    };
+    let mut contents = serde_json::to_vec_pretty(&manifest)?;
+    contents.push(b'\n');
+    fs::write(output.join(MANIFEST_FILE), contents)
+        .with_context(|| format!("Failed to write {}", MANIFEST_FILE))?;
+
+    Ok(manifest)
+}
+
+/// File name for a test case, with characters unsafe in paths replaced
+fn test_case_file_name(test_case: &TestCase) -> Result<String> {
+    let stem: String = test_case
+        .id
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect();
+    if stem.is_empty() {
+        anyhow::bail!("Test case has an empty id");
+    }
+    Ok(format!("{}.json", stem))
+}
+
 /// Validate a benchmark definition file
-pub async fn validate(file_path: String) -> Result<()> {
+///
+/// With `strict`, the definition is checked against the full schema rather
+/// than only for structural problems.
+pub async fn validate(file_path: String, strict: bool) -> Result<()> {
     let path = Path::new(&file_path);
     if !path.exists() {
         anyhow::bail!("File not found: {}", file_path);
@@ -295,42 +501,221 @@ pub async fn validate(file_path: String) -> Result<()> {
     let content = fs::read_to_string(path)
         .context("Failed to read benchmark definition file")?;
 
-    let definition: serde_json::Value = if file_path.ends_with(".yaml")
-        || file_path.ends_with(".yml")
-    {
-        let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
-            .context("Failed to parse YAML")?;
-        serde_json::to_value(yaml)?
-    } else {
-        serde_json::from_str(&content).context("Failed to parse JSON")?
-    };
+    let format = DefinitionFormat::from_path(path)?;
+    let definition = load_definition(path)?;
 
-    // Basic validation checks
-    let required_fields = ["name", "slug", "description", "category"];
-    let mut errors = Vec::new();
+    let issues = validate_definition(&definition, strict);
 
-    for field in &required_fields {
-        if definition.get(field).is_none() {
-            errors.push(format!("Missing required field: {}", field));
-        }
-    }
+    if !issues.is_empty() {
+        // Locations are only available for JSON sources, and point into the
+        // top-level file rather than any `$ref` includes
+        let source = (format == DefinitionFormat::Json).then_some(content.as_str());
+        let styled = std::io::stdout().is_terminal();
 
-    if !errors.is_empty() {
         println!("{}", colors::error("Validation failed:"));
-        for error in errors {
-            println!("  - {}", error);
+        for issue in &issues {
+            println!("{}", render_issue(issue, &file_path, source, styled));
         }
         anyhow::bail!("Validation failed");
     }
 
     println!("{}", colors::success("Validation successful!"));
-    println!("  Name:     {}", definition["name"].as_str().unwrap());
-    println!("  Slug:     {}", definition["slug"].as_str().unwrap());
-    println!("  Category: {}", definition["category"].as_str().unwrap());
+    println!("  Name:     {}", definition["name"].as_str().unwrap_or("-"));
+    println!("  Slug:     {}", definition["slug"].as_str().unwrap_or("-"));
+    println!("  Category: {}", definition["category"].as_str().unwrap_or("-"));
 
     Ok(())
 }
 
+/// A problem found in a benchmark definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// JSON pointer to the offending value, e.g. `/slug`
+    pub pointer: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn new(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Fields every benchmark definition must provide
+const REQUIRED_FIELDS: [&str; 5] = ["name", "slug", "description", "category", "version"];
+
+/// Check a parsed benchmark definition
+///
+/// Without `strict`, only structural problems are reported: the definition
+/// must be an object and known fields that are present must have the right
+/// type. `strict` enforces the full schema on top: required fields, a
+/// parseable semantic version, at least one test case, positive test-case
+/// weights, and a `minimum_test_cases` the test cases can satisfy.
+pub fn validate_definition(definition: &serde_json::Value, strict: bool) -> Vec<ValidationIssue> {
+    let Some(object) = definition.as_object() else {
+        return vec![ValidationIssue::new("", "definition must be an object")];
+    };
+
+    let mut issues = Vec::new();
+
+    for field in &REQUIRED_FIELDS {
+        let pointer = format!("/{}", field);
+        match object.get(*field) {
+            None if strict => issues.push(ValidationIssue::new(
+                pointer,
+                format!("missing required field `{}`", field),
+            )),
+            None => {}
+            Some(serde_json::Value::String(s)) if strict && s.trim().is_empty() => {
+                issues.push(ValidationIssue::new(pointer, "must not be empty"))
+            }
+            Some(serde_json::Value::String(_)) => {}
+            Some(_) => issues.push(ValidationIssue::new(pointer, "expected a string")),
+        }
+    }
+
+    let test_cases = match object.get("test_cases") {
+        Some(serde_json::Value::Array(cases)) => Some(cases),
+        Some(_) => {
+            issues.push(ValidationIssue::new("/test_cases", "expected an array"));
+            return issues;
+        }
+        None => None,
+    };
+
+    let criteria = match object.get("evaluation_criteria") {
+        Some(serde_json::Value::Object(criteria)) => Some(criteria),
+        Some(_) => {
+            issues.push(ValidationIssue::new(
+                "/evaluation_criteria",
+                "expected an object",
+            ));
+            return issues;
+        }
+        None => None,
+    };
+
+    if !strict {
+        return issues;
+    }
+
+    if let Some(slug) = object.get("slug").and_then(|s| s.as_str()) {
+        let well_formed = slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !slug.is_empty() && !well_formed {
+            issues.push(ValidationIssue::new(
+                "/slug",
+                "must contain only lowercase letters, digits and hyphens",
+            ));
+        }
+    }
+
+    if let Some(version) = object.get("version").and_then(|v| v.as_str()) {
+        if !version.trim().is_empty() {
+            if let Err(e) = SemanticVersion::parse(version) {
+                issues.push(ValidationIssue::new("/version", e.to_string()));
+            }
+        }
+    }
+
+    let test_cases = test_cases.map(Vec::as_slice).unwrap_or_default();
+    if test_cases.is_empty() {
+        issues.push(ValidationIssue::new(
+            "/test_cases",
+            "must contain at least one test case",
+        ));
+    }
+
+    for (index, case) in test_cases.iter().enumerate() {
+        let Some(weight) = case.get("weight") else {
+            continue;
+        };
+        let pointer = format!("/test_cases/{}/weight", index);
+        match weight.as_f64() {
+            Some(w) if w > 0.0 => {}
+            Some(_) => issues.push(ValidationIssue::new(pointer, "must be positive")),
+            None => issues.push(ValidationIssue::new(pointer, "expected a number")),
+        }
+    }
+
+    if let Some(minimum) = criteria.and_then(|c| c.get("minimum_test_cases")) {
+        let pointer = "/evaluation_criteria/minimum_test_cases";
+        match minimum.as_u64() {
+            Some(min) if min > test_cases.len() as u64 => issues.push(ValidationIssue::new(
+                pointer,
+                format!(
+                    "requires {} test cases but only {} are defined",
+                    min,
+                    test_cases.len()
+                ),
+            )),
+            Some(_) => {}
+            None => issues.push(ValidationIssue::new(
+                pointer,
+                "expected a non-negative integer",
+            )),
+        }
+    }
+
+    issues
+}
+
+/// Render a validation issue against its source file
+///
+/// Styled output (for terminals) shows the offending line with a caret under
+/// the location; plain output is a single `file:line:col: path: message`
+/// line. Without a source, only the file and pointer are shown.
+fn render_issue(
+    issue: &ValidationIssue,
+    file_path: &str,
+    source: Option<&str>,
+    styled: bool,
+) -> String {
+    let location = source.and_then(|src| locate_nearest(src, &issue.pointer));
+    let pointer = if issue.pointer.is_empty() { "/" } else { &issue.pointer };
+
+    let position = match location {
+        Some(loc) => format!("{}:{}", file_path, loc),
+        None => file_path.to_string(),
+    };
+
+    if !styled {
+        return format!("{}: {}: {}", position, pointer, issue.message);
+    }
+
+    let mut out = format!(
+        "{} {}\n  {} {} {}",
+        colors::error("error:"),
+        colors::bold(&issue.message),
+        colors::info("-->"),
+        position,
+        colors::dim(&format!("({})", pointer)),
+    );
+
+    if let (Some(src), Some(loc)) = (source, location) {
+        let line = src.lines().nth(loc.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(loc.line.to_string().len());
+        let caret = format!("{}^", " ".repeat(loc.column - 1));
+        out.push_str(&format!(
+            "\n{} {}\n{} {} {}\n{} {} {}",
+            gutter,
+            colors::info("|"),
+            colors::info(&loc.line.to_string()),
+            colors::info("|"),
+            line,
+            gutter,
+            colors::info("|"),
+            colors::error(&caret),
+        ));
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,4 +737,156 @@ mod tests {
         let json = serde_json::to_string(&benchmark).unwrap();
         assert!(json.contains("test-id"));
     }
+
+    const DEFINITION: &str = r#"{
+  "name": "Sample",
+  "slug": "Bad Slug",
+  "category": 42
+}"#;
+
+    #[test]
+    fn test_validate_definition() {
+        let definition: serde_json::Value = serde_json::from_str(DEFINITION).unwrap();
+        let issues = validate_definition(&definition, true);
+
+        let pointers: Vec<&str> = issues.iter().map(|i| i.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            vec!["/description", "/category", "/version", "/slug", "/test_cases"]
+        );
+
+        // Structural checks only report the mistyped field
+        let issues = validate_definition(&definition, false);
+        let pointers: Vec<&str> = issues.iter().map(|i| i.pointer.as_str()).collect();
+        assert_eq!(pointers, vec!["/category"]);
+
+        let valid = serde_json::json!({
+            "name": "Sample",
+            "slug": "sample-1",
+            "description": "A sample",
+            "category": "accuracy",
+            "version": "1.0.0",
+            "test_cases": [{"id": "tc-1", "weight": 1.0}],
+        });
+        assert!(validate_definition(&valid, true).is_empty());
+        assert_eq!(validate_definition(&serde_json::json!([]), false).len(), 1);
+    }
+
+    #[test]
+    fn test_validate_definition_test_cases() {
+        let definition = serde_json::json!({
+            "name": "Sample",
+            "slug": "sample",
+            "description": "A sample",
+            "category": "accuracy",
+            "version": "1.0.0",
+            "test_cases": [{"weight": 1.0}, {"weight": 0.0}, {"weight": "heavy"}],
+            "evaluation_criteria": {"minimum_test_cases": 5},
+        });
+        let issues = validate_definition(&definition, true);
+
+        let pointers: Vec<&str> = issues.iter().map(|i| i.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/test_cases/1/weight",
+                "/test_cases/2/weight",
+                "/evaluation_criteria/minimum_test_cases",
+            ]
+        );
+        assert!(validate_definition(&definition, false).is_empty());
+    }
+
+    #[test]
+    fn test_render_issue_plain() {
+        let slug = ValidationIssue::new("/slug", "bad slug");
+        assert_eq!(
+            render_issue(&slug, "bench.json", Some(DEFINITION), false),
+            "bench.json:3:3: /slug: bad slug"
+        );
+
+        // Missing fields point at the enclosing object
+        let missing = ValidationIssue::new("/description", "missing");
+        assert_eq!(
+            render_issue(&missing, "bench.json", Some(DEFINITION), false),
+            "bench.json:1:1: /description: missing"
+        );
+
+        // No source (e.g. YAML) means no location
+        assert_eq!(
+            render_issue(&slug, "bench.yaml", None, false),
+            "bench.yaml: /slug: bad slug"
+        );
+    }
+
+    #[test]
+    fn test_render_issue_styled() {
+        let issue = ValidationIssue::new("/category", "expected a string");
+        let output = render_issue(&issue, "bench.json", Some(DEFINITION), true);
+
+        assert!(output.contains("bench.json:4:3"));
+        assert!(output.contains(r#""category": 42"#));
+        assert!(output.lines().last().unwrap().contains("  ^"));
+    }
+
+    fn stats_context(server: &wiremock::MockServer, format: OutputFormat) -> CommandContext {
+        let config = crate::config::Config {
+            api_endpoint: server.uri(),
+            output_format: format,
+            ..Default::default()
+        };
+        CommandContext::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_stats_table() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/mmlu/stats"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_submissions": 1234,
+                "version_count": 3,
+                "unique_models": 42,
+                "unique_providers": 7,
+                "average_score": 0.8125,
+                "latest_submission_at": "2024-05-01T12:30:00Z",
+            })))
+            .mount(&server)
+            .await;
+
+        let ctx = stats_context(&server, OutputFormat::Table);
+        let stats = fetch_stats(&ctx, "mmlu").await.unwrap();
+        let table = render_stats(&ctx, &stats).unwrap();
+
+        for expected in ["Submissions", "1,234", "Versions", "Providers", "0.8125", "05/01/2024 12:30"] {
+            assert!(table.contains(expected), "missing {expected:?} in\n{table}");
+        }
+
+        let ctx = stats_context(&server, OutputFormat::Plain);
+        let plain = render_stats(&ctx, &stats).unwrap();
+        assert!(plain.lines().any(|l| l == "Providers: 7"));
+    }
+
+    #[tokio::test]
+    async fn test_stats_not_found() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/missing/stats"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": "NOT_FOUND",
+                "message": "Benchmark not found",
+            })))
+            .mount(&server)
+            .await;
+
+        let ctx = stats_context(&server, OutputFormat::Table);
+        let err = fetch_stats(&ctx, "missing").await.unwrap_err();
+        assert_eq!(err.to_string(), "Benchmark not found: missing");
+    }
 }
@@ -2,11 +2,16 @@
 
 use anyhow::Result;
 use colored::Colorize;
+use comfy_table::{Cell, Color};
+use llm_benchmark_sdk::services::{ExportFormat, LeaderboardExport};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
 
 use crate::commands::CommandContext;
 use crate::interactive::spinner;
-use crate::output::{colors, TableFormatter};
+use crate::output::{colors, LocaleFormatter, TableFormatter};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LeaderboardEntry {
@@ -67,28 +72,158 @@ pub async fn show(ctx: &CommandContext, benchmark_id: String) -> Result<()> {
         return Ok(());
     }
 
-    let headers = vec!["Rank", "Model", "Version", "Score", "Verified", "Submitted"];
+    let fmt = ctx.config.locale_formatter();
     let rows: Vec<Vec<String>> = leaderboard
         .entries
         .iter()
-        .map(|e| {
-            vec![
-                format!("#{}", e.rank),
-                e.model_name.clone(),
-                e.model_version.clone(),
-                format!("{:.4}", e.score),
-                if e.verified { "✓" } else { "-" }.to_string(),
-                e.submitted_at.clone(),
-            ]
-        })
+        .map(|e| entry_row(&fmt, e))
         .collect();
 
-    let table = TableFormatter::simple(headers, rows)?;
+    let table = TableFormatter::simple(LEADERBOARD_HEADERS.to_vec(), rows)?;
     println!("{}", table);
 
     Ok(())
 }
 
+const LEADERBOARD_HEADERS: [&str; 6] =
+    ["Rank", "Model", "Version", "Score", "Verified", "Submitted"];
+
+fn entry_row(fmt: &LocaleFormatter, e: &LeaderboardEntry) -> Vec<String> {
+    vec![
+        format!("#{}", fmt.format_count(e.rank)),
+        e.model_name.clone(),
+        e.model_version.clone(),
+        fmt.format_decimal(e.score, 4),
+        if e.verified { "✓" } else { "-" }.to_string(),
+        fmt.format_timestamp(&e.submitted_at),
+    ]
+}
+
+/// Rank change of each row in `current` since `previous`
+///
+/// Both lists are in rank order. A positive delta means the row moved up
+/// that many places, a negative one that it moved down; rows absent from
+/// `previous` get `None`.
+pub fn rank_deltas<K: Eq + Hash>(previous: &[K], current: &[K]) -> Vec<Option<i64>> {
+    let previous: HashMap<&K, usize> = previous
+        .iter()
+        .enumerate()
+        .map(|(position, key)| (key, position))
+        .collect();
+
+    current
+        .iter()
+        .enumerate()
+        .map(|(position, key)| {
+            previous
+                .get(key)
+                .map(|&before| before as i64 - position as i64)
+        })
+        .collect()
+}
+
+/// Identity of a leaderboard row across polls
+fn entry_key(entry: &LeaderboardEntry) -> (String, String) {
+    (entry.model_name.clone(), entry.model_version.clone())
+}
+
+/// Watch a leaderboard, redrawing it every `interval_secs` until Ctrl-C
+///
+/// Rows that moved since the previous poll are shown in green when they
+/// went up and red when they went down.
+pub async fn watch(ctx: &CommandContext, benchmark_id: String, interval_secs: u64) -> Result<()> {
+    if interval_secs == 0 {
+        anyhow::bail!("Interval must be at least 1 second");
+    }
+    let interval = Duration::from_secs(interval_secs);
+    let fmt = ctx.config.locale_formatter();
+    let path = format!("/api/v1/leaderboards/{}", benchmark_id);
+
+    let mut previous: Option<Vec<(String, String)>> = None;
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = ticker.tick() => {}
+        }
+
+        let leaderboard: Leaderboard = tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            result = ctx.client.get(&path) => match result {
+                Ok(leaderboard) => leaderboard,
+                // Keep the last table on screen and try again next tick
+                Err(e) => {
+                    eprintln!("{}", colors::error(&format!("Refresh failed: {}", e)));
+                    continue;
+                }
+            },
+        };
+
+        let keys: Vec<(String, String)> = leaderboard.entries.iter().map(entry_key).collect();
+        let deltas = match &previous {
+            Some(previous) => rank_deltas(previous, &keys),
+            // Nothing to compare the first poll against
+            None => vec![Some(0); keys.len()],
+        };
+
+        // Clear the screen and move the cursor home before redrawing
+        print!("\x1B[2J\x1B[H");
+        println!("{}", colors::bold(&format!("Leaderboard: {}", leaderboard.benchmark_name)));
+        println!(
+            "{}",
+            colors::dim(&format!(
+                "Refreshing every {}s, updated {}. Press Ctrl-C to stop.",
+                interval_secs,
+                fmt.format_datetime(&chrono::Utc::now())
+            ))
+        );
+        println!();
+
+        if leaderboard.entries.is_empty() {
+            println!("{}", colors::warning("No entries yet."));
+        } else {
+            println!("{}", watch_table(&fmt, &leaderboard.entries, &deltas));
+        }
+
+        previous = Some(keys);
+    }
+
+    println!();
+    println!("{}", colors::info("Stopped watching."));
+    Ok(())
+}
+
+fn watch_table(
+    fmt: &LocaleFormatter,
+    entries: &[LeaderboardEntry],
+    deltas: &[Option<i64>],
+) -> String {
+    let mut table = TableFormatter::new();
+    let mut headers = LEADERBOARD_HEADERS.to_vec();
+    headers.push("Change");
+    table.set_header(headers);
+
+    for (entry, delta) in entries.iter().zip(deltas) {
+        let (change, color) = match delta {
+            Some(d) if *d > 0 => (format!("▲ {}", d), Some(Color::Green)),
+            Some(d) if *d < 0 => (format!("▼ {}", -d), Some(Color::Red)),
+            Some(_) => ("-".to_string(), None),
+            None => ("new".to_string(), None),
+        };
+
+        let mut row = entry_row(fmt, entry);
+        row.push(change);
+        table.add_row(row.into_iter().map(|value| match color {
+            Some(color) => Cell::new(value).fg(color),
+            None => Cell::new(value),
+        }));
+    }
+
+    table.to_string()
+}
+
 /// Compare two models
 pub async fn compare(
     ctx: &CommandContext,
@@ -108,15 +243,17 @@ pub async fn compare(
 
     sp.finish_and_clear();
 
+    let fmt = ctx.config.locale_formatter();
+
     println!("{}", colors::bold("Model Comparison"));
     println!();
 
     // Overall scores
     println!("Model 1: {} ({})", comparison.model1.name, comparison.model1.version);
-    println!("  Overall Score: {:.4}", comparison.model1.overall_score);
+    println!("  Overall Score: {}", fmt.format_decimal(comparison.model1.overall_score, 4));
     println!();
     println!("Model 2: {} ({})", comparison.model2.name, comparison.model2.version);
-    println!("  Overall Score: {:.4}", comparison.model2.overall_score);
+    println!("  Overall Score: {}", fmt.format_decimal(comparison.model2.overall_score, 4));
     println!();
 
     // Metric breakdown
@@ -127,17 +264,17 @@ pub async fn compare(
             .iter()
             .map(|m| {
                 let diff_str = if m.difference > 0.0 {
-                    format!("+{:.4}", m.difference).green().to_string()
+                    format!("+{}", fmt.format_decimal(m.difference, 4)).green().to_string()
                 } else if m.difference < 0.0 {
-                    format!("{:.4}", m.difference).red().to_string()
+                    fmt.format_decimal(m.difference, 4).red().to_string()
                 } else {
-                    "0.0000".to_string()
+                    fmt.format_decimal(0.0, 4)
                 };
 
                 vec![
                     m.name.clone(),
-                    format!("{:.4}", m.model1_value),
-                    format!("{:.4}", m.model2_value),
+                    fmt.format_decimal(m.model1_value, 4),
+                    fmt.format_decimal(m.model2_value, 4),
                     diff_str,
                 ]
             })
@@ -151,47 +288,36 @@ pub async fn compare(
 }
 
 /// Export leaderboard data
+///
+/// `format` is `json` or `csv`; CSV follows RFC 4180 with the columns in
+/// [`CSV_COLUMNS`](llm_benchmark_sdk::services::CSV_COLUMNS).
 pub async fn export(
     ctx: &CommandContext,
     benchmark_id: String,
     format: String,
     output_file: Option<String>,
 ) -> Result<()> {
+    let format: ExportFormat = format.parse()?;
+
     let sp = spinner("Fetching leaderboard data...");
 
-    let leaderboard: Leaderboard = ctx
+    let export: LeaderboardExport = ctx
         .client
-        .get(&format!("/api/v1/leaderboards/{}", benchmark_id))
+        .get(&format!("/api/v1/leaderboards/{}/export", benchmark_id))
         .await?;
 
     sp.finish_and_clear();
 
-    let output = match format.to_lowercase().as_str() {
-        "json" => serde_json::to_string_pretty(&leaderboard)?,
-        "csv" => {
-            let mut csv = String::new();
-            csv.push_str("Rank,Model,Version,Score,Verified,Submitted At\n");
-            for entry in &leaderboard.entries {
-                csv.push_str(&format!(
-                    "{},{},{},{},{},{}\n",
-                    entry.rank,
-                    entry.model_name,
-                    entry.model_version,
-                    entry.score,
-                    entry.verified,
-                    entry.submitted_at
-                ));
-            }
-            csv
-        }
-        _ => anyhow::bail!("Unsupported format: {}. Use 'json' or 'csv'.", format),
+    let output = match format {
+        ExportFormat::Json => format!("{}\n", ctx.config.json_formatter().format(&export)?),
+        ExportFormat::Csv => export.to_csv(),
     };
 
     if let Some(file) = output_file {
         std::fs::write(&file, output)?;
         println!("{}", colors::success(&format!("Exported to: {}", file)));
     } else {
-        println!("{}", output);
+        print!("{}", output);
     }
 
     Ok(())
@@ -216,4 +342,26 @@ mod tests {
         let json = serde_json::to_string(&entry).unwrap();
         assert!(json.contains("GPT-4"));
     }
+
+    #[test]
+    fn test_rank_deltas() {
+        let previous = ["a", "b", "c", "d"];
+        let current = ["c", "a", "b", "e", "d"];
+
+        assert_eq!(
+            rank_deltas(&previous, &current),
+            vec![Some(2), Some(-1), Some(-1), None, Some(-1)]
+        );
+        assert_eq!(rank_deltas(&previous, &previous), vec![Some(0); 4]);
+        assert_eq!(rank_deltas::<&str>(&[], &["a"]), vec![None]);
+    }
+
+    #[test]
+    fn test_export_rejects_unknown_format() {
+        let err = "xlsx".parse::<ExportFormat>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation failed: Unsupported export format 'xlsx'. Use one of: json, csv"
+        );
+    }
 }
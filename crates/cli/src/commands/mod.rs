@@ -11,7 +11,18 @@ pub mod submit;
 
 use crate::client::ApiClient;
 use crate::config::Config;
+use crate::interactive::{fuzzy_pick, is_interactive, PickerCandidate};
+use crate::output::NdjsonWriter;
 use anyhow::Result;
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Page size used when streaming list results
+const STREAM_PAGE_SIZE: usize = 100;
+
+/// Number of candidates fetched for the interactive ID picker
+const PICKER_PAGE_SIZE: usize = 100;
 
 /// Context passed to all commands
 pub struct CommandContext {
@@ -41,6 +52,17 @@ impl CommandContext {
         Ok(Self { config, client })
     }
 
+    /// Build an SDK client for the configured endpoint and credentials
+    pub fn sdk_client(&self) -> Result<llm_benchmark_sdk::Client> {
+        let mut builder = llm_benchmark_sdk::Client::builder()
+            .base_url(&self.config.api_endpoint)
+            .timeout(Duration::from_secs(self.config.timeout_seconds));
+        if let Some(token) = &self.config.auth_token {
+            builder = builder.bearer_token(token);
+        }
+        Ok(builder.build()?)
+    }
+
     /// Check if user is authenticated, return error if not
     pub fn require_auth(&self) -> Result<()> {
         if !self.config.is_authenticated() {
@@ -49,3 +71,159 @@ impl CommandContext {
         Ok(())
     }
 }
+
+/// Stream every page of a list endpoint to stdout as NDJSON.
+///
+/// Items are written as each page arrives rather than collected first.
+/// `split` extracts the items and the reported total from a page response.
+/// Returns the number of items written.
+pub async fn stream_ndjson<L, T, F>(ctx: &CommandContext, path: &str, split: F) -> Result<usize>
+where
+    L: DeserializeOwned,
+    T: Serialize,
+    F: Fn(L) -> (Vec<T>, usize),
+{
+    let mut writer = NdjsonWriter::new(std::io::stdout());
+    let mut page = 1;
+
+    loop {
+        let list: L = ctx
+            .client
+            .get(&paged_path(path, page, STREAM_PAGE_SIZE))
+            .await?;
+        let (items, total) = split(list);
+
+        for item in &items {
+            writer.write(item)?;
+        }
+
+        if items.len() < STREAM_PAGE_SIZE || writer.count() >= total {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(writer.count())
+}
+
+/// Resolve a possibly partial ID given to a `show` command.
+///
+/// Full UUIDs are used as-is. On a terminal, a query naming an existing item
+/// under `list_path` (e.g. a full slug) is used as-is too; anything else is
+/// matched against the first page of candidates from `list_path` using the
+/// fuzzy picker. Without a terminal the query is passed through unchanged.
+pub async fn resolve_id<L, F>(
+    ctx: &CommandContext,
+    query: Option<String>,
+    list_path: &str,
+    prompt: &str,
+    to_candidates: F,
+) -> Result<String>
+where
+    L: DeserializeOwned,
+    F: FnOnce(L) -> Vec<PickerCandidate>,
+{
+    let query = query.unwrap_or_default();
+    if Uuid::parse_str(query.trim()).is_ok() {
+        return Ok(query);
+    }
+
+    if !is_interactive() {
+        if query.trim().is_empty() {
+            anyhow::bail!("An ID is required when not running in a terminal");
+        }
+        return Ok(query);
+    }
+
+    if exists_exactly(ctx, list_path, query.trim()).await? {
+        return Ok(query.trim().to_string());
+    }
+
+    let list: L = ctx
+        .client
+        .get(&paged_path(list_path, 1, PICKER_PAGE_SIZE))
+        .await?;
+    fuzzy_pick(prompt, &to_candidates(list), &query)
+}
+
+/// Whether `query` names an item that exists at `{list_path}/{query}`
+///
+/// Queries that aren't a single plain path segment are never looked up.
+async fn exists_exactly(ctx: &CommandContext, list_path: &str, query: &str) -> Result<bool> {
+    let plain_segment = !query.is_empty()
+        && query
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && query != "."
+        && query != "..";
+    if !plain_segment {
+        return Ok(false);
+    }
+    ctx.client.exists(&format!("{}/{}", list_path, query)).await
+}
+
+/// Append pagination parameters to a list path
+fn paged_path(path: &str, page: usize, per_page: usize) -> String {
+    let separator = if path.ends_with('?') || path.ends_with('&') {
+        ""
+    } else if path.contains('?') {
+        "&"
+    } else {
+        "?"
+    };
+    format!("{}{}page={}&per_page={}", path, separator, page, per_page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paged_path() {
+        assert_eq!(
+            paged_path("/api/v1/submissions", 1, 100),
+            "/api/v1/submissions?page=1&per_page=100"
+        );
+        assert_eq!(
+            paged_path("/api/v1/submissions?benchmark_id=abc", 2, 100),
+            "/api/v1/submissions?benchmark_id=abc&page=2&per_page=100"
+        );
+        assert_eq!(
+            paged_path("/api/v1/benchmarks?category=accuracy&", 3, 50),
+            "/api/v1/benchmarks?category=accuracy&page=3&per_page=50"
+        );
+        assert_eq!(
+            paged_path("/api/v1/benchmarks?", 1, 50),
+            "/api/v1/benchmarks?page=1&per_page=50"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exists_exactly() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/mmlu"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let ctx = CommandContext::new(Config {
+            api_endpoint: server.uri(),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(exists_exactly(&ctx, "/api/v1/benchmarks", "mmlu").await.unwrap());
+        assert!(!exists_exactly(&ctx, "/api/v1/benchmarks", "mml").await.unwrap());
+        // Never looked up, so never mistaken for a nested resource
+        assert!(!exists_exactly(&ctx, "/api/v1/benchmarks", "mmlu/stats").await.unwrap());
+        assert!(!exists_exactly(&ctx, "/api/v1/benchmarks", "").await.unwrap());
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+}
@@ -5,9 +5,9 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
-use crate::commands::CommandContext;
-use crate::interactive::{confirm_default_yes, prompt_input, spinner};
-use crate::output::{colors, TableFormatter};
+use crate::commands::{resolve_id, stream_ndjson, CommandContext};
+use crate::interactive::{confirm_default_yes, prompt_input, spinner, PickerCandidate};
+use crate::output::{colors, OutputFormat, TableFormatter};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Proposal {
@@ -46,6 +46,8 @@ pub struct VoteRequest {
 #[derive(Debug, Serialize)]
 pub struct CommentRequest {
     pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
 }
 
 /// List governance proposals
@@ -58,16 +60,28 @@ pub async fn list(ctx: &CommandContext, status: Option<String>) -> Result<()> {
         "/api/v1/proposals".to_string()
     };
 
+    if ctx.config.output_format == OutputFormat::Ndjson {
+        sp.finish_and_clear();
+        stream_ndjson(ctx, &path, |list: ProposalList| (list.proposals, list.total)).await?;
+        return Ok(());
+    }
+
     let list: ProposalList = ctx.client.get(&path).await?;
 
     sp.finish_and_clear();
 
+    if ctx.config.output_format == OutputFormat::Json {
+        println!("{}", ctx.config.json_formatter().format(&list)?);
+        return Ok(());
+    }
+
     if list.proposals.is_empty() {
         println!("{}", colors::warning("No proposals found."));
         return Ok(());
     }
 
     let headers = vec!["ID", "Title", "Type", "Status", "For", "Against", "Abstain"];
+    let fmt = ctx.config.locale_formatter();
     let rows: Vec<Vec<String>> = list
         .proposals
         .iter()
@@ -77,22 +91,36 @@ pub async fn list(ctx: &CommandContext, status: Option<String>) -> Result<()> {
                 p.title.clone(),
                 p.proposal_type.clone(),
                 p.status.clone(),
-                p.votes_for.to_string(),
-                p.votes_against.to_string(),
-                p.votes_abstain.to_string(),
+                fmt.format_integer(p.votes_for.into()),
+                fmt.format_integer(p.votes_against.into()),
+                fmt.format_integer(p.votes_abstain.into()),
             ]
         })
         .collect();
 
     let table = TableFormatter::simple(headers, rows)?;
     println!("{}", table);
-    println!("{} proposals found", colors::dim(&list.total.to_string()));
+    println!("{} proposals found", colors::dim(&fmt.format_count(list.total)));
 
     Ok(())
 }
 
 /// Show proposal details
-pub async fn show(ctx: &CommandContext, proposal_id: String) -> Result<()> {
+pub async fn show(ctx: &CommandContext, proposal_id: Option<String>) -> Result<()> {
+    let proposal_id = resolve_id(
+        ctx,
+        proposal_id,
+        "/api/v1/proposals",
+        "Select a proposal",
+        |list: ProposalList| {
+            list.proposals
+                .into_iter()
+                .map(|p| PickerCandidate::new(p.id, format!("{} ({})", p.title, p.status)))
+                .collect()
+        },
+    )
+    .await?;
+
     let sp = spinner("Fetching proposal details...");
 
     let proposal: Proposal = ctx
@@ -105,20 +133,24 @@ pub async fn show(ctx: &CommandContext, proposal_id: String) -> Result<()> {
     println!("{}", colors::bold("Proposal Details"));
     println!();
 
+    let fmt = ctx.config.locale_formatter();
     let items = vec![
         ("ID", proposal.id),
         ("Title", proposal.title),
         ("Type", proposal.proposal_type),
         ("Status", proposal.status),
         ("Proposer ID", proposal.proposer_id),
-        ("Created At", proposal.created_at),
+        ("Created At", fmt.format_timestamp(&proposal.created_at)),
         (
             "Voting Ends",
-            proposal.voting_ends_at.unwrap_or_else(|| "-".to_string()),
+            proposal
+                .voting_ends_at
+                .map(|t| fmt.format_timestamp(&t))
+                .unwrap_or_else(|| "-".to_string()),
         ),
-        ("Votes For", proposal.votes_for.to_string()),
-        ("Votes Against", proposal.votes_against.to_string()),
-        ("Votes Abstain", proposal.votes_abstain.to_string()),
+        ("Votes For", fmt.format_integer(proposal.votes_for.into())),
+        ("Votes Against", fmt.format_integer(proposal.votes_against.into())),
+        ("Votes Abstain", fmt.format_integer(proposal.votes_abstain.into())),
     ];
 
     let table = TableFormatter::key_value(items)?;
@@ -239,6 +271,7 @@ pub async fn comment(
     ctx: &CommandContext,
     proposal_id: String,
     message: Option<String>,
+    reply_to: Option<String>,
 ) -> Result<()> {
     ctx.require_auth()?;
 
@@ -252,6 +285,7 @@ pub async fn comment(
 
     let request = CommentRequest {
         message: comment_text,
+        parent_id: reply_to,
     };
 
     let _: serde_json::Value = ctx
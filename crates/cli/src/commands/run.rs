@@ -2,15 +2,27 @@
 //!
 //! This module provides CLI commands for running the canonical benchmark suite.
 
+use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 
+use crate::output::JsonFormatter;
+use llm_benchmark_benchmarks::diff::{diff_results, MetricsComparison, ResultsDiff};
 use llm_benchmark_benchmarks::{
-    all_targets, get_target, io, markdown, run_all_benchmarks, run_benchmark,
+    all_targets, get_target, io, markdown, run_target, BenchmarkResult,
 };
 
+/// Version of the `run summary --json` schema.
+///
+/// Bump this whenever a field is removed, renamed or changes meaning so CI
+/// consumers can detect incompatible output.
+pub const SUMMARY_SCHEMA_VERSION: u32 = 1;
+
 /// List all available benchmark targets
 pub async fn list() -> Result<()> {
     println!("{}", "Available Benchmark Targets".bold().cyan());
@@ -38,7 +50,7 @@ pub async fn list() -> Result<()> {
 }
 
 /// Run all benchmarks
-pub async fn run_all(output_dir: Option<PathBuf>, json: bool) -> Result<()> {
+pub async fn run_all(output_dir: Option<PathBuf>, json: Option<JsonFormatter>) -> Result<()> {
     let base_path = output_dir.as_deref();
 
     println!("{}", "Running All Benchmarks".bold().cyan());
@@ -46,7 +58,8 @@ pub async fn run_all(output_dir: Option<PathBuf>, json: bool) -> Result<()> {
     println!();
 
     // Ensure output directories exist
-    io::ensure_output_dirs(base_path)?;
+    let layout = io::OutputLayout::default();
+    io::ensure_output_dirs(base_path, &layout)?;
 
     let targets = all_targets();
     let total = targets.len();
@@ -65,7 +78,7 @@ pub async fn run_all(output_dir: Option<PathBuf>, json: bool) -> Result<()> {
             target.id().bold()
         );
 
-        match target.run().await {
+        match run_target(target.as_ref()).await {
             Ok(result) => {
                 println!("{}", "OK".green().bold());
 
@@ -101,26 +114,20 @@ pub async fn run_all(output_dir: Option<PathBuf>, json: bool) -> Result<()> {
 
     if !results.is_empty() {
         // Write results
-        io::write_results(&results, base_path)?;
-        let combined_path = io::write_combined_results(&results, base_path)?;
-        let summary_path = markdown::write_summary(&results, base_path)?;
+        io::write_results(&results, base_path, &layout)?;
+        let combined_path = io::write_combined_results(&results, base_path, &layout)?;
+        let summary_path = markdown::write_summary(&results, base_path, &layout)?;
 
         println!();
         println!("{}", "Output files:".bold());
         println!("  Combined results: {}", combined_path.display());
         println!("  Summary: {}", summary_path.display());
-        println!(
-            "  Raw results: {}",
-            base_path
-                .unwrap_or(std::path::Path::new("."))
-                .join(io::RAW_OUTPUT_DIR)
-                .display()
-        );
+        println!("  Raw results: {}", layout.raw_dir_in(base_path).display());
 
-        if json {
+        if let Some(formatter) = json {
             println!();
             println!("{}", "JSON Results:".bold());
-            println!("{}", serde_json::to_string_pretty(&results)?);
+            println!("{}", formatter.format(&results)?);
         }
     }
 
@@ -132,7 +139,11 @@ pub async fn run_all(output_dir: Option<PathBuf>, json: bool) -> Result<()> {
 }
 
 /// Run a specific benchmark by ID
-pub async fn run_single(target_id: String, output_dir: Option<PathBuf>, json: bool) -> Result<()> {
+pub async fn run_single(
+    target_id: String,
+    output_dir: Option<PathBuf>,
+    json: Option<JsonFormatter>,
+) -> Result<()> {
     let base_path = output_dir.as_deref();
 
     println!(
@@ -153,7 +164,7 @@ pub async fn run_single(target_id: String, output_dir: Option<PathBuf>, json: bo
 
     print!("Running ... ");
 
-    let result = target.run().await?;
+    let result = run_target(target.as_ref()).await?;
 
     println!("{}", "OK".green().bold());
     println!();
@@ -165,34 +176,430 @@ pub async fn run_single(target_id: String, output_dir: Option<PathBuf>, json: bo
 
     // Write result if output directory specified
     if let Some(ref base) = base_path {
-        io::ensure_output_dirs(Some(base))?;
-        let path = io::write_result(&result, Some(base))?;
+        let layout = io::OutputLayout::default();
+        io::ensure_output_dirs(Some(base), &layout)?;
+        let path = io::write_result(&result, Some(base), &layout)?;
         println!("Result written to: {}", path.display());
     }
 
-    if json {
+    if let Some(formatter) = json {
         println!();
         println!("{}", "Full Result JSON:".bold());
-        println!("{}", serde_json::to_string_pretty(&result)?);
+        println!("{}", formatter.format(&result)?);
     }
 
     Ok(())
 }
 
 /// Show benchmark results summary
-pub async fn show_summary(output_dir: Option<PathBuf>) -> Result<()> {
+///
+/// With `compare`, the results are also diffed against those in an earlier
+/// output directory.
+pub async fn show_summary(
+    output_dir: Option<PathBuf>,
+    threshold: Option<Threshold>,
+    compare: Option<PathBuf>,
+    json: Option<JsonFormatter>,
+) -> Result<()> {
     let base_path = output_dir.as_deref();
 
-    let results = io::read_all_results(base_path)?;
+    let results = io::read_all_results(base_path, &io::OutputLayout::default())?;
+    let mut summary = RunSummary::new(&results, threshold);
+
+    if let Some(old_dir) = &compare {
+        if !old_dir.is_dir() {
+            anyhow::bail!("Comparison directory not found: {}", old_dir.display());
+        }
+        let previous = io::read_all_results(Some(old_dir), &io::OutputLayout::default())?;
+        summary = summary.compare_with(&previous, &results);
+    }
 
-    if results.is_empty() {
+    if let Some(formatter) = json {
+        println!("{}", formatter.format(&summary)?);
+    } else if results.is_empty() {
         println!("{}", "No benchmark results found.".yellow());
         println!("Run 'llm-benchmark run all' to execute benchmarks.");
         return Ok(());
+    } else {
+        println!("{}", markdown::generate_summary(&results));
+
+        if let Some(threshold) = &summary.threshold {
+            println!("{} {}", "Threshold:".bold(), threshold);
+            for target in &summary.targets {
+                let status = match target.status {
+                    TargetStatus::Pass => "PASS".green().bold(),
+                    TargetStatus::Fail => "FAIL".red().bold(),
+                    TargetStatus::Unchecked => "N/A".dimmed(),
+                };
+                println!("  {} {}", status, target.target_id);
+            }
+        }
+
+        if let (Some(old_dir), Some(comparison)) = (&compare, &summary.comparison) {
+            println!();
+            print_comparison(old_dir, comparison);
+        }
     }
 
-    let summary = markdown::generate_summary(&results);
-    println!("{}", summary);
+    if summary.status == SummaryStatus::Fail {
+        anyhow::bail!("{} benchmark(s) did not meet the threshold", summary.failed);
+    }
 
     Ok(())
 }
+
+/// Print how the results changed since an earlier run
+fn print_comparison(old_dir: &std::path::Path, diff: &ResultsDiff) {
+    println!("{} {}", "Compared with".bold(), old_dir.display());
+
+    if diff.is_unchanged() {
+        println!("  {}", "No changes.".dimmed());
+        return;
+    }
+
+    for target_id in &diff.added {
+        println!("  {} {}", "+".green().bold(), target_id);
+    }
+    for target_id in &diff.removed {
+        println!("  {} {}", "-".red().bold(), target_id);
+    }
+    for target in &diff.targets {
+        match &target.comparison {
+            MetricsComparison::Comparable { deltas } => {
+                let changed: Vec<_> = deltas.iter().filter(|d| d.delta != 0.0).collect();
+                if changed.is_empty() {
+                    continue;
+                }
+                println!("  {} {}", "~".yellow().bold(), target.target_id);
+                for delta in changed {
+                    let percent = delta
+                        .percent_change
+                        .map(|p| format!(", {:+.1}%", p))
+                        .unwrap_or_default();
+                    println!(
+                        "      {}: {} -> {} ({:+}{})",
+                        delta.metric, delta.old, delta.new, delta.delta, percent
+                    );
+                }
+            }
+            MetricsComparison::Incomparable { reason } => {
+                println!(
+                    "  {} {} {}",
+                    "!".red().bold(),
+                    target.target_id,
+                    format!("(incomparable: {})", reason).dimmed()
+                );
+            }
+        }
+    }
+}
+
+/// Comparison applied by a [`Threshold`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThresholdComparison {
+    /// The metric must be less than or equal to the threshold value
+    AtMost,
+    /// The metric must be greater than or equal to the threshold value
+    AtLeast,
+}
+
+/// Pass/fail threshold on a single metric, parsed from `METRIC<=VALUE` or
+/// `METRIC>=VALUE`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Threshold {
+    /// Metric key looked up in each target's metrics
+    pub metric: String,
+    /// How the metric is compared against `value`
+    pub comparison: ThresholdComparison,
+    /// Threshold value
+    pub value: f64,
+}
+
+impl Threshold {
+    /// Check a target's metrics, returning `None` if the metric is absent or
+    /// not numeric
+    pub fn check(&self, metrics: &serde_json::Value) -> Option<bool> {
+        let actual = metrics.get(&self.metric)?.as_f64()?;
+        Some(match self.comparison {
+            ThresholdComparison::AtMost => actual <= self.value,
+            ThresholdComparison::AtLeast => actual >= self.value,
+        })
+    }
+}
+
+impl FromStr for Threshold {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (metric, comparison, value) = if let Some((metric, value)) = s.split_once("<=") {
+            (metric, ThresholdComparison::AtMost, value)
+        } else if let Some((metric, value)) = s.split_once(">=") {
+            (metric, ThresholdComparison::AtLeast, value)
+        } else {
+            return Err(format!(
+                "invalid threshold '{}', expected METRIC<=VALUE or METRIC>=VALUE",
+                s
+            ));
+        };
+
+        let metric = metric.trim();
+        if metric.is_empty() {
+            return Err(format!("invalid threshold '{}', metric name is empty", s));
+        }
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid threshold '{}', value is not a number", s))?;
+
+        Ok(Self {
+            metric: metric.to_string(),
+            comparison,
+            value,
+        })
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.comparison {
+            ThresholdComparison::AtMost => "<=",
+            ThresholdComparison::AtLeast => ">=",
+        };
+        write!(f, "{}{}{}", self.metric, op, self.value)
+    }
+}
+
+/// Overall status of a results summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStatus {
+    /// No target failed the threshold
+    Pass,
+    /// At least one target failed the threshold
+    Fail,
+    /// No results were found
+    NoResults,
+}
+
+/// Threshold outcome for a single target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetStatus {
+    /// The target met the threshold
+    Pass,
+    /// The target did not meet the threshold
+    Fail,
+    /// No threshold was given, or the target lacks the metric
+    Unchecked,
+}
+
+/// Per-target entry of a results summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetSummary {
+    /// Benchmark target ID
+    pub target_id: String,
+    /// When the target was run
+    pub timestamp: DateTime<Utc>,
+    /// Threshold outcome
+    pub status: TargetStatus,
+    /// Raw metrics reported by the target
+    pub metrics: serde_json::Value,
+}
+
+/// Machine-readable results summary emitted by `run summary --json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Schema version, see [`SUMMARY_SCHEMA_VERSION`]
+    pub schema_version: u32,
+    /// When the summary was generated
+    pub generated_at: DateTime<Utc>,
+    /// Overall status
+    pub status: SummaryStatus,
+    /// Threshold applied to each target, if any
+    pub threshold: Option<Threshold>,
+    /// Number of targets summarized
+    pub total: usize,
+    /// Number of targets that met the threshold
+    pub passed: usize,
+    /// Number of targets that did not meet the threshold
+    pub failed: usize,
+    /// Per-target results
+    pub targets: Vec<TargetSummary>,
+    /// Differences from an earlier run, when one was given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<ResultsDiff>,
+}
+
+impl RunSummary {
+    /// Summarize results, checking each against the optional threshold
+    pub fn new(results: &[BenchmarkResult], threshold: Option<Threshold>) -> Self {
+        let targets: Vec<TargetSummary> = results
+            .iter()
+            .map(|result| {
+                let status = match threshold.as_ref().and_then(|t| t.check(&result.metrics)) {
+                    Some(true) => TargetStatus::Pass,
+                    Some(false) => TargetStatus::Fail,
+                    None => TargetStatus::Unchecked,
+                };
+                TargetSummary {
+                    target_id: result.target_id.clone(),
+                    timestamp: result.timestamp,
+                    status,
+                    metrics: result.metrics.clone(),
+                }
+            })
+            .collect();
+
+        let passed = targets
+            .iter()
+            .filter(|t| t.status == TargetStatus::Pass)
+            .count();
+        let failed = targets
+            .iter()
+            .filter(|t| t.status == TargetStatus::Fail)
+            .count();
+        let status = if targets.is_empty() {
+            SummaryStatus::NoResults
+        } else if failed > 0 {
+            SummaryStatus::Fail
+        } else {
+            SummaryStatus::Pass
+        };
+
+        Self {
+            schema_version: SUMMARY_SCHEMA_VERSION,
+            generated_at: Utc::now(),
+            status,
+            threshold,
+            total: targets.len(),
+            passed,
+            failed,
+            targets,
+            comparison: None,
+        }
+    }
+
+    /// Attach the differences between `previous` and the summarized `results`
+    pub fn compare_with(self, previous: &[BenchmarkResult], results: &[BenchmarkResult]) -> Self {
+        Self {
+            comparison: Some(diff_results(previous, results)),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_results() -> Vec<BenchmarkResult> {
+        vec![
+            BenchmarkResult::new("fast".to_string(), json!({ "duration_ms": 120.0 })),
+            BenchmarkResult::new("slow".to_string(), json!({ "duration_ms": 900.0 })),
+            BenchmarkResult::new("other".to_string(), json!({ "items": 3 })),
+        ]
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        let threshold: Threshold = "duration_ms<=500".parse().unwrap();
+        assert_eq!(threshold.metric, "duration_ms");
+        assert_eq!(threshold.comparison, ThresholdComparison::AtMost);
+        assert_eq!(threshold.value, 500.0);
+        assert_eq!(threshold.to_string(), "duration_ms<=500");
+
+        let threshold: Threshold = "success_rate >= 0.95".parse().unwrap();
+        assert_eq!(threshold.comparison, ThresholdComparison::AtLeast);
+        assert_eq!(threshold.value, 0.95);
+
+        assert!("duration_ms=500".parse::<Threshold>().is_err());
+        assert!("<=500".parse::<Threshold>().is_err());
+        assert!("duration_ms<=fast".parse::<Threshold>().is_err());
+    }
+
+    #[test]
+    fn test_summary_with_threshold() {
+        let summary = RunSummary::new(&sample_results(), "duration_ms<=500".parse().ok());
+
+        assert_eq!(summary.status, SummaryStatus::Fail);
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.passed, 1);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.targets[0].status, TargetStatus::Pass);
+        assert_eq!(summary.targets[1].status, TargetStatus::Fail);
+        assert_eq!(summary.targets[2].status, TargetStatus::Unchecked);
+    }
+
+    #[test]
+    fn test_summary_without_threshold() {
+        let summary = RunSummary::new(&sample_results(), None);
+        assert_eq!(summary.status, SummaryStatus::Pass);
+        assert_eq!(summary.failed, 0);
+
+        let summary = RunSummary::new(&[], None);
+        assert_eq!(summary.status, SummaryStatus::NoResults);
+    }
+
+    #[test]
+    fn test_summary_json_schema() {
+        let summary = RunSummary::new(&sample_results(), "duration_ms<=500".parse().ok());
+        let value = serde_json::to_value(&summary).unwrap();
+
+        for key in [
+            "schema_version",
+            "generated_at",
+            "status",
+            "threshold",
+            "total",
+            "passed",
+            "failed",
+            "targets",
+        ] {
+            assert!(value.get(key).is_some(), "missing key {}", key);
+        }
+        assert_eq!(value["schema_version"], SUMMARY_SCHEMA_VERSION);
+        assert_eq!(value["status"], "fail");
+        assert_eq!(value["threshold"]["comparison"], "at_most");
+
+        let target = &value["targets"][0];
+        for key in ["target_id", "timestamp", "status", "metrics"] {
+            assert!(target.get(key).is_some(), "missing target key {}", key);
+        }
+        assert_eq!(target["status"], "pass");
+        assert_eq!(target["metrics"]["duration_ms"], 120.0);
+
+        // Only present when comparing
+        assert!(value.get("comparison").is_none());
+    }
+
+    #[test]
+    fn test_summary_comparison() {
+        let previous = vec![
+            BenchmarkResult::new("fast".to_string(), json!({ "duration_ms": 100.0 })),
+            BenchmarkResult::new("other".to_string(), json!({ "items": "three" })),
+            BenchmarkResult::new("gone".to_string(), json!({ "duration_ms": 1.0 })),
+        ];
+        let results = sample_results();
+        let summary = RunSummary::new(&results, None).compare_with(&previous, &results);
+        let value = serde_json::to_value(&summary).unwrap();
+
+        let comparison = &value["comparison"];
+        assert_eq!(comparison["added"], json!(["slow"]));
+        assert_eq!(comparison["removed"], json!(["gone"]));
+
+        let fast = &comparison["targets"][0];
+        assert_eq!(fast["target_id"], "fast");
+        assert_eq!(fast["comparison"]["kind"], "comparable");
+        assert_eq!(fast["comparison"]["deltas"][0]["delta"], 20.0);
+        assert_eq!(fast["comparison"]["deltas"][0]["percent_change"], 20.0);
+
+        let other = &comparison["targets"][1];
+        assert_eq!(other["comparison"]["kind"], "incomparable");
+        assert!(other["comparison"]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("string to number"));
+    }
+}
@@ -1,13 +1,14 @@
 //! Submission management commands
 
 use anyhow::{Context, Result};
+use llm_benchmark_sdk::{SdkError, SubmissionCancellation};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
-use crate::commands::CommandContext;
-use crate::interactive::{confirm_default_yes, spinner};
-use crate::output::{colors, TableFormatter};
+use crate::commands::{resolve_id, stream_ndjson, CommandContext};
+use crate::interactive::{confirm, confirm_default_yes, spinner, PickerCandidate};
+use crate::output::{colors, OutputFormat, TableFormatter};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Submission {
@@ -102,7 +103,24 @@ pub async fn submit(
 }
 
 /// Show submission details
-pub async fn show(ctx: &CommandContext, submission_id: String) -> Result<()> {
+pub async fn show(ctx: &CommandContext, submission_id: Option<String>) -> Result<()> {
+    let submission_id = resolve_id(
+        ctx,
+        submission_id,
+        "/api/v1/submissions",
+        "Select a submission",
+        |list: SubmissionList| {
+            list.submissions
+                .into_iter()
+                .map(|s| {
+                    let label = format!("{} {} ({})", s.model_name, s.model_version, s.status);
+                    PickerCandidate::new(s.id, label)
+                })
+                .collect()
+        },
+    )
+    .await?;
+
     let sp = spinner("Fetching submission details...");
 
     let submission: Submission = ctx
@@ -112,6 +130,7 @@ pub async fn show(ctx: &CommandContext, submission_id: String) -> Result<()> {
 
     sp.finish_and_clear();
 
+    let fmt = ctx.config.locale_formatter();
     let items = vec![
         ("ID", submission.id),
         ("Benchmark ID", submission.benchmark_id),
@@ -120,7 +139,7 @@ pub async fn show(ctx: &CommandContext, submission_id: String) -> Result<()> {
         ("Submitter ID", submission.submitter_id),
         ("Status", submission.status),
         ("Verified", submission.verified.to_string()),
-        ("Submitted At", submission.submitted_at),
+        ("Submitted At", fmt.format_timestamp(&submission.submitted_at)),
     ];
 
     let table = TableFormatter::key_value(items)?;
@@ -139,10 +158,21 @@ pub async fn list(ctx: &CommandContext, benchmark_id: Option<String>) -> Result<
         "/api/v1/submissions".to_string()
     };
 
+    if ctx.config.output_format == OutputFormat::Ndjson {
+        sp.finish_and_clear();
+        stream_ndjson(ctx, &path, |list: SubmissionList| (list.submissions, list.total)).await?;
+        return Ok(());
+    }
+
     let list: SubmissionList = ctx.client.get(&path).await?;
 
     sp.finish_and_clear();
 
+    if ctx.config.output_format == OutputFormat::Json {
+        println!("{}", ctx.config.json_formatter().format(&list)?);
+        return Ok(());
+    }
+
     if list.submissions.is_empty() {
         println!("{}", colors::warning("No submissions found."));
         return Ok(());
@@ -157,6 +187,7 @@ pub async fn list(ctx: &CommandContext, benchmark_id: Option<String>) -> Result<
         "Verified",
         "Submitted",
     ];
+    let fmt = ctx.config.locale_formatter();
     let rows: Vec<Vec<String>> = list
         .submissions
         .iter()
@@ -168,14 +199,14 @@ pub async fn list(ctx: &CommandContext, benchmark_id: Option<String>) -> Result<
                 s.model_version.clone(),
                 s.status.clone(),
                 if s.verified { "Yes" } else { "No" }.to_string(),
-                s.submitted_at.clone(),
+                fmt.format_timestamp(&s.submitted_at),
             ]
         })
         .collect();
 
     let table = TableFormatter::simple(headers, rows)?;
     println!("{}", table);
-    println!("{} submissions found", colors::dim(&list.total.to_string()));
+    println!("{} submissions found", colors::dim(&fmt.format_count(list.total)));
 
     Ok(())
 }
@@ -210,6 +241,51 @@ pub async fn request_verification(ctx: &CommandContext, submission_id: String) -
     Ok(())
 }
 
+/// Cancel a pending submission
+pub async fn cancel(ctx: &CommandContext, submission_id: String) -> Result<()> {
+    ctx.require_auth()?;
+
+    let confirmed = confirm(&format!("Cancel submission {}?", submission_id))?;
+    if !confirmed {
+        println!("Submission left unchanged.");
+        return Ok(());
+    }
+
+    let sp = spinner("Cancelling submission...");
+    let cancellation = cancel_submission(ctx, &submission_id).await;
+    sp.finish_and_clear();
+    let cancellation = cancellation?;
+
+    println!("{}", colors::success("Submission cancelled"));
+    println!("  ID: {}", cancellation.id);
+    println!("  Status: {}", cancellation.status);
+    println!(
+        "  Cancelled: {}",
+        ctx.config.locale_formatter().format_datetime(&cancellation.cancelled_at)
+    );
+
+    Ok(())
+}
+
+/// Cancel a submission through the SDK, explaining refusals
+pub async fn cancel_submission(
+    ctx: &CommandContext,
+    submission_id: &str,
+) -> Result<SubmissionCancellation> {
+    let client = ctx.sdk_client()?;
+    match client.submissions().cancel(submission_id).await {
+        Ok(cancellation) => Ok(cancellation),
+        Err(SdkError::Conflict { message }) => {
+            anyhow::bail!("Submission {} cannot be cancelled: {}", submission_id, message)
+        }
+        Err(SdkError::Forbidden { .. }) => {
+            anyhow::bail!("Submission {} belongs to another user", submission_id)
+        }
+        Err(SdkError::NotFound { .. }) => anyhow::bail!("Submission not found: {}", submission_id),
+        Err(e) => Err(e.into()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,11 +2,29 @@
 //!
 //! Handles loading and saving configuration from ~/.llm-benchmark/config.toml
 
-use crate::output::OutputFormat;
+use crate::output::{parse_date_format, JsonFormatter, Locale, LocaleFormatter, OutputFormat};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Keys accepted by [`Config::get`] and [`Config::set`]
+pub const CONFIG_KEYS: &[&str] = &[
+    "api_endpoint",
+    "auth_token",
+    "output_format",
+    "colored",
+    "timeout_seconds",
+    "debug",
+    "locale",
+    "date_format",
+];
+
+/// Keys whose values [`Config::get`] masks
+const SECRET_KEYS: &[&str] = &["auth_token"];
+
+/// Shown in place of a secret value
+const MASKED_VALUE: &str = "***";
 
 /// CLI configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +52,18 @@ pub struct Config {
     /// Enable debug logging
     #[serde(default)]
     pub debug: bool,
+
+    /// Locale for numbers and timestamps in table and plain output
+    #[serde(default)]
+    pub locale: Locale,
+
+    /// `strftime` pattern overriding the locale's timestamp format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+
+    /// Emit single-line JSON (resolved per invocation, never persisted)
+    #[serde(skip)]
+    pub json_compact: bool,
 }
 
 fn default_api_endpoint() -> String {
@@ -57,6 +87,9 @@ impl Default for Config {
             colored: default_colored(),
             timeout_seconds: default_timeout(),
             debug: false,
+            locale: Locale::default(),
+            date_format: None,
+            json_compact: false,
         }
     }
 }
@@ -88,10 +121,14 @@ impl Config {
         if let Ok(format) = std::env::var("LLM_BENCHMARK_OUTPUT_FORMAT") {
             config.output_format = match format.to_lowercase().as_str() {
                 "json" => OutputFormat::Json,
+                "ndjson" => OutputFormat::Ndjson,
                 "plain" => OutputFormat::Plain,
                 _ => OutputFormat::Table,
             };
         }
+        if let Ok(locale) = std::env::var("LLM_BENCHMARK_LOCALE") {
+            config.locale = locale.parse::<Locale>().map_err(|e| anyhow::anyhow!(e))?;
+        }
         if std::env::var("LLM_BENCHMARK_DEBUG").is_ok() {
             config.debug = true;
         }
@@ -102,30 +139,57 @@ impl Config {
         Ok(config)
     }
 
-    /// Load configuration from file only
-    fn load_from_file() -> Result<Self> {
-        let config_file = Self::config_file()?;
+    /// Load configuration from the config file only, ignoring environment
+    /// overrides
+    pub fn load_from_file() -> Result<Self> {
+        Self::load_file(&Self::config_file()?)
+    }
 
-        if !config_file.exists() {
+    /// Load configuration from `path`, or the defaults if it doesn't exist
+    pub fn load_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
             return Ok(Self::default());
         }
 
-        let contents = fs::read_to_string(&config_file).context("Failed to read config file")?;
+        let contents = fs::read_to_string(path).context("Failed to read config file")?;
         let config: Config = toml::from_str(&contents).context("Failed to parse config file")?;
 
         Ok(config)
     }
 
+    /// Number and timestamp formatter for the configured locale
+    pub fn locale_formatter(&self) -> LocaleFormatter {
+        let formatter = LocaleFormatter::new(self.locale);
+        match &self.date_format {
+            Some(date_format) => formatter.with_date_format(date_format.clone()),
+            None => formatter,
+        }
+    }
+
+    /// JSON formatter matching the requested output style
+    pub fn json_formatter(&self) -> JsonFormatter {
+        if self.json_compact {
+            JsonFormatter::compact()
+        } else {
+            JsonFormatter::pretty()
+        }
+    }
+
     /// Save configuration to file
     pub fn save(&self) -> Result<()> {
-        let config_dir = Self::config_dir()?;
-        if !config_dir.exists() {
-            fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
+        self.save_to(&Self::config_file()?)
+    }
+
+    /// Save configuration to `path`, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            if !dir.exists() {
+                fs::create_dir_all(dir).context("Failed to create config directory")?;
+            }
         }
 
-        let config_file = Self::config_file()?;
         let contents = toml::to_string_pretty(self).context("Failed to serialize config")?;
-        fs::write(&config_file, contents).context("Failed to write config file")?;
+        fs::write(path, contents).context("Failed to write config file")?;
 
         Ok(())
     }
@@ -147,54 +211,103 @@ impl Config {
         self.auth_token.is_some()
     }
 
-    /// Get a configuration value by key
-    pub fn get(&self, key: &str) -> Option<String> {
-        match key {
-            "api_endpoint" | "api-endpoint" | "api_url" => Some(self.api_endpoint.clone()),
-            "output_format" | "output-format" | "format" => Some(format!("{:?}", self.output_format)),
-            "colored" | "color" => Some(self.colored.to_string()),
-            "timeout" | "timeout_seconds" => Some(self.timeout_seconds.to_string()),
-            "debug" => Some(self.debug.to_string()),
-            "auth_token" | "token" => self.auth_token.clone(),
-            _ => None,
+    /// Get a configuration value by key, for display
+    ///
+    /// Returns `None` for optional keys that are not set. Secrets such as
+    /// `auth_token` are masked; read the field itself to use the value.
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        let key = canonical_key(key)?;
+        let value = self.get_unmasked(key)?;
+        if SECRET_KEYS.contains(&key) {
+            return Ok(value.map(|_| MASKED_VALUE.to_string()));
         }
+        Ok(value)
+    }
+
+    fn get_unmasked(&self, key: &'static str) -> Result<Option<String>> {
+        Ok(match key {
+            "api_endpoint" => Some(self.api_endpoint.clone()),
+            "auth_token" => self.auth_token.clone(),
+            "output_format" => Some(self.output_format.to_string()),
+            "colored" => Some(self.colored.to_string()),
+            "timeout_seconds" => Some(self.timeout_seconds.to_string()),
+            "debug" => Some(self.debug.to_string()),
+            "locale" => Some(self.locale.to_string()),
+            "date_format" => self.date_format.clone(),
+            other => anyhow::bail!("Configuration key {} cannot be read", other),
+        })
     }
 
     /// Set a configuration value by key
+    ///
+    /// Only updates this value; call [`save`](Self::save) to persist it.
     pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
-        match key {
-            "api_endpoint" | "api-endpoint" | "api_url" => {
+        match canonical_key(key)? {
+            "api_endpoint" => {
                 self.api_endpoint = value.to_string();
             }
-            "output_format" | "output-format" | "format" => {
+            "auth_token" => {
+                self.auth_token = Some(value.to_string());
+            }
+            "output_format" => {
                 self.output_format = match value.to_lowercase().as_str() {
                     "json" => OutputFormat::Json,
+                    "ndjson" => OutputFormat::Ndjson,
                     "plain" => OutputFormat::Plain,
                     "table" => OutputFormat::Table,
-                    _ => anyhow::bail!("Invalid output format: {}. Use json, table, or plain", value),
+                    _ => anyhow::bail!(
+                        "Invalid output format: {}. Use json, ndjson, table, or plain",
+                        value
+                    ),
                 };
             }
-            "colored" | "color" => {
+            "colored" => {
                 self.colored = value.parse().context("Invalid boolean value")?;
             }
-            "timeout" | "timeout_seconds" => {
+            "timeout_seconds" => {
                 self.timeout_seconds = value.parse().context("Invalid timeout value")?;
             }
             "debug" => {
                 self.debug = value.parse().context("Invalid boolean value")?;
             }
-            _ => anyhow::bail!("Unknown configuration key: {}", key),
+            "locale" => {
+                self.locale = value.parse::<Locale>().map_err(|e| anyhow::anyhow!(e))?;
+            }
+            "date_format" => {
+                self.date_format = Some(parse_date_format(value).map_err(|e| anyhow::anyhow!(e))?);
+            }
+            other => anyhow::bail!("Configuration key {} cannot be set", other),
         }
-        self.save()
+        Ok(())
     }
 
     /// Reset configuration to defaults
-    pub fn reset(&mut self) -> Result<()> {
+    ///
+    /// Only updates this value; call [`save`](Self::save) to persist it.
+    pub fn reset(&mut self) {
         *self = Self::default();
-        self.save()
     }
 }
 
+/// Resolve a key or one of its aliases to its name in [`CONFIG_KEYS`]
+fn canonical_key(key: &str) -> Result<&'static str> {
+    Ok(match key {
+        "api_endpoint" | "api-endpoint" | "api_url" => "api_endpoint",
+        "auth_token" | "auth-token" | "token" => "auth_token",
+        "output_format" | "output-format" | "format" => "output_format",
+        "colored" | "color" => "colored",
+        "timeout_seconds" | "timeout-seconds" | "timeout" => "timeout_seconds",
+        "debug" => "debug",
+        "locale" => "locale",
+        "date_format" | "date-format" => "date_format",
+        _ => anyhow::bail!(
+            "Unknown configuration key: {}. Valid keys: {}",
+            key,
+            CONFIG_KEYS.join(", ")
+        ),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,8 +332,104 @@ mod tests {
     #[test]
     fn test_config_get() {
         let config = Config::default();
-        assert_eq!(config.get("api_endpoint"), Some("https://api.llm-benchmark.org".to_string()));
-        assert_eq!(config.get("colored"), Some("true".to_string()));
-        assert_eq!(config.get("unknown"), None);
+        assert_eq!(
+            config.get("api_endpoint").unwrap(),
+            Some("https://api.llm-benchmark.org".to_string())
+        );
+        assert_eq!(config.get("colored").unwrap(), Some("true".to_string()));
+        assert_eq!(config.get("format").unwrap(), Some("table".to_string()));
+        assert_eq!(config.get("date_format").unwrap(), None);
+        assert_eq!(config.get("auth_token").unwrap(), None);
+    }
+
+    #[test]
+    fn test_config_get_masks_secrets() {
+        let mut config = Config::default();
+        config.set("token", "secret-token").unwrap();
+
+        assert_eq!(config.get("auth_token").unwrap().as_deref(), Some("***"));
+        assert_eq!(config.get("token").unwrap().as_deref(), Some("***"));
+        assert_eq!(config.auth_token.as_deref(), Some("secret-token"));
+    }
+
+    #[test]
+    fn test_set_rejects_invalid_date_format() {
+        let mut config = Config::default();
+        config.set("date_format", "%d.%m.%Y").unwrap();
+
+        let err = config.set("date-format", "%Y-%Q").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid date format"), "{}", err);
+        assert_eq!(config.date_format.as_deref(), Some("%d.%m.%Y"));
+    }
+
+    #[test]
+    fn test_unknown_key_lists_valid_keys() {
+        let mut config = Config::default();
+        let expected = "Unknown configuration key: unknown. Valid keys: api_endpoint, auth_token, \
+                        output_format, colored, timeout_seconds, debug, locale, date_format";
+
+        assert_eq!(config.get("unknown").unwrap_err().to_string(), expected);
+        assert_eq!(config.set("unknown", "x").unwrap_err().to_string(), expected);
+    }
+
+    #[test]
+    fn test_set_round_trips_through_config_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("config.toml");
+
+        let mut config = Config::load_file(&path).unwrap();
+        config.set("api_endpoint", "http://localhost:8080").unwrap();
+        config.set("output-format", "JSON").unwrap();
+        config.set("timeout", "90").unwrap();
+        config.set("token", "secret").unwrap();
+        config.save_to(&path).unwrap();
+
+        let reloaded = Config::load_file(&path).unwrap();
+        assert_eq!(reloaded.get("api_endpoint").unwrap().as_deref(), Some("http://localhost:8080"));
+        assert_eq!(reloaded.output_format, OutputFormat::Json);
+        assert_eq!(reloaded.timeout_seconds, 90);
+        assert_eq!(reloaded.auth_token.as_deref(), Some("secret"));
+
+        // An invalid value leaves the stored one untouched
+        let mut config = reloaded;
+        assert!(config.set("timeout_seconds", "soon").is_err());
+        assert_eq!(config.timeout_seconds, 90);
+    }
+
+    #[test]
+    fn test_reset_rewrites_defaults() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+
+        let mut config = Config::default();
+        config.set("colored", "false").unwrap();
+        config.set("locale", "de-DE").unwrap();
+        config.save_to(&path).unwrap();
+        assert!(!Config::load_file(&path).unwrap().colored);
+
+        config.reset();
+        config.save_to(&path).unwrap();
+
+        let reloaded = Config::load_file(&path).unwrap();
+        assert!(reloaded.colored);
+        assert_eq!(reloaded.locale, Locale::default());
+    }
+
+    #[test]
+    fn test_locale_formatter() {
+        let mut config = Config::default();
+        assert_eq!(config.locale_formatter().format_count(1234), "1,234");
+
+        config.locale = Locale::DeDe;
+        assert_eq!(config.locale_formatter().format_count(1234), "1.234");
+        assert_eq!(config.locale_formatter().format_timestamp("2024-03-09T14:05:00Z"), "09.03.2024 14:05");
+
+        config.date_format = Some("%Y-%m-%d".to_string());
+        assert_eq!(config.locale_formatter().format_timestamp("2024-03-09T14:05:00Z"), "2024-03-09");
+
+        let toml_str = toml::to_string(&config).unwrap();
+        let deserialized: Config = toml::from_str(&toml_str).unwrap();
+        assert_eq!(deserialized.locale, Locale::DeDe);
+        assert_eq!(deserialized.date_format.as_deref(), Some("%Y-%m-%d"));
     }
 }
@@ -0,0 +1,323 @@
+//! Benchmark definition loading
+//!
+//! Definitions may be JSON or YAML and can pull in other files with
+//! `{"$ref": "relative/path.json"}`. A reference is replaced by the parsed
+//! contents of the file it names; a reference inside an array whose target
+//! is itself an array is spliced in, so large test-case lists can be split
+//! across files:
+//!
+//! ```json
+//! {
+//!   "name": "Big QA",
+//!   "test_cases": [
+//!     {"$ref": "cases/part-1.json"},
+//!     {"$ref": "cases/part-2.yaml"},
+//!     {"id": "inline-1", "input": "..."}
+//!   ]
+//! }
+//! ```
+//!
+//! References resolve relative to the file that contains them, may not
+//! escape the root definition's directory, and may not form cycles.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Key marking a reference to another file
+pub const REF_KEY: &str = "$ref";
+
+/// Load a definition file and resolve every `$ref` it contains
+pub fn load_definition(path: &Path) -> Result<Value> {
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("File not found: {}", path.display()))?;
+    let root = path
+        .parent()
+        .context("Definition file has no parent directory")?
+        .to_path_buf();
+
+    let mut resolver = Resolver {
+        root,
+        stack: Vec::new(),
+    };
+    resolver.load(&path)
+}
+
+/// Serialization format of a definition file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionFormat {
+    Json,
+    Yaml,
+}
+
+impl DefinitionFormat {
+    /// Pick the format from the file extension: `.json`, `.yaml` or `.yml`
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Ok(Self::Json),
+            Some("yaml") | Some("yml") => Ok(Self::Yaml),
+            _ => anyhow::bail!(
+                "Unsupported definition file {}: expected a .json, .yaml or .yml extension",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// Parse definition text as YAML or JSON, based on the file extension
+///
+/// JSON is valid YAML, so a `.yaml` file may hold either. A `.json` file
+/// holding YAML is reported as such rather than as a JSON syntax error.
+pub fn parse_definition(path: &Path, content: &str) -> Result<Value> {
+    match DefinitionFormat::from_path(path)? {
+        DefinitionFormat::Yaml => {
+            let yaml: serde_yaml::Value =
+                serde_yaml::from_str(content).context("Failed to parse YAML")?;
+            Ok(serde_json::to_value(yaml)?)
+        }
+        DefinitionFormat::Json => serde_json::from_str(content).or_else(|e| {
+            // Malformed JSON is often still valid YAML, so only documents
+            // that don't open like JSON count as YAML
+            let looks_like_yaml = !content.trim_start().starts_with(['{', '['])
+                && matches!(
+                    serde_yaml::from_str::<serde_yaml::Value>(content),
+                    Ok(serde_yaml::Value::Mapping(_) | serde_yaml::Value::Sequence(_))
+                );
+            if looks_like_yaml {
+                anyhow::bail!(
+                    "{} has a .json extension but contains YAML; rename it to .yaml",
+                    path.display()
+                );
+            }
+            Err(e).context("Failed to parse JSON")
+        }),
+    }
+}
+
+struct Resolver {
+    /// Directory references may not escape
+    root: PathBuf,
+    /// Files currently being resolved, for cycle detection
+    stack: Vec<PathBuf>,
+}
+
+impl Resolver {
+    fn load(&mut self, path: &Path) -> Result<Value> {
+        if let Some(start) = self.stack.iter().position(|p| p == path) {
+            let cycle: Vec<String> = self.stack[start..]
+                .iter()
+                .chain(std::iter::once(&path.to_path_buf()))
+                .map(|p| self.display(p))
+                .collect();
+            anyhow::bail!("Circular $ref: {}", cycle.join(" -> "));
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", self.display(path)))?;
+        let value = parse_definition(path, &content)
+            .with_context(|| format!("Failed to parse {}", self.display(path)))?;
+
+        let dir = path.parent().unwrap_or(&self.root).to_path_buf();
+        self.stack.push(path.to_path_buf());
+        let resolved = self.resolve(value, &dir);
+        self.stack.pop();
+        resolved
+    }
+
+    fn resolve(&mut self, value: Value, dir: &Path) -> Result<Value> {
+        match value {
+            Value::Object(map) => {
+                if let Some(reference) = ref_target(&map) {
+                    let path = self.locate(reference, dir)?;
+                    return self.load(&path);
+                }
+                map.into_iter()
+                    .map(|(k, v)| Ok((k, self.resolve(v, dir)?)))
+                    .collect::<Result<_>>()
+                    .map(Value::Object)
+            }
+            Value::Array(items) => {
+                let mut out = Vec::with_capacity(items.len());
+                for item in items {
+                    let is_ref = matches!(&item, Value::Object(map) if ref_target(map).is_some());
+                    match self.resolve(item, dir)? {
+                        Value::Array(included) if is_ref => out.extend(included),
+                        resolved => out.push(resolved),
+                    }
+                }
+                Ok(Value::Array(out))
+            }
+            other => Ok(other),
+        }
+    }
+
+    /// Resolve a reference against `dir`, refusing paths outside the root
+    fn locate(&self, reference: &str, dir: &Path) -> Result<PathBuf> {
+        let candidate = dir.join(reference);
+        let path = candidate
+            .canonicalize()
+            .with_context(|| format!("$ref not found: {}", reference))?;
+
+        if !path.starts_with(&self.root) {
+            anyhow::bail!(
+                "$ref '{}' escapes the definition directory {}",
+                reference,
+                self.root.display()
+            );
+        }
+        Ok(path)
+    }
+
+    fn display(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+/// The file named by a `{"$ref": "..."}` object
+///
+/// Objects with other keys, and in-document references such as
+/// `#/definitions/x` (used by embedded JSON schemas), are left alone.
+fn ref_target(map: &serde_json::Map<String, Value>) -> Option<&str> {
+    if map.len() != 1 {
+        return None;
+    }
+    map.get(REF_KEY)
+        .and_then(Value::as_str)
+        .filter(|target| !target.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_definition_checks_extension() {
+        let yaml = "name: Sample\ntags:\n  - a\n";
+        assert_eq!(
+            parse_definition(Path::new("b.yml"), yaml).unwrap(),
+            json!({"name": "Sample", "tags": ["a"]})
+        );
+
+        let err = parse_definition(Path::new("b.json"), yaml).unwrap_err();
+        assert!(err.to_string().contains("contains YAML"), "{err}");
+
+        // Broken JSON is still reported as a JSON error
+        let err = parse_definition(Path::new("b.json"), r#"{"name": "#).unwrap_err();
+        assert_eq!(err.to_string(), "Failed to parse JSON");
+
+        let err = parse_definition(Path::new("b.txt"), "{}").unwrap_err();
+        assert!(err.to_string().contains("Unsupported definition file"), "{err}");
+    }
+
+    #[test]
+    fn test_resolves_two_file_definition() {
+        let dir = TempDir::new().unwrap();
+        let root = write(
+            dir.path(),
+            "benchmark.json",
+            r#"{
+                "name": "Split",
+                "test_cases": [
+                    {"$ref": "cases/extra.yaml"},
+                    {"id": "inline"}
+                ],
+                "config": {"$ref": "cases/config.json"}
+            }"#,
+        );
+        write(dir.path(), "cases/extra.yaml", "- id: tc-1\n- id: tc-2\n");
+        write(dir.path(), "cases/config.json", r#"{"timeout": 30}"#);
+
+        let definition = load_definition(&root).unwrap();
+
+        assert_eq!(
+            definition,
+            json!({
+                "name": "Split",
+                "test_cases": [{"id": "tc-1"}, {"id": "tc-2"}, {"id": "inline"}],
+                "config": {"timeout": 30},
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_refs_resolve_relative_to_their_file() {
+        let dir = TempDir::new().unwrap();
+        let root = write(
+            dir.path(),
+            "benchmark.json",
+            r#"{"test_cases": [{"$ref": "cases/index.json"}]}"#,
+        );
+        write(dir.path(), "cases/index.json", r#"[{"$ref": "more/a.json"}]"#);
+        write(dir.path(), "cases/more/a.json", r#"{"id": "deep"}"#);
+
+        let definition = load_definition(&root).unwrap();
+        assert_eq!(definition, json!({"test_cases": [{"id": "deep"}]}));
+    }
+
+    #[test]
+    fn test_rejects_parent_traversal() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "secret.json", r#"{"token": "hunter2"}"#);
+        let root = write(
+            dir.path(),
+            "bench/benchmark.json",
+            r#"{"test_cases": [{"$ref": "../secret.json"}]}"#,
+        );
+
+        let err = load_definition(&root).unwrap_err();
+        assert!(err.to_string().contains("escapes the definition directory"));
+    }
+
+    #[test]
+    fn test_rejects_cycles() {
+        let dir = TempDir::new().unwrap();
+        let root = write(dir.path(), "benchmark.json", r#"{"a": {"$ref": "a.json"}}"#);
+        write(dir.path(), "a.json", r#"{"b": {"$ref": "b.json"}}"#);
+        write(dir.path(), "b.json", r#"{"back": {"$ref": "a.json"}}"#);
+
+        let err = load_definition(&root).unwrap_err();
+        assert_eq!(err.to_string(), "Circular $ref: a.json -> b.json -> a.json");
+    }
+
+    #[test]
+    fn test_missing_ref() {
+        let dir = TempDir::new().unwrap();
+        let root = write(dir.path(), "benchmark.json", r#"{"a": {"$ref": "nope.json"}}"#);
+
+        let err = load_definition(&root).unwrap_err();
+        assert!(err.to_string().contains("$ref not found: nope.json"));
+    }
+
+    #[test]
+    fn test_schema_refs_are_left_alone() {
+        let dir = TempDir::new().unwrap();
+        let root = write(
+            dir.path(),
+            "benchmark.json",
+            r##"{
+                "schema": {"$ref": "#/defs/x"},
+                "other": {"$ref": "x.json", "type": "object"}
+            }"##,
+        );
+
+        let definition = load_definition(&root).unwrap();
+        assert_eq!(definition["schema"]["$ref"], "#/defs/x");
+        assert_eq!(definition["other"]["$ref"], "x.json");
+    }
+}
@@ -1,8 +1,9 @@
 //! Interactive prompts and dialogs
 
 use anyhow::Result;
-use dialoguer::{theme::ColorfulTheme, Confirm, Input, Password, Select};
+use dialoguer::{theme::ColorfulTheme, Confirm, FuzzySelect, Input, Password, Select};
 use indicatif::{ProgressBar, ProgressStyle};
+use std::io::IsTerminal;
 use std::time::Duration;
 
 /// Prompt for text input
@@ -59,6 +60,97 @@ pub fn select<T: ToString>(prompt: &str, items: &[T]) -> Result<usize> {
     Ok(selection)
 }
 
+/// An item offered by the fuzzy picker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickerCandidate {
+    /// Value returned when the candidate is selected
+    pub id: String,
+    /// Human-readable description shown next to the ID
+    pub label: String,
+}
+
+impl PickerCandidate {
+    /// Create a new candidate
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for PickerCandidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}  {}", self.id, self.label)
+    }
+}
+
+/// Whether both stdin and stdout are attached to a terminal
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// Filter candidates by a partial query.
+///
+/// Matching is case-insensitive: candidates whose ID starts with the query
+/// come first, then any whose ID or label contains the query's characters in
+/// order. An empty query matches everything.
+pub fn filter_candidates<'a>(
+    candidates: &'a [PickerCandidate],
+    query: &str,
+) -> Vec<&'a PickerCandidate> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return candidates.iter().collect();
+    }
+
+    let (mut prefixed, mut fuzzy): (Vec<_>, Vec<_>) = (Vec::new(), Vec::new());
+    for candidate in candidates {
+        let id = candidate.id.to_lowercase();
+        if id.starts_with(&query) {
+            prefixed.push(candidate);
+        } else if is_subsequence(&query, &id)
+            || is_subsequence(&query, &candidate.label.to_lowercase())
+        {
+            fuzzy.push(candidate);
+        }
+    }
+
+    prefixed.append(&mut fuzzy);
+    prefixed
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+/// Pick a candidate ID matching a partial query.
+///
+/// An exact ID match or a single filtered candidate is returned directly;
+/// otherwise the user chooses from a type-to-filter list seeded with the query.
+pub fn fuzzy_pick(prompt: &str, candidates: &[PickerCandidate], query: &str) -> Result<String> {
+    if let Some(exact) = candidates.iter().find(|c| c.id == query) {
+        return Ok(exact.id.clone());
+    }
+
+    let matches = filter_candidates(candidates, query);
+    match matches.as_slice() {
+        [] => anyhow::bail!("No matches found for '{}'", query),
+        [only] => Ok(only.id.clone()),
+        _ => {
+            let items: Vec<String> = matches.iter().map(|c| c.to_string()).collect();
+            let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
+                .with_prompt(prompt)
+                .with_initial_text(query.trim())
+                .items(&items)
+                .default(0)
+                .interact()?;
+            Ok(matches[selection].id.clone())
+        }
+    }
+}
+
 /// Create a progress bar
 pub fn progress_bar(len: u64) -> ProgressBar {
     let pb = ProgressBar::new(len);
@@ -88,6 +180,56 @@ pub fn spinner(message: &str) -> ProgressBar {
 mod tests {
     use super::*;
 
+    fn candidates() -> Vec<PickerCandidate> {
+        vec![
+            PickerCandidate::new("mmlu", "Massive Multitask Language Understanding"),
+            PickerCandidate::new("mmlu-pro", "MMLU Pro"),
+            PickerCandidate::new("humaneval", "HumanEval code generation"),
+            PickerCandidate::new("gsm8k", "Grade school math"),
+        ]
+    }
+
+    fn ids<'a>(matches: &[&'a PickerCandidate]) -> Vec<&'a str> {
+        matches.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_filter_candidates_empty_query_matches_all() {
+        let candidates = candidates();
+        assert_eq!(filter_candidates(&candidates, "").len(), 4);
+        assert_eq!(filter_candidates(&candidates, "   ").len(), 4);
+    }
+
+    #[test]
+    fn test_filter_candidates_prefix_first() {
+        let candidates = candidates();
+        let matches = filter_candidates(&candidates, "MM");
+        assert_eq!(ids(&matches), vec!["mmlu", "mmlu-pro"]);
+
+        // The ID prefix match is ranked ahead of earlier label matches
+        let matches = filter_candidates(&candidates, "g");
+        assert_eq!(ids(&matches), vec!["gsm8k", "mmlu", "humaneval"]);
+    }
+
+    #[test]
+    fn test_filter_candidates_matches_label() {
+        let candidates = candidates();
+        let matches = filter_candidates(&candidates, "code");
+        assert_eq!(ids(&matches), vec!["humaneval"]);
+
+        assert!(filter_candidates(&candidates, "xyz").is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_pick_without_prompt() {
+        let candidates = candidates();
+        // Exact ID wins even when other candidates also match
+        assert_eq!(fuzzy_pick("Pick", &candidates, "mmlu").unwrap(), "mmlu");
+        // A single match is returned without prompting
+        assert_eq!(fuzzy_pick("Pick", &candidates, "hum").unwrap(), "humaneval");
+        assert!(fuzzy_pick("Pick", &candidates, "xyz").is_err());
+    }
+
     #[test]
     fn test_progress_bar_creation() {
         let pb = progress_bar(100);
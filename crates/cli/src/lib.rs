@@ -7,12 +7,16 @@
 pub mod client;
 pub mod commands;
 pub mod config;
+pub mod definition;
 pub mod interactive;
 pub mod output;
 
 pub use client::ApiClient;
 pub use config::Config;
-pub use output::{JsonFormatter, OutputFormat, PlainFormatter, TableFormatter};
+pub use output::{
+    JsonFormatter, Locale, LocaleFormatter, NdjsonFormatter, OutputFormat, PlainFormatter,
+    TableFormatter,
+};
 
 /// Re-export common types
 pub use anyhow::{Context, Result};
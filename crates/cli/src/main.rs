@@ -9,7 +9,7 @@ use llm_benchmark_cli::commands::{
     auth, benchmark, init, leaderboard, proposal, run, submit, CommandContext,
 };
 use llm_benchmark_cli::config::Config;
-use llm_benchmark_cli::output::OutputFormat;
+use llm_benchmark_cli::output::{colors, parse_date_format, JsonFormatter, Locale, OutputFormat};
 
 /// Output format for CLI commands
 #[derive(Copy, Clone, Debug, Default, ValueEnum)]
@@ -21,6 +21,8 @@ pub enum CliOutputFormat {
     Table,
     /// Plain text output
     Plain,
+    /// Newline-delimited JSON (streamed for list commands)
+    Ndjson,
 }
 
 impl From<CliOutputFormat> for OutputFormat {
@@ -29,6 +31,7 @@ impl From<CliOutputFormat> for OutputFormat {
             CliOutputFormat::Json => OutputFormat::Json,
             CliOutputFormat::Table => OutputFormat::Table,
             CliOutputFormat::Plain => OutputFormat::Plain,
+            CliOutputFormat::Ndjson => OutputFormat::Ndjson,
         }
     }
 }
@@ -40,9 +43,21 @@ impl From<CliOutputFormat> for OutputFormat {
     Manage benchmarks, submit evaluation results, view leaderboards, and participate in governance.")]
 #[command(propagate_version = true)]
 struct Cli {
-    /// Output format
-    #[arg(short = 'o', long, global = true, value_enum, default_value = "table")]
-    format: CliOutputFormat,
+    /// Output format (overrides config, which defaults to table)
+    #[arg(short = 'o', long, global = true, value_enum)]
+    format: Option<CliOutputFormat>,
+
+    /// Emit single-line JSON (default: pretty on a terminal, compact when piped)
+    #[arg(long, global = true)]
+    json_compact: bool,
+
+    /// Locale for numbers and timestamps, e.g. en-US, de-DE (overrides config)
+    #[arg(long, global = true)]
+    locale: Option<Locale>,
+
+    /// strftime pattern for timestamps, e.g. "%Y-%m-%d %H:%M" (overrides the locale)
+    #[arg(long, global = true, value_parser = parse_date_format)]
+    date_format: Option<String>,
 
     /// API endpoint URL (overrides config)
     #[arg(long, global = true, env = "LLM_BENCHMARK_API_URL")]
@@ -229,9 +244,9 @@ enum BenchmarkCommands {
 
     /// Show benchmark details
     Show {
-        /// Benchmark ID or slug
+        /// Benchmark ID or slug (partial or omitted to pick interactively)
         #[arg(value_name = "ID")]
-        id: String,
+        id: Option<String>,
 
         /// Show version history
         #[arg(long)]
@@ -288,6 +303,10 @@ enum BenchmarkCommands {
         #[arg(value_name = "ID")]
         id: String,
 
+        /// Benchmark version (defaults to the latest)
+        #[arg(long, value_name = "VERSION")]
+        benchmark_version: Option<String>,
+
         /// Output directory
         #[arg(short, long, default_value = ".")]
         output: String,
@@ -337,9 +356,9 @@ enum SubmitCommands {
 
     /// Show submission details
     Show {
-        /// Submission ID
+        /// Submission ID (partial or omitted to pick interactively)
         #[arg(value_name = "ID")]
-        id: String,
+        id: Option<String>,
 
         /// Show full results
         #[arg(long)]
@@ -470,9 +489,9 @@ enum ProposalCommands {
 
     /// Show proposal details
     Show {
-        /// Proposal ID
+        /// Proposal ID (partial or omitted to pick interactively)
         #[arg(value_name = "ID")]
-        id: String,
+        id: Option<String>,
 
         /// Show comments
         #[arg(long)]
@@ -578,8 +597,20 @@ enum RunCommands {
     /// Show benchmark results summary
     Summary {
         /// Directory containing benchmark results
-        #[arg(short, long)]
+        #[arg(long)]
         output: Option<String>,
+
+        /// Fail targets whose metric misses the threshold, e.g. "duration_ms<=500"
+        #[arg(long, value_name = "METRIC<=VALUE|METRIC>=VALUE")]
+        threshold: Option<run::Threshold>,
+
+        /// Compare against the results in an earlier output directory
+        #[arg(long, value_name = "OLD_DIR")]
+        compare: Option<String>,
+
+        /// Output the summary as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -629,8 +660,17 @@ async fn main() -> Result<()> {
         config.auth_token = Some(token.clone());
     }
 
-    // Set output format
-    config.output_format = cli.format.into();
+    if let Some(locale) = cli.locale {
+        config.locale = locale;
+    }
+    if let Some(date_format) = &cli.date_format {
+        config.date_format = Some(date_format.clone());
+    }
+
+    if let Some(format) = cli.format {
+        config.output_format = format.into();
+    }
+    config.json_compact = cli.json_compact || JsonFormatter::for_stdout().is_compact();
 
     let mut ctx = CommandContext::new_with_execution(
         config,
@@ -667,15 +707,15 @@ async fn main() -> Result<()> {
             BenchmarkCommands::SubmitForReview { id, message: _ } => {
                 benchmark::submit_for_review(&ctx, id).await
             }
-            BenchmarkCommands::Validate { file, strict: _ } => benchmark::validate(file).await,
-            BenchmarkCommands::Download { id: _, output: _ } => {
-                println!("Download command not yet implemented");
-                Ok(())
-            }
-            BenchmarkCommands::Stats { id: _ } => {
-                println!("Stats command not yet implemented");
-                Ok(())
+            BenchmarkCommands::Validate { file, strict } => {
+                benchmark::validate(file, strict).await
             }
+            BenchmarkCommands::Download {
+                id,
+                benchmark_version,
+                output,
+            } => benchmark::download(&ctx, id, benchmark_version, output).await,
+            BenchmarkCommands::Stats { id } => benchmark::stats(&ctx, id).await,
         },
 
         Commands::Submit { command } => match command {
@@ -699,10 +739,7 @@ async fn main() -> Result<()> {
             SubmitCommands::RequestVerification { id, level: _ } => {
                 submit::request_verification(&ctx, id).await
             }
-            SubmitCommands::Cancel { id: _ } => {
-                println!("Cancel command not yet implemented");
-                Ok(())
-            }
+            SubmitCommands::Cancel { id } => submit::cancel(&ctx, id).await,
         },
 
         Commands::Leaderboard { command } => match command {
@@ -723,12 +760,9 @@ async fn main() -> Result<()> {
                 output,
             } => leaderboard::export(&ctx, benchmark_id, format, output).await,
             LeaderboardCommands::Watch {
-                benchmark_id: _,
-                interval: _,
-            } => {
-                println!("Watch command not yet implemented");
-                Ok(())
-            }
+                benchmark_id,
+                interval,
+            } => leaderboard::watch(&ctx, benchmark_id, interval).await,
         },
 
         Commands::Proposal { command } => match command {
@@ -752,8 +786,8 @@ async fn main() -> Result<()> {
             ProposalCommands::Comment {
                 id,
                 message,
-                reply_to: _,
-            } => proposal::comment(&ctx, id, message).await,
+                reply_to,
+            } => proposal::comment(&ctx, id, message, reply_to).await,
             ProposalCommands::Withdraw { id: _, reason: _ } => {
                 println!("Withdraw command not yet implemented");
                 Ok(())
@@ -784,16 +818,19 @@ async fn main() -> Result<()> {
                     println!("  Output Format: {:?}", ctx.config.output_format);
                 }
                 Some(ConfigCommands::Set { key, value }) => {
-                    println!("Setting {} = {}", key, value);
-                    // TODO: Implement config set
-                }
-                Some(ConfigCommands::Get { key }) => {
-                    println!("Getting {}", key);
-                    // TODO: Implement config get
+                    // Edit the file as stored, without environment or flag overrides
+                    let mut config = Config::load_from_file()?;
+                    config.set(&key, &value)?;
+                    config.save()?;
+                    println!("{}", colors::success(&format!("Updated {}", key)));
                 }
+                Some(ConfigCommands::Get { key }) => match ctx.config.get(&key)? {
+                    Some(value) => println!("{}", value),
+                    None => println!("{}", colors::dim("(not set)")),
+                },
                 Some(ConfigCommands::Reset) => {
-                    println!("Resetting configuration to defaults");
-                    // TODO: Implement config reset
+                    Config::default().save()?;
+                    println!("{}", colors::success("Configuration reset to defaults"));
                 }
             }
             Ok(())
@@ -806,16 +843,38 @@ async fn main() -> Result<()> {
 
         Commands::Run { command } => match command {
             RunCommands::All { output, json } => {
-                run::run_all(output.map(std::path::PathBuf::from), json).await
+                run::run_all(
+                    output.map(std::path::PathBuf::from),
+                    json.then(|| ctx.config.json_formatter()),
+                )
+                .await
             }
             RunCommands::Single {
                 target_id,
                 output,
                 json,
-            } => run::run_single(target_id, output.map(std::path::PathBuf::from), json).await,
+            } => {
+                run::run_single(
+                    target_id,
+                    output.map(std::path::PathBuf::from),
+                    json.then(|| ctx.config.json_formatter()),
+                )
+                .await
+            }
             RunCommands::List => run::list().await,
-            RunCommands::Summary { output } => {
-                run::show_summary(output.map(std::path::PathBuf::from)).await
+            RunCommands::Summary {
+                output,
+                threshold,
+                compare,
+                json,
+            } => {
+                run::show_summary(
+                    output.map(std::path::PathBuf::from),
+                    threshold,
+                    compare.map(std::path::PathBuf::from),
+                    json.then(|| ctx.config.json_formatter()),
+                )
+                .await
             }
         },
     };
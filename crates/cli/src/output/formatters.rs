@@ -2,14 +2,102 @@
 
 use anyhow::Result;
 use serde::Serialize;
+use std::io::{IsTerminal, Write};
 
 /// JSON formatter
-pub struct JsonFormatter;
+///
+/// Pretty output is indented for reading in a terminal; compact output puts
+/// each document on a single line so it can be piped into other tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JsonFormatter {
+    compact: bool,
+}
 
 impl JsonFormatter {
-    /// Format a value as pretty JSON
+    /// Indented, multi-line JSON
+    pub fn pretty() -> Self {
+        Self { compact: false }
+    }
+
+    /// Single-line JSON
+    pub fn compact() -> Self {
+        Self { compact: true }
+    }
+
+    /// Pretty when stdout is a terminal, compact when it is piped or redirected
+    pub fn for_stdout() -> Self {
+        if std::io::stdout().is_terminal() {
+            Self::pretty()
+        } else {
+            Self::compact()
+        }
+    }
+
+    /// Whether this formatter produces single-line JSON
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
+    /// Format a value as JSON
+    pub fn format<T: Serialize>(&self, value: &T) -> Result<String> {
+        if self.compact {
+            Ok(serde_json::to_string(value)?)
+        } else {
+            Ok(serde_json::to_string_pretty(value)?)
+        }
+    }
+}
+
+/// Newline-delimited JSON formatter
+///
+/// Arrays are written as one compact JSON document per element; any other
+/// value becomes a single line.
+pub struct NdjsonFormatter;
+
+impl NdjsonFormatter {
+    /// Format a value as NDJSON
     pub fn format<T: Serialize>(value: &T) -> Result<String> {
-        Ok(serde_json::to_string_pretty(value)?)
+        let mut out = Vec::new();
+        let mut writer = NdjsonWriter::new(&mut out);
+        match serde_json::to_value(value)? {
+            serde_json::Value::Array(items) => {
+                for item in &items {
+                    writer.write(item)?;
+                }
+            }
+            other => writer.write(&other)?,
+        }
+        Ok(String::from_utf8(out)?)
+    }
+}
+
+/// Streaming NDJSON writer
+///
+/// Each item is written and flushed as soon as it is available, so large
+/// result sets never have to be buffered in memory.
+pub struct NdjsonWriter<W: Write> {
+    inner: W,
+    count: usize,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    /// Wrap a writer
+    pub fn new(inner: W) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// Write one item as a single JSON line
+    pub fn write<T: Serialize>(&mut self, item: &T) -> Result<()> {
+        serde_json::to_writer(&mut self.inner, item)?;
+        self.inner.write_all(b"\n")?;
+        self.inner.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Number of items written so far
+    pub fn count(&self) -> usize {
+        self.count
     }
 }
 
@@ -68,11 +156,88 @@ mod tests {
             name: "test".to_string(),
             count: 42,
         };
-        let result = JsonFormatter::format(&data);
+        let result = JsonFormatter::default().format(&data);
         assert!(result.is_ok());
         assert!(result.unwrap().contains("test"));
     }
 
+    #[test]
+    fn test_json_formatter_compact() {
+        let data = vec![
+            TestData {
+                name: "first".to_string(),
+                count: 1,
+            },
+            TestData {
+                name: "second".to_string(),
+                count: 2,
+            },
+        ];
+        let output = JsonFormatter::compact().format(&data).unwrap();
+        assert!(!output.contains('\n'));
+        assert_eq!(
+            output,
+            r#"[{"name":"first","count":1},{"name":"second","count":2}]"#
+        );
+    }
+
+    #[test]
+    fn test_json_formatter_pretty() {
+        let data = TestData {
+            name: "test".to_string(),
+            count: 42,
+        };
+        let output = JsonFormatter::pretty().format(&data).unwrap();
+        assert!(output.contains('\n'));
+        assert!(output.contains("\n  \"name\": \"test\""));
+        assert!(output.contains("\n  \"count\": 42"));
+    }
+
+    #[test]
+    fn test_ndjson_writer_lines_are_valid_json() {
+        let mut out = Vec::new();
+        let mut writer = NdjsonWriter::new(&mut out);
+        for i in 0..25 {
+            writer
+                .write(&TestData {
+                    name: format!("item-{}", i),
+                    count: i,
+                })
+                .unwrap();
+        }
+        assert_eq!(writer.count(), 25);
+
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 25);
+        for (i, line) in lines.iter().enumerate() {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["count"], i as i64);
+        }
+    }
+
+    #[test]
+    fn test_ndjson_formatter() {
+        let data = vec![
+            TestData {
+                name: "first".to_string(),
+                count: 1,
+            },
+            TestData {
+                name: "second".to_string(),
+                count: 2,
+            },
+        ];
+        let output = NdjsonFormatter::format(&data).unwrap();
+        assert_eq!(output.lines().count(), 2);
+        assert!(output
+            .lines()
+            .all(|line| serde_json::from_str::<serde_json::Value>(line).is_ok()));
+
+        let single = NdjsonFormatter::format(&data[0]).unwrap();
+        assert_eq!(single, "{\"count\":1,\"name\":\"first\"}\n");
+    }
+
     #[test]
     fn test_plain_formatter() {
         let data = TestData {
@@ -0,0 +1,330 @@
+//! Locale-aware number and date formatting
+//!
+//! Table and plain output run counts, scores and timestamps through a
+//! [`LocaleFormatter`] so they use the reader's grouping separators and date
+//! order. JSON and NDJSON output are unaffected.
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Supported output locales
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Locale {
+    /// English (United States)
+    #[default]
+    EnUs,
+    /// English (United Kingdom)
+    EnGb,
+    /// German (Germany)
+    DeDe,
+    /// French (France)
+    FrFr,
+    /// Japanese (Japan)
+    JaJp,
+}
+
+impl Locale {
+    /// All supported locales
+    pub fn all() -> &'static [Locale] {
+        &[Self::EnUs, Self::EnGb, Self::DeDe, Self::FrFr, Self::JaJp]
+    }
+
+    /// BCP 47 tag, e.g. `en-US`
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::EnUs => "en-US",
+            Self::EnGb => "en-GB",
+            Self::DeDe => "de-DE",
+            Self::FrFr => "fr-FR",
+            Self::JaJp => "ja-JP",
+        }
+    }
+
+    /// Separator between groups of thousands
+    pub fn grouping_separator(&self) -> char {
+        match self {
+            Self::EnUs | Self::EnGb | Self::JaJp => ',',
+            Self::DeDe => '.',
+            // Narrow no-break space, per CLDR
+            Self::FrFr => '\u{202f}',
+        }
+    }
+
+    /// Separator between the integer and fractional parts
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Self::EnUs | Self::EnGb | Self::JaJp => '.',
+            Self::DeDe | Self::FrFr => ',',
+        }
+    }
+
+    /// Default `strftime` pattern for timestamps
+    pub fn date_format(&self) -> &'static str {
+        match self {
+            Self::EnUs => "%m/%d/%Y %H:%M",
+            Self::EnGb | Self::FrFr => "%d/%m/%Y %H:%M",
+            Self::DeDe => "%d.%m.%Y %H:%M",
+            Self::JaJp => "%Y/%m/%d %H:%M",
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Accepts `en-US`, `en_US`, `en_US.UTF-8` and bare languages like `de`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.split('.').next().unwrap_or_default().replace('_', "-");
+        let tag = tag.to_lowercase();
+
+        let locale = match tag.as_str() {
+            "en-us" | "en" | "c" | "posix" => Self::EnUs,
+            "en-gb" => Self::EnGb,
+            "de-de" | "de" => Self::DeDe,
+            "fr-fr" | "fr" => Self::FrFr,
+            "ja-jp" | "ja" => Self::JaJp,
+            _ => {
+                let supported: Vec<&str> = Self::all().iter().map(|l| l.tag()).collect();
+                return Err(format!(
+                    "Unsupported locale: {}. Use one of {}",
+                    s,
+                    supported.join(", ")
+                ));
+            }
+        };
+        Ok(locale)
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.tag().to_string()
+    }
+}
+
+/// Check that `pattern` is a valid `strftime` pattern, for use as a clap
+/// value parser
+pub fn parse_date_format(pattern: &str) -> Result<String, String> {
+    if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+        return Err(format!(
+            "Invalid date format: {}. Use strftime specifiers such as \"%Y-%m-%d %H:%M\"",
+            pattern
+        ));
+    }
+    Ok(pattern.to_string())
+}
+
+/// Formats numbers and timestamps for a locale
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocaleFormatter {
+    locale: Locale,
+    date_format: Option<String>,
+}
+
+impl LocaleFormatter {
+    /// Formatter using the locale's default date pattern
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            date_format: None,
+        }
+    }
+
+    /// Override the locale's date pattern with an explicit `strftime` pattern
+    pub fn with_date_format(mut self, date_format: impl Into<String>) -> Self {
+        self.date_format = Some(date_format.into());
+        self
+    }
+
+    /// Locale used by this formatter
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Format an integer with grouping separators, e.g. `1,234,567`
+    pub fn format_integer(&self, value: i64) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let grouped = self.group_digits(&digits);
+        if value < 0 {
+            format!("-{}", grouped)
+        } else {
+            grouped
+        }
+    }
+
+    /// Format a count with grouping separators
+    pub fn format_count(&self, value: usize) -> String {
+        self.group_digits(&value.to_string())
+    }
+
+    /// Format a decimal with grouping and a fixed number of fractional digits
+    pub fn format_decimal(&self, value: f64, precision: usize) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let formatted = format!("{:.*}", precision, value.abs());
+        let (int_part, frac_part) = match formatted.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (formatted.as_str(), None),
+        };
+
+        let mut out = String::new();
+        // Rounding can turn a tiny negative value into zero
+        if value.is_sign_negative() && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            out.push('-');
+        }
+        out.push_str(&self.group_digits(int_part));
+        if let Some(frac_part) = frac_part {
+            out.push(self.locale.decimal_separator());
+            out.push_str(frac_part);
+        }
+        out
+    }
+
+    /// Format a timestamp in UTC
+    ///
+    /// An invalid date pattern falls back to the locale's default.
+    pub fn format_datetime(&self, value: &DateTime<Utc>) -> String {
+        let pattern = self
+            .date_format
+            .as_deref()
+            .filter(|pattern| parse_date_format(pattern).is_ok())
+            .unwrap_or_else(|| self.locale.date_format());
+        value.format(pattern).to_string()
+    }
+
+    /// Format an RFC 3339 timestamp string from the API
+    ///
+    /// Values that don't parse (such as bare dates) are returned unchanged.
+    pub fn format_timestamp(&self, value: &str) -> String {
+        match DateTime::parse_from_rfc3339(value) {
+            Ok(dt) => self.format_datetime(&dt.with_timezone(&Utc)),
+            Err(_) => value.to_string(),
+        }
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        let separator = self.locale.grouping_separator();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                out.push(separator);
+            }
+            out.push(c);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_locale_from_str() {
+        assert_eq!("en-US".parse::<Locale>().unwrap(), Locale::EnUs);
+        assert_eq!("de_DE.UTF-8".parse::<Locale>().unwrap(), Locale::DeDe);
+        assert_eq!("fr".parse::<Locale>().unwrap(), Locale::FrFr);
+        assert_eq!("JA-jp".parse::<Locale>().unwrap(), Locale::JaJp);
+        assert!("xx-YY".parse::<Locale>().is_err());
+
+        for locale in Locale::all() {
+            assert_eq!(&locale.tag().parse::<Locale>().unwrap(), locale);
+        }
+    }
+
+    #[test]
+    fn test_thousands_separators() {
+        let en = LocaleFormatter::new(Locale::EnUs);
+        assert_eq!(en.format_integer(0), "0");
+        assert_eq!(en.format_integer(999), "999");
+        assert_eq!(en.format_integer(1_000), "1,000");
+        assert_eq!(en.format_integer(1_234_567), "1,234,567");
+        assert_eq!(en.format_integer(-1_234_567), "-1,234,567");
+        assert_eq!(en.format_count(12_345), "12,345");
+
+        let de = LocaleFormatter::new(Locale::DeDe);
+        assert_eq!(de.format_integer(1_234_567), "1.234.567");
+
+        let fr = LocaleFormatter::new(Locale::FrFr);
+        assert_eq!(fr.format_integer(1_234_567), "1\u{202f}234\u{202f}567");
+    }
+
+    #[test]
+    fn test_decimal_formatting() {
+        let en = LocaleFormatter::new(Locale::EnUs);
+        assert_eq!(en.format_decimal(1234.56789, 2), "1,234.57");
+        assert_eq!(en.format_decimal(0.9123, 4), "0.9123");
+        assert_eq!(en.format_decimal(-0.00001, 2), "0.00");
+        assert_eq!(en.format_decimal(-1234.5, 1), "-1,234.5");
+        assert_eq!(en.format_decimal(42.0, 0), "42");
+
+        let de = LocaleFormatter::new(Locale::DeDe);
+        assert_eq!(de.format_decimal(1234.56789, 2), "1.234,57");
+
+        let fr = LocaleFormatter::new(Locale::FrFr);
+        assert_eq!(fr.format_decimal(1234567.5, 1), "1\u{202f}234\u{202f}567,5");
+    }
+
+    #[test]
+    fn test_datetime_formatting() {
+        let dt = Utc.with_ymd_and_hms(2024, 3, 9, 14, 5, 0).unwrap();
+
+        assert_eq!(LocaleFormatter::new(Locale::EnUs).format_datetime(&dt), "03/09/2024 14:05");
+        assert_eq!(LocaleFormatter::new(Locale::EnGb).format_datetime(&dt), "09/03/2024 14:05");
+        assert_eq!(LocaleFormatter::new(Locale::DeDe).format_datetime(&dt), "09.03.2024 14:05");
+        assert_eq!(LocaleFormatter::new(Locale::JaJp).format_datetime(&dt), "2024/03/09 14:05");
+
+        let custom = LocaleFormatter::new(Locale::DeDe).with_date_format("%Y-%m-%d");
+        assert_eq!(custom.format_datetime(&dt), "2024-03-09");
+
+        let invalid = LocaleFormatter::new(Locale::DeDe).with_date_format("%Y-%Q");
+        assert_eq!(invalid.format_datetime(&dt), "09.03.2024 14:05");
+    }
+
+    #[test]
+    fn test_parse_date_format() {
+        assert_eq!(parse_date_format("%Y-%m-%d %H:%M").unwrap(), "%Y-%m-%d %H:%M");
+        assert_eq!(parse_date_format("no specifiers").unwrap(), "no specifiers");
+
+        for invalid in ["%Q", "%Y-%", "%-"] {
+            let err = parse_date_format(invalid).unwrap_err();
+            assert!(err.starts_with("Invalid date format"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp_string() {
+        let de = LocaleFormatter::new(Locale::DeDe);
+        assert_eq!(de.format_timestamp("2024-03-09T14:05:00Z"), "09.03.2024 14:05");
+        assert_eq!(de.format_timestamp("2024-03-09T16:05:00+02:00"), "09.03.2024 14:05");
+        assert_eq!(de.format_timestamp("2024-01-01"), "2024-01-01");
+    }
+
+    #[test]
+    fn test_locale_serialization() {
+        assert_eq!(serde_json::to_string(&Locale::DeDe).unwrap(), "\"de-DE\"");
+        assert_eq!(serde_json::from_str::<Locale>("\"fr_FR\"").unwrap(), Locale::FrFr);
+        assert!(serde_json::from_str::<Locale>("\"xx\"").is_err());
+    }
+}
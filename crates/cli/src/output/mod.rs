@@ -4,9 +4,13 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 mod formatters;
+mod locale;
+mod source;
 mod table;
 
-pub use formatters::{JsonFormatter, PlainFormatter};
+pub use formatters::{JsonFormatter, NdjsonFormatter, NdjsonWriter, PlainFormatter};
+pub use locale::{parse_date_format, Locale, LocaleFormatter};
+pub use source::{locate_json_pointer, locate_nearest, SourceLocation};
 pub use table::TableFormatter;
 
 /// Output format enum
@@ -20,6 +24,8 @@ pub enum OutputFormat {
     Table,
     /// Plain text output
     Plain,
+    /// Newline-delimited JSON, one object per line
+    Ndjson,
 }
 
 impl OutputFormat {
@@ -29,6 +35,7 @@ impl OutputFormat {
             "json" => Some(Self::Json),
             "table" => Some(Self::Table),
             "plain" => Some(Self::Plain),
+            "ndjson" => Some(Self::Ndjson),
             _ => None,
         }
     }
@@ -40,14 +47,20 @@ impl std::fmt::Display for OutputFormat {
             Self::Json => write!(f, "json"),
             Self::Table => write!(f, "table"),
             Self::Plain => write!(f, "plain"),
+            Self::Ndjson => write!(f, "ndjson"),
         }
     }
 }
 
 /// Trait for types that can be formatted for output
 pub trait Formattable {
-    /// Format as JSON
-    fn format_json(&self) -> Result<String>;
+    /// Format as JSON with the given formatter
+    fn format_json_with(&self, formatter: JsonFormatter) -> Result<String>;
+
+    /// Format as pretty JSON
+    fn format_json(&self) -> Result<String> {
+        self.format_json_with(JsonFormatter::pretty())
+    }
 
     /// Format as table
     fn format_table(&self) -> Result<String>;
@@ -55,20 +68,24 @@ pub trait Formattable {
     /// Format as plain text
     fn format_plain(&self) -> Result<String>;
 
+    /// Format as newline-delimited JSON
+    fn format_ndjson(&self) -> Result<String>;
+
     /// Format using the specified format
     fn format(&self, format: OutputFormat) -> Result<String> {
         match format {
             OutputFormat::Json => self.format_json(),
             OutputFormat::Table => self.format_table(),
             OutputFormat::Plain => self.format_plain(),
+            OutputFormat::Ndjson => self.format_ndjson(),
         }
     }
 }
 
 /// Default implementation for serializable types
 impl<T: Serialize> Formattable for T {
-    fn format_json(&self) -> Result<String> {
-        JsonFormatter::format(self)
+    fn format_json_with(&self, formatter: JsonFormatter) -> Result<String> {
+        formatter.format(self)
     }
 
     fn format_table(&self) -> Result<String> {
@@ -79,6 +96,10 @@ impl<T: Serialize> Formattable for T {
     fn format_plain(&self) -> Result<String> {
         PlainFormatter::format(self)
     }
+
+    fn format_ndjson(&self) -> Result<String> {
+        NdjsonFormatter::format(self)
+    }
 }
 
 /// Color helpers
@@ -120,6 +141,7 @@ mod tests {
         assert_eq!(OutputFormat::from_str("JSON"), Some(OutputFormat::Json));
         assert_eq!(OutputFormat::from_str("table"), Some(OutputFormat::Table));
         assert_eq!(OutputFormat::from_str("plain"), Some(OutputFormat::Plain));
+        assert_eq!(OutputFormat::from_str("ndjson"), Some(OutputFormat::Ndjson));
         assert_eq!(OutputFormat::from_str("invalid"), None);
     }
 
@@ -128,6 +150,7 @@ mod tests {
         assert_eq!(OutputFormat::Json.to_string(), "json");
         assert_eq!(OutputFormat::Table.to_string(), "table");
         assert_eq!(OutputFormat::Plain.to_string(), "plain");
+        assert_eq!(OutputFormat::Ndjson.to_string(), "ndjson");
     }
 
     #[test]
@@ -0,0 +1,264 @@
+//! Source locations for JSON documents
+//!
+//! Maps a JSON pointer (RFC 6901, e.g. `/metadata/tags/0`) back to the line
+//! and column where that value appears in the original text, so validation
+//! errors can point at the offending spot in the user's file.
+
+use std::fmt;
+
+/// 1-based line and column in a source file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourceLocation {
+    fn from_offset(source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = source[line_start..offset].chars().count() + 1;
+        Self { line, column }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Locate the value at `pointer` in a JSON document
+///
+/// Object members resolve to their key, array elements to the element
+/// itself. Returns `None` if the pointer doesn't resolve or the document is
+/// not valid JSON along the way.
+pub fn locate_json_pointer(source: &str, pointer: &str) -> Option<SourceLocation> {
+    let tokens = parse_pointer(pointer)?;
+    let mut scanner = Scanner {
+        src: source.as_bytes(),
+        pos: 0,
+    };
+    scanner.skip_ws();
+    let offset = scanner.find(&tokens)?;
+    Some(SourceLocation::from_offset(source, offset))
+}
+
+/// Locate `pointer`, falling back to its nearest ancestor that exists
+///
+/// Useful for missing fields, which are best reported at the enclosing
+/// object.
+pub fn locate_nearest(source: &str, pointer: &str) -> Option<SourceLocation> {
+    let mut pointer = pointer;
+    loop {
+        if let Some(location) = locate_json_pointer(source, pointer) {
+            return Some(location);
+        }
+        pointer = &pointer[..pointer.rfind('/')?];
+    }
+}
+
+/// Split a JSON pointer into unescaped reference tokens
+fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    let rest = pointer.strip_prefix('/')?;
+    Some(
+        rest.split('/')
+            .map(|t| t.replace("~1", "/").replace("~0", "~"))
+            .collect(),
+    )
+}
+
+struct Scanner<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    /// Find the offset of the value at `tokens`, starting at the current value
+    fn find(&mut self, tokens: &[String]) -> Option<usize> {
+        let Some((token, rest)) = tokens.split_first() else {
+            return Some(self.pos);
+        };
+
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                loop {
+                    self.skip_ws();
+                    if self.peek()? == b'}' {
+                        return None;
+                    }
+                    let key_start = self.pos;
+                    let key = self.string()?;
+                    self.skip_ws();
+                    self.expect(b':')?;
+                    self.skip_ws();
+                    if key == *token {
+                        return if rest.is_empty() {
+                            Some(key_start)
+                        } else {
+                            self.find(rest)
+                        };
+                    }
+                    self.skip_value()?;
+                    self.next_member()?;
+                }
+            }
+            b'[' => {
+                let index: usize = token.parse().ok()?;
+                self.pos += 1;
+                for i in 0.. {
+                    self.skip_ws();
+                    if self.peek()? == b']' {
+                        return None;
+                    }
+                    if i == index {
+                        return self.find(rest);
+                    }
+                    self.skip_value()?;
+                    self.next_member()?;
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Consume the separator after a member, failing at the end of the container
+    fn next_member(&mut self) -> Option<()> {
+        self.skip_ws();
+        self.expect(b',')
+    }
+
+    fn skip_value(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'"' => self.string().map(|_| ()),
+            b'{' | b'[' => {
+                let mut depth = 0usize;
+                loop {
+                    match self.peek()? {
+                        b'"' => {
+                            self.string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Some(());
+                            }
+                        }
+                        _ => {}
+                    }
+                    self.pos += 1;
+                }
+            }
+            _ => {
+                // Numbers and literals run until a delimiter
+                while let Some(b) = self.peek() {
+                    if matches!(b, b',' | b'}' | b']') || b.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Some(())
+            }
+        }
+    }
+
+    /// Consume a string literal and return its decoded value
+    fn string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.expect(b'"')?;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => self.pos += 1,
+            }
+        }
+        let raw = std::str::from_utf8(self.src.get(start..self.pos)?).ok()?;
+        serde_json::from_str(raw).ok()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.peek()? == byte {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"{
+  "name": "Sample",
+  "slug": "sample",
+  "metadata": {
+    "tags": ["qa", {"note": "a \"quoted\" ]"}, "last"],
+    "a/b": 1,
+    "héllo": true, "after": null
+  }
+}"#;
+
+    fn at(pointer: &str) -> Option<String> {
+        locate_json_pointer(SAMPLE, pointer).map(|l| l.to_string())
+    }
+
+    #[test]
+    fn test_locate_object_members() {
+        assert_eq!(at("").as_deref(), Some("1:1"));
+        assert_eq!(at("/name").as_deref(), Some("2:3"));
+        assert_eq!(at("/slug").as_deref(), Some("3:3"));
+        assert_eq!(at("/metadata").as_deref(), Some("4:3"));
+        assert_eq!(at("/metadata/a~1b").as_deref(), Some("6:5"));
+        // Columns count characters, not bytes
+        assert_eq!(at("/metadata/after").as_deref(), Some("7:20"));
+    }
+
+    #[test]
+    fn test_locate_array_elements() {
+        assert_eq!(at("/metadata/tags").as_deref(), Some("5:5"));
+        assert_eq!(at("/metadata/tags/0").as_deref(), Some("5:14"));
+        assert_eq!(at("/metadata/tags/1/note").as_deref(), Some("5:21"));
+        // Skipping an element with brackets inside a string
+        assert_eq!(at("/metadata/tags/2").as_deref(), Some("5:48"));
+    }
+
+    #[test]
+    fn test_unresolved_pointers() {
+        assert_eq!(at("/missing"), None);
+        assert_eq!(at("/metadata/tags/3"), None);
+        assert_eq!(at("/name/0"), None);
+        assert_eq!(at("no-leading-slash"), None);
+    }
+
+    #[test]
+    fn test_locate_nearest() {
+        let loc = locate_nearest(SAMPLE, "/metadata/missing/deeper").unwrap();
+        assert_eq!(loc, SourceLocation { line: 4, column: 3 });
+        assert_eq!(locate_nearest(SAMPLE, "/description").unwrap().to_string(), "1:1");
+    }
+}
@@ -0,0 +1,96 @@
+//! `submit cancel` against a mocked API
+
+use llm_benchmark_cli::commands::submit::cancel_submission;
+use llm_benchmark_cli::commands::CommandContext;
+use llm_benchmark_cli::Config;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SUBMISSION_ID: &str = "9f1c2a5e-1d2b-4c3d-8e9f-0a1b2c3d4e5f";
+
+fn context(server: &MockServer) -> CommandContext {
+    let config = Config {
+        api_endpoint: server.uri(),
+        auth_token: Some("owner-token".to_string()),
+        ..Default::default()
+    };
+    CommandContext::new(config).unwrap()
+}
+
+async fn respond(server: &MockServer, response: ResponseTemplate) {
+    Mock::given(method("POST"))
+        .and(path(format!("/api/v1/submissions/{}/cancel", SUBMISSION_ID)))
+        .and(header("authorization", "Bearer owner-token"))
+        .respond_with(response)
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+fn error(code: &str, message: &str) -> serde_json::Value {
+    serde_json::json!({ "error": code, "message": message })
+}
+
+#[tokio::test]
+async fn test_cancel_pending_submission() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": SUBMISSION_ID,
+            "status": "cancelled",
+            "cancelled_at": "2024-05-01T12:30:00Z"
+        })),
+    )
+    .await;
+
+    let cancellation = cancel_submission(&context(&server), SUBMISSION_ID)
+        .await
+        .unwrap();
+
+    assert_eq!(cancellation.id.to_string(), SUBMISSION_ID);
+    assert_eq!(cancellation.status, "cancelled");
+}
+
+#[tokio::test]
+async fn test_cancel_verified_submission_conflicts() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        ResponseTemplate::new(409)
+            .set_body_json(error("CONFLICT", "Verified submissions cannot be cancelled")),
+    )
+    .await;
+
+    let err = cancel_submission(&context(&server), SUBMISSION_ID)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Submission {} cannot be cancelled: Verified submissions cannot be cancelled",
+            SUBMISSION_ID
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_someone_elses_submission_is_forbidden() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        ResponseTemplate::new(403)
+            .set_body_json(error("FORBIDDEN", "Only the submitter can cancel a submission")),
+    )
+    .await;
+
+    let err = cancel_submission(&context(&server), SUBMISSION_ID)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        format!("Submission {} belongs to another user", SUBMISSION_ID)
+    );
+}
@@ -0,0 +1,37 @@
+//! Loading benchmark definitions from JSON and YAML files
+
+use llm_benchmark_cli::commands::benchmark::load_benchmark_definition;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+#[test]
+fn test_yaml_and_json_definitions_load_the_same() {
+    let json = load_benchmark_definition(&fixture("valid.json")).unwrap();
+    let yaml = load_benchmark_definition(&fixture("valid.yaml")).unwrap();
+
+    assert_eq!(json, yaml);
+    assert_eq!(yaml.slug, "arithmetic");
+    assert_eq!(yaml.rest["version"], "1.2.0");
+    assert_eq!(yaml.rest["test_cases"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_content_must_match_extension() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("benchmark.json");
+    fs::copy(fixture("valid.yaml"), &path).unwrap();
+
+    let err = load_benchmark_definition(&path).unwrap_err();
+    assert!(format!("{:#}", err).contains("contains YAML"), "{:#}", err);
+}
+
+#[test]
+fn test_missing_field_is_reported() {
+    let err = load_benchmark_definition(&fixture("missing-field.json")).unwrap_err();
+    assert!(format!("{:#}", err).contains("missing field `description`"), "{:#}", err);
+}
@@ -0,0 +1,131 @@
+//! `benchmark download` against a mocked API, writing to a temporary directory
+
+use llm_benchmark_cli::commands::benchmark::{download_to, DownloadManifest, MANIFEST_FILE};
+use llm_benchmark_cli::commands::CommandContext;
+use llm_benchmark_cli::Config;
+use sha2::{Digest, Sha256};
+use std::fs;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn context(server: &MockServer) -> CommandContext {
+    let config = Config {
+        api_endpoint: server.uri(),
+        ..Default::default()
+    };
+    CommandContext::new(config).unwrap()
+}
+
+fn read_manifest(dir: &std::path::Path) -> DownloadManifest {
+    serde_json::from_slice(&fs::read(dir.join(MANIFEST_FILE)).unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_download_writes_test_cases_and_manifest() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/benchmarks/mmlu/test-cases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "version": "2.1.0",
+            "test_cases": [
+                {"id": "tc-1", "input": {"prompt_template": "2+2?"}, "weight": 1.0},
+                {"id": "math/tc-2", "input": {"prompt_template": "3*3?"}, "weight": 2.0}
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = TempDir::new().unwrap();
+    // Missing directories are created
+    let output = dir.path().join("nested/out");
+
+    let manifest = download_to(&context(&server), "mmlu", None, &output)
+        .await
+        .unwrap();
+
+    assert_eq!(manifest.version, "2.1.0");
+    let files: Vec<&str> = manifest.test_cases.iter().map(|e| e.file.as_str()).collect();
+    assert_eq!(files, ["tc-1.json", "math_tc-2.json"]);
+
+    let written = read_manifest(&output);
+    assert_eq!(written.benchmark, "mmlu");
+    for entry in &written.test_cases {
+        let contents = fs::read(output.join(&entry.file)).unwrap();
+        assert_eq!(entry.sha256, hex::encode(Sha256::digest(&contents)));
+
+        let test_case: serde_json::Value = serde_json::from_slice(&contents).unwrap();
+        assert_eq!(test_case["id"], entry.id.as_str());
+    }
+    let first: serde_json::Value =
+        serde_json::from_slice(&fs::read(output.join("tc-1.json")).unwrap()).unwrap();
+    assert_eq!(first["input"]["prompt_template"], "2+2?");
+}
+
+#[tokio::test]
+async fn test_download_empty_version_writes_empty_manifest() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/benchmarks/mmlu/test-cases"))
+        .and(query_param("version", "0.1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "version": "0.1.0",
+            "test_cases": []
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = TempDir::new().unwrap();
+    let manifest = download_to(&context(&server), "mmlu", Some("0.1.0"), dir.path())
+        .await
+        .unwrap();
+
+    assert!(manifest.test_cases.is_empty());
+    assert!(read_manifest(dir.path()).test_cases.is_empty());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[tokio::test]
+async fn test_download_unknown_benchmark() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/benchmarks/missing/test-cases"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": "NOT_FOUND",
+            "message": "Resource not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = TempDir::new().unwrap();
+    let err = download_to(&context(&server), "missing", None, dir.path())
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Benchmark not found: missing");
+    assert!(!dir.path().join(MANIFEST_FILE).exists());
+}
+
+#[tokio::test]
+async fn test_download_rejects_test_case_named_like_the_manifest() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/benchmarks/mmlu/test-cases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "version": "2.1.0",
+            "test_cases": [
+                {"id": "manifest", "input": {"prompt_template": "2+2?"}, "weight": 1.0}
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let dir = TempDir::new().unwrap();
+    let err = download_to(&context(&server), "mmlu", None, dir.path())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("Test case 'manifest' would overwrite {}", MANIFEST_FILE)
+    );
+    assert!(!dir.path().join(MANIFEST_FILE).exists());
+}
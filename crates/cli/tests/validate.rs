@@ -0,0 +1,42 @@
+//! `benchmark validate` against definition fixtures, in structural and strict modes
+
+use llm_benchmark_cli::commands::benchmark::{validate, validate_definition};
+use llm_benchmark_cli::definition::load_definition;
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> String {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn issue_pointers(name: &str, strict: bool) -> Vec<String> {
+    let definition = load_definition(Path::new(&fixture(name))).unwrap();
+    validate_definition(&definition, strict)
+        .into_iter()
+        .map(|issue| issue.pointer)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_valid_definition_passes_strict() {
+    assert!(validate(fixture("valid.json"), false).await.is_ok());
+    assert!(validate(fixture("valid.json"), true).await.is_ok());
+    assert!(issue_pointers("valid.json", true).is_empty());
+}
+
+#[tokio::test]
+async fn test_missing_field_fails_only_in_strict_mode() {
+    assert!(validate(fixture("missing-field.json"), false).await.is_ok());
+    assert!(validate(fixture("missing-field.json"), true).await.is_err());
+    assert_eq!(issue_pointers("missing-field.json", true), vec!["/description"]);
+}
+
+#[tokio::test]
+async fn test_bad_semver_fails_only_in_strict_mode() {
+    assert!(validate(fixture("bad-semver.yaml"), false).await.is_ok());
+    assert!(validate(fixture("bad-semver.yaml"), true).await.is_err());
+    assert_eq!(issue_pointers("bad-semver.yaml", true), vec!["/version"]);
+}
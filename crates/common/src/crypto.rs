@@ -11,6 +11,12 @@ use argon2::{
 use blake3::Hasher as Blake3Hasher;
 use rand::Rng;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
 /// Hash a password using Argon2.
 ///
@@ -84,6 +90,15 @@ pub fn generate_token(length: usize) -> String {
     hex::encode(bytes)
 }
 
+/// SHA-256 content hash of a value's canonical JSON.
+///
+/// Equal values hash the same regardless of field or map iteration order;
+/// see [`to_canonical_json`](crate::serialization::to_canonical_json).
+pub fn content_hash<T: serde::Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    let canonical = crate::serialization::to_canonical_json(value)?;
+    Ok(ChecksumVerifier::Sha256.compute(canonical.as_bytes()))
+}
+
 /// Checksum verifier supporting multiple algorithms.
 #[derive(Debug, Clone, Copy)]
 pub enum ChecksumVerifier {
@@ -184,6 +199,139 @@ impl ChecksumVerifier {
         let data = std::fs::read(path).context("Failed to read file")?;
         self.verify(&data, expected_checksum)
     }
+
+    /// Compute a checksum from a reader without buffering it all in memory.
+    pub fn compute_reader<R: Read>(&self, mut reader: R) -> Result<String> {
+        match self {
+            Self::Sha256 => {
+                let mut hasher = Sha256::new();
+                std::io::copy(&mut reader, &mut hasher).context("Failed to read data")?;
+                Ok(hex::encode(hasher.finalize()))
+            }
+            Self::Blake3 => {
+                let mut hasher = Blake3Hasher::new();
+                std::io::copy(&mut reader, &mut hasher).context("Failed to read data")?;
+                Ok(hex::encode(hasher.finalize().as_bytes()))
+            }
+        }
+    }
+
+    /// Checksum every regular file under `root`, hashing up to `concurrency`
+    /// files at a time.
+    ///
+    /// Keys are paths relative to `root`, so the map is sorted and stable
+    /// across machines. Symlinks are skipped rather than followed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use common::crypto::ChecksumVerifier;
+    /// use std::path::Path;
+    ///
+    /// let verifier = ChecksumVerifier::Blake3;
+    /// let checksums = verifier
+    ///     .checksum_tree(Path::new("/path/to/corpus"), 8)
+    ///     .expect("Failed to checksum corpus");
+    /// let root = verifier.merkle_root(&checksums);
+    /// ```
+    pub fn checksum_tree(
+        &self,
+        root: &Path,
+        concurrency: usize,
+    ) -> Result<BTreeMap<PathBuf, String>> {
+        let mut files = Vec::new();
+        collect_files(root, &mut files)?;
+
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new(BTreeMap::new());
+        let workers = concurrency.clamp(1, files.len().max(1));
+
+        std::thread::scope(|scope| -> Result<()> {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| -> Result<()> {
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(path) = files.get(i) else {
+                                return Ok(());
+                            };
+                            let file = fs::File::open(path)
+                                .with_context(|| format!("Failed to open {}", path.display()))?;
+                            let checksum = self
+                                .compute_reader(file)
+                                .with_context(|| format!("Failed to hash {}", path.display()))?;
+                            let relative = path.strip_prefix(root).unwrap_or(path).to_path_buf();
+                            results.lock().unwrap().insert(relative, checksum);
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().expect("checksum worker panicked")?;
+            }
+            Ok(())
+        })?;
+
+        Ok(results.into_inner().unwrap())
+    }
+
+    /// Combine per-file checksums into a single Merkle root.
+    ///
+    /// Each leaf hashes a file's relative path together with its checksum, so
+    /// renames change the root as well as content edits. Leaves are paired
+    /// in path order; an odd node is carried up to the next level unchanged.
+    /// An empty tree hashes the empty string.
+    pub fn merkle_root(&self, checksums: &BTreeMap<PathBuf, String>) -> String {
+        let mut level: Vec<String> = checksums
+            .iter()
+            .map(|(path, checksum)| {
+                // Forward slashes keep the root identical across platforms
+                let path = path
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                self.compute(format!("{}\0{}", path, checksum).as_bytes())
+            })
+            .collect();
+
+        if level.is_empty() {
+            return self.compute(b"");
+        }
+
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => self.compute(format!("{}{}", left, right).as_bytes()),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        level.remove(0)
+    }
+}
+
+/// Recursively collect regular files under `dir`, skipping symlinks
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory {}", dir.display()))?;
+
+    for entry in entries {
+        let entry = entry.context("Failed to read directory entry")?;
+        // `DirEntry::file_type` does not follow symlinks
+        let file_type = entry.file_type().context("Failed to read file type")?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -288,4 +436,105 @@ mod tests {
         // Different algorithms should produce different checksums
         assert_ne!(sha256, blake3);
     }
+
+    #[test]
+    fn test_compute_reader_matches_compute() {
+        let data = vec![7u8; 200_000];
+        for verifier in [ChecksumVerifier::Sha256, ChecksumVerifier::Blake3] {
+            assert_eq!(
+                verifier.compute_reader(&data[..]).unwrap(),
+                verifier.compute(&data)
+            );
+        }
+    }
+
+    fn write_corpus(root: &Path) {
+        fs::create_dir_all(root.join("train/shard")).unwrap();
+        fs::create_dir_all(root.join("test")).unwrap();
+        fs::write(root.join("README"), "hello").unwrap();
+        fs::write(root.join("train/a.jsonl"), "{\"id\": 1}\n").unwrap();
+        fs::write(root.join("train/shard/b.jsonl"), "{\"id\": 2}\n").unwrap();
+        fs::write(root.join("test/c.jsonl"), "").unwrap();
+        for i in 0..20 {
+            fs::write(root.join(format!("train/shard/{:02}.txt", i)), i.to_string()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_checksum_tree() {
+        let dir = tempfile::TempDir::new().unwrap();
+        write_corpus(dir.path());
+
+        let verifier = ChecksumVerifier::Sha256;
+        let checksums = verifier.checksum_tree(dir.path(), 4).unwrap();
+
+        assert_eq!(checksums.len(), 24);
+        assert_eq!(
+            checksums[Path::new("README")],
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(
+            checksums[Path::new("test/c.jsonl")],
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            checksums[Path::new("train/shard/b.jsonl")],
+            verifier.compute(b"{\"id\": 2}\n")
+        );
+
+        // Sorted by relative path
+        let keys: Vec<&PathBuf> = checksums.keys().collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_merkle_root_is_reproducible() {
+        let verifier = ChecksumVerifier::Blake3;
+
+        let first = tempfile::TempDir::new().unwrap();
+        let second = tempfile::TempDir::new().unwrap();
+        write_corpus(first.path());
+        write_corpus(second.path());
+
+        // Independent of concurrency and of where the tree lives
+        let serial = verifier.checksum_tree(first.path(), 1).unwrap();
+        let parallel = verifier.checksum_tree(second.path(), 8).unwrap();
+        assert_eq!(serial, parallel);
+        assert_eq!(verifier.merkle_root(&serial), verifier.merkle_root(&parallel));
+
+        // Any content change alters the root
+        fs::write(second.path().join("train/shard/07.txt"), "changed").unwrap();
+        let changed = verifier.checksum_tree(second.path(), 8).unwrap();
+        assert_ne!(verifier.merkle_root(&serial), verifier.merkle_root(&changed));
+    }
+
+    #[test]
+    fn test_merkle_root_small_trees() {
+        let verifier = ChecksumVerifier::Sha256;
+        assert_eq!(verifier.merkle_root(&BTreeMap::new()), verifier.compute(b""));
+
+        let single = BTreeMap::from([(PathBuf::from("a"), "abc".to_string())]);
+        assert_eq!(verifier.merkle_root(&single), verifier.compute(b"a\0abc"));
+
+        // Renaming a file changes the root even with identical content
+        let renamed = BTreeMap::from([(PathBuf::from("b"), "abc".to_string())]);
+        assert_ne!(verifier.merkle_root(&single), verifier.merkle_root(&renamed));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_checksum_tree_skips_symlinks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let outside = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("real.txt"), "data").unwrap();
+        fs::write(outside.path().join("secret.txt"), "secret").unwrap();
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("linked_dir")).unwrap();
+        std::os::unix::fs::symlink(dir.path().join("real.txt"), dir.path().join("link.txt"))
+            .unwrap();
+
+        let checksums = ChecksumVerifier::Sha256.checksum_tree(dir.path(), 2).unwrap();
+        assert_eq!(checksums.keys().collect::<Vec<_>>(), vec![Path::new("real.txt")]);
+    }
 }
\ No newline at end of file
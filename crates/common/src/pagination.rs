@@ -15,6 +15,10 @@ const DEFAULT_PER_PAGE: u32 = 20;
 /// Maximum items per page
 const MAX_PER_PAGE: u32 = 100;
 
+/// Maximum number of rows a query may skip; deeper pages need keyset
+/// pagination instead of large offsets that force long scans
+pub const MAX_SQL_OFFSET: i64 = 100_000;
+
 /// Pagination parameters for API requests.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginationParams {
@@ -59,7 +63,7 @@ impl PaginationParams {
 
     /// Calculate the offset for database queries (0-indexed).
     pub fn offset(&self) -> u32 {
-        (self.page - 1) * self.per_page
+        self.page.saturating_sub(1).saturating_mul(self.per_page)
     }
 
     /// Get the limit for database queries.
@@ -67,6 +71,26 @@ impl PaginationParams {
         self.per_page
     }
 
+    /// Compute `LIMIT` and `OFFSET` values for a SQL query.
+    ///
+    /// The page size is clamped to `1..=100` and the offset capped at
+    /// [`MAX_SQL_OFFSET`], so parameters that bypassed [`Self::new`] (e.g.
+    /// deserialized from a query string) can neither overflow nor trigger
+    /// huge scans. [`SqlBounds::clamped`] reports whether anything was changed.
+    pub fn to_sql_bounds(&self) -> SqlBounds {
+        let limit = match self.per_page {
+            0 => DEFAULT_PER_PAGE,
+            n => n.min(MAX_PER_PAGE),
+        };
+        let offset = i64::from(self.page.saturating_sub(1)) * i64::from(limit);
+
+        SqlBounds {
+            limit: i64::from(limit),
+            offset: offset.min(MAX_SQL_OFFSET),
+            clamped: limit != self.per_page || offset > MAX_SQL_OFFSET,
+        }
+    }
+
     /// Validate pagination parameters.
     pub fn validate(&self) -> Result<(), String> {
         if self.page == 0 {
@@ -85,6 +109,17 @@ impl PaginationParams {
     }
 }
 
+/// `LIMIT`/`OFFSET` values for a SQL query, from [`PaginationParams::to_sql_bounds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqlBounds {
+    /// Rows to return
+    pub limit: i64,
+    /// Rows to skip
+    pub offset: i64,
+    /// Whether the page size or offset had to be clamped
+    pub clamped: bool,
+}
+
 /// Sort direction for query results.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -184,10 +219,26 @@ pub struct PaginatedResult<T> {
     pub has_prev: bool,
 }
 
+/// Number of pages needed to hold `total` items.
+///
+/// Zero for an empty result set (or a zero page size), so page numbers
+/// always run from 1 to the returned count.
+pub fn page_count(total: u64, per_page: u32) -> u32 {
+    if per_page == 0 {
+        return 0;
+    }
+    total
+        .div_ceil(u64::from(per_page))
+        .min(u64::from(u32::MAX)) as u32
+}
+
 impl<T> PaginatedResult<T> {
     /// Create a new paginated result.
+    ///
+    /// Page 0 is treated as page 1.
     pub fn new(items: Vec<T>, page: u32, per_page: u32, total: u64) -> Self {
-        let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
+        let page = page.max(DEFAULT_PAGE);
+        let total_pages = page_count(total, per_page);
         let has_next = page < total_pages;
         let has_prev = page > 1;
 
@@ -306,6 +357,45 @@ mod tests {
         assert_eq!(params.per_page, 100);
     }
 
+    #[test]
+    fn test_sql_bounds_in_range() {
+        let bounds = PaginationParams::new(3, 25).to_sql_bounds();
+        assert_eq!(
+            bounds,
+            SqlBounds {
+                limit: 25,
+                offset: 50,
+                clamped: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_sql_bounds_clamps_page_size() {
+        // Deserialized parameters skip the clamping in `new`
+        let params = PaginationParams { page: 2, per_page: 5_000 };
+        let bounds = params.to_sql_bounds();
+        assert_eq!(bounds.limit, 100);
+        assert_eq!(bounds.offset, 100);
+        assert!(bounds.clamped);
+
+        let bounds = PaginationParams { page: 1, per_page: 0 }.to_sql_bounds();
+        assert_eq!(bounds.limit, 20);
+        assert!(bounds.clamped);
+    }
+
+    #[test]
+    fn test_sql_bounds_caps_huge_page() {
+        let params = PaginationParams { page: u32::MAX, per_page: u32::MAX };
+        let bounds = params.to_sql_bounds();
+        assert_eq!(bounds.limit, 100);
+        assert_eq!(bounds.offset, MAX_SQL_OFFSET);
+        assert!(bounds.clamped);
+
+        // The u32 offset saturates instead of overflowing
+        assert_eq!(params.offset(), u32::MAX);
+    }
+
     #[test]
     fn test_pagination_params_validation() {
         let valid = PaginationParams::new(1, 20);
@@ -353,6 +443,38 @@ mod tests {
         assert!(result.has_prev);
     }
 
+    #[test]
+    fn test_paginated_result_page_zero() {
+        let result = PaginatedResult::new(vec![1, 2], 0, 2, 5);
+        assert_eq!(result.page, 1);
+        assert!(!result.has_prev);
+        assert!(result.has_next);
+
+        let params = PaginationParams { page: 0, per_page: 10 };
+        assert_eq!(params.offset(), 0);
+        assert_eq!(params.to_sql_bounds().offset, 0);
+    }
+
+    #[test]
+    fn test_paginated_result_last_partial_page() {
+        let result = PaginatedResult::new(vec![21, 22, 23], 3, 10, 23);
+        assert_eq!(result.total_pages, 3);
+        assert!(!result.has_next);
+        assert!(result.has_prev);
+        assert_eq!(PaginationParams::new(3, 10).offset(), 20);
+    }
+
+    #[test]
+    fn test_paginated_result_empty() {
+        let result = PaginatedResult::<u32>::new(vec![], 1, 20, 0);
+        assert_eq!(result.total_pages, 0);
+        assert!(!result.has_next);
+        assert!(!result.has_prev);
+
+        assert_eq!(page_count(0, 20), 0);
+        assert_eq!(page_count(5, 0), 0);
+    }
+
     #[test]
     fn test_paginated_result_map() {
         let items = vec![1, 2, 3];
@@ -363,6 +485,20 @@ mod tests {
         assert_eq!(mapped.total, 10);
     }
 
+    #[test]
+    fn test_paginated_result_map_changes_type() {
+        let result = PaginatedResult::new(vec![7, 8], 3, 4, 10);
+        let mapped: PaginatedResult<String> = result.map(|n| format!("#{}", n));
+
+        assert_eq!(mapped.items, vec!["#7", "#8"]);
+        assert_eq!(mapped.page, 3);
+        assert_eq!(mapped.per_page, 4);
+        assert_eq!(mapped.total, 10);
+        assert_eq!(mapped.total_pages, 3);
+        assert!(!mapped.has_next);
+        assert!(mapped.has_prev);
+    }
+
     #[test]
     fn test_date_range_validation() {
         use chrono::Utc;
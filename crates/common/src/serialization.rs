@@ -135,6 +135,35 @@ where
     serializer.serialize_str(&value.to_uppercase())
 }
 
+/// Serialize a value as canonical JSON.
+///
+/// Object keys are sorted and no insignificant whitespace is emitted, so
+/// values that are equal serialize to the same bytes regardless of field or
+/// map iteration order.
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    let value = canonicalize(serde_json::to_value(value)?);
+    serde_json::to_string(&value)
+}
+
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonicalize).collect()),
+        other => other,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,4 +261,19 @@ mod tests {
         let json = serde_json::to_string(&test_with_values).unwrap();
         assert_eq!(json, r#"{"optional":"present","flag":true,"required":"value"}"#);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_canonical_json_sorts_keys() {
+        let a = serde_json::json!({"b": 1, "a": {"y": [2, {"d": 0, "c": 1}], "x": null}});
+        let b: std::collections::HashMap<&str, serde_json::Value> = [
+            ("a", serde_json::json!({"x": null, "y": [2, {"c": 1, "d": 0}]})),
+            ("b", serde_json::json!(1)),
+        ]
+        .into_iter()
+        .collect();
+
+        let canonical = to_canonical_json(&a).unwrap();
+        assert_eq!(canonical, r#"{"a":{"x":null,"y":[2,{"c":1,"d":0}]},"b":1}"#);
+        assert_eq!(to_canonical_json(&b).unwrap(), canonical);
+    }
+}
@@ -6,8 +6,14 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+/// Maximum length of a custom category name
+pub const MAX_CUSTOM_CATEGORY_LEN: usize = 64;
+
 /// Top-level benchmark categories
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// `Custom` covers community-defined categories that don't fit the built-in
+/// set. Build it with [`BenchmarkCategory::custom`] so the name is validated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BenchmarkCategory {
     Performance,
@@ -16,6 +22,8 @@ pub enum BenchmarkCategory {
     Safety,
     Cost,
     Capability,
+    /// Community-defined category, named by a validated slug-like string
+    Custom(String),
 }
 
 impl BenchmarkCategory {
@@ -30,7 +38,55 @@ impl BenchmarkCategory {
         ]
     }
 
-    pub fn display_name(&self) -> &'static str {
+    /// Create a custom category, validating its name
+    pub fn custom(name: impl Into<String>) -> Result<Self, String> {
+        let category = Self::Custom(name.into());
+        category.validate()?;
+        Ok(category)
+    }
+
+    /// Check that a custom category name is well formed
+    ///
+    /// Names are lowercase ASCII letters, digits, `_` and `-`, start with a
+    /// letter, and must not shadow a built-in category. Built-in categories
+    /// are always valid.
+    pub fn validate(&self) -> Result<(), String> {
+        let Self::Custom(name) = self else {
+            return Ok(());
+        };
+
+        if name.is_empty() || name.len() > MAX_CUSTOM_CATEGORY_LEN {
+            return Err(format!(
+                "custom category name must be 1-{} characters",
+                MAX_CUSTOM_CATEGORY_LEN
+            ));
+        }
+        if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return Err("custom category name must start with a lowercase letter".to_string());
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        {
+            return Err(
+                "custom category name may only contain lowercase letters, digits, '_' and '-'"
+                    .to_string(),
+            );
+        }
+        if Self::all().iter().any(|c| c.display_name().eq_ignore_ascii_case(name)) {
+            return Err(format!("custom category '{}' shadows a built-in category", name));
+        }
+
+        Ok(())
+    }
+
+    /// Whether this is a community-defined category rather than a built-in one
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    /// Human-readable name; custom categories are shown by their name as given
+    pub fn display_name(&self) -> &str {
         match self {
             Self::Performance => "Performance",
             Self::Accuracy => "Accuracy",
@@ -38,6 +94,7 @@ impl BenchmarkCategory {
             Self::Safety => "Safety",
             Self::Cost => "Cost",
             Self::Capability => "Capability",
+            Self::Custom(name) => name,
         }
     }
 
@@ -49,6 +106,7 @@ impl BenchmarkCategory {
             Self::Safety => "Harmful content generation, jailbreak resistance, bias detection",
             Self::Cost => "Price per token, cost per task, cost-performance ratios",
             Self::Capability => "Context length, multi-modal support, function calling",
+            Self::Custom(_) => "Community-defined category",
         }
     }
 }
@@ -148,3 +206,37 @@ pub struct BenchmarkLineage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub migration_notes: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_category_validation() {
+        let category = BenchmarkCategory::custom("multilingual-qa").unwrap();
+        assert!(category.is_custom());
+        assert_eq!(category.display_name(), "multilingual-qa");
+
+        assert!(BenchmarkCategory::custom("").is_err());
+        assert!(BenchmarkCategory::custom("Multilingual").is_err());
+        assert!(BenchmarkCategory::custom("9lives").is_err());
+        assert!(BenchmarkCategory::custom("has space").is_err());
+        assert!(BenchmarkCategory::custom("a".repeat(MAX_CUSTOM_CATEGORY_LEN + 1)).is_err());
+        assert!(BenchmarkCategory::custom("safety").is_err());
+
+        assert!(BenchmarkCategory::Safety.validate().is_ok());
+    }
+
+    #[test]
+    fn test_custom_category_serde() {
+        let category = BenchmarkCategory::custom("code_review").unwrap();
+        let json = serde_json::to_string(&category).unwrap();
+        assert_eq!(json, r#"{"custom":"code_review"}"#);
+        assert_eq!(serde_json::from_str::<BenchmarkCategory>(&json).unwrap(), category);
+
+        assert_eq!(
+            serde_json::to_string(&BenchmarkCategory::Accuracy).unwrap(),
+            r#""accuracy""#
+        );
+    }
+}
@@ -5,6 +5,7 @@
 //! for API responses.
 
 use crate::identifiers::*;
+use crate::submission::VerificationLevel;
 use crate::version::VersionParseError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -77,6 +78,7 @@ impl AppError {
             Self::Validation(_) => 400,
             Self::Benchmark(BenchmarkError::NotFound(_)) => 404,
             Self::Submission(SubmissionError::NotFound(_)) => 404,
+            Self::Submission(SubmissionError::NotCancellable(_)) => 409,
             Self::Governance(GovernanceError::ProposalNotFound(_)) => 404,
             Self::Database(_) => 503,
             Self::Internal(_) => 500,
@@ -162,6 +164,10 @@ pub enum SubmissionError {
     /// Execution metadata incomplete
     #[error("Execution metadata incomplete: {0}")]
     IncompleteMetadata(String),
+
+    /// Submission cannot be cancelled in its current state
+    #[error("Submission cannot be cancelled: {0}")]
+    NotCancellable(String),
 }
 
 /// Verification-specific errors
@@ -190,6 +196,15 @@ pub enum VerificationError {
     /// Environment mismatch
     #[error("Environment mismatch: {0}")]
     EnvironmentMismatch(String),
+
+    /// Verification would lower the submission's current level
+    #[error("Cannot downgrade verification from {from:?} to {to:?}")]
+    Downgrade {
+        /// Current level
+        from: VerificationLevel,
+        /// Requested, lower level
+        to: VerificationLevel,
+    },
 }
 
 /// Governance-specific errors
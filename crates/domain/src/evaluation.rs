@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use url::Url;
 
+use crate::submission::VerificationLevel;
+
 /// Comprehensive evaluation criteria
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvaluationCriteria {
@@ -13,6 +15,12 @@ pub struct EvaluationCriteria {
     pub score_normalization: ScoreNormalization,
     pub minimum_test_cases: usize,
     pub confidence_level: f64,
+    /// Rounding applied to aggregate scores; full precision when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_precision: Option<ScorePrecision>,
+    /// Weakest verification level ranked on the leaderboard by default
+    #[serde(default)]
+    pub leaderboard_min_verification: VerificationLevel,
 }
 
 /// Metric definition
@@ -63,9 +71,99 @@ pub enum AggregationMethod {
     Min,
     Max,
     Percentile { percentile: f64 },
+    /// Mean after discarding a fraction of the sorted scores from each end
+    TrimmedMean {
+        /// Fraction of scores dropped from each end, in `[0.0, 0.5)`
+        trim_fraction: f64,
+    },
+    /// Weighted mean with weights keyed by test case difficulty tier
+    DifficultyWeighted {
+        /// Weight of each tier (e.g. `"hard"`); cases with no difficulty or
+        /// an unlisted tier weigh 1.0
+        tier_weights: HashMap<String, f64>,
+    },
     Custom { formula: String },
 }
 
+impl AggregationMethod {
+    /// Validate the method's parameters
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::TrimmedMean { trim_fraction } if !(0.0..0.5).contains(trim_fraction) => Err(
+                format!("trim_fraction must be in [0.0, 0.5), got {}", trim_fraction),
+            ),
+            Self::DifficultyWeighted { tier_weights } => {
+                match tier_weights.iter().find(|(_, w)| !w.is_finite() || **w < 0.0) {
+                    Some((tier, weight)) => Err(format!(
+                        "tier weight for '{}' must be a non-negative number, got {}",
+                        tier, weight
+                    )),
+                    None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Rounding policy for aggregate and displayed scores
+///
+/// Rounding goes through the decimal representation of the value, so
+/// `0.6666667` to three decimal places is exactly the `f64` nearest `0.667`
+/// and serializes as `0.667`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScorePrecision {
+    /// Round to a fixed number of decimal places
+    DecimalPlaces {
+        /// Digits kept after the decimal point
+        places: u32,
+    },
+    /// Round to a number of significant figures
+    SignificantFigures {
+        /// Significant digits kept
+        figures: u32,
+    },
+}
+
+impl ScorePrecision {
+    /// Most digits a policy may keep; `f64` carries about 15-17
+    pub const MAX_DIGITS: u32 = 15;
+
+    /// Round `value` under this policy; non-finite values are returned as is
+    pub fn round(&self, value: f64) -> f64 {
+        if !value.is_finite() {
+            return value;
+        }
+        let rounded = match *self {
+            Self::DecimalPlaces { places } => format!("{:.*}", places as usize, value),
+            Self::SignificantFigures { figures } => {
+                format!("{:.*e}", figures.saturating_sub(1) as usize, value)
+            }
+        };
+        rounded.parse().unwrap_or(value)
+    }
+
+    /// Validate the policy's parameters
+    pub fn validate(&self) -> Result<(), String> {
+        match *self {
+            Self::DecimalPlaces { places } if places > Self::MAX_DIGITS => Err(format!(
+                "decimal places must be at most {}, got {}",
+                Self::MAX_DIGITS,
+                places
+            )),
+            Self::SignificantFigures { figures } if !(1..=Self::MAX_DIGITS).contains(&figures) => {
+                Err(format!(
+                    "significant figures must be between 1 and {}, got {}",
+                    Self::MAX_DIGITS,
+                    figures
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Score normalization methods
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -169,3 +267,66 @@ pub enum DatasetFormat {
     Parquet,
     Arrow,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trimmed_mean_serde_roundtrip() {
+        let method = AggregationMethod::TrimmedMean { trim_fraction: 0.1 };
+        let json = serde_json::to_value(&method).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "trimmed_mean", "trim_fraction": 0.1})
+        );
+
+        let parsed: AggregationMethod = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            parsed,
+            AggregationMethod::TrimmedMean { trim_fraction } if trim_fraction == 0.1
+        ));
+    }
+
+    #[test]
+    fn test_trimmed_mean_validation() {
+        for trim_fraction in [0.0, 0.25, 0.499] {
+            assert!(AggregationMethod::TrimmedMean { trim_fraction }.validate().is_ok());
+        }
+        for trim_fraction in [-0.01, 0.5, 1.0, f64::NAN] {
+            assert!(AggregationMethod::TrimmedMean { trim_fraction }.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_score_precision_decimal_places() {
+        let policy = ScorePrecision::DecimalPlaces { places: 3 };
+        assert_eq!(policy.round(0.6666667), 0.667);
+        assert_eq!(serde_json::to_string(&policy.round(0.6666667)).unwrap(), "0.667");
+        assert_eq!(policy.round(0.1), 0.1);
+        assert_eq!(policy.round(-1.23456), -1.235);
+        assert_eq!(ScorePrecision::DecimalPlaces { places: 0 }.round(2.5001), 3.0);
+        assert!(policy.round(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_score_precision_significant_figures() {
+        let policy = ScorePrecision::SignificantFigures { figures: 3 };
+        assert_eq!(policy.round(0.6666667), 0.667);
+        assert_eq!(policy.round(0.000123456), 0.000123);
+        assert_eq!(policy.round(98765.4), 98800.0);
+        assert_eq!(policy.round(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_score_precision_serde_and_validation() {
+        let policy: ScorePrecision =
+            serde_json::from_value(serde_json::json!({"type": "decimal_places", "places": 3}))
+                .unwrap();
+        assert_eq!(policy, ScorePrecision::DecimalPlaces { places: 3 });
+
+        assert!(policy.validate().is_ok());
+        assert!(ScorePrecision::DecimalPlaces { places: 16 }.validate().is_err());
+        assert!(ScorePrecision::SignificantFigures { figures: 0 }.validate().is_err());
+    }
+}
@@ -1,10 +1,12 @@
 //! Governance types for community decision-making.
 
 use crate::benchmark::BenchmarkStatus;
-use crate::identifiers::{BenchmarkId, ProposalId, UserId};
+use crate::errors::GovernanceError;
+use crate::identifiers::{BenchmarkId, CommentId, ProposalId, UserId};
+use crate::user::UserRole;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Governance proposal
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,7 +26,7 @@ pub struct Proposal {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ProposalType {
     NewBenchmark,
@@ -46,9 +48,11 @@ pub enum ProposalStatus {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VotingState {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// When the voting window opens
+    #[serde(alias = "voting_opens_at", skip_serializing_if = "Option::is_none")]
     pub voting_starts: Option<DateTime<Utc>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// When the voting window closes
+    #[serde(alias = "voting_closes_at", skip_serializing_if = "Option::is_none")]
     pub voting_ends: Option<DateTime<Utc>>,
     pub votes_for: u32,
     pub votes_against: u32,
@@ -56,6 +60,169 @@ pub struct VotingState {
     pub voters: HashSet<UserId>,
     pub quorum_required: u32,
     pub approval_threshold: f64,
+    /// Individual votes in the order they were cast
+    #[serde(default)]
+    pub ballots: Vec<Ballot>,
+    /// Role weights the votes were tallied with, recorded at finalization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role_weights: Option<RoleWeights>,
+}
+
+/// A single vote as cast
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ballot {
+    /// Who voted
+    pub voter: UserId,
+    /// Role the voter held when voting
+    pub role: UserRole,
+    /// The vote cast
+    pub vote: Vote,
+    /// When the vote was cast
+    pub cast_at: DateTime<Utc>,
+}
+
+impl VotingState {
+    /// Total number of votes cast, including abstentions
+    pub fn total_votes(&self) -> u32 {
+        self.votes_for + self.votes_against + self.votes_abstain
+    }
+
+    /// Whether the voting period has ended at `now`
+    pub fn is_closed(&self, now: DateTime<Utc>) -> bool {
+        self.voting_ends.is_some_and(|ends| now >= ends)
+    }
+
+    /// Whether votes may be cast at `now`.
+    ///
+    /// The window must have an opening time; a missing closing time leaves
+    /// it open until the proposal is finalized.
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.voting_starts.is_some_and(|starts| now >= starts) && !self.is_closed(now)
+    }
+}
+
+/// Who may cast votes on a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VotingEligibility {
+    /// Lowest role allowed to vote
+    pub minimum_role: UserRole,
+}
+
+impl VotingEligibility {
+    /// Eligibility requiring at least `minimum_role`
+    pub fn new(minimum_role: UserRole) -> Self {
+        Self { minimum_role }
+    }
+
+    /// Whether a user with `role` may vote
+    pub fn allows(&self, role: UserRole) -> bool {
+        role >= self.minimum_role
+    }
+}
+
+impl Default for VotingEligibility {
+    fn default() -> Self {
+        Self::new(UserRole::Contributor)
+    }
+}
+
+/// Quorum and approval requirements applied when tallying a vote
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProposalPolicy {
+    /// Minimum number of votes cast, abstentions included
+    pub quorum: u32,
+    /// Fraction of decisive (for + against) votes required to approve
+    pub approval_threshold: f64,
+}
+
+/// Voting power of each voter role
+///
+/// Roles without an entry vote with a weight of 1.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RoleWeights(BTreeMap<UserRole, u32>);
+
+impl RoleWeights {
+    /// Weights from a role-to-weight map
+    pub fn new(weights: BTreeMap<UserRole, u32>) -> Self {
+        Self(weights)
+    }
+
+    /// Platform weights: reviewers vote with 2, admins with 3
+    pub fn platform_default() -> Self {
+        Self::new([(UserRole::Reviewer, 2), (UserRole::Admin, 3)].into())
+    }
+
+    /// Voting power of a voter holding `role`
+    pub fn weight(&self, role: UserRole) -> u32 {
+        self.0.get(&role).copied().unwrap_or(1)
+    }
+}
+
+impl ProposalPolicy {
+    /// Policy recorded on the proposal's voting state
+    pub fn from_voting(voting: &VotingState) -> Self {
+        Self {
+            quorum: voting.quorum_required,
+            approval_threshold: voting.approval_threshold,
+        }
+    }
+}
+
+impl Proposal {
+    /// Record a vote from `voter`.
+    ///
+    /// The proposal must be in `Voting` with its window open at `now`, the
+    /// voter's role must satisfy `eligibility`, and each voter may vote once.
+    pub fn cast_vote(
+        &mut self,
+        voter: UserId,
+        role: UserRole,
+        vote: Vote,
+        eligibility: &VotingEligibility,
+        now: DateTime<Utc>,
+    ) -> Result<(), GovernanceError> {
+        if self.status != ProposalStatus::Voting || !self.voting.is_open(now) {
+            return Err(GovernanceError::VotingNotActive);
+        }
+        if !eligibility.allows(role) {
+            return Err(GovernanceError::InsufficientVotingPower);
+        }
+        if !self.voting.voters.insert(voter) {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+
+        match vote {
+            Vote::Approve => self.voting.votes_for += 1,
+            Vote::Reject => self.voting.votes_against += 1,
+            Vote::Abstain => self.voting.votes_abstain += 1,
+        }
+        self.voting.ballots.push(Ballot {
+            voter,
+            role,
+            vote,
+            cast_at: now,
+        });
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Record the outcome of a closed vote at `now` and move to the matching
+    /// status.
+    ///
+    /// Only proposals currently in `Voting` can be finalized.
+    pub fn finalize(
+        &mut self,
+        outcome: ProposalOutcome,
+        now: DateTime<Utc>,
+    ) -> Result<(), GovernanceError> {
+        if self.status != ProposalStatus::Voting {
+            return Err(GovernanceError::ProposalNotModifiable);
+        }
+        self.status = outcome.resulting_status();
+        self.updated_at = now;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +256,88 @@ pub struct LineReference {
     pub end_line: Option<u32>,
 }
 
+/// Discussion comment on a proposal, optionally replying to another comment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    /// Comment identifier
+    pub id: CommentId,
+    /// Proposal the comment belongs to
+    pub proposal_id: ProposalId,
+    /// Who wrote the comment
+    pub author: UserId,
+    /// Comment text
+    pub body: String,
+    /// Comment this one replies to, if it is a reply
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<CommentId>,
+    /// When the comment was posted
+    pub created_at: DateTime<Utc>,
+}
+
+impl Comment {
+    /// Create a comment timestamped now
+    pub fn new(
+        proposal_id: ProposalId,
+        author: UserId,
+        body: impl Into<String>,
+        parent_id: Option<CommentId>,
+    ) -> Self {
+        Self {
+            id: CommentId::new(),
+            proposal_id,
+            author,
+            body: body.into(),
+            parent_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// A comment together with its nested replies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentThread {
+    /// The comment starting this thread
+    pub comment: Comment,
+    /// Direct replies, oldest first, each with its own replies
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    /// Arrange a flat list of comments into threads ordered by creation time.
+    ///
+    /// Comments whose parent is not in the list are treated as top-level.
+    pub fn build(mut comments: Vec<Comment>) -> Vec<CommentThread> {
+        comments.sort_by_key(|c| c.created_at);
+
+        let ids: HashSet<CommentId> = comments.iter().map(|c| c.id).collect();
+        let mut children: HashMap<Option<CommentId>, Vec<Comment>> = HashMap::new();
+        for comment in comments {
+            let parent = comment.parent_id.filter(|id| ids.contains(id));
+            children.entry(parent).or_default().push(comment);
+        }
+
+        fn attach(
+            comment: Comment,
+            children: &mut HashMap<Option<CommentId>, Vec<Comment>>,
+        ) -> CommentThread {
+            let replies = children
+                .remove(&Some(comment.id))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|reply| attach(reply, children))
+                .collect();
+            CommentThread { comment, replies }
+        }
+
+        children
+            .remove(&None)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|root| attach(root, &mut children))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ProposalOutcome {
@@ -98,7 +347,77 @@ pub enum ProposalOutcome {
     Expired,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+impl ProposalOutcome {
+    /// Tally a vote against a policy.
+    ///
+    /// Abstentions count towards quorum but not towards the approval ratio.
+    pub fn from_votes(voting: &VotingState, policy: &ProposalPolicy) -> Self {
+        Self::from_totals(
+            voting.votes_for,
+            voting.votes_against,
+            voting.votes_abstain,
+            policy,
+        )
+    }
+
+    /// Tally a vote with each ballot weighted by its voter's role.
+    ///
+    /// Quorum and approval are both computed over the weighted totals. Votes
+    /// counted on the voting state without a matching ballot weigh 1.
+    pub fn from_weighted_votes(
+        voting: &VotingState,
+        policy: &ProposalPolicy,
+        weights: &RoleWeights,
+    ) -> Self {
+        let mut totals = [0u32; 3];
+        let mut balloted = [0u32; 3];
+        for ballot in &voting.ballots {
+            let slot = match ballot.vote {
+                Vote::Approve => 0,
+                Vote::Reject => 1,
+                Vote::Abstain => 2,
+            };
+            totals[slot] += weights.weight(ballot.role);
+            balloted[slot] += 1;
+        }
+
+        let counted = [voting.votes_for, voting.votes_against, voting.votes_abstain];
+        for ((total, counted), balloted) in totals.iter_mut().zip(counted).zip(balloted) {
+            *total += counted.saturating_sub(balloted);
+        }
+
+        let [approve, reject, abstain] = totals;
+        Self::from_totals(approve, reject, abstain, policy)
+    }
+
+    fn from_totals(approve: u32, reject: u32, abstain: u32, policy: &ProposalPolicy) -> Self {
+        if approve + reject + abstain < policy.quorum {
+            return Self::QuorumNotMet;
+        }
+
+        let decisive = approve + reject;
+        if decisive == 0 {
+            return Self::Rejected;
+        }
+
+        let approval = approve as f64 / decisive as f64;
+        if approval >= policy.approval_threshold {
+            Self::Approved
+        } else {
+            Self::Rejected
+        }
+    }
+
+    /// Proposal status a finalized proposal moves to
+    pub fn resulting_status(&self) -> ProposalStatus {
+        match self {
+            Self::Approved => ProposalStatus::Approved,
+            Self::Rejected | Self::QuorumNotMet | Self::Expired => ProposalStatus::Rejected,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Vote {
     Approve,
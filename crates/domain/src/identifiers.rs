@@ -2,12 +2,119 @@
 //!
 //! This module defines unique identifiers for all major domain entities, preventing
 //! accidental mixing of different ID types through compile-time type safety.
-//! All IDs use UUID v7 for time-ordering and distributed generation.
+//!
+//! New IDs come from the process-wide [`IdGenerator`], which defaults to
+//! time-ordered UUID v7 and can be switched to ULIDs once at startup with
+//! [`set_id_generator`]. Either way IDs sort by creation time.
 
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
 use uuid::Uuid;
 
+/// Strategy for generating new entity IDs
+pub trait IdGenerator: Send + Sync {
+    /// Generate a new, unique ID
+    fn generate(&self) -> Uuid;
+}
+
+/// Generates time-ordered UUID v7 IDs
+///
+/// IDs generated by the same process are strictly increasing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV7Generator;
+
+impl IdGenerator for UuidV7Generator {
+    fn generate(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+/// Generates ULIDs, stored in UUID form
+///
+/// IDs from the same generator are strictly increasing, including those
+/// generated within the same millisecond.
+#[derive(Default)]
+pub struct UlidGenerator {
+    generator: Mutex<ulid::Generator>,
+}
+
+impl UlidGenerator {
+    /// Create a new ULID generator
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGenerator for UlidGenerator {
+    fn generate(&self) -> Uuid {
+        let mut generator = self
+            .generator
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Only fails once 2^80 IDs have been generated in one millisecond
+        let ulid = generator.generate().unwrap_or_else(|_| ulid::Ulid::new());
+        Uuid::from(ulid)
+    }
+}
+
+/// Named ID generation strategies, for configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdStrategy {
+    /// UUID v7, see [`UuidV7Generator`]
+    #[default]
+    UuidV7,
+    /// ULID, see [`UlidGenerator`]
+    Ulid,
+}
+
+impl IdStrategy {
+    /// Create a generator for this strategy
+    pub fn generator(self) -> Box<dyn IdGenerator> {
+        match self {
+            Self::UuidV7 => Box::new(UuidV7Generator),
+            Self::Ulid => Box::new(UlidGenerator::new()),
+        }
+    }
+}
+
+impl FromStr for IdStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "uuid_v7" | "uuidv7" | "v7" => Ok(Self::UuidV7),
+            "ulid" => Ok(Self::Ulid),
+            other => Err(format!(
+                "unknown ID strategy '{}', expected uuid_v7 or ulid",
+                other
+            )),
+        }
+    }
+}
+
+static ID_GENERATOR: OnceLock<Box<dyn IdGenerator>> = OnceLock::new();
+
+/// Install the process-wide ID generator
+///
+/// Must be called before any ID is generated, so that all IDs in a process
+/// come from one strategy. Returns the generator back if one is already in
+/// use.
+pub fn set_id_generator(generator: Box<dyn IdGenerator>) -> Result<(), Box<dyn IdGenerator>> {
+    ID_GENERATOR.set(generator)
+}
+
+/// Generate a new ID with the process-wide generator
+///
+/// Uses [`UuidV7Generator`] unless [`set_id_generator`] was called first.
+pub fn generate_id() -> Uuid {
+    ID_GENERATOR
+        .get_or_init(|| Box::new(UuidV7Generator))
+        .generate()
+}
+
 macro_rules! define_id {
     ($name:ident, $doc:expr) => {
         #[doc = $doc]
@@ -16,10 +123,10 @@ macro_rules! define_id {
         pub struct $name(Uuid);
 
         impl $name {
-            /// Create a new ID with a time-ordered UUID v7
+            /// Create a new ID with the process-wide [`IdGenerator`]
             #[inline]
             pub fn new() -> Self {
-                Self(Uuid::now_v7())
+                Self(generate_id())
             }
 
             /// Create an ID from an existing UUID
@@ -65,7 +172,7 @@ macro_rules! define_id {
             }
         }
 
-        impl std::str::FromStr for $name {
+        impl FromStr for $name {
             type Err = uuid::Error;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -78,7 +185,7 @@ macro_rules! define_id {
 // Define all ID types
 define_id!(
     BenchmarkId,
-    "Unique identifier for benchmarks (UUID v7 for time-ordering)"
+    "Unique identifier for benchmarks"
 );
 
 define_id!(
@@ -104,6 +211,8 @@ define_id!(LeaderboardId, "Unique identifier for leaderboards");
 
 define_id!(ProposalId, "Unique identifier for governance proposals");
 
+define_id!(CommentId, "Unique identifier for proposal comments");
+
 define_id!(
     VerificationId,
     "Unique identifier for verification runs"
@@ -115,6 +224,39 @@ define_id!(SubscriptionId, "Unique identifier for event subscriptions");
 mod tests {
     use super::*;
 
+    fn assert_increasing(generator: &dyn IdGenerator) {
+        let ids: Vec<Uuid> = (0..10_000).map(|_| generator.generate()).collect();
+        for pair in ids.windows(2) {
+            assert!(pair[0] < pair[1], "{} is not before {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_uuid_v7_ids_are_increasing() {
+        assert_increasing(&UuidV7Generator);
+        assert_eq!(UuidV7Generator.generate().get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_ulid_ids_are_increasing() {
+        assert_increasing(&UlidGenerator::new());
+    }
+
+    #[test]
+    fn test_generator_is_fixed_after_first_id() {
+        let _ = BenchmarkId::new();
+        assert!(set_id_generator(IdStrategy::Ulid.generator()).is_err());
+    }
+
+    #[test]
+    fn test_parse_id_strategy() {
+        assert_eq!("ulid".parse::<IdStrategy>().unwrap(), IdStrategy::Ulid);
+        assert_eq!("UUID_V7".parse::<IdStrategy>().unwrap(), IdStrategy::UuidV7);
+        assert_eq!("v7".parse::<IdStrategy>().unwrap(), IdStrategy::UuidV7);
+        assert!("v4".parse::<IdStrategy>().is_err());
+        assert_eq!(IdStrategy::default(), IdStrategy::UuidV7);
+    }
+
     #[test]
     fn test_id_creation() {
         let id = BenchmarkId::new();
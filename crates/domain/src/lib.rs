@@ -60,15 +60,18 @@ pub mod publication;
 
 // Re-export commonly used types
 pub use identifiers::*;
-pub use version::{SemanticVersion, VersionParseError};
+pub use version::{BumpKind, SemanticVersion, VersionParseError};
 pub use errors::{AppError, AppResult};
 pub use validation::{ValidationResult, ValidationIssue, IssueSeverity};
 
 // Re-export key domain types
 pub use benchmark::{BenchmarkCategory, BenchmarkStatus, BenchmarkMetadata, LicenseType, Citation};
 pub use user::{UserRole, OrganizationType, OrganizationRole};
-pub use submission::{VerificationLevel, SubmissionVisibility};
-pub use governance::{ProposalType, ProposalStatus, ProposalOutcome, Vote};
+pub use submission::{VerificationEvent, VerificationLevel, SubmissionVisibility};
+pub use governance::{
+    Ballot, Comment, CommentThread, ProposalType, ProposalStatus, ProposalOutcome, ProposalPolicy,
+    Vote, VotingEligibility,
+};
 pub use publication::{
     PublicationId, PublicationStatus, PublicationDecisionType, PublicationConfidence,
     ConfidenceLevel, PublicationConstraints, Publication, PublicationEvent, DecisionEvent,
@@ -1,6 +1,7 @@
 //! Submission types for benchmark results.
 
-use crate::evaluation::ModelParameters;
+use crate::errors::{SubmissionError, VerificationError};
+use crate::evaluation::{MetricRange, ModelParameters, ScorePrecision};
 use crate::identifiers::{BenchmarkId, BenchmarkVersionId, ModelId, OrganizationId, SubmissionId, UserId, VerificationId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,10 @@ use std::collections::HashMap;
 use url::Url;
 
 /// Benchmark result submission
+///
+/// Results, verification status and cancellation only change through
+/// [`Submission::set_results`], [`Submission::verify`] and
+/// [`Submission::cancel`], which enforce the submission's invariants.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Submission {
     pub id: SubmissionId,
@@ -15,14 +20,183 @@ pub struct Submission {
     pub benchmark_version_id: BenchmarkVersionId,
     pub model_info: ModelInfo,
     pub submitter: SubmitterInfo,
-    pub results: SubmissionResults,
+    results: SubmissionResults,
     pub execution_metadata: ExecutionMetadata,
-    pub verification_status: VerificationStatus,
+    verification_status: VerificationStatus,
     pub visibility: SubmissionVisibility,
+    /// When the submitter withdrew the submission, if they did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cancelled_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Check that a submission at `level` may be cancelled
+///
+/// Only submissions that have not been cancelled yet and have not been
+/// verified at any level can be withdrawn.
+pub fn check_cancellable(
+    level: VerificationLevel,
+    cancelled_at: Option<DateTime<Utc>>,
+) -> Result<(), SubmissionError> {
+    if cancelled_at.is_some() {
+        return Err(SubmissionError::NotCancellable(
+            "Submission is already cancelled".to_string(),
+        ));
+    }
+    if level != VerificationLevel::Unverified {
+        return Err(SubmissionError::NotCancellable(
+            "Verified submissions cannot be cancelled".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl Submission {
+    /// Create an unverified submission, enforcing the benchmark's score range
+    pub fn new(
+        benchmark_id: BenchmarkId,
+        benchmark_version_id: BenchmarkVersionId,
+        model_info: ModelInfo,
+        submitter: SubmitterInfo,
+        results: SubmissionResults,
+        execution_metadata: ExecutionMetadata,
+        visibility: SubmissionVisibility,
+        score_range: Option<&MetricRange>,
+    ) -> Result<Self, SubmissionError> {
+        results.check_range(score_range)?;
+        let now = Utc::now();
+        Ok(Self {
+            id: SubmissionId::new(),
+            benchmark_id,
+            benchmark_version_id,
+            model_info,
+            submitter,
+            results,
+            execution_metadata,
+            verification_status: VerificationStatus {
+                level: VerificationLevel::Unverified,
+                verified_at: None,
+                verified_by: None,
+                verification_details: None,
+            },
+            visibility,
+            cancelled_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Rebuild a submission from stored state without re-checking it
+    pub fn restore(
+        id: SubmissionId,
+        benchmark_id: BenchmarkId,
+        benchmark_version_id: BenchmarkVersionId,
+        model_info: ModelInfo,
+        submitter: SubmitterInfo,
+        results: SubmissionResults,
+        execution_metadata: ExecutionMetadata,
+        verification_status: VerificationStatus,
+        visibility: SubmissionVisibility,
+        cancelled_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            benchmark_id,
+            benchmark_version_id,
+            model_info,
+            submitter,
+            results,
+            execution_metadata,
+            verification_status,
+            visibility,
+            cancelled_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Submitted results
+    pub fn results(&self) -> &SubmissionResults {
+        &self.results
+    }
+
+    /// Take the results, consuming the submission
+    pub fn into_results(self) -> SubmissionResults {
+        self.results
+    }
+
+    /// Current verification status
+    pub fn verification_status(&self) -> &VerificationStatus {
+        &self.verification_status
+    }
+
+    /// When the submission was cancelled, if it was
+    pub fn cancelled_at(&self) -> Option<DateTime<Utc>> {
+        self.cancelled_at
+    }
+
+    /// Replace the results, enforcing the benchmark's score range
+    pub fn set_results(
+        &mut self,
+        results: SubmissionResults,
+        score_range: Option<&MetricRange>,
+    ) -> Result<(), SubmissionError> {
+        results.check_range(score_range)?;
+        self.results = results;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Record a verification at `level`
+    ///
+    /// Re-verifying at the current level is allowed; lowering the level or
+    /// verifying a cancelled submission is rejected. `details` replaces any
+    /// earlier verification details.
+    pub fn verify(
+        &mut self,
+        level: VerificationLevel,
+        verifier: Option<VerifiedBy>,
+        details: Option<VerificationDetails>,
+    ) -> Result<VerificationEvent, VerificationError> {
+        if self.cancelled_at.is_some() {
+            return Err(VerificationError::InvalidSubmissionState);
+        }
+        let from_level = self.verification_status.level;
+        from_level.check_transition(level)?;
+
+        let now = Utc::now();
+        self.verification_status = VerificationStatus {
+            level,
+            verified_at: verifier.as_ref().map(|_| now),
+            verified_by: verifier.clone(),
+            verification_details: details,
+        };
+        self.updated_at = now;
+
+        Ok(VerificationEvent {
+            submission_id: self.id,
+            from_level,
+            to_level: level,
+            verifier,
+            reason: None,
+            at: now,
+        })
+    }
+
+    /// Withdraw the submission, returning when it was cancelled
+    pub fn cancel(&mut self) -> Result<DateTime<Utc>, SubmissionError> {
+        check_cancellable(self.verification_status.level, self.cancelled_at)?;
+
+        let now = Utc::now();
+        self.cancelled_at = Some(now);
+        self.updated_at = now;
+        Ok(now)
+    }
+}
+
 /// Model information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelInfo {
@@ -47,7 +221,7 @@ pub struct SubmitterInfo {
 }
 
 /// Submission results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubmissionResults {
     pub aggregate_score: f64,
     pub metric_scores: HashMap<String, MetricScore>,
@@ -58,8 +232,42 @@ pub struct SubmissionResults {
     pub statistical_significance: Option<StatisticalSignificance>,
 }
 
+impl SubmissionResults {
+    /// Check that the aggregate score is finite and within `range`
+    pub fn check_range(&self, range: Option<&MetricRange>) -> Result<(), SubmissionError> {
+        let score = self.aggregate_score;
+        if !score.is_finite() {
+            return Err(SubmissionError::InvalidResults(format!(
+                "aggregate score must be finite, got {}",
+                score
+            )));
+        }
+        match range {
+            Some(range) if score < range.min || score > range.max => {
+                Err(SubmissionError::ScoreOutOfRange {
+                    score,
+                    min: range.min,
+                    max: range.max,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Round the aggregate and metric scores to a benchmark's precision
+    ///
+    /// Raw metric values, standard deviations and per-test-case scores keep
+    /// full precision.
+    pub fn apply_precision(&mut self, precision: &ScorePrecision) {
+        self.aggregate_score = precision.round(self.aggregate_score);
+        for metric in self.metric_scores.values_mut() {
+            metric.value = precision.round(metric.value);
+        }
+    }
+}
+
 /// Individual metric score
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricScore {
     pub value: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,7 +279,7 @@ pub struct MetricScore {
 }
 
 /// Individual test case result
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TestCaseResult {
     pub test_case_id: String,
     pub passed: bool,
@@ -85,13 +293,13 @@ pub struct TestCaseResult {
 }
 
 /// Test case execution error
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TestCaseError {
     pub error_type: TestCaseErrorType,
     pub message: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TestCaseErrorType {
     Timeout,
@@ -102,7 +310,7 @@ pub enum TestCaseErrorType {
 }
 
 /// Confidence interval
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConfidenceInterval {
     pub lower: f64,
     pub upper: f64,
@@ -110,7 +318,7 @@ pub struct ConfidenceInterval {
 }
 
 /// Statistical significance information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StatisticalSignificance {
     pub p_value: f64,
     pub effect_size: f64,
@@ -173,9 +381,16 @@ pub struct VerificationStatus {
     pub verification_details: Option<VerificationDetails>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// How thoroughly a submission's results have been verified.
+///
+/// Levels are ordered from weakest to strongest:
+/// `Unverified < CommunityVerified < PlatformVerified < Audited`.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum VerificationLevel {
+    #[default]
     Unverified,
     CommunityVerified,
     PlatformVerified,
@@ -191,6 +406,20 @@ impl VerificationLevel {
             Self::Audited => 3,
         }
     }
+
+    /// Whether this level is at least as strong as `minimum`
+    pub fn meets(&self, minimum: VerificationLevel) -> bool {
+        *self >= minimum
+    }
+
+    /// Check that moving from this level to `to` is not a downgrade
+    pub fn check_transition(self, to: VerificationLevel) -> Result<(), VerificationError> {
+        if to.meets(self) {
+            Ok(())
+        } else {
+            Err(VerificationError::Downgrade { from: self, to })
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -211,6 +440,25 @@ pub struct VerificationDetails {
     pub notes: Option<String>,
 }
 
+/// A recorded change of a submission's verification level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationEvent {
+    /// Submission whose verification changed
+    pub submission_id: SubmissionId,
+    /// Level before the change
+    pub from_level: VerificationLevel,
+    /// Level after the change
+    pub to_level: VerificationLevel,
+    /// Who performed the verification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verifier: Option<VerifiedBy>,
+    /// Reason or notes given by the verifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// When the change happened
+    pub at: DateTime<Utc>,
+}
+
 /// Submission visibility
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -219,3 +467,232 @@ pub enum SubmissionVisibility {
     Unlisted,
     Private,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verification_level_ordering() {
+        use VerificationLevel::*;
+
+        assert!(Unverified < CommunityVerified);
+        assert!(CommunityVerified < PlatformVerified);
+        assert!(PlatformVerified < Audited);
+
+        let mut levels = vec![Audited, Unverified, PlatformVerified, CommunityVerified];
+        levels.sort();
+        assert_eq!(
+            levels,
+            vec![Unverified, CommunityVerified, PlatformVerified, Audited]
+        );
+        assert_eq!(levels.iter().max(), Some(&Audited));
+    }
+
+    #[test]
+    fn test_verification_level_meets() {
+        use VerificationLevel::*;
+
+        assert!(Unverified.meets(Unverified));
+        assert!(!Unverified.meets(CommunityVerified));
+        assert!(PlatformVerified.meets(CommunityVerified));
+        assert!(PlatformVerified.meets(PlatformVerified));
+        assert!(!PlatformVerified.meets(Audited));
+        assert!(Audited.meets(Unverified));
+    }
+
+    fn results(aggregate_score: f64) -> SubmissionResults {
+        SubmissionResults {
+            aggregate_score,
+            metric_scores: HashMap::new(),
+            test_case_results: vec![],
+            confidence_interval: None,
+            statistical_significance: None,
+        }
+    }
+
+    fn submission() -> Submission {
+        submission_with(results(0.5), None).unwrap()
+    }
+
+    fn submission_with(
+        results: SubmissionResults,
+        score_range: Option<&MetricRange>,
+    ) -> Result<Submission, SubmissionError> {
+        let now = Utc::now();
+        Submission::new(
+            BenchmarkId::new(),
+            BenchmarkVersionId::new(),
+            ModelInfo {
+                model_id: None,
+                provider: "acme".to_string(),
+                model_name: "model-1".to_string(),
+                model_version: None,
+                api_endpoint: None,
+                is_official: false,
+            },
+            SubmitterInfo {
+                user_id: UserId::new(),
+                organization_id: None,
+                is_verified_provider: false,
+            },
+            results,
+            ExecutionMetadata {
+                execution_id: "exec-1".to_string(),
+                started_at: now,
+                completed_at: now,
+                duration_seconds: 0.0,
+                environment: EnvironmentInfo {
+                    platform: "linux".to_string(),
+                    architecture: "x86_64".to_string(),
+                    container_image: None,
+                    container_digest: None,
+                    python_version: None,
+                    package_versions: HashMap::new(),
+                    hardware: None,
+                },
+                model_parameters_used: ModelParameters {
+                    temperature: None,
+                    top_p: None,
+                    top_k: None,
+                    max_tokens: None,
+                    stop_sequences: vec![],
+                    random_seed: None,
+                    additional_params: HashMap::new(),
+                },
+                dataset_checksums: HashMap::new(),
+                random_seed: None,
+                executor_version: "1.0.0".to_string(),
+            },
+            SubmissionVisibility::Public,
+            score_range,
+        )
+    }
+
+    #[test]
+    fn test_new_enforces_range() {
+        let range = MetricRange { min: 0.0, max: 1.0 };
+
+        let submission = submission_with(results(0.5), Some(&range)).unwrap();
+        assert_eq!(submission.verification_status().level, VerificationLevel::Unverified);
+        assert!(submission.cancelled_at().is_none());
+        assert!(matches!(
+            submission_with(results(1.5), Some(&range)),
+            Err(SubmissionError::ScoreOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_set_results_enforces_range() {
+        let range = MetricRange { min: 0.0, max: 1.0 };
+        let mut submission = submission();
+
+        submission.set_results(results(1.0), Some(&range)).unwrap();
+        assert_eq!(submission.results.aggregate_score, 1.0);
+
+        let err = submission.set_results(results(1.5), Some(&range)).unwrap_err();
+        assert!(matches!(
+            err,
+            SubmissionError::ScoreOutOfRange { score, min, max }
+                if score == 1.5 && min == 0.0 && max == 1.0
+        ));
+        assert!(submission.set_results(results(-0.1), Some(&range)).is_err());
+        // Rejected results leave the existing ones in place
+        assert_eq!(submission.results.aggregate_score, 1.0);
+
+        // Without a range any finite score is accepted
+        submission.set_results(results(42.0), None).unwrap();
+        assert!(matches!(
+            submission.set_results(results(f64::NAN), None),
+            Err(SubmissionError::InvalidResults(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_downgrade() {
+        let mut submission = submission();
+        let verifier = VerifiedBy::CommunityMember { user_id: UserId::new() };
+
+        let event = submission
+            .verify(VerificationLevel::PlatformVerified, Some(verifier.clone()), None)
+            .unwrap();
+        assert_eq!(event.submission_id, submission.id);
+        assert_eq!(event.from_level, VerificationLevel::Unverified);
+        assert_eq!(event.to_level, VerificationLevel::PlatformVerified);
+        assert!(submission.verification_status.verified_at.is_some());
+
+        // Re-verifying at the same level is fine
+        submission
+            .verify(VerificationLevel::PlatformVerified, Some(verifier.clone()), None)
+            .unwrap();
+
+        let err = submission
+            .verify(VerificationLevel::CommunityVerified, Some(verifier), None)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            VerificationError::Downgrade {
+                from: VerificationLevel::PlatformVerified,
+                to: VerificationLevel::CommunityVerified,
+            }
+        ));
+        assert_eq!(
+            submission.verification_status.level,
+            VerificationLevel::PlatformVerified
+        );
+    }
+
+    #[test]
+    fn test_cancel_only_unverified_submissions() {
+        let mut submission = submission();
+        let cancelled_at = submission.cancel().unwrap();
+        assert_eq!(submission.cancelled_at, Some(cancelled_at));
+
+        // Cancelling twice is rejected, as is verifying a cancelled submission
+        assert!(matches!(submission.cancel(), Err(SubmissionError::NotCancellable(_))));
+        assert!(matches!(
+            submission.verify(VerificationLevel::CommunityVerified, None, None),
+            Err(VerificationError::InvalidSubmissionState)
+        ));
+
+        let mut verified = self::submission();
+        verified.verify(VerificationLevel::CommunityVerified, None, None).unwrap();
+        assert!(matches!(verified.cancel(), Err(SubmissionError::NotCancellable(_))));
+        assert!(verified.cancelled_at.is_none());
+    }
+
+    #[test]
+    fn test_apply_precision_rounds_only_display_scores() {
+        let mut results = results(2.0 / 3.0);
+        results.metric_scores.insert(
+            "accuracy".to_string(),
+            MetricScore {
+                value: 0.6666667,
+                unit: None,
+                raw_values: Some(vec![0.6666667]),
+                std_dev: Some(0.1234567),
+            },
+        );
+        results.test_case_results.push(TestCaseResult {
+            test_case_id: "tc-1".to_string(),
+            passed: true,
+            score: 0.6666667,
+            latency_ms: None,
+            tokens_generated: None,
+            error: None,
+        });
+
+        results.apply_precision(&ScorePrecision::DecimalPlaces { places: 3 });
+
+        assert_eq!(results.aggregate_score, 0.667);
+        let metric = &results.metric_scores["accuracy"];
+        assert_eq!(metric.value, 0.667);
+        assert_eq!(metric.raw_values, Some(vec![0.6666667]));
+        assert_eq!(metric.std_dev, Some(0.1234567));
+        assert_eq!(results.test_case_results[0].score, 0.6666667);
+        assert_eq!(
+            serde_json::to_value(&results).unwrap()["aggregate_score"],
+            serde_json::json!(0.667)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Test case types for benchmarks.
 
+use crate::validation::ValidationIssue;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -20,6 +21,41 @@ pub struct TestCase {
     pub difficulty: Option<DifficultyLevel>,
 }
 
+impl TestCase {
+    /// Default cap on the serialized size of a test case's input and of its
+    /// expected output
+    pub const DEFAULT_MAX_PAYLOAD_BYTES: usize = 256 * 1024;
+
+    /// Check that the serialized input and expected output each fit in
+    /// `max_bytes`
+    ///
+    /// Issue paths are relative to the test case (`input`, `expected_output`).
+    pub fn check_payload_size(&self, max_bytes: usize) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let mut check = |path: &str, size: usize| {
+            if size > max_bytes {
+                issues.push(ValidationIssue::error(
+                    path,
+                    format!(
+                        "Serialized {} is {} bytes, exceeding the limit of {} bytes",
+                        path, size, max_bytes
+                    ),
+                ));
+            }
+        };
+
+        check("input", serialized_len(&self.input));
+        if let Some(expected) = &self.expected_output {
+            check("expected_output", serialized_len(expected));
+        }
+        issues
+    }
+}
+
+fn serialized_len<T: Serialize>(value: &T) -> usize {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len())
+}
+
 /// Test case input specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestInput {
@@ -93,6 +129,18 @@ pub enum DifficultyLevel {
     Expert,
 }
 
+impl DifficultyLevel {
+    /// Tier name as used in serialized form and difficulty weight maps
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard",
+            Self::Expert => "expert",
+        }
+    }
+}
+
 /// Evaluation method
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -115,3 +163,46 @@ pub struct CodeTestCase {
     pub expected_output: String,
     pub timeout_ms: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_case(prompt: &str) -> TestCase {
+        TestCase {
+            id: "tc-1".to_string(),
+            name: "Case".to_string(),
+            description: None,
+            input: TestInput {
+                prompt_template: prompt.to_string(),
+                variables: HashMap::new(),
+                system_prompt: None,
+                few_shot_examples: vec![],
+                input_format: InputFormat::PlainText,
+            },
+            expected_output: None,
+            evaluation_method: EvaluationMethod::ExactMatch,
+            weight: 1.0,
+            tags: vec![],
+            difficulty: None,
+        }
+    }
+
+    #[test]
+    fn test_normal_payload_passes() {
+        let case = test_case("What is 2 + 2?");
+        assert!(case.check_payload_size(TestCase::DEFAULT_MAX_PAYLOAD_BYTES).is_empty());
+    }
+
+    #[test]
+    fn test_oversized_input_is_rejected() {
+        let case = test_case(&"x".repeat(2048));
+
+        let issues = case.check_payload_size(1024);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "input");
+        assert!(issues[0].severity.is_blocking());
+        assert!(issues[0].message.contains("exceeding the limit of 1024 bytes"));
+    }
+}
@@ -19,6 +19,29 @@ pub enum VersionParseError {
     InvalidComponent(String),
 }
 
+/// Version component to increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BumpKind {
+    /// Incompatible changes
+    Major,
+    /// Backwards-compatible additions
+    Minor,
+    /// Backwards-compatible fixes
+    Patch,
+}
+
+impl BumpKind {
+    /// The bump for a change: major if it is breaking, minor otherwise
+    pub fn for_change(breaking: bool) -> Self {
+        if breaking {
+            Self::Major
+        } else {
+            Self::Minor
+        }
+    }
+}
+
 /// Semantic version with strict ordering
 ///
 /// Follows SemVer 2.0.0 specification:
@@ -223,6 +246,25 @@ impl SemanticVersion {
         }
     }
 
+    /// Increment one component
+    ///
+    /// Lower components reset to 0 and prerelease and build metadata are
+    /// removed.
+    ///
+    /// # Example
+    /// ```
+    /// # use llm_benchmark_domain::version::{BumpKind, SemanticVersion};
+    /// let v = SemanticVersion::parse("1.2.3-rc.1").unwrap();
+    /// assert_eq!(v.bump(BumpKind::Minor).to_string(), "1.3.0");
+    /// ```
+    pub fn bump(&self, kind: BumpKind) -> Self {
+        match kind {
+            BumpKind::Major => self.increment_major(),
+            BumpKind::Minor => self.increment_minor(),
+            BumpKind::Patch => self.increment_patch(),
+        }
+    }
+
     /// Check if this is a prerelease version
     pub fn is_prerelease(&self) -> bool {
         self.prerelease.is_some()
@@ -372,6 +414,23 @@ mod tests {
         assert_eq!(v.increment_major(), SemanticVersion::new(2, 0, 0));
     }
 
+    #[test]
+    fn test_version_bump() {
+        let v = SemanticVersion::new(1, 2, 3);
+        assert_eq!(v.bump(BumpKind::Major).to_string(), "2.0.0");
+        assert_eq!(v.bump(BumpKind::Minor).to_string(), "1.3.0");
+        assert_eq!(v.bump(BumpKind::Patch).to_string(), "1.2.4");
+
+        let pre = SemanticVersion::parse("1.2.3-beta.2+build.7").unwrap();
+        assert_eq!(pre.bump(BumpKind::Major).to_string(), "2.0.0");
+        assert_eq!(pre.bump(BumpKind::Minor).to_string(), "1.3.0");
+        assert_eq!(pre.bump(BumpKind::Patch).to_string(), "1.2.4");
+        assert!(!pre.bump(BumpKind::Patch).is_prerelease());
+
+        assert_eq!(BumpKind::for_change(true), BumpKind::Major);
+        assert_eq!(BumpKind::for_change(false), BumpKind::Minor);
+    }
+
     #[test]
     fn test_version_serialization() {
         let v = SemanticVersion::parse("1.2.3-alpha+build.123").unwrap();
@@ -4,7 +4,11 @@
 
 use llm_benchmark_domain::{
     benchmark::BenchmarkStatus,
-    governance::ProposalStatus,
+    governance::{
+        Ballot, ProposalOutcome, ProposalPolicy, ProposalStatus, RoleWeights, Vote, VotingState,
+    },
+    identifiers::UserId,
+    user::UserRole,
 };
 
 // ============================================================================
@@ -202,6 +206,101 @@ fn test_proposal_status_all_variants() {
     }
 }
 
+fn voting_state(votes_for: u32, votes_against: u32, votes_abstain: u32) -> VotingState {
+    VotingState {
+        voting_starts: None,
+        voting_ends: None,
+        votes_for,
+        votes_against,
+        votes_abstain,
+        voters: Default::default(),
+        quorum_required: 0,
+        approval_threshold: 0.0,
+        ballots: Vec::new(),
+        role_weights: None,
+    }
+}
+
+#[test]
+fn test_proposal_outcome_from_votes() {
+    let policy = ProposalPolicy {
+        quorum: 5,
+        approval_threshold: 0.6,
+    };
+
+    assert_eq!(
+        ProposalOutcome::from_votes(&voting_state(4, 1, 0), &policy),
+        ProposalOutcome::Approved
+    );
+    assert_eq!(
+        ProposalOutcome::from_votes(&voting_state(2, 2, 1), &policy),
+        ProposalOutcome::Rejected
+    );
+    // Abstentions count towards quorum only
+    assert_eq!(
+        ProposalOutcome::from_votes(&voting_state(3, 0, 2), &policy),
+        ProposalOutcome::Approved
+    );
+    assert_eq!(
+        ProposalOutcome::from_votes(&voting_state(3, 0, 0), &policy),
+        ProposalOutcome::QuorumNotMet
+    );
+}
+
+#[test]
+fn test_proposal_outcome_from_weighted_votes() {
+    let policy = ProposalPolicy {
+        quorum: 5,
+        approval_threshold: 0.6,
+    };
+    let weights = RoleWeights::new([(UserRole::Reviewer, 2), (UserRole::Admin, 3)].into());
+    let ballot = |role, vote| Ballot {
+        voter: UserId::new(),
+        role,
+        vote,
+        cast_at: chrono::Utc::now(),
+    };
+
+    // Two reviewers and a contributor carry quorum alone...
+    let mut voting = voting_state(2, 1, 0);
+    voting.ballots = vec![
+        ballot(UserRole::Reviewer, Vote::Approve),
+        ballot(UserRole::Reviewer, Vote::Approve),
+        ballot(UserRole::Contributor, Vote::Reject),
+    ];
+    assert_eq!(
+        ProposalOutcome::from_weighted_votes(&voting, &policy, &weights),
+        ProposalOutcome::Approved
+    );
+    // ...which an unweighted count of three votes misses
+    assert_eq!(
+        ProposalOutcome::from_votes(&voting, &policy),
+        ProposalOutcome::QuorumNotMet
+    );
+
+    // An admin's rejection outweighs the two reviewers' approvals
+    voting.ballots.push(ballot(UserRole::Admin, Vote::Reject));
+    voting.votes_against += 1;
+    assert_eq!(
+        ProposalOutcome::from_weighted_votes(&voting, &policy, &weights),
+        ProposalOutcome::Rejected
+    );
+
+    // Counts without ballots weigh 1 each
+    assert_eq!(
+        ProposalOutcome::from_weighted_votes(&voting_state(4, 1, 0), &policy, &weights),
+        ProposalOutcome::Approved
+    );
+}
+
+#[test]
+fn test_proposal_outcome_resulting_status() {
+    assert_eq!(ProposalOutcome::Approved.resulting_status(), ProposalStatus::Approved);
+    assert_eq!(ProposalOutcome::Rejected.resulting_status(), ProposalStatus::Rejected);
+    assert_eq!(ProposalOutcome::QuorumNotMet.resulting_status(), ProposalStatus::Rejected);
+    assert_eq!(ProposalOutcome::Expired.resulting_status(), ProposalStatus::Rejected);
+}
+
 // ============================================================================
 // Cross-status Integration Tests
 // ============================================================================
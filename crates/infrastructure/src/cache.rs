@@ -169,7 +169,9 @@ impl RedisCache {
 
     /// Check rate limit for a key.
     ///
-    /// Returns (allowed, remaining, reset_at_secs).
+    /// Uses a sliding window: a request is allowed if fewer than `limit`
+    /// requests were recorded in the last `window_secs` seconds, and is then
+    /// recorded itself.
     #[instrument(skip(self))]
     pub async fn check_rate_limit(
         &self,
@@ -204,28 +206,20 @@ impl RedisCache {
             .await
             .map_err(Error::Cache)?;
 
-        if count >= limit {
-            // Get the oldest entry to determine reset time
-            let oldest: Vec<(String, f64)> = redis::cmd("ZRANGE")
-                .arg(&full_key)
-                .arg(0)
-                .arg(0)
-                .arg("WITHSCORES")
-                .query_async(&mut conn)
-                .await
-                .map_err(Error::Cache)?;
-
-            let reset_at = oldest
-                .first()
-                .map(|(_, score)| *score as u64 + window_secs)
-                .unwrap_or(now + window_secs);
-
-            return Ok(RateLimitResult {
-                allowed: false,
-                remaining: 0,
-                reset_at,
-                limit,
-            });
+        // The oldest entry determines when the window frees up
+        let oldest: Vec<(String, f64)> = redis::cmd("ZRANGE")
+            .arg(&full_key)
+            .arg(0)
+            .arg(0)
+            .arg("WITHSCORES")
+            .query_async(&mut conn)
+            .await
+            .map_err(Error::Cache)?;
+        let oldest = oldest.first().map(|(_, score)| *score as u64);
+
+        let result = RateLimitResult::from_window(limit, window_secs, now, count, oldest);
+        if !result.allowed {
+            return Ok(result);
         }
 
         // Add new entry
@@ -246,12 +240,7 @@ impl RedisCache {
             .await
             .map_err(Error::Cache)?;
 
-        Ok(RateLimitResult {
-            allowed: true,
-            remaining: limit - count - 1,
-            reset_at: now + window_secs,
-            limit,
-        })
+        Ok(result)
     }
 
     /// Acquire a distributed lock.
@@ -452,16 +441,46 @@ pub struct CacheHealthStatus {
 }
 
 /// Rate limit result.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RateLimitResult {
     /// Whether the request is allowed
     pub allowed: bool,
     /// Number of requests remaining in the window
     pub remaining: u64,
-    /// Unix timestamp when the limit resets
+    /// Unix timestamp when the oldest request leaves the window, freeing a slot
     pub reset_at: u64,
     /// The limit
     pub limit: u64,
+    /// Seconds to wait before retrying, set only when the request is denied
+    pub retry_after: Option<u64>,
+}
+
+impl RateLimitResult {
+    /// Compute the result of a request against a sliding window.
+    ///
+    /// `count` is the number of requests already in the window (excluding
+    /// this one) and `oldest` the timestamp of the earliest of them. An
+    /// allowed request counts against `remaining`.
+    pub fn from_window(
+        limit: u64,
+        window_secs: u64,
+        now: u64,
+        count: u64,
+        oldest: Option<u64>,
+    ) -> Self {
+        let allowed = count < limit;
+        let used = if allowed { count + 1 } else { count };
+        // An allowed request into an empty window becomes the oldest entry
+        let reset_at = oldest.unwrap_or(now) + window_secs;
+
+        Self {
+            allowed,
+            remaining: limit.saturating_sub(used),
+            reset_at,
+            limit,
+            retry_after: (!allowed).then(|| reset_at.saturating_sub(now).max(1)),
+        }
+    }
 }
 
 /// Distributed lock guard.
@@ -541,6 +560,67 @@ mod tests {
         assert_eq!(config.key_prefix, "llm-benchmark:");
     }
 
+    /// Replay requests at the given times through `from_window`, tracking
+    /// the window the way `check_rate_limit` does.
+    fn replay(limit: u64, window_secs: u64, times: &[u64]) -> Vec<RateLimitResult> {
+        let mut window: Vec<u64> = Vec::new();
+        times
+            .iter()
+            .map(|&now| {
+                window.retain(|&t| t > now - window_secs);
+                let result = RateLimitResult::from_window(
+                    limit,
+                    window_secs,
+                    now,
+                    window.len() as u64,
+                    window.first().copied(),
+                );
+                if result.allowed {
+                    window.push(now);
+                }
+                result
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rate_limit_result_within_window() {
+        let results = replay(3, 60, &[1000, 1010, 1020, 1030, 1059]);
+
+        let summary: Vec<(bool, u64, u64, Option<u64>)> = results
+            .iter()
+            .map(|r| (r.allowed, r.remaining, r.reset_at, r.retry_after))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (true, 2, 1060, None),
+                (true, 1, 1060, None),
+                (true, 0, 1060, None),
+                (false, 0, 1060, Some(30)),
+                (false, 0, 1060, Some(1)),
+            ]
+        );
+        assert!(results.iter().all(|r| r.limit == 3));
+    }
+
+    #[test]
+    fn test_rate_limit_result_window_slides() {
+        // Once the first request leaves the window a slot frees up, and the
+        // reset moves to the next oldest request
+        let results = replay(2, 60, &[1000, 1030, 1045, 1060, 1061]);
+
+        assert!(!results[2].allowed);
+        assert_eq!(results[2].retry_after, Some(15));
+
+        assert!(results[3].allowed);
+        assert_eq!(results[3].remaining, 0);
+        assert_eq!(results[3].reset_at, 1090);
+
+        assert!(!results[4].allowed);
+        assert_eq!(results[4].retry_after, Some(29));
+    }
+
     #[test]
     fn test_full_key() {
         // This would need a Redis connection to test properly
@@ -3,7 +3,13 @@
 //! Provides connection pool management, health checks, and transaction support
 //! for the LLM Benchmark Exchange platform.
 
-use sqlx::{postgres::PgPoolOptions, PgPool, Postgres, Transaction};
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions},
+    PgPool, Postgres, Transaction,
+};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, info, instrument, warn};
 
@@ -24,6 +30,11 @@ pub struct DatabaseConfig {
     pub idle_timeout: Duration,
     /// Maximum lifetime of a connection
     pub max_lifetime: Duration,
+    /// Number of prepared statements cached per connection (0 disables caching,
+    /// e.g. behind a transaction-mode PgBouncer)
+    pub statement_cache_capacity: usize,
+    /// Number of connections to open and exercise when the pool is created
+    pub warmup_connections: u32,
 }
 
 impl Default for DatabaseConfig {
@@ -35,6 +46,8 @@ impl Default for DatabaseConfig {
             acquire_timeout: Duration::from_secs(30),
             idle_timeout: Duration::from_secs(600),
             max_lifetime: Duration::from_secs(1800),
+            statement_cache_capacity: 100,
+            warmup_connections: 0,
         }
     }
 }
@@ -55,10 +68,22 @@ impl DatabaseConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(10);
 
+        let statement_cache_capacity = std::env::var("DATABASE_STATEMENT_CACHE_CAPACITY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(100);
+
+        let warmup_connections = std::env::var("DATABASE_WARMUP_CONNECTIONS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+
         Ok(Self {
             url,
             max_connections,
             min_connections,
+            statement_cache_capacity,
+            warmup_connections,
             ..Default::default()
         })
     }
@@ -72,6 +97,8 @@ impl DatabaseConfig {
             acquire_timeout: Duration::from_secs(5),
             idle_timeout: Duration::from_secs(60),
             max_lifetime: Duration::from_secs(300),
+            statement_cache_capacity: 100,
+            warmup_connections: 0,
         }
     }
 }
@@ -80,6 +107,8 @@ impl DatabaseConfig {
 #[derive(Clone)]
 pub struct DatabasePool {
     pool: PgPool,
+    /// Connections opened by the most recent warmup
+    warmed: Arc<AtomicU32>,
 }
 
 impl DatabasePool {
@@ -88,6 +117,10 @@ impl DatabasePool {
     pub async fn new(config: &DatabaseConfig) -> Result<Self> {
         info!("Initializing database connection pool");
 
+        let connect_options = PgConnectOptions::from_str(&config.url)
+            .map_err(Error::Database)?
+            .statement_cache_capacity(config.statement_cache_capacity);
+
         let pool = PgPoolOptions::new()
             .max_connections(config.max_connections)
             .min_connections(config.min_connections)
@@ -106,12 +139,50 @@ impl DatabasePool {
                     Ok(())
                 })
             })
-            .connect(&config.url)
+            .connect_with(connect_options)
             .await
             .map_err(Error::Database)?;
 
+        let pool = Self {
+            pool,
+            warmed: Arc::new(AtomicU32::new(0)),
+        };
+        if config.warmup_connections > 0 {
+            pool.warmup(config.warmup_connections).await?;
+        }
+
         info!("Database pool initialized successfully");
-        Ok(Self { pool })
+        Ok(pool)
+    }
+
+    /// Open `n` connections and run a trivial query on each.
+    ///
+    /// The connections are held concurrently so each one is distinct, then
+    /// returned to the pool as idle. `n` is capped at the pool's maximum
+    /// size. Returns the number of connections warmed.
+    #[instrument(skip(self))]
+    pub async fn warmup(&self, n: u32) -> Result<u32> {
+        let n = n.min(self.pool.options().get_max_connections());
+        let start = std::time::Instant::now();
+
+        let mut connections = Vec::with_capacity(n as usize);
+        for _ in 0..n {
+            let mut conn = self.pool.acquire().await.map_err(Error::Database)?;
+            sqlx::query_scalar::<_, i32>("SELECT 1")
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(Error::Database)?;
+            connections.push(conn);
+        }
+        drop(connections);
+
+        self.warmed.store(n, Ordering::Relaxed);
+        info!(
+            connections = n,
+            elapsed_ms = start.elapsed().as_millis(),
+            "Database pool warmed up"
+        );
+        Ok(n)
     }
 
     /// Get reference to the underlying pool.
@@ -165,6 +236,7 @@ impl DatabasePool {
         PoolStats {
             size: self.pool.size(),
             idle: self.pool.num_idle(),
+            warmed: self.warmed.load(Ordering::Relaxed),
         }
     }
 
@@ -180,6 +252,7 @@ impl std::fmt::Debug for DatabasePool {
         f.debug_struct("DatabasePool")
             .field("size", &self.pool.size())
             .field("idle", &self.pool.num_idle())
+            .field("warmed", &self.warmed.load(Ordering::Relaxed))
             .finish()
     }
 }
@@ -206,6 +279,8 @@ pub struct PoolStats {
     pub size: u32,
     /// Number of idle connections
     pub idle: usize,
+    /// Number of connections opened by the most recent warmup
+    pub warmed: u32,
 }
 
 /// Extension trait for transaction handling with automatic commit/rollback.
@@ -286,6 +361,8 @@ mod tests {
         assert_eq!(config.max_connections, 100);
         assert_eq!(config.min_connections, 10);
         assert_eq!(config.acquire_timeout, Duration::from_secs(30));
+        assert_eq!(config.statement_cache_capacity, 100);
+        assert_eq!(config.warmup_connections, 0);
     }
 
     #[test]
@@ -109,10 +109,13 @@ pub use messaging::{
     EventMessage, MessagingConfig, MessagingHealthStatus, Publisher, RedisMessaging, Subscriber,
 };
 pub use repositories::{
-    BenchmarkQuery, BenchmarkRecord, BenchmarkRepository, BenchmarkVersionSummary,
-    LeaderboardEntry, OrganizationMember, OrganizationQuery, OrganizationRepository,
-    PgBenchmarkRepository, PgOrganizationRepository, PgSubmissionRepository, PgUserRepository,
-    SubmissionQuery, SubmissionRepository, UserCredentials, UserQuery, UserRepository,
+    AccountDeletion, AccountDeletionOutcome, ApiKeyOwner, ApiKeyQuery, ApiKeyRecord,
+    ApiKeyRepository, BenchmarkQuery, BenchmarkRecord, BenchmarkRepository,
+    BenchmarkVersionSummary, GovernanceActivity, GovernanceActivityKind, LeaderboardEntry,
+    OrgLeaderboardEntry, OrganizationMember, OrganizationQuery, OrganizationRepository,
+    PgApiKeyRepository, PgBenchmarkRepository, PgOrganizationRepository, PgProposalRepository,
+    PgSubmissionRepository, PgUserRepository, ProposalRepository, SubmissionQuery,
+    SubmissionRepository, TestCaseUpsertSummary, UserCredentials, UserQuery, UserRepository,
 };
 pub use storage::{ObjectInfo, ObjectMetadata, S3Storage, Storage, StorageConfig, StorageHealthStatus};
 
@@ -129,6 +132,11 @@ pub use external_consumers::{
     TestBenchIngester, TestBenchConfig, BenchmarkResult, IngestionFormat,
 };
 
+use futures::future::{BoxFuture, FutureExt};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
 // Re-export result and error types
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -159,6 +167,10 @@ pub enum Error {
     #[error("Not found: {0}")]
     NotFound(String),
 
+    /// The resource's current state does not allow the change
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     /// Configuration errors
     #[error("Configuration error: {0}")]
     Configuration(String),
@@ -181,10 +193,19 @@ impl Error {
         )
     }
 
+    /// Check if the error is a unique constraint violation
+    pub fn is_unique_violation(&self) -> bool {
+        match self {
+            Error::Database(sqlx::Error::Database(db)) => db.is_unique_violation(),
+            _ => false,
+        }
+    }
+
     /// Get HTTP status code for this error
     pub fn http_status(&self) -> u16 {
         match self {
             Error::NotFound(_) => 404,
+            Error::Conflict(_) => 409,
             Error::Configuration(_) => 400,
             Error::Serialization(_) => 400,
             Error::Database(_) | Error::Cache(_) | Error::Storage(_) | Error::Messaging(_) => 503,
@@ -275,6 +296,141 @@ impl Default for InfrastructureHealth {
     }
 }
 
+/// Dependency health checks run together by [`HealthChecks::check_all`]
+///
+/// A check that errors or does not finish within the timeout marks its
+/// dependency unhealthy instead of holding up the aggregate.
+///
+/// ```rust,ignore
+/// let health = HealthChecks::new()
+///     .database(pool.health_check())
+///     .cache(cache.health_check())
+///     .check_all(Duration::from_secs(2))
+///     .await;
+/// ```
+#[derive(Default)]
+pub struct HealthChecks<'a> {
+    database: Option<BoxFuture<'a, Result<HealthStatus>>>,
+    cache: Option<BoxFuture<'a, Result<CacheHealthStatus>>>,
+    storage: Option<BoxFuture<'a, Result<StorageHealthStatus>>>,
+    messaging: Option<BoxFuture<'a, Result<MessagingHealthStatus>>>,
+}
+
+/// Why a single health check did not produce a status
+struct CheckFailure {
+    latency: Duration,
+    error: String,
+}
+
+impl<'a> HealthChecks<'a> {
+    /// Create an empty set of checks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check the database
+    pub fn database(
+        mut self,
+        check: impl Future<Output = Result<HealthStatus>> + Send + 'a,
+    ) -> Self {
+        self.database = Some(check.boxed());
+        self
+    }
+
+    /// Check the cache
+    pub fn cache(
+        mut self,
+        check: impl Future<Output = Result<CacheHealthStatus>> + Send + 'a,
+    ) -> Self {
+        self.cache = Some(check.boxed());
+        self
+    }
+
+    /// Check object storage
+    pub fn storage(
+        mut self,
+        check: impl Future<Output = Result<StorageHealthStatus>> + Send + 'a,
+    ) -> Self {
+        self.storage = Some(check.boxed());
+        self
+    }
+
+    /// Check messaging
+    pub fn messaging(
+        mut self,
+        check: impl Future<Output = Result<MessagingHealthStatus>> + Send + 'a,
+    ) -> Self {
+        self.messaging = Some(check.boxed());
+        self
+    }
+
+    /// Run every check concurrently, each limited to `timeout`
+    ///
+    /// A dependency whose check times out is reported unhealthy with the
+    /// error `"timeout"`; one whose check fails carries the failure's message.
+    pub async fn check_all(self, timeout: Duration) -> InfrastructureHealth {
+        let (database, cache, storage, messaging) = tokio::join!(
+            run_check(self.database, timeout),
+            run_check(self.cache, timeout),
+            run_check(self.storage, timeout),
+            run_check(self.messaging, timeout),
+        );
+
+        let mut health = InfrastructureHealth::new();
+        if let Some(status) = database {
+            health = health.with_database(status.unwrap_or_else(|failure| HealthStatus {
+                healthy: false,
+                latency: failure.latency,
+                pool_size: 0,
+                idle_connections: 0,
+                error: Some(failure.error),
+            }));
+        }
+        if let Some(status) = cache {
+            health = health.with_cache(status.unwrap_or_else(|failure| CacheHealthStatus {
+                healthy: false,
+                latency: failure.latency,
+                error: Some(failure.error),
+            }));
+        }
+        if let Some(status) = storage {
+            health = health.with_storage(status.unwrap_or_else(|failure| StorageHealthStatus {
+                healthy: false,
+                latency: failure.latency,
+                error: Some(failure.error),
+            }));
+        }
+        if let Some(status) = messaging {
+            health = health.with_messaging(status.unwrap_or_else(|failure| {
+                MessagingHealthStatus {
+                    healthy: false,
+                    latency: failure.latency,
+                    error: Some(failure.error),
+                }
+            }));
+        }
+        health
+    }
+}
+
+/// Run one optional check under `timeout`
+async fn run_check<T>(
+    check: Option<BoxFuture<'_, Result<T>>>,
+    timeout: Duration,
+) -> Option<std::result::Result<T, CheckFailure>> {
+    let check = check?;
+    let start = Instant::now();
+    let outcome = match tokio::time::timeout(timeout, check).await {
+        Ok(Ok(status)) => return Some(Ok(status)),
+        Ok(Err(e)) => e.to_string(),
+        Err(_) => "timeout".to_string(),
+    };
+    Some(Err(CheckFailure {
+        latency: start.elapsed(),
+        error: outcome,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +467,49 @@ mod tests {
         });
         assert!(!health.healthy);
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_check_all_times_out_slow_checks() {
+        let started = Instant::now();
+        let health = HealthChecks::new()
+            .cache(async {
+                Ok(CacheHealthStatus {
+                    healthy: true,
+                    latency: Duration::from_millis(1),
+                    error: None,
+                })
+            })
+            .storage(async {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(StorageHealthStatus {
+                    healthy: true,
+                    latency: Duration::from_secs(60),
+                    error: None,
+                })
+            })
+            .messaging(async { Err(Error::Connection("refused".to_string())) })
+            .check_all(Duration::from_millis(500))
+            .await;
+
+        assert_eq!(started.elapsed(), Duration::from_millis(500));
+        assert!(!health.healthy);
+        assert!(health.database.is_none());
+        assert!(health.cache.unwrap().healthy);
+
+        let storage = health.storage.unwrap();
+        assert!(!storage.healthy);
+        assert_eq!(storage.error.as_deref(), Some("timeout"));
+        assert_eq!(storage.latency, Duration::from_millis(500));
+
+        let messaging = health.messaging.unwrap();
+        assert!(!messaging.healthy);
+        assert_eq!(messaging.error.as_deref(), Some("Connection error: refused"));
+    }
+
+    #[tokio::test]
+    async fn test_check_all_without_checks_is_healthy() {
+        let health = HealthChecks::new().check_all(Duration::from_secs(1)).await;
+        assert!(health.healthy);
+        assert!(health.cache.is_none());
+    }
 }
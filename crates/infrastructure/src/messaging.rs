@@ -107,6 +107,14 @@ pub trait Publisher: Send + Sync {
         channels: &[&str],
         message: &EventMessage<T>,
     ) -> Result<()>;
+
+    /// Publish a message and keep it as the channel's retained message,
+    /// so subscribers arriving later can read the latest state.
+    async fn publish_retained<T: Serialize + Send + Sync>(
+        &self,
+        channel: &str,
+        message: &EventMessage<T>,
+    ) -> Result<()>;
 }
 
 /// Message subscriber trait.
@@ -126,6 +134,13 @@ pub trait Subscriber: Send + Sync {
 
     /// Unsubscribe from a channel.
     async fn unsubscribe(&self, channel: &str) -> Result<()>;
+
+    /// Get the message last published on a channel with
+    /// [`Publisher::publish_retained`], if it hasn't expired.
+    async fn retained<T: DeserializeOwned + Send + 'static>(
+        &self,
+        channel: &str,
+    ) -> Result<Option<EventMessage<T>>>;
 }
 
 /// Redis-based messaging implementation.
@@ -167,6 +182,11 @@ impl RedisMessaging {
         format!("{}{}", self.config.channel_prefix, channel)
     }
 
+    /// Key holding a channel's retained message.
+    fn retained_key(&self, channel: &str) -> String {
+        format!("{}:retained", self.full_channel(channel))
+    }
+
     /// Check messaging health.
     #[instrument(skip(self))]
     pub async fn health_check(&self) -> Result<MessagingHealthStatus> {
@@ -382,6 +402,28 @@ impl Publisher for RedisMessaging {
         }
         Ok(())
     }
+
+    #[instrument(skip(self, message))]
+    async fn publish_retained<T: Serialize + Send + Sync>(
+        &self,
+        channel: &str,
+        message: &EventMessage<T>,
+    ) -> Result<()> {
+        let serialized = serde_json::to_string(message).map_err(Error::Serialization)?;
+
+        // Store before publishing so a subscriber that sees the message can
+        // also read it back
+        let mut conn = self.conn();
+        conn.set_ex::<_, _, ()>(
+            self.retained_key(channel),
+            &serialized,
+            self.config.retention.as_secs(),
+        )
+        .await
+        .map_err(Error::Cache)?;
+
+        self.publish(channel, message).await
+    }
 }
 
 #[async_trait]
@@ -439,6 +481,22 @@ impl Subscriber for RedisMessaging {
         Ok(rx)
     }
 
+    #[instrument(skip(self))]
+    async fn retained<T: DeserializeOwned + Send + 'static>(
+        &self,
+        channel: &str,
+    ) -> Result<Option<EventMessage<T>>> {
+        let mut conn = self.conn();
+        let serialized: Option<String> = conn
+            .get(self.retained_key(channel))
+            .await
+            .map_err(Error::Cache)?;
+
+        serialized
+            .map(|s| serde_json::from_str(&s).map_err(Error::Serialization))
+            .transpose()
+    }
+
     #[instrument(skip(self))]
     async fn psubscribe<T: DeserializeOwned + Send + 'static>(
         &self,
@@ -570,4 +628,21 @@ mod tests {
         let full = format!("{}{}", prefix, channel);
         assert_eq!(full, "test:events:benchmark.created");
     }
+
+    #[tokio::test]
+    #[ignore = "requires Redis"]
+    async fn test_retained_message() {
+        let messaging = RedisMessaging::new(MessagingConfig::default()).await.unwrap();
+        let channel = format!("test.retained.{}", uuid::Uuid::new_v4());
+
+        assert!(messaging.retained::<String>(&channel).await.unwrap().is_none());
+
+        for payload in ["first", "second"] {
+            let message = EventMessage::new("test_event", payload.to_string(), "test_source");
+            messaging.publish_retained(&channel, &message).await.unwrap();
+        }
+
+        let retained = messaging.retained::<String>(&channel).await.unwrap().unwrap();
+        assert_eq!(retained.payload, "second");
+    }
 }
@@ -0,0 +1,280 @@
+//! API key repository implementation.
+//!
+//! PostgreSQL-backed persistence for personal and organization API keys.
+//! Secrets are never stored; keys are looked up by the SHA-256 of their secret.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use llm_benchmark_common::crypto::ChecksumVerifier;
+use llm_benchmark_common::pagination::{PaginatedResult, PaginationParams};
+use llm_benchmark_domain::identifiers::{OrganizationId, UserId};
+
+use crate::{Error, Result};
+
+/// Who an API key acts as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyOwner {
+    /// Personal key acting as the user.
+    User(UserId),
+    /// Service key acting on behalf of the organization.
+    Organization(OrganizationId),
+}
+
+impl ApiKeyOwner {
+    fn user_id(&self) -> Option<Uuid> {
+        match self {
+            Self::User(id) => Some(*id.as_uuid()),
+            Self::Organization(_) => None,
+        }
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        match self {
+            Self::User(_) => None,
+            Self::Organization(id) => Some(*id.as_uuid()),
+        }
+    }
+}
+
+/// A stored API key, without its secret.
+#[derive(Debug, Clone)]
+pub struct ApiKeyRecord {
+    pub id: Uuid,
+    pub owner: ApiKeyOwner,
+    pub name: String,
+    pub description: Option<String>,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Query parameters for listing an owner's keys.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyQuery {
+    pub include_expired: bool,
+    pub include_revoked: bool,
+    pub pagination: PaginationParams,
+}
+
+/// Repository trait for API key operations.
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    /// Store a new key, indexed by the hash of `secret`.
+    async fn create(&self, key: &ApiKeyRecord, secret: &str) -> Result<()>;
+
+    /// List an owner's keys, newest first.
+    async fn list(&self, owner: ApiKeyOwner, query: ApiKeyQuery) -> Result<PaginatedResult<ApiKeyRecord>>;
+
+    /// Revoke one of an owner's keys, keeping the original revocation time
+    /// if it was already revoked. Returns `false` if the owner has no such key.
+    async fn revoke(&self, owner: ApiKeyOwner, id: Uuid) -> Result<bool>;
+
+    /// Find the key a secret belongs to, whether or not it is still usable.
+    async fn find_by_secret(&self, secret: &str) -> Result<Option<ApiKeyRecord>>;
+
+    /// Record that a key was used at `used_at`.
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<()>;
+}
+
+/// PostgreSQL implementation of ApiKeyRepository.
+pub struct PgApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PgApiKeyRepository {
+    /// Create a new PostgreSQL API key repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Convert a database row to an ApiKeyRecord.
+    fn row_to_key(row: &sqlx::postgres::PgRow) -> Result<ApiKeyRecord> {
+        let owner = match (
+            row.get::<Option<Uuid>, _>("user_id"),
+            row.get::<Option<Uuid>, _>("organization_id"),
+        ) {
+            (Some(user_id), None) => ApiKeyOwner::User(UserId::from(user_id)),
+            (None, Some(org_id)) => ApiKeyOwner::Organization(OrganizationId::from(org_id)),
+            _ => {
+                return Err(Error::Configuration(
+                    "API key must belong to exactly one user or organization".to_string(),
+                ))
+            }
+        };
+
+        Ok(ApiKeyRecord {
+            id: row.get("id"),
+            owner,
+            name: row.get("name"),
+            description: row.get("description"),
+            scopes: row.get("scopes"),
+            last_used_at: row.get("last_used_at"),
+            expires_at: row.get("expires_at"),
+            revoked_at: row.get("revoked_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+/// Hex SHA-256 of a key secret, as stored in `api_keys.key_hash`.
+fn key_hash(secret: &str) -> String {
+    ChecksumVerifier::Sha256.compute(secret.as_bytes())
+}
+
+#[async_trait]
+impl ApiKeyRepository for PgApiKeyRepository {
+    #[instrument(skip(self, key, secret), fields(key_id = %key.id))]
+    async fn create(&self, key: &ApiKeyRecord, secret: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO api_keys (
+                id, user_id, organization_id, name, description, scopes,
+                key_hash, expires_at, created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(key.id)
+        .bind(key.owner.user_id())
+        .bind(key.owner.organization_id())
+        .bind(&key.name)
+        .bind(&key.description)
+        .bind(&key.scopes)
+        .bind(key_hash(secret))
+        .bind(key.expires_at)
+        .bind(key.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        debug!(key_id = %key.id, "API key created");
+        Ok(())
+    }
+
+    #[instrument(skip(self, query))]
+    async fn list(&self, owner: ApiKeyOwner, query: ApiKeyQuery) -> Result<PaginatedResult<ApiKeyRecord>> {
+        let (limit, offset) = super::sql_bounds(&query.pagination);
+        let filter = r#"
+            (user_id = $1 OR organization_id = $2)
+              AND ($3 OR expires_at IS NULL OR expires_at > NOW())
+              AND ($4 OR revoked_at IS NULL)
+        "#;
+
+        let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM api_keys WHERE {}", filter))
+            .bind(owner.user_id())
+            .bind(owner.organization_id())
+            .bind(query.include_expired)
+            .bind(query.include_revoked)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT
+                id, user_id, organization_id, name, description, scopes,
+                last_used_at, expires_at, revoked_at, created_at
+            FROM api_keys
+            WHERE {}
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5 OFFSET $6
+            "#,
+            filter
+        ))
+        .bind(owner.user_id())
+        .bind(owner.organization_id())
+        .bind(query.include_expired)
+        .bind(query.include_revoked)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        let items = rows.iter().map(Self::row_to_key).collect::<Result<Vec<_>>>()?;
+
+        Ok(PaginatedResult::new(
+            items,
+            query.pagination.page,
+            query.pagination.per_page,
+            total as u64,
+        ))
+    }
+
+    #[instrument(skip(self))]
+    async fn revoke(&self, owner: ApiKeyOwner, id: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET revoked_at = COALESCE(revoked_at, NOW())
+            WHERE id = $1 AND (user_id = $2 OR organization_id = $3)
+            "#,
+        )
+        .bind(id)
+        .bind(owner.user_id())
+        .bind(owner.organization_id())
+        .execute(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self, secret))]
+    async fn find_by_secret(&self, secret: &str) -> Result<Option<ApiKeyRecord>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                id, user_id, organization_id, name, description, scopes,
+                last_used_at, expires_at, revoked_at, created_at
+            FROM api_keys
+            WHERE key_hash = $1
+            "#,
+        )
+        .bind(key_hash(secret))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        row.as_ref().map(Self::row_to_key).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query("UPDATE api_keys SET last_used_at = GREATEST(last_used_at, $2) WHERE id = $1")
+            .bind(id)
+            .bind(used_at)
+            .execute(&self.pool)
+            .await
+            .map_err(Error::Database)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_hash_is_hex_sha256() {
+        let hash = key_hash("llm_bm_secret");
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, key_hash("llm_bm_secret"));
+        assert_ne!(hash, key_hash("llm_bm_other"));
+    }
+
+    #[test]
+    fn test_owner_columns() {
+        let user = ApiKeyOwner::User(UserId::new());
+        assert!(user.user_id().is_some() && user.organization_id().is_none());
+
+        let org = ApiKeyOwner::Organization(OrganizationId::new());
+        assert!(org.user_id().is_none() && org.organization_id().is_some());
+    }
+}
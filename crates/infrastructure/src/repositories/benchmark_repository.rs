@@ -12,7 +12,7 @@ use llm_benchmark_common::pagination::{PaginatedResult, PaginationParams, SortDi
 use llm_benchmark_domain::{
     benchmark::{BenchmarkCategory, BenchmarkMetadata, BenchmarkStatus, LicenseType},
     evaluation::{EvaluationCriteria, ExecutionConfig},
-    identifiers::{BenchmarkId, BenchmarkVersionId, UserId},
+    identifiers::{generate_id, BenchmarkId, BenchmarkVersionId, UserId},
     test_case::TestCase,
     version::SemanticVersion,
 };
@@ -51,6 +51,9 @@ pub struct BenchmarkRecord {
     pub evaluation_criteria: EvaluationCriteria,
     pub execution_config: ExecutionConfig,
     pub test_cases: Vec<TestCase>,
+    /// Submissions to any version; maintained by the submission repository
+    /// and ignored when creating or updating the benchmark.
+    pub submission_count: u64,
 }
 
 /// Version summary for benchmark history.
@@ -63,6 +66,19 @@ pub struct BenchmarkVersionSummary {
     pub breaking_changes: bool,
 }
 
+/// Row counts from upserting a version's test cases.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TestCaseUpsertSummary {
+    /// Cases that did not exist in the version
+    pub inserted: u64,
+    /// Existing cases whose content changed
+    pub updated: u64,
+    /// Existing cases left untouched
+    pub unchanged: u64,
+    /// Cases removed because they are no longer in the set
+    pub deleted: u64,
+}
+
 /// Repository trait for benchmark operations.
 #[async_trait]
 pub trait BenchmarkRepository: Send + Sync {
@@ -107,6 +123,24 @@ pub trait BenchmarkRepository: Send + Sync {
 
     /// Count total benchmarks matching optional filters.
     async fn count(&self, status: Option<BenchmarkStatus>, category: Option<BenchmarkCategory>) -> Result<u64>;
+
+    /// Atomically add one to the stored submission count, returning the new count.
+    async fn increment_submission_count(&self, id: BenchmarkId) -> Result<u64>;
+
+    /// Atomically subtract one from the stored submission count, returning the new count.
+    ///
+    /// The count never goes below zero.
+    async fn decrement_submission_count(&self, id: BenchmarkId) -> Result<u64>;
+
+    /// Replace a version's test cases in place, keyed by case ID.
+    ///
+    /// Unchanged cases are not rewritten, modified ones are updated in place,
+    /// new ones are inserted and cases missing from `test_cases` are deleted.
+    async fn upsert_test_cases(
+        &self,
+        version_id: BenchmarkVersionId,
+        test_cases: &[TestCase],
+    ) -> Result<TestCaseUpsertSummary>;
 }
 
 /// PostgreSQL implementation of BenchmarkRepository.
@@ -158,6 +192,7 @@ impl PgBenchmarkRepository {
             execution_config: serde_json::from_value(execution_config_json)
                 .map_err(Error::Serialization)?,
             test_cases,
+            submission_count: row.get::<i64, _>("submission_count") as u64,
         })
     }
 
@@ -219,7 +254,7 @@ impl PgBenchmarkRepository {
                 ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
                 "#,
             )
-            .bind(Uuid::now_v7())
+            .bind(generate_id())
             .bind(version_id)
             .bind(&test_case.id)
             .bind(&test_case.name)
@@ -282,9 +317,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
         sqlx::query(
             r#"
             INSERT INTO benchmarks (
-                id, slug, name, description, long_description, category, status,
-                license, created_by, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                id, slug, name, description, long_description, category, custom_category,
+                status, license, created_by, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6::benchmark_category, $7, $8, $9, $10, $11, $12)
             "#,
         )
         .bind(id.as_uuid())
@@ -292,7 +327,8 @@ impl BenchmarkRepository for PgBenchmarkRepository {
         .bind(&benchmark.name)
         .bind(&benchmark.description)
         .bind(&benchmark.long_description)
-        .bind(category_to_str(&benchmark.category))
+        .bind(stored_category(&benchmark.category))
+        .bind(custom_category_name(&benchmark.category))
         .bind(status_to_str(&benchmark.status))
         .bind(serde_json::to_value(&benchmark.license).map_err(Error::Serialization)?)
         .bind(benchmark.created_by.as_uuid())
@@ -346,7 +382,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -381,7 +419,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -416,7 +456,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -443,15 +485,14 @@ impl BenchmarkRepository for PgBenchmarkRepository {
 
     #[instrument(skip(self, query))]
     async fn list(&self, query: BenchmarkQuery) -> Result<PaginatedResult<BenchmarkRecord>> {
-        let offset = query.pagination.offset() as i64;
-        let limit = query.pagination.limit() as i64;
+        let (limit, offset) = super::sql_bounds(&query.pagination);
 
         // Build dynamic WHERE clause
         let mut conditions = vec!["1=1".to_string()];
         let mut bind_values: Vec<Box<dyn sqlx::Encode<'_, sqlx::Postgres> + Send + Sync>> = Vec::new();
 
         if let Some(ref category) = query.category {
-            conditions.push(format!("b.category = ${}", conditions.len() + 1));
+            conditions.push(format!("{} = ${}", CATEGORY_KEY, conditions.len() + 1));
         }
         if let Some(ref status) = query.status {
             conditions.push(format!("b.status = ${}", conditions.len() + 1));
@@ -504,7 +545,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -707,7 +750,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -778,7 +823,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -792,7 +839,8 @@ impl BenchmarkRepository for PgBenchmarkRepository {
                 ORDER BY created_at DESC
                 LIMIT 1
             ) bv ON true
-            WHERE b.category = $1 AND b.status = 'active'
+            WHERE COALESCE('custom:' || b.custom_category, b.category::text) = $1
+              AND b.status = 'active'
             ORDER BY b.created_at DESC
             LIMIT $2
             "#,
@@ -817,7 +865,9 @@ impl BenchmarkRepository for PgBenchmarkRepository {
             r#"
             SELECT
                 b.id, b.slug, b.name, b.description, b.long_description,
-                b.category, b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                COALESCE('custom:' || b.custom_category, b.category::text) AS category,
+                b.status, b.license, b.created_by, b.created_at, b.updated_at,
+                b.submission_count,
                 bv.id as version_id, bv.version_major, bv.version_minor, bv.version_patch,
                 bv.evaluation_criteria, bv.execution_config,
                 COALESCE(
@@ -859,9 +909,11 @@ impl BenchmarkRepository for PgBenchmarkRepository {
     ) -> Result<u64> {
         let (query, count) = match (status, category) {
             (Some(s), Some(c)) => {
-                let count: i64 = sqlx::query_scalar(
-                    "SELECT COUNT(*) FROM benchmarks WHERE status = $1 AND category = $2",
-                )
+                let sql = format!(
+                    "SELECT COUNT(*) FROM benchmarks b WHERE b.status = $1 AND {} = $2",
+                    CATEGORY_KEY
+                );
+                let count: i64 = sqlx::query_scalar(&sql)
                 .bind(status_to_str(&s))
                 .bind(category_to_str(&c))
                 .fetch_one(&self.pool)
@@ -879,12 +931,12 @@ impl BenchmarkRepository for PgBenchmarkRepository {
                 ("status", count)
             }
             (None, Some(c)) => {
-                let count: i64 =
-                    sqlx::query_scalar("SELECT COUNT(*) FROM benchmarks WHERE category = $1")
-                        .bind(category_to_str(&c))
-                        .fetch_one(&self.pool)
-                        .await
-                        .map_err(Error::Database)?;
+                let sql = format!("SELECT COUNT(*) FROM benchmarks b WHERE {} = $1", CATEGORY_KEY);
+                let count: i64 = sqlx::query_scalar(&sql)
+                    .bind(category_to_str(&c))
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(Error::Database)?;
                 ("category", count)
             }
             (None, None) => {
@@ -898,12 +950,170 @@ impl BenchmarkRepository for PgBenchmarkRepository {
 
         Ok(count as u64)
     }
+
+    #[instrument(skip(self))]
+    async fn increment_submission_count(&self, id: BenchmarkId) -> Result<u64> {
+        adjust_submission_count(&self.pool, id, 1).await
+    }
+
+    #[instrument(skip(self))]
+    async fn decrement_submission_count(&self, id: BenchmarkId) -> Result<u64> {
+        adjust_submission_count(&self.pool, id, -1).await
+    }
+
+    #[instrument(skip(self, test_cases), fields(count = test_cases.len()))]
+    async fn upsert_test_cases(
+        &self,
+        version_id: BenchmarkVersionId,
+        test_cases: &[TestCase],
+    ) -> Result<TestCaseUpsertSummary> {
+        let now = Utc::now();
+        let mut summary = TestCaseUpsertSummary::default();
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+
+        for test_case in test_cases {
+            // The WHERE clause skips the update, and so returns no row, when
+            // nothing changed; xmax is 0 only for freshly inserted rows
+            let inserted: Option<bool> = sqlx::query_scalar(
+                r#"
+                INSERT INTO test_cases (
+                    id, benchmark_version_id, case_id, name, description,
+                    input, expected_output, evaluation_method, weight, tags,
+                    updated_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                ON CONFLICT ON CONSTRAINT test_cases_unique_id DO UPDATE SET
+                    name = EXCLUDED.name,
+                    description = EXCLUDED.description,
+                    input = EXCLUDED.input,
+                    expected_output = EXCLUDED.expected_output,
+                    evaluation_method = EXCLUDED.evaluation_method,
+                    weight = EXCLUDED.weight,
+                    tags = EXCLUDED.tags,
+                    updated_at = EXCLUDED.updated_at
+                WHERE (
+                    test_cases.name, test_cases.description, test_cases.input,
+                    test_cases.expected_output, test_cases.evaluation_method,
+                    test_cases.weight, test_cases.tags
+                ) IS DISTINCT FROM (
+                    EXCLUDED.name, EXCLUDED.description, EXCLUDED.input,
+                    EXCLUDED.expected_output, EXCLUDED.evaluation_method,
+                    EXCLUDED.weight, EXCLUDED.tags
+                )
+                RETURNING (xmax = 0)
+                "#,
+            )
+            .bind(generate_id())
+            .bind(version_id.as_uuid())
+            .bind(&test_case.id)
+            .bind(&test_case.name)
+            .bind(&test_case.description)
+            .bind(serde_json::to_value(&test_case.input).map_err(Error::Serialization)?)
+            .bind(
+                test_case
+                    .expected_output
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .map_err(Error::Serialization)?,
+            )
+            .bind(serde_json::to_value(&test_case.evaluation_method).map_err(Error::Serialization)?)
+            .bind(test_case.weight)
+            .bind(&test_case.tags)
+            .bind(now)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(Error::Database)?;
+
+            match inserted {
+                Some(true) => summary.inserted += 1,
+                Some(false) => summary.updated += 1,
+                None => summary.unchanged += 1,
+            }
+        }
+
+        let case_ids: Vec<&str> = test_cases.iter().map(|tc| tc.id.as_str()).collect();
+        summary.deleted = sqlx::query(
+            r#"
+            DELETE FROM test_cases
+            WHERE benchmark_version_id = $1 AND case_id <> ALL($2)
+            "#,
+        )
+        .bind(version_id.as_uuid())
+        .bind(&case_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(Error::Database)?
+        .rows_affected();
+
+        tx.commit().await.map_err(Error::Database)?;
+
+        debug!(
+            version_id = %version_id,
+            inserted = summary.inserted,
+            updated = summary.updated,
+            unchanged = summary.unchanged,
+            deleted = summary.deleted,
+            "Test cases upserted"
+        );
+        Ok(summary)
+    }
 }
 
 // Helper functions for converting between domain types and database strings
 
-fn category_to_str(category: &BenchmarkCategory) -> &'static str {
+/// Prefix marking a custom category in the category key, e.g. `custom:code_review`
+const CUSTOM_CATEGORY_PREFIX: &str = "custom:";
+
+/// SQL expression for a stored category's key, as produced by `category_to_str`.
+///
+/// Custom categories are stored as the `custom` enum value with the name in
+/// `custom_category`; built-in ones are the enum value alone.
+const CATEGORY_KEY: &str = "COALESCE('custom:' || b.custom_category, b.category::text)";
+
+/// Add `delta` to a benchmark's stored submission count.
+///
+/// A single relative `UPDATE` takes the row lock, so concurrent adjustments
+/// serialize instead of overwriting each other. Generic over the executor so
+/// the submission repository can run it inside its own transaction.
+pub(crate) async fn adjust_submission_count<'e, E>(
+    executor: E,
+    id: BenchmarkId,
+    delta: i64,
+) -> Result<u64>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let count: Option<i64> = sqlx::query_scalar(
+        r#"
+        UPDATE benchmarks
+        SET submission_count = GREATEST(submission_count + $2, 0)
+        WHERE id = $1
+        RETURNING submission_count
+        "#,
+    )
+    .bind(id.as_uuid())
+    .bind(delta)
+    .fetch_optional(executor)
+    .await
+    .map_err(Error::Database)?;
+
+    count
+        .map(|count| count as u64)
+        .ok_or_else(|| Error::NotFound(format!("Benchmark {}", id)))
+}
+
+/// Key identifying a category in filters, matching `CATEGORY_KEY`
+fn category_to_str(category: &BenchmarkCategory) -> String {
     match category {
+        BenchmarkCategory::Custom(name) => format!("{}{}", CUSTOM_CATEGORY_PREFIX, name),
+        builtin => stored_category(builtin).to_string(),
+    }
+}
+
+/// Value stored in the `category` enum column
+fn stored_category(category: &BenchmarkCategory) -> &'static str {
+    match category {
+        BenchmarkCategory::Custom(_) => "custom",
         BenchmarkCategory::Performance => "performance",
         BenchmarkCategory::Accuracy => "accuracy",
         BenchmarkCategory::Reliability => "reliability",
@@ -913,7 +1123,20 @@ fn category_to_str(category: &BenchmarkCategory) -> &'static str {
     }
 }
 
+/// Value stored in the `custom_category` column
+fn custom_category_name(category: &BenchmarkCategory) -> Option<&str> {
+    match category {
+        BenchmarkCategory::Custom(name) => Some(name),
+        _ => None,
+    }
+}
+
 fn parse_category(s: &str) -> Result<BenchmarkCategory> {
+    if let Some(name) = s.strip_prefix(CUSTOM_CATEGORY_PREFIX) {
+        return BenchmarkCategory::custom(name)
+            .map_err(|e| Error::Configuration(format!("Invalid category '{}': {}", s, e)));
+    }
+
     match s.to_lowercase().as_str() {
         "performance" => Ok(BenchmarkCategory::Performance),
         "accuracy" => Ok(BenchmarkCategory::Accuracy),
@@ -957,6 +1180,24 @@ mod tests {
         assert!(parse_category("invalid").is_err());
     }
 
+    #[test]
+    fn test_custom_category_conversion() {
+        let category = BenchmarkCategory::custom("code_review").unwrap();
+        let stored = category_to_str(&category);
+        assert_eq!(stored, "custom:code_review");
+        assert_eq!(stored_category(&category), "custom");
+        assert_eq!(custom_category_name(&category), Some("code_review"));
+        assert_eq!(custom_category_name(&BenchmarkCategory::Cost), None);
+        assert_eq!(parse_category(&stored).unwrap(), category);
+
+        for category in BenchmarkCategory::all() {
+            assert_eq!(&parse_category(&category_to_str(category)).unwrap(), category);
+        }
+
+        assert!(parse_category("custom:").is_err());
+        assert!(parse_category("custom:Not Valid").is_err());
+    }
+
     #[test]
     fn test_status_conversion() {
         assert_eq!(status_to_str(&BenchmarkStatus::Active), "active");
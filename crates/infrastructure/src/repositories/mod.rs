@@ -7,14 +7,34 @@ mod benchmark_repository;
 mod submission_repository;
 mod user_repository;
 mod organization_repository;
+mod proposal_repository;
+mod api_key_repository;
 
 pub use benchmark_repository::*;
 pub use submission_repository::*;
 pub use user_repository::*;
 pub use organization_repository::*;
+pub use proposal_repository::*;
+pub use api_key_repository::*;
 
 use async_trait::async_trait;
 use llm_benchmark_common::pagination::{PaginatedResult, PaginationParams, SortParams};
+use tracing::warn;
+
+/// `LIMIT` and `OFFSET` for a page, logging when the request was clamped.
+pub(crate) fn sql_bounds(pagination: &PaginationParams) -> (i64, i64) {
+    let bounds = pagination.to_sql_bounds();
+    if bounds.clamped {
+        warn!(
+            page = pagination.page,
+            per_page = pagination.per_page,
+            limit = bounds.limit,
+            offset = bounds.offset,
+            "Pagination clamped to SQL bounds"
+        );
+    }
+    (bounds.limit, bounds.offset)
+}
 
 /// Common repository trait for CRUD operations.
 #[async_trait]
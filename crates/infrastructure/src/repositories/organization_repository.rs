@@ -224,8 +224,7 @@ impl OrganizationRepository for PgOrganizationRepository {
 
     #[instrument(skip(self, query))]
     async fn list(&self, query: OrganizationQuery) -> Result<PaginatedResult<Organization>> {
-        let offset = query.pagination.offset() as i64;
-        let limit = query.pagination.limit() as i64;
+        let (limit, offset) = super::sql_bounds(&query.pagination);
 
         // Build dynamic WHERE clause
         let mut conditions = vec!["deleted_at IS NULL".to_string()];
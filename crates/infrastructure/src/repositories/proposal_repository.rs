@@ -0,0 +1,540 @@
+//! Proposal repository implementation.
+//!
+//! PostgreSQL-backed persistence for governance proposals, their votes and
+//! their discussion comments.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use llm_benchmark_domain::{
+    governance::{
+        Ballot, Comment, LineReference, Proposal, ProposalStatus, ProposalType, Review,
+        ReviewComment, ReviewStatus, Vote, VotingEligibility, VotingState,
+    },
+    identifiers::{BenchmarkId, CommentId, ProposalId, UserId},
+};
+
+use super::user_repository::{parse_role, role_to_str};
+use crate::{Error, Result};
+
+/// Repository trait for governance proposal operations.
+#[async_trait]
+pub trait ProposalRepository: Send + Sync {
+    /// Get a proposal with its votes and reviews.
+    async fn get_by_id(&self, id: ProposalId) -> Result<Option<Proposal>>;
+
+    /// Update a proposal's details, status and voting window, and record
+    /// any votes not stored yet.
+    ///
+    /// `expected_status` is the status the proposal was loaded with; if the
+    /// stored status has changed since, nothing is written and `Conflict` is
+    /// returned. Moving a proposal out of voting also fails with `Conflict`
+    /// when votes were recorded that its ballots don't include.
+    ///
+    /// Vote totals are maintained by the database from the recorded votes.
+    async fn update(&self, proposal: &Proposal, expected_status: ProposalStatus) -> Result<()>;
+
+    /// Add a discussion comment to a proposal.
+    async fn add_comment(&self, comment: &Comment) -> Result<()>;
+
+    /// Get a discussion comment by its ID.
+    async fn get_comment(&self, id: CommentId) -> Result<Option<Comment>>;
+
+    /// List a proposal's discussion comments, oldest first.
+    async fn list_comments(&self, proposal_id: ProposalId) -> Result<Vec<Comment>>;
+
+    /// List the proposals, votes and comments a user made, oldest first.
+    async fn user_activity(&self, user_id: UserId) -> Result<Vec<GovernanceActivity>>;
+}
+
+/// Kind of governance action a user took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GovernanceActivityKind {
+    ProposalCreated,
+    VoteCast,
+    CommentAdded,
+}
+
+/// A governance action taken by a user.
+#[derive(Debug, Clone)]
+pub struct GovernanceActivity {
+    pub kind: GovernanceActivityKind,
+    pub proposal_id: ProposalId,
+    /// Vote choice or comment body, depending on the kind
+    pub detail: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+/// PostgreSQL implementation of ProposalRepository.
+pub struct PgProposalRepository {
+    pool: PgPool,
+}
+
+impl PgProposalRepository {
+    /// Create a new PostgreSQL proposal repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Votes on a proposal in the order they were cast.
+    async fn get_ballots(&self, proposal_id: Uuid) -> Result<Vec<Ballot>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT user_id, vote::text AS vote, voter_role::text AS voter_role, voted_at
+            FROM votes
+            WHERE proposal_id = $1
+            ORDER BY voted_at, user_id
+            "#,
+        )
+        .bind(proposal_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        rows.iter()
+            .map(|row| {
+                let role = match row.get::<Option<String>, _>("voter_role") {
+                    Some(role) => parse_role(&role)?,
+                    // Recorded before roles were stored with votes
+                    None => VotingEligibility::default().minimum_role,
+                };
+                Ok(Ballot {
+                    voter: UserId::from(row.get::<Uuid, _>("user_id")),
+                    role,
+                    vote: parse_vote(row.get("vote"))?,
+                    cast_at: row.get("voted_at"),
+                })
+            })
+            .collect()
+    }
+
+    /// Reviews of a proposal with their comments.
+    async fn get_reviews(&self, proposal_id: Uuid) -> Result<Vec<Review>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                r.id, r.reviewer_id, r.decision,
+                COALESCE(r.submitted_at, r.created_at) AS submitted_at,
+                c.content, c.file_path, c.line_start, c.line_end
+            FROM reviews r
+            LEFT JOIN review_comments c ON c.review_id = r.id
+            WHERE r.proposal_id = $1
+            ORDER BY r.created_at, r.id, c.created_at
+            "#,
+        )
+        .bind(proposal_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        let mut reviews: Vec<(Uuid, Review)> = Vec::new();
+        for row in &rows {
+            let review_id: Uuid = row.get("id");
+            if reviews.last().map(|(id, _)| *id) != Some(review_id) {
+                reviews.push((
+                    review_id,
+                    Review {
+                        reviewer_id: UserId::from(row.get::<Uuid, _>("reviewer_id")),
+                        status: parse_review_decision(row.get("decision"))?,
+                        comments: Vec::new(),
+                        submitted_at: row.get("submitted_at"),
+                    },
+                ));
+            }
+
+            let Some(content) = row.get::<Option<String>, _>("content") else {
+                continue;
+            };
+            let line_references = match (
+                row.get::<Option<String>, _>("file_path"),
+                row.get::<Option<i32>, _>("line_start"),
+            ) {
+                (Some(file), Some(start)) => vec![LineReference {
+                    file,
+                    start_line: start as u32,
+                    end_line: row.get::<Option<i32>, _>("line_end").map(|l| l as u32),
+                }],
+                _ => Vec::new(),
+            };
+            if let Some((_, review)) = reviews.last_mut() {
+                review.comments.push(ReviewComment {
+                    content,
+                    line_references,
+                });
+            }
+        }
+
+        Ok(reviews.into_iter().map(|(_, review)| review).collect())
+    }
+
+    fn row_to_comment(row: &sqlx::postgres::PgRow) -> Comment {
+        Comment {
+            id: CommentId::from(row.get::<Uuid, _>("id")),
+            proposal_id: ProposalId::from(row.get::<Uuid, _>("proposal_id")),
+            author: UserId::from(row.get::<Uuid, _>("author_id")),
+            body: row.get("body"),
+            parent_id: row.get::<Option<Uuid>, _>("parent_id").map(CommentId::from),
+            created_at: row.get("created_at"),
+        }
+    }
+}
+
+#[async_trait]
+impl ProposalRepository for PgProposalRepository {
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: ProposalId) -> Result<Option<Proposal>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                id, proposal_type::text AS proposal_type, title, description, rationale,
+                status::text AS status, benchmark_id, created_by,
+                voting_starts_at, voting_ends_at, votes_for, votes_against, votes_abstain,
+                quorum_required, approval_threshold, role_weights, created_at, updated_at
+            FROM proposals
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let ballots = self.get_ballots(*id.as_uuid()).await?;
+        let reviews = self.get_reviews(*id.as_uuid()).await?;
+        let role_weights = row
+            .get::<Option<serde_json::Value>, _>("role_weights")
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(Error::Serialization)?;
+
+        Ok(Some(Proposal {
+            id,
+            proposal_type: parse_proposal_type(row.get("proposal_type"))?,
+            title: row.get("title"),
+            description: row.get("description"),
+            created_by: UserId::from(row.get::<Uuid, _>("created_by")),
+            status: parse_proposal_status(row.get("status"))?,
+            benchmark_id: row.get::<Option<Uuid>, _>("benchmark_id").map(BenchmarkId::from),
+            rationale: row.get("rationale"),
+            voting: VotingState {
+                voting_starts: row.get("voting_starts_at"),
+                voting_ends: row.get("voting_ends_at"),
+                votes_for: row.get::<i32, _>("votes_for") as u32,
+                votes_against: row.get::<i32, _>("votes_against") as u32,
+                votes_abstain: row.get::<i32, _>("votes_abstain") as u32,
+                voters: ballots.iter().map(|b| b.voter).collect(),
+                quorum_required: row.get::<i32, _>("quorum_required") as u32,
+                approval_threshold: row.get("approval_threshold"),
+                ballots,
+                role_weights,
+            },
+            reviews,
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }))
+    }
+
+    #[instrument(skip(self, proposal), fields(proposal_id = %proposal.id))]
+    async fn update(&self, proposal: &Proposal, expected_status: ProposalStatus) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+
+        let stored: String =
+            sqlx::query_scalar("SELECT status::text FROM proposals WHERE id = $1 FOR UPDATE")
+                .bind(proposal.id.as_uuid())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(Error::Database)?
+                .ok_or_else(|| Error::NotFound(format!("Proposal not found: {}", proposal.id)))?;
+
+        // Checked under the row lock so a vote and a finalization racing on
+        // the same proposal cannot overwrite each other
+        let stored = parse_proposal_status(&stored)?;
+        if stored != expected_status {
+            return Err(Error::Conflict(format!(
+                "Proposal {} is {:?}, expected {:?}",
+                proposal.id, stored, expected_status
+            )));
+        }
+        if stored == ProposalStatus::Voting && proposal.status != ProposalStatus::Voting {
+            let recorded: i64 =
+                sqlx::query_scalar("SELECT COUNT(*) FROM votes WHERE proposal_id = $1")
+                    .bind(proposal.id.as_uuid())
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(Error::Database)?;
+            if recorded > proposal.voting.ballots.len() as i64 {
+                return Err(Error::Conflict(format!(
+                    "Proposal {} received votes after it was tallied",
+                    proposal.id
+                )));
+            }
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE proposals SET
+                title = $2,
+                description = $3,
+                rationale = $4,
+                status = $5::proposal_status,
+                benchmark_id = $6,
+                voting_starts_at = $7,
+                voting_ends_at = $8,
+                quorum_required = $9,
+                approval_threshold = $10,
+                role_weights = $11,
+                updated_at = $12
+            WHERE id = $1
+            "#,
+        )
+        .bind(proposal.id.as_uuid())
+        .bind(&proposal.title)
+        .bind(&proposal.description)
+        .bind(&proposal.rationale)
+        .bind(proposal_status_to_str(proposal.status))
+        .bind(proposal.benchmark_id.map(|id| *id.as_uuid()))
+        .bind(proposal.voting.voting_starts)
+        .bind(proposal.voting.voting_ends)
+        .bind(proposal.voting.quorum_required as i32)
+        .bind(proposal.voting.approval_threshold)
+        .bind(
+            proposal
+                .voting
+                .role_weights
+                .as_ref()
+                .map(serde_json::to_value)
+                .transpose()
+                .map_err(Error::Serialization)?,
+        )
+        .bind(proposal.updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(Error::Database)?;
+
+        // Votes are never changed once cast, so only new ones are inserted
+        for ballot in &proposal.voting.ballots {
+            sqlx::query(
+                r#"
+                INSERT INTO votes (proposal_id, user_id, vote, voter_role, voted_at)
+                VALUES ($1, $2, $3::vote_type, $4::user_role, $5)
+                ON CONFLICT (proposal_id, user_id) DO NOTHING
+                "#,
+            )
+            .bind(proposal.id.as_uuid())
+            .bind(ballot.voter.as_uuid())
+            .bind(vote_to_str(ballot.vote))
+            .bind(role_to_str(&ballot.role))
+            .bind(ballot.cast_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(Error::Database)?;
+        }
+
+        tx.commit().await.map_err(Error::Database)?;
+
+        debug!(proposal_id = %proposal.id, "Proposal updated");
+        Ok(())
+    }
+
+    #[instrument(skip(self, comment), fields(comment_id = %comment.id))]
+    async fn add_comment(&self, comment: &Comment) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO proposal_comments (id, proposal_id, author_id, body, parent_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(comment.id.as_uuid())
+        .bind(comment.proposal_id.as_uuid())
+        .bind(comment.author.as_uuid())
+        .bind(&comment.body)
+        .bind(comment.parent_id.map(|id| *id.as_uuid()))
+        .bind(comment.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_comment(&self, id: CommentId) -> Result<Option<Comment>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, proposal_id, author_id, body, parent_id, created_at
+            FROM proposal_comments
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(row.as_ref().map(Self::row_to_comment))
+    }
+
+    #[instrument(skip(self))]
+    async fn list_comments(&self, proposal_id: ProposalId) -> Result<Vec<Comment>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, proposal_id, author_id, body, parent_id, created_at
+            FROM proposal_comments
+            WHERE proposal_id = $1
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(proposal_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(rows.iter().map(Self::row_to_comment).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn user_activity(&self, user_id: UserId) -> Result<Vec<GovernanceActivity>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT 'proposal' AS kind, id AS proposal_id, NULL::text AS detail, created_at AS at
+            FROM proposals
+            WHERE created_by = $1
+            UNION ALL
+            SELECT 'vote', proposal_id, vote::text, voted_at
+            FROM votes
+            WHERE user_id = $1
+            UNION ALL
+            SELECT 'comment', proposal_id, body, created_at
+            FROM proposal_comments
+            WHERE author_id = $1
+            ORDER BY at, proposal_id
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        rows.iter()
+            .map(|row| {
+                let kind = match row.get::<&str, _>("kind") {
+                    "proposal" => GovernanceActivityKind::ProposalCreated,
+                    "vote" => GovernanceActivityKind::VoteCast,
+                    "comment" => GovernanceActivityKind::CommentAdded,
+                    other => {
+                        return Err(Error::Configuration(format!("Unknown activity: {}", other)))
+                    }
+                };
+                Ok(GovernanceActivity {
+                    kind,
+                    proposal_id: ProposalId::from(row.get::<Uuid, _>("proposal_id")),
+                    detail: row.get("detail"),
+                    at: row.get("at"),
+                })
+            })
+            .collect()
+    }
+}
+
+// Helper functions for enum conversion
+
+fn parse_proposal_type(s: &str) -> Result<ProposalType> {
+    match s {
+        "new_benchmark" => Ok(ProposalType::NewBenchmark),
+        "update_benchmark" => Ok(ProposalType::UpdateBenchmark),
+        "deprecate_benchmark" => Ok(ProposalType::DeprecateBenchmark),
+        "policy_change" => Ok(ProposalType::PolicyChange),
+        _ => Err(Error::Configuration(format!("Unknown proposal type: {}", s))),
+    }
+}
+
+fn proposal_status_to_str(status: ProposalStatus) -> &'static str {
+    match status {
+        ProposalStatus::Draft => "draft",
+        ProposalStatus::UnderReview => "under_review",
+        ProposalStatus::Voting => "voting",
+        ProposalStatus::Approved => "approved",
+        ProposalStatus::Rejected => "rejected",
+        ProposalStatus::Withdrawn => "withdrawn",
+    }
+}
+
+fn parse_proposal_status(s: &str) -> Result<ProposalStatus> {
+    match s {
+        "draft" => Ok(ProposalStatus::Draft),
+        "under_review" => Ok(ProposalStatus::UnderReview),
+        "voting" => Ok(ProposalStatus::Voting),
+        "approved" => Ok(ProposalStatus::Approved),
+        "rejected" => Ok(ProposalStatus::Rejected),
+        "withdrawn" => Ok(ProposalStatus::Withdrawn),
+        _ => Err(Error::Configuration(format!("Unknown proposal status: {}", s))),
+    }
+}
+
+fn vote_to_str(vote: Vote) -> &'static str {
+    match vote {
+        Vote::Approve => "approve",
+        Vote::Reject => "reject",
+        Vote::Abstain => "abstain",
+    }
+}
+
+fn parse_vote(s: &str) -> Result<Vote> {
+    match s {
+        "approve" => Ok(Vote::Approve),
+        "reject" => Ok(Vote::Reject),
+        "abstain" => Ok(Vote::Abstain),
+        _ => Err(Error::Configuration(format!("Unknown vote: {}", s))),
+    }
+}
+
+/// Review status from a review's decision; undecided reviews are pending
+fn parse_review_decision(decision: Option<&str>) -> Result<ReviewStatus> {
+    match decision {
+        None => Ok(ReviewStatus::Pending),
+        Some("approve") => Ok(ReviewStatus::Approved),
+        Some("request_changes") => Ok(ReviewStatus::RequestChanges),
+        Some("reject") => Ok(ReviewStatus::Rejected),
+        Some(other) => Err(Error::Configuration(format!("Unknown review decision: {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in [
+            ProposalStatus::Draft,
+            ProposalStatus::UnderReview,
+            ProposalStatus::Voting,
+            ProposalStatus::Approved,
+            ProposalStatus::Rejected,
+            ProposalStatus::Withdrawn,
+        ] {
+            assert_eq!(parse_proposal_status(proposal_status_to_str(status)).unwrap(), status);
+        }
+        assert!(parse_proposal_status("closed").is_err());
+    }
+
+    #[test]
+    fn test_vote_and_decision_parsing() {
+        for vote in [Vote::Approve, Vote::Reject, Vote::Abstain] {
+            assert_eq!(parse_vote(vote_to_str(vote)).unwrap(), vote);
+        }
+        assert_eq!(parse_review_decision(None).unwrap(), ReviewStatus::Pending);
+        assert_eq!(
+            parse_review_decision(Some("request_changes")).unwrap(),
+            ReviewStatus::RequestChanges
+        );
+        assert!(parse_review_decision(Some("maybe")).is_err());
+    }
+}
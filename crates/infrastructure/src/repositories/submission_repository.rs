@@ -5,19 +5,23 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Row};
+use std::collections::HashMap;
+use std::time::Duration;
 use tracing::{debug, instrument};
 use uuid::Uuid;
 
+use llm_benchmark_common::crypto::content_hash;
 use llm_benchmark_common::pagination::{PaginatedResult, PaginationParams, SortDirection, SortParams};
 use llm_benchmark_domain::{
     identifiers::{BenchmarkId, BenchmarkVersionId, ModelId, OrganizationId, SubmissionId, UserId, VerificationId},
     submission::{
         ConfidenceInterval, ExecutionMetadata, MetricScore, ModelInfo, StatisticalSignificance,
         Submission, SubmissionResults, SubmissionVisibility, SubmitterInfo, TestCaseResult,
-        VerificationLevel, VerificationStatus,
+        VerificationEvent, VerificationLevel, VerificationStatus,
     },
 };
 
+use super::benchmark_repository::adjust_submission_count;
 use crate::{Error, Result};
 
 /// Query parameters for submission searches.
@@ -49,6 +53,22 @@ pub struct LeaderboardEntry {
     pub organization_name: Option<String>,
 }
 
+/// An organization's best result on one benchmark.
+#[derive(Debug, Clone)]
+pub struct OrgLeaderboardEntry {
+    pub benchmark_id: BenchmarkId,
+    pub benchmark_name: String,
+    pub submission_id: SubmissionId,
+    /// Position among the benchmark's public submissions, 1 being the best
+    pub rank: u32,
+    /// Number of submissions ranked, including this one
+    pub ranked_submissions: u64,
+    pub model_info: ModelInfo,
+    pub aggregate_score: f64,
+    pub visibility: SubmissionVisibility,
+    pub submitted_at: DateTime<Utc>,
+}
+
 /// Repository trait for submission operations.
 #[async_trait]
 pub trait SubmissionRepository: Send + Sync {
@@ -75,13 +95,21 @@ pub trait SubmissionRepository: Send + Sync {
         pagination: PaginationParams,
     ) -> Result<PaginatedResult<Submission>>;
 
-    /// Update submission verification status.
+    /// Update submission verification status and record the change in the
+    /// submission's verification history.
+    ///
+    /// Fails with `Error::Conflict` when the submission has been cancelled or
+    /// `status` would lower its verification level.
     async fn update_verification(
         &self,
         id: SubmissionId,
         status: &VerificationStatus,
+        reason: Option<&str>,
     ) -> Result<()>;
 
+    /// Get the verification history of a submission, oldest first.
+    async fn verification_history(&self, id: SubmissionId) -> Result<Vec<VerificationEvent>>;
+
     /// Update submission visibility.
     async fn update_visibility(
         &self,
@@ -89,15 +117,37 @@ pub trait SubmissionRepository: Send + Sync {
         visibility: SubmissionVisibility,
     ) -> Result<()>;
 
+    /// Cancel an unverified submission, returning when it was cancelled.
+    ///
+    /// Returns `None` when the submission does not exist, has already been
+    /// cancelled, or has been verified at any level.
+    async fn cancel(&self, id: SubmissionId) -> Result<Option<DateTime<Utc>>>;
+
+    /// Replace submission results and the derived aggregate score.
+    async fn update_results(&self, id: SubmissionId, results: &SubmissionResults) -> Result<()>;
+
     /// Delete a submission.
     async fn delete(&self, id: SubmissionId) -> Result<bool>;
 
+    /// Delete every submission made by a user, returning how many were removed.
+    async fn delete_by_user(&self, user_id: UserId) -> Result<u64>;
+
+    /// Reassign every submission made by `from` to `to`, returning how many moved.
+    async fn reassign_submitter(&self, from: UserId, to: UserId) -> Result<u64>;
+
     /// Get leaderboard for a benchmark.
+    ///
+    /// Only public submissions verified at `min_verification` or above are
+    /// ranked, plus non-public ones made by `viewer_id` or on behalf of one
+    /// of `organization_ids`.
     async fn get_leaderboard(
         &self,
         benchmark_id: BenchmarkId,
         version_id: Option<BenchmarkVersionId>,
         limit: usize,
+        viewer_id: Option<UserId>,
+        organization_ids: &[OrganizationId],
+        min_verification: VerificationLevel,
     ) -> Result<Vec<LeaderboardEntry>>;
 
     /// Get the best submission for a model on a benchmark.
@@ -124,6 +174,53 @@ pub trait SubmissionRepository: Send + Sync {
         model_name: &str,
         model_version: Option<&str>,
     ) -> Result<bool>;
+
+    /// Find the earliest submission of the same model to a benchmark whose
+    /// results have the given content hash.
+    async fn find_by_content_hash(
+        &self,
+        benchmark_id: BenchmarkId,
+        model_provider: &str,
+        model_name: &str,
+        model_version: Option<&str>,
+        content_hash: &str,
+    ) -> Result<Option<SubmissionId>>;
+
+    /// Get an organization's best submission on each benchmark, with its rank
+    /// among all public submissions to that benchmark.
+    ///
+    /// Only public submissions are considered unless `viewer` is a member of
+    /// the organization, in which case unlisted and private ones count too.
+    /// Entries are ordered by benchmark name.
+    async fn org_leaderboard(
+        &self,
+        org_id: OrganizationId,
+        viewer: Option<UserId>,
+        pagination: PaginationParams,
+    ) -> Result<Vec<OrgLeaderboardEntry>>;
+
+    /// Get active benchmarks the model has no submission for.
+    ///
+    /// With `model_version` set only submissions of that version count as
+    /// coverage; with `None`, a submission of any version does.
+    async fn missing_benchmarks_for_model(
+        &self,
+        model_provider: &str,
+        model_name: &str,
+        model_version: Option<&str>,
+    ) -> Result<Vec<BenchmarkId>>;
+
+    /// Rank active benchmarks by decayed popularity, most popular first.
+    ///
+    /// Each public submission made within `window` contributes a weight that
+    /// halves every quarter of the window, and the benchmark's total public
+    /// submission count adds `ln(1 + total)`. Benchmarks without a submission
+    /// in the window are not returned.
+    async fn trending_benchmarks(
+        &self,
+        window: Duration,
+        limit: usize,
+    ) -> Result<Vec<(BenchmarkId, f64)>>;
 }
 
 /// PostgreSQL implementation of SubmissionRepository.
@@ -147,21 +244,20 @@ impl PgSubmissionRepository {
         let verification_status_json: serde_json::Value = row.get("verification_status");
         let visibility_str: String = row.get("visibility");
 
-        Ok(Submission {
-            id: SubmissionId::from(id),
-            benchmark_id: BenchmarkId::from(row.get::<Uuid, _>("benchmark_id")),
-            benchmark_version_id: BenchmarkVersionId::from(row.get::<Uuid, _>("benchmark_version_id")),
-            model_info: serde_json::from_value(model_info_json).map_err(Error::Serialization)?,
-            submitter: serde_json::from_value(submitter_info_json).map_err(Error::Serialization)?,
-            results: serde_json::from_value(results_json).map_err(Error::Serialization)?,
-            execution_metadata: serde_json::from_value(execution_metadata_json)
-                .map_err(Error::Serialization)?,
-            verification_status: serde_json::from_value(verification_status_json)
-                .map_err(Error::Serialization)?,
-            visibility: parse_visibility(&visibility_str)?,
-            created_at: row.get("created_at"),
-            updated_at: row.get("updated_at"),
-        })
+        Ok(Submission::restore(
+            SubmissionId::from(id),
+            BenchmarkId::from(row.get::<Uuid, _>("benchmark_id")),
+            BenchmarkVersionId::from(row.get::<Uuid, _>("benchmark_version_id")),
+            serde_json::from_value(model_info_json).map_err(Error::Serialization)?,
+            serde_json::from_value(submitter_info_json).map_err(Error::Serialization)?,
+            serde_json::from_value(results_json).map_err(Error::Serialization)?,
+            serde_json::from_value(execution_metadata_json).map_err(Error::Serialization)?,
+            serde_json::from_value(verification_status_json).map_err(Error::Serialization)?,
+            parse_visibility(&visibility_str)?,
+            row.get("cancelled_at"),
+            row.get("created_at"),
+            row.get("updated_at"),
+        ))
     }
 }
 
@@ -172,14 +268,16 @@ impl SubmissionRepository for PgSubmissionRepository {
         let id = SubmissionId::new();
         let now = Utc::now();
 
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+
         sqlx::query(
             r#"
             INSERT INTO submissions (
                 id, benchmark_id, benchmark_version_id,
                 model_info, submitter_info, results, execution_metadata,
                 verification_status, visibility, aggregate_score,
-                created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                content_hash, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             "#,
         )
         .bind(id.as_uuid())
@@ -187,17 +285,22 @@ impl SubmissionRepository for PgSubmissionRepository {
         .bind(submission.benchmark_version_id.as_uuid())
         .bind(serde_json::to_value(&submission.model_info).map_err(Error::Serialization)?)
         .bind(serde_json::to_value(&submission.submitter).map_err(Error::Serialization)?)
-        .bind(serde_json::to_value(&submission.results).map_err(Error::Serialization)?)
+        .bind(serde_json::to_value(submission.results()).map_err(Error::Serialization)?)
         .bind(serde_json::to_value(&submission.execution_metadata).map_err(Error::Serialization)?)
-        .bind(serde_json::to_value(&submission.verification_status).map_err(Error::Serialization)?)
+        .bind(serde_json::to_value(submission.verification_status()).map_err(Error::Serialization)?)
         .bind(visibility_to_str(&submission.visibility))
-        .bind(submission.results.aggregate_score)
+        .bind(submission.results().aggregate_score)
+        .bind(content_hash(submission.results()).map_err(Error::Serialization)?)
         .bind(now)
         .bind(now)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(Error::Database)?;
 
+        adjust_submission_count(&mut *tx, submission.benchmark_id, 1).await?;
+
+        tx.commit().await.map_err(Error::Database)?;
+
         debug!(submission_id = %id, "Submission created successfully");
         Ok(id)
     }
@@ -209,7 +312,7 @@ impl SubmissionRepository for PgSubmissionRepository {
             SELECT
                 id, benchmark_id, benchmark_version_id,
                 model_info, submitter_info, results, execution_metadata,
-                verification_status, visibility, created_at, updated_at
+                verification_status, visibility, cancelled_at, created_at, updated_at
             FROM submissions
             WHERE id = $1
             "#,
@@ -227,8 +330,7 @@ impl SubmissionRepository for PgSubmissionRepository {
 
     #[instrument(skip(self, query))]
     async fn list(&self, query: SubmissionQuery) -> Result<PaginatedResult<Submission>> {
-        let offset = query.pagination.offset() as i64;
-        let limit = query.pagination.limit() as i64;
+        let (limit, offset) = super::sql_bounds(&query.pagination);
 
         // Build dynamic WHERE clause
         let mut conditions = vec!["1=1".to_string()];
@@ -254,6 +356,20 @@ impl SubmissionRepository for PgSubmissionRepository {
             param_count += 1;
             conditions.push(format!("aggregate_score <= ${}", param_count));
         }
+        if query.model_provider.is_some() {
+            param_count += 1;
+            conditions.push(format!("model_info->>'provider' = ${}", param_count));
+        }
+        if query.model_name.is_some() {
+            param_count += 1;
+            conditions.push(format!("model_info->>'model_name' = ${}", param_count));
+        }
+        // Levels at least as strong as the requested one
+        let levels = query.verification_level.map(verification_levels_meeting);
+        if levels.is_some() {
+            param_count += 1;
+            conditions.push(format!("verification_status->>'level' = ANY(${})", param_count));
+        }
 
         let where_clause = conditions.join(" AND ");
         let order_column = match query.sort.field.as_str() {
@@ -289,6 +405,15 @@ impl SubmissionRepository for PgSubmissionRepository {
         if let Some(max_score) = query.max_score {
             count_query = count_query.bind(max_score);
         }
+        if let Some(ref provider) = query.model_provider {
+            count_query = count_query.bind(provider);
+        }
+        if let Some(ref model_name) = query.model_name {
+            count_query = count_query.bind(model_name);
+        }
+        if let Some(ref levels) = levels {
+            count_query = count_query.bind(levels);
+        }
 
         let total: i64 = count_query
             .fetch_one(&self.pool)
@@ -301,7 +426,7 @@ impl SubmissionRepository for PgSubmissionRepository {
             SELECT
                 id, benchmark_id, benchmark_version_id,
                 model_info, submitter_info, results, execution_metadata,
-                verification_status, visibility, created_at, updated_at
+                verification_status, visibility, cancelled_at, created_at, updated_at
             FROM submissions
             WHERE {}
             ORDER BY {} {}
@@ -326,6 +451,15 @@ impl SubmissionRepository for PgSubmissionRepository {
         if let Some(max_score) = query.max_score {
             list_query = list_query.bind(max_score);
         }
+        if let Some(ref provider) = query.model_provider {
+            list_query = list_query.bind(provider);
+        }
+        if let Some(ref model_name) = query.model_name {
+            list_query = list_query.bind(model_name);
+        }
+        if let Some(ref levels) = levels {
+            list_query = list_query.bind(levels);
+        }
 
         let rows = list_query
             .fetch_all(&self.pool)
@@ -376,13 +510,44 @@ impl SubmissionRepository for PgSubmissionRepository {
         self.list(query).await
     }
 
-    #[instrument(skip(self, status))]
+    #[instrument(skip(self, status, reason))]
     async fn update_verification(
         &self,
         id: SubmissionId,
         status: &VerificationStatus,
+        reason: Option<&str>,
     ) -> Result<()> {
-        let result = sqlx::query(
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT verification_status, cancelled_at
+            FROM submissions
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(Error::Database)?
+        .ok_or_else(|| Error::NotFound(format!("Submission {}", id)))?;
+
+        let previous: VerificationStatus =
+            serde_json::from_value(row.get("verification_status")).map_err(Error::Serialization)?;
+
+        // Checked under the row lock so a concurrent cancel or verification
+        // cannot slip in between the check and the update
+        if row.get::<Option<DateTime<Utc>>, _>("cancelled_at").is_some() {
+            return Err(Error::Conflict(format!("Submission {} has been cancelled", id)));
+        }
+        previous
+            .level
+            .check_transition(status.level)
+            .map_err(|e| Error::Conflict(e.to_string()))?;
+
+        sqlx::query(
             r#"
             UPDATE submissions
             SET verification_status = $2, updated_at = $3
@@ -391,19 +556,79 @@ impl SubmissionRepository for PgSubmissionRepository {
         )
         .bind(id.as_uuid())
         .bind(serde_json::to_value(status).map_err(Error::Serialization)?)
-        .bind(Utc::now())
-        .execute(&self.pool)
+        .bind(now)
+        .execute(&mut *tx)
         .await
         .map_err(Error::Database)?;
 
-        if result.rows_affected() == 0 {
-            return Err(Error::NotFound(format!("Submission {}", id)));
-        }
+        let verifier = status
+            .verified_by
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(Error::Serialization)?;
 
-        debug!(submission_id = %id, "Verification status updated");
+        sqlx::query(
+            r#"
+            INSERT INTO submission_verification_events (
+                submission_id, from_level, to_level, verifier, reason, created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(id.as_uuid())
+        .bind(verification_level_to_str(previous.level))
+        .bind(verification_level_to_str(status.level))
+        .bind(verifier)
+        .bind(reason)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(Error::Database)?;
+
+        tx.commit().await.map_err(Error::Database)?;
+
+        debug!(
+            submission_id = %id,
+            from = ?previous.level,
+            to = ?status.level,
+            "Verification status updated"
+        );
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn verification_history(&self, id: SubmissionId) -> Result<Vec<VerificationEvent>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT from_level, to_level, verifier, reason, created_at
+            FROM submission_verification_events
+            WHERE submission_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        rows.into_iter()
+            .map(|row| {
+                let verifier: Option<serde_json::Value> = row.get("verifier");
+                Ok(VerificationEvent {
+                    submission_id: id,
+                    from_level: parse_verification_level(row.get("from_level"))?,
+                    to_level: parse_verification_level(row.get("to_level"))?,
+                    verifier: verifier
+                        .map(serde_json::from_value)
+                        .transpose()
+                        .map_err(Error::Serialization)?,
+                    reason: row.get("reason"),
+                    at: row.get("created_at"),
+                })
+            })
+            .collect()
+    }
+
     #[instrument(skip(self))]
     async fn update_visibility(
         &self,
@@ -431,15 +656,88 @@ impl SubmissionRepository for PgSubmissionRepository {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn cancel(&self, id: SubmissionId) -> Result<Option<DateTime<Utc>>> {
+        // Conditional so a concurrent verification cannot slip in between
+        // the state check and the update
+        let cancelled_at = sqlx::query_scalar(
+            r#"
+            UPDATE submissions
+            SET cancelled_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+              AND cancelled_at IS NULL
+              AND verification_status->>'level' = 'unverified'
+            RETURNING cancelled_at
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        if cancelled_at.is_some() {
+            debug!(submission_id = %id, "Submission cancelled");
+        }
+        Ok(cancelled_at)
+    }
+
+    #[instrument(skip(self, results))]
+    async fn update_results(&self, id: SubmissionId, results: &SubmissionResults) -> Result<()> {
+        let result = sqlx::query(
+            r#"
+            UPDATE submissions
+            SET results = $2, aggregate_score = $3, content_hash = $4, updated_at = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .bind(serde_json::to_value(results).map_err(Error::Serialization)?)
+        .bind(results.aggregate_score)
+        .bind(content_hash(results).map_err(Error::Serialization)?)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::NotFound(format!("Submission {}", id)));
+        }
+
+        debug!(submission_id = %id, "Submission results updated");
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     async fn delete(&self, id: SubmissionId) -> Result<bool> {
-        let result = sqlx::query("DELETE FROM submissions WHERE id = $1")
-            .bind(id.as_uuid())
-            .execute(&self.pool)
-            .await
-            .map_err(Error::Database)?;
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+
+        let benchmark_id: Option<Uuid> =
+            sqlx::query_scalar("DELETE FROM submissions WHERE id = $1 RETURNING benchmark_id")
+                .bind(id.as_uuid())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(Error::Database)?;
+
+        let Some(benchmark_id) = benchmark_id else {
+            return Ok(false);
+        };
+        adjust_submission_count(&mut *tx, BenchmarkId::from(benchmark_id), -1).await?;
+
+        tx.commit().await.map_err(Error::Database)?;
+        Ok(true)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_by_user(&self, user_id: UserId) -> Result<u64> {
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+        let deleted = delete_user_submissions(&mut tx, user_id).await?;
+        tx.commit().await.map_err(Error::Database)?;
+        Ok(deleted)
+    }
 
-        Ok(result.rows_affected() > 0)
+    #[instrument(skip(self))]
+    async fn reassign_submitter(&self, from: UserId, to: UserId) -> Result<u64> {
+        reassign_user_submissions(&self.pool, from, to).await
     }
 
     #[instrument(skip(self))]
@@ -448,7 +746,13 @@ impl SubmissionRepository for PgSubmissionRepository {
         benchmark_id: BenchmarkId,
         version_id: Option<BenchmarkVersionId>,
         limit: usize,
+        viewer_id: Option<UserId>,
+        organization_ids: &[OrganizationId],
+        min_verification: VerificationLevel,
     ) -> Result<Vec<LeaderboardEntry>> {
+        let viewer_id = viewer_id.map(|id| id.to_string());
+        let organization_ids: Vec<String> = organization_ids.iter().map(ToString::to_string).collect();
+        let levels = verification_levels_meeting(min_verification);
         let rows = match version_id {
             Some(vid) => {
                 sqlx::query(
@@ -467,7 +771,11 @@ impl SubmissionRepository for PgSubmissionRepository {
                     LEFT JOIN organizations o ON o.id = (s.submitter_info->>'organization_id')::uuid
                     WHERE s.benchmark_id = $1
                       AND s.benchmark_version_id = $2
-                      AND s.visibility = 'public'
+                      AND s.cancelled_at IS NULL
+                      AND (s.visibility = 'public'
+                        OR s.submitter_info->>'user_id' = $4
+                        OR s.submitter_info->>'organization_id' = ANY($5))
+                      AND s.verification_status->>'level' = ANY($6)
                     ORDER BY s.aggregate_score DESC
                     LIMIT $3
                     "#,
@@ -475,6 +783,9 @@ impl SubmissionRepository for PgSubmissionRepository {
                 .bind(benchmark_id.as_uuid())
                 .bind(vid.as_uuid())
                 .bind(limit as i64)
+                .bind(&viewer_id)
+                .bind(&organization_ids)
+                .bind(&levels)
                 .fetch_all(&self.pool)
                 .await
                 .map_err(Error::Database)?
@@ -495,13 +806,20 @@ impl SubmissionRepository for PgSubmissionRepository {
                     LEFT JOIN users u ON u.id = (s.submitter_info->>'user_id')::uuid
                     LEFT JOIN organizations o ON o.id = (s.submitter_info->>'organization_id')::uuid
                     WHERE s.benchmark_id = $1
-                      AND s.visibility = 'public'
+                      AND s.cancelled_at IS NULL
+                      AND (s.visibility = 'public'
+                        OR s.submitter_info->>'user_id' = $3
+                        OR s.submitter_info->>'organization_id' = ANY($4))
+                      AND s.verification_status->>'level' = ANY($5)
                     ORDER BY s.aggregate_score DESC
                     LIMIT $2
                     "#,
                 )
                 .bind(benchmark_id.as_uuid())
                 .bind(limit as i64)
+                .bind(&viewer_id)
+                .bind(&organization_ids)
+                .bind(&levels)
                 .fetch_all(&self.pool)
                 .await
                 .map_err(Error::Database)?
@@ -542,12 +860,13 @@ impl SubmissionRepository for PgSubmissionRepository {
             SELECT
                 id, benchmark_id, benchmark_version_id,
                 model_info, submitter_info, results, execution_metadata,
-                verification_status, visibility, created_at, updated_at
+                verification_status, visibility, cancelled_at, created_at, updated_at
             FROM submissions
             WHERE benchmark_id = $1
               AND model_info->>'provider' = $2
               AND model_info->>'model_name' = $3
               AND visibility = 'public'
+              AND cancelled_at IS NULL
             ORDER BY aggregate_score DESC
             LIMIT 1
             "#,
@@ -667,6 +986,210 @@ impl SubmissionRepository for PgSubmissionRepository {
 
         Ok(exists)
     }
+
+    #[instrument(skip(self))]
+    async fn find_by_content_hash(
+        &self,
+        benchmark_id: BenchmarkId,
+        model_provider: &str,
+        model_name: &str,
+        model_version: Option<&str>,
+        content_hash: &str,
+    ) -> Result<Option<SubmissionId>> {
+        let id: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM submissions
+            WHERE benchmark_id = $1
+              AND content_hash = $2
+              AND model_info->>'provider' = $3
+              AND model_info->>'model_name' = $4
+              AND model_info->>'model_version' IS NOT DISTINCT FROM $5
+            ORDER BY created_at, id
+            LIMIT 1
+            "#,
+        )
+        .bind(benchmark_id.as_uuid())
+        .bind(content_hash)
+        .bind(model_provider)
+        .bind(model_name)
+        .bind(model_version)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(id.map(SubmissionId::from))
+    }
+
+    #[instrument(skip(self))]
+    async fn org_leaderboard(
+        &self,
+        org_id: OrganizationId,
+        viewer: Option<UserId>,
+        pagination: PaginationParams,
+    ) -> Result<Vec<OrgLeaderboardEntry>> {
+        let (limit, offset) = super::sql_bounds(&pagination);
+        let rows = sqlx::query(
+            r#"
+            WITH viewer_is_member AS (
+                SELECT EXISTS(
+                    SELECT 1 FROM organization_members
+                    WHERE organization_id = $1 AND user_id = $2
+                ) AS is_member
+            ),
+            best AS (
+                SELECT DISTINCT ON (s.benchmark_id)
+                    s.id, s.benchmark_id, s.model_info, s.aggregate_score,
+                    s.visibility, s.created_at
+                FROM submissions s, viewer_is_member v
+                WHERE (s.submitter_info->>'organization_id')::uuid = $1
+                  AND (s.visibility = 'public' OR v.is_member)
+                  AND s.cancelled_at IS NULL
+                ORDER BY s.benchmark_id, s.aggregate_score DESC, s.created_at
+            )
+            SELECT
+                best.id,
+                best.benchmark_id,
+                b.name AS benchmark_name,
+                best.model_info,
+                best.aggregate_score,
+                best.visibility,
+                best.created_at,
+                1 + (
+                    SELECT COUNT(*) FROM submissions other
+                    WHERE other.benchmark_id = best.benchmark_id
+                      AND other.visibility = 'public'
+                      AND other.cancelled_at IS NULL
+                      AND other.id <> best.id
+                      AND other.aggregate_score > best.aggregate_score
+                ) AS rank,
+                1 + (
+                    SELECT COUNT(*) FROM submissions other
+                    WHERE other.benchmark_id = best.benchmark_id
+                      AND other.visibility = 'public'
+                      AND other.cancelled_at IS NULL
+                      AND other.id <> best.id
+                ) AS ranked_submissions
+            FROM best
+            JOIN benchmarks b ON b.id = best.benchmark_id
+            ORDER BY b.name, best.benchmark_id
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(org_id.as_uuid())
+        .bind(viewer.map(|id| *id.as_uuid()))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            let model_info_json: serde_json::Value = row.get("model_info");
+            let visibility_str: String = row.get("visibility");
+
+            entries.push(OrgLeaderboardEntry {
+                benchmark_id: BenchmarkId::from(row.get::<Uuid, _>("benchmark_id")),
+                benchmark_name: row.get("benchmark_name"),
+                submission_id: SubmissionId::from(row.get::<Uuid, _>("id")),
+                rank: row.get::<i64, _>("rank") as u32,
+                ranked_submissions: row.get::<i64, _>("ranked_submissions") as u64,
+                model_info: serde_json::from_value(model_info_json).map_err(Error::Serialization)?,
+                aggregate_score: row.get("aggregate_score"),
+                visibility: parse_visibility(&visibility_str)?,
+                submitted_at: row.get("created_at"),
+            });
+        }
+
+        Ok(entries)
+    }
+
+    #[instrument(skip(self))]
+    async fn missing_benchmarks_for_model(
+        &self,
+        model_provider: &str,
+        model_name: &str,
+        model_version: Option<&str>,
+    ) -> Result<Vec<BenchmarkId>> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT b.id
+            FROM benchmarks b
+            LEFT JOIN submissions s
+              ON s.benchmark_id = b.id
+             AND s.model_info->>'provider' = $1
+             AND s.model_info->>'model_name' = $2
+             AND ($3::text IS NULL OR s.model_info->>'model_version' = $3)
+            WHERE b.status = 'active'
+              AND s.id IS NULL
+            ORDER BY b.name, b.id
+            "#,
+        )
+        .bind(model_provider)
+        .bind(model_name)
+        .bind(model_version)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(ids.into_iter().map(BenchmarkId::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn trending_benchmarks(
+        &self,
+        window: Duration,
+        limit: usize,
+    ) -> Result<Vec<(BenchmarkId, f64)>> {
+        let window_secs = window.as_secs_f64();
+        if window_secs <= 0.0 || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            WITH recent AS (
+                SELECT s.benchmark_id,
+                       SUM(POWER(
+                           0.5,
+                           EXTRACT(EPOCH FROM (NOW() - s.created_at)) / ($1 / 4.0)
+                       )) AS velocity
+                FROM submissions s
+                WHERE s.visibility = 'public'
+                  AND s.created_at >= NOW() - make_interval(secs => $1)
+                GROUP BY s.benchmark_id
+            ),
+            totals AS (
+                SELECT s.benchmark_id, COUNT(*) AS total
+                FROM submissions s
+                JOIN recent r ON r.benchmark_id = s.benchmark_id
+                WHERE s.visibility = 'public'
+                GROUP BY s.benchmark_id
+            )
+            SELECT r.benchmark_id,
+                   (r.velocity + LN(1 + t.total))::float8 AS score
+            FROM recent r
+            JOIN totals t ON t.benchmark_id = r.benchmark_id
+            JOIN benchmarks b ON b.id = r.benchmark_id
+            WHERE b.status = 'active'
+            ORDER BY score DESC, r.benchmark_id
+            LIMIT $2
+            "#,
+        )
+        .bind(window_secs)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(Error::Database)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let id: Uuid = row.get("benchmark_id");
+                (BenchmarkId::from(id), row.get("score"))
+            })
+            .collect())
+    }
 }
 
 // Helper functions for visibility conversion
@@ -688,6 +1211,87 @@ fn parse_visibility(s: &str) -> Result<SubmissionVisibility> {
     }
 }
 
+// Helper functions for verification level conversion
+
+fn verification_level_to_str(level: VerificationLevel) -> &'static str {
+    match level {
+        VerificationLevel::Unverified => "unverified",
+        VerificationLevel::CommunityVerified => "community_verified",
+        VerificationLevel::PlatformVerified => "platform_verified",
+        VerificationLevel::Audited => "audited",
+    }
+}
+
+/// Stored names of every level at least as strong as `minimum`
+fn verification_levels_meeting(minimum: VerificationLevel) -> Vec<&'static str> {
+    [
+        VerificationLevel::Unverified,
+        VerificationLevel::CommunityVerified,
+        VerificationLevel::PlatformVerified,
+        VerificationLevel::Audited,
+    ]
+    .into_iter()
+    .filter(|level| level.meets(minimum))
+    .map(verification_level_to_str)
+    .collect()
+}
+
+fn parse_verification_level(s: &str) -> Result<VerificationLevel> {
+    match s {
+        "unverified" => Ok(VerificationLevel::Unverified),
+        "community_verified" => Ok(VerificationLevel::CommunityVerified),
+        "platform_verified" => Ok(VerificationLevel::PlatformVerified),
+        "audited" => Ok(VerificationLevel::Audited),
+        _ => Err(Error::Configuration(format!("Unknown verification level: {}", s))),
+    }
+}
+
+/// Delete every submission made by a user, keeping benchmark submission
+/// counts in step. Runs on the caller's connection so it can share a
+/// transaction.
+pub(crate) async fn delete_user_submissions(conn: &mut sqlx::PgConnection, user_id: UserId) -> Result<u64> {
+    let benchmark_ids: Vec<Uuid> = sqlx::query_scalar(
+        "DELETE FROM submissions WHERE submitter_info->>'user_id' = $1 RETURNING benchmark_id",
+    )
+    .bind(user_id.to_string())
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(Error::Database)?;
+
+    let mut removed: HashMap<Uuid, i64> = HashMap::new();
+    for benchmark_id in &benchmark_ids {
+        *removed.entry(*benchmark_id).or_default() += 1;
+    }
+    for (benchmark_id, count) in removed {
+        adjust_submission_count(&mut *conn, BenchmarkId::from(benchmark_id), -count).await?;
+    }
+
+    Ok(benchmark_ids.len() as u64)
+}
+
+/// Reassign every submission made by `from` to `to`, returning how many moved.
+pub(crate) async fn reassign_user_submissions<'e, E>(executor: E, from: UserId, to: UserId) -> Result<u64>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let result = sqlx::query(
+        r#"
+        UPDATE submissions
+        SET submitter_info = jsonb_set(submitter_info, '{user_id}', to_jsonb($2::text)),
+            updated_at = $3
+        WHERE submitter_info->>'user_id' = $1
+        "#,
+    )
+    .bind(from.to_string())
+    .bind(to.to_string())
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .map_err(Error::Database)?;
+
+    Ok(result.rows_affected())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -698,4 +1302,29 @@ mod tests {
         assert!(parse_visibility("public").is_ok());
         assert!(parse_visibility("invalid").is_err());
     }
+
+    #[test]
+    fn test_verification_level_conversion() {
+        for level in [
+            VerificationLevel::Unverified,
+            VerificationLevel::CommunityVerified,
+            VerificationLevel::PlatformVerified,
+            VerificationLevel::Audited,
+        ] {
+            let s = verification_level_to_str(level);
+            assert_eq!(parse_verification_level(s).unwrap(), level);
+            // Stored values match the serde representation
+            assert_eq!(serde_json::to_value(level).unwrap(), s);
+        }
+        assert!(parse_verification_level("invalid").is_err());
+    }
+
+    #[test]
+    fn test_verification_levels_meeting() {
+        assert_eq!(verification_levels_meeting(VerificationLevel::Unverified).len(), 4);
+        assert_eq!(
+            verification_levels_meeting(VerificationLevel::PlatformVerified),
+            vec!["platform_verified", "audited"]
+        );
+    }
 }
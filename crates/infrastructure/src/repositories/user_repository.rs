@@ -14,8 +14,29 @@ use llm_benchmark_domain::{
     user::{OrganizationMembership, OrganizationRole, User, UserProfile, UserRole},
 };
 
+use super::submission_repository::{delete_user_submissions, reassign_user_submissions};
 use crate::{Error, Result};
 
+/// What happens to a user's content when their account is deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDeletion {
+    /// Delete their submissions, proposals, votes and comments.
+    RemoveContent,
+    /// Move their submissions, proposals and comments to `tombstone`. Votes
+    /// stay with the anonymized account, as one user may only vote once per
+    /// proposal.
+    ReassignContent { tombstone: UserId },
+}
+
+/// Result of [`UserRepository::delete_account`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDeletionOutcome {
+    Deleted,
+    NotFound,
+    /// Nothing was changed because the user is the only remaining admin.
+    LastAdmin,
+}
+
 /// Query parameters for user searches.
 #[derive(Debug, Clone, Default)]
 pub struct UserQuery {
@@ -73,6 +94,16 @@ pub trait UserRepository: Send + Sync {
     /// Delete a user (soft delete).
     async fn delete(&self, id: UserId) -> Result<bool>;
 
+    /// Strip personal data from a user and soft delete the account.
+    async fn anonymize(&self, id: UserId) -> Result<bool>;
+
+    /// Delete a user's account in one transaction.
+    ///
+    /// The user's API keys are deleted and their content disposed of as
+    /// `deletion` says, then the account is anonymized. Every admin row is
+    /// locked first, so concurrent deletions can never remove the last admin.
+    async fn delete_account(&self, id: UserId, deletion: AccountDeletion) -> Result<AccountDeletionOutcome>;
+
     /// Check if an email is already registered.
     async fn email_exists(&self, email: &str) -> Result<bool>;
 
@@ -246,8 +277,7 @@ impl UserRepository for PgUserRepository {
 
     #[instrument(skip(self, query))]
     async fn list(&self, query: UserQuery) -> Result<PaginatedResult<User>> {
-        let offset = query.pagination.offset() as i64;
-        let limit = query.pagination.limit() as i64;
+        let (limit, offset) = super::sql_bounds(&query.pagination);
 
         // Build dynamic WHERE clause
         let mut conditions = vec!["deleted_at IS NULL".to_string()];
@@ -496,6 +526,82 @@ impl UserRepository for PgUserRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    #[instrument(skip(self))]
+    async fn anonymize(&self, id: UserId) -> Result<bool> {
+        anonymize_user(&self.pool, id).await
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_account(&self, id: UserId, deletion: AccountDeletion) -> Result<AccountDeletionOutcome> {
+        let mut tx = self.pool.begin().await.map_err(Error::Database)?;
+
+        // Admins are locked in a fixed order before the user, so concurrent
+        // deletions queue up and each sees the admins the others left behind
+        let admins: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT id FROM users WHERE role = 'admin' AND deleted_at IS NULL ORDER BY id FOR UPDATE",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(Error::Database)?;
+
+        let found: Option<Uuid> =
+            sqlx::query_scalar("SELECT id FROM users WHERE id = $1 AND deleted_at IS NULL FOR UPDATE")
+                .bind(id.as_uuid())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(Error::Database)?;
+        if found.is_none() {
+            return Ok(AccountDeletionOutcome::NotFound);
+        }
+        if admins.contains(id.as_uuid()) && admins.len() <= 1 {
+            return Ok(AccountDeletionOutcome::LastAdmin);
+        }
+
+        sqlx::query("DELETE FROM api_keys WHERE user_id = $1")
+            .bind(id.as_uuid())
+            .execute(&mut *tx)
+            .await
+            .map_err(Error::Database)?;
+
+        match deletion {
+            AccountDeletion::RemoveContent => {
+                delete_user_submissions(&mut tx, id).await?;
+                // Votes and comments on the user's own proposals go with them
+                for statement in [
+                    "DELETE FROM proposal_comments WHERE author_id = $1",
+                    "DELETE FROM votes WHERE user_id = $1",
+                    "DELETE FROM proposals WHERE created_by = $1",
+                ] {
+                    sqlx::query(statement)
+                        .bind(id.as_uuid())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(Error::Database)?;
+                }
+            }
+            AccountDeletion::ReassignContent { tombstone } => {
+                reassign_user_submissions(&mut *tx, id, tombstone).await?;
+                for statement in [
+                    "UPDATE proposals SET created_by = $2 WHERE created_by = $1",
+                    "UPDATE proposal_comments SET author_id = $2 WHERE author_id = $1",
+                ] {
+                    sqlx::query(statement)
+                        .bind(id.as_uuid())
+                        .bind(tombstone.as_uuid())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(Error::Database)?;
+                }
+            }
+        }
+
+        anonymize_user(&mut *tx, id).await?;
+        tx.commit().await.map_err(Error::Database)?;
+
+        debug!(user_id = %id, deletion = ?deletion, "User account deleted");
+        Ok(AccountDeletionOutcome::Deleted)
+    }
+
     #[instrument(skip(self))]
     async fn email_exists(&self, email: &str) -> Result<bool> {
         let exists: bool = sqlx::query_scalar(
@@ -672,7 +778,7 @@ impl UserRepository for PgUserRepository {
 
 // Helper functions for role conversion
 
-fn role_to_str(role: &UserRole) -> &'static str {
+pub(super) fn role_to_str(role: &UserRole) -> &'static str {
     match role {
         UserRole::Anonymous => "anonymous",
         UserRole::Registered => "registered",
@@ -682,7 +788,7 @@ fn role_to_str(role: &UserRole) -> &'static str {
     }
 }
 
-fn parse_role(s: &str) -> Result<UserRole> {
+pub(super) fn parse_role(s: &str) -> Result<UserRole> {
     match s.to_lowercase().as_str() {
         "anonymous" => Ok(UserRole::Anonymous),
         "registered" => Ok(UserRole::Registered),
@@ -710,6 +816,37 @@ fn parse_org_role(s: &str) -> Result<OrganizationRole> {
     }
 }
 
+/// Strip personal data from a user and soft delete the account.
+///
+/// Unique columns get placeholders derived from the ID so the row stays
+/// valid without holding any personal data.
+async fn anonymize_user<'e, E>(executor: E, id: UserId) -> Result<bool>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let result = sqlx::query(
+        r#"
+        UPDATE users
+        SET email = 'deleted-' || replace(id::text, '-', '') || '@deleted.invalid',
+            username = 'deleted-' || replace(id::text, '-', ''),
+            display_name = NULL,
+            profile = '{}'::jsonb,
+            password_hash = '',
+            email_verified = FALSE,
+            deleted_at = $2,
+            updated_at = $2
+        WHERE id = $1
+        "#,
+    )
+    .bind(id.as_uuid())
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .map_err(Error::Database)?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
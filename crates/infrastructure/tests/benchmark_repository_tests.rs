@@ -0,0 +1,35 @@
+//! Integration tests for the benchmark repository
+//!
+//! These tests require a migrated PostgreSQL database and are marked with #[ignore].
+//! Run with: TEST_DATABASE_URL=postgres://... cargo test --test benchmark_repository_tests -- --ignored
+
+mod common;
+
+use common::benchmark_record;
+use llm_benchmark_domain::benchmark::BenchmarkCategory;
+use llm_benchmark_infrastructure::{BenchmarkRepository, PgBenchmarkRepository};
+
+#[tokio::test]
+#[ignore]
+async fn test_custom_category_round_trip() {
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let repo = PgBenchmarkRepository::new(db.pool().clone());
+
+    let name = format!("custom-{}", &uuid::Uuid::new_v4().simple().to_string()[..12]);
+    let category = BenchmarkCategory::custom(name).unwrap();
+    let mut record = benchmark_record(user_id, vec![]);
+    record.category = category.clone();
+    let id = repo.create(&record).await.expect("Failed to create benchmark");
+
+    let stored = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(stored.category, category);
+
+    assert_eq!(repo.count(None, Some(category.clone())).await.unwrap(), 1);
+    assert_eq!(
+        repo.count(None, Some(BenchmarkCategory::custom("other").unwrap()))
+            .await
+            .unwrap(),
+        0
+    );
+}
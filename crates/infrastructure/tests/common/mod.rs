@@ -0,0 +1,99 @@
+//! Shared setup for tests that run against a migrated PostgreSQL database
+
+// Each test binary uses a different subset of these helpers
+#![allow(dead_code)]
+
+use chrono::Utc;
+use llm_benchmark_domain::{
+    benchmark::{BenchmarkCategory, BenchmarkStatus, LicenseType},
+    identifiers::{BenchmarkId, BenchmarkVersionId, UserId},
+    test_case::TestCase,
+    version::SemanticVersion,
+};
+use llm_benchmark_infrastructure::{
+    BenchmarkRecord, DatabaseConfig, DatabasePool, PgUserRepository, UserRepository,
+};
+use llm_benchmark_testing::fixtures::create_test_user;
+use serde_json::json;
+
+/// Connect to the database named by `TEST_DATABASE_URL`
+pub async fn connect() -> DatabasePool {
+    let database_url =
+        std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    DatabasePool::new(&DatabaseConfig::test_config(database_url))
+        .await
+        .expect("Failed to connect to database")
+}
+
+/// Insert a fresh user to own benchmarks and submissions
+pub async fn create_user(db: &DatabasePool) -> UserId {
+    PgUserRepository::new(db.pool().clone())
+        .create(&create_test_user(), "not-a-real-hash")
+        .await
+        .expect("Failed to create user")
+}
+
+/// A minimal test case with an exact-match evaluation
+pub fn test_case(id: &str, prompt: &str) -> TestCase {
+    serde_json::from_value(json!({
+        "id": id,
+        "name": format!("Case {}", id),
+        "input": {
+            "prompt_template": prompt,
+            "variables": {},
+            "few_shot_examples": [],
+            "input_format": {"type": "plain_text"}
+        },
+        "evaluation_method": {"type": "exact_match"},
+        "weight": 1.0,
+        "tags": []
+    }))
+    .unwrap()
+}
+
+/// A draft benchmark with a unique slug
+pub fn benchmark_record(created_by: UserId, test_cases: Vec<TestCase>) -> BenchmarkRecord {
+    let now = Utc::now();
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    BenchmarkRecord {
+        id: BenchmarkId::new(),
+        version_id: BenchmarkVersionId::new(),
+        slug: format!("test-{}", &suffix[..12]),
+        name: format!("Test {}", &suffix[..12]),
+        description: "Benchmark created by an integration test".to_string(),
+        long_description: None,
+        category: BenchmarkCategory::Accuracy,
+        status: BenchmarkStatus::Draft,
+        version: SemanticVersion::new(1, 0, 0),
+        tags: vec![],
+        license: LicenseType::MIT,
+        created_by,
+        created_at: now,
+        updated_at: now,
+        evaluation_criteria: serde_json::from_value(json!({
+            "primary_metric": {
+                "name": "accuracy",
+                "description": "Fraction correct",
+                "metric_type": {"type": "accuracy"},
+                "higher_is_better": true
+            },
+            "secondary_metrics": [],
+            "aggregation_method": {"type": "mean"},
+            "score_normalization": {"type": "none"},
+            "minimum_test_cases": 1,
+            "confidence_level": 0.95
+        }))
+        .unwrap(),
+        execution_config: serde_json::from_value(json!({
+            "timeout_per_test_ms": 30000,
+            "max_retries": 0,
+            "retry_delay_ms": 0,
+            "parallelism": {"max_concurrent_requests": 1},
+            "model_parameters": {"stop_sequences": [], "additional_params": {}},
+            "environment_requirements": {"required_packages": [], "gpu_required": false}
+        }))
+        .unwrap(),
+        test_cases,
+        submission_count: 0,
+    }
+}
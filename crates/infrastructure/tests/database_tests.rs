@@ -0,0 +1,71 @@
+//! Integration tests for the database pool
+//!
+//! These tests require a PostgreSQL database and are marked with #[ignore].
+//! Run with: TEST_DATABASE_URL=postgres://... cargo test --test database_tests -- --ignored
+
+use llm_benchmark_infrastructure::{DatabaseConfig, DatabasePool};
+
+fn test_config() -> DatabaseConfig {
+    let database_url =
+        std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    DatabaseConfig {
+        min_connections: 0,
+        ..DatabaseConfig::test_config(database_url)
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_warmup_leaves_idle_connections() {
+    let db = DatabasePool::new(&test_config())
+        .await
+        .expect("Failed to connect to database");
+
+    let warmed = db.warmup(3).await.expect("Warmup failed");
+    assert_eq!(warmed, 3);
+
+    let stats = db.stats();
+    assert_eq!(stats.warmed, 3);
+    assert_eq!(stats.size, 3);
+    assert_eq!(stats.idle, 3);
+
+    db.close().await;
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_warmup_is_capped_at_max_connections() {
+    let config = test_config();
+    let db = DatabasePool::new(&DatabaseConfig {
+        warmup_connections: config.max_connections + 10,
+        ..config.clone()
+    })
+    .await
+    .expect("Failed to connect to database");
+
+    let stats = db.stats();
+    assert_eq!(stats.warmed, config.max_connections);
+    assert_eq!(stats.idle, config.max_connections as usize);
+
+    db.close().await;
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_queries_without_statement_cache() {
+    let db = DatabasePool::new(&DatabaseConfig {
+        statement_cache_capacity: 0,
+        ..test_config()
+    })
+    .await
+    .expect("Failed to connect to database");
+
+    for _ in 0..3 {
+        let value: i32 = sqlx::query_scalar("SELECT $1")
+            .bind(7)
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+    }
+}
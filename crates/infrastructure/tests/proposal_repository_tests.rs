@@ -0,0 +1,227 @@
+//! Integration tests for the proposal repository
+//!
+//! These tests require a migrated PostgreSQL database and are marked with #[ignore].
+//! Run with: TEST_DATABASE_URL=postgres://... cargo test --test proposal_repository_tests -- --ignored
+
+mod common;
+
+use chrono::{Duration, Utc};
+use llm_benchmark_domain::{
+    governance::{Ballot, Comment, ProposalStatus, RoleWeights, Vote},
+    identifiers::{ProposalId, UserId},
+    user::UserRole,
+};
+use llm_benchmark_infrastructure::{
+    AccountDeletion, AccountDeletionOutcome, DatabasePool, Error, GovernanceActivityKind,
+    PgProposalRepository, PgUserRepository, ProposalRepository, UserRepository,
+};
+
+/// Insert a policy-change proposal in voting, opened a day ago
+async fn create_proposal(db: &DatabasePool, created_by: UserId) -> ProposalId {
+    let id = ProposalId::new();
+    sqlx::query(
+        r#"
+        INSERT INTO proposals (
+            id, proposal_type, title, description, rationale, status,
+            voting_starts_at, voting_ends_at, quorum_required, created_by
+        ) VALUES ($1, 'policy_change', 'Policy', 'Change a policy', 'Because', 'voting',
+                  $2, $3, 5, $4)
+        "#,
+    )
+    .bind(id.as_uuid())
+    .bind(Utc::now() - Duration::days(1))
+    .bind(Utc::now() + Duration::days(1))
+    .bind(created_by.as_uuid())
+    .execute(db.pool())
+    .await
+    .expect("Failed to insert proposal");
+    id
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_votes_and_status_round_trip() {
+    let db = common::connect().await;
+    let author = common::create_user(&db).await;
+    let voter = common::create_user(&db).await;
+    let repo = PgProposalRepository::new(db.pool().clone());
+    let id = create_proposal(&db, author).await;
+
+    let mut proposal = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Voting);
+    assert!(proposal.voting.ballots.is_empty());
+
+    proposal.voting.ballots.push(Ballot {
+        voter,
+        role: UserRole::Reviewer,
+        vote: Vote::Approve,
+        cast_at: Utc::now(),
+    });
+    repo.update(&proposal, ProposalStatus::Voting).await.unwrap();
+
+    let stored = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(stored.voting.ballots.len(), 1);
+    assert_eq!(stored.voting.ballots[0].role, UserRole::Reviewer);
+    // Totals are kept by the database from the recorded votes
+    assert_eq!(stored.voting.votes_for, 1);
+    assert!(stored.voting.voters.contains(&voter));
+
+    assert_eq!(stored.voting.role_weights, None);
+
+    let mut finalized = stored;
+    finalized.status = ProposalStatus::Approved;
+    finalized.voting.role_weights = Some(RoleWeights::platform_default());
+    repo.update(&finalized, ProposalStatus::Voting).await.unwrap();
+    let stored = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(stored.status, ProposalStatus::Approved);
+    assert_eq!(stored.voting.role_weights, Some(RoleWeights::platform_default()));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_comment_threads() {
+    let db = common::connect().await;
+    let author = common::create_user(&db).await;
+    let repo = PgProposalRepository::new(db.pool().clone());
+    let id = create_proposal(&db, author).await;
+
+    let root = Comment::new(id, author, "Looks good", None);
+    let reply = Comment::new(id, author, "Agreed", Some(root.id));
+    repo.add_comment(&root).await.unwrap();
+    repo.add_comment(&reply).await.unwrap();
+
+    let stored = repo.get_comment(reply.id).await.unwrap().unwrap();
+    assert_eq!(stored.parent_id, Some(root.id));
+
+    let comments = repo.list_comments(id).await.unwrap();
+    let ids: Vec<_> = comments.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![root.id, reply.id]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_update_missing_proposal_fails() {
+    let db = common::connect().await;
+    let author = common::create_user(&db).await;
+    let repo = PgProposalRepository::new(db.pool().clone());
+    let id = create_proposal(&db, author).await;
+
+    let mut proposal = repo.get_by_id(id).await.unwrap().unwrap();
+    proposal.id = ProposalId::new();
+    assert!(repo.update(&proposal, ProposalStatus::Voting).await.is_err());
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_vote_and_finalization_do_not_overwrite_each_other() {
+    let db = common::connect().await;
+    let author = common::create_user(&db).await;
+    let voter = common::create_user(&db).await;
+    let late_voter = common::create_user(&db).await;
+    let repo = PgProposalRepository::new(db.pool().clone());
+    let ballot = |voter| Ballot {
+        voter,
+        role: UserRole::Contributor,
+        vote: Vote::Approve,
+        cast_at: Utc::now(),
+    };
+
+    // A vote recorded after the tally was taken blocks the finalization
+    let id = create_proposal(&db, author).await;
+    let mut tallied = repo.get_by_id(id).await.unwrap().unwrap();
+    let mut voted = tallied.clone();
+    voted.voting.ballots.push(ballot(voter));
+    repo.update(&voted, ProposalStatus::Voting).await.unwrap();
+    tallied.status = ProposalStatus::Rejected;
+    let err = repo.update(&tallied, ProposalStatus::Voting).await.unwrap_err();
+    assert!(matches!(err, Error::Conflict(_)));
+
+    // A vote on a proposal finalized after it was loaded is not recorded
+    let mut stale = repo.get_by_id(id).await.unwrap().unwrap();
+    let mut finalized = stale.clone();
+    finalized.status = ProposalStatus::Approved;
+    repo.update(&finalized, ProposalStatus::Voting).await.unwrap();
+    stale.voting.ballots.push(ballot(late_voter));
+    let err = repo.update(&stale, ProposalStatus::Voting).await.unwrap_err();
+    assert!(matches!(err, Error::Conflict(_)));
+
+    let stored = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(stored.status, ProposalStatus::Approved);
+    assert_eq!(stored.voting.ballots.len(), 1);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_user_activity_covers_proposals_votes_and_comments() {
+    let db = common::connect().await;
+    let author = common::create_user(&db).await;
+    let other = common::create_user(&db).await;
+    let repo = PgProposalRepository::new(db.pool().clone());
+    let id = create_proposal(&db, author).await;
+
+    let mut proposal = repo.get_by_id(id).await.unwrap().unwrap();
+    proposal.voting.ballots.push(Ballot {
+        voter: author,
+        role: UserRole::Reviewer,
+        vote: Vote::Reject,
+        cast_at: Utc::now(),
+    });
+    repo.update(&proposal, ProposalStatus::Voting).await.unwrap();
+    repo.add_comment(&Comment::new(id, author, "Not yet", None)).await.unwrap();
+
+    let activity = repo.user_activity(author).await.unwrap();
+    let mut kinds: Vec<_> = activity.iter().map(|a| a.kind).collect();
+    kinds.sort_by_key(|kind| *kind as u8);
+    assert_eq!(
+        kinds,
+        vec![
+            GovernanceActivityKind::ProposalCreated,
+            GovernanceActivityKind::VoteCast,
+            GovernanceActivityKind::CommentAdded,
+        ]
+    );
+    assert!(activity.iter().all(|a| a.proposal_id == id));
+    assert!(activity.iter().any(|a| a.detail.as_deref() == Some("reject")));
+
+    assert!(repo.user_activity(other).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_delete_account_removes_or_reassigns_governance_content() {
+    let db = common::connect().await;
+    let repo = PgProposalRepository::new(db.pool().clone());
+    let users = PgUserRepository::new(db.pool().clone());
+    let tombstone = UserId::from(uuid::Uuid::nil());
+
+    let removed_author = common::create_user(&db).await;
+    let removed = create_proposal(&db, removed_author).await;
+    assert_eq!(
+        users.delete_account(removed_author, AccountDeletion::RemoveContent).await.unwrap(),
+        AccountDeletionOutcome::Deleted
+    );
+    assert!(repo.get_by_id(removed).await.unwrap().is_none());
+    assert!(repo.user_activity(removed_author).await.unwrap().is_empty());
+
+    let anonymized_author = common::create_user(&db).await;
+    let kept = create_proposal(&db, anonymized_author).await;
+    repo.add_comment(&Comment::new(kept, anonymized_author, "Keep this", None))
+        .await
+        .unwrap();
+    assert_eq!(
+        users
+            .delete_account(anonymized_author, AccountDeletion::ReassignContent { tombstone })
+            .await
+            .unwrap(),
+        AccountDeletionOutcome::Deleted
+    );
+    assert!(repo.user_activity(anonymized_author).await.unwrap().is_empty());
+    let inherited = repo.user_activity(tombstone).await.unwrap();
+    assert_eq!(inherited.iter().filter(|a| a.proposal_id == kept).count(), 2);
+
+    // The account is gone, so a second deletion finds nothing
+    assert_eq!(
+        users.delete_account(anonymized_author, AccountDeletion::RemoveContent).await.unwrap(),
+        AccountDeletionOutcome::NotFound
+    );
+}
@@ -63,7 +63,7 @@ async fn test_submission_repository_create() {
     let submission = create_test_submission();
 
     // Assert
-    assert!(!submission.results.test_case_results.is_empty());
+    assert!(!submission.results().test_case_results.is_empty());
 }
 
 #[tokio::test]
@@ -188,7 +188,7 @@ async fn test_repository_json_field_queries() {
 
     // PostgreSQL JSONB queries like:
     // SELECT * FROM submissions WHERE results->>'aggregate_score' > '0.9'
-    assert!(submission.results.aggregate_score >= 0.0);
+    assert!(submission.results().aggregate_score >= 0.0);
 }
 
 #[cfg(test)]
@@ -0,0 +1,514 @@
+//! Integration tests for the submission repository
+//!
+//! These tests require a migrated PostgreSQL database and are marked with #[ignore].
+//! Run with: TEST_DATABASE_URL=postgres://... cargo test --test submission_repository_tests -- --ignored
+
+mod common;
+
+use common::benchmark_record;
+use llm_benchmark_common::crypto::content_hash;
+use llm_benchmark_common::pagination::PaginationParams;
+use llm_benchmark_domain::{
+    benchmark::BenchmarkStatus,
+    identifiers::{BenchmarkId, OrganizationId, UserId, VerificationId},
+    submission::{Submission, SubmissionVisibility, VerificationLevel, VerifiedBy},
+};
+use llm_benchmark_infrastructure::{
+    BenchmarkRepository, DatabasePool, Error, OrgLeaderboardEntry, OrganizationRepository,
+    PgBenchmarkRepository, PgOrganizationRepository, PgSubmissionRepository, SubmissionQuery,
+    SubmissionRepository,
+};
+use llm_benchmark_testing::fixtures::{create_test_organization, create_test_submission};
+
+async fn create_active_benchmark(repo: &PgBenchmarkRepository, user_id: UserId) -> BenchmarkId {
+    let id = repo
+        .create(&benchmark_record(user_id, vec![]))
+        .await
+        .expect("Failed to create benchmark");
+    repo.update_status(id, BenchmarkStatus::Active).await.unwrap();
+    id
+}
+
+/// A public, unaffiliated submission to the benchmark's current version
+async fn submission_for(db: &DatabasePool, benchmark_id: BenchmarkId, user_id: UserId) -> Submission {
+    let version_id = PgBenchmarkRepository::new(db.pool().clone())
+        .get_by_id(benchmark_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .version_id;
+
+    let mut submission = create_test_submission();
+    submission.benchmark_id = benchmark_id;
+    submission.benchmark_version_id = version_id;
+    submission.submitter.user_id = user_id;
+    submission.submitter.organization_id = None;
+    submission.visibility = SubmissionVisibility::Public;
+    submission
+}
+
+async fn save(db: &DatabasePool, submission: &Submission) {
+    PgSubmissionRepository::new(db.pool().clone())
+        .create(submission)
+        .await
+        .expect("Failed to create submission");
+}
+
+async fn submit(
+    db: &DatabasePool,
+    benchmark_id: BenchmarkId,
+    user_id: UserId,
+    model: (&str, &str, &str),
+) {
+    let mut submission = submission_for(db, benchmark_id, user_id).await;
+    submission.model_info.provider = model.0.to_string();
+    submission.model_info.model_name = model.1.to_string();
+    submission.model_info.model_version = Some(model.2.to_string());
+    save(db, &submission).await;
+}
+
+async fn submit_scored(
+    db: &DatabasePool,
+    benchmark_id: BenchmarkId,
+    user_id: UserId,
+    org_id: Option<OrganizationId>,
+    score: f64,
+    visibility: SubmissionVisibility,
+) {
+    let mut submission = submission_for(db, benchmark_id, user_id).await;
+    submission.submitter.organization_id = org_id;
+    let mut results = submission.results().clone();
+    results.aggregate_score = score;
+    submission.set_results(results, None).unwrap();
+    submission.visibility = visibility;
+    save(db, &submission).await;
+}
+
+async fn create_org(db: &DatabasePool, owner: UserId) -> OrganizationId {
+    let mut org = create_test_organization();
+    org.slug = format!("org-{}", &uuid::Uuid::new_v4().simple().to_string()[..12]);
+    PgOrganizationRepository::new(db.pool().clone())
+        .create(&org, owner)
+        .await
+        .expect("Failed to create organization")
+}
+
+/// (benchmark, score, rank, ranked submissions) for each entry
+fn summarize(entries: &[OrgLeaderboardEntry]) -> Vec<(BenchmarkId, f64, u32, u64)> {
+    entries
+        .iter()
+        .map(|e| (e.benchmark_id, e.aggregate_score, e.rank, e.ranked_submissions))
+        .collect()
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_missing_benchmarks_for_model() {
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+
+    let provider = format!("provider-{}", uuid::Uuid::new_v4().simple());
+    let covered = create_active_benchmark(&benchmarks, user_id).await;
+    let other_version = create_active_benchmark(&benchmarks, user_id).await;
+    let uncovered = create_active_benchmark(&benchmarks, user_id).await;
+    let draft = benchmarks
+        .create(&benchmark_record(user_id, vec![]))
+        .await
+        .unwrap();
+
+    submit(&db, covered, user_id, (&provider, "model", "1.0")).await;
+    submit(&db, other_version, user_id, (&provider, "model", "2.0")).await;
+    // Another model covering the uncovered benchmark doesn't count
+    submit(&db, uncovered, user_id, (&provider, "other-model", "1.0")).await;
+
+    let ours = [covered, other_version, uncovered, draft];
+    let missing = |ids: Vec<BenchmarkId>| -> Vec<BenchmarkId> {
+        ids.into_iter().filter(|id| ours.contains(id)).collect()
+    };
+
+    let for_version = submissions
+        .missing_benchmarks_for_model(&provider, "model", Some("1.0"))
+        .await
+        .unwrap();
+    let mut for_version = missing(for_version);
+    for_version.sort_by_key(|id| id.to_string());
+    let mut expected = vec![other_version, uncovered];
+    expected.sort_by_key(|id| id.to_string());
+    assert_eq!(for_version, expected);
+
+    let any_version = submissions
+        .missing_benchmarks_for_model(&provider, "model", None)
+        .await
+        .unwrap();
+    assert_eq!(missing(any_version), vec![uncovered]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_org_leaderboard_visibility_and_ranks() {
+    let db = common::connect().await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+
+    let alice = common::create_user(&db).await;
+    let bob = common::create_user(&db).await;
+    let carol = common::create_user(&db).await;
+    let org_a = create_org(&db, alice).await;
+    let org_b = create_org(&db, bob).await;
+
+    let shared = create_active_benchmark(&benchmarks, alice).await;
+    let b_only = create_active_benchmark(&benchmarks, bob).await;
+
+    use SubmissionVisibility::{Private, Public};
+    submit_scored(&db, shared, alice, Some(org_a), 0.90, Public).await;
+    submit_scored(&db, shared, alice, Some(org_a), 0.95, Private).await;
+    submit_scored(&db, shared, bob, Some(org_b), 0.80, Public).await;
+    submit_scored(&db, shared, carol, None, 0.85, Public).await;
+    submit_scored(&db, b_only, bob, Some(org_b), 0.70, Private).await;
+
+    let leaderboard = |org, viewer| {
+        let submissions = &submissions;
+        async move {
+            let entries = submissions
+                .org_leaderboard(org, viewer, PaginationParams::default())
+                .await
+                .unwrap();
+            summarize(&entries)
+        }
+    };
+
+    // A member sees the private best, ranked against the public field
+    assert_eq!(leaderboard(org_a, Some(alice)).await, vec![(shared, 0.95, 1, 4)]);
+    // Outsiders only see the public best
+    assert_eq!(leaderboard(org_a, Some(bob)).await, vec![(shared, 0.90, 1, 3)]);
+    assert_eq!(leaderboard(org_a, None).await, vec![(shared, 0.90, 1, 3)]);
+
+    let mut b_member = leaderboard(org_b, Some(bob)).await;
+    b_member.sort_by_key(|(id, ..)| *id == b_only);
+    assert_eq!(b_member, vec![(shared, 0.80, 3, 3), (b_only, 0.70, 1, 1)]);
+    assert_eq!(leaderboard(org_b, Some(carol)).await, vec![(shared, 0.80, 3, 3)]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_leaderboard_ranks_private_submissions_only_for_their_owners() {
+    let db = common::connect().await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+
+    let alice = common::create_user(&db).await;
+    let bob = common::create_user(&db).await;
+    let carol = common::create_user(&db).await;
+    let org = create_org(&db, bob).await;
+    let benchmark = create_active_benchmark(&benchmarks, alice).await;
+
+    use SubmissionVisibility::{Private, Public, Unlisted};
+    submit_scored(&db, benchmark, alice, None, 0.95, Private).await;
+    submit_scored(&db, benchmark, bob, Some(org), 0.90, Unlisted).await;
+    submit_scored(&db, benchmark, carol, None, 0.80, Public).await;
+
+    let scores = |viewer, org: Option<OrganizationId>| {
+        let submissions = &submissions;
+        let orgs: Vec<_> = org.into_iter().collect();
+        async move {
+            submissions
+                .get_leaderboard(benchmark, None, 10, viewer, &orgs, VerificationLevel::Unverified)
+                .await
+                .unwrap()
+                .iter()
+                .map(|entry| (entry.aggregate_score, entry.rank))
+                .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(scores(None, None).await, vec![(0.80, 1)]);
+    assert_eq!(scores(Some(alice), None).await, vec![(0.95, 1), (0.80, 2)]);
+    assert_eq!(scores(Some(carol), Some(org)).await, vec![(0.90, 1), (0.80, 2)]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_leaderboard_filters_verification_before_limit() {
+    let db = common::connect().await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+
+    let alice = common::create_user(&db).await;
+    let benchmark = create_active_benchmark(&benchmarks, alice).await;
+
+    submit_scored(&db, benchmark, alice, None, 0.95, SubmissionVisibility::Public).await;
+    submit_scored(&db, benchmark, alice, None, 0.90, SubmissionVisibility::Public).await;
+    let mut verified = submission_for(&db, benchmark, alice).await;
+    let mut results = verified.results().clone();
+    results.aggregate_score = 0.70;
+    verified.set_results(results, None).unwrap();
+    let verifier = VerifiedBy::Platform { verification_id: VerificationId::new() };
+    verified
+        .verify(VerificationLevel::PlatformVerified, Some(verifier), None)
+        .unwrap();
+    save(&db, &verified).await;
+
+    // The two stronger unverified entries must not crowd out the verified one
+    let entries = submissions
+        .get_leaderboard(benchmark, None, 1, None, &[], VerificationLevel::PlatformVerified)
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].submission_id, verified.id);
+    assert_eq!(entries[0].rank, 1);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_list_filters_by_model_and_verification_level() {
+    let db = common::connect().await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+
+    let alice = common::create_user(&db).await;
+    let benchmark = create_active_benchmark(&benchmarks, alice).await;
+    let provider = format!("provider-{}", uuid::Uuid::new_v4().simple());
+
+    submit(&db, benchmark, alice, (&provider, "model", "1.0")).await;
+    submit(&db, benchmark, alice, (&provider, "other-model", "1.0")).await;
+    submit(&db, benchmark, alice, ("other-provider", "model", "1.0")).await;
+    let mut verified = submission_for(&db, benchmark, alice).await;
+    verified.model_info.provider = provider.clone();
+    verified.model_info.model_name = "model".to_string();
+    let verifier = VerifiedBy::Platform { verification_id: VerificationId::new() };
+    verified
+        .verify(VerificationLevel::PlatformVerified, Some(verifier), None)
+        .unwrap();
+    save(&db, &verified).await;
+
+    let list = |model_name: Option<&str>, level: Option<VerificationLevel>| {
+        let submissions = &submissions;
+        let query = SubmissionQuery {
+            benchmark_id: Some(benchmark),
+            model_provider: Some(provider.clone()),
+            model_name: model_name.map(str::to_string),
+            verification_level: level,
+            pagination: PaginationParams::new(1, 2),
+            ..Default::default()
+        };
+        async move { submissions.list(query).await.unwrap() }
+    };
+
+    // The count honours the filters, not just the page
+    let by_provider = list(None, None).await;
+    assert_eq!(by_provider.total, 3);
+    assert_eq!(by_provider.items.len(), 2);
+    assert!(by_provider.items.iter().all(|s| s.model_info.provider == provider));
+
+    let by_model = list(Some("model"), None).await;
+    assert_eq!(by_model.total, 2);
+    assert!(by_model.items.iter().all(|s| s.model_info.model_name == "model"));
+
+    // Levels at or above the requested one match
+    let by_level = list(Some("model"), Some(VerificationLevel::CommunityVerified)).await;
+    assert_eq!(by_level.total, 1);
+    assert_eq!(by_level.items[0].id, verified.id);
+}
+
+/// A public submission backdated by `age`
+async fn submit_aged(
+    db: &DatabasePool,
+    benchmark_id: BenchmarkId,
+    user_id: UserId,
+    age: chrono::Duration,
+) {
+    let submission = submission_for(db, benchmark_id, user_id).await;
+    let id = PgSubmissionRepository::new(db.pool().clone())
+        .create(&submission)
+        .await
+        .expect("Failed to create submission");
+    sqlx::query("UPDATE submissions SET created_at = $2 WHERE id = $1")
+        .bind(id.as_uuid())
+        .bind(chrono::Utc::now() - age)
+        .execute(db.pool())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_trending_benchmarks_favours_recent_activity() {
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+
+    // Many submissions, but mostly before the window
+    let established = create_active_benchmark(&benchmarks, user_id).await;
+    for _ in 0..6 {
+        submit_aged(&db, established, user_id, chrono::Duration::days(20)).await;
+    }
+    submit_aged(&db, established, user_id, chrono::Duration::days(6)).await;
+
+    // Fewer submissions, all within the last few hours
+    let rising = create_active_benchmark(&benchmarks, user_id).await;
+    for hours in [1, 2, 3] {
+        submit_aged(&db, rising, user_id, chrono::Duration::hours(hours)).await;
+    }
+
+    // Nothing within the window
+    let dormant = create_active_benchmark(&benchmarks, user_id).await;
+    submit_aged(&db, dormant, user_id, chrono::Duration::days(30)).await;
+
+    let window = std::time::Duration::from_secs(7 * 24 * 60 * 60);
+    let trending = submissions.trending_benchmarks(window, 1000).await.unwrap();
+    let ours: Vec<(BenchmarkId, f64)> = trending
+        .into_iter()
+        .filter(|(id, _)| [established, rising, dormant].contains(id))
+        .collect();
+
+    let ids: Vec<BenchmarkId> = ours.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, vec![rising, established]);
+    assert!(ours[0].1 > ours[1].1);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_find_by_content_hash() {
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+    let benchmark_id = create_active_benchmark(&benchmarks, user_id).await;
+
+    let original = submission_for(&db, benchmark_id, user_id).await;
+    let original_id = submissions.create(&original).await.unwrap();
+    let hash = content_hash(original.results()).unwrap();
+    let model = &original.model_info;
+
+    let found = submissions
+        .find_by_content_hash(
+            benchmark_id,
+            &model.provider,
+            &model.model_name,
+            model.model_version.as_deref(),
+            &hash,
+        )
+        .await
+        .unwrap();
+    assert_eq!(found, Some(original_id));
+
+    // Changing the results changes the hash
+    let mut changed = original.results().clone();
+    changed.aggregate_score += 0.001;
+    let changed_hash = content_hash(&changed).unwrap();
+    submissions.update_results(original_id, &changed).await.unwrap();
+
+    let lookup = |hash: String| {
+        let submissions = &submissions;
+        async move {
+            submissions
+                .find_by_content_hash(
+                    benchmark_id,
+                    &model.provider,
+                    &model.model_name,
+                    model.model_version.as_deref(),
+                    &hash,
+                )
+                .await
+                .unwrap()
+        }
+    };
+    assert_eq!(lookup(hash).await, None);
+    assert_eq!(lookup(changed_hash).await, Some(original_id));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_update_verification_rejects_downgrades_and_cancelled() {
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+    let benchmark_id = create_active_benchmark(&benchmarks, user_id).await;
+
+    let mut submission = submission_for(&db, benchmark_id, user_id).await;
+    let id = submissions.create(&submission).await.unwrap();
+    let verifier = VerifiedBy::Platform { verification_id: VerificationId::new() };
+    submission
+        .verify(VerificationLevel::PlatformVerified, Some(verifier), None)
+        .unwrap();
+    submissions
+        .update_verification(id, submission.verification_status(), None)
+        .await
+        .unwrap();
+
+    // Lowering the level is rejected and leaves the stored status alone
+    let mut lowered = submission.verification_status().clone();
+    lowered.level = VerificationLevel::CommunityVerified;
+    let err = submissions.update_verification(id, &lowered, None).await.unwrap_err();
+    assert!(matches!(err, Error::Conflict(_)));
+    let stored = submissions.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(stored.verification_status().level, VerificationLevel::PlatformVerified);
+
+    // Cancelled submissions cannot be verified
+    let pending = submission_for(&db, benchmark_id, user_id).await;
+    let pending_id = submissions.create(&pending).await.unwrap();
+    submissions.cancel(pending_id).await.unwrap().unwrap();
+    let err = submissions
+        .update_verification(pending_id, submission.verification_status(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Conflict(_)));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_concurrent_creates_keep_exact_submission_count() {
+    const SUBMISSIONS: usize = 32;
+
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let benchmarks = PgBenchmarkRepository::new(db.pool().clone());
+    let benchmark_id = create_active_benchmark(&benchmarks, user_id).await;
+    let template = submission_for(&db, benchmark_id, user_id).await;
+
+    let creates = (0..SUBMISSIONS).map(|_| {
+        let submissions = PgSubmissionRepository::new(db.pool().clone());
+        let submission = template.clone();
+        tokio::spawn(async move { submissions.create(&submission).await.unwrap() })
+    });
+    let ids: Vec<_> = futures::future::try_join_all(creates).await.unwrap();
+
+    let count = || async {
+        benchmarks
+            .get_by_id(benchmark_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .submission_count
+    };
+    assert_eq!(count().await, SUBMISSIONS as u64);
+
+    // Deletes give the count back, including concurrent ones
+    let deletes = ids[..SUBMISSIONS / 2].iter().map(|id| {
+        let submissions = PgSubmissionRepository::new(db.pool().clone());
+        let id = *id;
+        tokio::spawn(async move { submissions.delete(id).await.unwrap() })
+    });
+    let deleted = futures::future::try_join_all(deletes).await.unwrap();
+    assert!(deleted.into_iter().all(|d| d));
+    assert_eq!(count().await, (SUBMISSIONS / 2) as u64);
+
+    // Deleting a missing submission leaves the count alone
+    let submissions = PgSubmissionRepository::new(db.pool().clone());
+    assert!(!submissions.delete(ids[0]).await.unwrap());
+    assert_eq!(count().await, (SUBMISSIONS / 2) as u64);
+
+    assert_eq!(
+        submissions.delete_by_user(user_id).await.unwrap(),
+        (SUBMISSIONS / 2) as u64
+    );
+    assert_eq!(count().await, 0);
+
+    // The counter never goes negative
+    assert_eq!(benchmarks.decrement_submission_count(benchmark_id).await.unwrap(), 0);
+    assert_eq!(benchmarks.increment_submission_count(benchmark_id).await.unwrap(), 1);
+}
@@ -0,0 +1,114 @@
+//! Integration tests for upserting benchmark test cases
+//!
+//! These tests require a migrated PostgreSQL database and are marked with #[ignore].
+//! Run with: TEST_DATABASE_URL=postgres://... cargo test --test test_case_upsert_tests -- --ignored
+
+mod common;
+
+use chrono::{DateTime, Utc};
+use common::{benchmark_record, test_case};
+use llm_benchmark_domain::identifiers::BenchmarkVersionId;
+use llm_benchmark_infrastructure::{
+    BenchmarkRepository, DatabasePool, PgBenchmarkRepository, TestCaseUpsertSummary,
+};
+use std::collections::HashMap;
+
+async fn setup() -> (DatabasePool, PgBenchmarkRepository, BenchmarkVersionId) {
+    let db = common::connect().await;
+    let user_id = common::create_user(&db).await;
+    let record = benchmark_record(
+        user_id,
+        vec![test_case("a", "A?"), test_case("b", "B?"), test_case("c", "C?")],
+    );
+
+    let repo = PgBenchmarkRepository::new(db.pool().clone());
+    let id = repo.create(&record).await.expect("Failed to create benchmark");
+    let version_id = repo.get_by_id(id).await.unwrap().unwrap().version_id;
+
+    (db, repo, version_id)
+}
+
+async fn updated_at(db: &DatabasePool, version_id: BenchmarkVersionId) -> HashMap<String, DateTime<Utc>> {
+    sqlx::query_as("SELECT case_id, updated_at FROM test_cases WHERE benchmark_version_id = $1")
+        .bind(version_id.as_uuid())
+        .fetch_all(db.pool())
+        .await
+        .unwrap()
+        .into_iter()
+        .collect()
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_upsert_updates_only_the_edited_case() {
+    let (db, repo, version_id) = setup().await;
+    let before = updated_at(&db, version_id).await;
+
+    let summary = repo
+        .upsert_test_cases(
+            version_id,
+            &[test_case("a", "A?"), test_case("b", "B, edited?"), test_case("c", "C?")],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        summary,
+        TestCaseUpsertSummary {
+            inserted: 0,
+            updated: 1,
+            unchanged: 2,
+            deleted: 0,
+        }
+    );
+
+    let after = updated_at(&db, version_id).await;
+    assert_eq!(after["a"], before["a"]);
+    assert!(after["b"] > before["b"]);
+    assert_eq!(after["c"], before["c"]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_upsert_inserts_and_deletes_cases() {
+    let (db, repo, version_id) = setup().await;
+    let before = updated_at(&db, version_id).await;
+
+    let summary = repo
+        .upsert_test_cases(version_id, &[test_case("a", "A?"), test_case("d", "D?")])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        summary,
+        TestCaseUpsertSummary {
+            inserted: 1,
+            updated: 0,
+            unchanged: 1,
+            deleted: 2,
+        }
+    );
+
+    let after = updated_at(&db, version_id).await;
+    assert_eq!(after.len(), 2);
+    assert_eq!(after["a"], before["a"]);
+    assert!(after.contains_key("d"));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_upsert_without_changes_writes_nothing() {
+    let (db, repo, version_id) = setup().await;
+    let before = updated_at(&db, version_id).await;
+
+    let summary = repo
+        .upsert_test_cases(
+            version_id,
+            &[test_case("a", "A?"), test_case("b", "B?"), test_case("c", "C?")],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(summary.unchanged, 3);
+    assert_eq!(updated_at(&db, version_id).await, before);
+}
@@ -5,21 +5,193 @@
 use crate::config::ClientConfig;
 use crate::error::{SdkError, SdkResult};
 use crate::services::{BenchmarkService, GovernanceService, LeaderboardService, SubmissionService};
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+use reqwest::header::{
+    HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER,
+    USER_AGENT,
+};
+use reqwest::StatusCode;
 use serde::{de::DeserializeOwned, Serialize};
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use tracing::{debug, error};
 
 /// Main SDK client
 #[derive(Clone)]
 pub struct Client {
     inner: Arc<ClientInner>,
+    /// Overrides the configured timeout for requests made through this handle
+    timeout: Option<Duration>,
 }
 
 struct ClientInner {
     http: reqwest::Client,
     config: ClientConfig,
+    breaker: CircuitBreaker,
+    etags: Option<EtagCache>,
+}
+
+/// State of the client's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent normally
+    Closed,
+    /// Requests fail fast with [`SdkError::ServiceUnavailable`]
+    Open,
+    /// The cooldown has elapsed and the next request probes the service
+    HalfOpen,
+}
+
+/// Fails requests fast after consecutive server or connection failures
+///
+/// After `threshold` consecutive failures the circuit opens for `cooldown`.
+/// Once it elapses a single probe request is let through: success closes the
+/// circuit, failure opens it for another cooldown.
+struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    fn state(&self) -> CircuitState {
+        self.state_of(&self.state.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn state_of(&self, state: &BreakerState) -> CircuitState {
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(at) if at.elapsed() >= self.cooldown => CircuitState::HalfOpen,
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    /// Admit a request, or fail fast while the circuit is open
+    ///
+    /// The returned permit records the request's outcome; dropping it
+    /// unrecorded (e.g. when the request future is cancelled) releases a
+    /// half-open probe so a later request can probe instead.
+    fn acquire(&self) -> SdkResult<BreakerPermit<'_>> {
+        if self.threshold == 0 {
+            return Ok(BreakerPermit::new(self, false));
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match self.state_of(&state) {
+            CircuitState::Closed => Ok(BreakerPermit::new(self, false)),
+            CircuitState::HalfOpen if !state.probing => {
+                state.probing = true;
+                Ok(BreakerPermit::new(self, true))
+            }
+            _ => Err(SdkError::ServiceUnavailable {
+                message: format!(
+                    "Circuit breaker is open after {} consecutive failures",
+                    state.consecutive_failures
+                ),
+            }),
+        }
+    }
+
+    /// Record the outcome of an admitted request
+    fn record(&self, healthy: bool) {
+        if self.threshold == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if healthy {
+            *state = BreakerState::default();
+            return;
+        }
+
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.probing || state.consecutive_failures >= self.threshold {
+            state.opened_at = Some(Instant::now());
+            state.probing = false;
+        }
+    }
+
+    /// Release a probe whose request never completed
+    fn abandon_probe(&self) {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).probing = false;
+    }
+}
+
+/// A request admitted by the circuit breaker
+struct BreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    recorded: bool,
+}
+
+impl<'a> BreakerPermit<'a> {
+    fn new(breaker: &'a CircuitBreaker, probe: bool) -> Self {
+        Self {
+            breaker,
+            probe,
+            recorded: false,
+        }
+    }
+
+    /// Record the outcome of the admitted request
+    fn record(mut self, healthy: bool) {
+        self.recorded = true;
+        self.breaker.record(healthy);
+    }
+}
+
+impl Drop for BreakerPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.abandon_probe();
+        }
+    }
+}
+
+/// GET response bodies keyed by request URL, revalidated by ETag
+///
+/// Bodies are kept as received, so a `304 Not Modified` is answered by
+/// deserializing the cached body exactly as the original response was.
+#[derive(Default)]
+struct EtagCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+struct CachedResponse {
+    etag: HeaderValue,
+    body: String,
+}
+
+impl EtagCache {
+    fn etag(&self, url: &str) -> Option<HeaderValue> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.get(url).map(|cached| cached.etag.clone())
+    }
+
+    fn body(&self, url: &str) -> Option<String> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.get(url).map(|cached| cached.body.clone())
+    }
+
+    fn store(&self, url: String, etag: HeaderValue, body: String) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.insert(url, CachedResponse { etag, body });
+    }
 }
 
 impl Client {
@@ -58,8 +230,18 @@ impl Client {
                 message: format!("Failed to create HTTP client: {}", e),
             })?;
 
+        let breaker =
+            CircuitBreaker::new(config.circuit_breaker_threshold, config.circuit_breaker_cooldown);
+        let etags = config.etag_cache.then(EtagCache::default);
+
         Ok(Self {
-            inner: Arc::new(ClientInner { http, config }),
+            inner: Arc::new(ClientInner {
+                http,
+                config,
+                breaker,
+                etags,
+            }),
+            timeout: None,
         })
     }
 
@@ -74,6 +256,27 @@ impl Client {
         &self.inner.config
     }
 
+    /// Current state of the circuit breaker
+    pub fn circuit_state(&self) -> CircuitState {
+        self.inner.breaker.state()
+    }
+
+    /// A handle whose requests use `timeout` instead of the configured one
+    ///
+    /// The handle shares this client's connection pool and circuit breaker;
+    /// this client keeps its own timeout.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            timeout: Some(timeout),
+        }
+    }
+
+    /// Timeout applied to requests made through this handle
+    pub fn timeout(&self) -> Duration {
+        self.timeout.unwrap_or(self.inner.config.timeout)
+    }
+
     /// Get the benchmark service
     pub fn benchmarks(&self) -> BenchmarkService {
         BenchmarkService::new(self.clone())
@@ -108,15 +311,12 @@ impl Client {
     ) -> SdkResult<T> {
         let url = format!("{}{}", self.inner.config.base_url, path);
 
-        let response = self
-            .inner
-            .http
-            .get(&url)
-            .query(query)
-            .send()
-            .await?;
+        let request = self.http_request(reqwest::Method::GET, &url).query(query);
+        let (request, cache_key) = self.conditional(request);
+
+        let response = self.send(request).await?;
 
-        self.handle_response(response).await
+        self.handle_response(response, cache_key).await
     }
 
     /// Make a POST request
@@ -150,7 +350,7 @@ impl Client {
     pub(crate) async fn delete(&self, path: &str) -> SdkResult<()> {
         let url = format!("{}{}", self.inner.config.base_url, path);
 
-        let response = self.inner.http.delete(&url).send().await?;
+        let response = self.send(self.http_request(reqwest::Method::DELETE, &url)).await?;
 
         if response.status().is_success() {
             Ok(())
@@ -172,15 +372,72 @@ impl Client {
             debug!("SDK request: {} {}", method, url);
         }
 
-        let mut request = self.inner.http.request(method.clone(), &url);
+        let mut request = self.http_request(method.clone(), &url);
+        let mut cache_key = None;
 
         if let Some(body) = body {
             request = request.json(body);
+        } else if method == reqwest::Method::GET {
+            (request, cache_key) = self.conditional(request);
         }
 
         let response = self.execute_with_retry(request, &method, &url).await?;
 
-        self.handle_response(response).await
+        self.handle_response(response, cache_key).await
+    }
+
+    /// Start a request, applying this handle's timeout override
+    fn http_request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        let request = self.inner.http.request(method, url);
+        match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
+    }
+
+    /// Attach `If-None-Match` to a GET whose response is cached
+    ///
+    /// Returns the cache key, the full request URL, when the ETag cache is
+    /// enabled.
+    fn conditional(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> (reqwest::RequestBuilder, Option<String>) {
+        let Some(etags) = &self.inner.etags else {
+            return (request, None);
+        };
+        let Some(url) = request
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .map(|r| r.url().to_string())
+        else {
+            return (request, None);
+        };
+
+        match etags.etag(&url) {
+            Some(etag) => (request.header(IF_NONE_MATCH, etag), Some(url)),
+            None => (request, Some(url)),
+        }
+    }
+
+    /// Send a request through the circuit breaker
+    ///
+    /// 5xx responses and connection failures count against the breaker; any
+    /// other response closes it.
+    async fn send(&self, request: reqwest::RequestBuilder) -> SdkResult<reqwest::Response> {
+        let permit = self.inner.breaker.acquire()?;
+
+        match request.send().await {
+            Ok(response) => {
+                permit.record(!response.status().is_server_error());
+                Ok(response)
+            }
+            Err(e) => {
+                let err: SdkError = e.into();
+                permit.record(!err.is_retryable());
+                Err(err)
+            }
+        }
     }
 
     /// Execute request with retry logic
@@ -193,6 +450,7 @@ impl Client {
         let max_retries = self.inner.config.retry_count;
         let mut attempt = 0;
         let mut last_error: Option<SdkError> = None;
+        let mut retry_after: Option<Duration>;
 
         loop {
             attempt += 1;
@@ -205,7 +463,7 @@ impl Client {
                     source: None,
                 })?;
 
-            match request_clone.send().await {
+            match self.send(request_clone).await {
                 Ok(response) => {
                     if response.status().is_success() || !is_retryable_status(response.status()) {
                         return Ok(response);
@@ -216,26 +474,29 @@ impl Client {
                         return Ok(response); // Let handle_response deal with the error
                     }
 
+                    retry_after = parse_retry_after(response.headers()).map(Duration::from_secs);
+
                     last_error = Some(SdkError::ServerError {
                         status_code: response.status().as_u16(),
                         message: format!("Request failed with status {}", response.status()),
                     });
                 }
-                Err(e) => {
-                    let err: SdkError = e.into();
+                Err(err) => {
                     if !err.is_retryable() || attempt > max_retries {
                         return Err(err);
                     }
                     last_error = Some(err);
+                    retry_after = None;
                 }
             }
 
-            // Calculate backoff
+            // Calculate backoff, never retrying sooner than the server asked
             let backoff = calculate_backoff(
                 attempt,
                 self.inner.config.retry_initial_backoff,
                 self.inner.config.retry_max_backoff,
-            );
+            )
+            .max(retry_after.unwrap_or_default());
 
             if self.inner.config.debug {
                 debug!(
@@ -249,32 +510,61 @@ impl Client {
     }
 
     /// Handle successful response
+    ///
+    /// With a `cache_key`, a `304 Not Modified` is answered from the ETag
+    /// cache and successful responses carrying an ETag are stored in it.
     async fn handle_response<T: DeserializeOwned>(
         &self,
         response: reqwest::Response,
+        cache_key: Option<String>,
     ) -> SdkResult<T> {
+        let etags = self.inner.etags.as_ref().zip(cache_key);
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(text) = etags.as_ref().and_then(|(etags, url)| etags.body(url)) {
+                if self.inner.config.debug {
+                    debug!("SDK response not modified, using cached body");
+                }
+                return self.parse_body(&text);
+            }
+        }
+
         if response.status().is_success() {
+            let etag = response.headers().get(ETAG).cloned();
             let text = response.text().await?;
 
             if self.inner.config.debug {
                 debug!("SDK response body: {}", text);
             }
 
-            serde_json::from_str(&text).map_err(|e| {
-                error!("Failed to parse response: {}", e);
-                SdkError::InvalidResponse {
-                    message: format!("Failed to parse response: {}", e),
-                }
-            })
+            let value = self.parse_body(&text)?;
+            if let (Some((etags, url)), Some(etag)) = (etags, etag) {
+                etags.store(url, etag, text);
+            }
+            Ok(value)
         } else {
             Err(self.handle_error_response(response).await)
         }
     }
 
+    /// Deserialize a successful response body
+    fn parse_body<T: DeserializeOwned>(&self, text: &str) -> SdkResult<T> {
+        #[cfg(all(feature = "validate-responses", debug_assertions))]
+        crate::validation::validate_response::<T>(text)?;
+
+        serde_json::from_str(text).map_err(|e| {
+            error!("Failed to parse response: {}", e);
+            SdkError::InvalidResponse {
+                message: format!("Failed to parse response: {}", e),
+            }
+        })
+    }
+
     /// Handle error response
     async fn handle_error_response(&self, response: reqwest::Response) -> SdkError {
         let status = response.status();
         let status_code = status.as_u16();
+        let retry_after = parse_retry_after(response.headers());
 
         // Try to parse error body
         let body = response.text().await.unwrap_or_default();
@@ -301,21 +591,14 @@ impl Client {
                 409 => SdkError::Conflict {
                     message: api_error.message,
                 },
-                422 | 400 => SdkError::ValidationError {
-                    message: api_error.message,
-                    field_errors: api_error
-                        .field_errors
-                        .unwrap_or_default()
-                        .into_iter()
-                        .map(|f| crate::error::FieldError::new(f.field, f.message))
-                        .collect(),
-                },
-                429 => SdkError::RateLimited { retry_after: None },
+                429 => SdkError::RateLimited { retry_after },
                 500..=599 => SdkError::ServerError {
                     status_code,
                     message: api_error.message,
                 },
-                _ => SdkError::ApiError {
+                _ => SdkError::Api {
+                    status: status_code,
+                    field_errors: api_error.field_errors(),
                     code: api_error.code.unwrap_or_else(|| status_code.to_string()),
                     message: api_error.message,
                     details: api_error.details,
@@ -323,7 +606,7 @@ impl Client {
             };
         }
 
-        // Fallback to generic error
+        // Not an error envelope, e.g. a proxy's HTML error page
         match status_code {
             401 => SdkError::Unauthorized {
                 message: "Unauthorized".to_string(),
@@ -337,7 +620,7 @@ impl Client {
                 resource_type: "Resource".to_string(),
                 resource_id: "unknown".to_string(),
             },
-            429 => SdkError::RateLimited { retry_after: None },
+            429 => SdkError::RateLimited { retry_after },
             _ => SdkError::ServerError {
                 status_code,
                 message: body,
@@ -350,15 +633,43 @@ impl Client {
 #[derive(Debug, serde::Deserialize)]
 struct ApiErrorResponse {
     message: String,
+    /// The API sends the error code as `error`
+    #[serde(alias = "error")]
     code: Option<String>,
     details: Option<serde_json::Value>,
     field_errors: Option<Vec<ApiFieldError>>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+impl ApiErrorResponse {
+    /// Field errors from the top level of the body or from `details.field_errors`
+    fn field_errors(&self) -> Vec<crate::error::FieldError> {
+        let nested = || {
+            self.details
+                .as_ref()?
+                .get("field_errors")
+                .cloned()
+                .and_then(|value| serde_json::from_value::<Vec<ApiFieldError>>(value).ok())
+        };
+
+        self.field_errors
+            .clone()
+            .or_else(nested)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|f| crate::error::FieldError {
+                field: f.field,
+                message: f.message,
+                code: f.code,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
 struct ApiFieldError {
     field: String,
     message: String,
+    code: Option<String>,
 }
 
 /// Check if a status code is retryable
@@ -366,8 +677,21 @@ fn is_retryable_status(status: reqwest::StatusCode) -> bool {
     status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
 }
 
+/// Parse a `Retry-After` header given either as delta-seconds or an HTTP date
+fn parse_retry_after(headers: &HeaderMap) -> Option<u64> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = (at.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds();
+    Some(seconds.max(0) as u64)
+}
+
 /// Calculate exponential backoff
-fn calculate_backoff(attempt: u32, initial: Duration, max: Duration) -> Duration {
+pub(crate) fn calculate_backoff(attempt: u32, initial: Duration, max: Duration) -> Duration {
     let backoff = initial.saturating_mul(2u32.saturating_pow(attempt - 1));
     backoff.min(max)
 }
@@ -428,6 +752,25 @@ impl ClientBuilder {
         self
     }
 
+    /// Enable the circuit breaker with a failure threshold and cooldown
+    ///
+    /// The breaker is off by default; a threshold of `0` disables it.
+    pub fn circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.config.circuit_breaker_threshold = threshold;
+        self.config.circuit_breaker_cooldown = cooldown;
+        self
+    }
+
+    /// Enable or disable the ETag response cache
+    ///
+    /// When enabled, GET responses carrying an `ETag` are cached by URL and
+    /// later GETs of the same URL send `If-None-Match`; a `304 Not Modified`
+    /// returns the cached value.
+    pub fn etag_cache(mut self, enabled: bool) -> Self {
+        self.config.etag_cache = enabled;
+        self
+    }
+
     /// Enable debug mode
     pub fn debug(mut self, debug: bool) -> Self {
         self.config.debug = debug;
@@ -460,6 +803,113 @@ mod tests {
         assert_eq!(client.config().retry_count, 5);
     }
 
+    #[tokio::test]
+    async fn test_api_error_keeps_field_errors() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/benchmarks"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "error": "VALIDATION_FAILED",
+                "message": "Validation failed",
+                "details": {
+                    "field_errors": [
+                        { "field": "slug", "message": "Slug is already taken", "code": "DUPLICATE" },
+                        { "field": "version", "message": "Invalid semantic version" }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder().base_url(server.uri()).build().unwrap();
+        let err = client
+            .post::<serde_json::Value, _>("/api/v1/benchmarks", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.status_code(), Some(422));
+        assert_eq!(err.field_errors().len(), 2);
+        match err {
+            SdkError::Api { status, code, message, field_errors, details } => {
+                assert_eq!(status, 422);
+                assert_eq!(code, "VALIDATION_FAILED");
+                assert_eq!(message, "Validation failed");
+                assert!(details.is_some());
+                assert_eq!(field_errors.len(), 2);
+                assert_eq!(field_errors[0].field, "slug");
+                assert_eq!(field_errors[0].code.as_deref(), Some("DUPLICATE"));
+                assert_eq!(field_errors[1].field, "version");
+                assert_eq!(field_errors[1].message, "Invalid semantic version");
+                assert!(field_errors[1].code.is_none());
+            }
+            other => panic!("expected API error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_json_error_body_falls_back_to_server_error() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("<html>Bad Gateway</html>"))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.uri())
+            .retry_count(0)
+            .build()
+            .unwrap();
+        let err = client
+            .get::<serde_json::Value>("/api/v1/benchmarks")
+            .await
+            .unwrap_err();
+
+        match err {
+            SdkError::ServerError { status_code, message } => {
+                assert_eq!(status_code, 500);
+                assert_eq!(message, "<html>Bad Gateway</html>");
+            }
+            other => panic!("expected server error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_body_field_errors() {
+        let body: ApiErrorResponse = serde_json::from_str(
+            r#"{"message": "Invalid", "code": "BAD", "field_errors": [{"field": "name", "message": "Required"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(body.code.as_deref(), Some("BAD"));
+        assert_eq!(body.field_errors()[0].field, "name");
+
+        let body: ApiErrorResponse =
+            serde_json::from_str(r#"{"error": "VALIDATION_ERROR", "message": "Invalid"}"#).unwrap();
+        assert_eq!(body.code.as_deref(), Some("VALIDATION_ERROR"));
+        assert!(body.field_errors().is_empty());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(parse_retry_after(&headers), Some(120));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(parse_retry_after(&headers), Some(0));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
     #[test]
     fn test_calculate_backoff() {
         let initial = Duration::from_millis(100);
@@ -470,4 +920,218 @@ mod tests {
         assert_eq!(calculate_backoff(3, initial, max), Duration::from_millis(400));
         assert_eq!(calculate_backoff(10, initial, max), max); // Capped at max
     }
+
+    async fn mount_status(server: &wiremock::MockServer, status: u16) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        server.reset().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/health"))
+            .respond_with(ResponseTemplate::new(status).set_body_json(serde_json::json!({
+                "message": "status"
+            })))
+            .mount(server)
+            .await;
+    }
+
+    fn breaker_client(server: &wiremock::MockServer) -> Client {
+        Client::builder()
+            .base_url(server.uri())
+            .retry_count(0)
+            .circuit_breaker(3, Duration::from_millis(200))
+            .build()
+            .unwrap()
+    }
+
+    async fn health(client: &Client) -> SdkResult<serde_json::Value> {
+        client.get("/api/v1/health").await
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_trips_and_half_opens() {
+        let server = wiremock::MockServer::start().await;
+        mount_status(&server, 503).await;
+        let client = breaker_client(&server);
+
+        for _ in 0..3 {
+            assert_eq!(client.circuit_state(), CircuitState::Closed);
+            let err = health(&client).await.unwrap_err();
+            assert!(matches!(err, SdkError::ServerError { status_code: 503, .. }));
+        }
+        assert_eq!(client.circuit_state(), CircuitState::Open);
+
+        // Open circuits fail fast without reaching the server
+        let err = health(&client).await.unwrap_err();
+        assert!(matches!(err, SdkError::ServiceUnavailable { .. }), "{:?}", err);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(client.circuit_state(), CircuitState::HalfOpen);
+
+        mount_status(&server, 200).await;
+        health(&client).await.unwrap();
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_failed_probe_reopens_circuit() {
+        let server = wiremock::MockServer::start().await;
+        mount_status(&server, 500).await;
+        let client = breaker_client(&server);
+
+        for _ in 0..3 {
+            health(&client).await.unwrap_err();
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(client.circuit_state(), CircuitState::HalfOpen);
+
+        let err = health(&client).await.unwrap_err();
+        assert!(matches!(err, SdkError::ServerError { status_code: 500, .. }));
+        assert_eq!(client.circuit_state(), CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_do_not_trip_circuit() {
+        let server = wiremock::MockServer::start().await;
+        mount_status(&server, 404).await;
+        let client = breaker_client(&server);
+
+        for _ in 0..5 {
+            let err = health(&client).await.unwrap_err();
+            assert!(matches!(err, SdkError::NotFound { .. }));
+        }
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_connection_failures_trip_circuit() {
+        let client = Client::builder()
+            .base_url("http://127.0.0.1:1")
+            .retry_count(0)
+            .circuit_breaker(2, Duration::from_secs(60))
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let err = health(&client).await.unwrap_err();
+            assert!(matches!(err, SdkError::NetworkError { .. }), "{:?}", err);
+        }
+        assert_eq!(client.circuit_state(), CircuitState::Open);
+        assert!(matches!(
+            health(&client).await.unwrap_err(),
+            SdkError::ServiceUnavailable { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_probe_releases_half_open_circuit() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = wiremock::MockServer::start().await;
+        mount_status(&server, 503).await;
+        let client = breaker_client(&server);
+
+        for _ in 0..3 {
+            health(&client).await.unwrap_err();
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(client.circuit_state(), CircuitState::HalfOpen);
+
+        // The probe hangs and its caller gives up on it
+        server.reset().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/health"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+        let probe = tokio::time::timeout(Duration::from_millis(50), health(&client)).await;
+        assert!(probe.is_err());
+
+        server.reset().await;
+        mount_status(&server, 200).await;
+        health(&client).await.unwrap();
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_breaker_is_off_by_default() {
+        let client = Client::builder().build().unwrap();
+        assert_eq!(client.inner.breaker.threshold, 0);
+    }
+
+    async fn etag_server() -> wiremock::MockServer {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/mmlu"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/mmlu"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!({ "slug": "mmlu", "version": "1.0.0" })),
+            )
+            .mount(&server)
+            .await;
+        server
+    }
+
+    async fn if_none_match_headers(server: &wiremock::MockServer) -> Vec<Option<String>> {
+        server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| {
+                r.headers
+                    .get(&"if-none-match".into())
+                    .map(|v| v.last().as_str().to_string())
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_etag_cache_serves_not_modified_from_cache() {
+        let server = etag_server().await;
+        let client = Client::builder()
+            .base_url(server.uri())
+            .etag_cache(true)
+            .build()
+            .unwrap();
+
+        let first: serde_json::Value = client.get("/api/v1/benchmarks/mmlu").await.unwrap();
+        let second: serde_json::Value = client.get("/api/v1/benchmarks/mmlu").await.unwrap();
+        let third: serde_json::Value = client
+            .get_with_query("/api/v1/benchmarks/mmlu", &[("", ""); 0])
+            .await
+            .unwrap();
+
+        assert_eq!(first["version"], "1.0.0");
+        assert_eq!(second, first);
+        assert_eq!(third, first);
+        assert_eq!(
+            if_none_match_headers(&server).await,
+            [None, Some("\"v1\"".to_string()), Some("\"v1\"".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_etag_cache_disabled_by_default() {
+        let server = etag_server().await;
+        let client = Client::builder().base_url(server.uri()).build().unwrap();
+
+        for _ in 0..2 {
+            let value: serde_json::Value = client.get("/api/v1/benchmarks/mmlu").await.unwrap();
+            assert_eq!(value["slug"], "mmlu");
+        }
+        assert_eq!(if_none_match_headers(&server).await, [None, None]);
+    }
 }
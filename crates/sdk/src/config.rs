@@ -29,6 +29,16 @@ pub struct ClientConfig {
     /// Maximum backoff duration for retries
     pub retry_max_backoff: Duration,
 
+    /// Consecutive server or connection failures that open the circuit
+    /// breaker; `0` (the default) disables it
+    pub circuit_breaker_threshold: u32,
+
+    /// How long an open circuit fails fast before letting a probe through
+    pub circuit_breaker_cooldown: Duration,
+
+    /// Cache GET responses by ETag and revalidate them with `If-None-Match`
+    pub etag_cache: bool,
+
     /// User agent string
     pub user_agent: String,
 
@@ -46,6 +56,9 @@ impl Default for ClientConfig {
             retry_count: 3,
             retry_initial_backoff: Duration::from_millis(100),
             retry_max_backoff: Duration::from_secs(10),
+            circuit_breaker_threshold: 0,
+            circuit_breaker_cooldown: Duration::from_secs(30),
+            etag_cache: false,
             user_agent: format!("llm-benchmark-sdk/{}", crate::VERSION),
             debug: false,
         }
@@ -125,6 +138,19 @@ impl ClientConfig {
         self
     }
 
+    /// Set the circuit breaker's failure threshold and cooldown
+    pub fn with_circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker_threshold = threshold;
+        self.circuit_breaker_cooldown = cooldown;
+        self
+    }
+
+    /// Enable or disable the ETag response cache
+    pub fn with_etag_cache(mut self, enabled: bool) -> Self {
+        self.etag_cache = enabled;
+        self
+    }
+
     /// Enable debug mode
     pub fn with_debug(mut self, debug: bool) -> Self {
         self.debug = debug;
@@ -175,6 +201,9 @@ mod tests {
         assert!(config.api_key.is_none());
         assert_eq!(config.timeout, Duration::from_secs(30));
         assert_eq!(config.retry_count, 3);
+        assert_eq!(config.circuit_breaker_threshold, 0);
+        assert_eq!(config.circuit_breaker_cooldown, Duration::from_secs(30));
+        assert!(!config.etag_cache);
     }
 
     #[test]
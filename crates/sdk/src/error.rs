@@ -38,7 +38,10 @@ pub enum SdkError {
         resource_id: String,
     },
 
-    /// Request validation failed
+    /// Request failed client-side validation
+    ///
+    /// Validation failures reported by the API are [`SdkError::Api`] errors
+    /// with status `400` or `422`.
     #[error("Validation failed: {message}")]
     ValidationError {
         /// Error message
@@ -94,6 +97,13 @@ pub enum SdkError {
         message: String,
     },
 
+    /// The client's circuit breaker is open after repeated failures
+    #[error("Service unavailable: {message}")]
+    ServiceUnavailable {
+        /// Error message
+        message: String,
+    },
+
     /// Configuration error
     #[error("Configuration error: {message}")]
     ConfigError {
@@ -112,6 +122,7 @@ pub enum SdkError {
     },
 
     /// Generic API error
+    #[deprecated(note = "the client returns `SdkError::Api` instead")]
     #[error("API error: {code} - {message}")]
     ApiError {
         /// Error code
@@ -121,6 +132,24 @@ pub enum SdkError {
         /// Additional details
         details: Option<serde_json::Value>,
     },
+
+    /// Structured error returned by the API
+    ///
+    /// Used for error envelopes without a more specific variant, including
+    /// `400` and `422` validation failures.
+    #[error("API error ({status}): {code} - {message}")]
+    Api {
+        /// HTTP status code
+        status: u16,
+        /// Error code, e.g. `VALIDATION_FAILED`
+        code: String,
+        /// Error message
+        message: String,
+        /// Field-specific errors
+        field_errors: Vec<FieldError>,
+        /// Additional details
+        details: Option<serde_json::Value>,
+    },
 }
 
 impl SdkError {
@@ -136,19 +165,30 @@ impl SdkError {
     }
 
     /// Get the HTTP status code if available
+    ///
+    /// Client-side errors such as [`SdkError::ValidationError`] have none.
     pub fn status_code(&self) -> Option<u16> {
         match self {
             SdkError::Unauthorized { status_code, .. } => Some(*status_code),
             SdkError::ServerError { status_code, .. } => Some(*status_code),
             SdkError::NotFound { .. } => Some(404),
             SdkError::Forbidden { .. } => Some(403),
-            SdkError::ValidationError { .. } => Some(400),
             SdkError::Conflict { .. } => Some(409),
             SdkError::RateLimited { .. } => Some(429),
+            SdkError::Api { status, .. } => Some(*status),
             _ => None,
         }
     }
 
+    /// Field-level errors of a validation failure; empty for other errors
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            SdkError::ValidationError { field_errors, .. } => field_errors,
+            SdkError::Api { field_errors, .. } => field_errors,
+            _ => &[],
+        }
+    }
+
     /// Create a not found error
     pub fn not_found(resource_type: impl Into<String>, resource_id: impl Into<String>) -> Self {
         SdkError::NotFound {
@@ -328,6 +368,18 @@ mod tests {
             .status_code(),
             None
         );
+        assert_eq!(SdkError::validation("Missing model name").status_code(), None);
+        assert_eq!(
+            SdkError::Api {
+                status: 422,
+                code: "VALIDATION_FAILED".to_string(),
+                message: "".to_string(),
+                field_errors: vec![],
+                details: None,
+            }
+            .status_code(),
+            Some(422)
+        );
     }
 
     #[test]
@@ -339,5 +391,9 @@ mod tests {
 
         let err = FieldError::with_code("email", "Invalid email format", "INVALID_EMAIL");
         assert_eq!(err.code, Some("INVALID_EMAIL".to_string()));
+
+        let err = SdkError::validation_with_fields("Invalid", vec![err]);
+        assert_eq!(err.field_errors()[0].field, "email");
+        assert!(SdkError::validation("Invalid").field_errors().is_empty());
     }
 }
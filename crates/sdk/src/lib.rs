@@ -72,6 +72,13 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Response Validation
+//!
+//! Enabling the `validate-responses` feature checks API responses against
+//! JSON schemas bundled with the SDK in debug builds, returning
+//! `SdkError::InvalidResponse` when the API's response shape has drifted from
+//! the SDK models. Validation is compiled out of release builds.
 
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
@@ -81,16 +88,19 @@ pub mod config;
 pub mod error;
 pub mod models;
 pub mod services;
+mod pagination;
+#[cfg(all(feature = "validate-responses", debug_assertions))]
+mod validation;
 
 // Re-exports
-pub use client::{Client, ClientBuilder};
+pub use client::{CircuitState, Client, ClientBuilder};
 pub use config::ClientConfig;
 pub use error::{SdkError, SdkResult};
 pub use models::*;
 
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::client::{Client, ClientBuilder};
+    pub use crate::client::{CircuitState, Client, ClientBuilder};
     pub use crate::config::ClientConfig;
     pub use crate::error::{SdkError, SdkResult};
     pub use crate::models::*;
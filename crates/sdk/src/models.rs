@@ -17,18 +17,8 @@ pub use llm_benchmark_domain::user::UserRole;
 pub struct PaginatedResponse<T> {
     /// List of items
     pub items: Vec<T>,
-    /// Current page (1-indexed)
-    pub page: u32,
-    /// Items per page
-    pub page_size: u32,
-    /// Total number of items
-    pub total_items: u64,
-    /// Total number of pages
-    pub total_pages: u32,
-    /// Whether there's a next page
-    pub has_next: bool,
-    /// Whether there's a previous page
-    pub has_previous: bool,
+    /// Pagination metadata
+    pub pagination: PaginationMeta,
 }
 
 impl<T> PaginatedResponse<T> {
@@ -41,6 +31,56 @@ impl<T> PaginatedResponse<T> {
     pub fn len(&self) -> usize {
         self.items.len()
     }
+
+    /// Total number of items across all pages
+    pub fn total(&self) -> u64 {
+        self.pagination.total
+    }
+
+    /// Current page (1-indexed)
+    pub fn page(&self) -> u32 {
+        self.pagination.page
+    }
+
+    /// Items per page
+    pub fn page_size(&self) -> u32 {
+        self.pagination.per_page
+    }
+
+    /// Total number of pages
+    pub fn total_pages(&self) -> u32 {
+        self.pagination.total_pages
+    }
+
+    /// Whether there's a next page
+    pub fn has_next(&self) -> bool {
+        self.pagination.has_next
+    }
+
+    /// Whether there's a previous page
+    pub fn has_prev(&self) -> bool {
+        self.pagination.has_prev
+    }
+}
+
+/// Pagination metadata returned with list responses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationMeta {
+    /// Current page (1-indexed)
+    pub page: u32,
+    /// Items per page
+    #[serde(alias = "page_size")]
+    pub per_page: u32,
+    /// Total number of items
+    #[serde(alias = "total_items")]
+    pub total: u64,
+    /// Total number of pages
+    pub total_pages: u32,
+    /// Whether there's a next page
+    pub has_next: bool,
+    /// Whether there's a previous page
+    #[serde(alias = "has_previous")]
+    pub has_prev: bool,
 }
 
 /// Pagination parameters for list requests
@@ -392,6 +432,17 @@ pub struct SubmitterInfo {
     pub organization: Option<String>,
 }
 
+/// Outcome of cancelling a submission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmissionCancellation {
+    /// Submission ID
+    pub id: Uuid,
+    /// Status after cancellation, e.g. `cancelled`
+    pub status: String,
+    /// Cancellation timestamp
+    pub cancelled_at: DateTime<Utc>,
+}
+
 /// Request to create a submission
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateSubmissionRequest {
@@ -621,18 +672,59 @@ mod tests {
     fn test_paginated_response() {
         let response: PaginatedResponse<String> = PaginatedResponse {
             items: vec!["a".to_string(), "b".to_string()],
-            page: 1,
-            page_size: 20,
-            total_items: 2,
-            total_pages: 1,
-            has_next: false,
-            has_previous: false,
+            pagination: PaginationMeta {
+                page: 1,
+                per_page: 20,
+                total: 2,
+                total_pages: 1,
+                has_next: false,
+                has_prev: false,
+            },
         };
 
         assert_eq!(response.len(), 2);
         assert!(!response.is_empty());
     }
 
+    #[test]
+    fn test_paginated_response_from_api() {
+        let response: PaginatedResponse<String> = serde_json::from_value(serde_json::json!({
+            "items": ["c", "d"],
+            "pagination": {
+                "page": 2,
+                "per_page": 2,
+                "total": 5,
+                "total_pages": 3,
+                "has_next": true,
+                "has_prev": true
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.items, vec!["c", "d"]);
+        assert_eq!(response.total(), 5);
+        assert_eq!(response.page(), 2);
+        assert_eq!(response.page_size(), 2);
+        assert_eq!(response.total_pages(), 3);
+        assert!(response.has_next());
+        assert!(response.has_prev());
+
+        let last: PaginatedResponse<String> = serde_json::from_value(serde_json::json!({
+            "items": ["e"],
+            "pagination": {
+                "page": 3,
+                "per_page": 2,
+                "total": 5,
+                "total_pages": 3,
+                "has_next": false,
+                "has_prev": true
+            }
+        }))
+        .unwrap();
+        assert!(!last.has_next());
+        assert_eq!(last.len(), 1);
+    }
+
     #[test]
     fn test_benchmark_filter() {
         let filter = BenchmarkFilter::new()
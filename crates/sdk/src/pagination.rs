@@ -0,0 +1,65 @@
+//! Automatic pagination
+//!
+//! Turns a page-fetching function into a stream of items that follows
+//! `has_next` until the last page, stopping early once a caller-supplied
+//! item cap is reached.
+
+use crate::error::SdkResult;
+use crate::models::PaginatedResponse;
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::future::Future;
+
+struct PageState<T, F> {
+    fetch: F,
+    next_page: Option<u32>,
+    buffered: VecDeque<T>,
+    remaining: Option<usize>,
+}
+
+/// Stream every item from `first_page` onwards, fetching pages on demand
+///
+/// A failed page fetch is yielded as an error and ends the stream.
+pub(crate) fn paginate<T, F, Fut>(
+    first_page: u32,
+    max_items: Option<usize>,
+    fetch: F,
+) -> impl Stream<Item = SdkResult<T>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = SdkResult<PaginatedResponse<T>>>,
+{
+    let state = PageState {
+        fetch,
+        next_page: Some(first_page),
+        buffered: VecDeque::new(),
+        remaining: max_items,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        if state.remaining == Some(0) {
+            return None;
+        }
+
+        while state.buffered.is_empty() {
+            let page = state.next_page?;
+            match (state.fetch)(page).await {
+                Ok(response) => {
+                    let more = response.has_next() && !response.items.is_empty();
+                    state.next_page = more.then(|| page + 1);
+                    state.buffered.extend(response.items);
+                }
+                Err(err) => {
+                    state.next_page = None;
+                    return Some((Err(err), state));
+                }
+            }
+        }
+
+        let item = state.buffered.pop_front()?;
+        if let Some(remaining) = state.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some((Ok(item), state))
+    })
+}
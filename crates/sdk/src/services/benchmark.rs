@@ -3,11 +3,14 @@
 //! Service for managing benchmarks.
 
 use crate::client::Client;
-use crate::error::SdkResult;
+use crate::error::{SdkError, SdkResult};
 use crate::models::{
     Benchmark, BenchmarkFilter, BenchmarkSummary, CreateBenchmarkRequest, PaginatedResponse,
     UpdateBenchmarkRequest,
 };
+use crate::pagination::paginate;
+use futures::{Stream, TryStreamExt};
+use std::time::Duration;
 
 /// Service for benchmark operations
 #[derive(Clone)]
@@ -21,6 +24,16 @@ impl BenchmarkService {
         Self { client }
     }
 
+    /// Use `timeout` for requests made through the returned service
+    ///
+    /// Overrides the client's configured timeout without changing it, for
+    /// calls that legitimately take longer.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            client: self.client.with_timeout(timeout),
+        }
+    }
+
     /// List benchmarks with optional filters
     ///
     /// # Example
@@ -54,6 +67,56 @@ impl BenchmarkService {
         self.client.get_with_query("/api/v1/benchmarks", &filter).await
     }
 
+    /// Stream benchmarks matching a filter across every page
+    ///
+    /// Pages are fetched as the stream is consumed, starting from the
+    /// filter's page (or the first). At most `max_items` benchmarks are
+    /// yielded. A failed page fetch is yielded as an error and ends the
+    /// stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use llm_benchmark_sdk::{BenchmarkFilter, Client};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("key").build()?;
+    ///
+    /// let benchmarks = client.benchmarks().list_stream(BenchmarkFilter::new(), Some(500));
+    /// let mut benchmarks = std::pin::pin!(benchmarks);
+    /// while let Some(benchmark) = benchmarks.try_next().await? {
+    ///     println!("{}", benchmark.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_stream(
+        &self,
+        filter: BenchmarkFilter,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = SdkResult<BenchmarkSummary>> {
+        let service = self.clone();
+        let first_page = filter.pagination.page.unwrap_or(1);
+        paginate(first_page, max_items, move |page| {
+            let service = service.clone();
+            let mut filter = filter.clone();
+            filter.pagination.page = Some(page);
+            async move { service.list_with_filter(filter).await }
+        })
+    }
+
+    /// Collect benchmarks matching a filter from every page
+    ///
+    /// See [`list_stream`](Self::list_stream).
+    pub async fn list_all(
+        &self,
+        filter: BenchmarkFilter,
+        max_items: Option<usize>,
+    ) -> SdkResult<Vec<BenchmarkSummary>> {
+        self.list_stream(filter, max_items).try_collect().await
+    }
+
     /// Get a benchmark by ID or slug
     ///
     /// # Example
@@ -136,11 +199,77 @@ impl BenchmarkService {
             .await
     }
 
-    /// Get benchmark statistics
-    pub async fn get_stats(&self, id: &str) -> SdkResult<BenchmarkStats> {
+    /// Get submission statistics for a benchmark
+    ///
+    /// Accepts a benchmark ID or slug. An unknown benchmark yields
+    /// [`SdkError::NotFound`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use llm_benchmark_sdk::Client;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("key").build()?;
+    ///
+    /// let stats = client.benchmarks().stats("mmlu").await?;
+    /// println!("{} submissions, average {:.3}", stats.total_submissions, stats.average_score);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stats(&self, id_or_slug: &str) -> SdkResult<BenchmarkStats> {
         self.client
-            .get(&format!("/api/v1/benchmarks/{}/stats", id))
+            .get(&format!("/api/v1/benchmarks/{}/stats", id_or_slug))
             .await
+            .map_err(|e| match e {
+                SdkError::NotFound { .. } => SdkError::not_found("Benchmark", id_or_slug),
+                other => other,
+            })
+    }
+
+    /// Get the test cases of a benchmark version
+    ///
+    /// Accepts a benchmark ID or slug. Without a `version` the latest
+    /// version's test cases are returned. An unknown benchmark or version
+    /// yields [`SdkError::NotFound`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use llm_benchmark_sdk::Client;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("key").build()?;
+    ///
+    /// let set = client.benchmarks().test_cases("mmlu", Some("1.2.0")).await?;
+    /// println!("{} test cases in {}", set.test_cases.len(), set.version);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn test_cases(
+        &self,
+        id_or_slug: &str,
+        version: Option<&str>,
+    ) -> SdkResult<TestCaseSet> {
+        let path = format!("/api/v1/benchmarks/{}/test-cases", id_or_slug);
+        let result = match version {
+            Some(version) => {
+                self.client
+                    .get_with_query(&path, &[("version", version)])
+                    .await
+            }
+            None => self.client.get(&path).await,
+        };
+        result.map_err(|e| match e {
+            SdkError::NotFound { .. } => SdkError::not_found("Benchmark", id_or_slug),
+            other => other,
+        })
+    }
+
+    /// Get benchmark statistics
+    #[deprecated(note = "use `stats`, which also accepts a slug")]
+    pub async fn get_stats(&self, id: &str) -> SdkResult<BenchmarkStats> {
+        self.stats(id).await
     }
 }
 
@@ -155,16 +284,49 @@ pub struct BenchmarkVersion {
     pub changelog: Option<String>,
 }
 
+/// The test cases of one benchmark version
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestCaseSet {
+    /// Version the test cases belong to
+    pub version: String,
+    /// Test cases, in definition order
+    #[serde(default)]
+    pub test_cases: Vec<TestCase>,
+}
+
+/// A single benchmark test case
+///
+/// Only the ID is interpreted; the rest of the definition (input, expected
+/// output, evaluation method, ...) is kept as-is.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TestCase {
+    /// Test case ID
+    pub id: String,
+    /// Remaining fields of the test case definition
+    #[serde(flatten)]
+    pub definition: serde_json::Map<String, serde_json::Value>,
+}
+
 /// Benchmark statistics
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BenchmarkStats {
     /// Total submissions
     pub total_submissions: u64,
+    /// Number of published versions
+    #[serde(default)]
+    pub version_count: u64,
     /// Unique models
     pub unique_models: u64,
-    /// Average score
+    /// Distinct model providers
+    #[serde(default)]
+    pub unique_providers: u64,
+    /// Average aggregate score
     pub average_score: f64,
+    /// When the most recent submission was made, if there is one
+    #[serde(default)]
+    pub latest_submission_at: Option<chrono::DateTime<chrono::Utc>>,
     /// Score distribution
+    #[serde(default)]
     pub score_distribution: Vec<ScoreBucket>,
 }
 
@@ -195,4 +357,260 @@ mod tests {
         // Service is created, actual API calls would require a running server
         assert!(true);
     }
+
+    fn benchmark_page(page: u32, total_pages: u32, ids: &[u128]) -> serde_json::Value {
+        let items: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "id": uuid::Uuid::from_u128(*id),
+                    "slug": format!("bench-{}", id),
+                    "name": format!("Bench {}", id),
+                    "description": "A benchmark",
+                    "category": "accuracy",
+                    "status": "active",
+                    "submission_count": 0,
+                    "created_at": "2024-03-09T14:05:00Z"
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "items": items,
+            "pagination": {
+                "page": page,
+                "per_page": 2,
+                "total": 5,
+                "total_pages": total_pages,
+                "has_next": page < total_pages,
+                "has_prev": page > 1
+            }
+        })
+    }
+
+    async fn paged_server() -> wiremock::MockServer {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for (page, ids) in [(1, &[1, 2][..]), (2, &[3, 4][..]), (3, &[5][..])] {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/benchmarks"))
+                .and(query_param("page", page.to_string()))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(benchmark_page(page, 3, ids)),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        server
+    }
+
+    fn ids(benchmarks: &[BenchmarkSummary]) -> Vec<u128> {
+        benchmarks.iter().map(|b| b.id.as_u128()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_all_follows_every_page() {
+        let server = paged_server().await;
+        let client = Client::builder().base_url(server.uri()).build().unwrap();
+
+        let benchmarks = client
+            .benchmarks()
+            .list_all(BenchmarkFilter::new().page_size(2), None)
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&benchmarks), [1, 2, 3, 4, 5]);
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_stream_stops_at_max_items() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(benchmark_page(1, 3, &[1, 2])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(benchmark_page(2, 3, &[3, 4])))
+            .mount(&server)
+            .await;
+        let client = Client::builder().base_url(server.uri()).build().unwrap();
+
+        let benchmarks = client
+            .benchmarks()
+            .list_all(BenchmarkFilter::new(), Some(3))
+            .await
+            .unwrap();
+
+        // The third page is never requested
+        assert_eq!(ids(&benchmarks), [1, 2, 3]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_stream_surfaces_page_errors() {
+        use futures::StreamExt;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(benchmark_page(1, 3, &[1, 2])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "error": "FORBIDDEN",
+                "message": "Token expired"
+            })))
+            .mount(&server)
+            .await;
+        let client = Client::builder().base_url(server.uri()).build().unwrap();
+
+        let results: Vec<_> = client
+            .benchmarks()
+            .list_stream(BenchmarkFilter::new(), None)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id.as_u128(), 1);
+        assert_eq!(results[1].as_ref().unwrap().id.as_u128(), 2);
+        assert!(matches!(results[2], Err(SdkError::Forbidden { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_stats_by_slug_and_not_found() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/mmlu/stats"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_submissions": 12,
+                "version_count": 3,
+                "unique_models": 7,
+                "unique_providers": 4,
+                "average_score": 0.81,
+                "latest_submission_at": "2024-03-09T14:05:00Z"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/missing/stats"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": "NOT_FOUND",
+                "message": "Resource not found"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.uri())
+            .retry_count(0)
+            .build()
+            .unwrap();
+
+        let stats = client.benchmarks().stats("mmlu").await.unwrap();
+        assert_eq!(stats.total_submissions, 12);
+        assert_eq!(stats.version_count, 3);
+        assert_eq!(stats.unique_providers, 4);
+        assert!(stats.score_distribution.is_empty());
+        assert_eq!(
+            stats.latest_submission_at.unwrap().to_rfc3339(),
+            "2024-03-09T14:05:00+00:00"
+        );
+
+        let err = client.benchmarks().stats("missing").await.unwrap_err();
+        assert_eq!(err.to_string(), "Resource not found: Benchmark 'missing'");
+    }
+
+    #[tokio::test]
+    async fn test_test_cases_for_version() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks/mmlu/test-cases"))
+            .and(query_param("version", "1.0.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "version": "1.0.0",
+                "test_cases": [{"id": "tc-1", "input": {"prompt_template": "2+2?"}}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.uri())
+            .retry_count(0)
+            .build()
+            .unwrap();
+
+        let set = client
+            .benchmarks()
+            .test_cases("mmlu", Some("1.0.0"))
+            .await
+            .unwrap();
+        assert_eq!(set.version, "1.0.0");
+        assert_eq!(set.test_cases[0].id, "tc-1");
+        assert_eq!(
+            set.test_cases[0].definition["input"]["prompt_template"],
+            "2+2?"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_call_timeout_overrides_client_default() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/benchmarks"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(benchmark_page(1, 1, &[1]))
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.uri())
+            .timeout(Duration::from_millis(100))
+            .retry_count(0)
+            .build()
+            .unwrap();
+
+        let err = client.benchmarks().list().await.unwrap_err();
+        assert!(matches!(err, SdkError::Timeout { .. }), "{:?}", err);
+
+        let page = client
+            .benchmarks()
+            .with_timeout(Duration::from_secs(5))
+            .list()
+            .await
+            .unwrap();
+        assert_eq!(ids(&page.items), [1]);
+
+        // The shared client keeps its own timeout
+        assert_eq!(client.timeout(), Duration::from_millis(100));
+        let err = client.benchmarks().list().await.unwrap_err();
+        assert!(matches!(err, SdkError::Timeout { .. }), "{:?}", err);
+    }
 }
@@ -8,6 +8,7 @@ use crate::models::{
     CreateProposalRequest, PaginatedResponse, Proposal, ProposalFilter, ProposalSummary,
     VoteRequest, VoteType,
 };
+use std::time::Duration;
 
 /// Service for governance operations
 #[derive(Clone)]
@@ -21,6 +22,16 @@ impl GovernanceService {
         Self { client }
     }
 
+    /// Use `timeout` for requests made through the returned service
+    ///
+    /// Overrides the client's configured timeout without changing it, for
+    /// calls that legitimately take longer.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            client: self.client.with_timeout(timeout),
+        }
+    }
+
     /// List proposals with optional filters
     ///
     /// # Example
@@ -2,9 +2,14 @@
 //!
 //! Service for viewing benchmark leaderboards and comparing models.
 
-use crate::client::Client;
-use crate::error::SdkResult;
+use crate::client::{calculate_backoff, Client};
+use crate::error::{SdkError, SdkResult};
 use crate::models::{Leaderboard, LeaderboardEntry, ModelComparison, VerificationLevel};
+use futures::Stream;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::debug;
 
 /// Service for leaderboard operations
 #[derive(Clone)]
@@ -18,6 +23,16 @@ impl LeaderboardService {
         Self { client }
     }
 
+    /// Use `timeout` for requests made through the returned service
+    ///
+    /// Overrides the client's configured timeout without changing it, for
+    /// calls that legitimately take longer.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            client: self.client.with_timeout(timeout),
+        }
+    }
+
     /// Get the leaderboard for a benchmark
     ///
     /// # Example
@@ -111,6 +126,91 @@ impl LeaderboardService {
             .await
     }
 
+    /// Poll a leaderboard, yielding a snapshot whenever it changes
+    ///
+    /// The leaderboard is fetched every `interval` and a snapshot is only
+    /// emitted when its entries differ from the previous one (by submission ID
+    /// and score), so the first poll always emits. When the API responds with
+    /// `429 Too Many Requests` polling pauses for the `Retry-After` duration,
+    /// or backs off exponentially if none was given, then resumes. Other errors
+    /// are yielded and polling continues.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use llm_benchmark_sdk::Client;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("key").build()?;
+    ///
+    /// let updates = client.leaderboards().poll("mmlu", Duration::from_secs(30));
+    /// futures::pin_mut!(updates);
+    /// while let Some(leaderboard) = updates.next().await {
+    ///     let leaderboard = leaderboard?;
+    ///     println!("{} entries", leaderboard.entries.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poll(
+        &self,
+        benchmark_id: &str,
+        interval: Duration,
+    ) -> impl Stream<Item = SdkResult<Leaderboard>> + Send + 'static {
+        let state = PollState {
+            service: self.clone(),
+            benchmark_id: benchmark_id.to_string(),
+            interval,
+            delay: Duration::ZERO,
+            rate_limited: 0,
+            last: None,
+        };
+
+        futures::stream::unfold(state, |mut state| async move {
+            loop {
+                tokio::time::sleep(state.delay).await;
+
+                match state.service.get(&state.benchmark_id).await {
+                    Ok(leaderboard) => {
+                        state.rate_limited = 0;
+                        state.delay = state.interval;
+
+                        let fingerprint = fingerprint(&leaderboard);
+                        if state.last.as_ref() != Some(&fingerprint) {
+                            state.last = Some(fingerprint);
+                            return Some((Ok(leaderboard), state));
+                        }
+                    }
+                    Err(SdkError::RateLimited { retry_after }) => {
+                        state.rate_limited += 1;
+                        state.delay = match retry_after {
+                            Some(seconds) => Duration::from_secs(seconds),
+                            None => {
+                                let max = state
+                                    .service
+                                    .client
+                                    .config()
+                                    .retry_max_backoff
+                                    .max(state.interval);
+                                calculate_backoff(state.rate_limited, state.interval, max)
+                            }
+                        };
+                        debug!(
+                            "Leaderboard poll for {} rate limited, backing off for {:?}",
+                            state.benchmark_id, state.delay
+                        );
+                    }
+                    Err(e) => {
+                        state.delay = state.interval;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+
     /// Export leaderboard data
     ///
     /// Returns the leaderboard in a format suitable for export.
@@ -119,6 +219,46 @@ impl LeaderboardService {
             .get(&format!("/api/v1/leaderboards/{}/export", benchmark_id))
             .await
     }
+
+    /// Export leaderboard data rendered in the given format
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use llm_benchmark_sdk::services::ExportFormat;
+    /// use llm_benchmark_sdk::Client;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("key").build()?;
+    ///
+    /// let format: ExportFormat = "csv".parse()?;
+    /// let csv = client.leaderboards().export_as("mmlu", format).await?;
+    /// std::fs::write("mmlu.csv", csv)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_as(&self, benchmark_id: &str, format: ExportFormat) -> SdkResult<String> {
+        self.export(benchmark_id).await?.render(format)
+    }
+}
+
+/// State carried between leaderboard polls
+struct PollState {
+    service: LeaderboardService,
+    benchmark_id: String,
+    interval: Duration,
+    delay: Duration,
+    rate_limited: u32,
+    last: Option<Vec<(uuid::Uuid, u64)>>,
+}
+
+/// Identify a leaderboard by its ordered submission IDs and scores
+fn fingerprint(leaderboard: &Leaderboard) -> Vec<(uuid::Uuid, u64)> {
+    leaderboard
+        .entries
+        .iter()
+        .map(|entry| (entry.submission_id, entry.score.to_bits()))
+        .collect()
 }
 
 /// Options for leaderboard queries
@@ -217,9 +357,236 @@ pub struct ExportMetadata {
     pub format_version: String,
 }
 
+impl LeaderboardExport {
+    /// Render the export in the given format
+    pub fn render(&self, format: ExportFormat) -> SdkResult<String> {
+        match format {
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            ExportFormat::Csv => Ok(self.to_csv()),
+        }
+    }
+
+    /// Render the entries as RFC 4180 CSV, one row per entry
+    ///
+    /// Columns are [`CSV_COLUMNS`]. Fields containing commas, quotes or line
+    /// breaks are quoted, and rows end with CRLF.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        write_csv_record(&mut out, CSV_COLUMNS);
+        for entry in &self.entries {
+            let verification_level = serde_json::to_value(entry.verification_level)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default();
+            write_csv_record(
+                &mut out,
+                [
+                    entry.rank.to_string().as_str(),
+                    entry.provider.as_deref().unwrap_or_default(),
+                    entry.model_name.as_str(),
+                    entry.model_version.as_str(),
+                    entry.score.to_string().as_str(),
+                    verification_level.as_str(),
+                    entry.submitted_at.to_rfc3339().as_str(),
+                ],
+            );
+        }
+        out
+    }
+}
+
+/// Column headers of a CSV leaderboard export
+pub const CSV_COLUMNS: [&str; 7] = [
+    "rank",
+    "model_provider",
+    "model_name",
+    "model_version",
+    "aggregate_score",
+    "verification_level",
+    "submitted_at",
+];
+
+/// Append one CSV record, quoting fields as RFC 4180 requires
+fn write_csv_record<'a>(out: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+/// Leaderboard export formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// The export document as pretty-printed JSON
+    #[default]
+    Json,
+    /// Entries as RFC 4180 CSV
+    Csv,
+}
+
+impl ExportFormat {
+    /// All supported formats
+    pub fn all() -> &'static [ExportFormat] {
+        &[Self::Json, Self::Csv]
+    }
+
+    /// Format name, also the conventional file extension
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = SdkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|format| format.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                let supported: Vec<&str> = Self::all().iter().map(|f| f.name()).collect();
+                SdkError::validation(format!(
+                    "Unsupported export format '{}'. Use one of: {}",
+                    s,
+                    supported.join(", ")
+                ))
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures::StreamExt;
+    use std::time::Instant;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn leaderboard(scores: &[(uuid::Uuid, f64)]) -> Leaderboard {
+        Leaderboard {
+            benchmark_id: uuid::Uuid::nil(),
+            benchmark_name: "MMLU".to_string(),
+            entries: scores
+                .iter()
+                .enumerate()
+                .map(|(i, (submission_id, score))| LeaderboardEntry {
+                    rank: i as u32 + 1,
+                    submission_id: *submission_id,
+                    model_name: format!("model-{}", i),
+                    model_version: "1.0".to_string(),
+                    provider: None,
+                    score: *score,
+                    metrics: Default::default(),
+                    verification_level: VerificationLevel::Unverified,
+                    submitted_at: chrono::Utc::now(),
+                })
+                .collect(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    async fn mount_once(server: &MockServer, response: ResponseTemplate) {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/leaderboards/mmlu"))
+            .respond_with(response)
+            .up_to_n_times(1)
+            .mount(server)
+            .await;
+    }
+
+    fn client(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.uri())
+            .retry_count(0)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_poll_backs_off_on_rate_limit_and_emits_changes() {
+        let server = MockServer::start().await;
+        let first = uuid::Uuid::new_v4();
+        let second = uuid::Uuid::new_v4();
+        let initial = leaderboard(&[(first, 0.9)]);
+        let changed = leaderboard(&[(second, 0.95), (first, 0.9)]);
+
+        mount_once(&server, ResponseTemplate::new(200).set_body_json(&initial)).await;
+        mount_once(
+            &server,
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "1")
+                .set_body_json(serde_json::json!({ "message": "Too many requests" })),
+        )
+        .await;
+        // Unchanged data is not emitted again
+        mount_once(&server, ResponseTemplate::new(200).set_body_json(&initial)).await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/leaderboards/mmlu"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&changed))
+            .mount(&server)
+            .await;
+
+        let updates = client(&server)
+            .leaderboards()
+            .poll("mmlu", Duration::from_millis(10));
+        futures::pin_mut!(updates);
+
+        let snapshot = updates.next().await.unwrap().unwrap();
+        assert_eq!(snapshot.entries.len(), 1);
+
+        let started = Instant::now();
+        let snapshot = updates.next().await.unwrap().unwrap();
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(snapshot.entries[0].submission_id, second);
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_poll_emits_score_changes() {
+        let server = MockServer::start().await;
+        let id = uuid::Uuid::new_v4();
+
+        mount_once(
+            &server,
+            ResponseTemplate::new(200).set_body_json(leaderboard(&[(id, 0.5)])),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/leaderboards/mmlu"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(leaderboard(&[(id, 0.6)])))
+            .mount(&server)
+            .await;
+
+        let scores: Vec<f64> = client(&server)
+            .leaderboards()
+            .poll("mmlu", Duration::from_millis(10))
+            .take(2)
+            .map(|snapshot| snapshot.unwrap().entries[0].score)
+            .collect()
+            .await;
+        assert_eq!(scores, vec![0.5, 0.6]);
+    }
 
     #[test]
     fn test_leaderboard_options() {
@@ -232,4 +599,91 @@ mod tests {
         assert_eq!(options.offset, Some(20));
         assert_eq!(options.verified_only, Some(true));
     }
+
+    #[test]
+    fn test_export_format_parsing() {
+        assert_eq!("json".parse::<ExportFormat>().unwrap(), ExportFormat::Json);
+        assert_eq!("CSV".parse::<ExportFormat>().unwrap(), ExportFormat::Csv);
+
+        let err = "xml".parse::<ExportFormat>().unwrap_err();
+        assert!(matches!(err, SdkError::ValidationError { .. }));
+        assert!(err.to_string().contains("Unsupported export format 'xml'"));
+        assert!(err.to_string().contains("json, csv"));
+    }
+
+    /// Minimal RFC 4180 reader, enough to check what `to_csv` writes
+    fn parse_csv(input: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut chars = input.chars().peekable();
+        let mut quoted = false;
+
+        while let Some(c) = chars.next() {
+            match (quoted, c) {
+                (true, '"') if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                (true, '"') => quoted = false,
+                (true, c) => field.push(c),
+                (false, '"') if field.is_empty() => quoted = true,
+                (false, ',') => record.push(std::mem::take(&mut field)),
+                (false, '\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                (false, c) => field.push(c),
+            }
+        }
+        assert!(!quoted && field.is_empty() && record.is_empty(), "unterminated record");
+        records
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mut board = leaderboard(&[
+            (uuid::Uuid::new_v4(), 0.9125),
+            (uuid::Uuid::new_v4(), 0.875),
+            (uuid::Uuid::new_v4(), 0.5),
+        ]);
+        board.entries[0].provider = Some("Acme, Inc.".to_string());
+        board.entries[0].verification_level = VerificationLevel::PlatformVerified;
+        board.entries[1].model_name = "the \"big\" one".to_string();
+        board.entries[2].model_version = "line\nbreak".to_string();
+
+        let export = LeaderboardExport {
+            benchmark_id: board.benchmark_id,
+            benchmark_name: board.benchmark_name.clone(),
+            exported_at: chrono::Utc::now(),
+            metadata: ExportMetadata {
+                total_entries: board.entries.len() as u64,
+                format_version: "1.0".to_string(),
+            },
+            entries: board.entries,
+        };
+
+        let csv = export.render(ExportFormat::Csv).unwrap();
+        assert!(csv.contains(",\"Acme, Inc.\","));
+        assert!(csv.contains(",\"the \"\"big\"\" one\","));
+
+        let records = parse_csv(&csv);
+        assert_eq!(records[0], CSV_COLUMNS);
+        assert_eq!(records.len(), export.entries.len() + 1);
+
+        for (record, entry) in records[1..].iter().zip(&export.entries) {
+            assert_eq!(record[0].parse::<u32>().unwrap(), entry.rank);
+            assert_eq!(record[1], entry.provider.clone().unwrap_or_default());
+            assert_eq!(record[2], entry.model_name);
+            assert_eq!(record[3], entry.model_version);
+            assert_eq!(record[4].parse::<f64>().unwrap(), entry.score);
+            let level: VerificationLevel =
+                serde_json::from_value(serde_json::Value::String(record[5].clone())).unwrap();
+            assert_eq!(level, entry.verification_level);
+            let submitted_at = chrono::DateTime::parse_from_rfc3339(&record[6]).unwrap();
+            assert_eq!(submitted_at, entry.submitted_at);
+        }
+        assert_eq!(records[1][5], "platform_verified");
+    }
 }
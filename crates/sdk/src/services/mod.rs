@@ -7,7 +7,11 @@ mod governance;
 mod leaderboard;
 mod submission;
 
-pub use benchmark::BenchmarkService;
+pub use benchmark::{
+    BenchmarkService, BenchmarkStats, BenchmarkVersion, ScoreBucket, TestCase, TestCaseSet,
+};
 pub use governance::GovernanceService;
-pub use leaderboard::LeaderboardService;
-pub use submission::SubmissionService;
+pub use leaderboard::{
+    ExportFormat, ExportMetadata, LeaderboardExport, LeaderboardService, CSV_COLUMNS,
+};
+pub use submission::{CreateSubmissionBuilder, SubmissionService};
@@ -3,11 +3,15 @@
 //! Service for managing benchmark submissions.
 
 use crate::client::Client;
-use crate::error::SdkResult;
+use crate::error::{FieldError, SdkError, SdkResult};
 use crate::models::{
-    CreateSubmissionRequest, PaginatedResponse, Submission, SubmissionFilter, SubmissionSummary,
+    CreateSubmissionRequest, PaginatedResponse, Submission, SubmissionCancellation,
+    SubmissionFilter, SubmissionResults, SubmissionSummary, SubmissionVisibility,
     VerificationLevel,
 };
+use crate::pagination::paginate;
+use futures::{Stream, TryStreamExt};
+use std::time::Duration;
 
 /// Service for submission operations
 #[derive(Clone)]
@@ -21,6 +25,16 @@ impl SubmissionService {
         Self { client }
     }
 
+    /// Use `timeout` for requests made through the returned service
+    ///
+    /// Overrides the client's configured timeout without changing it, for
+    /// calls that legitimately take longer.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            client: self.client.with_timeout(timeout),
+        }
+    }
+
     /// List submissions with optional filters
     ///
     /// # Example
@@ -57,6 +71,38 @@ impl SubmissionService {
             .await
     }
 
+    /// Stream submissions matching a filter across every page
+    ///
+    /// Pages are fetched as the stream is consumed, starting from the
+    /// filter's page (or the first). At most `max_items` submissions are
+    /// yielded. A failed page fetch is yielded as an error and ends the
+    /// stream.
+    pub fn list_stream(
+        &self,
+        filter: SubmissionFilter,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = SdkResult<SubmissionSummary>> {
+        let service = self.clone();
+        let first_page = filter.pagination.page.unwrap_or(1);
+        paginate(first_page, max_items, move |page| {
+            let service = service.clone();
+            let mut filter = filter.clone();
+            filter.pagination.page = Some(page);
+            async move { service.list_with_filter(filter).await }
+        })
+    }
+
+    /// Collect submissions matching a filter from every page
+    ///
+    /// See [`list_stream`](Self::list_stream).
+    pub async fn list_all(
+        &self,
+        filter: SubmissionFilter,
+        max_items: Option<usize>,
+    ) -> SdkResult<Vec<SubmissionSummary>> {
+        self.list_stream(filter, max_items).try_collect().await
+    }
+
     /// Get a submission by ID
     pub async fn get(&self, id: &str) -> SdkResult<Submission> {
         self.client
@@ -64,14 +110,15 @@ impl SubmissionService {
             .await
     }
 
-    /// Create a new submission
+    /// Start building a new submission
     ///
-    /// Requires authentication.
+    /// Requires authentication. Required fields are checked when the
+    /// submission is sent, before any request is made.
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// use llm_benchmark_sdk::{Client, CreateSubmissionRequest, SubmissionResults};
+    /// use llm_benchmark_sdk::{Client, SubmissionResults, SubmissionVisibility};
     /// use std::collections::HashMap;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -83,22 +130,32 @@ impl SubmissionService {
     ///     test_case_results: None,
     /// };
     ///
-    /// let request = CreateSubmissionRequest {
-    ///     benchmark_id: "mmlu".to_string(),
-    ///     model_name: "gpt-4".to_string(),
-    ///     model_version: "0613".to_string(),
-    ///     results,
-    ///     provider: Some("OpenAI".to_string()),
-    ///     visibility: None,
-    ///     notes: None,
-    /// };
-    ///
-    /// let submission = client.submissions().create(request).await?;
+    /// let submission = client
+    ///     .submissions()
+    ///     .create()
+    ///     .benchmark("mmlu")
+    ///     .model("gpt-4")
+    ///     .version("0613")
+    ///     .results(results)
+    ///     .provider("OpenAI")
+    ///     .visibility(SubmissionVisibility::Public)
+    ///     .send()
+    ///     .await?;
     /// println!("Created submission: {}", submission.id);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create(&self, request: CreateSubmissionRequest) -> SdkResult<Submission> {
+    pub fn create(&self) -> CreateSubmissionBuilder {
+        CreateSubmissionBuilder::new(self.clone())
+    }
+
+    /// Create a new submission from a complete request
+    ///
+    /// Requires authentication.
+    pub async fn create_with_request(
+        &self,
+        request: CreateSubmissionRequest,
+    ) -> SdkResult<Submission> {
         self.client.post("/api/v1/submissions", &request).await
     }
 
@@ -145,6 +202,34 @@ impl SubmissionService {
             .await
     }
 
+    /// Cancel a pending submission
+    ///
+    /// Only unverified submissions can be cancelled, and only by their
+    /// submitter. Fails with [`SdkError::Conflict`] once the submission has
+    /// been verified and [`SdkError::Forbidden`] for someone else's.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use llm_benchmark_sdk::Client;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("key").build()?;
+    ///
+    /// let cancellation = client.submissions().cancel("submission-id").await?;
+    /// println!("Status: {}", cancellation.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel(&self, id: &str) -> SdkResult<SubmissionCancellation> {
+        self.client
+            .post(
+                &format!("/api/v1/submissions/{}/cancel", id),
+                &serde_json::json!({}),
+            )
+            .await
+    }
+
     /// Delete a submission
     ///
     /// Only the submitter can delete their own submissions.
@@ -179,6 +264,132 @@ impl SubmissionService {
     }
 }
 
+/// Fluent builder for creating a submission
+///
+/// Obtained from [`SubmissionService::create`].
+#[must_use = "the submission is not created until `send` is called"]
+pub struct CreateSubmissionBuilder {
+    service: SubmissionService,
+    benchmark_id: Option<String>,
+    model_name: Option<String>,
+    model_version: Option<String>,
+    results: Option<SubmissionResults>,
+    provider: Option<String>,
+    visibility: Option<SubmissionVisibility>,
+    notes: Option<String>,
+}
+
+impl CreateSubmissionBuilder {
+    fn new(service: SubmissionService) -> Self {
+        Self {
+            service,
+            benchmark_id: None,
+            model_name: None,
+            model_version: None,
+            results: None,
+            provider: None,
+            visibility: None,
+            notes: None,
+        }
+    }
+
+    /// Set the benchmark ID or slug (required)
+    pub fn benchmark(mut self, benchmark_id: impl Into<String>) -> Self {
+        self.benchmark_id = Some(benchmark_id.into());
+        self
+    }
+
+    /// Set the model name (required)
+    pub fn model(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = Some(model_name.into());
+        self
+    }
+
+    /// Set the model version (required)
+    pub fn version(mut self, model_version: impl Into<String>) -> Self {
+        self.model_version = Some(model_version.into());
+        self
+    }
+
+    /// Set the results (required)
+    pub fn results(mut self, results: SubmissionResults) -> Self {
+        self.results = Some(results);
+        self
+    }
+
+    /// Set the model provider
+    pub fn provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Set the visibility
+    pub fn visibility(mut self, visibility: SubmissionVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Set notes
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Build the request, failing if a required field is missing or blank
+    pub fn build(&self) -> SdkResult<CreateSubmissionRequest> {
+        let mut field_errors = Vec::new();
+
+        let mut required = |field: &str, value: &Option<String>| match value {
+            Some(value) if !value.trim().is_empty() => value.clone(),
+            _ => {
+                field_errors.push(FieldError::with_code(
+                    field,
+                    format!("{} is required", field),
+                    "REQUIRED",
+                ));
+                String::new()
+            }
+        };
+
+        let benchmark_id = required("benchmark_id", &self.benchmark_id);
+        let model_name = required("model_name", &self.model_name);
+        let model_version = required("model_version", &self.model_version);
+
+        if self.results.is_none() {
+            field_errors.push(FieldError::with_code(
+                "results",
+                "results is required",
+                "REQUIRED",
+            ));
+        }
+
+        match &self.results {
+            Some(results) if field_errors.is_empty() => Ok(CreateSubmissionRequest {
+                benchmark_id,
+                model_name,
+                model_version,
+                results: results.clone(),
+                provider: self.provider.clone(),
+                visibility: self.visibility,
+                notes: self.notes.clone(),
+            }),
+            _ => {
+                let fields: Vec<_> = field_errors.iter().map(|e| e.field.as_str()).collect();
+                Err(SdkError::validation_with_fields(
+                    format!("Missing required submission fields: {}", fields.join(", ")),
+                    field_errors,
+                ))
+            }
+        }
+    }
+
+    /// Validate the submission and send it
+    pub async fn send(self) -> SdkResult<Submission> {
+        let request = self.build()?;
+        self.service.create_with_request(request).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +405,144 @@ mod tests {
         let _service = client.submissions();
         assert!(true);
     }
+
+    fn results() -> SubmissionResults {
+        SubmissionResults {
+            aggregate_score: 0.9,
+            metrics: std::collections::HashMap::from([("accuracy".to_string(), 0.9)]),
+            test_case_results: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builder_missing_fields_fail_before_request() {
+        use wiremock::{matchers::any, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(201))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder().base_url(server.uri()).build().unwrap();
+        let err = client
+            .submissions()
+            .create()
+            .benchmark("mmlu")
+            .model("gpt-4")
+            .version("  ")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SdkError::ValidationError { .. }));
+        let fields: Vec<_> = err.field_errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["model_version", "results"]);
+        assert!(err.to_string().contains("model_version, results"));
+    }
+
+    #[tokio::test]
+    async fn test_builder_sends_expected_body() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let expected = serde_json::json!({
+            "benchmark_id": "mmlu",
+            "model_name": "gpt-4",
+            "model_version": "0613",
+            "results": {
+                "aggregate_score": 0.9,
+                "metrics": { "accuracy": 0.9 }
+            },
+            "provider": "OpenAI",
+            "visibility": "unlisted"
+        });
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/submissions"))
+            .and(body_json(&expected))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "BAD_REQUEST",
+                "message": "matched"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let builder = Client::builder()
+            .base_url(server.uri())
+            .build()
+            .unwrap()
+            .submissions()
+            .create()
+            .benchmark("mmlu")
+            .model("gpt-4")
+            .version("0613")
+            .results(results())
+            .provider("OpenAI")
+            .visibility(SubmissionVisibility::Unlisted);
+
+        assert_eq!(serde_json::to_value(builder.build().unwrap()).unwrap(), expected);
+
+        // The mock only answers requests carrying the expected body
+        let err = builder.send().await.unwrap_err();
+        assert!(err.to_string().contains("matched"));
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/submissions/9f1c2a5e-1d2b-4c3d-8e9f-0a1b2c3d4e5f/cancel"))
+            .and(header("authorization", "Bearer owner-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "9f1c2a5e-1d2b-4c3d-8e9f-0a1b2c3d4e5f",
+                "status": "cancelled",
+                "cancelled_at": "2024-05-01T12:30:00Z"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/submissions/verified/cancel"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "error": "CONFLICT",
+                "message": "Verified submissions cannot be cancelled"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/submissions/someone-elses/cancel"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "error": "FORBIDDEN",
+                "message": "Only the submitter can cancel a submission"
+            })))
+            .mount(&server)
+            .await;
+
+        let submissions = Client::builder()
+            .base_url(server.uri())
+            .bearer_token("owner-token")
+            .build()
+            .unwrap()
+            .submissions();
+
+        let cancellation = submissions
+            .cancel("9f1c2a5e-1d2b-4c3d-8e9f-0a1b2c3d4e5f")
+            .await
+            .unwrap();
+        assert_eq!(cancellation.status, "cancelled");
+        assert_eq!(cancellation.cancelled_at.to_rfc3339(), "2024-05-01T12:30:00+00:00");
+
+        let err = submissions.cancel("verified").await.unwrap_err();
+        assert!(matches!(err, SdkError::Conflict { .. }));
+        assert_eq!(err.to_string(), "Conflict: Verified submissions cannot be cancelled");
+
+        let err = submissions.cancel("someone-elses").await.unwrap_err();
+        assert!(matches!(err, SdkError::Forbidden { .. }));
+    }
 }
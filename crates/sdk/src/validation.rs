@@ -0,0 +1,135 @@
+//! Response schema validation
+//!
+//! With the `validate-responses` feature enabled, debug builds check API
+//! responses against the JSON schemas bundled in `schemas/` before they are
+//! deserialized. A mismatch is logged and surfaced as
+//! [`SdkError::InvalidResponse`], so changes in the API's response shape show
+//! up in tests instead of as subtle deserialization failures in the field.
+//! Release builds skip validation entirely.
+
+use crate::error::{SdkError, SdkResult};
+use crate::models::{Benchmark, Leaderboard};
+use jsonschema::Validator;
+use std::any::type_name;
+use std::sync::OnceLock;
+use tracing::error;
+
+/// Bundled schema for a response model
+struct ResponseSchema {
+    /// Type name of the model the schema describes
+    model: &'static str,
+    validator: Validator,
+}
+
+fn schemas() -> &'static [ResponseSchema] {
+    static SCHEMAS: OnceLock<Vec<ResponseSchema>> = OnceLock::new();
+
+    SCHEMAS.get_or_init(|| {
+        [
+            (type_name::<Benchmark>(), include_str!("../schemas/benchmark.json")),
+            (type_name::<Leaderboard>(), include_str!("../schemas/leaderboard.json")),
+        ]
+        .into_iter()
+        .map(|(model, schema)| {
+            let schema: serde_json::Value =
+                serde_json::from_str(schema).expect("bundled schema is valid JSON");
+            ResponseSchema {
+                model,
+                validator: jsonschema::validator_for(&schema)
+                    .expect("bundled schema is a valid JSON schema"),
+            }
+        })
+        .collect()
+    })
+}
+
+/// Validate a response body against the bundled schema for `T`, if any
+pub(crate) fn validate_response<T>(body: &str) -> SdkResult<()> {
+    let model = type_name::<T>();
+    let Some(schema) = schemas().iter().find(|s| s.model == model) else {
+        return Ok(());
+    };
+
+    // Bodies that are not JSON are reported by deserialization
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else {
+        return Ok(());
+    };
+
+    let violations: Vec<String> = schema
+        .validator
+        .iter_errors(&value)
+        .map(|e| format!("{} at '{}'", e, e.instance_path))
+        .collect();
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    error!(
+        "SDK response does not match the {} schema: {}",
+        model,
+        violations.join("; ")
+    );
+    Err(SdkError::InvalidResponse {
+        message: format!(
+            "Response does not match the {} schema: {}",
+            model,
+            violations.join("; ")
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaderboard() -> serde_json::Value {
+        serde_json::json!({
+            "benchmark_id": "550e8400-e29b-41d4-a716-446655440000",
+            "benchmark_name": "MMLU",
+            "entries": [{
+                "rank": 1,
+                "submission_id": "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+                "model_name": "gpt-4",
+                "model_version": "0613",
+                "score": 0.9,
+                "metrics": { "accuracy": 0.9 },
+                "verification_level": "unverified",
+                "submitted_at": "2024-01-01T00:00:00Z"
+            }],
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    #[test]
+    fn test_valid_response_passes() {
+        let body = leaderboard().to_string();
+        assert!(validate_response::<Leaderboard>(&body).is_ok());
+        assert!(serde_json::from_str::<Leaderboard>(&body).is_ok());
+    }
+
+    #[test]
+    fn test_missing_required_field_fails() {
+        let mut body = leaderboard();
+        body["entries"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("submitted_at");
+
+        let err = validate_response::<Leaderboard>(&body.to_string()).unwrap_err();
+        match err {
+            SdkError::InvalidResponse { message } => {
+                assert!(message.contains("Leaderboard schema"));
+                assert!(message.contains("\"submitted_at\" is a required property"));
+                assert!(message.contains("/entries/0"));
+            }
+            other => panic!("expected invalid response, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_models_without_schema_are_not_checked() {
+        assert!(validate_response::<serde_json::Value>("{}").is_ok());
+        assert!(validate_response::<Leaderboard>("not json").is_ok());
+    }
+}
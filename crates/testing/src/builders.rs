@@ -3,7 +3,7 @@
 //! This module provides builder structs for creating complex domain entities
 //! with a fluent API for customization.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use llm_benchmark_domain::{
     benchmark::{BenchmarkCategory, BenchmarkMetadata, LicenseType},
     governance::{Proposal, ProposalStatus, ProposalType, VotingState},
@@ -16,6 +16,7 @@ use llm_benchmark_domain::{
 };
 use std::collections::HashSet;
 
+use crate::clock::FixtureClock;
 use crate::fixtures::{
     create_test_execution_metadata, create_test_model_info, create_test_submission_results,
     create_test_submitter_info,
@@ -30,6 +31,7 @@ pub struct UserBuilder {
     display_name: Option<String>,
     role: UserRole,
     email_verified: bool,
+    created_at: Option<DateTime<Utc>>,
 }
 
 impl UserBuilder {
@@ -41,6 +43,7 @@ impl UserBuilder {
             display_name: Some("Test User".to_string()),
             role: UserRole::Registered,
             email_verified: true,
+            created_at: None,
         }
     }
 
@@ -89,7 +92,17 @@ impl UserBuilder {
         self
     }
 
+    pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn with_clock(self, clock: &FixtureClock) -> Self {
+        self.with_created_at(clock.tick())
+    }
+
     pub fn build(self) -> User {
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
         User {
             id: self.id,
             email: self.email,
@@ -97,8 +110,8 @@ impl UserBuilder {
             display_name: self.display_name,
             role: self.role,
             organizations: vec![],
-            created_at: Utc::now(),
-            last_active_at: Some(Utc::now()),
+            created_at,
+            last_active_at: Some(created_at),
             email_verified: self.email_verified,
             profile: UserProfile {
                 bio: None,
@@ -126,6 +139,7 @@ pub struct OrganizationBuilder {
     slug: String,
     organization_type: OrganizationType,
     verified: bool,
+    created_at: Option<DateTime<Utc>>,
 }
 
 impl OrganizationBuilder {
@@ -136,6 +150,7 @@ impl OrganizationBuilder {
             slug: "test-org".to_string(),
             organization_type: OrganizationType::ResearchInstitution,
             verified: true,
+            created_at: None,
         }
     }
 
@@ -169,7 +184,17 @@ impl OrganizationBuilder {
         self
     }
 
+    pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn with_clock(self, clock: &FixtureClock) -> Self {
+        self.with_created_at(clock.tick())
+    }
+
     pub fn build(self) -> Organization {
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
         Organization {
             id: self.id,
             name: self.name,
@@ -179,8 +204,8 @@ impl OrganizationBuilder {
             logo_url: None,
             organization_type: self.organization_type,
             verified: self.verified,
-            verification_date: if self.verified { Some(Utc::now()) } else { None },
-            created_at: Utc::now(),
+            verification_date: if self.verified { Some(created_at) } else { None },
+            created_at,
         }
     }
 }
@@ -288,6 +313,7 @@ pub struct SubmissionBuilder {
     benchmark_version_id: BenchmarkVersionId,
     visibility: SubmissionVisibility,
     verification_level: VerificationLevel,
+    created_at: Option<DateTime<Utc>>,
 }
 
 impl SubmissionBuilder {
@@ -298,6 +324,7 @@ impl SubmissionBuilder {
             benchmark_version_id: BenchmarkVersionId::new(),
             visibility: SubmissionVisibility::Public,
             verification_level: VerificationLevel::Unverified,
+            created_at: None,
         }
     }
 
@@ -341,29 +368,40 @@ impl SubmissionBuilder {
         self
     }
 
+    pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn with_clock(self, clock: &FixtureClock) -> Self {
+        self.with_created_at(clock.tick())
+    }
+
     pub fn build(self) -> Submission {
-        Submission {
-            id: self.id,
-            benchmark_id: self.benchmark_id,
-            benchmark_version_id: self.benchmark_version_id,
-            model_info: create_test_model_info(),
-            submitter: create_test_submitter_info(),
-            results: create_test_submission_results(),
-            execution_metadata: create_test_execution_metadata(),
-            verification_status: VerificationStatus {
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
+        Submission::restore(
+            self.id,
+            self.benchmark_id,
+            self.benchmark_version_id,
+            create_test_model_info(),
+            create_test_submitter_info(),
+            create_test_submission_results(),
+            create_test_execution_metadata(),
+            VerificationStatus {
                 level: self.verification_level,
                 verified_at: if self.verification_level != VerificationLevel::Unverified {
-                    Some(Utc::now())
+                    Some(created_at)
                 } else {
                     None
                 },
                 verified_by: None,
                 verification_details: None,
             },
-            visibility: self.visibility,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-        }
+            self.visibility,
+            None,
+            created_at,
+            created_at,
+        )
     }
 }
 
@@ -383,6 +421,7 @@ pub struct ProposalBuilder {
     created_by: UserId,
     status: ProposalStatus,
     benchmark_id: Option<BenchmarkId>,
+    created_at: Option<DateTime<Utc>>,
 }
 
 impl ProposalBuilder {
@@ -395,6 +434,7 @@ impl ProposalBuilder {
             created_by: UserId::new(),
             status: ProposalStatus::Draft,
             benchmark_id: Some(BenchmarkId::new()),
+            created_at: None,
         }
     }
 
@@ -453,7 +493,17 @@ impl ProposalBuilder {
         self
     }
 
+    pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn with_clock(self, clock: &FixtureClock) -> Self {
+        self.with_created_at(clock.tick())
+    }
+
     pub fn build(self) -> Proposal {
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
         Proposal {
             id: self.id,
             proposal_type: self.proposal_type,
@@ -472,10 +522,12 @@ impl ProposalBuilder {
                 voters: HashSet::new(),
                 quorum_required: 10,
                 approval_threshold: 0.66,
+                ballots: Vec::new(),
+                role_weights: None,
             },
             reviews: vec![],
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
+            created_at,
+            updated_at: created_at,
         }
     }
 }
@@ -535,11 +587,44 @@ mod tests {
 
         assert_eq!(submission.visibility, SubmissionVisibility::Private);
         assert_eq!(
-            submission.verification_status.level,
+            submission.verification_status().level,
             VerificationLevel::PlatformVerified
         );
     }
 
+    #[test]
+    fn test_submissions_with_controlled_timestamps() {
+        let earlier = "2024-03-01T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let later = "2024-03-01T10:30:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let newer = SubmissionBuilder::new().with_created_at(later).build();
+        let older = SubmissionBuilder::new().with_created_at(earlier).build();
+        assert_eq!(older.created_at, earlier);
+        assert_eq!(older.updated_at, earlier);
+
+        let (older_id, newer_id) = (older.id, newer.id);
+        let mut submissions = [newer, older];
+        submissions.sort_by_key(|s| s.created_at);
+        assert_eq!(submissions[0].id, older_id);
+        assert_eq!(submissions[1].id, newer_id);
+    }
+
+    #[test]
+    fn test_builders_with_clock() {
+        let clock = FixtureClock::fixed();
+        let start = clock.now();
+
+        let first = SubmissionBuilder::new().with_clock(&clock).build();
+        let second = SubmissionBuilder::new().with_clock(&clock).build();
+        let user = UserBuilder::new().with_clock(&clock).build();
+        let proposal = ProposalBuilder::new().with_clock(&clock).build();
+
+        assert_eq!(first.created_at, start);
+        assert!(first.created_at < second.created_at);
+        assert!(second.created_at < user.created_at);
+        assert!(user.created_at < proposal.created_at);
+    }
+
     #[test]
     fn test_proposal_builder() {
         let proposal = ProposalBuilder::new()
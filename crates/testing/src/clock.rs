@@ -0,0 +1,99 @@
+//! Deterministic clock for fixtures.
+//!
+//! Builders stamp entities with `Utc::now()` by default, which makes
+//! assertions about time ordering depend on how fast the test runs. A
+//! [`FixtureClock`] hands out fixed, strictly increasing timestamps instead.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// Controllable clock for producing entity timestamps in tests
+///
+/// Clones share the same underlying time.
+#[derive(Debug, Clone)]
+pub struct FixtureClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+    step: Duration,
+}
+
+impl FixtureClock {
+    /// Create a clock starting at `start` that advances one second per tick
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+            step: Duration::seconds(1),
+        }
+    }
+
+    /// Create a clock starting at 2024-01-01T00:00:00Z
+    pub fn fixed() -> Self {
+        Self::new(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+    }
+
+    /// Set how far the clock advances on each [`tick`](Self::tick)
+    pub fn with_step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Current time, without advancing the clock
+    pub fn now(&self) -> DateTime<Utc> {
+        *self.now.lock()
+    }
+
+    /// Return the current time and advance the clock by one step
+    pub fn tick(&self) -> DateTime<Utc> {
+        let mut now = self.now.lock();
+        let current = *now;
+        *now = current + self.step;
+        current
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock() += by;
+    }
+
+    /// Set the clock to a specific time
+    pub fn set(&self, to: DateTime<Utc>) {
+        *self.now.lock() = to;
+    }
+}
+
+impl Default for FixtureClock {
+    fn default() -> Self {
+        Self::fixed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_is_strictly_increasing() {
+        let clock = FixtureClock::fixed().with_step(Duration::minutes(5));
+        let start = clock.now();
+
+        let first = clock.tick();
+        let second = clock.tick();
+
+        assert_eq!(first, start);
+        assert_eq!(second - first, Duration::minutes(5));
+        assert_eq!(clock.now(), start + Duration::minutes(10));
+    }
+
+    #[test]
+    fn test_clones_share_time() {
+        let clock = FixtureClock::fixed();
+        let shared = clock.clone();
+
+        shared.advance(Duration::hours(1));
+        assert_eq!(clock.now(), FixtureClock::fixed().now() + Duration::hours(1));
+
+        let target = Utc.with_ymd_and_hms(2030, 6, 1, 12, 0, 0).unwrap();
+        clock.set(target);
+        assert_eq!(shared.tick(), target);
+    }
+}
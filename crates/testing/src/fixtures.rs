@@ -19,8 +19,7 @@ use llm_benchmark_domain::{
     submission::{
         ConfidenceInterval, EnvironmentInfo, ExecutionMetadata, HardwareInfo, MetricScore,
         ModelInfo, StatisticalSignificance, Submission, SubmissionResults, SubmissionVisibility,
-        SubmitterInfo, TestCaseError, TestCaseErrorType, TestCaseResult, VerificationLevel,
-        VerificationStatus,
+        SubmitterInfo, TestCaseError, TestCaseErrorType, TestCaseResult,
     },
     user::{Organization, OrganizationMembership, OrganizationRole, OrganizationType, User, UserProfile, UserRole},
     version::SemanticVersion,
@@ -281,30 +280,27 @@ pub fn create_test_execution_metadata() -> ExecutionMetadata {
 
 /// Create a test submission
 pub fn create_test_submission() -> Submission {
-    Submission {
-        id: SubmissionId::new(),
-        benchmark_id: BenchmarkId::new(),
-        benchmark_version_id: BenchmarkVersionId::new(),
-        model_info: create_test_model_info(),
-        submitter: create_test_submitter_info(),
-        results: create_test_submission_results(),
-        execution_metadata: create_test_execution_metadata(),
-        verification_status: VerificationStatus {
-            level: VerificationLevel::Unverified,
-            verified_at: None,
-            verified_by: None,
-            verification_details: None,
-        },
-        visibility: SubmissionVisibility::Public,
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-    }
+    create_test_submission_with_results(create_test_submission_results())
+}
+
+fn create_test_submission_with_results(results: SubmissionResults) -> Submission {
+    Submission::new(
+        BenchmarkId::new(),
+        BenchmarkVersionId::new(),
+        create_test_model_info(),
+        create_test_submitter_info(),
+        results,
+        create_test_execution_metadata(),
+        SubmissionVisibility::Public,
+        None,
+    )
+    .expect("test submission results are valid")
 }
 
 /// Create a test submission with failed test cases
 pub fn create_test_submission_with_failures() -> Submission {
-    let mut submission = create_test_submission();
-    submission.results.test_case_results.push(TestCaseResult {
+    let mut results = create_test_submission_results();
+    results.test_case_results.push(TestCaseResult {
         test_case_id: "test_case_3".to_string(),
         passed: false,
         score: 0.0,
@@ -315,7 +311,7 @@ pub fn create_test_submission_with_failures() -> Submission {
             message: "Request timed out after 30s".to_string(),
         }),
     });
-    submission
+    create_test_submission_with_results(results)
 }
 
 /// Create a test proposal
@@ -338,6 +334,8 @@ pub fn create_test_proposal() -> Proposal {
             voters: HashSet::new(),
             quorum_required: 10,
             approval_threshold: 0.66,
+            ballots: Vec::new(),
+            role_weights: None,
         },
         reviews: vec![],
         created_at: Utc::now(),
@@ -396,14 +394,14 @@ mod tests {
     #[test]
     fn test_create_submission() {
         let submission = create_test_submission();
-        assert_eq!(submission.results.test_case_results.len(), 2);
-        assert!(submission.results.confidence_interval.is_some());
+        assert_eq!(submission.results().test_case_results.len(), 2);
+        assert!(submission.results().confidence_interval.is_some());
     }
 
     #[test]
     fn test_create_submission_with_failures() {
         let submission = create_test_submission_with_failures();
-        assert!(submission.results.test_case_results.iter().any(|r| !r.passed));
+        assert!(submission.results().test_case_results.iter().any(|r| !r.passed));
     }
 
     #[test]
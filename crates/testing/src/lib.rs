@@ -3,9 +3,11 @@
 //! This crate provides comprehensive testing utilities including:
 //! - Test fixtures for all domain types
 //! - Builder patterns for complex test data construction
+//! - A deterministic clock for controlling entity timestamps
 //! - Mock implementations of repositories and services
 //! - Test database setup with testcontainers
-//! - Property-based testing utilities
+//! - Property-based testing utilities, including serde round-trip checks
+//! - Conformance checks for repository port implementations
 //!
 //! # Examples
 //!
@@ -23,15 +25,20 @@
 //! ```
 
 pub mod builders;
+pub mod clock;
 pub mod database;
 pub mod fixtures;
 pub mod mocks;
+pub mod repository_conformance;
+pub mod serde_roundtrip;
 pub mod testcontainers_ext;
 
 // Re-export commonly used types
 pub use builders::*;
+pub use clock::FixtureClock;
 pub use fixtures::*;
 pub use mocks::*;
+pub use serde_roundtrip::assert_serde_roundtrip;
 
 // Re-export testing dependencies for convenience
 pub use fake;
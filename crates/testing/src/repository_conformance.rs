@@ -0,0 +1,420 @@
+//! Repository conformance suite.
+//!
+//! Generic checks that every implementation of the application repository
+//! ports must pass, so the in-memory and PostgreSQL repositories behave the
+//! same way. Each check is a standalone async function taking the repository
+//! under test; `run_*` helpers execute the whole suite for a port.
+//!
+//! The checks only rely on data they create themselves (scoped by a unique
+//! search term or benchmark), so they can run against a shared database.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use llm_benchmark_testing::repository_conformance::*;
+//!
+//! #[tokio::test]
+//! async fn in_memory_benchmarks_conform() {
+//!     let repo = InMemoryBenchmarkRepository::new();
+//!     run_benchmark_conformance(&repo, &uuid::Uuid::new_v4().to_string()).await;
+//! }
+//! ```
+
+use llm_benchmark_application::{
+    services::{
+        BenchmarkFilters, BenchmarkRepositoryPort, CreateBenchmarkData, CreateSubmissionData,
+        Pagination, SubmissionRepositoryPort, UpdateBenchmarkData, UpdateSubmissionData,
+    },
+    validation::SubmissionQueryFilters,
+    ApplicationError,
+};
+use llm_benchmark_domain::{
+    benchmark::BenchmarkCategory,
+    submission::{SubmissionVisibility, VerificationLevel},
+    test_case::TestCase,
+};
+use std::collections::HashSet;
+
+/// Existing records that submissions created by the suite refer to
+#[derive(Debug, Clone)]
+pub struct SubmissionFixture {
+    pub benchmark_id: String,
+    pub benchmark_version_id: String,
+    pub submitter_id: String,
+}
+
+impl SubmissionFixture {
+    /// Fixture with fresh IDs, for repositories without referential integrity
+    pub fn unreferenced() -> Self {
+        Self {
+            benchmark_id: uuid::Uuid::new_v4().to_string(),
+            benchmark_version_id: uuid::Uuid::new_v4().to_string(),
+            submitter_id: uuid::Uuid::new_v4().to_string(),
+        }
+    }
+}
+
+fn unique_token() -> String {
+    format!("conformance{}", uuid::Uuid::new_v4().simple())
+}
+
+fn missing_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn assert_not_found<T: std::fmt::Debug>(result: Result<T, ApplicationError>, operation: &str) {
+    match result {
+        Err(ApplicationError::NotFound(_)) => {}
+        other => panic!("{} on a missing record should be NotFound, got {:?}", operation, other),
+    }
+}
+
+fn benchmark_data(token: &str, index: usize, creator_id: &str) -> CreateBenchmarkData {
+    CreateBenchmarkData {
+        name: format!("Benchmark {} {}", token, index),
+        slug: format!("{}-{}", token, index),
+        description: format!("Conformance benchmark {}", index),
+        category: BenchmarkCategory::Accuracy,
+        tags: vec!["conformance".to_string()],
+        version: "1.0.0".to_string(),
+        creator_id: creator_id.to_string(),
+        leaderboard_min_verification: VerificationLevel::Unverified,
+        test_cases: vec![test_case("case-1")],
+    }
+}
+
+fn test_case(id: &str) -> TestCase {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "name": id,
+        "input": {
+            "prompt_template": format!("Question {}?", id),
+            "variables": {},
+            "few_shot_examples": [],
+            "input_format": {"type": "plain_text"}
+        },
+        "evaluation_method": {"type": "exact_match"},
+        "weight": 1.0,
+        "tags": []
+    }))
+    .expect("valid test case")
+}
+
+fn test_case_ids(test_cases: &[TestCase]) -> Vec<&str> {
+    test_cases.iter().map(|t| t.id.as_str()).collect()
+}
+
+fn submission_data(fixture: &SubmissionFixture, score: f64) -> CreateSubmissionData {
+    CreateSubmissionData {
+        benchmark_id: fixture.benchmark_id.clone(),
+        benchmark_version_id: fixture.benchmark_version_id.clone(),
+        model_provider: "conformance".to_string(),
+        model_name: format!("model-{}", uuid::Uuid::new_v4().simple()),
+        model_version: Some("1.0".to_string()),
+        submitter_id: fixture.submitter_id.clone(),
+        organization_id: None,
+        aggregate_score: score,
+        visibility: SubmissionVisibility::Public,
+    }
+}
+
+fn search(token: &str) -> BenchmarkFilters {
+    BenchmarkFilters {
+        search: Some(token.to_string()),
+        ..Default::default()
+    }
+}
+
+fn for_benchmark(fixture: &SubmissionFixture) -> SubmissionQueryFilters {
+    SubmissionQueryFilters {
+        benchmark_id: Some(fixture.benchmark_id.clone()),
+        ..Default::default()
+    }
+}
+
+// ============================================================================
+// Benchmark repository
+// ============================================================================
+
+/// Created benchmarks can be read back by ID and slug
+pub async fn benchmark_create_and_get<R: BenchmarkRepositoryPort>(repo: &R, creator_id: &str) {
+    let token = unique_token();
+    let data = benchmark_data(&token, 0, creator_id);
+    let id = repo.create(&data).await.expect("create benchmark");
+
+    let by_id = repo
+        .get_by_id(&id)
+        .await
+        .expect("get benchmark by id")
+        .expect("created benchmark exists");
+    assert_eq!(by_id.id, id);
+    assert_eq!(by_id.name, data.name);
+    assert_eq!(by_id.slug, data.slug);
+    assert_eq!(by_id.description, data.description);
+    assert_eq!(by_id.category, data.category);
+
+    let by_slug = repo
+        .get_by_slug(&data.slug)
+        .await
+        .expect("get benchmark by slug")
+        .expect("created benchmark exists by slug");
+    assert_eq!(by_slug.id, id);
+    assert!(repo.slug_exists(&data.slug).await.expect("slug exists"));
+}
+
+/// Lookups of unknown benchmarks return `None`, mutations return `NotFound`
+pub async fn benchmark_not_found<R: BenchmarkRepositoryPort>(repo: &R) {
+    let id = missing_id();
+
+    assert!(repo.get_by_id(&id).await.expect("get missing").is_none());
+    assert!(repo
+        .get_by_slug(&unique_token())
+        .await
+        .expect("get missing slug")
+        .is_none());
+    assert!(!repo.slug_exists(&unique_token()).await.expect("slug exists"));
+
+    let update = UpdateBenchmarkData {
+        name: Some("Renamed".to_string()),
+        description: None,
+        tags: None,
+        long_description: None,
+        test_cases: None,
+    };
+    assert_not_found(repo.update(&id, &update).await, "update");
+    assert_not_found(repo.delete(&id).await, "delete");
+}
+
+/// Updates change only the provided fields
+pub async fn benchmark_update<R: BenchmarkRepositoryPort>(repo: &R, creator_id: &str) {
+    let token = unique_token();
+    let data = benchmark_data(&token, 0, creator_id);
+    let id = repo.create(&data).await.expect("create benchmark");
+    let test_cases = repo.get_test_cases(&id).await.expect("get test cases");
+    assert_eq!(test_case_ids(&test_cases), ["case-1"]);
+
+    let update = UpdateBenchmarkData {
+        name: Some(format!("Renamed {}", token)),
+        description: None,
+        tags: Some(vec!["updated".to_string()]),
+        long_description: None,
+        test_cases: None,
+    };
+    repo.update(&id, &update).await.expect("update benchmark");
+
+    let updated = repo.get_by_id(&id).await.expect("get").expect("exists");
+    assert_eq!(updated.name, format!("Renamed {}", token));
+    assert_eq!(updated.description, data.description);
+    assert_eq!(updated.tags, vec!["updated".to_string()]);
+    assert_eq!(updated.slug, data.slug);
+    let test_cases = repo.get_test_cases(&id).await.expect("get test cases");
+    assert_eq!(test_case_ids(&test_cases), ["case-1"]);
+
+    let update = UpdateBenchmarkData {
+        name: None,
+        description: None,
+        tags: None,
+        long_description: None,
+        test_cases: Some(vec![test_case("case-2"), test_case("case-3")]),
+    };
+    repo.update(&id, &update).await.expect("replace test cases");
+    let test_cases = repo.get_test_cases(&id).await.expect("get test cases");
+    assert_eq!(test_case_ids(&test_cases), ["case-2", "case-3"]);
+}
+
+/// Deleted benchmarks are gone, and deleting twice reports `NotFound`
+pub async fn benchmark_delete<R: BenchmarkRepositoryPort>(repo: &R, creator_id: &str) {
+    let token = unique_token();
+    let data = benchmark_data(&token, 0, creator_id);
+    let id = repo.create(&data).await.expect("create benchmark");
+
+    repo.delete(&id).await.expect("delete benchmark");
+    assert!(repo.get_by_id(&id).await.expect("get deleted").is_none());
+    assert!(!repo.slug_exists(&data.slug).await.expect("slug exists"));
+    assert_not_found(repo.delete(&id).await, "second delete");
+}
+
+/// Pages are disjoint, cover every match and report the full total
+pub async fn benchmark_pagination<R: BenchmarkRepositoryPort>(repo: &R, creator_id: &str) {
+    let token = unique_token();
+    let mut created = HashSet::new();
+    for i in 0..5 {
+        created.insert(
+            repo.create(&benchmark_data(&token, i, creator_id))
+                .await
+                .expect("create benchmark"),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    for page in 1..=3 {
+        let (items, total) = repo
+            .list(&search(&token), &Pagination::new(page, 2))
+            .await
+            .expect("list benchmarks");
+        assert_eq!(total, 5, "total on page {}", page);
+        assert_eq!(items.len(), if page < 3 { 2 } else { 1 }, "items on page {}", page);
+        for item in items {
+            assert!(seen.insert(item.id), "benchmark repeated across pages");
+        }
+    }
+    assert_eq!(seen, created);
+
+    let (items, total) = repo
+        .list(&search(&token), &Pagination::new(4, 2))
+        .await
+        .expect("list past the last page");
+    assert!(items.is_empty());
+    assert_eq!(total, 5);
+
+    let (items, total) = repo
+        .list(&search(&unique_token()), &Pagination::default())
+        .await
+        .expect("list without matches");
+    assert!(items.is_empty());
+    assert_eq!(total, 0);
+}
+
+/// Run every benchmark repository check
+pub async fn run_benchmark_conformance<R: BenchmarkRepositoryPort>(repo: &R, creator_id: &str) {
+    benchmark_create_and_get(repo, creator_id).await;
+    benchmark_not_found(repo).await;
+    benchmark_update(repo, creator_id).await;
+    benchmark_delete(repo, creator_id).await;
+    benchmark_pagination(repo, creator_id).await;
+}
+
+// ============================================================================
+// Submission repository
+// ============================================================================
+
+/// Created submissions can be read back by ID
+pub async fn submission_create_and_get<R: SubmissionRepositoryPort>(
+    repo: &R,
+    fixture: &SubmissionFixture,
+) {
+    let data = submission_data(fixture, 0.75);
+    let id = repo.create(&data).await.expect("create submission");
+
+    let submission = repo
+        .get_by_id(&id)
+        .await
+        .expect("get submission")
+        .expect("created submission exists");
+    assert_eq!(submission.id, id);
+    assert_eq!(submission.benchmark_id, data.benchmark_id);
+    assert_eq!(submission.model_name, data.model_name);
+    assert_eq!(submission.model_provider, data.model_provider);
+    assert_eq!(submission.submitter_id, data.submitter_id);
+    assert_eq!(submission.visibility, data.visibility);
+}
+
+/// Lookups of unknown submissions return `None`, mutations return `NotFound`
+pub async fn submission_not_found<R: SubmissionRepositoryPort>(repo: &R) {
+    let id = missing_id();
+
+    assert!(repo.get_by_id(&id).await.expect("get missing").is_none());
+
+    let update = UpdateSubmissionData {
+        visibility: Some(SubmissionVisibility::Private),
+        notes: None,
+    };
+    assert_not_found(repo.update(&id, &update).await, "update");
+    assert_not_found(repo.delete(&id).await, "delete");
+}
+
+/// Visibility updates are persisted
+pub async fn submission_update<R: SubmissionRepositoryPort>(
+    repo: &R,
+    fixture: &SubmissionFixture,
+) {
+    let id = repo
+        .create(&submission_data(fixture, 0.5))
+        .await
+        .expect("create submission");
+
+    let update = UpdateSubmissionData {
+        visibility: Some(SubmissionVisibility::Unlisted),
+        notes: None,
+    };
+    repo.update(&id, &update).await.expect("update submission");
+
+    let updated = repo.get_by_id(&id).await.expect("get").expect("exists");
+    assert_eq!(updated.visibility, SubmissionVisibility::Unlisted);
+}
+
+/// Deleted submissions are gone, and deleting twice reports `NotFound`
+pub async fn submission_delete<R: SubmissionRepositoryPort>(
+    repo: &R,
+    fixture: &SubmissionFixture,
+) {
+    let id = repo
+        .create(&submission_data(fixture, 0.5))
+        .await
+        .expect("create submission");
+
+    repo.delete(&id).await.expect("delete submission");
+    assert!(repo.get_by_id(&id).await.expect("get deleted").is_none());
+    assert_not_found(repo.delete(&id).await, "second delete");
+}
+
+/// Pages are disjoint, cover every match and report the full total
+///
+/// Expects the fixture's benchmark to have no other submissions.
+pub async fn submission_pagination<R: SubmissionRepositoryPort>(
+    repo: &R,
+    fixture: &SubmissionFixture,
+) {
+    let mut created = HashSet::new();
+    for i in 0..5 {
+        created.insert(
+            repo.create(&submission_data(fixture, 0.1 * (i + 1) as f64))
+                .await
+                .expect("create submission"),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    for page in 1..=3 {
+        let (items, total) = repo
+            .list(&for_benchmark(fixture), &Pagination::new(page, 2))
+            .await
+            .expect("list submissions");
+        assert_eq!(total, 5, "total on page {}", page);
+        assert_eq!(items.len(), if page < 3 { 2 } else { 1 }, "items on page {}", page);
+        for item in items {
+            assert_eq!(item.benchmark_id, fixture.benchmark_id);
+            assert!(seen.insert(item.id), "submission repeated across pages");
+        }
+    }
+    assert_eq!(seen, created);
+
+    let (items, total) = repo
+        .list(&for_benchmark(fixture), &Pagination::new(4, 2))
+        .await
+        .expect("list past the last page");
+    assert!(items.is_empty());
+    assert_eq!(total, 5);
+
+    let (items, total) = repo
+        .get_user_submissions(&fixture.submitter_id, &Pagination::new(1, 10))
+        .await
+        .expect("list user submissions");
+    assert_eq!(total, 5);
+    assert_eq!(items.len(), 5);
+}
+
+/// Run every submission repository check
+///
+/// `fixture` must refer to a benchmark and submitter without submissions;
+/// checks that create data are given their own copy of it.
+pub async fn run_submission_conformance<R: SubmissionRepositoryPort>(
+    repo: &R,
+    fixture: &SubmissionFixture,
+) {
+    submission_pagination(repo, fixture).await;
+    submission_create_and_get(repo, fixture).await;
+    submission_not_found(repo).await;
+    submission_update(repo, fixture).await;
+    submission_delete(repo, fixture).await;
+}
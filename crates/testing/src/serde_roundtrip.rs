@@ -0,0 +1,194 @@
+//! Serialization round-trip checks.
+//!
+//! [`assert_serde_roundtrip`] asserts that a value survives a trip through
+//! JSON unchanged. The proptest strategies below generate the core domain
+//! value objects so serde regressions (renamed fields, skipped options,
+//! lossy numbers) are caught across many shapes rather than one fixture.
+//!
+//! Generated floats are kept to four decimal places, which JSON represents
+//! exactly.
+
+use llm_benchmark_domain::{
+    submission::{
+        ConfidenceInterval, MetricScore, StatisticalSignificance, SubmissionResults,
+        TestCaseError, TestCaseErrorType, TestCaseResult,
+    },
+    version::SemanticVersion,
+};
+use proptest::{collection, option, prelude::*};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+/// Assert that `value` deserializes back to itself after serializing to JSON
+pub fn assert_serde_roundtrip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).expect("value serializes to JSON");
+    let decoded: T = serde_json::from_str(&json)
+        .unwrap_or_else(|e| panic!("failed to deserialize {}: {}", json, e));
+    assert_eq!(&decoded, value, "round trip through {} changed the value", json);
+}
+
+/// Scores in `[0, 1]`
+pub fn arb_score() -> impl Strategy<Value = f64> {
+    (0u32..=10_000).prop_map(|n| f64::from(n) / 10_000.0)
+}
+
+fn arb_identifier() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9]{0,7}(\\.[a-z0-9]{1,4}){0,2}"
+}
+
+/// Semantic versions, with and without prerelease and build metadata
+pub fn arb_semantic_version() -> impl Strategy<Value = SemanticVersion> {
+    (
+        0u32..1000,
+        0u32..1000,
+        0u32..1000,
+        option::of(arb_identifier()),
+        option::of(arb_identifier()),
+    )
+        .prop_map(|(major, minor, patch, prerelease, build_metadata)| SemanticVersion {
+            major,
+            minor,
+            patch,
+            prerelease,
+            build_metadata,
+        })
+}
+
+fn arb_metric_score() -> impl Strategy<Value = MetricScore> {
+    (
+        arb_score(),
+        option::of("[a-z]{1,6}"),
+        option::of(collection::vec(arb_score(), 0..5)),
+        option::of(arb_score()),
+    )
+        .prop_map(|(value, unit, raw_values, std_dev)| MetricScore {
+            value,
+            unit,
+            raw_values,
+            std_dev,
+        })
+}
+
+fn arb_test_case_error() -> impl Strategy<Value = TestCaseError> {
+    (
+        prop_oneof![
+            Just(TestCaseErrorType::Timeout),
+            Just(TestCaseErrorType::RateLimited),
+            Just(TestCaseErrorType::ModelError),
+            Just(TestCaseErrorType::InvalidOutput),
+            Just(TestCaseErrorType::EvaluationError),
+        ],
+        ".{0,32}",
+    )
+        .prop_map(|(error_type, message)| TestCaseError {
+            error_type,
+            message,
+        })
+}
+
+fn arb_test_case_result() -> impl Strategy<Value = TestCaseResult> {
+    (
+        "tc-[0-9]{1,4}",
+        any::<bool>(),
+        arb_score(),
+        option::of(any::<u64>()),
+        option::of(any::<u32>()),
+        option::of(arb_test_case_error()),
+    )
+        .prop_map(
+            |(test_case_id, passed, score, latency_ms, tokens_generated, error)| TestCaseResult {
+                test_case_id,
+                passed,
+                score,
+                latency_ms,
+                tokens_generated,
+                error,
+            },
+        )
+}
+
+fn arb_confidence_interval() -> impl Strategy<Value = ConfidenceInterval> {
+    (arb_score(), arb_score(), arb_score()).prop_map(|(a, b, confidence_level)| {
+        ConfidenceInterval {
+            lower: a.min(b),
+            upper: a.max(b),
+            confidence_level,
+        }
+    })
+}
+
+fn arb_statistical_significance() -> impl Strategy<Value = StatisticalSignificance> {
+    (arb_score(), arb_score(), 0usize..100_000, "[a-z_]{1,16}").prop_map(
+        |(p_value, effect_size, sample_size, test_used)| StatisticalSignificance {
+            p_value,
+            effect_size,
+            sample_size,
+            test_used,
+        },
+    )
+}
+
+/// Submission results with a mix of metrics, test cases and statistics
+pub fn arb_submission_results() -> impl Strategy<Value = SubmissionResults> {
+    (
+        arb_score(),
+        collection::hash_map("[a-z_]{1,12}", arb_metric_score(), 0..5),
+        collection::vec(arb_test_case_result(), 0..5),
+        option::of(arb_confidence_interval()),
+        option::of(arb_statistical_significance()),
+    )
+        .prop_map(
+            |(
+                aggregate_score,
+                metric_scores,
+                test_case_results,
+                confidence_interval,
+                statistical_significance,
+            )| SubmissionResults {
+                aggregate_score,
+                metric_scores,
+                test_case_results,
+                confidence_interval,
+                statistical_significance,
+            },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{create_test_submission_results, create_test_version};
+
+    #[test]
+    fn test_fixtures_roundtrip() {
+        assert_serde_roundtrip(&create_test_version());
+        assert_serde_roundtrip(&create_test_submission_results());
+    }
+
+    #[test]
+    #[should_panic(expected = "round trip")]
+    fn test_lossy_type_fails() {
+        #[derive(Debug, PartialEq, Serialize, serde::Deserialize)]
+        struct Lossy {
+            #[serde(skip)]
+            value: u32,
+        }
+
+        assert_serde_roundtrip(&Lossy { value: 1 });
+    }
+
+    proptest! {
+        #[test]
+        fn test_semantic_version_roundtrip(version in arb_semantic_version()) {
+            assert_serde_roundtrip(&version);
+        }
+
+        #[test]
+        fn test_submission_results_roundtrip(results in arb_submission_results()) {
+            assert_serde_roundtrip(&results);
+        }
+    }
+}
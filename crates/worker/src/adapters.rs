@@ -0,0 +1,75 @@
+//! Bindings from application ports to infrastructure
+//!
+//! The workers depend on the application's repository ports; these adapters
+//! back them with the PostgreSQL repositories.
+
+use async_trait::async_trait;
+use llm_benchmark_application::services::ProposalRepositoryPort;
+use llm_benchmark_application::ApplicationError;
+use llm_benchmark_domain::governance::{Comment, Proposal, ProposalStatus};
+use llm_benchmark_domain::identifiers::{CommentId, ProposalId};
+use llm_benchmark_infrastructure::{
+    DatabasePool, Error as InfraError, PgProposalRepository, ProposalRepository,
+};
+
+/// Map an infrastructure error onto the application's error kinds
+fn map_infra_error(err: InfraError) -> ApplicationError {
+    match err {
+        InfraError::NotFound(msg) => ApplicationError::NotFound(msg),
+        InfraError::Conflict(msg) => ApplicationError::Conflict(msg),
+        InfraError::Timeout(msg) => ApplicationError::Timeout(msg),
+        InfraError::Connection(msg) => ApplicationError::ServiceUnavailable(msg),
+        other if other.is_unique_violation() => ApplicationError::Conflict(other.to_string()),
+        other => ApplicationError::Internal(other.to_string()),
+    }
+}
+
+/// PostgreSQL-backed proposal store
+pub struct PgProposalStore {
+    proposals: PgProposalRepository,
+}
+
+impl PgProposalStore {
+    /// Create a store over `db`
+    pub fn new(db: &DatabasePool) -> Self {
+        Self {
+            proposals: PgProposalRepository::new(db.pool().clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl ProposalRepositoryPort for PgProposalStore {
+    async fn get_by_id(&self, id: &ProposalId) -> Result<Option<Proposal>, ApplicationError> {
+        self.proposals.get_by_id(*id).await.map_err(map_infra_error)
+    }
+
+    async fn update(
+        &self,
+        proposal: &Proposal,
+        expected_status: ProposalStatus,
+    ) -> Result<(), ApplicationError> {
+        self.proposals
+            .update(proposal, expected_status)
+            .await
+            .map_err(map_infra_error)
+    }
+
+    async fn add_comment(&self, comment: &Comment) -> Result<(), ApplicationError> {
+        self.proposals.add_comment(comment).await.map_err(map_infra_error)
+    }
+
+    async fn get_comment(&self, id: &CommentId) -> Result<Option<Comment>, ApplicationError> {
+        self.proposals.get_comment(*id).await.map_err(map_infra_error)
+    }
+
+    async fn list_comments(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<Vec<Comment>, ApplicationError> {
+        self.proposals
+            .list_comments(*proposal_id)
+            .await
+            .map_err(map_infra_error)
+    }
+}
@@ -1,6 +1,8 @@
 //! Worker configuration
 
+use llm_benchmark_domain::governance::{ProposalPolicy, ProposalType, RoleWeights};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Worker pool configuration
@@ -23,6 +25,10 @@ pub struct WorkerConfig {
 
     /// Scheduler settings
     pub scheduler: SchedulerConfig,
+
+    /// Governance finalization settings
+    #[serde(default)]
+    pub governance: GovernanceConfig,
 }
 
 impl Default for WorkerConfig {
@@ -34,6 +40,7 @@ impl Default for WorkerConfig {
             queue: QueueConfig::default(),
             retry: RetryConfig::default(),
             scheduler: SchedulerConfig::default(),
+            governance: GovernanceConfig::default(),
         }
     }
 }
@@ -61,6 +68,14 @@ pub struct QueueConfig {
 
     /// Job visibility timeout (seconds)
     pub visibility_timeout: u64,
+
+    /// How long a job's status is kept after its last transition (seconds)
+    #[serde(default = "default_status_ttl")]
+    pub status_ttl: u64,
+}
+
+fn default_status_ttl() -> u64 {
+    crate::queue::status::DEFAULT_STATUS_TTL_SECS
 }
 
 impl Default for QueueConfig {
@@ -73,6 +88,7 @@ impl Default for QueueConfig {
             max_retries: 3,
             dead_letter_queue: "jobs:dlq".to_string(),
             visibility_timeout: 300, // 5 minutes
+            status_ttl: default_status_ttl(),
         }
     }
 }
@@ -170,6 +186,77 @@ impl Default for SchedulerConfig {
     }
 }
 
+/// Governance finalization configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceConfig {
+    /// Quorum and approval threshold per proposal type
+    pub policies: HashMap<ProposalType, ProposalPolicy>,
+
+    /// Policy used for proposal types without an explicit entry
+    pub default_policy: ProposalPolicy,
+
+    /// Voting power of each voter role when tallying
+    #[serde(default = "default_role_weights")]
+    pub role_weights: RoleWeights,
+}
+
+fn default_role_weights() -> RoleWeights {
+    RoleWeights::platform_default()
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        let mut policies = HashMap::new();
+        policies.insert(
+            ProposalType::NewBenchmark,
+            ProposalPolicy {
+                quorum: 10,
+                approval_threshold: 0.5,
+            },
+        );
+        policies.insert(
+            ProposalType::UpdateBenchmark,
+            ProposalPolicy {
+                quorum: 5,
+                approval_threshold: 0.5,
+            },
+        );
+        policies.insert(
+            ProposalType::DeprecateBenchmark,
+            ProposalPolicy {
+                quorum: 10,
+                approval_threshold: 0.66,
+            },
+        );
+        policies.insert(
+            ProposalType::PolicyChange,
+            ProposalPolicy {
+                quorum: 20,
+                approval_threshold: 0.66,
+            },
+        );
+
+        Self {
+            policies,
+            default_policy: ProposalPolicy {
+                quorum: 10,
+                approval_threshold: 0.66,
+            },
+            role_weights: default_role_weights(),
+        }
+    }
+}
+
+impl GovernanceConfig {
+    /// Get the finalization policy for a proposal type
+    pub fn policy_for(&self, proposal_type: ProposalType) -> ProposalPolicy {
+        self.policies
+            .get(&proposal_type)
+            .copied()
+            .unwrap_or(self.default_policy)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,4 +292,19 @@ mod tests {
         assert_eq!(config.calculate_backoff(5), Duration::from_secs(80));
         assert_eq!(config.calculate_backoff(6), Duration::from_secs(100)); // capped at max
     }
+
+    #[test]
+    fn test_governance_policy_lookup() {
+        let mut config = GovernanceConfig::default();
+        config.policies.remove(&ProposalType::PolicyChange);
+
+        assert_eq!(
+            config.policy_for(ProposalType::UpdateBenchmark).quorum,
+            5
+        );
+        assert_eq!(
+            config.policy_for(ProposalType::PolicyChange),
+            config.default_policy
+        );
+    }
 }
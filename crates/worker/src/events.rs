@@ -0,0 +1,40 @@
+//! Service events published by the worker
+//!
+//! Forwards [`ServiceEvent`]s from the workers to Redis, on the same channels
+//! the API publishes on, so API subscribers see progress made here.
+
+use async_trait::async_trait;
+use llm_benchmark_application::services::{EventPublisher, ServiceEvent};
+use llm_benchmark_application::ApplicationError;
+use llm_benchmark_infrastructure::{EventMessage, Publisher};
+use std::sync::Arc;
+
+/// Source name attached to events published by the worker
+const EVENT_SOURCE: &str = "worker";
+
+/// Event publisher that forwards service events to a message broker
+pub struct MessagingEventPublisher<P: Publisher> {
+    publisher: Arc<P>,
+}
+
+impl<P: Publisher> MessagingEventPublisher<P> {
+    /// Create a publisher forwarding to `publisher`
+    pub fn new(publisher: Arc<P>) -> Self {
+        Self { publisher }
+    }
+}
+
+#[async_trait]
+impl<P: Publisher + 'static> EventPublisher for MessagingEventPublisher<P> {
+    async fn publish(&self, event: ServiceEvent) -> Result<(), ApplicationError> {
+        let (channel, payload) = event.to_message();
+        let message = EventMessage::new(channel.as_str(), payload, EVENT_SOURCE);
+
+        let published = if event.is_retained() {
+            self.publisher.publish_retained(&channel, &message).await
+        } else {
+            self.publisher.publish(&channel, &message).await
+        };
+        published.map_err(|e| ApplicationError::ServiceUnavailable(e.to_string()))
+    }
+}
@@ -9,27 +9,36 @@
 //! - Retry policies and dead letter queue
 //! - Metrics and monitoring
 
+pub mod adapters;
 pub mod config;
+pub mod events;
 pub mod metrics;
 pub mod queue;
 pub mod scheduler;
 pub mod workers;
 
 pub use config::WorkerConfig;
-pub use metrics::WorkerMetrics;
+pub use metrics::{MetricsDelta, MetricsSnapshot, WorkerMetrics};
 pub use queue::{JobConsumer, JobProducer, JobQueue};
 
+use adapters::PgProposalStore;
 use anyhow::Result;
+use events::MessagingEventPublisher;
+use llm_benchmark_application::services::EventPublisher;
+use llm_benchmark_infrastructure::{DatabaseConfig, DatabasePool, MessagingConfig, RedisMessaging};
 use scheduler::Scheduler;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+use workers::JobHandler;
 
 /// Worker pool for processing background jobs
 pub struct WorkerPool {
     config: WorkerConfig,
     producer: JobProducer,
     consumer: JobConsumer,
+    handler: Arc<JobHandler>,
     metrics: WorkerMetrics,
     shutdown_tx: mpsc::Sender<()>,
     shutdown_rx: mpsc::Receiver<()>,
@@ -38,8 +47,35 @@ pub struct WorkerPool {
 impl WorkerPool {
     /// Create a new worker pool
     pub async fn new(config: WorkerConfig) -> Result<Self> {
-        let producer = JobProducer::new(&config.redis_url).await?;
-        let consumer = JobConsumer::new(&config.redis_url, config.pool_size).await?;
+        // Events go to the API's channels, on the same Redis as the queue
+        let messaging = RedisMessaging::new(MessagingConfig {
+            url: config.redis_url.clone(),
+            ..MessagingConfig::from_env()?
+        })
+        .await?;
+        let event_publisher: Arc<dyn EventPublisher> =
+            Arc::new(MessagingEventPublisher::new(Arc::new(messaging)));
+
+        let producer = JobProducer::new(&config.redis_url)
+            .await?
+            .with_status_ttl(config.queue.status_ttl)
+            .with_event_publisher(event_publisher.clone());
+        let consumer = JobConsumer::new(&config.redis_url, config.pool_size)
+            .await?
+            .with_status_ttl(config.queue.status_ttl);
+        let mut handler = JobHandler::new(config.clone()).with_event_publisher(event_publisher);
+        match &config.database_url {
+            Some(url) => {
+                let db = DatabasePool::new(&DatabaseConfig {
+                    url: url.clone(),
+                    ..DatabaseConfig::default()
+                })
+                .await?;
+                handler = handler.with_proposals(Arc::new(PgProposalStore::new(&db)));
+            }
+            None => warn!("No database configured; governance jobs will fail"),
+        }
+        let handler = Arc::new(handler);
         let metrics = WorkerMetrics::new();
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
 
@@ -47,6 +83,7 @@ impl WorkerPool {
             config,
             producer,
             consumer,
+            handler,
             metrics,
             shutdown_tx,
             shutdown_rx,
@@ -63,6 +100,7 @@ impl WorkerPool {
 
         // Start worker threads
         let worker_handles = self.consumer.start(
+            self.handler.clone(),
             self.config.clone(),
             self.metrics.clone(),
         ).await?;
@@ -101,19 +101,26 @@ async fn main() -> Result<()> {
     // Start metrics reporting
     let metrics_handle = tokio::spawn(async move {
         let mut interval = tokio::time::interval(Duration::from_secs(args.metrics_interval));
+        let mut previous = metrics.snapshot();
         loop {
             interval.tick().await;
             let snapshot = metrics.snapshot();
+            let delta = snapshot.diff(&previous);
             info!(
-                jobs_processed = snapshot.jobs_processed,
-                jobs_succeeded = snapshot.jobs_succeeded,
-                jobs_failed = snapshot.jobs_failed,
-                success_rate = format!("{:.2}%", snapshot.success_rate * 100.0),
+                jobs_processed = delta.jobs_processed,
+                jobs_succeeded = delta.jobs_succeeded,
+                jobs_failed = delta.jobs_failed,
+                jobs_retried = delta.jobs_retried,
+                throughput = format!("{:.2} jobs/sec", delta.throughput()),
+                success_rate = format!("{:.2}%", delta.success_rate() * 100.0),
+                interval_secs = delta.elapsed.as_secs(),
+                total_jobs_processed = snapshot.jobs_processed,
                 avg_duration_ms = snapshot.average_duration
                     .map(|d| d.as_millis())
                     .unwrap_or(0),
                 "Worker metrics"
             );
+            previous = snapshot;
         }
     });
 
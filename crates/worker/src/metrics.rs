@@ -3,7 +3,7 @@
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Worker metrics
 #[derive(Clone)]
@@ -220,6 +220,7 @@ impl WorkerMetrics {
             p99_duration: self.p99_duration(),
             total_queue_depth: self.total_queue_depth(),
             processing_rate: inner.processing_rate,
+            captured_at: Instant::now(),
         }
     }
 
@@ -265,9 +266,25 @@ pub struct MetricsSnapshot {
     pub p99_duration: Option<Duration>,
     pub total_queue_depth: usize,
     pub processing_rate: f64,
+    /// When the snapshot was taken
+    pub captured_at: Instant,
 }
 
 impl MetricsSnapshot {
+    /// Compute the change in counters since an earlier snapshot
+    ///
+    /// Counters that went backwards (e.g. after [`WorkerMetrics::reset`])
+    /// are reported as zero rather than underflowing.
+    pub fn diff(&self, earlier: &Self) -> MetricsDelta {
+        MetricsDelta {
+            jobs_processed: self.jobs_processed.saturating_sub(earlier.jobs_processed),
+            jobs_succeeded: self.jobs_succeeded.saturating_sub(earlier.jobs_succeeded),
+            jobs_failed: self.jobs_failed.saturating_sub(earlier.jobs_failed),
+            jobs_retried: self.jobs_retried.saturating_sub(earlier.jobs_retried),
+            elapsed: self.captured_at.saturating_duration_since(earlier.captured_at),
+        }
+    }
+
     /// Format metrics for display
     pub fn format(&self) -> String {
         format!(
@@ -300,6 +317,38 @@ impl MetricsSnapshot {
     }
 }
 
+/// Change in counters between two snapshots
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsDelta {
+    pub jobs_processed: u64,
+    pub jobs_succeeded: u64,
+    pub jobs_failed: u64,
+    pub jobs_retried: u64,
+    /// Time between the two snapshots
+    pub elapsed: Duration,
+}
+
+impl MetricsDelta {
+    /// Jobs processed per second over the window
+    pub fn throughput(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.jobs_processed as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// Success rate over the window (0.0 - 1.0)
+    pub fn success_rate(&self) -> f64 {
+        if self.jobs_processed == 0 {
+            0.0
+        } else {
+            self.jobs_succeeded as f64 / self.jobs_processed as f64
+        }
+    }
+}
+
 fn format_duration(duration: Option<Duration>) -> String {
     match duration {
         Some(d) => format!("{:.2}ms", d.as_secs_f64() * 1000.0),
@@ -360,4 +409,52 @@ mod tests {
         assert_eq!(metrics.queue_depth("high"), Some(10));
         assert_eq!(metrics.total_queue_depth(), 15);
     }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let metrics = WorkerMetrics::new();
+        metrics.increment_jobs_processed();
+        metrics.increment_jobs_succeeded();
+        metrics.increment_jobs_retried();
+        let earlier = metrics.snapshot();
+
+        for _ in 0..4 {
+            metrics.increment_jobs_processed();
+            metrics.increment_jobs_succeeded();
+        }
+        metrics.increment_jobs_processed();
+        metrics.increment_jobs_failed();
+        let mut later = metrics.snapshot();
+        later.captured_at = earlier.captured_at + Duration::from_secs(2);
+
+        let delta = later.diff(&earlier);
+        assert_eq!(delta.jobs_processed, 5);
+        assert_eq!(delta.jobs_succeeded, 4);
+        assert_eq!(delta.jobs_failed, 1);
+        // Retries didn't change during the window
+        assert_eq!(delta.jobs_retried, 0);
+        assert_eq!(delta.elapsed, Duration::from_secs(2));
+        assert_eq!(delta.throughput(), 2.5);
+        assert_eq!(delta.success_rate(), 0.8);
+    }
+
+    #[test]
+    fn test_snapshot_diff_edge_cases() {
+        let metrics = WorkerMetrics::new();
+        metrics.increment_jobs_processed();
+        let snapshot = metrics.snapshot();
+
+        // Same snapshot: nothing happened and no time elapsed
+        let delta = snapshot.diff(&snapshot);
+        assert_eq!(delta.jobs_processed, 0);
+        assert_eq!(delta.elapsed, Duration::ZERO);
+        assert_eq!(delta.throughput(), 0.0);
+        assert_eq!(delta.success_rate(), 0.0);
+
+        // Counters reset between snapshots
+        metrics.reset();
+        let after_reset = metrics.snapshot();
+        let delta = after_reset.diff(&snapshot);
+        assert_eq!(delta.jobs_processed, 0);
+    }
 }
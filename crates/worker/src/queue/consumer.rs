@@ -1,6 +1,7 @@
 //! Job consumer - fetch and process jobs from Redis
 
 use super::job::{Job, JobPriority, JobStatus};
+use super::status;
 use crate::config::WorkerConfig;
 use crate::metrics::WorkerMetrics;
 use crate::workers::JobHandler;
@@ -19,6 +20,7 @@ pub struct JobConsumer {
     redis: ConnectionManager,
     prefix: String,
     pool_size: usize,
+    status_ttl: u64,
 }
 
 impl JobConsumer {
@@ -34,12 +36,20 @@ impl JobConsumer {
             redis,
             prefix: "llm-benchmark".to_string(),
             pool_size,
+            status_ttl: status::DEFAULT_STATUS_TTL_SECS,
         })
     }
 
-    /// Start the consumer worker pool
+    /// Set how long job statuses are kept after their last transition
+    pub fn with_status_ttl(mut self, ttl_secs: u64) -> Self {
+        self.status_ttl = ttl_secs;
+        self
+    }
+
+    /// Start the consumer worker pool, routing jobs through `handler`
     pub async fn start(
         &self,
+        handler: Arc<JobHandler>,
         config: WorkerConfig,
         metrics: WorkerMetrics,
     ) -> Result<Vec<JoinHandle<()>>> {
@@ -50,13 +60,14 @@ impl JobConsumer {
 
         for worker_id in 0..self.pool_size {
             let consumer = self.clone();
+            let handler = handler.clone();
             let config = config.clone();
             let metrics = metrics.clone();
             let semaphore = semaphore.clone();
 
             let handle = tokio::spawn(async move {
                 if let Err(e) = consumer
-                    .worker_loop(worker_id, config, metrics, semaphore)
+                    .worker_loop(worker_id, handler, config, metrics, semaphore)
                     .await
                 {
                     error!(worker_id, error = %e, "Worker loop error");
@@ -83,6 +94,7 @@ impl JobConsumer {
     async fn worker_loop(
         &self,
         worker_id: usize,
+        handler: Arc<JobHandler>,
         config: WorkerConfig,
         metrics: WorkerMetrics,
         semaphore: Arc<Semaphore>,
@@ -107,9 +119,10 @@ impl JobConsumer {
                     let start = std::time::Instant::now();
 
                     job.mark_processing();
+                    self.record_status(&mut redis, &job).await;
 
                     // Process the job
-                    let result = self.process_job(&job, &config).await;
+                    let result = handler.handle(&job).await;
 
                     let duration = start.elapsed();
                     metrics.record_job_duration(duration);
@@ -117,6 +130,7 @@ impl JobConsumer {
                     match result {
                         Ok(_) => {
                             job.mark_completed();
+                            self.record_status(&mut redis, &job).await;
                             metrics.increment_jobs_succeeded();
                             info!(
                                 worker_id,
@@ -168,6 +182,16 @@ impl JobConsumer {
         }
     }
 
+    /// Record a job's status transition
+    ///
+    /// Status tracking is best-effort: a failed write is logged rather than
+    /// interrupting job processing.
+    async fn record_status(&self, redis: &mut ConnectionManager, job: &Job) {
+        if let Err(e) = status::record_status(redis, &self.prefix, job, self.status_ttl).await {
+            warn!(job_id = %job.id, error = %e, "Failed to record job status");
+        }
+    }
+
     /// Fetch a job from the queue with priority
     async fn fetch_job(
         &self,
@@ -197,13 +221,7 @@ impl JobConsumer {
         }
     }
 
-    /// Process a job
-    async fn process_job(&self, job: &Job, config: &WorkerConfig) -> Result<()> {
-        let handler = JobHandler::new(config.clone());
-        handler.handle(job).await
-    }
-
-    /// Re-enqueue a job with delay
+    /// Re-enqueue a job with delay, recording its status in the same transaction
     async fn requeue_job(
         &self,
         redis: &mut ConnectionManager,
@@ -215,22 +233,29 @@ impl JobConsumer {
         let job_json = serde_json::to_string(job)
             .context("Failed to serialize job")?;
 
-        redis
-            .zadd::<_, _, _, ()>(&delayed_key, &job_json, score)
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        status::queue_status(&mut pipe, &self.prefix, job, self.status_ttl);
+        pipe.zadd(&delayed_key, &job_json, score).ignore();
+        pipe.query_async::<_, ()>(redis)
             .await
             .context("Failed to requeue job")?;
 
         Ok(())
     }
 
-    /// Move a job to the dead letter queue
+    /// Move a job to the dead letter queue, recording its status in the same
+    /// transaction
     async fn move_to_dlq(&self, redis: &mut ConnectionManager, job: &Job) -> Result<()> {
         let dlq_key = format!("{}:{}", self.prefix, "jobs:dlq");
         let job_json = serde_json::to_string(job)
             .context("Failed to serialize job")?;
 
-        redis
-            .lpush::<_, _, ()>(&dlq_key, &job_json)
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        status::queue_status(&mut pipe, &self.prefix, job, self.status_ttl);
+        pipe.lpush(&dlq_key, &job_json).ignore();
+        pipe.query_async::<_, ()>(redis)
             .await
             .context("Failed to move job to DLQ")?;
 
@@ -328,19 +353,19 @@ impl JobConsumer {
                     job.retry_count = 0;
                     job.error = None;
 
-                    // Remove from DLQ
-                    redis
-                        .lrem::<_, _, ()>(&dlq_key, 1, job_json)
-                        .await
-                        .context("Failed to remove job from DLQ")?;
-
-                    // Re-enqueue
+                    // Move it from the DLQ back onto its queue, resetting
+                    // the status (and clearing the old error) in the same
+                    // transaction
                     let queue_name = job.priority.queue_name(&self.prefix);
                     let new_job_json = serde_json::to_string(&job)?;
-                    redis
-                        .lpush::<_, _, ()>(&queue_name, &new_job_json)
+                    let mut pipe = redis::pipe();
+                    pipe.atomic();
+                    pipe.lrem(&dlq_key, 1, job_json).ignore();
+                    status::queue_status(&mut pipe, &self.prefix, &job, self.status_ttl);
+                    pipe.lpush(&queue_name, &new_job_json).ignore();
+                    pipe.query_async::<_, ()>(&mut redis)
                         .await
-                        .context("Failed to re-enqueue job")?;
+                        .context("Failed to re-enqueue job from DLQ")?;
 
                     info!(job_id = %job_id, "Job retried from DLQ");
                     return Ok(());
@@ -3,6 +3,7 @@
 pub mod consumer;
 pub mod job;
 pub mod producer;
+pub mod status;
 
 pub use consumer::JobConsumer;
 pub use job::{
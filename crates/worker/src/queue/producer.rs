@@ -1,16 +1,23 @@
 //! Job producer - enqueue jobs to Redis
 
-use super::job::{Job, JobPriority, JobType};
+use super::job::{Job, JobPriority, JobStatus, JobType};
+use super::status::{self, DEFAULT_STATUS_TTL_SECS};
 use anyhow::{Context, Result};
+use llm_benchmark_application::services::{
+    EventPublisher, ServiceEvent, VerificationProgress, VerificationStage,
+};
 use redis::aio::ConnectionManager;
 use redis::AsyncCommands;
-use tracing::{debug, info};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
 
 /// Job producer for enqueueing jobs
 #[derive(Clone)]
 pub struct JobProducer {
     redis: ConnectionManager,
     prefix: String,
+    status_ttl: u64,
+    event_publisher: Option<Arc<dyn EventPublisher>>,
 }
 
 impl JobProducer {
@@ -25,6 +32,8 @@ impl JobProducer {
         Ok(Self {
             redis,
             prefix: "llm-benchmark".to_string(),
+            status_ttl: DEFAULT_STATUS_TTL_SECS,
+            event_publisher: None,
         })
     }
 
@@ -36,7 +45,32 @@ impl JobProducer {
             .await
             .context("Failed to connect to Redis")?;
 
-        Ok(Self { redis, prefix })
+        Ok(Self {
+            redis,
+            prefix,
+            status_ttl: DEFAULT_STATUS_TTL_SECS,
+            event_publisher: None,
+        })
+    }
+
+    /// Set how long job statuses are kept after their last transition
+    pub fn with_status_ttl(mut self, ttl_secs: u64) -> Self {
+        self.status_ttl = ttl_secs;
+        self
+    }
+
+    /// Announce queued verifications through `event_publisher`
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publisher = Some(event_publisher);
+        self
+    }
+
+    /// Get the current status of a job
+    ///
+    /// Returns `None` if the job is unknown or its status has expired.
+    pub async fn status(&self, job_id: &uuid::Uuid) -> Result<Option<JobStatus>> {
+        let mut redis = self.redis.clone();
+        status::fetch_status(&mut redis, &self.prefix, job_id).await
     }
 
     /// Enqueue a job with default priority (Normal)
@@ -53,6 +87,7 @@ impl JobProducer {
     ) -> Result<Job> {
         let job = Job::new(job_type, priority);
         self.push_job(&job).await?;
+        self.announce(&job).await;
 
         debug!(
             job_id = %job.id,
@@ -80,11 +115,18 @@ impl JobProducer {
         let delayed_key = format!("{}:jobs:delayed", self.prefix);
         let score = job.scheduled_at.timestamp();
 
-        self.redis
-            .zadd::<_, _, _, ()>(&delayed_key, &job_json, score)
+        // Record the status in the same transaction so a consumer can never
+        // see the job before its `Queued` status exists
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        status::queue_status(&mut pipe, &self.prefix, &job, self.status_ttl);
+        pipe.zadd(&delayed_key, &job_json, score).ignore();
+        pipe.query_async::<_, ()>(&mut self.redis)
             .await
             .context("Failed to add delayed job")?;
 
+        self.announce(&job).await;
+
         debug!(
             job_id = %job.id,
             priority = ?job.priority,
@@ -107,38 +149,70 @@ impl JobProducer {
             created_jobs.push(job);
         }
 
-        // Push all jobs in a pipeline for better performance
+        // Push all jobs and their statuses in one transaction
         let mut pipe = redis::pipe();
+        pipe.atomic();
         for job in &created_jobs {
             let queue_name = job.priority.queue_name(&self.prefix);
             let job_json = serde_json::to_string(job)
                 .context("Failed to serialize job")?;
-            pipe.lpush::<_, _>(&queue_name, &job_json);
+            status::queue_status(&mut pipe, &self.prefix, job, self.status_ttl);
+            pipe.lpush(&queue_name, &job_json).ignore();
         }
 
-        pipe.query_async(&mut self.redis)
+        pipe.query_async::<_, ()>(&mut self.redis)
             .await
             .context("Failed to enqueue batch jobs")?;
 
+        for job in &created_jobs {
+            self.announce(job).await;
+        }
+
         info!(count = created_jobs.len(), "Batch jobs enqueued");
 
         Ok(created_jobs)
     }
 
     /// Push a job to the appropriate queue
+    ///
+    /// The `Queued` status is written in the same transaction as the push,
+    /// so a consumer can never pop the job before its status exists.
     async fn push_job(&mut self, job: &Job) -> Result<()> {
         let queue_name = job.priority.queue_name(&self.prefix);
         let job_json = serde_json::to_string(job)
             .context("Failed to serialize job")?;
 
-        self.redis
-            .lpush::<_, _, ()>(&queue_name, &job_json)
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        status::queue_status(&mut pipe, &self.prefix, job, self.status_ttl);
+        pipe.lpush(&queue_name, &job_json).ignore();
+        pipe.query_async::<_, ()>(&mut self.redis)
             .await
             .context("Failed to push job to queue")?;
 
         Ok(())
     }
 
+    /// Publish the `queued` verification stage for a verification job
+    ///
+    /// The job is already enqueued, so a failed publish is only logged.
+    async fn announce(&self, job: &Job) {
+        let (Some(publisher), JobType::VerifySubmission(data)) =
+            (&self.event_publisher, &job.job_type)
+        else {
+            return;
+        };
+
+        let progress =
+            VerificationProgress::new(data.submission_id.to_string(), VerificationStage::Queued);
+        if let Err(e) = publisher
+            .publish(ServiceEvent::SubmissionVerificationProgress { progress })
+            .await
+        {
+            warn!(job_id = %job.id, error = %e, "Failed to announce queued verification");
+        }
+    }
+
     /// Get the number of jobs in a queue
     pub async fn queue_size(&mut self, priority: JobPriority) -> Result<usize> {
         let queue_name = priority.queue_name(&self.prefix);
@@ -255,4 +329,60 @@ mod tests {
         // Clean up
         producer.clear_queue(JobPriority::High).await.ok();
     }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_job_status_transitions() {
+        let mut producer = JobProducer::new("redis://localhost:6379")
+            .await
+            .expect("Failed to create producer")
+            .with_status_ttl(60);
+        producer.clear_queue(JobPriority::Low).await.ok();
+
+        assert_eq!(producer.status(&Uuid::new_v4()).await.unwrap(), None);
+
+        let mut job = producer
+            .enqueue_with_priority(
+                JobType::RecomputeLeaderboard(RecomputeLeaderboardJob {
+                    benchmark_id: Uuid::new_v4(),
+                    invalidate_cache: false,
+                }),
+                JobPriority::Low,
+            )
+            .await
+            .expect("Failed to enqueue job");
+        assert_eq!(
+            producer.status(&job.id).await.unwrap(),
+            Some(JobStatus::Queued)
+        );
+
+        // Drive the transitions the consumer makes while processing
+        let mut redis = producer.redis.clone();
+        job.mark_processing();
+        status::record_status(&mut redis, &producer.prefix, &job, 60)
+            .await
+            .unwrap();
+        assert_eq!(
+            producer.status(&job.id).await.unwrap(),
+            Some(JobStatus::Processing)
+        );
+
+        job.mark_completed();
+        status::record_status(&mut redis, &producer.prefix, &job, 60)
+            .await
+            .unwrap();
+        assert_eq!(
+            producer.status(&job.id).await.unwrap(),
+            Some(JobStatus::Completed)
+        );
+
+        let ttl: i64 = redis
+            .ttl(status::status_key(&producer.prefix, &job.id))
+            .await
+            .unwrap();
+        assert!(ttl > 0 && ttl <= 60);
+
+        // Clean up
+        producer.clear_queue(JobPriority::Low).await.ok();
+    }
 }
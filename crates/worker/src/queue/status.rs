@@ -0,0 +1,192 @@
+//! Job status tracking
+//!
+//! Each job's latest status is kept in a Redis hash at
+//! `{prefix}:jobs:status:{job_id}` so callers can poll a job after
+//! enqueueing it. The producer writes the initial `Queued` entry and the
+//! consumer updates it as the job moves through processing. Every write
+//! refreshes the key's TTL, so entries expire once a job goes quiet.
+
+use super::job::{Job, JobStatus};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Default time to keep a job's status after its last transition (24 hours)
+pub const DEFAULT_STATUS_TTL_SECS: u64 = 86_400;
+
+/// Redis key holding the status hash for a job
+pub fn status_key(prefix: &str, job_id: &Uuid) -> String {
+    format!("{}:jobs:status:{}", prefix, job_id)
+}
+
+/// Record the job's current status, refreshing the TTL
+pub(crate) async fn record_status(
+    redis: &mut ConnectionManager,
+    prefix: &str,
+    job: &Job,
+    ttl_secs: u64,
+) -> Result<()> {
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+    queue_status(&mut pipe, prefix, job, ttl_secs);
+
+    pipe.query_async::<_, ()>(redis)
+        .await
+        .context("Failed to record job status")?;
+
+    Ok(())
+}
+
+/// Add the commands recording the job's current status to `pipe`
+///
+/// Lets callers write the status in the same transaction as the queue
+/// operation it describes. An error left over from an earlier attempt is
+/// cleared when the job no longer carries one.
+pub(crate) fn queue_status(pipe: &mut redis::Pipeline, prefix: &str, job: &Job, ttl_secs: u64) {
+    let key = status_key(prefix, &job.id);
+
+    pipe.hset_multiple(&key, &status_fields(job)).ignore();
+    if job.error.is_none() {
+        pipe.hdel(&key, "error").ignore();
+    }
+    pipe.expire(&key, ttl_secs as i64).ignore();
+}
+
+/// Fetch the recorded status of a job, if it is still tracked
+pub(crate) async fn fetch_status(
+    redis: &mut ConnectionManager,
+    prefix: &str,
+    job_id: &Uuid,
+) -> Result<Option<JobStatus>> {
+    let fields: HashMap<String, String> = redis
+        .hgetall(status_key(prefix, job_id))
+        .await
+        .context("Failed to fetch job status")?;
+
+    parse_status(&fields)
+}
+
+/// Hash fields written for a job
+fn status_fields(job: &Job) -> Vec<(&'static str, String)> {
+    let mut fields = vec![
+        ("status", status_name(job.status).to_string()),
+        ("retry_count", job.retry_count.to_string()),
+        ("updated_at", Utc::now().to_rfc3339()),
+    ];
+    if let Some(error) = &job.error {
+        fields.push(("error", error.clone()));
+    }
+    fields
+}
+
+/// Parse the status from a job's hash fields
+fn parse_status(fields: &HashMap<String, String>) -> Result<Option<JobStatus>> {
+    let Some(status) = fields.get("status") else {
+        return Ok(None);
+    };
+
+    let status = match status.as_str() {
+        "queued" => JobStatus::Queued,
+        "processing" => JobStatus::Processing,
+        "completed" => JobStatus::Completed,
+        "failed" => JobStatus::Failed,
+        "retried" => JobStatus::Retried,
+        "dead" => JobStatus::Dead,
+        other => anyhow::bail!("Unknown job status: {}", other),
+    };
+
+    Ok(Some(status))
+}
+
+fn status_name(status: JobStatus) -> &'static str {
+    match status {
+        JobStatus::Queued => "queued",
+        JobStatus::Processing => "processing",
+        JobStatus::Completed => "completed",
+        JobStatus::Failed => "failed",
+        JobStatus::Retried => "retried",
+        JobStatus::Dead => "dead",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queue::job::{JobPriority, JobType, RecomputeLeaderboardJob};
+
+    fn fields_of(job: &Job) -> HashMap<String, String> {
+        status_fields(job)
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+    }
+
+    #[test]
+    fn test_status_fields_roundtrip() {
+        let mut job = Job::new(
+            JobType::RecomputeLeaderboard(RecomputeLeaderboardJob {
+                benchmark_id: Uuid::new_v4(),
+                invalidate_cache: true,
+            }),
+            JobPriority::Normal,
+        );
+
+        for status in [
+            JobStatus::Queued,
+            JobStatus::Processing,
+            JobStatus::Completed,
+            JobStatus::Failed,
+            JobStatus::Retried,
+            JobStatus::Dead,
+        ] {
+            job.status = status;
+            assert_eq!(parse_status(&fields_of(&job)).unwrap(), Some(status));
+        }
+
+        job.mark_failed("boom".to_string());
+        assert_eq!(fields_of(&job)["error"], "boom");
+    }
+
+    #[test]
+    fn test_queue_status_clears_stale_error() {
+        let mut job = Job::new(
+            JobType::RecomputeLeaderboard(RecomputeLeaderboardJob {
+                benchmark_id: Uuid::new_v4(),
+                invalidate_cache: true,
+            }),
+            JobPriority::Normal,
+        );
+        let packed = |job: &Job| {
+            let mut pipe = redis::pipe();
+            queue_status(&mut pipe, "test", job, 60);
+            String::from_utf8_lossy(&pipe.get_packed_pipeline()).into_owned()
+        };
+
+        job.mark_failed("boom".to_string());
+        assert!(!packed(&job).contains("HDEL"));
+
+        job.status = JobStatus::Queued;
+        job.error = None;
+        assert!(packed(&job).contains("HDEL"));
+    }
+
+    #[test]
+    fn test_parse_missing_or_unknown_status() {
+        assert_eq!(parse_status(&HashMap::new()).unwrap(), None);
+
+        let fields = HashMap::from([("status".to_string(), "exploded".to_string())]);
+        assert!(parse_status(&fields).is_err());
+    }
+
+    #[test]
+    fn test_status_key() {
+        let id = Uuid::nil();
+        assert_eq!(
+            status_key("llm-benchmark", &id),
+            "llm-benchmark:jobs:status:00000000-0000-0000-0000-000000000000"
+        );
+    }
+}
@@ -9,99 +9,158 @@ use std::time::Duration;
 use tokio::task::JoinHandle;
 use tracing::{error, info};
 
+/// Set of values matched by one field of a [`Schedule`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CronField {
+    /// Matches any value (`*`)
+    Any,
+    /// Matches only the listed values, sorted and deduplicated
+    Values(Vec<u32>),
+}
+
+impl CronField {
+    /// Field matching a single value
+    pub fn at(value: u32) -> Self {
+        Self::Values(vec![value])
+    }
+
+    /// Whether `value` is matched by this field
+    pub fn contains(&self, value: u32) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Values(values) => values.binary_search(&value).is_ok(),
+        }
+    }
+
+    /// Parse a field such as `*`, `5`, `1-5`, `*/15`, `10-40/10` or `0,30`
+    ///
+    /// `min` and `max` are the inclusive bounds for the field; `name` is
+    /// used in error messages.
+    fn parse(field: &str, name: &str, min: u32, max: u32) -> Result<Self> {
+        if field == "*" {
+            return Ok(Self::Any);
+        }
+
+        let parse_value = |s: &str| -> Result<u32> {
+            let value: u32 = s
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid {} value: {}", name, s))?;
+            if !(min..=max).contains(&value) {
+                return Err(anyhow::anyhow!(
+                    "{} value {} out of range {}-{}",
+                    name,
+                    value,
+                    min,
+                    max
+                ));
+            }
+            Ok(value)
+        };
+
+        let mut values = Vec::new();
+        for item in field.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 = step
+                        .parse()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| anyhow::anyhow!("Invalid {} step: {}", name, item))?;
+                    (range, Some(step))
+                }
+                None => (item, None),
+            };
+
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                let (start, end) = (parse_value(start)?, parse_value(end)?);
+                if start > end {
+                    return Err(anyhow::anyhow!("Invalid {} range: {}", name, range));
+                }
+                (start, end)
+            } else {
+                let value = parse_value(range)?;
+                // `5/15` runs from 5 to the end of the field, like cron
+                (value, if step.is_some() { max } else { value })
+            };
+
+            values.extend((start..=end).step_by(step.unwrap_or(1) as usize));
+        }
+
+        values.sort_unstable();
+        values.dedup();
+        Ok(Self::Values(values))
+    }
+}
+
 /// Cron-like schedule expression
 #[derive(Debug, Clone)]
 pub struct Schedule {
     /// Minute (0-59)
-    pub minute: Option<u32>,
+    pub minute: CronField,
     /// Hour (0-23)
-    pub hour: Option<u32>,
+    pub hour: CronField,
     /// Day of month (1-31)
-    pub day: Option<u32>,
+    pub day: CronField,
     /// Month (1-12)
-    pub month: Option<u32>,
+    pub month: CronField,
     /// Day of week (0-6, where 0 is Sunday)
-    pub day_of_week: Option<u32>,
+    pub day_of_week: CronField,
 }
 
 impl Schedule {
     /// Create a schedule that runs every minute
     pub fn every_minute() -> Self {
         Self {
-            minute: None,
-            hour: None,
-            day: None,
-            month: None,
-            day_of_week: None,
+            minute: CronField::Any,
+            hour: CronField::Any,
+            day: CronField::Any,
+            month: CronField::Any,
+            day_of_week: CronField::Any,
         }
     }
 
     /// Create a schedule that runs hourly at a specific minute
     pub fn hourly(minute: u32) -> Self {
         Self {
-            minute: Some(minute),
-            hour: None,
-            day: None,
-            month: None,
-            day_of_week: None,
+            minute: CronField::at(minute),
+            ..Self::every_minute()
         }
     }
 
     /// Create a schedule that runs daily at a specific time
     pub fn daily(hour: u32, minute: u32) -> Self {
         Self {
-            minute: Some(minute),
-            hour: Some(hour),
-            day: None,
-            month: None,
-            day_of_week: None,
+            hour: CronField::at(hour),
+            ..Self::hourly(minute)
         }
     }
 
     /// Create a schedule that runs weekly on a specific day and time
     pub fn weekly(day_of_week: u32, hour: u32, minute: u32) -> Self {
         Self {
-            minute: Some(minute),
-            hour: Some(hour),
-            day: None,
-            month: None,
-            day_of_week: Some(day_of_week),
+            day_of_week: CronField::at(day_of_week),
+            ..Self::daily(hour, minute)
         }
     }
 
     /// Check if the schedule matches the given time
     pub fn matches(&self, time: &DateTime<Utc>) -> bool {
-        if let Some(minute) = self.minute {
-            if time.minute() != minute {
-                return false;
-            }
-        }
-        if let Some(hour) = self.hour {
-            if time.hour() != hour {
-                return false;
-            }
-        }
-        if let Some(day) = self.day {
-            if time.day() != day {
-                return false;
-            }
-        }
-        if let Some(month) = self.month {
-            if time.month() != month {
-                return false;
-            }
-        }
-        if let Some(day_of_week) = self.day_of_week {
-            if time.weekday().num_days_from_sunday() != day_of_week {
-                return false;
-            }
-        }
-        true
+        self.minute.contains(time.minute())
+            && self.hour.contains(time.hour())
+            && self.day.contains(time.day())
+            && self.month.contains(time.month())
+            && self
+                .day_of_week
+                .contains(time.weekday().num_days_from_sunday())
     }
 
-    /// Parse a cron-like expression (simplified)
+    /// Parse a cron-like expression
     /// Format: "minute hour day month day_of_week"
-    /// Use * for any value
+    ///
+    /// Each field accepts `*`, a single value, a range (`1-5`), a step
+    /// (`*/15`, `0-30/10`) or a comma-separated list of these (`0,30`).
     pub fn parse(expr: &str) -> Result<Self> {
         let parts: Vec<&str> = expr.split_whitespace().collect();
         if parts.len() != 5 {
@@ -110,20 +169,12 @@ impl Schedule {
             ));
         }
 
-        let parse_field = |s: &str| -> Option<u32> {
-            if s == "*" {
-                None
-            } else {
-                s.parse().ok()
-            }
-        };
-
         Ok(Self {
-            minute: parse_field(parts[0]),
-            hour: parse_field(parts[1]),
-            day: parse_field(parts[2]),
-            month: parse_field(parts[3]),
-            day_of_week: parse_field(parts[4]),
+            minute: CronField::parse(parts[0], "minute", 0, 59)?,
+            hour: CronField::parse(parts[1], "hour", 0, 23)?,
+            day: CronField::parse(parts[2], "day", 1, 31)?,
+            month: CronField::parse(parts[3], "month", 1, 12)?,
+            day_of_week: CronField::parse(parts[4], "day of week", 0, 6)?,
         })
     }
 }
@@ -326,4 +377,72 @@ mod tests {
         let result = Schedule::parse("30 14");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_schedule_parse_step() {
+        let schedule = Schedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(schedule.minute, CronField::Values(vec![0, 15, 30, 45]));
+
+        let on = Utc.with_ymd_and_hms(2024, 1, 15, 10, 45, 0).unwrap();
+        let off = Utc.with_ymd_and_hms(2024, 1, 15, 10, 50, 0).unwrap();
+        assert!(schedule.matches(&on));
+        assert!(!schedule.matches(&off));
+
+        let schedule = Schedule::parse("5/20 * * * *").unwrap();
+        assert_eq!(schedule.minute, CronField::Values(vec![5, 25, 45]));
+    }
+
+    #[test]
+    fn test_schedule_parse_ranges() {
+        let schedule = Schedule::parse("0 9-17 * * 1-5").unwrap();
+
+        // Monday 2024-01-15
+        let weekday_morning = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+        let weekday_evening = Utc.with_ymd_and_hms(2024, 1, 15, 17, 0, 0).unwrap();
+        let weekday_night = Utc.with_ymd_and_hms(2024, 1, 15, 18, 0, 0).unwrap();
+        let saturday = Utc.with_ymd_and_hms(2024, 1, 20, 9, 0, 0).unwrap();
+
+        assert!(schedule.matches(&weekday_morning));
+        assert!(schedule.matches(&weekday_evening));
+        assert!(!schedule.matches(&weekday_night));
+        assert!(!schedule.matches(&saturday));
+    }
+
+    #[test]
+    fn test_schedule_parse_lists() {
+        let schedule = Schedule::parse("0,30 0-6/3,12 * * *").unwrap();
+        assert_eq!(schedule.minute, CronField::Values(vec![0, 30]));
+        assert_eq!(schedule.hour, CronField::Values(vec![0, 3, 6, 12]));
+    }
+
+    #[test]
+    fn test_schedule_parse_rejects_bad_fields() {
+        for expr in [
+            "5-2 * * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 7",
+            "*/0 * * * *",
+            "a * * * *",
+            "1, * * * *",
+        ] {
+            assert!(Schedule::parse(expr).is_err(), "{} should be rejected", expr);
+        }
+
+        let err = Schedule::parse("5-2 * * * *").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid minute range: 5-2");
+    }
+
+    #[test]
+    fn test_schedule_weekly() {
+        let schedule = Schedule::weekly(0, 4, 0);
+        // Sunday 2024-01-14
+        let sunday = Utc.with_ymd_and_hms(2024, 1, 14, 4, 0, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2024, 1, 15, 4, 0, 0).unwrap();
+
+        assert!(schedule.matches(&sunday));
+        assert!(!schedule.matches(&monday));
+    }
 }
@@ -3,19 +3,44 @@
 use super::Worker;
 use crate::config::WorkerConfig;
 use crate::queue::job::{FinalizeProposalJob, Job, JobType};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
+use chrono::Utc;
+use llm_benchmark_application::services::{
+    EventPublisher, NoOpEventPublisher, ProposalRepositoryPort, ServiceEvent,
+};
+use llm_benchmark_domain::governance::{ProposalOutcome, ProposalStatus};
+use llm_benchmark_domain::identifiers::ProposalId;
+use std::sync::Arc;
 use tracing::{info, warn};
 
 /// Worker for processing governance jobs
 pub struct GovernanceWorker {
     config: WorkerConfig,
+    proposals: Option<Arc<dyn ProposalRepositoryPort>>,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl GovernanceWorker {
     /// Create a new governance worker
     pub fn new(config: WorkerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            proposals: None,
+            event_publisher: Arc::new(NoOpEventPublisher),
+        }
+    }
+
+    /// Use a proposal repository for loading and persisting proposals
+    pub fn with_repository(mut self, proposals: Arc<dyn ProposalRepositoryPort>) -> Self {
+        self.proposals = Some(proposals);
+        self
+    }
+
+    /// Use an event publisher for finalization events
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publisher = event_publisher;
+        self
     }
 
     /// Finalize a governance proposal
@@ -25,30 +50,64 @@ impl GovernanceWorker {
             "Starting proposal finalization"
         );
 
-        // TODO: Implement actual proposal finalization logic
-        // This would typically:
-        // 1. Fetch proposal details from database
-        // 2. Verify voting period has ended
-        // 3. Tally votes
-        // 4. Determine outcome (approved/rejected)
-        // 5. Execute proposal actions if approved
-        // 6. Update proposal status
-        // 7. Send notifications to stakeholders
-        // 8. Record governance event
+        // Without a repository the job can't be done; fail it so it is
+        // retried rather than acknowledged
+        let Some(proposals) = &self.proposals else {
+            anyhow::bail!(
+                "No proposal repository configured, cannot finalize proposal {}",
+                job_data.proposal_id
+            );
+        };
+
+        let proposal_id = ProposalId::from_uuid(job_data.proposal_id);
+        let mut proposal = proposals
+            .get_by_id(&proposal_id)
+            .await?
+            .with_context(|| format!("Proposal not found: {}", proposal_id))?;
 
-        // Simulate voting tallying
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        if proposal.status != ProposalStatus::Voting {
+            info!(
+                proposal_id = %proposal_id,
+                status = ?proposal.status,
+                "Proposal is not in voting, nothing to finalize"
+            );
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        if !proposal.voting.is_closed(now) {
+            anyhow::bail!("Voting period for proposal {} has not closed", proposal_id);
+        }
 
         info!(
-            proposal_id = %job_data.proposal_id,
+            proposal_id = %proposal_id,
             "Tallying votes"
         );
 
-        // Simulate proposal execution
-        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        let policy = self.config.governance.policy_for(proposal.proposal_type);
+        // Keep the policy that decided the proposal so the decision can be audited
+        proposal.voting.quorum_required = policy.quorum;
+        proposal.voting.approval_threshold = policy.approval_threshold;
+        proposal.voting.role_weights = Some(self.config.governance.role_weights.clone());
+        let outcome = ProposalOutcome::from_weighted_votes(
+            &proposal.voting,
+            &policy,
+            &self.config.governance.role_weights,
+        );
+        proposal.finalize(outcome, now)?;
+        // Fails if a vote landed after the tally or another run finalized it
+        proposals.update(&proposal, ProposalStatus::Voting).await?;
+
+        self.event_publisher
+            .publish(ServiceEvent::ProposalFinalized {
+                proposal_id: proposal_id.to_string(),
+                outcome,
+            })
+            .await?;
 
         info!(
-            proposal_id = %job_data.proposal_id,
+            proposal_id = %proposal_id,
+            outcome = ?outcome,
             "Proposal finalization completed"
         );
 
@@ -83,10 +142,93 @@ impl Worker for GovernanceWorker {
 mod tests {
     use super::*;
     use crate::queue::job::JobPriority;
+    use crate::workers::test_support::RecordingPublisher;
+    use llm_benchmark_application::ApplicationError;
+    use llm_benchmark_domain::governance::{
+        Ballot, Comment, Proposal, ProposalType, Vote, VotingState,
+    };
+    use llm_benchmark_domain::identifiers::{CommentId, UserId};
+    use llm_benchmark_domain::user::UserRole;
+    use parking_lot::Mutex;
+    use std::collections::{HashMap, HashSet};
     use uuid::Uuid;
 
+    #[derive(Default)]
+    struct InMemoryProposals {
+        proposals: Mutex<HashMap<ProposalId, Proposal>>,
+    }
+
+    #[async_trait]
+    impl ProposalRepositoryPort for InMemoryProposals {
+        async fn get_by_id(&self, id: &ProposalId) -> Result<Option<Proposal>, ApplicationError> {
+            Ok(self.proposals.lock().get(id).cloned())
+        }
+
+        async fn update(
+            &self,
+            proposal: &Proposal,
+            _expected_status: ProposalStatus,
+        ) -> Result<(), ApplicationError> {
+            self.proposals.lock().insert(proposal.id, proposal.clone());
+            Ok(())
+        }
+
+        async fn add_comment(&self, _comment: &Comment) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+
+        async fn get_comment(&self, _id: &CommentId) -> Result<Option<Comment>, ApplicationError> {
+            Ok(None)
+        }
+
+        async fn list_comments(
+            &self,
+            _proposal_id: &ProposalId,
+        ) -> Result<Vec<Comment>, ApplicationError> {
+            Ok(vec![])
+        }
+    }
+
+    fn closed_proposal(votes_for: u32, votes_against: u32, votes_abstain: u32) -> Proposal {
+        let now = Utc::now();
+        Proposal {
+            id: ProposalId::new(),
+            proposal_type: ProposalType::UpdateBenchmark,
+            title: "Update benchmark".to_string(),
+            description: "Test proposal".to_string(),
+            created_by: UserId::new(),
+            status: ProposalStatus::Voting,
+            benchmark_id: None,
+            rationale: "Test rationale".to_string(),
+            voting: VotingState {
+                voting_starts: Some(now - chrono::Duration::days(7)),
+                voting_ends: Some(now - chrono::Duration::minutes(1)),
+                votes_for,
+                votes_against,
+                votes_abstain,
+                voters: HashSet::new(),
+                quorum_required: 0,
+                approval_threshold: 0.0,
+                ballots: Vec::new(),
+                role_weights: None,
+            },
+            reviews: vec![],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn finalize_job(proposal: &Proposal) -> Job {
+        Job::new(
+            JobType::FinalizeProposal(FinalizeProposalJob {
+                proposal_id: proposal.id.into_uuid(),
+            }),
+            JobPriority::High,
+        )
+    }
+
     #[tokio::test]
-    async fn test_governance_worker() {
+    async fn test_governance_worker_without_repository_fails() {
         let config = WorkerConfig::default();
         let worker = GovernanceWorker::new(config);
 
@@ -97,7 +239,100 @@ mod tests {
             JobPriority::High,
         );
 
-        let result = worker.process(&job).await;
-        assert!(result.is_ok());
+        // Acknowledging the job would drop the finalization
+        assert!(worker.process(&job).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_finalize_proposal_reaching_quorum_passes() {
+        let repo = Arc::new(InMemoryProposals::default());
+        let publisher = Arc::new(RecordingPublisher::default());
+        // UpdateBenchmark defaults to quorum 5 and a simple majority
+        let proposal = closed_proposal(4, 1, 1);
+        repo.update(&proposal, proposal.status).await.unwrap();
+
+        let worker = GovernanceWorker::new(WorkerConfig::default())
+            .with_repository(repo.clone())
+            .with_event_publisher(publisher.clone());
+
+        worker.process(&finalize_job(&proposal)).await.unwrap();
+
+        let stored = repo.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, ProposalStatus::Approved);
+        // The applied policy is kept for auditing
+        assert_eq!(stored.voting.quorum_required, 5);
+
+        let events = publisher.events.lock();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            ServiceEvent::ProposalFinalized { outcome: ProposalOutcome::Approved, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_proposal_without_quorum_is_rejected() {
+        let repo = Arc::new(InMemoryProposals::default());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let proposal = closed_proposal(3, 0, 0);
+        repo.update(&proposal, proposal.status).await.unwrap();
+
+        let worker = GovernanceWorker::new(WorkerConfig::default())
+            .with_repository(repo.clone())
+            .with_event_publisher(publisher.clone());
+
+        worker.process(&finalize_job(&proposal)).await.unwrap();
+
+        let stored = repo.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, ProposalStatus::Rejected);
+        assert!(matches!(
+            &publisher.events.lock()[0],
+            ServiceEvent::ProposalFinalized { outcome: ProposalOutcome::QuorumNotMet, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_proposal_weighs_votes_by_role() {
+        let repo = Arc::new(InMemoryProposals::default());
+        let mut proposal = closed_proposal(2, 1, 0);
+        // Two reviewers (weight 2 each) and one contributor make quorum 5
+        for (role, vote) in [
+            (UserRole::Reviewer, Vote::Approve),
+            (UserRole::Reviewer, Vote::Approve),
+            (UserRole::Contributor, Vote::Reject),
+        ] {
+            proposal.voting.ballots.push(Ballot {
+                voter: UserId::new(),
+                role,
+                vote,
+                cast_at: Utc::now(),
+            });
+        }
+        repo.update(&proposal, proposal.status).await.unwrap();
+
+        let worker = GovernanceWorker::new(WorkerConfig::default()).with_repository(repo.clone());
+        worker.process(&finalize_job(&proposal)).await.unwrap();
+
+        let stored = repo.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, ProposalStatus::Approved);
+        // The weights are kept with the decision, like the policy
+        assert_eq!(
+            stored.voting.role_weights,
+            Some(WorkerConfig::default().governance.role_weights)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_finalize_proposal_while_voting_open_fails() {
+        let repo = Arc::new(InMemoryProposals::default());
+        let mut proposal = closed_proposal(10, 0, 0);
+        proposal.voting.voting_ends = Some(Utc::now() + chrono::Duration::days(1));
+        repo.update(&proposal, proposal.status).await.unwrap();
+
+        let worker = GovernanceWorker::new(WorkerConfig::default()).with_repository(repo.clone());
+
+        assert!(worker.process(&finalize_job(&proposal)).await.is_err());
+        let stored = repo.get_by_id(&proposal.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, ProposalStatus::Voting);
     }
 }
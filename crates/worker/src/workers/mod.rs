@@ -5,12 +5,18 @@ pub mod governance;
 pub mod leaderboard;
 pub mod notification;
 pub mod sync;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod verification;
 
 use crate::config::WorkerConfig;
 use crate::queue::job::{Job, JobType};
 use anyhow::Result;
 use async_trait::async_trait;
+use llm_benchmark_application::services::{
+    EventPublisher, NoOpEventPublisher, ProposalRepositoryPort,
+};
+use std::sync::Arc;
 
 /// Worker trait for processing jobs
 #[async_trait]
@@ -25,19 +31,38 @@ pub trait Worker: Send + Sync {
 /// Job handler that routes jobs to appropriate workers
 pub struct JobHandler {
     config: WorkerConfig,
+    event_publisher: Arc<dyn EventPublisher>,
+    proposals: Option<Arc<dyn ProposalRepositoryPort>>,
 }
 
 impl JobHandler {
     /// Create a new job handler
     pub fn new(config: WorkerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            event_publisher: Arc::new(NoOpEventPublisher),
+            proposals: None,
+        }
+    }
+
+    /// Use a proposal repository for governance jobs
+    pub fn with_proposals(mut self, proposals: Arc<dyn ProposalRepositoryPort>) -> Self {
+        self.proposals = Some(proposals);
+        self
+    }
+
+    /// Use an event publisher for the events workers publish
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publisher = event_publisher;
+        self
     }
 
     /// Handle a job by routing to the appropriate worker
     pub async fn handle(&self, job: &Job) -> Result<()> {
         match &job.job_type {
             JobType::VerifySubmission(_) => {
-                let worker = verification::VerificationWorker::new(self.config.clone());
+                let worker = verification::VerificationWorker::new(self.config.clone())
+                    .with_event_publisher(self.event_publisher.clone());
                 worker.process(job).await
             }
             JobType::RecomputeLeaderboard(_) => {
@@ -49,7 +74,11 @@ impl JobHandler {
                 worker.process(job).await
             }
             JobType::FinalizeProposal(_) => {
-                let worker = governance::GovernanceWorker::new(self.config.clone());
+                let mut worker = governance::GovernanceWorker::new(self.config.clone())
+                    .with_event_publisher(self.event_publisher.clone());
+                if let Some(proposals) = &self.proposals {
+                    worker = worker.with_repository(proposals.clone());
+                }
                 worker.process(job).await
             }
             JobType::SendNotification(_) => {
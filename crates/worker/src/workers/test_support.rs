@@ -0,0 +1,20 @@
+//! Test doubles shared by the worker tests
+
+use async_trait::async_trait;
+use llm_benchmark_application::services::{EventPublisher, ServiceEvent};
+use llm_benchmark_application::ApplicationError;
+use parking_lot::Mutex;
+
+/// Keeps every published event, in order
+#[derive(Default)]
+pub(crate) struct RecordingPublisher {
+    pub(crate) events: Mutex<Vec<ServiceEvent>>,
+}
+
+#[async_trait]
+impl EventPublisher for RecordingPublisher {
+    async fn publish(&self, event: ServiceEvent) -> Result<(), ApplicationError> {
+        self.events.lock().push(event);
+        Ok(())
+    }
+}
@@ -5,21 +5,85 @@ use crate::config::WorkerConfig;
 use crate::queue::job::{Job, JobType, VerifySubmissionJob};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use llm_benchmark_application::services::{
+    EventPublisher, NoOpEventPublisher, ServiceEvent, VerificationProgress, VerificationStage,
+};
+use std::sync::Arc;
 use tracing::{info, warn};
 
 /// Worker for processing verification jobs
 pub struct VerificationWorker {
     config: WorkerConfig,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 impl VerificationWorker {
     /// Create a new verification worker
     pub fn new(config: WorkerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            event_publisher: Arc::new(NoOpEventPublisher),
+        }
+    }
+
+    /// Use an event publisher for verification progress
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publisher = event_publisher;
+        self
     }
 
-    /// Process a verification job
+    /// Process a verification job, reporting progress as it goes
     async fn verify_submission(&self, job_data: &VerifySubmissionJob) -> Result<()> {
+        let submission_id = job_data.submission_id.to_string();
+        self.report(VerificationProgress::new(&submission_id, VerificationStage::Running))
+            .await;
+
+        let result = self.run_verification(job_data).await;
+        self.report_outcome(&submission_id, &result).await;
+
+        result
+    }
+
+    /// Report how a verification ended, announcing failures with their reason
+    async fn report_outcome(&self, submission_id: &str, result: &Result<()>) {
+        let Err(e) = result else {
+            self.report(VerificationProgress::new(submission_id, VerificationStage::Verified))
+                .await;
+            return;
+        };
+
+        let reason = e.to_string();
+        self.report(
+            VerificationProgress::new(submission_id, VerificationStage::Failed)
+                .with_message(reason.clone()),
+        )
+        .await;
+        let event = ServiceEvent::SubmissionVerificationFailed {
+            submission_id: submission_id.to_string(),
+            reason,
+        };
+        self.publish(submission_id, event).await;
+    }
+
+    /// Publish a progress update
+    async fn report(&self, progress: VerificationProgress) {
+        let submission_id = progress.submission_id.clone();
+        self.publish(&submission_id, ServiceEvent::SubmissionVerificationProgress { progress })
+            .await;
+    }
+
+    /// Publish an event; failing to do so doesn't fail the job
+    async fn publish(&self, submission_id: &str, event: ServiceEvent) {
+        if let Err(e) = self.event_publisher.publish(event).await {
+            warn!(
+                submission_id = %submission_id,
+                error = %e,
+                "Failed to publish verification event"
+            );
+        }
+    }
+
+    async fn run_verification(&self, job_data: &VerifySubmissionJob) -> Result<()> {
         info!(
             submission_id = %job_data.submission_id,
             benchmark_id = %job_data.benchmark_id,
@@ -85,6 +149,7 @@ impl Worker for VerificationWorker {
 mod tests {
     use super::*;
     use crate::queue::job::JobPriority;
+    use crate::workers::test_support::RecordingPublisher;
     use uuid::Uuid;
 
     #[tokio::test]
@@ -103,4 +168,60 @@ mod tests {
         let result = worker.process(&job).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_verification_worker_reports_progress() {
+        let publisher = Arc::new(RecordingPublisher::default());
+        let worker =
+            VerificationWorker::new(WorkerConfig::default()).with_event_publisher(publisher.clone());
+        let submission_id = Uuid::new_v4();
+
+        let job = Job::new(
+            JobType::VerifySubmission(VerifySubmissionJob {
+                submission_id,
+                benchmark_id: Uuid::new_v4(),
+            }),
+            JobPriority::Normal,
+        );
+        worker.process(&job).await.unwrap();
+
+        let stages: Vec<VerificationStage> = publisher
+            .events
+            .lock()
+            .iter()
+            .map(|event| match event {
+                ServiceEvent::SubmissionVerificationProgress { progress } => {
+                    assert_eq!(progress.submission_id, submission_id.to_string());
+                    progress.stage
+                }
+                other => panic!("unexpected event {:?}", other),
+            })
+            .collect();
+        assert_eq!(stages, [VerificationStage::Running, VerificationStage::Verified]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_verification_publishes_reason() {
+        let publisher = Arc::new(RecordingPublisher::default());
+        let worker = VerificationWorker::new(WorkerConfig::default())
+            .with_event_publisher(publisher.clone());
+
+        let result = Err(anyhow::anyhow!("Reproduced score differs by 0.2"));
+        worker.report_outcome("sub-1", &result).await;
+
+        let events = publisher.events.lock();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0],
+            ServiceEvent::SubmissionVerificationProgress { progress }
+                if progress.stage == VerificationStage::Failed
+        ));
+        match &events[1] {
+            ServiceEvent::SubmissionVerificationFailed { submission_id, reason } => {
+                assert_eq!(submission_id, "sub-1");
+                assert_eq!(reason, "Reproduced score differs by 0.2");
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,41 @@
+-- ============================================================================
+-- Migration: 00011_verification_events.sql
+-- Description: Verification level history for submissions
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- ============================================================================
+-- SUBMISSION VERIFICATION EVENTS TABLE
+-- ============================================================================
+
+CREATE TABLE submission_verification_events (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+
+    -- Submission whose verification changed
+    submission_id UUID NOT NULL REFERENCES submissions(id) ON DELETE CASCADE,
+
+    -- Level transition
+    from_level VARCHAR(30) NOT NULL,
+    to_level VARCHAR(30) NOT NULL,
+
+    -- Who verified and why
+    verifier JSONB,  -- Serialized VerifiedBy, NULL when unverified
+    reason TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT verification_events_valid_from_level CHECK (
+        from_level IN ('unverified', 'community_verified', 'platform_verified', 'audited')
+    ),
+    CONSTRAINT verification_events_valid_to_level CHECK (
+        to_level IN ('unverified', 'community_verified', 'platform_verified', 'audited')
+    )
+);
+
+CREATE INDEX idx_verification_events_submission
+    ON submission_verification_events(submission_id, created_at);
+
+COMMENT ON TABLE submission_verification_events IS 'Append-only history of submission verification level changes';
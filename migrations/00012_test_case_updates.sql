@@ -0,0 +1,16 @@
+-- ============================================================================
+-- Migration: 00012_test_case_updates.sql
+-- Description: Track in-place updates to test cases
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- Test cases are upserted by (benchmark_version_id, test_case_id), so
+-- editing one case no longer rewrites the whole set. updated_at records when
+-- a case's content last changed; unchanged cases keep their timestamp.
+ALTER TABLE test_cases
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+UPDATE test_cases SET updated_at = created_at;
+
+COMMENT ON COLUMN test_cases.updated_at IS 'Last time the case content changed';
@@ -0,0 +1,19 @@
+-- ============================================================================
+-- Migration: 00013_submission_content_hash.sql
+-- Description: Content hash of submission results for duplicate detection
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- SHA-256 of the results' canonical JSON (sorted keys, no whitespace). Two
+-- submissions with identical results share a hash, which lets a resubmission
+-- of the same results for the same model be detected without comparing the
+-- full results documents. Existing rows are left NULL and never match.
+ALTER TABLE submissions
+    ADD COLUMN content_hash TEXT;
+
+CREATE INDEX idx_submissions_content_hash
+    ON submissions (benchmark_id, content_hash)
+    WHERE content_hash IS NOT NULL;
+
+COMMENT ON COLUMN submissions.content_hash IS 'SHA-256 of the canonical JSON of results';
@@ -0,0 +1,49 @@
+-- ============================================================================
+-- Migration: 00014_benchmark_version_uniqueness.sql
+-- Description: Enforce one row per semantic version of a benchmark
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- benchmark_versions_semver_unique includes version_prerelease, which is NULL
+-- for stable releases. NULLs never compare equal in a UNIQUE constraint, so
+-- two rows for the same stable version were accepted. This index treats a
+-- missing prerelease as the empty string so stable versions are unique too.
+--
+-- Duplicate rows may already exist and can be referenced by test cases and
+-- submissions, so they are not merged automatically. Fail with the offending
+-- versions listed instead of a bare unique violation, so they can be resolved
+-- by hand before re-running this migration.
+DO $$
+DECLARE
+    v_duplicates TEXT;
+BEGIN
+    SELECT string_agg(
+               format('%s@%s.%s.%s%s (%s rows)',
+                      benchmark_id, version_major, version_minor, version_patch,
+                      NULLIF('-' || prerelease, '-'), row_count),
+               ', ')
+      INTO v_duplicates
+      FROM (
+          SELECT benchmark_id, version_major, version_minor, version_patch,
+                 COALESCE(version_prerelease, '') AS prerelease, COUNT(*) AS row_count
+            FROM benchmark_versions
+           GROUP BY benchmark_id, version_major, version_minor, version_patch,
+                    COALESCE(version_prerelease, '')
+          HAVING COUNT(*) > 1
+      ) duplicates;
+
+    IF v_duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'Duplicate benchmark versions must be resolved first: %', v_duplicates;
+    END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX idx_benchmark_versions_semver_unique
+    ON benchmark_versions (
+        benchmark_id,
+        version_major,
+        version_minor,
+        version_patch,
+        COALESCE(version_prerelease, '')
+    );
@@ -0,0 +1,34 @@
+-- ============================================================================
+-- Migration: 00015_benchmark_submission_count.sql
+-- Description: Stored per-benchmark submission counter
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- Maintained by the submission repository in the same transaction as the
+-- insert or delete it accounts for, using a single relative UPDATE so
+-- concurrent submissions cannot overwrite each other's increments.
+ALTER TABLE benchmarks
+    ADD COLUMN submission_count BIGINT NOT NULL DEFAULT 0
+    CHECK (submission_count >= 0);
+
+UPDATE benchmarks b
+SET submission_count = counts.total
+FROM (
+    SELECT benchmark_id, COUNT(*) AS total
+    FROM submissions
+    GROUP BY benchmark_id
+) counts
+WHERE counts.benchmark_id = b.id;
+
+-- A new submission is not a change to the benchmark itself, so counter
+-- updates leave updated_at alone.
+DROP TRIGGER update_benchmarks_updated_at ON benchmarks;
+
+CREATE TRIGGER update_benchmarks_updated_at
+    BEFORE UPDATE ON benchmarks
+    FOR EACH ROW
+    WHEN (OLD.submission_count IS NOT DISTINCT FROM NEW.submission_count)
+    EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON COLUMN benchmarks.submission_count IS 'Number of submissions to any version of the benchmark';
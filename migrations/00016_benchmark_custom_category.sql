@@ -0,0 +1,29 @@
+-- ============================================================================
+-- Migration: 00016_benchmark_custom_category.sql
+-- Description: Community-defined benchmark categories
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- Custom categories are stored as category = 'custom' with the name in
+-- custom_category. The enum value cannot be referenced in the transaction
+-- that adds it, so the constraint below compares category as text.
+ALTER TYPE benchmark_category ADD VALUE IF NOT EXISTS 'custom';
+
+ALTER TABLE benchmarks
+    ADD COLUMN custom_category VARCHAR(64);
+
+-- Same rules as BenchmarkCategory::validate: lowercase letters, digits,
+-- '_' and '-', starting with a letter. The name is present exactly when
+-- the category is 'custom'.
+ALTER TABLE benchmarks
+    ADD CONSTRAINT benchmarks_custom_category_check CHECK (
+        (category::text = 'custom') = (custom_category IS NOT NULL)
+        AND (custom_category IS NULL OR custom_category ~ '^[a-z][a-z0-9_-]*$')
+    );
+
+CREATE INDEX idx_benchmarks_custom_category
+    ON benchmarks(custom_category)
+    WHERE custom_category IS NOT NULL;
+
+COMMENT ON COLUMN benchmarks.custom_category IS 'Name of a community-defined category; set only when category is custom';
@@ -0,0 +1,47 @@
+-- ============================================================================
+-- Migration: 00017_proposal_persistence.sql
+-- Description: Voter roles and discussion comments for governance proposals
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- Role the voter held when voting. Finalization weighs each vote by this
+-- role, so it is recorded with the vote rather than read from the user's
+-- current role. Votes cast before this migration are left NULL and are
+-- read as the lowest role allowed to vote.
+ALTER TABLE votes
+    ADD COLUMN voter_role user_role;
+
+COMMENT ON COLUMN votes.voter_role IS 'Role of the voter when the vote was cast';
+
+-- ============================================================================
+-- PROPOSAL COMMENTS TABLE
+-- ============================================================================
+
+CREATE TABLE proposal_comments (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+
+    -- Proposal being discussed
+    proposal_id UUID NOT NULL REFERENCES proposals(id) ON DELETE CASCADE,
+
+    -- Comment content
+    author_id UUID NOT NULL REFERENCES users(id),
+    body TEXT NOT NULL,
+
+    -- Threading
+    parent_id UUID REFERENCES proposal_comments(id) ON DELETE CASCADE,
+
+    -- Timestamp
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT proposal_comments_body_not_empty CHECK (length(trim(body)) > 0)
+);
+
+CREATE INDEX idx_proposal_comments_proposal ON proposal_comments(proposal_id, created_at);
+CREATE INDEX idx_proposal_comments_author ON proposal_comments(author_id);
+CREATE INDEX idx_proposal_comments_parent ON proposal_comments(parent_id)
+    WHERE parent_id IS NOT NULL;
+
+COMMENT ON TABLE proposal_comments IS 'Threaded discussion comments on governance proposals';
+COMMENT ON COLUMN proposal_comments.parent_id IS 'Comment this one replies to, if any';
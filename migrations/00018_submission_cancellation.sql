@@ -0,0 +1,24 @@
+-- ============================================================================
+-- Migration: 00018_submission_cancellation.sql
+-- Description: Let submitters withdraw unverified submissions
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- A cancelled submission is kept for its history but no longer ranks on
+-- leaderboards and can no longer be verified.
+ALTER TABLE submissions
+    ADD COLUMN cancelled_at TIMESTAMPTZ;
+
+-- Only unverified submissions can be cancelled, and a cancelled submission
+-- stays unverified.
+ALTER TABLE submissions
+    ADD CONSTRAINT submissions_cancelled_unverified_check CHECK (
+        cancelled_at IS NULL OR verification_level = 'unverified'
+    );
+
+CREATE INDEX idx_submissions_active_leaderboard
+    ON submissions(benchmark_id, aggregate_score DESC)
+    WHERE deleted_at IS NULL AND cancelled_at IS NULL;
+
+COMMENT ON COLUMN submissions.cancelled_at IS 'When the submitter withdrew the submission; NULL while active';
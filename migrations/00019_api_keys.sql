@@ -0,0 +1,45 @@
+-- ============================================================================
+-- Migration: 00019_api_keys.sql
+-- Description: Personal and organization API keys
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- A key belongs either to a user, acting as that user, or to an organization,
+-- acting on behalf of the organization. Only a SHA-256 hash of the secret is
+-- stored; the secret itself is shown once when the key is created.
+CREATE TABLE api_keys (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+
+    -- Owner
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    organization_id UUID REFERENCES organizations(id) ON DELETE CASCADE,
+
+    -- Key details
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    scopes TEXT[] NOT NULL DEFAULT '{}',
+    key_hash CHAR(64) NOT NULL UNIQUE,
+
+    -- Lifecycle
+    last_used_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT api_keys_single_owner CHECK (
+        (user_id IS NULL) <> (organization_id IS NULL)
+    )
+);
+
+-- Indexes
+CREATE INDEX idx_api_keys_user ON api_keys(user_id, created_at DESC) WHERE user_id IS NOT NULL;
+CREATE INDEX idx_api_keys_organization ON api_keys(organization_id, created_at DESC)
+    WHERE organization_id IS NOT NULL;
+
+-- Comments
+COMMENT ON TABLE api_keys IS 'API keys acting as a user or on behalf of an organization';
+COMMENT ON COLUMN api_keys.key_hash IS 'Hex SHA-256 of the key secret';
+COMMENT ON COLUMN api_keys.revoked_at IS 'When the key was revoked; revoked keys no longer authenticate';
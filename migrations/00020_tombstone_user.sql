@@ -0,0 +1,24 @@
+-- ============================================================================
+-- Migration: 00020_tombstone_user.sql
+-- Description: Placeholder user that inherits content from anonymized accounts
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- Anonymizing an account moves its submissions, proposals and comments to
+-- this user, so every foreign key keeps pointing at an existing row. The
+-- account has no usable password and is soft deleted, so it can never sign
+-- in or show up in user listings.
+INSERT INTO users (
+    id, email, username, display_name, password_hash, role, email_verified, deleted_at
+) VALUES (
+    '00000000-0000-0000-0000-000000000000',
+    'tombstone@deleted.invalid',
+    'deleted-user',
+    'Deleted user',
+    '',
+    'registered',
+    FALSE,
+    NOW()
+)
+ON CONFLICT (id) DO NOTHING;
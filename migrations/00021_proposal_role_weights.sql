@@ -0,0 +1,15 @@
+-- ============================================================================
+-- Migration: 00021_proposal_role_weights.sql
+-- Description: Role weights a proposal's votes were tallied with
+-- Author: LLM Benchmark Exchange
+-- Created: 2026-10-16
+-- ============================================================================
+
+-- Voting power of each role when the proposal was finalized, as a JSON
+-- object keyed by role. Kept with the proposal like the quorum and threshold
+-- so decision records stay reproducible after the configured weights change.
+-- Proposals finalized before this migration are left NULL.
+ALTER TABLE proposals
+    ADD COLUMN role_weights JSONB;
+
+COMMENT ON COLUMN proposals.role_weights IS 'Role weights the votes were tallied with at finalization';
@@ -157,6 +157,17 @@ MIGRATIONS=(
     "00008_events_audit.sql:Event sourcing and audit logs"
     "00009_materialized_views.sql:Performance views"
     "00010_functions.sql:Database functions and triggers"
+    "00011_verification_events.sql:Verification history"
+    "00012_test_case_updates.sql:In-place test case updates"
+    "00013_submission_content_hash.sql:Submission content hashes"
+    "00014_benchmark_version_uniqueness.sql:Unique benchmark versions"
+    "00015_benchmark_submission_count.sql:Benchmark submission counts"
+    "00016_benchmark_custom_category.sql:Custom benchmark categories"
+    "00017_proposal_persistence.sql:Proposal persistence"
+    "00018_submission_cancellation.sql:Submission cancellation"
+    "00019_api_keys.sql:API keys"
+    "00020_tombstone_user.sql:Tombstone user"
+    "00021_proposal_role_weights.sql:Proposal role weights"
 )
 
 FAILED_MIGRATIONS=()